@@ -14,7 +14,7 @@
 
 use george_comm::errors::{Errs, GeorgeResult};
 
-use crate::cmd::{george_error, Alter, Client};
+use crate::cmd::{george_error, george_errors, Alter, Client};
 
 impl Alter {
     pub(crate) fn analysis(
@@ -23,8 +23,8 @@ impl Alter {
         scan: String,
         vss: Vec<String>,
     ) -> GeorgeResult<()> {
-        let intent = vss[1].as_str();
-        match intent {
+        let intent = vss[1].to_ascii_lowercase();
+        match intent.as_str() {
             "database" => {
                 // alter database [database:string] [database:string]
                 // alter database [database:string] [database:string] [comment:string]
@@ -84,6 +84,59 @@ impl Alter {
                 let filepath = vss[3].clone();
                 client.view.archive(used, name, filepath)
             }
+            "cipher" => {
+                // alter cipher [view:string] [fields:string] [deterministic:bool] [readers:string]
+                if used.is_empty() {
+                    return Err(Errs::str(
+                        "database name not defined, please use `use [database/page/ledger] [database]` first!",
+                    ));
+                }
+                if vss.len() != 6 {
+                    return Err(george_error(scan));
+                }
+                let name = vss[2].clone();
+                let fields: Vec<String> = vss[3].split(',').map(|s| s.to_string()).collect();
+                let deterministic: bool;
+                match vss[4].to_ascii_lowercase().parse::<bool>() {
+                    Ok(res) => deterministic = res,
+                    Err(err) => return Err(george_errors(scan, err)),
+                }
+                let readers: Vec<String> = vss[5].split(',').map(|s| s.to_string()).collect();
+                client
+                    .view
+                    .cipher(used, name, fields, deterministic, readers)
+            }
+            "quota" => {
+                // alter quota [scope:string] [name:string] [rate:u32] [burst:u32] [max_bytes:u64] [max_keys:u64]
+                if vss.len() != 8 {
+                    return Err(george_error(scan));
+                }
+                let scope = vss[2].clone();
+                let name = vss[3].clone();
+                let rate: u32;
+                match vss[4].parse::<u32>() {
+                    Ok(res) => rate = res,
+                    Err(err) => return Err(george_errors(scan, err)),
+                }
+                let burst: u32;
+                match vss[5].parse::<u32>() {
+                    Ok(res) => burst = res,
+                    Err(err) => return Err(george_errors(scan, err)),
+                }
+                let max_bytes: u64;
+                match vss[6].parse::<u64>() {
+                    Ok(res) => max_bytes = res,
+                    Err(err) => return Err(george_errors(scan, err)),
+                }
+                let max_keys: u64;
+                match vss[7].parse::<u64>() {
+                    Ok(res) => max_keys = res,
+                    Err(err) => return Err(george_errors(scan, err)),
+                }
+                client
+                    .quota
+                    .set(scope, name, rate, burst, max_bytes, max_keys)
+            }
             _ => Err(Errs::string(format!(
                 "command do not support prefix {} in {}",
                 intent, scan
@@ -0,0 +1,39 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use serde_json::json;
+
+use george_comm::errors::GeorgeResult;
+
+use crate::cmd::{george_error, Backup, Client, Sheet};
+
+impl Backup {
+    pub(crate) fn analysis(
+        client: &mut Client,
+        scan: String,
+        vss: Vec<String>,
+    ) -> GeorgeResult<()> {
+        // backup [dir:string]
+        // backup [dir:string] compress
+        let compress = match vss.len() {
+            2 => false,
+            3 if vss[2].eq_ignore_ascii_case("compress") => true,
+            _ => return Err(george_error(scan)),
+        };
+        let resp = client.backup.backup(vss[1].clone(), compress)?;
+        let mut sheet = Sheet::new(vec!["Dir", "Compress", "Journal Seq", "Files", "Bytes"]);
+        sheet.push(json!([vss[1], compress, resp.seq, resp.files, resp.bytes]));
+        client.print(sheet)
+    }
+}
@@ -12,22 +12,30 @@
  * limitations under the License.
  */
 
-use std::io;
-use std::io::Write;
+#[cfg(unix)]
+use std::path::Path;
 
 use george_comm::errors::{Errs, GeorgeResult};
 use george_rpc::client::db::{
-    DatabaseRpcClient, DiskRpcClient, IndexRpcClient, MemoryRpcClient, PageRpcClient,
-    UserRpcClient, ViewRpcClient,
+    AuditRpcClient, BackupRpcClient, ClusterRpcClient, DatabaseRpcClient, DiskRpcClient,
+    IndexRpcClient, LedgerRpcClient, MemoryRpcClient, PageRpcClient, QuotaRpcClient,
+    ReplicationRpcClient, TransferRpcClient, UserRpcClient, ViewRpcClient,
 };
 use george_rpc::client::{RequestCond, RpcClient, TLSType};
 use george_rpc::tools::Trim;
 
 use crate::cmd::{
-    george_error, Alter, Client, Create, Delete, Drop, Get, Insert, Inspect, Put, Remove, Select,
-    Set, Show,
+    george_error, Alter, Backup, Client, Cluster, Create, Delete, Drop, Export, Format, Get,
+    Import, Insert, Inspect, Load, Promote, Put, Remove, Select, Set, Sheet, Show,
 };
 
+/// 客户端支持的命令关键字
+const INTENTS: [&str; 25] = [
+    "use", "clear", "output", "exit", "quit", "e", "q", "show", "inspect", "create", "alter",
+    "drop", "put", "set", "insert", "get", "remove", "select", "delete", "backup", "export",
+    "import", "load", "promote", "cluster",
+];
+
 impl RpcClient for Client {
     fn new(remote: &str, port: u16, cond_op: Option<RequestCond>) -> GeorgeResult<Self>
     where
@@ -40,6 +48,53 @@ impl RpcClient for Client {
         let index = IndexRpcClient::new(remote, port, cond_op)?;
         let disk = DiskRpcClient::new(remote, port, cond_op)?;
         let memory = MemoryRpcClient::new(remote, port, cond_op)?;
+        let audit = AuditRpcClient::new(remote, port, cond_op)?;
+        let quota = QuotaRpcClient::new(remote, port, cond_op)?;
+        let backup = BackupRpcClient::new(remote, port, cond_op)?;
+        let transfer = TransferRpcClient::new(remote, port, cond_op)?;
+        let replication = ReplicationRpcClient::new(remote, port, cond_op)?;
+        let cluster = ClusterRpcClient::new(remote, port, cond_op)?;
+        let ledger = LedgerRpcClient::new(remote, port, cond_op)?;
+        Ok(Client {
+            user,
+            database,
+            page,
+            view,
+            index,
+            disk,
+            memory,
+            audit,
+            quota,
+            backup,
+            transfer,
+            replication,
+            cluster,
+            ledger,
+            ledger_used: false,
+            format: Format::Table,
+        })
+    }
+
+    #[cfg(unix)]
+    fn new_uds<P: AsRef<Path>>(path: P, cond_op: Option<RequestCond>) -> GeorgeResult<Self>
+    where
+        Self: Sized,
+    {
+        let path = path.as_ref();
+        let user = UserRpcClient::new_uds(path, cond_op)?;
+        let database = DatabaseRpcClient::new_uds(path, cond_op)?;
+        let page = PageRpcClient::new_uds(path, cond_op)?;
+        let view = ViewRpcClient::new_uds(path, cond_op)?;
+        let index = IndexRpcClient::new_uds(path, cond_op)?;
+        let disk = DiskRpcClient::new_uds(path, cond_op)?;
+        let memory = MemoryRpcClient::new_uds(path, cond_op)?;
+        let audit = AuditRpcClient::new_uds(path, cond_op)?;
+        let quota = QuotaRpcClient::new_uds(path, cond_op)?;
+        let backup = BackupRpcClient::new_uds(path, cond_op)?;
+        let transfer = TransferRpcClient::new_uds(path, cond_op)?;
+        let replication = ReplicationRpcClient::new_uds(path, cond_op)?;
+        let cluster = ClusterRpcClient::new_uds(path, cond_op)?;
+        let ledger = LedgerRpcClient::new_uds(path, cond_op)?;
         Ok(Client {
             user,
             database,
@@ -48,6 +103,15 @@ impl RpcClient for Client {
             index,
             disk,
             memory,
+            audit,
+            quota,
+            backup,
+            transfer,
+            replication,
+            cluster,
+            ledger,
+            ledger_used: false,
+            format: Format::Table,
         })
     }
 
@@ -119,6 +183,62 @@ impl RpcClient for Client {
             dn.clone(),
             cond_op,
         )?;
+        let audit = AuditRpcClient::new_tls_bytes(
+            tls_type,
+            remote,
+            port,
+            ca_bytes.clone(),
+            dn.clone(),
+            cond_op,
+        )?;
+        let quota = QuotaRpcClient::new_tls_bytes(
+            tls_type,
+            remote,
+            port,
+            ca_bytes.clone(),
+            dn.clone(),
+            cond_op,
+        )?;
+        let backup = BackupRpcClient::new_tls_bytes(
+            tls_type,
+            remote,
+            port,
+            ca_bytes.clone(),
+            dn.clone(),
+            cond_op,
+        )?;
+        let transfer = TransferRpcClient::new_tls_bytes(
+            tls_type,
+            remote,
+            port,
+            ca_bytes.clone(),
+            dn.clone(),
+            cond_op,
+        )?;
+        let replication = ReplicationRpcClient::new_tls_bytes(
+            tls_type,
+            remote,
+            port,
+            ca_bytes.clone(),
+            dn.clone(),
+            cond_op,
+        )?;
+        let cluster = ClusterRpcClient::new_tls_bytes(
+            tls_type,
+            remote,
+            port,
+            ca_bytes.clone(),
+            dn.clone(),
+            cond_op,
+        )?;
+        let ledger = LedgerRpcClient::new_tls_bytes(
+            tls_type,
+            remote,
+            port,
+            ca_bytes.clone(),
+            dn.clone(),
+            cond_op,
+        )?;
         Ok(Client {
             user,
             database,
@@ -127,6 +247,15 @@ impl RpcClient for Client {
             index,
             disk,
             memory,
+            audit,
+            quota,
+            backup,
+            transfer,
+            replication,
+            cluster,
+            ledger,
+            ledger_used: false,
+            format: Format::Table,
         })
     }
 
@@ -214,6 +343,76 @@ impl RpcClient for Client {
             dn.clone(),
             cond_op,
         )?;
+        let audit = AuditRpcClient::new_tls_bytes_check(
+            tls_type,
+            remote,
+            port,
+            key_bytes.clone(),
+            cert_bytes.clone(),
+            ca_bytes.clone(),
+            dn.clone(),
+            cond_op,
+        )?;
+        let quota = QuotaRpcClient::new_tls_bytes_check(
+            tls_type,
+            remote,
+            port,
+            key_bytes.clone(),
+            cert_bytes.clone(),
+            ca_bytes.clone(),
+            dn.clone(),
+            cond_op,
+        )?;
+        let backup = BackupRpcClient::new_tls_bytes_check(
+            tls_type,
+            remote,
+            port,
+            key_bytes.clone(),
+            cert_bytes.clone(),
+            ca_bytes.clone(),
+            dn.clone(),
+            cond_op,
+        )?;
+        let transfer = TransferRpcClient::new_tls_bytes_check(
+            tls_type,
+            remote,
+            port,
+            key_bytes.clone(),
+            cert_bytes.clone(),
+            ca_bytes.clone(),
+            dn.clone(),
+            cond_op,
+        )?;
+        let replication = ReplicationRpcClient::new_tls_bytes_check(
+            tls_type,
+            remote,
+            port,
+            key_bytes.clone(),
+            cert_bytes.clone(),
+            ca_bytes.clone(),
+            dn.clone(),
+            cond_op,
+        )?;
+        let cluster = ClusterRpcClient::new_tls_bytes_check(
+            tls_type,
+            remote,
+            port,
+            key_bytes.clone(),
+            cert_bytes.clone(),
+            ca_bytes.clone(),
+            dn.clone(),
+            cond_op,
+        )?;
+        let ledger = LedgerRpcClient::new_tls_bytes_check(
+            tls_type,
+            remote,
+            port,
+            key_bytes.clone(),
+            cert_bytes.clone(),
+            ca_bytes.clone(),
+            dn.clone(),
+            cond_op,
+        )?;
         Ok(Client {
             user,
             database,
@@ -222,82 +421,122 @@ impl RpcClient for Client {
             index,
             disk,
             memory,
+            audit,
+            quota,
+            backup,
+            transfer,
+            replication,
+            cluster,
+            ledger,
+            ledger_used: false,
+            format: Format::Table,
         })
     }
 }
 
 impl Client {
     pub(crate) fn login(&mut self, name: String, pass: String) -> GeorgeResult<()> {
-        self.user.login(name, pass)
+        let token = self.user.login(name, pass)?;
+        self.user.set_token(token.clone());
+        self.database.set_token(token.clone());
+        self.page.set_token(token.clone());
+        self.view.set_token(token.clone());
+        self.index.set_token(token.clone());
+        self.disk.set_token(token.clone());
+        self.memory.set_token(token.clone());
+        self.audit.set_token(token.clone());
+        self.quota.set_token(token.clone());
+        self.backup.set_token(token.clone());
+        self.transfer.set_token(token.clone());
+        self.replication.set_token(token.clone());
+        self.cluster.set_token(token.clone());
+        self.ledger.set_token(token);
+        Ok(())
     }
 
-    pub fn scan(&mut self) {
-        print!("george->: ");
-        io::stdout().flush().unwrap();
-        let mut new_str = String::new();
-        let mut all_str = String::new();
-        let mut disk: bool = false;
-        let mut used = String::from("");
-        while io::stdin().read_line(&mut new_str).is_ok() {
-            new_str = Trim::str(new_str);
-            if new_str.ends_with(";") {
-                all_str.push_str(new_str.as_str());
-                let scan = Trim::parse(all_str.clone());
-                if scan.starts_with("use ") {
-                    match self.use_check(scan) {
-                        Ok((d, u)) => {
-                            disk = d;
-                            used = u;
-                            print!("george->: ");
-                            io::stdout().flush().unwrap();
-                            new_str.clear();
-                            all_str.clear();
-                            continue;
-                        }
-                        Err(err) => {
-                            println!("{}", err);
-                            print!("george->: ");
-                            io::stdout().flush().unwrap();
-                            new_str.clear();
-                            all_str.clear();
-                            continue;
-                        }
-                    }
-                } else if scan.starts_with("clear") {
-                    match self.clear_check(scan) {
-                        Ok(()) => {
-                            disk = false;
-                            used = String::from("");
-                            print!("george->: ");
-                            io::stdout().flush().unwrap();
-                            new_str.clear();
-                            all_str.clear();
-                            continue;
-                        }
-                        Err(err) => {
-                            println!("{}", err);
-                            print!("george->: ");
-                            io::stdout().flush().unwrap();
-                            new_str.clear();
-                            all_str.clear();
-                            continue;
-                        }
-                    }
-                } else if scan.eq("exit") | scan.eq("quit") | scan.eq("e") | scan.eq("q") {
-                    break;
-                }
-                match self.parse(disk, used.clone(), scan.clone()) {
-                    Ok(()) => println!("exec \"{}\" on {} success!", scan, used),
-                    Err(err) => println!("exec \"{}\" on {} error: {}", scan, used, err),
+    /// 按当前输出格式打印命令输出内容
+    pub(crate) fn print(&self, sheet: Sheet) -> GeorgeResult<()> {
+        sheet.print(self.format)
+    }
+
+    /// 执行一条完整命令，`use`及`clear`命令更新当前使用的存储引擎，`output`命令切换输出格式
+    ///
+    /// ###Params
+    ///
+    /// * disk 是否为磁盘存储类型
+    /// * used 存储引擎名称
+    /// * scan 已去除结尾`;`的命令
+    ///
+    /// #return
+    ///
+    /// * bool 是否继续执行后续命令，退出命令返回false
+    pub(crate) fn exec(
+        &mut self,
+        disk: &mut bool,
+        used: &mut String,
+        scan: String,
+    ) -> GeorgeResult<bool> {
+        let vss = Trim::split(scan.clone());
+        match Client::intent(&scan)? {
+            "use" => {
+                let (d, u) = self.use_check(scan)?;
+                *disk = d;
+                *used = u;
+            }
+            "clear" => {
+                self.clear_check(scan)?;
+                *disk = false;
+                self.ledger_used = false;
+                used.clear();
+            }
+            "output" => {
+                // output [table/json/jsonl/csv/raw];
+                if vss.len() != 2 {
+                    return Err(george_error(scan));
                 }
-                print!("george->: ");
-                io::stdout().flush().unwrap();
-                all_str.clear();
-            } else {
-                all_str.push_str(new_str.as_str());
-                all_str.push_str(" ");
+                self.format = Format::parse(vss[1].as_str())?;
             }
-            new_str.clear();
+            "exit" | "quit" | "e" | "q" if vss.len() == 1 => return Ok(false),
+            _ => self.parse(*disk, used.clone(), scan)?,
+        }
+        Ok(true)
+    }
+
+    /// 忽略大小写匹配命令首个关键字，返回其小写形式，不改变命令中的名称及字面量
+    pub(crate) fn intent(scan: &str) -> GeorgeResult<&'static str> {
+        let word = scan.split(' ').next().unwrap_or("");
+        match INTENTS
+            .iter()
+            .find(|intent| word.eq_ignore_ascii_case(intent))
+        {
+            Some(intent) => Ok(*intent),
+            None => Err(Errs::string(format!(
+                "command do not support prefix {} in '{}'",
+                word, scan
+            ))),
+        }
+    }
+
+    /// 解析`use [database/page/ledger] [name]`，忽略大小写匹配存储类型，名称保留原样
+    ///
+    /// #return
+    ///
+    /// * &str 小写的存储类型
+    /// * String 存储引擎名称
+    pub(crate) fn used(scan: &str) -> GeorgeResult<(&'static str, String)> {
+        let vss = Trim::split(scan.to_string());
+        if vss.len() != 3 || !vss[0].eq_ignore_ascii_case("use") {
+            return Err(george_error(scan.to_string()));
+        }
+        match ["database", "page", "ledger"]
+            .iter()
+            .find(|used| vss[1].eq_ignore_ascii_case(used))
+        {
+            Some(used) => Ok((*used, vss[2].clone())),
+            None => Err(Errs::string(format!(
+                "command do not support prefix {} in '{}'",
+                vss[1], scan
+            ))),
         }
     }
 
@@ -308,19 +547,15 @@ impl Client {
     /// * bool 是否为磁盘存储类型
     /// * String 存储引擎名称
     fn use_check(&mut self, scan: String) -> GeorgeResult<(bool, String)> {
-        let vss = Trim::split(scan.clone());
-        if vss.len() != 3 {
-            return Err(george_error(scan));
-        }
+        let (used, name) = Client::used(&scan)?;
         let disk: bool;
-        let used = vss[1].as_str();
-        let name = vss[2].clone();
         match used {
             "database" => match self.database.list() {
                 Ok(res) => {
                     for database in res.iter() {
                         if name.eq(database.name.as_str()) {
                             disk = true;
+                            self.ledger_used = false;
                             return Ok((disk, name));
                         }
                     }
@@ -333,6 +568,7 @@ impl Client {
                     for page in res.iter() {
                         if name.eq(page.name.as_str()) {
                             disk = false;
+                            self.ledger_used = false;
                             return Ok((disk, name));
                         }
                     }
@@ -340,7 +576,19 @@ impl Client {
                 }
                 _ => Err(Errs::string(format!("no page matched {}!", name))),
             },
-            "ledger" => Err(Errs::str("no ledger matched!")),
+            "ledger" => match self.ledger.list() {
+                Ok(res) => {
+                    for ledger in res.iter() {
+                        if name.eq(ledger.name.as_str()) {
+                            disk = false;
+                            self.ledger_used = true;
+                            return Ok((disk, name));
+                        }
+                    }
+                    Err(Errs::string(format!("no ledger matched {}!", name)))
+                }
+                _ => Err(Errs::string(format!("no ledger matched {}!", name))),
+            },
             _ => Err(Errs::string(format!(
                 "command do not support prefix {} in '{}'",
                 used, scan
@@ -355,12 +603,13 @@ impl Client {
             return Err(george_error(scan));
         }
         let clear = vss[0].as_str();
-        match clear {
-            "clear" => Ok(()),
-            _ => Err(Errs::string(format!(
+        if clear.eq_ignore_ascii_case("clear") {
+            Ok(())
+        } else {
+            Err(Errs::string(format!(
                 "command do not support prefix {} in '{}'",
                 clear, scan
-            ))),
+            )))
         }
     }
 
@@ -369,7 +618,7 @@ impl Client {
         if vss.len() == 0 {
             return Err(george_error(scan));
         }
-        let intent = vss[0].as_str();
+        let intent = Client::intent(&scan)?;
         match intent {
             "show" => Show::analysis(self, disk, used, scan, vss),
             "inspect" => Inspect::analysis(self, used, scan, vss),
@@ -378,11 +627,17 @@ impl Client {
             "drop" => Drop::analysis(self, used, scan, vss),
             "put" => Put::analysis(self, disk, used, scan, vss),
             "set" => Set::analysis(self, disk, used, scan, vss),
-            "insert" => Insert::analysis(&self, used, scan, vss),
+            "insert" => Insert::analysis(self, used, scan, vss),
             "get" => Get::analysis(self, disk, used, scan, vss),
             "remove" => Remove::analysis(self, disk, used, scan, vss),
             "select" => Select::analysis(self, used, scan, vss),
             "delete" => Delete::analysis(self, used, scan, vss),
+            "backup" => Backup::analysis(self, scan, vss),
+            "export" => Export::analysis(self, disk, used, scan, vss),
+            "import" => Import::analysis(self, disk, used, scan, vss),
+            "load" => Load::analysis(self, disk, used, scan, vss),
+            "promote" => Promote::analysis(self, scan, vss),
+            "cluster" => Cluster::analysis(self, scan, vss),
             _ => Err(Errs::string(format!(
                 "command do not support prefix {} in '{}'",
                 intent, scan
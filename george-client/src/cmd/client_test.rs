@@ -0,0 +1,46 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[cfg(test)]
+mod client {
+    use george_rpc::tools::Trim;
+
+    use crate::cmd::Client;
+
+    #[test]
+    fn intent_test() {
+        let scan = Trim::parse("SHOW DATABASES;".to_string());
+        assert_eq!(scan, "SHOW DATABASES");
+        assert_eq!(Client::intent(&scan).unwrap(), "show");
+        assert_eq!(Client::intent("Exit").unwrap(), "exit");
+        assert_eq!(Client::intent("select * from User").unwrap(), "select");
+        assert!(Client::intent("unknown command").is_err());
+    }
+
+    #[test]
+    fn used_test() {
+        let scan = Trim::parse("use DATABASE Sys;".to_string());
+        assert_eq!(Client::intent(&scan).unwrap(), "use");
+        assert_eq!(
+            Client::used(&scan).unwrap(),
+            ("database", "Sys".to_string())
+        );
+        assert_eq!(
+            Client::used("USE Ledger Chain").unwrap(),
+            ("ledger", "Chain".to_string())
+        );
+        assert!(Client::used("use table Sys").is_err());
+        assert!(Client::used("use database").is_err());
+    }
+}
@@ -0,0 +1,53 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use george_comm::errors::{Errs, GeorgeResult};
+
+use crate::cmd::{george_error, george_errors, Client, Cluster};
+
+impl Cluster {
+    pub(crate) fn analysis(
+        client: &mut Client,
+        scan: String,
+        vss: Vec<String>,
+    ) -> GeorgeResult<()> {
+        if vss.len() < 3 {
+            return Err(george_error(scan));
+        }
+        let id = match vss[2].parse::<u64>() {
+            Ok(res) => res,
+            Err(err) => return Err(george_errors(scan, err)),
+        };
+        match vss[1].to_ascii_lowercase().as_str() {
+            "join" => {
+                // cluster join [id] [host:port];
+                if vss.len() != 4 {
+                    return Err(george_error(scan));
+                }
+                client.cluster.join(id, vss[3].clone())
+            }
+            "leave" => {
+                // cluster leave [id];
+                if vss.len() != 3 {
+                    return Err(george_error(scan));
+                }
+                client.cluster.leave(id)
+            }
+            _ => Err(Errs::string(format!(
+                "command do not support prefix {} in {}",
+                vss[1], scan
+            ))),
+        }
+    }
+}
@@ -12,19 +12,20 @@
  * limitations under the License.
  */
 
+use std::env;
+use std::process;
+
 use clap::{App, ArgMatches};
 
-use george_comm::errors::{Errs, GeorgeResult};
+use george_comm::errors::GeorgeResult;
 use george_comm::io::file::FilerReader;
 use george_comm::io::Filer;
 use george_deploy::Builder;
-use george_rpc::client::{RpcClient, TLSType};
 
-use crate::cmd::{Client, Command, Config, Options};
+use crate::cmd::{Command, Config, Credentials, Options, Repl, Script};
 
 impl Command {
     pub fn init() {
-        // todo config yaml
         match match_value(
             App::new("george-client")
                 .version(Builder::version())
@@ -33,104 +34,53 @@ impl Command {
                 .about(Builder::about())
                 .arg(Options::remote())
                 .arg(Options::port())
+                .arg(Options::socket())
                 .arg(Options::user())
                 .arg(Options::pass())
                 .arg(Options::config())
+                .arg(Options::execute())
+                .arg(Options::file())
+                .arg(Options::force())
+                .arg(Options::output())
+                .arg(Options::profile())
+                .arg(Options::save_password())
                 .get_matches(),
         ) {
-            Err(err) => println!("{}", err),
+            Err(err) => {
+                println!("{}", err);
+                process::exit(1)
+            }
             _ => {}
         }
     }
 }
 
 fn match_value(matches: ArgMatches) -> GeorgeResult<()> {
-    let remote: &str;
-    let port: u16;
-    let name: String;
-    let pass: String;
-    let config_path: String;
-    if matches.is_present("remote") {
-        remote = remote_fn(&matches);
-        port = port_fn(&matches)?;
-        name = user_fn(&matches);
-        pass = pass_fn(&matches);
-        config_path = config_fn(&matches);
-    } else {
-        return Err(Errs::str("remote & port & user & pass must be assign!"));
-    }
-    let config = Config::from(config_path)?;
-    let mut client;
-    if config.tls() {
-        if let Some(ca) = config.tls_ca() {
-            if config.tls_key().is_none() && config.tls_cert().is_none() {
-                client = Client::new_tls(
-                    config.tls_type(),
-                    remote,
-                    port,
-                    ca,
-                    config.domain(),
-                    config.http_config(),
-                )?;
-            } else if config.tls_key().is_some() && config.tls_cert().is_some() {
-                let key = config.tls_key_unwrap();
-                let cert = config.tls_cert_unwrap();
-                client = Client::new_tls_check(
-                    config.tls_type(),
-                    remote,
-                    port,
-                    key,
-                    cert,
-                    ca,
-                    config.domain(),
-                    config.http_config(),
-                )?;
-            } else {
-                return Err(Errs::str("key & cert must be assign together!"));
-            }
-        } else {
-            return Err(Errs::str("ca must be assign!"));
-        }
-    } else {
-        client = Client::new(remote, port, config.http_config())?;
-    }
-    client.login(name, pass)?;
-    Ok(client.scan())
-}
-
-fn remote_fn<'a>(matches: &'a ArgMatches) -> &'a str {
-    if let Some(res) = matches.value_of("remote") {
-        res
-    } else {
-        "127.0.0.1"
-    }
-}
-
-fn port_fn(matches: &ArgMatches) -> GeorgeResult<u16> {
-    if let Some(res) = matches.value_of("port") {
-        if let Ok(port) = res.parse::<u16>() {
-            Ok(port)
-        } else {
-            Err(Errs::str("port must be u16!"))
-        }
-    } else {
-        Ok(9219)
+    let config = Config::load(config_fn(&matches))?;
+    let name = match matches.value_of("profile") {
+        Some(res) => Some(res.to_string()),
+        None => env::var("GEORGE_PROFILE").ok(),
+    };
+    let mut profile = config.profile(name)?;
+    profile.env()?;
+    profile.args(&matches)?;
+    let mut client = profile.connect()?;
+    let mut credentials = Credentials::load()?;
+    let pass = profile.password(&credentials)?;
+    client.login(profile.user(), pass.clone())?;
+    if matches.is_present("save-password") {
+        credentials.save(profile.target(), pass)?;
     }
-}
-
-fn user_fn(matches: &ArgMatches) -> String {
-    if let Some(res) = matches.value_of("user") {
-        res.to_string()
+    client.format = profile.format()?;
+    let force = matches.is_present("force");
+    if let Some(commands) = matches.value_of("execute") {
+        Script::run(client, Script::split(commands), force)
+    } else if let Some(filepath) = matches.value_of("file") {
+        let text = Filer::read(filepath)?;
+        Script::run(client, Script::split(&text), force)
     } else {
-        String::from("")
-    }
-}
-
-fn pass_fn(matches: &ArgMatches) -> String {
-    if let Some(res) = matches.value_of("pass") {
-        res.to_string()
-    } else {
-        String::from("")
+        Repl::run(client);
+        Ok(())
     }
 }
 
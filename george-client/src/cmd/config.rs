@@ -17,10 +17,13 @@ use std::path::Path;
 use george_comm::errors::{Errs, GeorgeResult};
 use george_comm::io::file::{FilerHandler, FilerReader};
 use george_comm::io::Filer;
-use george_deploy::{ConfigServerHttp, ConfigServerTLS};
-use george_rpc::client::{RequestCond, TLSType};
 
-use crate::cmd::Config;
+use crate::cmd::{george_home, Config, Profile};
+
+/// 默认配置文件名，位于`~/.george`
+const CONFIG_FILE: &str = "client.yaml";
+/// 未选择连接配置时使用的连接配置名称
+const DEFAULT_PROFILE: &str = "default";
 
 impl Config {
     pub(crate) fn from<P: AsRef<Path>>(filepath: P) -> GeorgeResult<Self> {
@@ -36,60 +39,49 @@ impl Config {
         }
     }
 
-    pub(crate) fn tls_type(&self) -> TLSType {
-        match self.config.unwrap().tls.unwrap().rust_tls {
-            Some(res) => {
-                if res {
-                    TLSType::Rustls
-                } else {
-                    TLSType::Openssl
-                }
+    /// 读取配置文件，未指定路径时读取`~/.george/client.yaml`，该文件不存在则使用空配置
+    pub(crate) fn load(filepath: String) -> GeorgeResult<Self> {
+        if !filepath.is_empty() {
+            return Config::from(filepath);
+        }
+        match george_home() {
+            Some(home) if Filer::exist(home.join(CONFIG_FILE)) => {
+                Config::from(home.join(CONFIG_FILE))
             }
-            None => TLSType::Openssl,
+            _ => Ok(Config::default()),
         }
     }
 
-    pub(crate) fn tls(&self) -> bool {
-        match self.config.clone() {
-            Some(res) => match res.tls {
-                Some(_) => true,
-                None => false,
-            },
-            None => false,
+    /// 以`config`中的`TLS`及请求条件为基础，合并指定名称的连接配置
+    ///
+    /// 未指定名称时合并名为`default`的连接配置，不存在则仅使用`config`。
+    /// 配置文件中不允许保存密码，密码应通过凭据文件、`GEORGE_PASS`或交互式输入提供
+    pub(crate) fn profile(&self, name: Option<String>) -> GeorgeResult<Profile> {
+        let mut profile = Profile::default();
+        if let Some(conf) = &self.config {
+            profile.tls = conf.tls.clone();
+            profile.http = conf.http;
         }
-    }
-
-    pub(crate) fn tls_ca(&self) -> Option<String> {
-        self.config?.tls?.ca
-    }
-
-    pub(crate) fn tls_key(&self) -> Option<String> {
-        self.config?.tls?.key
-    }
-
-    pub(crate) fn tls_cert(&self) -> Option<String> {
-        self.config?.tls?.cert
-    }
-
-    pub(crate) fn tls_key_unwrap(&self) -> String {
-        self.config?.tls?.key.unwrap()
-    }
-
-    pub(crate) fn tls_cert_unwrap(&self) -> String {
-        self.config?.tls?.cert.unwrap()
-    }
-
-    pub(crate) fn domain(&self) -> String {
-        match self.config.unwrap().tls.unwrap().domain {
-            Some(res) => res,
-            None => "".to_string(),
-        }
-    }
-
-    pub(crate) fn http_config(&self) -> Option<RequestCond> {
-        match self.config.clone() {
-            Some(res) => res.http,
-            None => None,
+        let profiles = self.profiles.clone().unwrap_or_default();
+        for (name, res) in profiles.iter() {
+            if res.pass.is_some() {
+                return Err(Errs::string(format!(
+                    "profile {} must not contain pass, use the credentials file, GEORGE_PASS or the password prompt instead!",
+                    name
+                )));
+            }
         }
+        let selected = match name {
+            Some(name) => match profiles.get(&name) {
+                Some(res) => res.clone(),
+                None => return Err(Errs::string(format!("no profile matched {}!", name))),
+            },
+            None => match profiles.get(DEFAULT_PROFILE) {
+                Some(res) => res.clone(),
+                None => return Ok(profile),
+            },
+        };
+        profile.merge(selected);
+        Ok(profile)
     }
 }
@@ -25,8 +25,8 @@ impl Create {
         scan: String,
         vss: Vec<String>,
     ) -> GeorgeResult<()> {
-        let intent = vss[1].as_str();
-        match intent {
+        let intent = vss[1].to_ascii_lowercase();
+        match intent.as_str() {
             "database" => {
                 // create database [database:string]
                 // create database [database:string] [comment:string]
@@ -72,7 +72,22 @@ impl Create {
                 }
                 client.page.create(name, comment, size, period)
             }
-            "ledger" => Err(Errs::str("no support ledger now!")),
+            "ledger" => {
+                // create ledger [ledger:string]
+                // create ledger [ledger:string] [comment:string]
+                let name: String;
+                let comment: String;
+                if vss.len() == 3 {
+                    name = vss[2].clone();
+                    comment = "".to_string();
+                } else if vss.len() == 4 {
+                    name = vss[2].clone();
+                    comment = vss[3].clone();
+                } else {
+                    return Err(george_error(scan));
+                }
+                client.ledger.create(name, comment)
+            }
             "view" => {
                 // create view [view:string] [increment:bool]
                 // create view [view:string] [increment:bool] [comment:string]
@@ -91,7 +106,7 @@ impl Create {
                 } else {
                     return Err(george_error(scan));
                 }
-                match vss[3].parse::<bool>() {
+                match vss[3].to_ascii_lowercase().parse::<bool>() {
                     Ok(b) => increment = b,
                     Err(err) => return Err(george_errors(scan, err)),
                 }
@@ -119,15 +134,15 @@ impl Create {
                     key_type = KeyType::String;
                     engine = Engine::Disk;
                 } else if vss.len() == 10 {
-                    match vss[5].parse::<bool>() {
+                    match vss[5].to_ascii_lowercase().parse::<bool>() {
                         Ok(b) => primary = b,
                         Err(err) => return Err(george_errors(scan, err)),
                     }
-                    match vss[6].parse::<bool>() {
+                    match vss[6].to_ascii_lowercase().parse::<bool>() {
                         Ok(b) => unique = b,
                         Err(err) => return Err(george_errors(scan, err)),
                     }
-                    match vss[7].parse::<bool>() {
+                    match vss[7].to_ascii_lowercase().parse::<bool>() {
                         Ok(b) => null = b,
                         Err(err) => return Err(george_errors(scan, err)),
                     }
@@ -0,0 +1,101 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+
+use george_comm::errors::{Errs, GeorgeResult};
+use george_comm::io::file::{FilerHandler, FilerReader};
+use george_comm::io::Filer;
+
+use crate::cmd::{george_home, Credentials};
+
+/// 凭据文件名，位于`~/.george`
+const CREDENTIALS_FILE: &str = "credentials.yaml";
+
+impl Credentials {
+    /// 读取`~/.george/credentials.yaml`，文件不存在时返回空凭据
+    pub(crate) fn load() -> GeorgeResult<Self> {
+        match filepath() {
+            Some(filepath) if Filer::exist(&filepath) => {
+                match serde_yaml::from_slice(&Filer::read_bytes(&filepath)?) {
+                    Ok(res) => Ok(res),
+                    Err(err) => Err(Errs::strs("serde yaml", err)),
+                }
+            }
+            _ => Ok(Credentials::default()),
+        }
+    }
+
+    pub(crate) fn get(&self, key: &str) -> Option<String> {
+        self.credentials.get(key).cloned()
+    }
+
+    /// 保存密码并覆写凭据文件，unix下文件权限为`0600`
+    pub(crate) fn save(&mut self, key: String, pass: String) -> GeorgeResult<()> {
+        let filepath = match filepath() {
+            Some(res) => res,
+            None => {
+                return Err(Errs::str(
+                    "can not find home directory to save credentials!",
+                ))
+            }
+        };
+        self.credentials.insert(key, pass);
+        let content = match serde_yaml::to_string(&self) {
+            Ok(res) => res,
+            Err(err) => return Err(Errs::strs("serde yaml", err)),
+        };
+        if let Some(dir) = filepath.parent() {
+            if let Err(err) = fs::create_dir_all(dir) {
+                return Err(Errs::strs("create credentials dir", err));
+            }
+        }
+        let mut options = fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        let mut file = match options.open(&filepath) {
+            Ok(res) => res,
+            Err(err) => return Err(Errs::strs("open credentials", err)),
+        };
+        if let Err(err) = restrict(&file) {
+            return Err(Errs::strs("restrict credentials", err));
+        }
+        match file.write_all(content.as_bytes()) {
+            Ok(()) => Ok(()),
+            Err(err) => Err(Errs::strs("write credentials", err)),
+        }
+    }
+}
+
+fn filepath() -> Option<PathBuf> {
+    Some(george_home()?.join(CREDENTIALS_FILE))
+}
+
+/// 已存在的凭据文件可能权限过宽，写入前收紧为`0600`
+#[cfg(unix)]
+fn restrict(file: &fs::File) -> std::io::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    file.set_permissions(fs::Permissions::from_mode(0o600))
+}
+
+#[cfg(not(unix))]
+fn restrict(_file: &fs::File) -> std::io::Result<()> {
+    Ok(())
+}
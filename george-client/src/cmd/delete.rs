@@ -12,12 +12,10 @@
  * limitations under the License.
  */
 
-use cli_table::format::Justify;
-use cli_table::{Cell, Style, Table};
-
 use george_comm::errors::{Errs, GeorgeResult};
+use george_comm::Sql;
 
-use crate::cmd::{george_error, print_table, Client, Delete};
+use crate::cmd::{george_error, Client, Delete, Sheet};
 
 impl Delete {
     pub(crate) fn analysis(
@@ -27,31 +25,29 @@ impl Delete {
         vss: Vec<String>,
     ) -> GeorgeResult<()> {
         // delete [view:string] [constraint:string]
+        // delete from [view:string] [where ...] [order by ...] [limit n] [offset n]
         if used.is_empty() {
             return Err(Errs::str(
                 "database name not defined, please use `use [database/page/ledger] [database]` first!",
             ));
         }
-        if vss.len() != 3 {
-            return Err(george_error(scan));
-        }
-        let view_name = vss[1].clone();
-        let constraint_json_bytes = vss[2].as_bytes().to_vec();
+        let (view_name, constraint_json_bytes) =
+            if vss.len() > 1 && vss[1].eq_ignore_ascii_case("from") {
+                let sql = Sql::parse(&scan)?;
+                (sql.view.clone(), sql.constraint_bytes())
+            } else if vss.len() == 3 {
+                (vss[1].clone(), vss[2].as_bytes().to_vec())
+            } else {
+                return Err(george_error(scan));
+            };
         let deleted = client.disk.delete(used, view_name, constraint_json_bytes)?;
-        let table = vec![vec![
-            deleted.total.cell(),
-            deleted.count.cell(),
-            deleted.index_name.cell(),
-            deleted.asc.cell().justify(Justify::Right),
-        ]]
-        .table()
-        .title(vec![
-            "Total".cell().bold(true),
-            "Count".cell().bold(true),
-            "Index Name".cell().bold(true),
-            "Asc".cell().bold(true),
-        ])
-        .bold(true);
-        print_table(table)
+        let mut sheet = Sheet::new(vec![]);
+        sheet.expect(
+            deleted.total,
+            deleted.count,
+            deleted.index_name,
+            deleted.asc,
+        );
+        client.print(sheet)
     }
 }
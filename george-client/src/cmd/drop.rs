@@ -26,8 +26,8 @@ impl Drop {
         if vss.len() < 3 {
             return Err(george_error(scan));
         }
-        let intent = vss[1].as_str();
-        match intent {
+        let intent = vss[1].to_ascii_lowercase();
+        match intent.as_str() {
             "database" => {
                 // drop database [database:string]
                 if vss.len() != 3 {
@@ -44,7 +44,14 @@ impl Drop {
                 let name = vss[2].clone();
                 client.page.remove(name)
             }
-            "ledger" => Err(Errs::str("no support ledger now!")),
+            "ledger" => {
+                // drop ledger [ledger:string]
+                if vss.len() != 3 {
+                    return Err(george_error(scan));
+                }
+                let name = vss[2].clone();
+                client.ledger.remove(name)
+            }
             "view" => {
                 // drop view [view:string]
                 if used.is_empty() {
@@ -0,0 +1,55 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use serde_json::json;
+
+use george_comm::errors::{Errs, GeorgeResult};
+
+use crate::cmd::{george_error, Client, Export, Sheet};
+
+impl Export {
+    pub(crate) fn analysis(
+        client: &mut Client,
+        disk: bool,
+        used: String,
+        scan: String,
+        vss: Vec<String>,
+    ) -> GeorgeResult<()> {
+        // export database [database:string] [filepath:string]
+        // export view [view:string] [filepath:string]
+        if vss.len() != 4 {
+            return Err(george_error(scan));
+        }
+        let (database_name, view_name) = match vss[1].to_ascii_lowercase().as_str() {
+            "database" => (vss[2].clone(), "".to_string()),
+            "view" => {
+                if used.is_empty() || !disk {
+                    return Err(Errs::str(
+                        "database name not defined, please use `use [database] [database]` first!",
+                    ));
+                }
+                (used, vss[2].clone())
+            }
+            _ => return Err(george_error(scan)),
+        };
+        let filepath = vss[3].clone();
+        let bytes =
+            client
+                .transfer
+                .export(database_name.clone(), view_name.clone(), filepath.clone())?;
+        let mut sheet = Sheet::new(vec!["Database", "View", "File", "Bytes"]);
+        sheet.push(json!([database_name, view_name, filepath, bytes]));
+        client.print(sheet)
+    }
+}
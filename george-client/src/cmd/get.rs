@@ -12,11 +12,11 @@
  * limitations under the License.
  */
 
+use serde_json::json;
+
 use george_comm::errors::{Errs, GeorgeResult};
-use george_comm::strings::StringHandler;
-use george_comm::Strings;
 
-use crate::cmd::{george_error, Client, Get};
+use crate::cmd::{block_sheet, document, george_error, Client, Get, Sheet};
 
 impl Get {
     pub(crate) fn analysis(
@@ -30,6 +30,9 @@ impl Get {
         if len < 2 {
             return Err(george_error(scan));
         }
+        if client.ledger_used {
+            return ledger(client, used, scan, vss);
+        }
         if disk {
             // get [view:string] [key:string]
             // get [view:string] [key:string]
@@ -44,8 +47,7 @@ impl Get {
                 let view_name = vss[1].clone();
                 let key = vss[2].clone();
                 let value = client.disk.get(used, view_name, key)?;
-                println!("{}", Strings::from_utf8(value)?);
-                Ok(())
+                print_value(client, value)
             } else if len == 4 {
                 let view_name = vss[1].clone();
                 let key = vss[2].clone();
@@ -53,8 +55,7 @@ impl Get {
                 let value = client
                     .disk
                     .fetch_by_index(used, view_name, index_name, key)?;
-                println!("{}", Strings::from_utf8(value)?);
-                Ok(())
+                print_value(client, value)
             } else {
                 return Err(george_error(scan));
             }
@@ -65,14 +66,55 @@ impl Get {
                 return Err(george_error(scan));
             }
             let key = vss[1].clone();
-            if used.is_empty() {
-                let value = client.memory.get(key)?;
-                println!("{}", Strings::from_utf8(value)?);
+            let value = if used.is_empty() {
+                client.memory.get(key)?
             } else {
-                let value = client.memory.fetch_by_page(used, key)?;
-                println!("{}", Strings::from_utf8(value)?);
+                client.memory.fetch_by_page(used, key)?
+            };
+            print_value(client, value)
+        }
+    }
+}
+
+/// 读取当前使用账本中的区块或交易
+fn ledger(client: &mut Client, used: String, scan: String, vss: Vec<String>) -> GeorgeResult<()> {
+    // 关键字忽略大小写匹配，高度及哈希取自原始输入
+    let keywords = vss
+        .iter()
+        .map(|vs| vs.to_ascii_lowercase())
+        .collect::<Vec<String>>();
+    match keywords
+        .iter()
+        .map(|vs| vs.as_str())
+        .collect::<Vec<&str>>()
+        .as_slice()
+    {
+        // get block by height [height:u64]
+        ["get", "block", "by", "height", _] => match vss[4].parse::<u64>() {
+            Ok(height) => {
+                let block = client.ledger.block(used, height)?;
+                client.print(block_sheet(block)?)
             }
-            Ok(())
+            Err(err) => Err(Errs::strs("block height parse u64", err)),
+        },
+        // get block by hash [hash:string]
+        ["get", "block", "by", "hash", _] => {
+            let block = client.ledger.block_by_hash(used, vss[4].clone())?;
+            client.print(block_sheet(block)?)
+        }
+        // get tx [hash:string]
+        ["get", "tx", _] => {
+            let (height, block_hash, tx) = client.ledger.transaction(used, vss[2].clone())?;
+            let mut sheet = Sheet::new(vec!["Height", "Block Hash", "Hash", "Value"]);
+            sheet.push(json!([height, block_hash, tx.hash, document(tx.value)?]));
+            client.print(sheet)
         }
+        _ => Err(george_error(scan)),
     }
 }
+
+fn print_value(client: &Client, value: Vec<u8>) -> GeorgeResult<()> {
+    let mut sheet = Sheet::new(vec!["Value"]);
+    sheet.push(json!([document(value)?]));
+    client.print(sheet)
+}
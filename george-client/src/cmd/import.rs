@@ -0,0 +1,61 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use serde_json::json;
+
+use george_comm::errors::{Errs, GeorgeResult};
+
+use crate::cmd::{george_error, Client, Import, Sheet};
+
+impl Import {
+    pub(crate) fn analysis(
+        client: &mut Client,
+        disk: bool,
+        used: String,
+        scan: String,
+        vss: Vec<String>,
+    ) -> GeorgeResult<()> {
+        // import [filepath:string]
+        // import [filepath:string] overwrite
+        // import [filepath:string] [view:string]
+        // import [filepath:string] [view:string] overwrite
+        if used.is_empty() || !disk {
+            return Err(Errs::str(
+                "database name not defined, please use `use [database] [database]` first!",
+            ));
+        }
+        let overwrite = vss.last().unwrap().eq_ignore_ascii_case("overwrite");
+        let view_name = match (vss.len(), overwrite) {
+            (2, false) | (3, true) => "".to_string(),
+            (3, false) | (4, true) => vss[2].clone(),
+            _ => return Err(george_error(scan)),
+        };
+        let resp = client
+            .transfer
+            .import(used, view_name, overwrite, vss[1].clone())?;
+        let mut sheet = Sheet::new(vec!["Rows", "Imported", "Skipped", "Failed"]);
+        sheet.push(json!(
+            [resp.rows, resp.imported, resp.skipped, resp.failed,]
+        ));
+        client.print(sheet)?;
+        if resp.errors.is_empty() {
+            return Ok(());
+        }
+        let mut sheet = Sheet::new(vec!["Line", "Error"]);
+        for error in resp.errors {
+            sheet.push(json!([error.line, error.msg_err]));
+        }
+        client.print(sheet)
+    }
+}
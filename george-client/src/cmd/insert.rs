@@ -12,17 +12,28 @@
  * limitations under the License.
  */
 
-use george_comm::errors::GeorgeResult;
+use george_comm::errors::{Errs, GeorgeResult};
 
-use crate::cmd::{Client, Insert};
+use crate::cmd::{block_sheet, george_error, Client, Insert};
 
 impl Insert {
     pub(crate) fn analysis(
-        _client: &Client,
-        _used: String,
-        _scan: String,
-        _vss: Vec<String>,
+        client: &mut Client,
+        used: String,
+        scan: String,
+        vss: Vec<String>,
     ) -> GeorgeResult<()> {
-        unimplemented!()
+        // insert block [tx:string] [tx:string] ...
+        if !client.ledger_used || used.is_empty() {
+            return Err(Errs::str(
+                "ledger name not defined, please use `use ledger [ledger]` first!",
+            ));
+        }
+        if vss.len() < 3 || !vss[1].eq_ignore_ascii_case("block") {
+            return Err(george_error(scan));
+        }
+        let transactions = vss[2..].iter().map(|tx| tx.clone().into_bytes()).collect();
+        let block = client.ledger.insert(used, transactions)?;
+        client.print(block_sheet(block)?)
     }
 }
@@ -12,14 +12,13 @@
  * limitations under the License.
  */
 
-use cli_table::format::Justify;
-use cli_table::{Cell, Style, Table};
+use serde_json::json;
 
 use george_comm::errors::{Errs, GeorgeResult};
 use george_db::utils::comm::INDEX_INCREMENT;
 use george_rpc::tools::Trans;
 
-use crate::cmd::{george_error, print_table, Client, Inspect};
+use crate::cmd::{george_error, Client, Inspect, Sheet};
 
 impl Inspect {
     pub(crate) fn analysis(
@@ -28,8 +27,8 @@ impl Inspect {
         scan: String,
         vss: Vec<String>,
     ) -> GeorgeResult<()> {
-        let intent = vss[1].as_str();
-        match intent {
+        let intent = vss[1].to_ascii_lowercase();
+        match intent.as_str() {
             "database" => {
                 // inspect database [database:string]
                 let name: String;
@@ -39,22 +38,14 @@ impl Inspect {
                     return Err(Errs::str("database name is none!"));
                 }
                 let database = client.database.info(name)?;
-                let table = vec![vec![
-                    database.name.clone().cell(),
-                    database.comment.clone().cell(),
-                    Trans::grpc_timestamp_2_string(database.create_time.as_ref().unwrap().seconds)
-                        .cell(),
-                    database.views.len().cell().justify(Justify::Right),
-                ]]
-                .table()
-                .title(vec![
-                    "Name".cell().bold(true),
-                    "Comment".cell().bold(true),
-                    "Create Time".cell().bold(true),
-                    "View Count".cell().bold(true),
-                ])
-                .bold(true);
-                print_table(table)
+                let mut sheet = Sheet::new(vec!["Name", "Comment", "Create Time", "View Count"]);
+                sheet.push(json!([
+                    database.name,
+                    database.comment,
+                    Trans::grpc_timestamp_2_string(database.create_time.as_ref().unwrap().seconds),
+                    database.views.len(),
+                ]));
+                client.print(sheet)
             }
             "page" => {
                 // inspect page [page:string]
@@ -65,27 +56,37 @@ impl Inspect {
                     return Err(Errs::str("page name is none!"));
                 }
                 let page = client.page.info(name)?;
-                let table = vec![vec![
-                    page.name.clone().cell(),
-                    page.comment.clone().cell(),
-                    page.size.cell(),
-                    page.period.cell(),
-                    Trans::grpc_timestamp_2_string(page.create_time.as_ref().unwrap().seconds)
-                        .cell()
-                        .justify(Justify::Right),
-                ]]
-                .table()
-                .title(vec![
-                    "Name".cell().bold(true),
-                    "Comment".cell().bold(true),
-                    "Size".cell().bold(true),
-                    "Period".cell().bold(true),
-                    "Create Time".cell().bold(true),
-                ])
-                .bold(true);
-                print_table(table)
+                let mut sheet =
+                    Sheet::new(vec!["Name", "Comment", "Size", "Period", "Create Time"]);
+                sheet.push(json!([
+                    page.name,
+                    page.comment,
+                    page.size,
+                    page.period,
+                    Trans::grpc_timestamp_2_string(page.create_time.as_ref().unwrap().seconds),
+                ]));
+                client.print(sheet)
+            }
+            "ledger" => {
+                // inspect ledger [ledger:string]
+                let name: String;
+                if vss.len() == 3 {
+                    name = vss[2].clone();
+                } else {
+                    return Err(Errs::str("ledger name is none!"));
+                }
+                let ledger = client.ledger.info(name)?;
+                let mut sheet =
+                    Sheet::new(vec!["Name", "Comment", "Height", "Hash", "Create Time"]);
+                sheet.push(json!([
+                    ledger.name,
+                    ledger.comment,
+                    ledger.height,
+                    ledger.hash,
+                    Trans::grpc_timestamp_2_string(ledger.create_time.as_ref().unwrap().seconds),
+                ]));
+                client.print(sheet)
             }
-            "ledger" => Err(Errs::str("no support ledger now!")),
             "view" => {
                 // inspect view [view]
                 if used.is_empty() {
@@ -110,28 +111,25 @@ impl Inspect {
                         break;
                     }
                 }
-                let table = vec![vec![
-                    view.name.clone().cell(),
-                    view.comment.clone().cell(),
-                    increment.cell(),
-                    Trans::grpc_timestamp_2_string(view.create_time.as_ref().unwrap().seconds)
-                        .cell(),
-                    view.indexes.len().cell().justify(Justify::Right),
-                    view.filepath.cell(),
-                    view.version.cell(),
-                ]]
-                .table()
-                .title(vec![
-                    "Name".cell().bold(true),
-                    "Comment".cell().bold(true),
-                    "Increment".cell().bold(true),
-                    "Create Time".cell().bold(true),
-                    "Index Count".cell().bold(true),
-                    "Filepath".cell().bold(true),
-                    "Version".cell().bold(true),
-                ])
-                .bold(true);
-                print_table(table)
+                let mut sheet = Sheet::new(vec![
+                    "Name",
+                    "Comment",
+                    "Increment",
+                    "Create Time",
+                    "Index Count",
+                    "Filepath",
+                    "Version",
+                ]);
+                sheet.push(json!([
+                    view.name,
+                    view.comment,
+                    increment,
+                    Trans::grpc_timestamp_2_string(view.create_time.as_ref().unwrap().seconds),
+                    view.indexes.len(),
+                    view.filepath,
+                    view.version,
+                ]));
+                client.print(sheet)
             }
             "index" => {
                 // inspect index [index:string] from [view:string]
@@ -140,7 +138,7 @@ impl Inspect {
                         "database name not defined, please use `use [database/page/ledger] [database]` first!",
                     ));
                 }
-                if vss[3].ne("from") {
+                if !vss[3].eq_ignore_ascii_case("from") {
                     return Err(george_error(scan));
                 }
                 let name: String;
@@ -152,29 +150,25 @@ impl Inspect {
                     return Err(george_error(scan));
                 }
                 let index = client.index.info(used, view_name, name)?;
-                let table = vec![vec![
-                    index.name.clone().cell(),
-                    index.unique.cell(),
-                    index.primary.cell(),
-                    index.null.cell(),
-                    Trans::i32_2_key_type_str(index.key_type)?.cell(),
-                    Trans::i32_2_engine_str(index.engine)?.cell(),
-                    Trans::grpc_timestamp_2_string(index.create_time.as_ref().unwrap().seconds)
-                        .cell()
-                        .justify(Justify::Right),
-                ]]
-                .table()
-                .title(vec![
-                    "Name".cell().bold(true),
-                    "Unique".cell().bold(true),
-                    "Primary".cell().bold(true),
-                    "Null".cell().bold(true),
-                    "Key Type".cell().bold(true),
-                    "Engine".cell().bold(true),
-                    "Create Time".cell().bold(true),
-                ])
-                .bold(true);
-                print_table(table)
+                let mut sheet = Sheet::new(vec![
+                    "Name",
+                    "Unique",
+                    "Primary",
+                    "Null",
+                    "Key Type",
+                    "Engine",
+                    "Create Time",
+                ]);
+                sheet.push(json!([
+                    index.name,
+                    index.unique,
+                    index.primary,
+                    index.null,
+                    Trans::i32_2_key_type_str(index.key_type)?,
+                    Trans::i32_2_engine_str(index.engine)?,
+                    Trans::grpc_timestamp_2_string(index.create_time.as_ref().unwrap().seconds),
+                ]));
+                client.print(sheet)
             }
             _ => Err(Errs::string(format!(
                 "command do not support prefix {} in {}",
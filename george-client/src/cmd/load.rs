@@ -0,0 +1,128 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+
+use serde_json::{json, Value};
+
+use george_comm::errors::{Errs, GeorgeResult};
+use george_rpc::protos::db::db::ResponseBatch;
+
+use crate::cmd::{george_error, Client, Load, Sheet};
+
+impl Load {
+    pub(crate) fn analysis(
+        client: &mut Client,
+        disk: bool,
+        used: String,
+        scan: String,
+        vss: Vec<String>,
+    ) -> GeorgeResult<()> {
+        // load [filepath:string] [view:string]
+        // load [filepath:string] [view:string] [put/set/remove]
+        // load [filepath:string] [view:string] [put/set/remove] atomic
+        // load [filepath:string]
+        // load [filepath:string] [put/set/remove]
+        // load [filepath:string] [put/set/remove] atomic
+        if client.ledger_used {
+            return Err(Errs::str(
+                "ledger do not support load, please use `insert block` instead!",
+            ));
+        }
+        let len = vss.len();
+        let atomic = vss.last().unwrap().eq_ignore_ascii_case("atomic");
+        let args = if atomic { len - 1 } else { len };
+        let (view_name, mode) = match (disk, args) {
+            (true, 3) => (vss[2].clone(), "put".to_string()),
+            (true, 4) => (vss[2].clone(), vss[3].to_ascii_lowercase()),
+            (false, 2) => ("".to_string(), "put".to_string()),
+            (false, 3) => ("".to_string(), vss[2].to_ascii_lowercase()),
+            _ => return Err(george_error(scan)),
+        };
+        let mode = mode.as_str();
+        if !["put", "set", "remove"].contains(&mode) {
+            return Err(george_error(scan));
+        }
+        if disk && used.is_empty() {
+            return Err(Errs::str(
+                "database name not defined, please use `use [database/page/ledger] [database]` first!",
+            ));
+        }
+        let items = Load::items(vss[1].clone())?;
+        let resp = match (disk, mode) {
+            (true, "put") => client.disk.put_batch(used, view_name, atomic, items)?,
+            (true, "set") => client.disk.set_batch(used, view_name, atomic, items)?,
+            (true, "remove") => client.disk.remove_batch(used, view_name, atomic, items)?,
+            (false, "put") => client.memory.put_batch(used, atomic, items)?,
+            (false, "set") => client.memory.set_batch(used, atomic, items)?,
+            _ => client.memory.remove_batch(used, atomic, items)?,
+        };
+        Load::print(client, resp)
+    }
+
+    /// 逐行读取`JSON Lines`文件，每行形如`{"key": "k", "value": ...}`，空行将被忽略
+    ///
+    /// value为字符串时以其文本作为值，否则以其JSON文本作为值，remove时可省略
+    fn items(
+        filepath: String,
+    ) -> GeorgeResult<impl Iterator<Item = GeorgeResult<(String, Vec<u8>)>>> {
+        let file = match File::open(&filepath) {
+            Ok(res) => res,
+            Err(err) => return Err(Errs::strs(&format!("open file {}", filepath), err)),
+        };
+        let lines = BufReader::new(file).lines().enumerate();
+        Ok(lines.filter_map(|(index, line)| match line {
+            Ok(line) if line.trim().is_empty() => None,
+            Ok(line) => Some(Load::item(index + 1, line)),
+            Err(err) => Some(Err(Errs::strs(&format!("read line {}", index + 1), err))),
+        }))
+    }
+
+    fn item(line_no: usize, line: String) -> GeorgeResult<(String, Vec<u8>)> {
+        let value: Value = match serde_json::from_str(&line) {
+            Ok(res) => res,
+            Err(err) => return Err(Errs::strs(&format!("parse line {}", line_no), err)),
+        };
+        let key = match value["key"].as_str() {
+            Some(res) => res.to_string(),
+            None => return Err(Errs::string(format!("line {} has no string key!", line_no))),
+        };
+        let bytes = match &value["value"] {
+            Value::Null => vec![],
+            Value::String(res) => res.as_bytes().to_vec(),
+            res => res.to_string().into_bytes(),
+        };
+        Ok((key, bytes))
+    }
+
+    fn print(client: &mut Client, resp: ResponseBatch) -> GeorgeResult<()> {
+        let mut sheet = Sheet::new(vec!["Total", "Succeeded", "Failed", "Rolled Back"]);
+        sheet.push(json!([
+            resp.total,
+            resp.succeeded,
+            resp.failed,
+            resp.rolled_back,
+        ]));
+        client.print(sheet)?;
+        if resp.errors.is_empty() {
+            return Ok(());
+        }
+        let mut sheet = Sheet::new(vec!["Index", "Key", "Error"]);
+        for error in resp.errors {
+            sheet.push(json!([error.index, error.key, error.msg_err]));
+        }
+        client.print(sheet)
+    }
+}
@@ -12,37 +12,67 @@
  * limitations under the License.
  */
 
-use cli_table::{print_stdout, Table};
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::env;
+use std::path::PathBuf;
+use std::sync::mpsc::Receiver;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+use serde_json::{json, Value};
 
 use george_comm::errors::{Errs, GeorgeError, GeorgeResult};
+use george_comm::strings::StringHandler;
+use george_comm::Strings;
 use george_deploy::ConfigServerTLS;
 use george_rpc::client::db::{
-    DatabaseRpcClient, DiskRpcClient, IndexRpcClient, MemoryRpcClient, PageRpcClient,
-    UserRpcClient, ViewRpcClient,
+    AuditRpcClient, BackupRpcClient, ClusterRpcClient, DatabaseRpcClient, DiskRpcClient,
+    IndexRpcClient, LedgerRpcClient, MemoryRpcClient, PageRpcClient, QuotaRpcClient,
+    ReplicationRpcClient, TransferRpcClient, UserRpcClient, ViewRpcClient,
 };
 use george_rpc::client::RequestCond;
+use george_rpc::protos::db::db::LedgerBlock;
+use george_rpc::tools::Trans;
 
 mod alter;
+mod backup;
 mod client;
+mod client_test;
+mod cluster;
 mod command;
 mod config;
 mod create;
+mod credentials;
 mod delete;
 mod drop;
+mod export;
 mod get;
+mod import;
 mod insert;
 mod inspect;
+mod load;
 mod options;
+mod profile;
+mod profile_test;
+mod promote;
 mod put;
 mod remove;
+mod repl;
+mod repl_test;
+mod script;
+mod script_test;
 mod select;
 mod set;
+mod sheet;
 mod show;
 
 /// yaml解析辅助结构
-#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone, Default)]
 pub(crate) struct Config {
     config: Option<Conf>,
+    /// 命名连接配置，通过`--profile`选择，未选择时使用名为`default`的连接配置
+    profiles: Option<HashMap<String, Profile>>,
 }
 
 /// 基础配置信息，优先读取环境变量中的结果<p>
@@ -54,6 +84,35 @@ pub(crate) struct Conf {
     http: Option<RequestCond>,
 }
 
+/// 连接配置
+///
+/// 依次以配置文件中的`config`、所选连接配置、`GEORGE_*`环境变量及命令行参数合并得到，后者优先
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone, Default)]
+pub(crate) struct Profile {
+    /// 服务地址，默认`127.0.0.1`
+    remote: Option<String>,
+    /// 服务端口，默认`9219`
+    port: Option<u16>,
+    /// 本机`Unix domain socket`文件路径，优先于服务地址
+    socket: Option<String>,
+    /// 用户名
+    user: Option<String>,
+    /// 密码，仅来自`GEORGE_PASS`或命令行参数，配置文件中的连接配置不允许包含密码；未提供时依次尝试凭据文件及交互式输入
+    pass: Option<String>,
+    /// 输出格式，可选`table`、`json`、`jsonl`、`csv`及`raw`
+    output: Option<String>,
+    tls: Option<ConfigServerTLS>,
+    http: Option<RequestCond>,
+}
+
+/// 凭据文件
+///
+/// 以`user@host:port`或`user@socket`为键保存密码，文件仅当前用户可读写
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone, Default)]
+pub(crate) struct Credentials {
+    credentials: HashMap<String, String>,
+}
+
 pub(crate) struct Command;
 
 pub(crate) struct Options;
@@ -78,10 +137,22 @@ pub(crate) struct Remove;
 
 pub(crate) struct Insert;
 
+pub(crate) struct Load;
+
 pub(crate) struct Select;
 
 pub(crate) struct Delete;
 
+pub(crate) struct Backup;
+
+pub(crate) struct Export;
+
+pub(crate) struct Import;
+
+pub(crate) struct Promote;
+
+pub(crate) struct Cluster;
+
 pub(crate) struct Client {
     user: UserRpcClient,
     database: DatabaseRpcClient,
@@ -90,8 +161,81 @@ pub(crate) struct Client {
     index: IndexRpcClient,
     disk: DiskRpcClient,
     memory: MemoryRpcClient,
+    audit: AuditRpcClient,
+    quota: QuotaRpcClient,
+    backup: BackupRpcClient,
+    transfer: TransferRpcClient,
+    replication: ReplicationRpcClient,
+    cluster: ClusterRpcClient,
+    ledger: LedgerRpcClient,
+    /// 当前使用的存储引擎是否为账本，使用账本时`insert`及`get`命令作用于该账本
+    ledger_used: bool,
+    /// 命令输出格式
+    format: Format,
 }
 
+/// 命令输出格式
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Format {
+    /// 供人阅读的表格
+    Table,
+    /// `json`文档
+    Json,
+    /// 每行一个`json`对象
+    Jsonl,
+    /// 含列名的`csv`
+    Csv,
+    /// 不含列名，单元格以制表符分隔
+    Raw,
+}
+
+/// 命令输出内容
+///
+/// 按客户端当前的输出格式打印，表格供人阅读，其余格式便于管道处理
+pub(crate) struct Sheet {
+    /// 列名
+    title: Vec<&'static str>,
+    /// 各行单元格
+    rows: Vec<Vec<Value>>,
+    /// 条件检索结果元数据，依次为总数、返回数、使用的索引名称及是否升序
+    expectation: Option<Vec<Value>>,
+}
+
+/// 交互式命令行
+///
+/// 支持多行编辑及逐行追加的历史记录，`Tab`键补全命令关键字，以及从服务端获取并缓存的库、缓存页、账本、视图和索引名称
+pub(crate) struct Repl {
+    client: Arc<Mutex<Client>>,
+    /// 是否为磁盘存储类型
+    disk: Cell<bool>,
+    /// 当前使用的存储引擎名称
+    used: RefCell<String>,
+    /// 已获取的补全名称及获取时间，执行命令后清空
+    cache: RefCell<HashMap<Names, (Instant, Vec<String>)>>,
+    /// 尚未返回的补全名称获取，超时后在下次补全时继续等待
+    pending: RefCell<HashMap<Names, Receiver<Vec<String>>>>,
+}
+
+/// 补全时需从服务端获取的名称
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) enum Names {
+    /// 库名称
+    Databases,
+    /// 缓存页名称
+    Pages,
+    /// 账本名称
+    Ledgers,
+    /// 指定库中的视图名称
+    Views(String),
+    /// 指定库中指定视图的索引名称
+    Indexes(String, String),
+}
+
+/// 非交互式执行
+///
+/// 依次执行`-e`指定的命令或`-f`指定脚本文件中的命令，命令以`;`分隔，`--`至行尾为注释
+pub(crate) struct Script;
+
 pub(crate) fn george_error(scan: String) -> GeorgeError {
     Errs::string(format!("error command with '{}'", scan))
 }
@@ -100,9 +244,41 @@ pub(crate) fn george_errors<Err: ToString>(scan: String, err: Err) -> GeorgeErro
     Errs::strings(format!("error command with '{}'", scan), err)
 }
 
-pub(crate) fn print_table<T: Table>(table: T) -> GeorgeResult<()> {
-    match print_stdout(table) {
-        Ok(()) => Ok(()),
-        Err(err) => Err(Errs::strs("print stdout", err)),
+/// 将存储的值转为输出内容，有效的`json`文档保留其结构，其余按字符串输出
+pub(crate) fn document(v8s: Vec<u8>) -> GeorgeResult<Value> {
+    match serde_json::from_slice::<Value>(&v8s) {
+        Ok(res) => Ok(res),
+        Err(_) => Ok(Value::String(Strings::from_utf8(v8s)?)),
+    }
+}
+
+/// 将区块转为输出内容，交易内容按存储的值输出
+pub(crate) fn block_sheet(block: LedgerBlock) -> GeorgeResult<Sheet> {
+    let mut transactions: Vec<Value> = vec![];
+    for tx in block.transactions {
+        transactions.push(json!({"hash": tx.hash, "nonce": tx.nonce, "value": document(tx.value)?}));
     }
+    let mut sheet = Sheet::new(vec![
+        "Height",
+        "Hash",
+        "Pre Hash",
+        "Tx Root",
+        "Timestamp",
+        "Transactions",
+    ]);
+    sheet.push(json!([
+        block.height,
+        block.hash,
+        block.pre_hash,
+        block.tx_root,
+        Trans::grpc_timestamp_2_string(block.timestamp.as_ref().unwrap().seconds),
+        transactions,
+    ]));
+    Ok(sheet)
+}
+
+/// 客户端配置目录`~/.george`，无法确定用户主目录时为`None`
+pub(crate) fn george_home() -> Option<PathBuf> {
+    let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"))?;
+    Some(PathBuf::from(home).join(".george"))
 }
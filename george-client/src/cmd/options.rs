@@ -31,6 +31,14 @@ impl Options {
             .takes_value(true)
     }
 
+    pub(crate) fn socket() -> Arg<'static, 'static> {
+        Arg::with_name("socket")
+            .short("S")
+            .long("socket")
+            .help("local unix domain socket filepath, takes precedence over remote host")
+            .takes_value(true)
+    }
+
     pub(crate) fn user() -> Arg<'static, 'static> {
         Arg::with_name("user")
             .short("u")
@@ -48,7 +56,52 @@ impl Options {
     pub(crate) fn config() -> Arg<'static, 'static> {
         Arg::with_name("config-path")
             .short("c")
-            .help("client init config filepath")
+            .help("client config filepath, default ~/.george/client.yaml")
+            .takes_value(true)
+    }
+
+    pub(crate) fn execute() -> Arg<'static, 'static> {
+        Arg::with_name("execute")
+            .short("e")
+            .long("execute")
+            .help("execute commands separated by ';' and exit")
             .takes_value(true)
+            .conflicts_with("file")
+    }
+
+    pub(crate) fn file() -> Arg<'static, 'static> {
+        Arg::with_name("file")
+            .short("f")
+            .long("file")
+            .help("execute commands in script file and exit")
+            .takes_value(true)
+    }
+
+    pub(crate) fn force() -> Arg<'static, 'static> {
+        Arg::with_name("force")
+            .long("force")
+            .help("continue executing the remaining commands after a failure")
+    }
+
+    pub(crate) fn output() -> Arg<'static, 'static> {
+        Arg::with_name("output")
+            .short("o")
+            .long("output")
+            .help("output format, default table")
+            .possible_values(&["table", "json", "jsonl", "csv", "raw"])
+            .takes_value(true)
+    }
+
+    pub(crate) fn profile() -> Arg<'static, 'static> {
+        Arg::with_name("profile")
+            .long("profile")
+            .help("connection profile name in client config, default GEORGE_PROFILE or 'default'")
+            .takes_value(true)
+    }
+
+    pub(crate) fn save_password() -> Arg<'static, 'static> {
+        Arg::with_name("save-password")
+            .long("save-password")
+            .help("save password into ~/.george/credentials.yaml after login")
     }
 }
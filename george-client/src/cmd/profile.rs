@@ -0,0 +1,183 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::env;
+
+use clap::ArgMatches;
+
+use george_comm::errors::{Errs, GeorgeResult};
+use george_rpc::client::{RpcClient, TLSType};
+
+use crate::cmd::{Client, Credentials, Format, Profile};
+
+/// 默认服务地址
+const DEFAULT_REMOTE: &str = "127.0.0.1";
+/// 默认服务端口
+const DEFAULT_PORT: u16 = 9219;
+
+impl Profile {
+    /// 以`other`中已配置的项覆盖当前连接配置
+    pub(crate) fn merge(&mut self, other: Profile) {
+        if other.remote.is_some() {
+            self.remote = other.remote;
+        }
+        if other.port.is_some() {
+            self.port = other.port;
+        }
+        if other.socket.is_some() {
+            self.socket = other.socket;
+        }
+        if other.user.is_some() {
+            self.user = other.user;
+        }
+        if other.pass.is_some() {
+            self.pass = other.pass;
+        }
+        if other.output.is_some() {
+            self.output = other.output;
+        }
+        if other.tls.is_some() {
+            self.tls = other.tls;
+        }
+        if other.http.is_some() {
+            self.http = other.http;
+        }
+    }
+
+    /// 以`GEORGE_HOST`、`GEORGE_PORT`、`GEORGE_SOCKET`、`GEORGE_USER`、`GEORGE_PASS`及
+    /// `GEORGE_OUTPUT`环境变量覆盖连接配置
+    pub(crate) fn env(&mut self) -> GeorgeResult<()> {
+        if let Ok(res) = env::var("GEORGE_PORT") {
+            self.port = Some(parse_port(&res)?);
+        }
+        self.merge(Profile {
+            remote: env::var("GEORGE_HOST").ok(),
+            socket: env::var("GEORGE_SOCKET").ok(),
+            user: env::var("GEORGE_USER").ok(),
+            pass: env::var("GEORGE_PASS").ok(),
+            output: env::var("GEORGE_OUTPUT").ok(),
+            ..Profile::default()
+        });
+        Ok(())
+    }
+
+    /// 以命令行参数覆盖连接配置，优先级最高
+    pub(crate) fn args(&mut self, matches: &ArgMatches) -> GeorgeResult<()> {
+        if let Some(res) = matches.value_of("port") {
+            self.port = Some(parse_port(res)?);
+        }
+        let value = |name: &str| matches.value_of(name).map(|res| res.to_string());
+        self.merge(Profile {
+            remote: value("remote"),
+            socket: value("socket"),
+            user: value("user"),
+            pass: value("pass"),
+            output: value("output"),
+            ..Profile::default()
+        });
+        Ok(())
+    }
+
+    pub(crate) fn format(&self) -> GeorgeResult<Format> {
+        match &self.output {
+            Some(res) => Format::parse(res),
+            None => Ok(Format::Table),
+        }
+    }
+
+    pub(crate) fn user(&self) -> String {
+        self.user.clone().unwrap_or_default()
+    }
+
+    /// 凭据文件中的键，`user@host:port`或`user@socket`
+    pub(crate) fn target(&self) -> String {
+        match &self.socket {
+            Some(socket) => format!("{}@{}", self.user(), socket),
+            None => format!("{}@{}:{}", self.user(), self.remote(), self.port()),
+        }
+    }
+
+    /// 依次取连接配置中的密码、凭据文件中的密码，均不存在时在终端中无回显输入
+    pub(crate) fn password(&self, credentials: &Credentials) -> GeorgeResult<String> {
+        if let Some(pass) = &self.pass {
+            return Ok(pass.clone());
+        }
+        if let Some(pass) = credentials.get(&self.target()) {
+            return Ok(pass);
+        }
+        match rpassword::read_password_from_tty(Some("password: ")) {
+            Ok(res) => Ok(res),
+            Err(err) => Err(Errs::strs("read password", err)),
+        }
+    }
+
+    /// 根据连接配置建立客户端，配置`socket`时优先使用`Unix domain socket`
+    pub(crate) fn connect(&self) -> GeorgeResult<Client> {
+        if let Some(socket) = &self.socket {
+            return uds_fn(socket, self);
+        }
+        let remote = self.remote();
+        let port = self.port();
+        let tls = match &self.tls {
+            Some(res) => res,
+            None => return Client::new(&remote, port, self.http),
+        };
+        let tls_type = if tls.rust_tls.unwrap_or(false) {
+            TLSType::Rustls
+        } else {
+            TLSType::Openssl
+        };
+        let domain = tls.domain.clone().unwrap_or_default();
+        let ca = match &tls.ca {
+            Some(res) => res,
+            None => return Err(Errs::str("ca must be assign!")),
+        };
+        match (&tls.key, &tls.cert) {
+            (None, None) => Client::new_tls(tls_type, &remote, port, ca, domain, self.http),
+            (Some(key), Some(cert)) => {
+                Client::new_tls_check(tls_type, &remote, port, key, cert, ca, domain, self.http)
+            }
+            _ => Err(Errs::str("key & cert must be assign together!")),
+        }
+    }
+
+    fn remote(&self) -> String {
+        self.remote
+            .clone()
+            .unwrap_or_else(|| DEFAULT_REMOTE.to_string())
+    }
+
+    fn port(&self) -> u16 {
+        self.port.unwrap_or(DEFAULT_PORT)
+    }
+}
+
+fn parse_port(port: &str) -> GeorgeResult<u16> {
+    match port.parse::<u16>() {
+        Ok(res) => Ok(res),
+        Err(_) => Err(Errs::str("port must be u16!")),
+    }
+}
+
+#[cfg(unix)]
+fn uds_fn(socket: &str, profile: &Profile) -> GeorgeResult<Client> {
+    Client::new_uds(socket, profile.http)
+}
+
+#[cfg(not(unix))]
+fn uds_fn(_socket: &str, _profile: &Profile) -> GeorgeResult<Client> {
+    Err(Errs::str(
+        "unix domain socket is not supported on this platform!",
+    ))
+}
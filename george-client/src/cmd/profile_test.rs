@@ -0,0 +1,118 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[cfg(test)]
+mod profile {
+    use std::env;
+
+    use clap::App;
+
+    use crate::cmd::{Config, Options, Profile};
+
+    const CONFIG: &str = "
+config:
+  http:
+    timeout: 30
+profiles:
+  default:
+    remote: 10.0.0.1
+    user: admin
+  prod:
+    remote: 10.0.0.2
+    port: 9300
+    user: admin
+    output: json
+";
+
+    fn config(yaml: &str) -> Config {
+        serde_yaml::from_str(yaml).unwrap()
+    }
+
+    fn args(argv: Vec<&str>) -> Profile {
+        let matches = App::new("george-client")
+            .arg(Options::remote())
+            .arg(Options::port())
+            .arg(Options::socket())
+            .arg(Options::user())
+            .arg(Options::pass())
+            .arg(Options::output())
+            .get_matches_from(argv);
+        let mut profile = Profile::default();
+        profile.args(&matches).unwrap();
+        profile
+    }
+
+    #[test]
+    fn profile_select_test() {
+        let config = config(CONFIG);
+        let profile = config.profile(None).unwrap();
+        assert_eq!(profile.remote, Some("10.0.0.1".to_string()));
+        assert!(profile.http.is_some());
+        let profile = config.profile(Some("prod".to_string())).unwrap();
+        assert_eq!(profile.target(), "admin@10.0.0.2:9300");
+        assert_eq!(profile.output, Some("json".to_string()));
+        assert!(config.profile(Some("test".to_string())).is_err());
+    }
+
+    #[test]
+    fn profile_pass_test() {
+        let config = config("profiles:\n  prod:\n    user: admin\n    pass: secret\n");
+        assert!(config.profile(Some("prod".to_string())).is_err());
+        assert!(config.profile(None).is_err());
+    }
+
+    #[test]
+    fn merge_precedence_test() {
+        let mut profile = config(CONFIG).profile(Some("prod".to_string())).unwrap();
+        env::set_var("GEORGE_HOST", "10.0.0.3");
+        env::set_var("GEORGE_USER", "reader");
+        env::set_var("GEORGE_PASS", "env");
+        let res = profile.env();
+        env::remove_var("GEORGE_HOST");
+        env::remove_var("GEORGE_USER");
+        env::remove_var("GEORGE_PASS");
+        res.unwrap();
+        // 环境变量覆盖连接配置，未设置的项保留连接配置中的结果
+        assert_eq!(profile.target(), "reader@10.0.0.3:9300");
+        assert_eq!(profile.pass, Some("env".to_string()));
+        assert_eq!(profile.output, Some("json".to_string()));
+        // 命令行参数覆盖环境变量
+        profile.merge(args(vec![
+            "george-client",
+            "-H",
+            "10.0.0.4",
+            "-p",
+            "arg",
+            "-o",
+            "csv",
+        ]));
+        assert_eq!(profile.target(), "reader@10.0.0.4:9300");
+        assert_eq!(profile.pass, Some("arg".to_string()));
+        assert_eq!(profile.output, Some("csv".to_string()));
+        // 未指定的命令行参数不覆盖已有结果
+        profile.merge(args(vec!["george-client"]));
+        assert_eq!(profile.target(), "reader@10.0.0.4:9300");
+    }
+
+    #[test]
+    fn args_port_test() {
+        let profile = args(vec!["george-client", "-P", "9400", "-u", "admin"]);
+        assert_eq!(profile.port, Some(9400));
+        assert_eq!(profile.target(), "admin@127.0.0.1:9400");
+        let matches = App::new("george-client")
+            .arg(Options::port())
+            .get_matches_from(vec!["george-client", "-P", "port"]);
+        assert!(Profile::default().args(&matches).is_err());
+    }
+}
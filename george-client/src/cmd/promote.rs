@@ -0,0 +1,36 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use serde_json::json;
+
+use george_comm::errors::GeorgeResult;
+
+use crate::cmd::{george_error, Client, Promote, Sheet};
+
+impl Promote {
+    pub(crate) fn analysis(
+        client: &mut Client,
+        scan: String,
+        vss: Vec<String>,
+    ) -> GeorgeResult<()> {
+        // promote
+        if vss.len() != 1 {
+            return Err(george_error(scan));
+        }
+        let applied = client.replication.promote()?;
+        let mut sheet = Sheet::new(vec!["Role", "Applied"]);
+        sheet.push(json!(["primary", applied]));
+        client.print(sheet)
+    }
+}
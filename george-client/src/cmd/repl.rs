@@ -0,0 +1,371 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::env;
+use std::path::PathBuf;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use rustyline::completion::{Completer, Pair};
+use rustyline::error::ReadlineError;
+use rustyline::highlight::Highlighter;
+use rustyline::hint::Hinter;
+use rustyline::validate::{ValidationContext, ValidationResult, Validator};
+use rustyline::{Context, Editor, Helper};
+
+use george_rpc::tools::Trim;
+
+use crate::cmd::{Client, Names, Repl};
+
+/// 命令提示符
+const PROMPT: &str = "george->: ";
+/// 历史记录文件名，位于用户主目录
+const HISTORY_FILE: &str = ".george_history";
+/// 补全时等待服务端返回名称的最长时间
+const COMPLETION_TIMEOUT: Duration = Duration::from_millis(500);
+/// 补全名称缓存的有效期
+const CACHE_TTL: Duration = Duration::from_secs(10);
+/// 命令关键字
+const COMMANDS: [&str; 23] = [
+    "alter", "backup", "clear", "cluster", "create", "delete", "drop", "exit", "export", "get",
+    "import", "insert", "inspect", "load", "output", "promote", "put", "quit", "remove", "select",
+    "set", "show", "use",
+];
+
+impl Repl {
+    /// 运行交互式命令行，输入`exit;`、`quit;`或`Ctrl-D`后退出
+    pub(crate) fn run(client: Client) {
+        let mut editor = Editor::<Repl>::new();
+        editor.set_helper(Some(Repl {
+            client: Arc::new(Mutex::new(client)),
+            disk: Cell::new(false),
+            used: RefCell::new(String::from("")),
+            cache: RefCell::new(HashMap::new()),
+            pending: RefCell::new(HashMap::new()),
+        }));
+        let history = history_path();
+        if let Some(path) = &history {
+            let _ = editor.load_history(path);
+        }
+        loop {
+            match editor.readline(PROMPT) {
+                Ok(line) => {
+                    let scan = Trim::str(line);
+                    if scan.is_empty() {
+                        continue;
+                    }
+                    // 逐条追加历史记录，异常退出时不丢失已执行的命令
+                    if editor.add_history_entry(scan.as_str()) {
+                        if let Some(path) = &history {
+                            if let Err(err) = editor.append_history(path) {
+                                println!("save history error: {}", err);
+                            }
+                        }
+                    }
+                    match editor.helper() {
+                        Some(repl) => {
+                            if !repl.exec(Trim::parse(scan)) {
+                                break;
+                            }
+                        }
+                        None => break,
+                    }
+                }
+                // `Ctrl-C`放弃当前输入
+                Err(ReadlineError::Interrupted) => continue,
+                Err(ReadlineError::Eof) => break,
+                Err(err) => {
+                    println!("read line error: {}", err);
+                    break;
+                }
+            }
+        }
+    }
+
+    /// 执行一条完整命令，返回是否继续读取后续命令
+    fn exec(&self, scan: String) -> bool {
+        let mut disk = self.disk.get();
+        let mut used = self.used.borrow().clone();
+        let result = match self.client.lock() {
+            Ok(mut client) => client.exec(&mut disk, &mut used, scan.clone()),
+            Err(err) => err.into_inner().exec(&mut disk, &mut used, scan.clone()),
+        };
+        self.disk.set(disk);
+        self.used.replace(used.clone());
+        // 命令可能新建或删除了库、视图等，已缓存的名称不再可信
+        self.cache.borrow_mut().clear();
+        self.pending.borrow_mut().clear();
+        match result {
+            Ok(false) => false,
+            Ok(true) => {
+                println!("exec \"{}\" on {} success!", scan, used);
+                true
+            }
+            Err(err) => {
+                println!("exec \"{}\" on {} error: {}", scan, used, err);
+                true
+            }
+        }
+    }
+
+    /// 根据光标前已输入的内容给出候选词
+    ///
+    /// 首个词补全命令关键字；`use`之后补全存储类型及库、缓存页或账本名称；使用库后补全视图名称，视图名称之后补全索引名称。
+    /// 需从服务端获取的名称通过`fetch`取得
+    pub(crate) fn candidates(
+        words: &[String],
+        disk: bool,
+        used: &str,
+        fetch: &mut dyn FnMut(Names) -> Vec<String>,
+    ) -> Vec<String> {
+        match words {
+            [] => COMMANDS.iter().map(|word| word.to_string()).collect(),
+            [intent] if intent.eq_ignore_ascii_case("use") => vec![
+                "database".to_string(),
+                "page".to_string(),
+                "ledger".to_string(),
+            ],
+            [intent, used] if intent.eq_ignore_ascii_case("use") => {
+                match used.to_ascii_lowercase().as_str() {
+                    "database" => fetch(Names::Databases),
+                    "page" => fetch(Names::Pages),
+                    "ledger" => fetch(Names::Ledgers),
+                    _ => vec![],
+                }
+            }
+            [intent] => match Repl::keywords(intent.to_ascii_lowercase().as_str()) {
+                Some(res) => res.iter().map(|word| word.to_string()).collect(),
+                None => views(disk, used, fetch),
+            },
+            [.., last] => {
+                let views = views(disk, used, &mut *fetch);
+                if views.contains(last) {
+                    fetch(Names::Indexes(used.to_string(), last.clone()))
+                } else {
+                    views
+                }
+            }
+        }
+    }
+
+    /// 补全光标前的输入，返回被替换词的起始位置及候选词
+    ///
+    /// 命令关键字忽略大小写匹配，库、视图及索引等名称按输入原样区分大小写匹配
+    pub(crate) fn completions(
+        line: &str,
+        disk: bool,
+        used: &str,
+        fetch: &mut dyn FnMut(Names) -> Vec<String>,
+    ) -> (usize, Vec<String>) {
+        let start = match line.rfind(char::is_whitespace) {
+            Some(index) => index + 1,
+            None => 0,
+        };
+        let word = &line[start..];
+        let words = line[..start]
+            .split_whitespace()
+            .map(|word| word.to_string())
+            .collect::<Vec<String>>();
+        let keyword = match words.as_slice() {
+            [] => true,
+            [intent] => {
+                intent.eq_ignore_ascii_case("use")
+                    || Repl::keywords(intent.to_ascii_lowercase().as_str()).is_some()
+            }
+            _ => false,
+        };
+        let lower = word.to_ascii_lowercase();
+        let mut candidates = Repl::candidates(&words, disk, used, fetch)
+            .into_iter()
+            .filter(|candidate| {
+                if keyword {
+                    candidate.starts_with(lower.as_str())
+                } else {
+                    candidate.starts_with(word)
+                }
+            })
+            .collect::<Vec<String>>();
+        candidates.sort();
+        candidates.dedup();
+        (start, candidates)
+    }
+
+    /// 紧随命令关键字之后的关键字
+    pub(crate) fn keywords(intent: &str) -> Option<&'static [&'static str]> {
+        match intent {
+            "show" => Some(&[
+                "databases",
+                "pages",
+                "ledgers",
+                "views",
+                "record",
+                "records",
+                "indexes",
+                "audits",
+                "quotas",
+                "audit",
+                "replication",
+                "cluster",
+            ]),
+            "inspect" | "create" => Some(&["database", "page", "ledger", "view", "index"]),
+            "alter" => Some(&[
+                "database", "page", "ledger", "view", "archive", "cipher", "quota",
+            ]),
+            "drop" => Some(&["database", "page", "ledger", "view"]),
+            "insert" => Some(&["block"]),
+            "cluster" => Some(&["join", "leave"]),
+            "output" => Some(&["table", "json", "jsonl", "csv", "raw"]),
+            _ => None,
+        }
+    }
+
+    /// 获取补全名称
+    ///
+    /// 优先使用未过期的缓存；否则在后台线程中向服务端获取，至多等待`COMPLETION_TIMEOUT`，
+    /// 超时则返回过期缓存或空列表，不阻塞输入，后台结果在下次补全时写入缓存
+    fn fetch(&self, names: Names) -> Vec<String> {
+        if let Some((time, res)) = self.cache.borrow().get(&names) {
+            if time.elapsed() < CACHE_TTL {
+                return res.clone();
+            }
+        }
+        let mut pending = self.pending.borrow_mut();
+        if !pending.contains_key(&names) {
+            let (sender, receiver) = mpsc::channel();
+            let client = self.client.clone();
+            let fetch_names = names.clone();
+            thread::spawn(move || {
+                let res = match client.lock() {
+                    Ok(mut client) => fetch_names.fetch(&mut client),
+                    Err(_) => vec![],
+                };
+                let _ = sender.send(res);
+            });
+            pending.insert(names.clone(), receiver);
+        }
+        let received = match pending.get(&names) {
+            Some(receiver) => receiver.recv_timeout(COMPLETION_TIMEOUT),
+            None => Err(RecvTimeoutError::Disconnected),
+        };
+        match received {
+            Ok(res) => {
+                pending.remove(&names);
+                self.cache
+                    .borrow_mut()
+                    .insert(names, (Instant::now(), res.clone()));
+                res
+            }
+            Err(RecvTimeoutError::Timeout) => match self.cache.borrow().get(&names) {
+                Some((_, res)) => res.clone(),
+                None => vec![],
+            },
+            Err(RecvTimeoutError::Disconnected) => {
+                pending.remove(&names);
+                vec![]
+            }
+        }
+    }
+}
+
+impl Names {
+    /// 向服务端获取名称，获取失败时返回空列表
+    fn fetch(&self, client: &mut Client) -> Vec<String> {
+        let res: Result<Vec<String>, _> = match self {
+            Names::Databases => client
+                .database
+                .list()
+                .map(|res| res.into_iter().map(|database| database.name).collect()),
+            Names::Pages => client
+                .page
+                .list()
+                .map(|res| res.into_iter().map(|page| page.name).collect()),
+            Names::Ledgers => client
+                .ledger
+                .list()
+                .map(|res| res.into_iter().map(|ledger| ledger.name).collect()),
+            Names::Views(database_name) => client
+                .view
+                .list(database_name.clone())
+                .map(|res| res.into_iter().map(|view| view.name).collect()),
+            Names::Indexes(database_name, view_name) => client
+                .index
+                .list(database_name.clone(), view_name.clone())
+                .map(|res| res.into_iter().map(|index| index.name).collect()),
+        };
+        res.unwrap_or_default()
+    }
+}
+
+impl Completer for Repl {
+    type Candidate = Pair;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        _ctx: &Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<Pair>)> {
+        let disk = self.disk.get();
+        let used = self.used.borrow().clone();
+        let (start, candidates) =
+            Repl::completions(&line[..pos], disk, &used, &mut |names| self.fetch(names));
+        let pairs = candidates
+            .into_iter()
+            .map(|candidate| Pair {
+                display: candidate.clone(),
+                replacement: candidate,
+            })
+            .collect::<Vec<Pair>>();
+        Ok((start, pairs))
+    }
+}
+
+impl Hinter for Repl {
+    type Hint = String;
+}
+
+impl Highlighter for Repl {}
+
+/// 未以`;`结尾的输入视为未完成，换行后继续编辑
+impl Validator for Repl {
+    fn validate(&self, ctx: &mut ValidationContext<'_>) -> rustyline::Result<ValidationResult> {
+        let input = ctx.input().trim();
+        if input.is_empty() || input.ends_with(';') {
+            Ok(ValidationResult::Valid(None))
+        } else {
+            Ok(ValidationResult::Incomplete)
+        }
+    }
+}
+
+impl Helper for Repl {}
+
+/// 使用磁盘存储库时获取当前库中的视图名称
+fn views(disk: bool, used: &str, fetch: &mut dyn FnMut(Names) -> Vec<String>) -> Vec<String> {
+    if disk {
+        fetch(Names::Views(used.to_string()))
+    } else {
+        vec![]
+    }
+}
+
+/// 历史记录文件路径，无法确定用户主目录时不记录历史
+fn history_path() -> Option<PathBuf> {
+    let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"))?;
+    Some(PathBuf::from(home).join(HISTORY_FILE))
+}
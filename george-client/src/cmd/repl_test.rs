@@ -0,0 +1,149 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[cfg(test)]
+mod repl {
+    use crate::cmd::{Names, Repl};
+
+    fn words(line: &str) -> Vec<String> {
+        line.split_whitespace()
+            .map(|word| word.to_string())
+            .collect()
+    }
+
+    fn fetch(names: Names) -> Vec<String> {
+        match names {
+            Names::Databases => vec!["sys".to_string(), "test".to_string()],
+            Names::Pages => vec!["page1".to_string()],
+            Names::Ledgers => vec!["ledger1".to_string()],
+            Names::Views(database_name) if database_name == "sys" => {
+                vec!["user".to_string(), "order".to_string()]
+            }
+            Names::Indexes(database_name, view_name)
+                if database_name == "sys" && view_name == "user" =>
+            {
+                vec!["age".to_string()]
+            }
+            Names::Views(database_name) if database_name == "Shop" => {
+                vec!["OrderItem".to_string(), "orderLog".to_string()]
+            }
+            Names::Indexes(database_name, view_name)
+                if database_name == "Shop" && view_name == "OrderItem" =>
+            {
+                vec!["ItemId".to_string(), "itemName".to_string()]
+            }
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn candidates_command_test() {
+        let res = Repl::candidates(&words(""), false, "", &mut fetch);
+        assert!(res.contains(&"select".to_string()));
+        assert!(res.contains(&"use".to_string()));
+        assert_eq!(
+            Repl::candidates(&words("use"), false, "", &mut fetch),
+            vec!["database", "page", "ledger"]
+        );
+    }
+
+    #[test]
+    fn candidates_use_test() {
+        assert_eq!(
+            Repl::candidates(&words("use database"), false, "", &mut fetch),
+            vec!["sys", "test"]
+        );
+        assert_eq!(
+            Repl::candidates(&words("use page"), false, "", &mut fetch),
+            vec!["page1"]
+        );
+        assert_eq!(
+            Repl::candidates(&words("use ledger"), false, "", &mut fetch),
+            vec!["ledger1"]
+        );
+        assert!(Repl::candidates(&words("use view"), false, "", &mut fetch).is_empty());
+    }
+
+    #[test]
+    fn candidates_view_test() {
+        assert_eq!(
+            Repl::candidates(&words("select"), true, "sys", &mut fetch),
+            vec!["user", "order"]
+        );
+        assert_eq!(
+            Repl::candidates(&words("select user"), true, "sys", &mut fetch),
+            vec!["age"]
+        );
+        assert_eq!(
+            Repl::candidates(&words("select where"), true, "sys", &mut fetch),
+            vec!["user", "order"]
+        );
+        // 非磁盘存储不获取视图名称
+        let mut fetched = false;
+        let res = Repl::candidates(&words("select user"), false, "page1", &mut |names| {
+            fetched = true;
+            fetch(names)
+        });
+        assert!(res.is_empty());
+        assert!(!fetched);
+    }
+
+    #[test]
+    fn keywords_test() {
+        assert_eq!(
+            Repl::candidates(&words("drop"), true, "sys", &mut fetch),
+            vec!["database", "page", "ledger", "view"]
+        );
+        assert!(Repl::keywords("show").unwrap().contains(&"databases"));
+        assert_eq!(Repl::keywords("cluster"), Some(&["join", "leave"][..]));
+        assert_eq!(Repl::keywords("output").unwrap().len(), 5);
+        assert!(Repl::keywords("select").is_none());
+        assert!(Repl::keywords("use").is_none());
+    }
+
+    #[test]
+    fn completions_mixed_case_test() {
+        // 关键字忽略大小写
+        assert_eq!(
+            Repl::completions("SEL", false, "", &mut fetch),
+            (0, vec!["select".to_string()])
+        );
+        assert_eq!(
+            Repl::completions("SHOW DATA", false, "", &mut fetch),
+            (5, vec!["databases".to_string()])
+        );
+        // 名称按原样区分大小写
+        assert_eq!(
+            Repl::completions("select Ord", true, "Shop", &mut fetch),
+            (7, vec!["OrderItem".to_string()])
+        );
+        assert_eq!(
+            Repl::completions("select ord", true, "Shop", &mut fetch),
+            (7, vec!["orderLog".to_string()])
+        );
+        // 大小写混合的视图名称之后补全其索引名称
+        assert_eq!(
+            Repl::completions("SELECT OrderItem I", true, "Shop", &mut fetch),
+            (17, vec!["ItemId".to_string()])
+        );
+        assert_eq!(
+            Repl::candidates(&words("select OrderItem"), true, "Shop", &mut fetch),
+            vec!["ItemId", "itemName"]
+        );
+        assert_eq!(
+            Repl::candidates(&words("USE Database"), false, "", &mut fetch),
+            vec!["sys", "test"]
+        );
+    }
+}
@@ -0,0 +1,143 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use george_comm::errors::{Errs, GeorgeResult};
+use george_rpc::tools::Trim;
+
+use crate::cmd::{Client, Script};
+
+impl Script {
+    /// 将脚本内容拆分为命令
+    ///
+    /// 命令以引号外的`;`分隔，引号外词首的`--`至行尾为注释，末尾未以`;`结尾的内容同样视为一条命令；
+    /// 引号外的连续空白及换行合并为一个空格，引号内的内容原样保留
+    ///
+    /// #return
+    ///
+    /// * Vec<(usize, String)> 命令起始行号及去除结尾`;`的命令
+    pub(crate) fn split(text: &str) -> Vec<(usize, String)> {
+        let mut statements: Vec<(usize, String)> = vec![];
+        let mut statement = String::new();
+        let mut start = 0;
+        let mut line = 1;
+        let mut quote: Option<char> = None;
+        let mut comment = false;
+        let mut chars = text.chars().peekable();
+        while let Some(c) = chars.next() {
+            if c == '\n' {
+                line += 1;
+                comment = false;
+            }
+            if comment {
+                continue;
+            }
+            match quote {
+                Some(q) => {
+                    if c == q {
+                        quote = None;
+                    }
+                }
+                None => match c {
+                    '\'' | '"' => quote = Some(c),
+                    // 仅在词首出现的`--`为注释，如`a--b`中的`--`属于该词
+                    '-' if chars.peek() == Some(&'-')
+                        && statement.chars().last().map_or(true, char::is_whitespace) =>
+                    {
+                        comment = true;
+                        continue;
+                    }
+                    ';' => {
+                        push(&mut statements, start, &statement);
+                        statement.clear();
+                        continue;
+                    }
+                    _ => {}
+                },
+            }
+            if statement.trim().is_empty() && !c.is_whitespace() {
+                start = line;
+            }
+            statement.push(c);
+        }
+        push(&mut statements, start, &statement);
+        statements
+    }
+
+    /// 依次执行命令，返回时断开连接
+    ///
+    /// ###Params
+    ///
+    /// * statements 命令起始行号及命令
+    /// * force 失败后是否继续执行后续命令，为false时首个失败即停止
+    pub(crate) fn run(
+        mut client: Client,
+        statements: Vec<(usize, String)>,
+        force: bool,
+    ) -> GeorgeResult<()> {
+        let mut disk = false;
+        let mut used = String::from("");
+        let mut failed = 0;
+        for (line, statement) in statements {
+            match client.exec(&mut disk, &mut used, Trim::str(statement.clone())) {
+                Ok(true) => {}
+                Ok(false) => break,
+                Err(err) => {
+                    eprintln!("line {}: exec \"{}\" error: {}", line, statement, err);
+                    if !force {
+                        return Err(Errs::string(format!("script stopped at line {}!", line)));
+                    }
+                    failed += 1;
+                }
+            }
+        }
+        if failed > 0 {
+            Err(Errs::string(format!("{} commands failed!", failed)))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// 合并引号外的连续空白及换行、去除首尾空白后记录非空命令，引号内的内容原样保留
+fn push(statements: &mut Vec<(usize, String)>, start: usize, statement: &str) {
+    let mut res = String::new();
+    let mut quote: Option<char> = None;
+    let mut space = false;
+    for c in statement.trim().chars() {
+        match quote {
+            Some(q) => {
+                if c == q {
+                    quote = None;
+                }
+            }
+            None => {
+                if c.is_whitespace() {
+                    space = true;
+                    continue;
+                }
+                if c == '\'' || c == '"' {
+                    quote = Some(c);
+                }
+            }
+        }
+        if space {
+            res.push(' ');
+            space = false;
+        }
+        res.push(c);
+    }
+    if !res.is_empty() {
+        statements.push((start, res));
+    }
+}
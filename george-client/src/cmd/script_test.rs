@@ -0,0 +1,68 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[cfg(test)]
+mod script {
+    use crate::cmd::Script;
+
+    #[test]
+    fn split_test() {
+        let text = "-- 初始化\nuse database sys;\nshow views; -- 视图列表\n\ninspect view\n  user;\nshow databases";
+        assert_eq!(
+            Script::split(text),
+            vec![
+                (2, "use database sys".to_string()),
+                (3, "show views".to_string()),
+                (5, "inspect view user".to_string()),
+                (7, "show databases".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn split_quote_test() {
+        let text = "put v1 k1 'a;b -- c'; -- ;\nput v1 k2 \"d\";;";
+        assert_eq!(
+            Script::split(text),
+            vec![
+                (1, "put v1 k1 'a;b -- c'".to_string()),
+                (2, "put v1 k2 \"d\"".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn split_quote_space_test() {
+        let text = "put  v1\n  k1 'a  b\n  c' ;insert block \"x\ty\"";
+        assert_eq!(
+            Script::split(text),
+            vec![
+                (1, "put v1 k1 'a  b\n  c'".to_string()),
+                (3, "insert block \"x\ty\"".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn split_comment_token_test() {
+        let text = "put v1 a--b c; --注释\nput v1 k2 v2--x -- 注释\n--;\nshow views";
+        assert_eq!(
+            Script::split(text),
+            vec![
+                (1, "put v1 a--b c".to_string()),
+                (2, "put v1 k2 v2--x show views".to_string()),
+            ]
+        );
+    }
+}
@@ -12,14 +12,12 @@
  * limitations under the License.
  */
 
-use cli_table::format::Justify;
-use cli_table::{Cell, Style, Table};
+use serde_json::json;
 
 use george_comm::errors::{Errs, GeorgeResult};
-use george_comm::strings::StringHandler;
-use george_comm::Strings;
+use george_comm::Sql;
 
-use crate::cmd::{george_error, print_table, Client, Select};
+use crate::cmd::{document, george_error, Client, Select, Sheet};
 
 impl Select {
     pub(crate) fn analysis(
@@ -29,43 +27,31 @@ impl Select {
         vss: Vec<String>,
     ) -> GeorgeResult<()> {
         // select [view:string] [constraint:string]
+        // select * from [view:string] [where ...] [order by ...] [limit n] [offset n]
         if used.is_empty() {
             return Err(Errs::str(
                 "database name not defined, please use `use [database/page/ledger] [database]` first!",
             ));
         }
-        if vss.len() != 3 {
+        let (view_name, constraint_json_bytes) = if vss.len() > 1 && vss[1].eq("*") {
+            let sql = Sql::parse(&scan)?;
+            (sql.view.clone(), sql.constraint_bytes())
+        } else if vss.len() == 3 {
+            (vss[1].clone(), vss[2].as_bytes().to_vec())
+        } else {
             return Err(george_error(scan));
-        }
-        let view_name = vss[1].clone();
-        let constraint_json_bytes = vss[2].as_bytes().to_vec();
+        };
         let selected = client.disk.select(used, view_name, constraint_json_bytes)?;
-        let mut table = vec![];
+        let mut sheet = Sheet::new(vec!["Value"]);
         for v8s in selected.values.to_vec() {
-            table.push(vec![Strings::from_utf8(v8s)?
-                .cell()
-                .justify(Justify::Right)])
+            sheet.push(json!([document(v8s)?]));
         }
-        print_table(
-            table
-                .table()
-                .title(vec!["Value".cell().bold(true)])
-                .bold(true),
-        )?;
-        let table = vec![vec![
-            selected.total.cell(),
-            selected.count.cell(),
-            selected.index_name.cell(),
-            selected.asc.cell().justify(Justify::Right),
-        ]]
-        .table()
-        .title(vec![
-            "Total".cell().bold(true),
-            "Count".cell().bold(true),
-            "Index Name".cell().bold(true),
-            "Asc".cell().bold(true),
-        ])
-        .bold(true);
-        print_table(table)
+        sheet.expect(
+            selected.total,
+            selected.count,
+            selected.index_name,
+            selected.asc,
+        );
+        client.print(sheet)
     }
 }
@@ -0,0 +1,234 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::slice;
+
+use cli_table::format::Justify;
+use cli_table::{print_stdout, Cell, CellStruct, Style, Table};
+use serde_json::{json, Map, Value};
+
+use george_comm::errors::{Errs, GeorgeResult};
+
+use crate::cmd::{Format, Sheet};
+
+/// 条件检索结果元数据的列名
+const EXPECTATION_TITLE: [&str; 4] = ["Total", "Count", "Index Name", "Asc"];
+
+impl Format {
+    /// 根据名称获取输出格式，可选`table`、`json`、`jsonl`、`csv`及`raw`
+    pub(crate) fn parse(name: &str) -> GeorgeResult<Format> {
+        match name.to_ascii_lowercase().as_str() {
+            "table" => Ok(Format::Table),
+            "json" => Ok(Format::Json),
+            "jsonl" => Ok(Format::Jsonl),
+            "csv" => Ok(Format::Csv),
+            "raw" => Ok(Format::Raw),
+            _ => Err(Errs::string(format!(
+                "output format {} is not supported, expect table/json/jsonl/csv/raw!",
+                name
+            ))),
+        }
+    }
+
+    pub(crate) fn name(&self) -> &'static str {
+        match self {
+            Format::Table => "table",
+            Format::Json => "json",
+            Format::Jsonl => "jsonl",
+            Format::Csv => "csv",
+            Format::Raw => "raw",
+        }
+    }
+}
+
+impl Sheet {
+    pub(crate) fn new(title: Vec<&'static str>) -> Sheet {
+        Sheet {
+            title,
+            rows: vec![],
+            expectation: None,
+        }
+    }
+
+    /// 新增一行，`row`为与列名一一对应的`json`数组
+    pub(crate) fn push(&mut self, row: Value) {
+        match row {
+            Value::Array(cells) => self.rows.push(cells),
+            cell => self.rows.push(vec![cell]),
+        }
+    }
+
+    /// 记录条件检索结果元数据
+    pub(crate) fn expect(&mut self, total: u64, count: u64, index_name: String, asc: bool) {
+        self.expectation = Some(vec![
+            json!(total),
+            json!(count),
+            json!(index_name),
+            json!(asc),
+        ]);
+    }
+
+    /// 按指定格式打印
+    ///
+    /// `json`格式在存在条件检索结果元数据时输出包含元数据及`rows`的对象，否则输出各行对象组成的数组；
+    /// `jsonl`、`csv`及`raw`格式仅输出各行，仅有元数据时将元数据作为一行输出
+    pub(crate) fn print(&self, format: Format) -> GeorgeResult<()> {
+        match format {
+            Format::Table => {
+                if !self.title.is_empty() {
+                    table(&self.title, &self.rows)?;
+                }
+                if let Some(expectation) = &self.expectation {
+                    table(&EXPECTATION_TITLE, slice::from_ref(expectation))?;
+                }
+                Ok(())
+            }
+            Format::Json => {
+                let rows = Value::Array(
+                    self.rows
+                        .iter()
+                        .map(|row| object(&self.title, row))
+                        .collect(),
+                );
+                let value = match &self.expectation {
+                    Some(expectation) => {
+                        let mut value = object(&EXPECTATION_TITLE, expectation);
+                        if !self.title.is_empty() {
+                            if let Value::Object(map) = &mut value {
+                                map.insert("rows".to_string(), rows);
+                            }
+                        }
+                        value
+                    }
+                    None => rows,
+                };
+                match serde_json::to_string_pretty(&value) {
+                    Ok(res) => println!("{}", res),
+                    Err(err) => return Err(Errs::strs("json output", err)),
+                }
+                Ok(())
+            }
+            Format::Jsonl => {
+                let (title, rows) = self.lines();
+                for row in rows {
+                    println!("{}", object(title, row));
+                }
+                Ok(())
+            }
+            Format::Csv => {
+                let (title, rows) = self.lines();
+                println!(
+                    "{}",
+                    title
+                        .iter()
+                        .map(|name| csv(name))
+                        .collect::<Vec<String>>()
+                        .join(",")
+                );
+                for row in rows {
+                    println!(
+                        "{}",
+                        row.iter()
+                            .map(|cell| csv(&text(cell)))
+                            .collect::<Vec<String>>()
+                            .join(",")
+                    );
+                }
+                Ok(())
+            }
+            Format::Raw => {
+                let (_, rows) = self.lines();
+                for row in rows {
+                    println!(
+                        "{}",
+                        row.iter().map(text).collect::<Vec<String>>().join("\t")
+                    );
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// 逐行输出的列名及各行，仅有元数据时为元数据
+    fn lines(&self) -> (&[&'static str], &[Vec<Value>]) {
+        match &self.expectation {
+            Some(expectation) if self.title.is_empty() => {
+                (&EXPECTATION_TITLE[..], slice::from_ref(expectation))
+            }
+            _ => (&self.title[..], &self.rows[..]),
+        }
+    }
+}
+
+fn table(title: &[&'static str], rows: &[Vec<Value>]) -> GeorgeResult<()> {
+    let table = rows
+        .iter()
+        .map(|row| row.iter().map(cell).collect::<Vec<CellStruct>>())
+        .collect::<Vec<Vec<CellStruct>>>()
+        .table()
+        .title(
+            title
+                .iter()
+                .map(|name| name.cell().bold(true))
+                .collect::<Vec<CellStruct>>(),
+        )
+        .bold(true);
+    match print_stdout(table) {
+        Ok(()) => Ok(()),
+        Err(err) => Err(Errs::strs("print stdout", err)),
+    }
+}
+
+/// 表格单元格，数值右对齐
+fn cell(value: &Value) -> CellStruct {
+    match value {
+        Value::Number(_) => text(value).cell().justify(Justify::Right),
+        _ => text(value).cell(),
+    }
+}
+
+/// 单元格文本，字符串不带引号，对象及数组为紧凑的`json`
+fn text(value: &Value) -> String {
+    match value {
+        Value::String(res) => res.clone(),
+        Value::Null => "".to_string(),
+        _ => value.to_string(),
+    }
+}
+
+/// 以列名为键的行对象，列名转为小写并以`_`连接，如`Index Name`转为`index_name`
+fn object(title: &[&'static str], row: &[Value]) -> Value {
+    let mut map = Map::new();
+    for (name, value) in title.iter().zip(row.iter()) {
+        map.insert(key(name), value.clone());
+    }
+    Value::Object(map)
+}
+
+fn key(name: &str) -> String {
+    name.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<&str>>()
+        .join("_")
+}
+
+/// 含`,`、`"`或换行的字段以`"`包裹，字段中的`"`转义为`""`
+fn csv(field: &str) -> String {
+    if field.contains(&[',', '"', '\n', '\r'][..]) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
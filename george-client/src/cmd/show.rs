@@ -12,13 +12,12 @@
  * limitations under the License.
  */
 
-use cli_table::format::Justify;
-use cli_table::{Cell, Style, Table};
+use serde_json::json;
 
 use george_comm::errors::{Errs, GeorgeResult};
 use george_rpc::tools::Trans;
 
-use crate::cmd::{george_error, print_table, Client, Show};
+use crate::cmd::{george_error, Client, Sheet, Show};
 
 impl Show {
     pub(crate) fn analysis(
@@ -30,68 +29,60 @@ impl Show {
     ) -> GeorgeResult<()> {
         let len = vss.len();
         if len == 1 {
-            let table = vec![vec![used.cell(), disk.cell().justify(Justify::Right)]]
-                .table()
-                .title(vec!["Used".cell().bold(true), "Disk".cell().bold(true)])
-                .bold(true);
-            return print_table(table);
+            let mut sheet = Sheet::new(vec!["Used", "Disk", "Output"]);
+            sheet.push(json!([used, disk, client.format.name()]));
+            return client.print(sheet);
         }
-        let intent = vss[1].as_str();
-        match intent {
+        let intent = vss[1].to_ascii_lowercase();
+        match intent.as_str() {
             "databases" => {
                 // show databases;
                 let list = client.database.list()?;
-                let mut table = vec![];
+                let mut sheet = Sheet::new(vec!["Name", "Comment", "Create Time", "View Count"]);
                 for db in list.iter() {
-                    table.push(vec![
-                        db.name.clone().cell(),
-                        db.comment.clone().cell(),
-                        Trans::grpc_timestamp_2_string(db.create_time.as_ref().unwrap().seconds)
-                            .cell(),
-                        db.views.len().cell().justify(Justify::Right),
-                    ])
+                    sheet.push(json!([
+                        db.name,
+                        db.comment,
+                        Trans::grpc_timestamp_2_string(db.create_time.as_ref().unwrap().seconds),
+                        db.views.len(),
+                    ]));
                 }
-                print_table(
-                    table
-                        .table()
-                        .title(vec![
-                            "Name".cell().bold(true),
-                            "Comment".cell().bold(true),
-                            "Create Time".cell().bold(true),
-                            "View Count".cell().bold(true),
-                        ])
-                        .bold(true),
-                )
+                client.print(sheet)
             }
             "pages" => {
                 // show pages;
                 let list = client.page.list()?;
-                let mut table = vec![];
+                let mut sheet =
+                    Sheet::new(vec!["Name", "Comment", "Size", "Period", "Create Time"]);
                 for page in list.iter() {
-                    table.push(vec![
-                        page.name.clone().cell(),
-                        page.comment.clone().cell(),
-                        page.size.cell(),
-                        page.period.cell(),
-                        Trans::grpc_timestamp_2_string(page.create_time.as_ref().unwrap().seconds)
-                            .cell()
-                            .justify(Justify::Right),
-                    ])
+                    sheet.push(json!([
+                        page.name,
+                        page.comment,
+                        page.size,
+                        page.period,
+                        Trans::grpc_timestamp_2_string(page.create_time.as_ref().unwrap().seconds),
+                    ]));
                 }
-                print_table(
-                    table
-                        .table()
-                        .title(vec![
-                            "Name".cell().bold(true),
-                            "Comment".cell().bold(true),
-                            "Size".cell().bold(true),
-                            "Period".cell().bold(true),
-                            "Create Time".cell().bold(true),
-                        ])
-                        .bold(true),
-                )
+                client.print(sheet)
+            }
+            "ledgers" => {
+                // show ledgers;
+                let list = client.ledger.list()?;
+                let mut sheet =
+                    Sheet::new(vec!["Name", "Comment", "Height", "Hash", "Create Time"]);
+                for ledger in list.iter() {
+                    sheet.push(json!([
+                        ledger.name,
+                        ledger.comment,
+                        ledger.height,
+                        ledger.hash,
+                        Trans::grpc_timestamp_2_string(
+                            ledger.create_time.as_ref().unwrap().seconds
+                        ),
+                    ]));
+                }
+                client.print(sheet)
             }
-            "ledgers" => Err(Errs::str("no support ledgers now!")),
             "views" => {
                 // show views;
                 if used.is_empty() {
@@ -100,27 +91,16 @@ impl Show {
                     ));
                 }
                 let list = client.view.list(used)?;
-                let mut table = vec![];
+                let mut sheet = Sheet::new(vec!["Name", "Comment", "Create Time", "Index Count"]);
                 for view in list.iter() {
-                    table.push(vec![
-                        view.name.clone().cell(),
-                        view.comment.clone().cell(),
-                        Trans::grpc_timestamp_2_string(view.create_time.as_ref().unwrap().seconds)
-                            .cell(),
-                        view.indexes.len().cell().justify(Justify::Right),
-                    ])
+                    sheet.push(json!([
+                        view.name,
+                        view.comment,
+                        Trans::grpc_timestamp_2_string(view.create_time.as_ref().unwrap().seconds),
+                        view.indexes.len(),
+                    ]));
                 }
-                print_table(
-                    table
-                        .table()
-                        .title(vec![
-                            "Name".cell().bold(true),
-                            "Comment".cell().bold(true),
-                            "Create Time".cell().bold(true),
-                            "Index Count".cell().bold(true),
-                        ])
-                        .bold(true),
-                )
+                client.print(sheet)
             }
             "record" => {
                 // show record [view:string] [version:u16]
@@ -135,19 +115,13 @@ impl Show {
                 let name = vss[2].clone();
                 let version = vss[3].parse::<u32>().unwrap();
                 let record = client.view.record(used, name, version)?;
-                let table = vec![vec![
-                    record.filepath.cell(),
-                    Trans::grpc_timestamp_2_string(record.time.as_ref().unwrap().seconds).cell(),
-                    version.cell().justify(Justify::Right),
-                ]]
-                .table()
-                .title(vec![
-                    "Filepath".cell().bold(true),
-                    "Time".cell().bold(true),
-                    "Version".cell().bold(true),
-                ])
-                .bold(true);
-                print_table(table)
+                let mut sheet = Sheet::new(vec!["Filepath", "Time", "Version"]);
+                sheet.push(json!([
+                    record.filepath,
+                    Trans::grpc_timestamp_2_string(record.time.as_ref().unwrap().seconds),
+                    version,
+                ]));
+                client.print(sheet)
             }
             "records" => {
                 // show records [view:string]
@@ -161,25 +135,15 @@ impl Show {
                 }
                 let name = vss[2].clone();
                 let records = client.view.records(used, name)?;
-                let mut table = vec![];
+                let mut sheet = Sheet::new(vec!["Filepath", "Time", "Version"]);
                 for record in records {
-                    table.push(vec![
-                        record.filepath.cell(),
-                        Trans::grpc_timestamp_2_string(record.time.as_ref().unwrap().seconds)
-                            .cell(),
-                        record.version.cell().justify(Justify::Right),
-                    ])
+                    sheet.push(json!([
+                        record.filepath,
+                        Trans::grpc_timestamp_2_string(record.time.as_ref().unwrap().seconds),
+                        record.version,
+                    ]));
                 }
-                print_table(
-                    table
-                        .table()
-                        .title(vec![
-                            "Filepath".cell().bold(true),
-                            "Time".cell().bold(true),
-                            "Version".cell().bold(true),
-                        ])
-                        .bold(true),
-                )
+                client.print(sheet)
             }
             "indexes" => {
                 // show indexes from [view:string];
@@ -191,7 +155,7 @@ impl Show {
                         "database name not defined, please use `use [database/page/ledger] [database]` first!",
                     ));
                 }
-                if vss[2].ne("from") {
+                if !vss[2].eq_ignore_ascii_case("from") {
                     return Err(george_error(scan));
                 }
                 let view_name = vss[3].clone();
@@ -219,34 +183,189 @@ impl Show {
                     )));
                 }
                 let list = client.index.list(used, view_name)?;
-                let mut table = vec![];
+                let mut sheet = Sheet::new(vec![
+                    "Name",
+                    "Unique",
+                    "Primary",
+                    "Null",
+                    "Key Type",
+                    "Engine",
+                    "Create Time",
+                ]);
                 for index in list.iter() {
-                    table.push(vec![
-                        index.name.clone().cell(),
-                        index.unique.cell(),
-                        index.primary.cell(),
-                        index.null.cell(),
-                        Trans::i32_2_key_type_str(index.key_type)?.cell(),
-                        Trans::i32_2_engine_str(index.engine)?.cell(),
-                        Trans::grpc_timestamp_2_string(index.create_time.as_ref().unwrap().seconds)
-                            .cell()
-                            .justify(Justify::Right),
-                    ])
+                    sheet.push(json!([
+                        index.name,
+                        index.unique,
+                        index.primary,
+                        index.null,
+                        Trans::i32_2_key_type_str(index.key_type)?,
+                        Trans::i32_2_engine_str(index.engine)?,
+                        Trans::grpc_timestamp_2_string(index.create_time.as_ref().unwrap().seconds),
+                    ]));
+                }
+                client.print(sheet)
+            }
+            "audits" => {
+                // show audits;
+                // show audits [limit:u32];
+                // show audits by [principal:string];
+                // show audits by [principal:string] [limit:u32];
+                let mut principal = String::from("");
+                let mut limit = 20;
+                match len {
+                    2 => {}
+                    3 => match vss[2].parse::<u32>() {
+                        Ok(res) => limit = res,
+                        Err(err) => return Err(Errs::strs("limit parse", err)),
+                    },
+                    4 | 5 => {
+                        if !vss[2].eq_ignore_ascii_case("by") {
+                            return Err(george_error(scan));
+                        }
+                        principal = vss[3].clone();
+                        if len == 5 {
+                            match vss[4].parse::<u32>() {
+                                Ok(res) => limit = res,
+                                Err(err) => return Err(Errs::strs("limit parse", err)),
+                            }
+                        }
+                    }
+                    _ => return Err(george_error(scan)),
+                }
+                let records = client.audit.list(principal, "".to_string(), 0, 0, limit)?;
+                let mut sheet = Sheet::new(vec![
+                    "Seq",
+                    "Time",
+                    "Principal",
+                    "Remote",
+                    "Operation",
+                    "Target",
+                    "Success",
+                    "Error",
+                    "Latency(us)",
+                ]);
+                for record in records {
+                    sheet.push(json!([
+                        record.seq,
+                        Trans::grpc_timestamp_2_string(record.time.as_ref().unwrap().seconds),
+                        record.principal,
+                        record.remote,
+                        record.operation,
+                        record.target,
+                        record.success,
+                        record.msg_err,
+                        record.latency,
+                    ]));
+                }
+                client.print(sheet)
+            }
+            "quotas" => {
+                // show quotas;
+                // show quotas [scope:string];
+                let scope = match len {
+                    2 => "".to_string(),
+                    3 => vss[2].clone(),
+                    _ => return Err(george_error(scan)),
+                };
+                let policies = client.quota.list(scope)?;
+                let mut sheet = Sheet::new(vec![
+                    "Scope",
+                    "Name",
+                    "Rate",
+                    "Burst",
+                    "Max Bytes",
+                    "Max Keys",
+                ]);
+                for policy in policies {
+                    sheet.push(json!([
+                        policy.scope,
+                        policy.name,
+                        policy.rate,
+                        policy.burst,
+                        policy.max_bytes,
+                        policy.max_keys,
+                    ]));
+                }
+                client.print(sheet)
+            }
+            "audit" => {
+                // show audit;
+                if len != 2 {
+                    return Err(george_error(scan));
+                }
+                let count = client.audit.verify()?;
+                let mut sheet = Sheet::new(vec!["Verified", "Records"]);
+                sheet.push(json!([true, count]));
+                client.print(sheet)
+            }
+            "replication" => {
+                // show replication;
+                if len != 2 {
+                    return Err(george_error(scan));
+                }
+                let status = client.replication.status()?;
+                let contact = if status.contact_time > 0 {
+                    Trans::grpc_timestamp_2_string(status.contact_time / 1_000_000_000)
+                } else {
+                    "".to_string()
+                };
+                let mut sheet = Sheet::new(vec![
+                    "Role",
+                    "Primary",
+                    "Journal Seq",
+                    "Applied",
+                    "Primary Seq",
+                    "Lag",
+                    "Lag Millis",
+                    "Contact Time",
+                ]);
+                sheet.push(json!([
+                    status.role,
+                    status.primary,
+                    status.seq,
+                    status.applied,
+                    status.primary_seq,
+                    status.lag,
+                    status.lag_millis,
+                    contact,
+                ]));
+                client.print(sheet)
+            }
+            "cluster" => {
+                // show cluster;
+                if len != 2 {
+                    return Err(george_error(scan));
                 }
-                print_table(
-                    table
-                        .table()
-                        .title(vec![
-                            "Name".cell().bold(true),
-                            "Unique".cell().bold(true),
-                            "Primary".cell().bold(true),
-                            "Null".cell().bold(true),
-                            "Key Type".cell().bold(true),
-                            "Engine".cell().bold(true),
-                            "Create Time".cell().bold(true),
-                        ])
-                        .bold(true),
-                )
+                let status = client.cluster.status()?;
+                let members = status
+                    .members
+                    .iter()
+                    .map(|peer| format!("{}@{}", peer.id, peer.addr))
+                    .collect::<Vec<String>>()
+                    .join(", ");
+                let mut sheet = Sheet::new(vec![
+                    "Id",
+                    "Role",
+                    "Term",
+                    "Leader",
+                    "Leader Addr",
+                    "Last Index",
+                    "Commit",
+                    "Applied",
+                    "Members",
+                ]);
+                sheet.push(json!([
+                    status.id,
+                    status.role,
+                    status.term,
+                    status.leader,
+                    status.leader_addr,
+                    status.last_index,
+                    status.commit,
+                    status.applied,
+                    members,
+                ]));
+                client.print(sheet)
             }
             _ => Err(Errs::string(format!(
                 "command do not support prefix {} in {}",
@@ -12,16 +12,20 @@
  * limitations under the License.
  */
 
+use std::cmp::Ordering;
 use std::fs::read;
+use std::os::raw::c_int;
 use std::path::Path;
 
-use openssl::asn1::{Asn1Integer, Asn1Time};
+use foreign_types::{ForeignType, ForeignTypeRef};
+
+use openssl::asn1::{Asn1Integer, Asn1IntegerRef, Asn1Time, Asn1TimeRef};
 use openssl::bn::{BigNum, MsbOption};
 use openssl::error::ErrorStack;
 use openssl::hash::MessageDigest;
 use openssl::nid::Nid;
 use openssl::pkcs12::Pkcs12;
-use openssl::pkey::{PKey, Private, Public};
+use openssl::pkey::{PKey, PKeyRef, Private, Public};
 use openssl::stack::Stack;
 use openssl::x509::extension::{
     AuthorityKeyIdentifier, BasicConstraints, ExtendedKeyUsage, KeyUsage, SubjectAlternativeName,
@@ -29,9 +33,10 @@ use openssl::x509::extension::{
 };
 use openssl::x509::store::X509StoreBuilder;
 use openssl::x509::{
-    X509Extension, X509Name, X509NameBuilder, X509NameRef, X509Req, X509ReqBuilder,
-    X509StoreContext, X509VerifyResult, X509,
+    CrlStatus, X509Crl, X509Extension, X509Name, X509NameBuilder, X509NameRef, X509Ref, X509Req,
+    X509ReqBuilder, X509StoreContext, X509VerifyResult, X509,
 };
+use openssl_sys as ffi;
 
 use crate::cryptos::Cert;
 use crate::errors::Errs;
@@ -424,7 +429,7 @@ impl Cert {
         )
     }
 
-    /// 签发用户证书
+    /// 签发用户证书，扩展密钥用法仅包含服务器认证，双向TLS的客户端证书由`sign_client`签发
     ///
     /// * op_x509 根证书。待签发证书如果自签名则为None，否则不能为None
     /// * bits 以比特为单位的数字长度
@@ -454,55 +459,21 @@ impl Cert {
         san: Option<SAN>,
         message_digest: MessageDigest,
     ) -> GeorgeResult<Cert> {
-        let basic_constraints: X509Extension;
-        match BasicConstraints::new() // 基本约束
-            .critical() // 关键
-            .build()
-        {
-            Ok(ext) => basic_constraints = ext,
-            Err(err) => return Err(Errs::strs("BasicConstraints build", err)),
-        }
-        let key_usage: X509Extension;
-        match KeyUsage::new() // 密钥使用
-            .critical() // 关键
-            .non_repudiation()
-            .data_encipherment() // 数据加密
-            .key_encipherment() // 密钥加密
-            .digital_signature() // 数字签名
-            .build()
-        {
-            Ok(ext) => key_usage = ext,
-            Err(err) => return Err(Errs::strs("BasicConstraints build", err)),
-        }
-        let ext_key_usage: Option<X509Extension>;
-        match ExtendedKeyUsage::new() // 扩展的密钥使用
-            .server_auth() // 服务器认证
-            // .client_auth() // 客户端认证
-            .build()
-        {
-            Ok(ext) => ext_key_usage = Some(ext),
-            Err(err) => return Err(Errs::strs("BasicConstraints build", err)),
-        }
-        match generate_x509(
-            Some(x509),
+        sign_user(
+            x509,
+            bits,
+            msb_ca,
+            odd,
             sk,
             pk,
-            SerialNumber::new(bits, msb_ca, odd),
             subject_info,
             version,
             not_before_day,
             not_after_day,
-            Extensions {
-                basic_constraints,
-                key_usage,
-                ext_key_usage,
-            },
             san,
+            false,
             message_digest,
-        ) {
-            Ok(x509) => Ok(Cert { x509 }),
-            Err(err) => Err(Errs::strs("create_cert", err)),
-        }
+        )
     }
 
     /// 签发128位签名用户证书
@@ -713,6 +684,259 @@ impl Cert {
     }
 }
 
+/// sign client
+impl Cert {
+    /// 签发客户端证书，扩展密钥用法包含服务器认证及客户端认证，用于双向TLS
+    ///
+    /// * x509 签发者证书
+    /// * bits 以比特为单位的数字长度
+    /// * msb_ca 期望的最高位属性，是随机生成' BigNum '的最有效位的选项
+    /// * odd 如果' true '，则生成的数字为奇数
+    /// * sk 签发证书用的私钥
+    /// * pk 待签发证书的公钥
+    /// * subject_info 证书的主题信息，CN字段用于通用名称
+    /// * version 证书版本。版本是零索引的，也就是说，对应于X.509标准版本3的证书应该将“2”传递给该方法。
+    /// * not_before_day 证书上的有效期在指定天之后
+    /// * not_after_day 证书上的有效期在指定天之前
+    /// * san 主题备用名称扩展对象
+    /// * message_digest 生成签名时摘要算法，如：MessageDigest::sha256()
+    pub fn sign_client(
+        x509: X509,
+        bits: i32,
+        msb_ca: MsbOptionCA,
+        odd: bool,
+        sk: PKey<Private>,
+        pk: PKey<Public>,
+        subject_info: &X509NameRef,
+        version: i32,
+        not_before_day: u32,
+        not_after_day: u32,
+        san: Option<SAN>,
+        message_digest: MessageDigest,
+    ) -> GeorgeResult<Cert> {
+        sign_user(
+            x509,
+            bits,
+            msb_ca,
+            odd,
+            sk,
+            pk,
+            subject_info,
+            version,
+            not_before_day,
+            not_after_day,
+            san,
+            true,
+            message_digest,
+        )
+    }
+
+    /// 签发128位签名客户端证书
+    ///
+    /// * x509 签发者证书
+    /// * msb_ca 期望的最高位属性，是随机生成' BigNum '的最有效位的选项
+    /// * odd 如果' true '，则生成的数字为奇数
+    /// * sk 签发证书用的私钥
+    /// * pk 待签发证书的公钥
+    /// * subject_info 证书的主题信息，CN字段用于通用名称
+    /// * version 证书版本。版本是零索引的，也就是说，对应于X.509标准版本3的证书应该将“2”传递给该方法。
+    /// * not_before_day 证书上的有效期在指定天之后
+    /// * not_after_day 证书上的有效期在指定天之前
+    /// * san 主题备用名称扩展对象
+    /// * message_digest 生成签名时摘要算法，如：MessageDigest::sha256()
+    pub fn sign_client_128(
+        x509: X509,
+        msb_ca: MsbOptionCA,
+        odd: bool,
+        sk: PKey<Private>,
+        pk: PKey<Public>,
+        subject_info: &X509NameRef,
+        version: i32,
+        not_before_day: u32,
+        not_after_day: u32,
+        san: Option<SAN>,
+        message_digest: MessageDigest,
+    ) -> GeorgeResult<Cert> {
+        Cert::sign_client(
+            x509,
+            128,
+            msb_ca,
+            odd,
+            sk,
+            pk,
+            subject_info,
+            version,
+            not_before_day,
+            not_after_day,
+            san,
+            message_digest,
+        )
+    }
+
+    /// 签发256位签名客户端证书
+    ///
+    /// * x509 签发者证书
+    /// * msb_ca 期望的最高位属性，是随机生成' BigNum '的最有效位的选项
+    /// * odd 如果' true '，则生成的数字为奇数
+    /// * sk 签发证书用的私钥
+    /// * pk 待签发证书的公钥
+    /// * subject_info 证书的主题信息，CN字段用于通用名称
+    /// * version 证书版本。版本是零索引的，也就是说，对应于X.509标准版本3的证书应该将“2”传递给该方法。
+    /// * not_before_day 证书上的有效期在指定天之后
+    /// * not_after_day 证书上的有效期在指定天之前
+    /// * san 主题备用名称扩展对象
+    /// * message_digest 生成签名时摘要算法，如：MessageDigest::sha256()
+    pub fn sign_client_256(
+        x509: X509,
+        msb_ca: MsbOptionCA,
+        odd: bool,
+        sk: PKey<Private>,
+        pk: PKey<Public>,
+        subject_info: &X509NameRef,
+        version: i32,
+        not_before_day: u32,
+        not_after_day: u32,
+        san: Option<SAN>,
+        message_digest: MessageDigest,
+    ) -> GeorgeResult<Cert> {
+        Cert::sign_client(
+            x509,
+            256,
+            msb_ca,
+            odd,
+            sk,
+            pk,
+            subject_info,
+            version,
+            not_before_day,
+            not_after_day,
+            san,
+            message_digest,
+        )
+    }
+
+    /// 根据证书签名申请签发客户端证书，扩展密钥用法包含服务器认证及客户端认证，用于双向TLS
+    ///
+    /// * csr 证书签名申请
+    /// * x509 签发者证书
+    /// * bits 以比特为单位的数字长度
+    /// * msb_ca 期望的最高位属性，是随机生成' BigNum '的最有效位的选项
+    /// * odd 如果' true '，则生成的数字为奇数
+    /// * sk 签发证书用的私钥
+    /// * version 证书版本。版本是零索引的，也就是说，对应于X.509标准版本3的证书应该将“2”传递给该方法。
+    /// * not_before_day 证书上的有效期在指定天之后
+    /// * not_after_day 证书上的有效期在指定天之前
+    /// * san 主题备用名称扩展对象
+    /// * message_digest 生成签名时摘要算法，如：MessageDigest::sha256()
+    pub fn sign_client_by_csr(
+        csr: CSR,
+        x509: X509,
+        bits: i32,
+        msb_ca: MsbOptionCA,
+        odd: bool,
+        sk: PKey<Private>,
+        version: i32,
+        not_before_day: u32,
+        not_after_day: u32,
+        san: Option<SAN>,
+        message_digest: MessageDigest,
+    ) -> GeorgeResult<Cert> {
+        sign_user(
+            x509,
+            bits,
+            msb_ca,
+            odd,
+            sk,
+            csr.pk()?,
+            csr.x509_req.subject_name(),
+            version,
+            not_before_day,
+            not_after_day,
+            san,
+            true,
+            message_digest,
+        )
+    }
+
+    /// 根据证书签名申请签发128位签名客户端证书
+    ///
+    /// * csr 证书签名申请
+    /// * x509 签发者证书
+    /// * msb_ca 期望的最高位属性，是随机生成' BigNum '的最有效位的选项
+    /// * odd 如果' true '，则生成的数字为奇数
+    /// * sk 签发证书用的私钥
+    /// * version 证书版本。版本是零索引的，也就是说，对应于X.509标准版本3的证书应该将“2”传递给该方法。
+    /// * not_before_day 证书上的有效期在指定天之后
+    /// * not_after_day 证书上的有效期在指定天之前
+    /// * san 主题备用名称扩展对象
+    /// * message_digest 生成签名时摘要算法，如：MessageDigest::sha256()
+    pub fn sign_client_128_by_csr(
+        csr: CSR,
+        x509: X509,
+        msb_ca: MsbOptionCA,
+        odd: bool,
+        sk: PKey<Private>,
+        version: i32,
+        not_before_day: u32,
+        not_after_day: u32,
+        san: Option<SAN>,
+        message_digest: MessageDigest,
+    ) -> GeorgeResult<Cert> {
+        Cert::sign_client_by_csr(
+            csr,
+            x509,
+            128,
+            msb_ca,
+            odd,
+            sk,
+            version,
+            not_before_day,
+            not_after_day,
+            san,
+            message_digest,
+        )
+    }
+
+    /// 根据证书签名申请签发256位签名客户端证书
+    ///
+    /// * csr 证书签名申请
+    /// * x509 签发者证书
+    /// * msb_ca 期望的最高位属性，是随机生成' BigNum '的最有效位的选项
+    /// * odd 如果' true '，则生成的数字为奇数
+    /// * sk 签发证书用的私钥
+    /// * version 证书版本。版本是零索引的，也就是说，对应于X.509标准版本3的证书应该将“2”传递给该方法。
+    /// * not_before_day 证书上的有效期在指定天之后
+    /// * not_after_day 证书上的有效期在指定天之前
+    /// * san 主题备用名称扩展对象
+    /// * message_digest 生成签名时摘要算法，如：MessageDigest::sha256()
+    pub fn sign_client_256_by_csr(
+        csr: CSR,
+        x509: X509,
+        msb_ca: MsbOptionCA,
+        odd: bool,
+        sk: PKey<Private>,
+        version: i32,
+        not_before_day: u32,
+        not_after_day: u32,
+        san: Option<SAN>,
+        message_digest: MessageDigest,
+    ) -> GeorgeResult<Cert> {
+        Cert::sign_client_by_csr(
+            csr,
+            x509,
+            256,
+            msb_ca,
+            odd,
+            sk,
+            version,
+            not_before_day,
+            not_after_day,
+            san,
+            message_digest,
+        )
+    }
+}
+
 /// save
 impl Cert {
     pub fn save_pem<P: AsRef<Path>>(&self, filepath: P) -> GeorgeResult<()> {
@@ -833,6 +1057,51 @@ impl Cert {
         }
     }
 
+    /// 携带证书吊销列表的证书链有效性验证
+    ///
+    /// 在`verify_cert_chain`基础上，逐一检查待验证证书及证书链中的中间证书是否被其签发者吊销，
+    /// 证书吊销列表须由证书链中对应的签发者签名，签名不符或校验签名出错的吊销列表将返回错误，
+    /// 已超过下一次更新时间的吊销列表同样返回错误，除非`allow_stale`为true
+    ///
+    /// * pre_x509s 证书链
+    /// * crls 证书吊销列表集合
+    /// * x509 待验证证书
+    /// * allow_stale 是否接受已超过下一次更新时间的吊销列表
+    pub fn verify_cert_chain_with_crl(
+        pre_x509s: Vec<X509>,
+        crls: Vec<CRL>,
+        x509: X509,
+        allow_stale: bool,
+    ) -> GeorgeResult<bool> {
+        if !Cert::verify_cert_chain(pre_x509s.clone(), x509.clone())? {
+            return Ok(false);
+        }
+        let mut x509s = pre_x509s.clone();
+        x509s.push(x509);
+        for crl in crls.iter() {
+            if !allow_stale && crl.expired()? {
+                return Err(Errs::str("crl has passed its next update time!"));
+            }
+            let mut issuer = None;
+            for pre in pre_x509s.iter() {
+                if crl.verify(pre)? {
+                    issuer = Some(pre);
+                    break;
+                }
+            }
+            let issuer = match issuer {
+                Some(res) => res,
+                None => return Err(Errs::str("crl is not signed by any certificate in chain!")),
+            };
+            for x509 in x509s.iter() {
+                if issuer.issued(x509) == X509VerifyResult::OK && crl.revoked(x509) {
+                    return Ok(false);
+                }
+            }
+        }
+        Ok(true)
+    }
+
     pub fn save_chain_pem<P: AsRef<Path>>(filepath: P, x509s: Vec<X509>) -> GeorgeResult<()> {
         let mut stacks: Vec<u8> = vec![];
         for x509 in x509s {
@@ -986,6 +1255,362 @@ impl CSR {
     }
 }
 
+/// Certificate Revocation List的缩写，即证书吊销列表。
+///
+/// 由签发证书的根证书或中间证书签名，列出在有效期内被提前吊销的证书序列号，
+/// 验证证书时一旦发现其序列号出现在签发者的吊销列表中，则该证书不再被信任。
+pub struct CRL {
+    pub x509_crl: X509Crl,
+}
+
+/// sign
+impl CRL {
+    /// 签发证书吊销列表
+    ///
+    /// * sk 签发者私钥，须与`issuer`证书公钥匹配
+    /// * issuer 签发者证书，通常为根证书或中间证书
+    /// * x509s 待吊销证书集合，须由`issuer`签发
+    /// * next_update_day 下一次更新吊销列表的时间，即当前吊销列表在指定天之后过期
+    /// * message_digest 生成签名时摘要算法，如：MessageDigest::sha256()
+    pub fn sign(
+        sk: PKey<Private>,
+        issuer: Cert,
+        x509s: Vec<X509>,
+        next_update_day: u32,
+        message_digest: MessageDigest,
+    ) -> GeorgeResult<CRL> {
+        let mut serials: Vec<BigNum> = vec![];
+        for x509 in x509s {
+            match x509.serial_number().to_bn() {
+                Ok(serial) => serials.push(serial),
+                Err(err) => return Err(Errs::strs("serial_number to_bn", err)),
+            }
+        }
+        CRL::sign_serials(sk, issuer, serials, next_update_day, message_digest)
+    }
+
+    /// 根据证书序列号签发证书吊销列表
+    ///
+    /// 适用于待吊销证书文件已丢失，仅保留序列号的场景。
+    /// 如需在已有吊销列表基础上追加，可将`serials()`的结果与新序列号合并后重新签发
+    ///
+    /// * sk 签发者私钥，须与`issuer`证书公钥匹配
+    /// * issuer 签发者证书，通常为根证书或中间证书
+    /// * serials 待吊销证书序列号集合
+    /// * next_update_day 下一次更新吊销列表的时间，即当前吊销列表在指定天之后过期
+    /// * message_digest 生成签名时摘要算法，如：MessageDigest::sha256()
+    pub fn sign_serials(
+        sk: PKey<Private>,
+        issuer: Cert,
+        serials: Vec<BigNum>,
+        next_update_day: u32,
+        message_digest: MessageDigest,
+    ) -> GeorgeResult<CRL> {
+        if !issuer
+            .x509
+            .public_key()
+            .map_or(false, |pk| pk.public_eq(&sk))
+        {
+            return Err(Errs::str(
+                "crl sign key is not match with issuer certificate!",
+            ));
+        }
+        let last_update = CRL::asn1_time(0)?;
+        let next_update = CRL::asn1_time(next_update_day)?;
+        let mut builder = CrlBuilder::new()?;
+        builder.set_version_v2()?;
+        builder.set_issuer_name(issuer.x509.subject_name())?;
+        builder.set_last_update(&last_update)?;
+        builder.set_next_update(&next_update)?;
+        for serial in serials {
+            let serial = match serial.to_asn1_integer() {
+                Ok(res) => res,
+                Err(err) => return Err(Errs::strs("serial to_asn1_integer", err)),
+            };
+            builder.add_revoked(&serial, &last_update)?;
+        }
+        builder.sort()?;
+        builder.sign(&sk, message_digest)?;
+        Ok(CRL {
+            x509_crl: builder.build(),
+        })
+    }
+
+    fn asn1_time(day: u32) -> GeorgeResult<Asn1Time> {
+        match Asn1Time::days_from_now(day) {
+            Ok(res) => Ok(res),
+            Err(err) => Err(Errs::strs("Asn1Time days_from_now", err)),
+        }
+    }
+}
+
+/// check
+impl CRL {
+    /// 检查吊销列表是否由`issuer`签发
+    ///
+    /// 只检查签名，不检查吊销列表是否过期
+    pub fn verify(&self, issuer: &X509Ref) -> GeorgeResult<bool> {
+        let pk = match issuer.public_key() {
+            Ok(res) => res,
+            Err(err) => return Err(Errs::strs("x509 public_key", err)),
+        };
+        // 安全性：两指针均来自存活的托管对象，X509_CRL_verify不转移所有权
+        match unsafe { ffi::X509_CRL_verify(self.x509_crl.as_ptr(), pk.as_ptr()) } {
+            n if n > 0 => Ok(true),
+            0 => Ok(false),
+            _ => Err(Errs::strs("X509_CRL_verify", ErrorStack::get())),
+        }
+    }
+
+    /// 吊销列表是否已超过下一次更新时间
+    pub fn expired(&self) -> GeorgeResult<bool> {
+        match self.x509_crl.next_update() {
+            Some(next_update) => {
+                let now = CRL::asn1_time(0)?;
+                match now.compare(next_update) {
+                    Ok(ordering) => Ok(ordering == Ordering::Greater),
+                    Err(err) => Err(Errs::strs("Asn1Time compare", err)),
+                }
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// 证书是否已被吊销
+    ///
+    /// 同时比较签发者名称与序列号，因此非本吊销列表签发者所签发的证书总是返回false
+    pub fn revoked(&self, x509: &X509Ref) -> bool {
+        match self.x509_crl.get_by_cert(x509) {
+            CrlStatus::Revoked(_) => true,
+            _ => false,
+        }
+    }
+
+    /// 吊销列表中所有证书序列号
+    pub fn serials(&self) -> GeorgeResult<Vec<BigNum>> {
+        let mut serials: Vec<BigNum> = vec![];
+        if let Some(revoked_stack) = self.x509_crl.get_revoked() {
+            for revoked in revoked_stack {
+                match revoked.serial_number().to_bn() {
+                    Ok(serial) => serials.push(serial),
+                    Err(err) => return Err(Errs::strs("serial_number to_bn", err)),
+                }
+            }
+        }
+        Ok(serials)
+    }
+}
+
+/// save & load
+impl CRL {
+    pub fn save_pem<P: AsRef<Path>>(&self, filepath: P) -> GeorgeResult<()> {
+        match self.x509_crl.to_pem() {
+            Ok(v8s) => {
+                Filer::write_force(filepath, v8s)?;
+                Ok(())
+            }
+            Err(err) => Err(Errs::strs("x509_crl to_pem", err)),
+        }
+    }
+
+    pub fn save_der<P: AsRef<Path>>(&self, filepath: P) -> GeorgeResult<()> {
+        match self.x509_crl.to_der() {
+            Ok(v8s) => {
+                Filer::write_force(filepath, v8s)?;
+                Ok(())
+            }
+            Err(err) => Err(Errs::strs("x509_crl to_der", err)),
+        }
+    }
+
+    pub fn load_pem(bytes: Vec<u8>) -> GeorgeResult<CRL> {
+        match X509Crl::from_pem(bytes.as_slice()) {
+            Ok(x509_crl) => Ok(CRL { x509_crl }),
+            Err(err) => Err(Errs::strs("X509Crl from_pem", err)),
+        }
+    }
+
+    pub fn load_der(bytes: Vec<u8>) -> GeorgeResult<CRL> {
+        match X509Crl::from_der(bytes.as_slice()) {
+            Ok(x509_crl) => Ok(CRL { x509_crl }),
+            Err(err) => Err(Errs::strs("X509Crl from_der", err)),
+        }
+    }
+
+    pub fn load_pem_file<P: AsRef<Path>>(filepath: P) -> GeorgeResult<CRL> {
+        match read(filepath) {
+            Ok(bytes) => CRL::load_pem(bytes),
+            Err(err) => Err(Errs::strs("read", err)),
+        }
+    }
+
+    pub fn load_der_file<P: AsRef<Path>>(filepath: P) -> GeorgeResult<CRL> {
+        match read(filepath) {
+            Ok(bytes) => CRL::load_der(bytes),
+            Err(err) => Err(Errs::strs("read", err)),
+        }
+    }
+}
+
+/// 吊销列表签发过程的安全封装
+///
+/// 每个方法仅执行一次`ffi`调用并校验返回值，`X509_CRL`自创建起即由`X509Crl`托管，任一步骤出错时随之释放
+struct CrlBuilder {
+    x509_crl: X509Crl,
+}
+
+impl CrlBuilder {
+    fn new() -> GeorgeResult<CrlBuilder> {
+        // 安全性：X509_CRL_new返回新分配的对象或空指针，非空时所有权立即交由X509Crl
+        let crl = unsafe { ffi::X509_CRL_new() };
+        if crl.is_null() {
+            return Err(Errs::strs("X509_CRL_new", ErrorStack::get()));
+        }
+        Ok(CrlBuilder {
+            x509_crl: unsafe { X509Crl::from_ptr(crl) },
+        })
+    }
+
+    /// 版本是零索引的，1即为v2，v2才支持扩展
+    fn set_version_v2(&mut self) -> GeorgeResult<()> {
+        let res = unsafe { ffi::X509_CRL_set_version(self.x509_crl.as_ptr(), 1) };
+        CrlBuilder::check("X509_CRL_set_version", res)
+    }
+
+    fn set_issuer_name(&mut self, name: &X509NameRef) -> GeorgeResult<()> {
+        // 安全性：X509_CRL_set_issuer_name复制名称，不持有`name`
+        let res = unsafe { ffi::X509_CRL_set_issuer_name(self.x509_crl.as_ptr(), name.as_ptr()) };
+        CrlBuilder::check("X509_CRL_set_issuer_name", res)
+    }
+
+    fn set_last_update(&mut self, time: &Asn1TimeRef) -> GeorgeResult<()> {
+        // 安全性：set1系列函数复制参数，不持有`time`
+        let res = unsafe { ffi::X509_CRL_set1_lastUpdate(self.x509_crl.as_ptr(), time.as_ptr()) };
+        CrlBuilder::check("X509_CRL_set1_lastUpdate", res)
+    }
+
+    fn set_next_update(&mut self, time: &Asn1TimeRef) -> GeorgeResult<()> {
+        let res = unsafe { ffi::X509_CRL_set1_nextUpdate(self.x509_crl.as_ptr(), time.as_ptr()) };
+        CrlBuilder::check("X509_CRL_set1_nextUpdate", res)
+    }
+
+    /// 追加吊销条目，条目成功加入后所有权转交吊销列表，失败时在此释放
+    fn add_revoked(&mut self, serial: &Asn1IntegerRef, date: &Asn1TimeRef) -> GeorgeResult<()> {
+        unsafe {
+            let revoked = ffi::X509_REVOKED_new();
+            if revoked.is_null() {
+                return Err(Errs::strs("X509_REVOKED_new", ErrorStack::get()));
+            }
+            if ffi::X509_REVOKED_set_serialNumber(revoked, serial.as_ptr()) <= 0
+                || ffi::X509_REVOKED_set_revocationDate(revoked, date.as_ptr()) <= 0
+            {
+                ffi::X509_REVOKED_free(revoked);
+                return Err(Errs::strs("X509_REVOKED set", ErrorStack::get()));
+            }
+            if ffi::X509_CRL_add0_revoked(self.x509_crl.as_ptr(), revoked) <= 0 {
+                ffi::X509_REVOKED_free(revoked);
+                return Err(Errs::strs("X509_CRL_add0_revoked", ErrorStack::get()));
+            }
+        }
+        Ok(())
+    }
+
+    fn sort(&mut self) -> GeorgeResult<()> {
+        let res = unsafe { ffi::X509_CRL_sort(self.x509_crl.as_ptr()) };
+        CrlBuilder::check("X509_CRL_sort", res)
+    }
+
+    fn sign(&mut self, sk: &PKeyRef<Private>, message_digest: MessageDigest) -> GeorgeResult<()> {
+        let res = unsafe {
+            ffi::X509_CRL_sign(self.x509_crl.as_ptr(), sk.as_ptr(), message_digest.as_ptr())
+        };
+        CrlBuilder::check("X509_CRL_sign", res)
+    }
+
+    fn build(self) -> X509Crl {
+        self.x509_crl
+    }
+
+    fn check(name: &str, res: c_int) -> GeorgeResult<()> {
+        if res > 0 {
+            Ok(())
+        } else {
+            Err(Errs::strs(name, ErrorStack::get()))
+        }
+    }
+}
+
+/// 签发用户证书
+///
+/// * client_auth 扩展密钥用法是否包含客户端认证，为false时仅可作为服务端证书使用
+/// * 其余参数同`Cert::sign_user`
+fn sign_user(
+    x509: X509,
+    bits: i32,
+    msb_ca: MsbOptionCA,
+    odd: bool,
+    sk: PKey<Private>,
+    pk: PKey<Public>,
+    subject_info: &X509NameRef,
+    version: i32,
+    not_before_day: u32,
+    not_after_day: u32,
+    san: Option<SAN>,
+    client_auth: bool,
+    message_digest: MessageDigest,
+) -> GeorgeResult<Cert> {
+    let basic_constraints: X509Extension;
+    match BasicConstraints::new() // 基本约束
+        .critical() // 关键
+        .build()
+    {
+        Ok(ext) => basic_constraints = ext,
+        Err(err) => return Err(Errs::strs("BasicConstraints build", err)),
+    }
+    let key_usage: X509Extension;
+    match KeyUsage::new() // 密钥使用
+        .critical() // 关键
+        .non_repudiation()
+        .data_encipherment() // 数据加密
+        .key_encipherment() // 密钥加密
+        .digital_signature() // 数字签名
+        .build()
+    {
+        Ok(ext) => key_usage = ext,
+        Err(err) => return Err(Errs::strs("BasicConstraints build", err)),
+    }
+    let ext_key_usage: Option<X509Extension>;
+    let mut eku = ExtendedKeyUsage::new(); // 扩展的密钥使用
+    eku.server_auth(); // 服务器认证
+    if client_auth {
+        eku.client_auth(); // 客户端认证
+    }
+    match eku.build() {
+        Ok(ext) => ext_key_usage = Some(ext),
+        Err(err) => return Err(Errs::strs("BasicConstraints build", err)),
+    }
+    match generate_x509(
+        Some(x509),
+        sk,
+        pk,
+        SerialNumber::new(bits, msb_ca, odd),
+        subject_info,
+        version,
+        not_before_day,
+        not_after_day,
+        Extensions {
+            basic_constraints,
+            key_usage,
+            ext_key_usage,
+        },
+        san,
+        message_digest,
+    ) {
+        Ok(x509) => Ok(Cert { x509 }),
+        Err(err) => Err(Errs::strs("create_cert", err)),
+    }
+}
+
 /// 签发证书
 ///
 /// * op_x509 根证书。待签发证书如果自签名则为None，否则不能为None
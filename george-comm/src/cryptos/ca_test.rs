@@ -14,7 +14,7 @@
 
 #[cfg(test)]
 mod ca {
-    use crate::cryptos::ca::{MsbOptionCA, P12Handler, X509NameInfo, CSR, P12, SAN};
+    use crate::cryptos::ca::{MsbOptionCA, P12Handler, X509NameInfo, CRL, CSR, P12, SAN};
     use crate::cryptos::rsa::{RSANewStore, RSAStoreKey};
     use crate::cryptos::Cert;
     use crate::cryptos::ECDSA;
@@ -215,6 +215,212 @@ mod ca {
         Cert::verify_cert(root.x509.clone(), intermediate_cert.x509.clone()).unwrap();
     }
 
+    #[test]
+    fn crl_test() {
+        let rsa_root = RSA::new(2048).unwrap();
+        let subject_info = X509NameInfo::new_cus(
+            "CRLRoot".to_string(),
+            "CN".to_string(),
+            Some("org".to_string()),
+            Some("org unit".to_string()),
+            Some("loc".to_string()),
+            Some("pro".to_string()),
+            Some("sa".to_string()),
+        )
+        .unwrap();
+        let root = Cert::sign_root_256(
+            MsbOptionCA::MaybeZero,
+            true,
+            rsa_root.sk(),
+            rsa_root.pk(),
+            subject_info.as_ref(),
+            2,
+            0,
+            365,
+            None,
+            MessageDigest::sha384(),
+        )
+        .unwrap();
+
+        let ecdsa_intermediate = ECDSA::new().unwrap();
+        let subject_info = X509NameInfo::new_cus(
+            "CRLIntermediate".to_string(),
+            "CN".to_string(),
+            Some("org inter".to_string()),
+            Some("org unit inter".to_string()),
+            Some("loc inter".to_string()),
+            Some("pro inter".to_string()),
+            Some("sa inter".to_string()),
+        )
+        .unwrap();
+        let intermediate = Cert::sign_intermediate_128(
+            root.x509.clone(),
+            MsbOptionCA::MaybeZero,
+            true,
+            rsa_root.sk(),
+            ecdsa_intermediate.pk(),
+            subject_info.as_ref(),
+            2,
+            0,
+            364,
+            None,
+            MessageDigest::sha384(),
+        )
+        .unwrap();
+
+        let mut users: Vec<Cert> = vec![];
+        for name in vec!["CRLUser1", "CRLUser2"] {
+            let rsa_user = RSA::new(1024).unwrap();
+            let subject_info = X509NameInfo::new_cus(
+                name.to_string(),
+                "CN".to_string(),
+                Some("org user".to_string()),
+                Some("org unit user".to_string()),
+                Some("loc user".to_string()),
+                Some("pro user".to_string()),
+                Some("sa user".to_string()),
+            )
+            .unwrap();
+            let user = Cert::sign_user_128(
+                intermediate.x509.clone(),
+                MsbOptionCA::MaybeZero,
+                true,
+                ecdsa_intermediate.sk(),
+                rsa_user.pk(),
+                subject_info.as_ref(),
+                2,
+                0,
+                363,
+                None,
+                MessageDigest::sha256(),
+            )
+            .unwrap();
+            users.push(user);
+        }
+        let user1 = users.get(0).unwrap().x509.clone();
+        let user2 = users.get(1).unwrap().x509.clone();
+        let chain = vec![root.x509.clone(), intermediate.x509.clone()];
+
+        // 签名私钥与签发者证书不匹配
+        assert!(CRL::sign(
+            rsa_root.sk(),
+            Cert {
+                x509: intermediate.x509.clone()
+            },
+            vec![user1.clone()],
+            7,
+            MessageDigest::sha256(),
+        )
+        .is_err());
+
+        let crl = CRL::sign(
+            ecdsa_intermediate.sk(),
+            Cert {
+                x509: intermediate.x509.clone(),
+            },
+            vec![user1.clone()],
+            7,
+            MessageDigest::sha256(),
+        )
+        .unwrap();
+        crl.save_pem("src/test/crypto/ca/crl/intermediate.pem.crl")
+            .unwrap();
+        crl.save_der("src/test/crypto/ca/crl/intermediate.der.crl")
+            .unwrap();
+        let crl1 = CRL::load_pem_file("src/test/crypto/ca/crl/intermediate.pem.crl").unwrap();
+        let crl2 = CRL::load_der_file("src/test/crypto/ca/crl/intermediate.der.crl").unwrap();
+
+        assert!(crl1.verify(&intermediate.x509).unwrap());
+        assert!(!crl1.verify(&root.x509).unwrap());
+        assert!(!crl2.expired().unwrap());
+        assert!(crl2.revoked(&user1));
+        assert!(!crl2.revoked(&user2));
+        assert_eq!(1, crl2.serials().unwrap().len());
+
+        assert!(Cert::verify_cert_chain(chain.clone(), user1.clone()).unwrap());
+        assert!(
+            !Cert::verify_cert_chain_with_crl(chain.clone(), vec![crl1], user1.clone(), false)
+                .unwrap()
+        );
+        assert!(
+            Cert::verify_cert_chain_with_crl(chain.clone(), vec![crl2], user2.clone(), false)
+                .unwrap()
+        );
+
+        // 在已有吊销列表基础上追加吊销中间证书签发的另一张用户证书
+        let mut serials = crl.serials().unwrap();
+        serials.push(user2.serial_number().to_bn().unwrap());
+        let crl = CRL::sign_serials(
+            ecdsa_intermediate.sk(),
+            Cert {
+                x509: intermediate.x509.clone(),
+            },
+            serials,
+            7,
+            MessageDigest::sha256(),
+        )
+        .unwrap();
+        assert!(crl.revoked(&user1));
+        assert!(crl.revoked(&user2));
+
+        // 根证书吊销中间证书后，中间证书签发的证书均不再有效
+        let crl_root = CRL::sign(
+            rsa_root.sk(),
+            Cert {
+                x509: root.x509.clone(),
+            },
+            vec![intermediate.x509.clone()],
+            7,
+            MessageDigest::sha256(),
+        )
+        .unwrap();
+        assert!(!Cert::verify_cert_chain_with_crl(
+            chain.clone(),
+            vec![crl_root],
+            user2.clone(),
+            false
+        )
+        .unwrap());
+
+        // 吊销列表签发者不在证书链中
+        assert!(Cert::verify_cert_chain_with_crl(
+            vec![root.x509.clone()],
+            vec![crl],
+            intermediate.x509.clone(),
+            false
+        )
+        .is_err());
+
+        // 已超过下一次更新时间的吊销列表默认拒绝，显式允许时仍按其吊销条目校验
+        let crl_stale = CRL::sign(
+            ecdsa_intermediate.sk(),
+            Cert {
+                x509: intermediate.x509.clone(),
+            },
+            vec![user1.clone()],
+            0,
+            MessageDigest::sha256(),
+        )
+        .unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(1100));
+        assert!(crl_stale.expired().unwrap());
+        let crl_stale_der = crl_stale.x509_crl.to_der().unwrap();
+        assert!(Cert::verify_cert_chain_with_crl(
+            chain.clone(),
+            vec![crl_stale],
+            user2.clone(),
+            false
+        )
+        .is_err());
+        assert!(Cert::verify_cert_chain_with_crl(
+            chain.clone(),
+            vec![CRL::load_der(crl_stale_der).unwrap()],
+            user2.clone(),
+            true
+        )
+        .unwrap());
+    }
+
     #[test]
     fn csr_test() {
         let rsa_root = RSA::new(2048).unwrap();
@@ -800,7 +1006,7 @@ mod ca {
                 "src/test/crypto/ca/tls/client_pk.pub",
             )
             .unwrap();
-        let client_cert = Cert::sign_user_256(
+        let client_cert = Cert::sign_client_256(
             client_ca.x509.clone(),
             MsbOptionCA::One,
             true,
@@ -12,12 +12,15 @@
  * limitations under the License.
  */
 
-use crate::cryptos::hex::HexEncoder;
+use crate::cryptos::hex::{HexDecoder, HexEncoder};
 use crate::cryptos::{Hash, Hex};
 use crate::errors::{Errs, GeorgeResult};
 use crate::strings::StringHandler;
 use crate::Strings;
 use openssl::hash::{Hasher, MessageDigest};
+use openssl::memcmp;
+use openssl::pkey::PKey;
+use openssl::sign::Signer;
 use std::ops::Add;
 
 pub trait HashMD5Handler<T> {
@@ -34,6 +37,14 @@ pub trait HashMD5Handler<T> {
     fn sha256(comment: T) -> String;
 }
 
+pub trait HashHmacHandler {
+    /// 以`key`为密钥计算`comment`的HMAC-SHA256，返回hex字符串
+    fn hmac_sha256(key: &[u8], comment: &[u8]) -> GeorgeResult<String>;
+
+    /// 常量时间比较`comment`的HMAC-SHA256与期望的hex值
+    fn hmac_sha256_verify(key: &[u8], comment: &[u8], hmac: &str) -> GeorgeResult<bool>;
+}
+
 pub trait HashCRCHandler<T> {
     fn crc32(comment: T) -> u32;
 
@@ -69,6 +80,17 @@ pub trait HashCRCTypeHandler {
 }
 
 impl HashMD5Handler<&[u8]> for Hash {
+    fn hmac(md: MessageDigest, key: &[u8], comment: &[u8]) -> GeorgeResult<Vec<u8>> {
+        let pkey = PKey::hmac(key).map_err(|err| Errs::strs("hmac key", err))?;
+        let mut signer = Signer::new(md, &pkey).map_err(|err| Errs::strs("hmac signer", err))?;
+        signer
+            .update(comment)
+            .map_err(|err| Errs::strs("hmac update", err))?;
+        signer
+            .sign_to_vec()
+            .map_err(|err| Errs::strs("hmac sign", err))
+    }
+
     fn digest(md: MessageDigest, comment: &[u8]) -> GeorgeResult<String> {
         digest(md, comment)
     }
@@ -156,6 +178,21 @@ impl HashCRCHandler<&[u8]> for Hash {
     }
 }
 
+impl HashHmacHandler for Hash {
+    fn hmac_sha256(key: &[u8], comment: &[u8]) -> GeorgeResult<String> {
+        Ok(Hex::encode(hmac(MessageDigest::sha256(), key, comment)?))
+    }
+
+    fn hmac_sha256_verify(key: &[u8], comment: &[u8], hmac_hex: &str) -> GeorgeResult<bool> {
+        let expect = hmac(MessageDigest::sha256(), key, comment)?;
+        let actual = match Hex::decode(hmac_hex.to_string()) {
+            Ok(actual) => actual,
+            Err(_) => return Ok(false),
+        };
+        Ok(expect.len() == actual.len() && memcmp::eq(&expect, &actual))
+    }
+}
+
 impl HashCRCHandler<Vec<u8>> for Hash {
     fn crc32(comment: Vec<u8>) -> u32 {
         hashcode32(comment.as_slice())
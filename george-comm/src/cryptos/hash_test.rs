@@ -14,7 +14,9 @@
 
 #[cfg(test)]
 mod md5 {
-    use crate::cryptos::hash::{HashCRCHandler, HashCRCTypeHandler, HashMD5Handler};
+    use crate::cryptos::hash::{
+        HashCRCHandler, HashCRCTypeHandler, HashHmacHandler, HashMD5Handler,
+    };
     use crate::cryptos::Hash;
 
     #[test]
@@ -30,6 +32,30 @@ mod md5 {
         println!("test md516 2 = {}", md516_2);
     }
 
+    #[test]
+    fn hmac_sha256_test() {
+        // RFC 4231 test case 2
+        let hmac = Hash::hmac_sha256("Jefe".as_bytes(), "what do ya want for nothing?".as_bytes())
+            .unwrap();
+        assert_eq!(
+            hmac,
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+        assert!(Hash::hmac_sha256_verify(
+            "Jefe".as_bytes(),
+            "what do ya want for nothing?".as_bytes(),
+            hmac.as_str()
+        )
+        .unwrap());
+        assert!(!Hash::hmac_sha256_verify(
+            "Jeff".as_bytes(),
+            "what do ya want for nothing?".as_bytes(),
+            hmac.as_str()
+        )
+        .unwrap());
+        assert!(!Hash::hmac_sha256_verify("Jefe".as_bytes(), "x".as_bytes(), "zz").unwrap());
+    }
+
     #[test]
     fn sm3_test() {
         let str = "test".to_string();
@@ -0,0 +1,24 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::IndexDefine;
+
+/// 类型化文档，可由`#[derive(Document)]`派生
+pub trait Document: Serialize + DeserializeOwned {
+    /// 文档的索引定义集合
+    fn indexes() -> Vec<IndexDefine>;
+}
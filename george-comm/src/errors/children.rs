@@ -101,6 +101,17 @@ impl Display for ViewExistError {
 
 impl Error for ViewExistError {}
 
+#[derive(Debug, Clone)]
+pub struct LedgerExistError;
+
+impl Display for LedgerExistError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "ledger already exist!")
+    }
+}
+
+impl Error for LedgerExistError {}
+
 #[derive(Debug, Clone)]
 pub struct IndexExistError;
 
@@ -178,6 +189,17 @@ impl Display for ViewNoExistError {
 
 impl Error for ViewNoExistError {}
 
+#[derive(Debug, Clone)]
+pub struct LedgerNoExistError;
+
+impl Display for LedgerNoExistError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "ledger is not exist!")
+    }
+}
+
+impl Error for LedgerNoExistError {}
+
 #[derive(Debug, Clone)]
 pub struct IndexNoExistError;
 
@@ -210,3 +232,31 @@ impl Display for NoneError {
 }
 
 impl Error for NoneError {}
+
+/// 配额或速率限制超限，与其它错误区分，便于调用方退避重试
+#[derive(Debug, Clone)]
+pub struct QuotaExceededError {
+    pub(crate) error_msg: String,
+}
+
+impl Display for QuotaExceededError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "quota exceeded: {}", self.error_msg)
+    }
+}
+
+impl Error for QuotaExceededError {}
+
+/// 身份未认证或权限不足，如非管理员调用管理接口、无解密授权等
+#[derive(Debug, Clone)]
+pub struct PermissionDeniedError {
+    pub(crate) error_msg: String,
+}
+
+impl Display for PermissionDeniedError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "permission denied: {}", self.error_msg)
+    }
+}
+
+impl Error for PermissionDeniedError {}
@@ -18,7 +18,8 @@ use std::fmt::{Display, Formatter, Result};
 use crate::errors::children::{
     DataExistError, DataNoExistError, DatabaseExistError, DatabaseNoExistError, DirExistError,
     DirNoExistError, FileExistError, FileNoExistError, IndexExistError, IndexNoExistError,
-    MethodNoSupportError, NoneError, PageExistError, PageNoExistError, StringError, ViewExistError,
+    LedgerExistError, LedgerNoExistError, MethodNoSupportError, NoneError, PageExistError,
+    PageNoExistError, PermissionDeniedError, QuotaExceededError, StringError, ViewExistError,
     ViewNoExistError,
 };
 use crate::errors::{Errs, GeorgeError, GeorgeString, GeorgeStringErr};
@@ -34,15 +35,19 @@ impl Error for GeorgeError {
             GeorgeError::PageNoExistError(ref e) => Some(e),
             GeorgeError::DatabaseExistError(ref e) => Some(e),
             GeorgeError::ViewExistError(ref e) => Some(e),
+            GeorgeError::LedgerExistError(ref e) => Some(e),
             GeorgeError::IndexExistError(ref e) => Some(e),
             GeorgeError::DirNoExistError(ref e) => Some(e),
             GeorgeError::FileNoExistError(ref e) => Some(e),
             GeorgeError::DataNoExistError(ref e) => Some(e),
             GeorgeError::DatabaseNoExistError(ref e) => Some(e),
             GeorgeError::ViewNoExistError(ref e) => Some(e),
+            GeorgeError::LedgerNoExistError(ref e) => Some(e),
             GeorgeError::IndexNoExistError(ref e) => Some(e),
             GeorgeError::MethodNoSupportError(ref e) => Some(e),
             GeorgeError::NoneError(ref e) => Some(e),
+            GeorgeError::QuotaExceededError(ref e) => Some(e),
+            GeorgeError::PermissionDeniedError(ref e) => Some(e),
         }
     }
 }
@@ -58,15 +63,19 @@ impl Display for GeorgeError {
             GeorgeError::PageNoExistError(ref e) => e.fmt(f),
             GeorgeError::DatabaseExistError(ref e) => e.fmt(f),
             GeorgeError::ViewExistError(ref e) => e.fmt(f),
+            GeorgeError::LedgerExistError(ref e) => e.fmt(f),
             GeorgeError::IndexExistError(ref e) => e.fmt(f),
             GeorgeError::DirNoExistError(ref e) => e.fmt(f),
             GeorgeError::FileNoExistError(ref e) => e.fmt(f),
             GeorgeError::DataNoExistError(ref e) => e.fmt(f),
             GeorgeError::DatabaseNoExistError(ref e) => e.fmt(f),
             GeorgeError::ViewNoExistError(ref e) => e.fmt(f),
+            GeorgeError::LedgerNoExistError(ref e) => e.fmt(f),
             GeorgeError::IndexNoExistError(ref e) => e.fmt(f),
             GeorgeError::MethodNoSupportError(ref e) => e.fmt(f),
             GeorgeError::NoneError(ref e) => e.fmt(f),
+            GeorgeError::QuotaExceededError(ref e) => e.fmt(f),
+            GeorgeError::PermissionDeniedError(ref e) => e.fmt(f),
         }
     }
 }
@@ -131,6 +140,12 @@ impl From<ViewExistError> for GeorgeError {
     }
 }
 
+impl From<LedgerExistError> for GeorgeError {
+    fn from(s: LedgerExistError) -> Self {
+        GeorgeError::LedgerExistError(s)
+    }
+}
+
 impl From<IndexExistError> for GeorgeError {
     fn from(s: IndexExistError) -> Self {
         GeorgeError::IndexExistError(s)
@@ -155,6 +170,12 @@ impl From<ViewNoExistError> for GeorgeError {
     }
 }
 
+impl From<LedgerNoExistError> for GeorgeError {
+    fn from(s: LedgerNoExistError) -> Self {
+        GeorgeError::LedgerNoExistError(s)
+    }
+}
+
 impl From<IndexNoExistError> for GeorgeError {
     fn from(s: IndexNoExistError) -> Self {
         GeorgeError::IndexNoExistError(s)
@@ -173,6 +194,18 @@ impl From<MethodNoSupportError> for GeorgeError {
     }
 }
 
+impl From<QuotaExceededError> for GeorgeError {
+    fn from(s: QuotaExceededError) -> Self {
+        GeorgeError::QuotaExceededError(s)
+    }
+}
+
+impl From<PermissionDeniedError> for GeorgeError {
+    fn from(s: PermissionDeniedError) -> Self {
+        GeorgeError::PermissionDeniedError(s)
+    }
+}
+
 impl<T: ToString> GeorgeStringErr<String, T> for GeorgeError {
     fn string(msg: String, err: T) -> Self {
         err_strings(msg, err.to_string())
@@ -238,6 +271,10 @@ impl Errs {
         GeorgeError::from(ViewExistError)
     }
 
+    pub fn ledger_exist_error() -> GeorgeError {
+        GeorgeError::from(LedgerExistError)
+    }
+
     pub fn index_exist_error() -> GeorgeError {
         GeorgeError::from(IndexExistError)
     }
@@ -270,9 +307,80 @@ impl Errs {
         GeorgeError::from(ViewNoExistError)
     }
 
+    pub fn ledger_no_exist_error() -> GeorgeError {
+        GeorgeError::from(LedgerNoExistError)
+    }
+
     pub fn index_no_exist_error() -> GeorgeError {
         GeorgeError::from(IndexNoExistError)
     }
+
+    pub fn quota_exceeded_error(msg: String) -> GeorgeError {
+        GeorgeError::from(QuotaExceededError { error_msg: msg })
+    }
+
+    pub fn permission_denied_error(msg: String) -> GeorgeError {
+        GeorgeError::from(PermissionDeniedError { error_msg: msg })
+    }
+
+    /// 是否为读取的数据不存在，区别于读取过程中的其它错误
+    pub fn data_absent(err: &GeorgeError) -> bool {
+        matches!(
+            err,
+            GeorgeError::DataNoExistError(_) | GeorgeError::NoneError(_)
+        )
+    }
+
+    /// 是否为操作对象已存在或不存在的错误，重复执行已生效的变更时出现
+    pub fn state_conflict(err: &GeorgeError) -> bool {
+        matches!(
+            err,
+            GeorgeError::DirExistError(_)
+                | GeorgeError::FileExistError(_)
+                | GeorgeError::DataExistError(_)
+                | GeorgeError::PageExistError(_)
+                | GeorgeError::DatabaseExistError(_)
+                | GeorgeError::ViewExistError(_)
+                | GeorgeError::LedgerExistError(_)
+                | GeorgeError::IndexExistError(_)
+                | GeorgeError::DirNoExistError(_)
+                | GeorgeError::FileNoExistError(_)
+                | GeorgeError::DataNoExistError(_)
+                | GeorgeError::PageNoExistError(_)
+                | GeorgeError::DatabaseNoExistError(_)
+                | GeorgeError::ViewNoExistError(_)
+                | GeorgeError::LedgerNoExistError(_)
+                | GeorgeError::IndexNoExistError(_)
+                | GeorgeError::NoneError(_)
+        )
+    }
+
+    /// 错误类型名称，用于按错误类型统计失败请求等场景
+    pub fn kind(err: &GeorgeError) -> &'static str {
+        match err {
+            GeorgeError::StringError(_) => "string",
+            GeorgeError::DirExistError(_) => "dir_exist",
+            GeorgeError::FileExistError(_) => "file_exist",
+            GeorgeError::DataExistError(_) => "data_exist",
+            GeorgeError::PageExistError(_) => "page_exist",
+            GeorgeError::PageNoExistError(_) => "page_no_exist",
+            GeorgeError::DatabaseExistError(_) => "database_exist",
+            GeorgeError::ViewExistError(_) => "view_exist",
+            GeorgeError::LedgerExistError(_) => "ledger_exist",
+            GeorgeError::IndexExistError(_) => "index_exist",
+            GeorgeError::DirNoExistError(_) => "dir_no_exist",
+            GeorgeError::FileNoExistError(_) => "file_no_exist",
+            GeorgeError::DataNoExistError(_) => "data_no_exist",
+            GeorgeError::DatabaseNoExistError(_) => "database_no_exist",
+            GeorgeError::ViewNoExistError(_) => "view_no_exist",
+            GeorgeError::LedgerNoExistError(_) => "ledger_no_exist",
+            GeorgeError::IndexNoExistError(_) => "index_no_exist",
+            GeorgeError::MethodNoSupportError(_) => "method_no_support",
+            GeorgeError::NoneError(_) => "none",
+            GeorgeError::QuotaExceededError(_) => "quota_exceeded",
+            GeorgeError::PermissionDeniedError(_) => "permission_denied",
+        }
+    }
 }
 
 fn err_string(msg: String) -> GeorgeError {
@@ -15,7 +15,8 @@
 use crate::errors::children::{
     DataExistError, DataNoExistError, DatabaseExistError, DatabaseNoExistError, DirExistError,
     DirNoExistError, FileExistError, FileNoExistError, IndexExistError, IndexNoExistError,
-    MethodNoSupportError, NoneError, PageExistError, PageNoExistError, StringError, ViewExistError,
+    LedgerExistError, LedgerNoExistError, MethodNoSupportError, NoneError, PageExistError,
+    PageNoExistError, PermissionDeniedError, QuotaExceededError, StringError, ViewExistError,
     ViewNoExistError,
 };
 
@@ -45,15 +46,19 @@ pub enum GeorgeError {
     PageNoExistError(PageNoExistError),
     DatabaseExistError(DatabaseExistError),
     ViewExistError(ViewExistError),
+    LedgerExistError(LedgerExistError),
     IndexExistError(IndexExistError),
     DirNoExistError(DirNoExistError),
     FileNoExistError(FileNoExistError),
     DataNoExistError(DataNoExistError),
     DatabaseNoExistError(DatabaseNoExistError),
     ViewNoExistError(ViewNoExistError),
+    LedgerNoExistError(LedgerNoExistError),
     IndexNoExistError(IndexNoExistError),
     MethodNoSupportError(MethodNoSupportError),
     NoneError(NoneError),
+    QuotaExceededError(QuotaExceededError),
+    PermissionDeniedError(PermissionDeniedError),
 }
 
 pub struct Errs;
@@ -19,6 +19,7 @@ extern crate phf;
 use chrono::Duration;
 
 pub mod cryptos;
+pub mod document;
 pub mod env;
 mod env_test;
 pub mod errors;
@@ -28,6 +29,8 @@ mod json_test;
 pub mod merkle;
 pub mod openssl;
 pub mod pool;
+pub mod sql;
+mod sql_test;
 pub mod strings;
 mod strings_test;
 pub mod time;
@@ -39,6 +42,8 @@ mod vectors_test;
 pub mod yaml;
 mod yaml_test;
 
+pub use document::Document;
+
 pub struct Env;
 
 pub struct Strings;
@@ -60,6 +65,70 @@ pub struct JsonArray {
     value: serde_json::Value,
 }
 
+/// 类`SQL`语句
+///
+/// 由`Sql::parse`解析`SELECT * FROM view WHERE ...`及`DELETE FROM view WHERE ...`得到，
+/// 检索条件转换为`select`及`delete`接受的条件检索`json`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sql {
+    /// 语句类型
+    pub kind: SqlKind,
+    /// 视图名称
+    pub view: String,
+    /// 条件检索`json`，包括`Conditions`、`Sort`、`Limit`及`Skip`
+    pub constraint: serde_json::Value,
+}
+
+/// 类`SQL`语句类型
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SqlKind {
+    /// 条件检索
+    Select,
+    /// 条件删除
+    Delete,
+}
+
+/// 类型化文档的索引定义，通常由`#[derive(Document)]`依据字段上的`#[george(...)]`属性生成
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexDefine {
+    /// 索引名，即文档序列化为json后的字段名
+    pub name: String,
+    /// 存储引擎类型
+    pub engine: IndexEngine,
+    /// 索引值类型
+    pub key_type: IndexKeyType,
+    /// 是否主键
+    pub primary: bool,
+    /// 是否唯一索引
+    pub unique: bool,
+    /// 是否允许为空
+    pub null: bool,
+}
+
+/// 类型化文档索引可用的存储引擎类型
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IndexEngine {
+    /// 卷宗存储引擎(单文件索引存储-64位)，最合适用于不重复u64
+    Sequence,
+    /// 卷宗存储引擎(单文件索引存储-32位)
+    Disk,
+}
+
+/// 类型化文档索引可用的索引值类型
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IndexKeyType {
+    /// 字符串索引
+    String,
+    /// 无符号64位整型
+    UInt,
+    /// 有符号64位整型
+    Int,
+    /// 有符号64位浮点类型
+    Float,
+    /// bool类型
+    Bool,
+}
+
 pub struct Yaml {
     value: serde_yaml::Value,
 }
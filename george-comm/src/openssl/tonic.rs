@@ -25,12 +25,14 @@ use std::{
     io,
     pin::Pin,
     task::{Context, Poll},
+    time::Duration,
 };
 
 use async_stream::try_stream;
 use futures_util::{Stream, TryStream, TryStreamExt};
 use openssl::ssl::SslAcceptor;
 use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+use tokio::sync::mpsc;
 use tonic::transport::server::Connected;
 
 /// Wrapper error type.
@@ -40,6 +42,10 @@ pub type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
 /// value that can be passed directly to OpenSSL.
 pub const ALPN_H2_WIRE: &[u8] = b"\x02h2";
 
+/// Number of completed handshakes that may wait for the server
+/// to pick them up before new handshakes wait in turn.
+const HANDSHAKE_BACKLOG: usize = 128;
+
 /// Wrap some incoming stream of io types with OpenSSL's
 /// `SslStream` type. This will take some acceptor and a
 /// stream of io types and accept connections.
@@ -73,7 +79,7 @@ where
             match openssl::ssl::Ssl::new(acceptor.context()) {
                 Ok(ssl) => {
                     if ssl.verify_result() != openssl::x509::X509VerifyResult::OK {
-                        println!("ssl verify failed!")
+                        log::warn!("ssl verify failed!")
                     }
                     match super::tokio::SslStream::new(ssl, stream) {
                         Ok(mut tls) => {
@@ -85,10 +91,10 @@ where
 
                             yield ssl;
                         },
-                        Err(err) => println!("error is {}", err)
+                        Err(err) => log::error!("ssl stream new failed! error is {}", err)
                     }
                 },
-                Err(err) => println!("error is {}", err)
+                Err(err) => log::error!("ssl new failed! error is {}", err)
             }
         }
     }
@@ -129,6 +135,73 @@ where
     // }
 }
 
+/// Wrap some incoming stream of io types with OpenSSL's
+/// `SslStream` type. Unlike [`incoming`], the acceptor is taken
+/// from `acceptor` for every new connection, so it can be swapped
+/// (e.g. after a certificate rotation) while connections that were
+/// already accepted keep using the acceptor they were created with.
+///
+/// Every handshake runs in its own task and is abandoned after
+/// `handshake_timeout`, so a slow or stalled client can not block
+/// the accept loop. Completed handshakes are delivered in the order
+/// they finish.
+pub fn incoming_with<S, F>(
+    tcp_listener_stream: S,
+    acceptor: F,
+    handshake_timeout: Duration,
+) -> impl Stream<Item = Result<SslStream<S::Ok>, Error>>
+where
+    S: TryStream + Unpin + Send + 'static,
+    S::Ok: AsyncRead + AsyncWrite + Send + Sync + Debug + Unpin + 'static,
+    S::Error: Into<Error> + Send,
+    F: Fn() -> SslAcceptor + Send + 'static,
+{
+    let (tx, mut rx) = mpsc::channel(HANDSHAKE_BACKLOG);
+    tokio::spawn(async move {
+        let mut tcp_listener_stream = tcp_listener_stream;
+        loop {
+            let stream = match tcp_listener_stream.try_next().await {
+                Ok(Some(stream)) => stream,
+                Ok(None) => break,
+                Err(err) => {
+                    let _ = tx.send(Err(err.into())).await;
+                    break;
+                }
+            };
+            let acceptor = acceptor();
+            let tx = tx.clone();
+            tokio::spawn(async move {
+                let ssl = match openssl::ssl::Ssl::new(acceptor.context()) {
+                    Ok(ssl) => ssl,
+                    Err(err) => {
+                        log::error!("ssl new failed! error is {}", err);
+                        return;
+                    }
+                };
+                let mut tls = match super::tokio::SslStream::new(ssl, stream) {
+                    Ok(tls) => tls,
+                    Err(err) => {
+                        log::error!("ssl stream new failed! error is {}", err);
+                        return;
+                    }
+                };
+                match tokio::time::timeout(handshake_timeout, Pin::new(&mut tls).accept()).await {
+                    Ok(Ok(())) => {
+                        let _ = tx.send(Ok(SslStream { inner: tls })).await;
+                    }
+                    Ok(Err(err)) => log::warn!("ssl accept failed! error is {}", err),
+                    Err(_) => log::warn!("ssl accept timeout after {:?}!", handshake_timeout),
+                }
+            });
+        }
+    });
+    async_stream::stream! {
+        while let Some(res) = rx.recv().await {
+            yield res;
+        }
+    }
+}
+
 /// A `SslStream` wrapper type that implements tokio's io traits
 /// and tonic's `Connected` trait.
 #[derive(Debug)]
@@ -12,6 +12,7 @@
  * limitations under the License.
  */
 
+use std::sync::atomic::AtomicUsize;
 use std::sync::Arc;
 use tokio::runtime::Runtime;
 
@@ -21,4 +22,6 @@ mod thread_pool_test;
 #[derive(Debug, Clone)]
 pub struct ThreadPool {
     runtime: Arc<Runtime>,
+    /// 已提交但尚未结束的任务数
+    pending: Arc<AtomicUsize>,
 }
@@ -13,7 +13,10 @@
  */
 
 use std::future::Future;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
 
 use tokio::task::JoinHandle;
 
@@ -31,6 +34,7 @@ impl ThreadPool {
         match build.build() {
             Ok(runtime) => Ok(ThreadPool {
                 runtime: Arc::new(runtime),
+                pending: Arc::new(AtomicUsize::new(0)),
             }),
             Err(err) => Err(Errs::strs("runtime new", err)),
         }
@@ -43,7 +47,11 @@ impl ThreadPool {
         T: Future + Send + 'static,
         T::Output: Send + 'static,
     {
-        tokio::spawn(task)
+        let pending = self.pending();
+        tokio::spawn(async move {
+            let _pending = pending;
+            task.await
+        })
     }
 
     pub fn task_block_on<F: Future>(&self, future: F) -> F::Output {
@@ -55,7 +63,11 @@ impl ThreadPool {
         F: Future + Send + 'static,
         F::Output: Send + 'static,
     {
-        self.runtime.spawn(future)
+        let pending = self.pending();
+        self.runtime.spawn(async move {
+            let _pending = pending;
+            future.await
+        })
     }
 
     pub fn task_spawn_blocking<F, R>(&self, func: F) -> JoinHandle<R>
@@ -63,6 +75,48 @@ impl ThreadPool {
         F: FnOnce() -> R + Send + 'static,
         R: Send + 'static,
     {
-        self.runtime.spawn_blocking(func)
+        let pending = self.pending();
+        self.runtime.spawn_blocking(move || {
+            let _pending = pending;
+            func()
+        })
+    }
+
+    /// 已提交但尚未结束的任务数
+    pub fn pending_count(&self) -> usize {
+        self.pending.load(Ordering::SeqCst)
+    }
+
+    /// 等待已提交的任务全部结束，超时返回false
+    ///
+    /// 不可在线程池任务中调用，否则会因等待自身而超时
+    pub fn wait_idle(&self, timeout: Duration) -> bool {
+        let start = Instant::now();
+        while self.pending_count() > 0 {
+            if start.elapsed() > timeout {
+                return false;
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+        true
+    }
+
+    /// 登记一个新提交的任务，返回的对象在任务结束(包括异常结束)时释放登记
+    fn pending(&self) -> Pending {
+        self.pending.fetch_add(1, Ordering::SeqCst);
+        Pending {
+            count: self.pending.clone(),
+        }
+    }
+}
+
+/// 任务登记，释放时减少未结束的任务数
+struct Pending {
+    count: Arc<AtomicUsize>,
+}
+
+impl Drop for Pending {
+    fn drop(&mut self) {
+        self.count.fetch_sub(1, Ordering::SeqCst);
     }
 }
@@ -66,4 +66,23 @@ mod thread_pools {
             println!("return is {}", f);
         }
     }
+
+    #[cfg(test)]
+    mod thread_pool_2 {
+        use std::thread;
+        use std::time::Duration;
+
+        use crate::pool::ThreadPool;
+
+        #[test]
+        fn wait_idle() {
+            let thread_pool = ThreadPool::new(2).unwrap();
+            thread_pool.task_spawn_blocking(|| thread::sleep(Duration::from_millis(200)));
+            thread_pool.task_spawn(async {});
+            assert!(thread_pool.pending_count() > 0);
+            assert!(!thread_pool.wait_idle(Duration::from_millis(10)));
+            assert!(thread_pool.wait_idle(Duration::from_secs(5)));
+            assert_eq!(thread_pool.pending_count(), 0);
+        }
+    }
 }
@@ -0,0 +1,306 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use serde_json::{json, Map, Number, Value};
+
+use crate::errors::{Errs, GeorgeResult};
+use crate::{Sql, SqlKind};
+
+/// 词法单元
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    /// 关键字或标识符
+    Word(String),
+    /// 引号包裹的字符串
+    Str(String),
+    /// 数值
+    Num(Number),
+    /// 符号，包括`*`及比较运算符
+    Sym(&'static str),
+}
+
+impl Sql {
+    /// 解析类`SQL`语句，关键字不区分大小写，条件之间仅支持`AND`
+    ///
+    /// ```text
+    /// SELECT * FROM view [WHERE cond [AND cond]...] [ORDER BY param [ASC|DESC]] [LIMIT n] [OFFSET n]
+    /// DELETE FROM view [WHERE cond [AND cond]...] [ORDER BY param [ASC|DESC]] [LIMIT n] [OFFSET n]
+    /// ```
+    ///
+    /// cond为`param op value`，op可选`=`、`!=`、`<>`、`>`、`>=`、`<`及`<=`，
+    /// value可为数值、`true`/`false`或以单引号、双引号包裹的字符串
+    pub fn parse(sql: &str) -> GeorgeResult<Sql> {
+        Parser {
+            tokens: tokenize(sql)?,
+            pos: 0,
+        }
+        .statement()
+    }
+
+    /// 条件检索`json`字节数组
+    pub fn constraint_bytes(&self) -> Vec<u8> {
+        self.constraint.to_string().into_bytes()
+    }
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn statement(&mut self) -> GeorgeResult<Sql> {
+        let kind = match self.word()?.to_lowercase().as_str() {
+            "select" => {
+                self.expect_sym("*")?;
+                SqlKind::Select
+            }
+            "delete" => SqlKind::Delete,
+            other => {
+                return Err(Errs::string(format!(
+                    "sql statement {} is not supported, expect select or delete!",
+                    other
+                )))
+            }
+        };
+        self.expect_keyword("from")?;
+        let view = self.word()?;
+        let mut constraint = Map::new();
+        if self.keyword("where") {
+            let mut conditions = vec![self.condition()?];
+            while self.keyword("and") {
+                conditions.push(self.condition()?);
+            }
+            constraint.insert("Conditions".to_string(), Value::Array(conditions));
+        }
+        if self.keyword("order") {
+            self.expect_keyword("by")?;
+            let param = self.word()?;
+            let asc = if self.keyword("desc") {
+                false
+            } else {
+                self.keyword("asc");
+                true
+            };
+            constraint.insert("Sort".to_string(), json!({"Param": param, "Asc": asc}));
+        }
+        if self.keyword("limit") {
+            constraint.insert("Limit".to_string(), json!(self.unsigned()?));
+        }
+        if self.keyword("offset") {
+            constraint.insert("Skip".to_string(), json!(self.unsigned()?));
+        }
+        match self.next() {
+            None => Ok(Sql {
+                kind,
+                view,
+                constraint: Value::Object(constraint),
+            }),
+            Some(token) => Err(Errs::string(format!(
+                "sql unexpected {} after statement end!",
+                describe(&token)
+            ))),
+        }
+    }
+
+    /// 解析单个条件`param op value`
+    fn condition(&mut self) -> GeorgeResult<Value> {
+        let param = self.word()?;
+        let cond = match self.next() {
+            Some(Token::Sym("=")) => "eq",
+            Some(Token::Sym("!=")) => "ne",
+            Some(Token::Sym(">")) => "gt",
+            Some(Token::Sym(">=")) => "ge",
+            Some(Token::Sym("<")) => "lt",
+            Some(Token::Sym("<=")) => "le",
+            Some(token) => {
+                return Err(Errs::string(format!(
+                    "sql expect compare operator after {} but found {}!",
+                    param,
+                    describe(&token)
+                )))
+            }
+            None => {
+                return Err(Errs::string(format!(
+                    "sql expect compare operator after {}!",
+                    param
+                )))
+            }
+        };
+        let value = match self.next() {
+            Some(Token::Str(res)) => Value::String(res),
+            Some(Token::Num(res)) => Value::Number(res),
+            Some(Token::Word(res)) if res.eq_ignore_ascii_case("true") => Value::Bool(true),
+            Some(Token::Word(res)) if res.eq_ignore_ascii_case("false") => Value::Bool(false),
+            Some(token) => {
+                return Err(Errs::string(format!(
+                    "sql value of {} must be number, bool or quoted string but found {}!",
+                    param,
+                    describe(&token)
+                )))
+            }
+            None => return Err(Errs::string(format!("sql expect value of {}!", param))),
+        };
+        Ok(json!({"Param": param, "Cond": cond, "Value": value}))
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        if token.is_some() {
+            self.pos += 1;
+        }
+        token
+    }
+
+    /// 下一个词法单元为指定关键字时消费并返回true
+    fn keyword(&mut self, keyword: &str) -> bool {
+        match self.tokens.get(self.pos) {
+            Some(Token::Word(word)) if word.eq_ignore_ascii_case(keyword) => {
+                self.pos += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> GeorgeResult<()> {
+        if self.keyword(keyword) {
+            Ok(())
+        } else {
+            Err(Errs::string(format!(
+                "sql expect keyword {}!",
+                keyword.to_uppercase()
+            )))
+        }
+    }
+
+    fn expect_sym(&mut self, sym: &str) -> GeorgeResult<()> {
+        match self.next() {
+            Some(Token::Sym(res)) if res == sym => Ok(()),
+            _ => Err(Errs::string(format!("sql expect {}!", sym))),
+        }
+    }
+
+    /// 关键字或标识符
+    fn word(&mut self) -> GeorgeResult<String> {
+        match self.next() {
+            Some(Token::Word(res)) => Ok(res),
+            Some(token) => Err(Errs::string(format!(
+                "sql expect name but found {}!",
+                describe(&token)
+            ))),
+            None => Err(Errs::str("sql expect name but found end!")),
+        }
+    }
+
+    fn unsigned(&mut self) -> GeorgeResult<u64> {
+        match self.next() {
+            Some(Token::Num(res)) if res.is_u64() => Ok(res.as_u64().unwrap()),
+            _ => Err(Errs::str(
+                "sql limit and offset must be non-negative integer!",
+            )),
+        }
+    }
+}
+
+fn describe(token: &Token) -> String {
+    match token {
+        Token::Word(res) => res.clone(),
+        Token::Str(res) => format!("'{}'", res),
+        Token::Num(res) => res.to_string(),
+        Token::Sym(res) => res.to_string(),
+    }
+}
+
+/// 拆分词法单元，字符串中重复的引号表示引号本身，语句末尾的`;`被忽略
+fn tokenize(sql: &str) -> GeorgeResult<Vec<Token>> {
+    let mut tokens = vec![];
+    let chars: Vec<char> = sql.trim().trim_end_matches(';').chars().collect();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c.is_whitespace() {
+            i += 1;
+        } else if c == '\'' || c == '"' {
+            let mut res = String::new();
+            i += 1;
+            loop {
+                match chars.get(i) {
+                    Some(&q) if q == c => {
+                        if chars.get(i + 1) == Some(&c) {
+                            res.push(c);
+                            i += 2;
+                        } else {
+                            i += 1;
+                            break;
+                        }
+                    }
+                    Some(&ch) => {
+                        res.push(ch);
+                        i += 1;
+                    }
+                    None => return Err(Errs::str("sql string literal is not closed!")),
+                }
+            }
+            tokens.push(Token::Str(res));
+        } else if c.is_ascii_digit()
+            || (c == '-' && matches!(chars.get(i + 1), Some(n) if n.is_ascii_digit()))
+        {
+            let start = i;
+            i += 1;
+            while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                i += 1;
+            }
+            let text: String = chars[start..i].iter().collect();
+            tokens.push(Token::Num(number(&text)?));
+        } else if c.is_alphanumeric() || c == '_' {
+            let start = i;
+            while i < chars.len()
+                && (chars[i].is_alphanumeric() || chars[i] == '_' || chars[i] == '.')
+            {
+                i += 1;
+            }
+            tokens.push(Token::Word(chars[start..i].iter().collect()));
+        } else {
+            let next = chars.get(i + 1).copied();
+            let (sym, len) = match (c, next) {
+                ('>', Some('=')) => (">=", 2),
+                ('<', Some('=')) => ("<=", 2),
+                ('<', Some('>')) | ('!', Some('=')) => ("!=", 2),
+                ('=', Some('=')) => ("=", 2),
+                ('>', _) => (">", 1),
+                ('<', _) => ("<", 1),
+                ('=', _) => ("=", 1),
+                ('*', _) => ("*", 1),
+                _ => return Err(Errs::string(format!("sql unexpected character {}!", c))),
+            };
+            tokens.push(Token::Sym(sym));
+            i += len;
+        }
+    }
+    Ok(tokens)
+}
+
+fn number(text: &str) -> GeorgeResult<Number> {
+    if let Ok(res) = text.parse::<u64>() {
+        return Ok(Number::from(res));
+    }
+    if let Ok(res) = text.parse::<i64>() {
+        return Ok(Number::from(res));
+    }
+    match text.parse::<f64>().ok().and_then(Number::from_f64) {
+        Some(res) => Ok(res),
+        None => Err(Errs::string(format!("sql number {} is invalid!", text))),
+    }
+}
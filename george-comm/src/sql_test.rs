@@ -0,0 +1,82 @@
+/*
+ * Copyright (c) 2020. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[cfg(test)]
+mod sql {
+    use serde_json::json;
+
+    use crate::{Sql, SqlKind};
+
+    #[test]
+    fn select_test() {
+        let sql = Sql::parse(
+            "SELECT * FROM user WHERE age > 20 AND name = 'it''s' and vip = true ORDER BY age DESC LIMIT 10 OFFSET 5;",
+        )
+        .unwrap();
+        assert_eq!(sql.kind, SqlKind::Select);
+        assert_eq!(sql.view, "user");
+        assert_eq!(
+            sql.constraint,
+            json!({
+                "Conditions": [
+                    {"Param": "age", "Cond": "gt", "Value": 20},
+                    {"Param": "name", "Cond": "eq", "Value": "it's"},
+                    {"Param": "vip", "Cond": "eq", "Value": true}
+                ],
+                "Sort": {"Param": "age", "Asc": false},
+                "Limit": 10,
+                "Skip": 5
+            })
+        );
+    }
+
+    #[test]
+    fn select_operator_test() {
+        let sql =
+            Sql::parse("select * from v where a>=-1.5 and b<>\"x y\" and c<=3 and d<4").unwrap();
+        assert_eq!(
+            sql.constraint,
+            json!({
+                "Conditions": [
+                    {"Param": "a", "Cond": "ge", "Value": -1.5},
+                    {"Param": "b", "Cond": "ne", "Value": "x y"},
+                    {"Param": "c", "Cond": "le", "Value": 3},
+                    {"Param": "d", "Cond": "lt", "Value": 4}
+                ]
+            })
+        );
+        let sql = Sql::parse("select * from v order by a").unwrap();
+        assert_eq!(sql.constraint, json!({"Sort": {"Param": "a", "Asc": true}}));
+    }
+
+    #[test]
+    fn delete_test() {
+        let sql = Sql::parse("DELETE FROM user WHERE age <= 18").unwrap();
+        assert_eq!(sql.kind, SqlKind::Delete);
+        assert_eq!(sql.view, "user");
+        assert_eq!(
+            sql.constraint,
+            json!({"Conditions": [{"Param": "age", "Cond": "le", "Value": 18}]})
+        );
+    }
+
+    #[test]
+    fn error_test() {
+        assert!(Sql::parse("select name from user").is_err());
+        assert!(Sql::parse("select * from user where age > 1 or age < 0").is_err());
+        assert!(Sql::parse("select * from user where name = 'x").is_err());
+        assert!(Sql::parse("select * from user limit -1").is_err());
+        assert!(Sql::parse("update user").is_err());
+    }
+}
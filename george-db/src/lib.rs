@@ -35,17 +35,42 @@
 
 use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
+use std::time::Duration;
 
-use george_comm::errors::GeorgeResult;
+use once_cell::sync::OnceCell;
+
+use george_comm::errors::{Errs, GeorgeResult};
+use george_comm::io::file::FilerHandler;
+use george_comm::io::Filer;
 use george_comm::Time;
 use george_deploy::{Init, LogPolicy};
+use george_ge::utils::{Filed, Snapshot};
 
+use crate::task::audit::AuditRecord;
+use crate::task::backup::BackupManifest;
+use crate::task::batch::{BatchOp, BatchReport, BatchTarget};
 use crate::task::engine::traits::TIndex;
+use crate::task::journal::{JournalCursor, Mutation, MutationOp};
+use crate::task::ledger::{Block, Transaction};
+use crate::task::quota::{QuotaPolicy, QUOTA_SCOPE_DATABASE, QUOTA_SCOPE_PAGE, QUOTA_SCOPE_VIEW};
+use crate::task::raft::{RaftOptions, RaftPeer, RaftTransport};
+use crate::task::replication::ReplicationState;
 use crate::task::rich::Expectation;
+use crate::task::secret::TOKEN_TTL;
 use crate::task::traits::TMaster;
-use crate::task::{Database, Master, Page, View, GLOBAL_THREAD_POOL};
+use crate::task::transfer::{ImportReport, TransferFormat};
+use crate::task::{
+    Audit, Backup, Batch, Database, Journal, Ledger, Master, Page, Quota, Raft, Replication,
+    Secret, Transfer, View, GLOBAL_AUDIT, GLOBAL_JOURNAL, GLOBAL_METRICS, GLOBAL_QUOTA,
+    GLOBAL_REPLICATION, GLOBAL_SECRET, GLOBAL_THREAD_POOL,
+};
+use crate::utils::comm::INDEX_DISK;
 use crate::utils::deploy::GLOBAL_CONFIG;
-use crate::utils::enums::{Engine, KeyType};
+use crate::utils::enums::{Engine, Enum, EnumHandler, KeyType};
+use crate::utils::Paths;
+
+pub use george_comm::{Document, IndexDefine, IndexEngine, IndexKeyType};
+pub use george_derive::Document;
 
 mod example;
 pub mod task;
@@ -54,6 +79,22 @@ pub mod utils;
 #[derive(Debug, Clone)]
 pub struct Task {
     master: Master,
+    /// 服务主密钥
+    secret: Arc<Secret>,
+    /// 节点间认证密钥，未配置时拒绝所有节点间请求
+    peer: Option<Arc<Secret>>,
+    /// 审计日志
+    audit: Arc<Audit>,
+    /// 变更日志
+    journal: Arc<Journal>,
+    /// 租户配额
+    quota: Arc<Quota>,
+    /// 主从复制状态
+    replication: Arc<Replication>,
+    /// 集群共识，以集群模式启动后设置
+    cluster: Arc<OnceCell<Arc<Raft>>>,
+    /// 是否为从节点应用主节点变更或集群中应用已提交变更的实例，该实例直接写入
+    applier: bool,
 }
 
 impl Task {
@@ -63,8 +104,27 @@ impl Task {
         log::info!("config & log init success!");
         GLOBAL_THREAD_POOL.init();
         log::info!("thread pool init success!");
+        let secret = GLOBAL_SECRET
+            .get_or_try_init(|| Secret::recovery().map(Arc::new))?
+            .clone();
         Ok(Task {
             master: Master::generate()?,
+            audit: GLOBAL_AUDIT
+                .get_or_try_init(|| Audit::recovery(secret.clone()).map(Arc::new))?
+                .clone(),
+            secret,
+            peer: None,
+            journal: GLOBAL_JOURNAL
+                .get_or_try_init(|| Journal::recovery().map(Arc::new))?
+                .clone(),
+            quota: GLOBAL_QUOTA
+                .get_or_try_init(|| Quota::recovery().map(Arc::new))?
+                .clone(),
+            replication: GLOBAL_REPLICATION
+                .get_or_try_init(|| Replication::recovery().map(Arc::new))?
+                .clone(),
+            cluster: Arc::new(OnceCell::new()),
+            applier: false,
         })
     }
 
@@ -79,10 +139,698 @@ impl Task {
         log::info!("config & log init success!");
         GLOBAL_THREAD_POOL.init();
         log::info!("thread pool init success!");
+        let secret = GLOBAL_SECRET
+            .get_or_try_init(|| Secret::recovery().map(Arc::new))?
+            .clone();
         Ok(Task {
             master: Master::generate()?,
+            audit: GLOBAL_AUDIT
+                .get_or_try_init(|| Audit::recovery(secret.clone()).map(Arc::new))?
+                .clone(),
+            secret,
+            peer: match init.peer_key() {
+                Some(filepath) => Some(Arc::new(Secret::peer(filepath)?)),
+                None => None,
+            },
+            journal: GLOBAL_JOURNAL
+                .get_or_try_init(|| Journal::recovery().map(Arc::new))?
+                .clone(),
+            quota: GLOBAL_QUOTA
+                .get_or_try_init(|| Quota::recovery().map(Arc::new))?
+                .clone(),
+            replication: GLOBAL_REPLICATION
+                .get_or_try_init(|| Replication::recovery().map(Arc::new))?
+                .clone(),
+            cluster: Arc::new(OnceCell::new()),
+            applier: false,
         })
     }
+
+    /// 追加审计记录
+    ///
+    /// ###Params
+    ///
+    /// * principal 操作发起人
+    /// * remote 操作发起地址
+    /// * operation 操作名称
+    /// * target 操作对象
+    /// * success 操作是否成功
+    /// * msg_err 操作失败时的错误信息
+    /// * latency 操作耗时，单位微秒
+    pub fn audit_record(
+        &self,
+        principal: String,
+        remote: String,
+        operation: String,
+        target: String,
+        success: bool,
+        msg_err: String,
+        latency: u64,
+    ) -> GeorgeResult<()> {
+        self.audit.record(
+            principal, remote, operation, target, success, msg_err, latency,
+        )
+    }
+
+    /// 检索审计记录，按记录先后顺序返回最近的`limit`条
+    pub fn audit_records(
+        &self,
+        principal: String,
+        operation: String,
+        start: i64,
+        end: i64,
+        limit: u32,
+    ) -> GeorgeResult<Vec<AuditRecord>> {
+        self.audit.records(principal, operation, start, end, limit)
+    }
+
+    /// 校验审计链完整性，返回已校验的记录条数
+    pub fn audit_verify(&self) -> GeorgeResult<u64> {
+        self.audit.verify()
+    }
+
+    /// 为已通过密码校验的操作人签发登录令牌
+    pub fn token_issue(&self, principal: String) -> GeorgeResult<String> {
+        self.secret.token_issue(principal, TOKEN_TTL)
+    }
+
+    /// 校验登录令牌签名及有效期，通过则返回令牌所属操作人
+    pub fn token_verify(&self, token: &str) -> GeorgeResult<String> {
+        self.secret.token_verify(token)
+    }
+
+    /// 校验节点间认证凭证，未配置节点间认证密钥时拒绝
+    pub fn peer_verify(&self, credential: &str) -> GeorgeResult<()> {
+        match &self.peer {
+            Some(peer) => peer.peer_verify(credential),
+            None => Err(Errs::str(
+                "peer key is not configured, reject peer request!",
+            )),
+        }
+    }
+
+    /// 设置配额策略，已存在则覆盖，所有限制项都为0则移除策略
+    pub fn quota_set(&self, policy: QuotaPolicy) -> GeorgeResult<()> {
+        self.quota.set(policy)
+    }
+
+    /// 配额策略集合，`scope`为空则不限作用范围
+    pub fn quota_policies(&self, scope: String) -> Vec<QuotaPolicy> {
+        self.quota.policies(scope)
+    }
+
+    /// 为一次请求消耗操作人及库的令牌，任一令牌不足则返回配额超限错误
+    ///
+    /// ###Params
+    ///
+    /// * principal 操作发起人
+    /// * database_name 请求所操作的库，为空则仅校验操作人
+    pub fn quota_acquire(&self, principal: String, database_name: String) -> GeorgeResult<()> {
+        self.quota.acquire(principal, database_name)
+    }
+
+    /// 记录一次请求的运行指标
+    ///
+    /// ###Params
+    ///
+    /// * operation 操作名称，如`database.create`
+    /// * kind 请求失败时的错误类型，成功则为None
+    /// * latency 请求耗时
+    pub fn metrics_record(&self, operation: &str, kind: Option<String>, latency: Duration) {
+        GLOBAL_METRICS.rpc(operation, kind, latency)
+    }
+
+    /// 以`Prometheus`文本格式导出运行指标
+    pub fn metrics(&self) -> GeorgeResult<String> {
+        GLOBAL_METRICS.export(&self.master)
+    }
+
+    /// 按指定格式导出库中视图的定义及数据，视图名称为空则导出库中所有视图，返回导出行数
+    ///
+    /// ###Params
+    ///
+    /// * principal 读取人，加密字段按其授权解密或脱敏
+    /// * sink 导出行回调，每次回调一行，返回错误时终止导出
+    pub fn export(
+        &self,
+        database_name: String,
+        view_name: String,
+        format: TransferFormat,
+        principal: String,
+        sink: &mut dyn FnMut(Vec<u8>) -> GeorgeResult<()>,
+    ) -> GeorgeResult<u64> {
+        Transfer::export(self, database_name, view_name, format, principal, sink)
+    }
+
+    /// 按指定格式导入一批行，逐行将结果累加至`report`
+    ///
+    /// ###Params
+    ///
+    /// * view_name 导入的目标视图，为空则使用行中的视图名称
+    /// * overwrite 数据已存在时是否覆盖
+    /// * principal 导入人，每行消耗一次操作人及库的令牌
+    /// * rows 行号及行文本集合
+    pub fn import(
+        &self,
+        database_name: String,
+        view_name: String,
+        format: TransferFormat,
+        overwrite: bool,
+        principal: String,
+        rows: Vec<(u64, String)>,
+        report: &mut ImportReport,
+    ) {
+        Transfer::import(
+            self,
+            database_name.clone(),
+            view_name,
+            format,
+            overwrite,
+            rows,
+            report,
+            &mut || self.quota.acquire(principal.clone(), database_name.clone()),
+        )
+    }
+
+    /// 逐项写入一批数据，将结果累加至`report`，全部成功或全部失败时任一数据项失败即停止写入
+    ///
+    /// ###Params
+    ///
+    /// * target 写入目标
+    /// * op 写入方式
+    /// * atomic 是否全部成功或全部失败
+    /// * principal 写入人，每个数据项消耗一次操作人及库的令牌
+    /// * items 键及值集合，删除时忽略值
+    pub fn batch(
+        &self,
+        target: &BatchTarget,
+        op: BatchOp,
+        atomic: bool,
+        principal: String,
+        items: Vec<(String, Vec<u8>)>,
+        report: &mut BatchReport,
+    ) {
+        let database_name = target.database_name();
+        Batch::apply(self, target, op, atomic, items, report, &mut || {
+            self.quota.acquire(principal.clone(), database_name.clone())
+        })
+    }
+
+    /// 回滚全部成功或全部失败的批量写入中已写入的数据项
+    pub fn batch_rollback(
+        &self,
+        target: &BatchTarget,
+        report: &mut BatchReport,
+    ) -> GeorgeResult<()> {
+        Batch::rollback(self, target, report)
+    }
+
+    /// 在写入持续进行的同时备份所有缓存页、库、视图及索引
+    ///
+    /// ###Params
+    ///
+    /// * dir 备份目录，为配置的备份根路径下不含`..`的相对路径，须为空目录或不存在
+    /// * compress 是否以`gzip`压缩备份文件
+    pub fn backup(&self, dir: String, compress: bool) -> GeorgeResult<BackupManifest> {
+        self.snapshot(Backup::resolve(dir)?, compress)
+    }
+
+    /// 在写入持续进行的同时将所有缓存页、库、视图及索引一致地备份到指定目录，目录不受备份根路径限制，供复制快照等内部场景使用
+    ///
+    /// ###Params
+    ///
+    /// * dir 备份目录，须为空目录或不存在
+    /// * compress 是否以`gzip`压缩备份文件
+    pub fn snapshot(&self, dir: String, compress: bool) -> GeorgeResult<BackupManifest> {
+        // 先取变更日志序号再开启快照，快照前已提交的变更至多被重复重放，不会遗漏
+        let seq = self.journal.seq();
+        Backup::create(dir, compress, seq, &|| self.journal.seq())
+    }
+
+    /// 重放变更日志中序号大于`seq`且不晚于`until`的变更，返回重放成功及因已包含在备份中而跳过的变更数
+    ///
+    /// 序号不大于`end_seq`的变更可能已包含在备份中，仅当其因对象已存在或不存在而失败时跳过，其余失败及序号不连续均中断重放并返回错误
+    ///
+    /// ###Params
+    ///
+    /// * path 变更日志文件路径，或包含变更日志及其历史分段的目录，不能为当前实例正在使用的变更日志
+    /// * seq 备份时刻的变更日志序号
+    /// * end_seq 备份结束快照时的变更日志序号
+    /// * until 重放截止时间，单位秒，为0则重放全部
+    pub fn journal_replay(
+        &self,
+        path: String,
+        seq: u64,
+        end_seq: u64,
+        until: i64,
+    ) -> GeorgeResult<(u64, u64)> {
+        let files = Journal::files(path)?;
+        for filepath in files.iter() {
+            if self.journal.owns(filepath)? {
+                return Err(Errs::str(
+                    "journal to replay can not be the one in use, move it out of data dir first!",
+                ));
+            }
+        }
+        let (mut applied, mut skipped, mut next, mut stop) = (0, 0, seq + 1, false);
+        for filepath in files {
+            Journal::read(filepath, &mut |mutation| {
+                if stop || mutation.seq < next {
+                    return Ok(());
+                }
+                if mutation.seq > next {
+                    return Err(Errs::string(format!(
+                        "journal misses seq {} before seq {}!",
+                        next, mutation.seq
+                    )));
+                }
+                if until > 0 && mutation.time / 1_000_000_000 > until {
+                    stop = true;
+                    return Ok(());
+                }
+                next = mutation.seq + 1;
+                let mutation_seq = mutation.seq;
+                match mutation.apply(self) {
+                    Ok(()) => applied += 1,
+                    Err(err) if mutation_seq <= end_seq && Errs::state_conflict(&err) => {
+                        skipped += 1;
+                        log::warn!(
+                            "journal replay seq {} skipped as it may be in backup! error is {}",
+                            mutation_seq,
+                            err
+                        )
+                    }
+                    Err(err) => {
+                        return Err(Errs::string(format!(
+                            "journal replay seq {} failed! error is {}",
+                            mutation_seq, err
+                        )))
+                    }
+                }
+                Ok(())
+            })?;
+            if stop {
+                break;
+            }
+        }
+        Ok((applied, skipped))
+    }
+
+    /// 从序号`seq`之后读取变更日志的游标，供主节点向从节点推送变更
+    pub fn journal_cursor(&self, seq: u64) -> GeorgeResult<JournalCursor> {
+        self.journal.cursor(seq)
+    }
+
+    /// 等待序号大于`seq`的变更记录追加，返回最新变更记录序号
+    pub fn journal_wait(&self, seq: u64, timeout: Duration) -> u64 {
+        self.journal.wait(seq, timeout)
+    }
+
+    /// 复制状态及本地变更日志最新序号
+    pub fn replication(&self) -> (ReplicationState, u64) {
+        (self.replication.state(), self.journal.seq())
+    }
+
+    /// 作为从节点跟随指定主节点，返回是否跟随，曾被提升为主节点的实例不再跟随
+    ///
+    /// ###Params
+    ///
+    /// * primary 主节点地址，格式为`host:port`
+    pub fn replication_follow(&self, primary: String) -> GeorgeResult<bool> {
+        self.replication.follow(primary)
+    }
+
+    /// 从节点按序应用主节点推送的变更
+    ///
+    /// 已应用过的变更直接跳过。单条变更应用失败则停止应用并返回错误，已应用序号停留在失败变更之前，重连后从该变更重试；
+    /// 序号不大于复制状态中`pending`的变更可能已生效，仅当其因对象已存在或不存在而失败时跳过，与时间点恢复的重放一致
+    ///
+    /// ###Params
+    ///
+    /// * primary_seq 主节点最新变更日志序号
+    /// * mutations 主节点推送的变更，可为空，仅用于更新主节点最新变更日志序号
+    pub fn replicate(&self, primary_seq: u64, mutations: Vec<Mutation>) -> GeorgeResult<()> {
+        if !self.replication.replica() {
+            return Err(Errs::str("replication is not a replica, stop applying!"));
+        }
+        self.replication.contact(primary_seq);
+        let state = self.replication.state();
+        if let Some(last) = mutations.iter().map(|mutation| mutation.seq).max() {
+            self.replication.pend(last)?;
+        }
+        let applier = Task {
+            applier: true,
+            ..self.clone()
+        };
+        let (mut applied, mut applied_time) = (state.applied, state.applied_time);
+        let mut result = Ok(());
+        for mutation in mutations {
+            if mutation.seq <= applied {
+                continue;
+            }
+            if mutation.seq > applied + 1 {
+                result = Err(Errs::string(format!(
+                    "replication expect seq {} but receive {}, sync from snapshot again!",
+                    applied + 1,
+                    mutation.seq
+                )));
+                break;
+            }
+            let (seq, time) = (mutation.seq, mutation.time);
+            match mutation.apply(&applier) {
+                Ok(()) => {}
+                Err(err) if seq <= state.pending && Errs::state_conflict(&err) => log::warn!(
+                    "replication apply seq {} skipped as it may have taken effect! error is {}",
+                    seq,
+                    err
+                ),
+                Err(err) => {
+                    result = Err(Errs::string(format!(
+                        "replication apply seq {} failed, stop applying! error is {}",
+                        seq, err
+                    )));
+                    break;
+                }
+            }
+            applied = seq;
+            applied_time = time;
+        }
+        if applied != state.applied {
+            self.replication.apply(applied, applied_time)?;
+        }
+        result
+    }
+
+    /// 将从节点提升为主节点，提升后不再应用主节点变更并开始接受写入，返回已应用的主节点变更日志序号
+    pub fn promote(&self) -> GeorgeResult<u64> {
+        self.replication.promote()
+    }
+
+    /// 以集群模式启动，此后数据变更经共识日志复制到多数节点并提交后，由各节点按序应用
+    ///
+    /// ###Params
+    ///
+    /// * id 本节点编号，集群内唯一且不为0
+    /// * peers 集群初始节点集合，以新节点身份加入已运行的集群时为空
+    /// * options 共识参数
+    /// * transport 节点间通信
+    pub fn cluster_start(
+        &self,
+        id: u64,
+        peers: Vec<RaftPeer>,
+        options: RaftOptions,
+        transport: Arc<dyn RaftTransport>,
+    ) -> GeorgeResult<Arc<Raft>> {
+        if self.replication.replica() {
+            return Err(Errs::str("replica can not run in cluster mode!"));
+        }
+        let applier = Task {
+            applier: true,
+            ..self.clone()
+        };
+        let snapshotter = self.clone();
+        let raft = Raft::new(
+            id,
+            peers,
+            Paths::raft_path(),
+            options,
+            transport,
+            Box::new(move |op: MutationOp| op.execute(&applier)),
+            Box::new(move |dir: String| snapshotter.snapshot(dir, false).map(|_| ())),
+        )?;
+        if self.cluster.set(raft.clone()).is_err() {
+            return Err(Errs::str("cluster already started!"));
+        }
+        raft.start()?;
+        Ok(raft)
+    }
+
+    /// 集群共识，未以集群模式启动时为`None`
+    pub fn cluster(&self) -> Option<Arc<Raft>> {
+        self.cluster.get().cloned()
+    }
+
+    /// 停止服务前调用，等待线程池中尚未完成的索引任务，并将所有已打开的文件及审计日志同步到磁盘
+    ///
+    /// ###Params
+    ///
+    /// * timeout 等待索引任务完成的最长时间，超时后不再等待，直接同步文件
+    pub fn shutdown(&self, timeout: Duration) -> GeorgeResult<()> {
+        let pending = GLOBAL_THREAD_POOL.pending_count();
+        if pending > 0 {
+            log::info!("shutdown waiting for {} index tasks", pending);
+        }
+        if !GLOBAL_THREAD_POOL.wait_idle(timeout) {
+            log::warn!(
+                "shutdown timeout with {} index tasks unfinished",
+                GLOBAL_THREAD_POOL.pending_count()
+            );
+        }
+        if let Some(raft) = self.cluster.get() {
+            raft.stop()
+        }
+        let count = Filed::sync_all()?;
+        self.audit.sync()?;
+        self.journal.sync()?;
+        log::info!("shutdown synced {} files", count);
+        Ok(())
+    }
+
+    /// 记录已生效的数据变更，记录失败时返回错误，此时变更已生效但无法重放及复制，调用方应视为写入结果未知
+    fn journal(&self, op: MutationOp) -> GeorgeResult<()> {
+        match self.journal.record(op) {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Errs::strs("journal record", err)),
+        }
+    }
+
+    /// 从节点只读，拒绝除应用主节点变更之外的写入
+    fn writable(&self) -> GeorgeResult<()> {
+        if !self.applier && self.replication.replica() {
+            Err(Errs::str("replica is read only, write to primary instead!"))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// 集群模式下须经共识提交的写入返回集群共识，应用已提交变更的实例直接写入
+    fn consensus(&self) -> Option<&Arc<Raft>> {
+        if self.applier {
+            None
+        } else {
+            self.cluster.get()
+        }
+    }
+
+    /// 备份进行中时拒绝会移动或删除数据文件的操作
+    fn snapshot_check(&self) -> GeorgeResult<()> {
+        if Snapshot::active() {
+            Err(Errs::str("backup in progress, try again later!"))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// 写入缓存页，新增键时校验缓存页最大键数量
+    fn into_memory(
+        &self,
+        page_name: String,
+        key: String,
+        value: Vec<u8>,
+        force: bool,
+    ) -> GeorgeResult<()> {
+        self.writable()?;
+        if let Some(raft) = self.consensus() {
+            let op = if force {
+                MutationOp::MemorySet {
+                    page: page_name,
+                    key,
+                    value,
+                }
+            } else {
+                MutationOp::MemoryPut {
+                    page: page_name,
+                    key,
+                    value,
+                }
+            };
+            return raft.propose(op).map(|_| ());
+        }
+        let page = self.master.page(page_name.clone())?;
+        // 独占缓存页，使新增键的判断、预留、写入及变更记录之间没有同一缓存页的其它写入，变更日志与写入顺序一致
+        let page_w = page.write().unwrap();
+        let fresh = if self.quota.keys_limited(page_name.as_str()) {
+            let fresh = match page_w.get(key.clone()) {
+                Ok(_) => false,
+                Err(err) if Errs::data_absent(&err) => true,
+                Err(err) => return Err(err),
+            };
+            if fresh {
+                self.quota.take_key(page_name.clone(), &|| page_w.count())?;
+            }
+            fresh
+        } else {
+            false
+        };
+        let result = if force {
+            page_w.set(key.clone(), value.clone())
+        } else {
+            page_w.put(key.clone(), value.clone())
+        };
+        if let Err(err) = result {
+            if fresh {
+                self.quota.give_key(page_name)
+            }
+            return Err(err);
+        }
+        let page = page_name;
+        if force {
+            self.journal(MutationOp::MemorySet { page, key, value })
+        } else {
+            self.journal(MutationOp::MemoryPut { page, key, value })
+        }
+    }
+
+    /// 写入视图，按视图加密策略转换为存储值后写入
+    fn into_disk(
+        &self,
+        database_name: String,
+        view_name: String,
+        key: String,
+        value: Vec<u8>,
+        force: bool,
+    ) -> GeorgeResult<()> {
+        self.writable()?;
+        let stored = self
+            .master
+            .view(database_name.clone(), view_name.clone())?
+            .read()
+            .unwrap()
+            .seal(value)?;
+        self.commit_disk(database_name, view_name, key, stored, force)
+    }
+
+    /// 写入已按视图加密策略转换的存储值，集群模式下经共识提交，否则直接写入
+    pub(crate) fn commit_disk(
+        &self,
+        database_name: String,
+        view_name: String,
+        key: String,
+        stored: Vec<u8>,
+        force: bool,
+    ) -> GeorgeResult<()> {
+        if let Some(raft) = self.consensus() {
+            let op = if force {
+                MutationOp::DiskSet {
+                    database: database_name,
+                    view: view_name,
+                    key,
+                    value: stored,
+                }
+            } else {
+                MutationOp::DiskPut {
+                    database: database_name,
+                    view: view_name,
+                    key,
+                    value: stored,
+                }
+            };
+            return raft.propose(op).map(|_| ());
+        }
+        self.store_disk(database_name, view_name, key, stored, force)
+    }
+
+    /// 以指定密钥设置视图字段加密策略，变更日志同样记录密钥，使各副本及重放得到相同的密钥
+    ///
+    /// 早期变更日志中的记录不含密钥，此时沿用视图已有密钥或随机生成
+    pub(crate) fn store_cipher(
+        &self,
+        database_name: String,
+        view_name: String,
+        fields: Vec<String>,
+        deterministic: bool,
+        readers: Vec<String>,
+        key: String,
+        iv: String,
+    ) -> GeorgeResult<()> {
+        let view = self.master.view(database_name.clone(), view_name.clone())?;
+        let mut view_w = view.write().unwrap();
+        let (key, iv) = if key.is_empty() || iv.is_empty() {
+            view_w.cipher_key()
+        } else {
+            (key, iv)
+        };
+        view_w.modify_cipher(
+            fields.clone(),
+            deterministic,
+            readers.clone(),
+            key.clone(),
+            iv.clone(),
+        )?;
+        self.journal(MutationOp::ViewCipher {
+            database: database_name,
+            name: view_name,
+            fields,
+            deterministic,
+            readers,
+            key,
+            iv,
+        })
+    }
+
+    /// 写入已按视图加密策略转换的存储值，校验库及视图最大存储字节数，变更日志同样记录存储值
+    ///
+    /// 写入及变更记录期间独占视图，使变更日志与写入顺序一致
+    pub(crate) fn store_disk(
+        &self,
+        database_name: String,
+        view_name: String,
+        key: String,
+        stored: Vec<u8>,
+        force: bool,
+    ) -> GeorgeResult<()> {
+        let view = self.master.view(database_name.clone(), view_name.clone())?;
+        let view_w = view.write().unwrap();
+        let len = if self
+            .quota
+            .bytes_limited(database_name.clone(), view_name.clone())
+        {
+            // 按加密后的存储值计量，覆盖已有数据时仅计入增长的字节数
+            let exist = match view_w.get(INDEX_DISK, key.clone()) {
+                Ok(res) => res.len() as u64,
+                Err(err) if Errs::data_absent(&err) => 0,
+                Err(err) => return Err(err),
+            };
+            let len = (stored.len() as u64).saturating_sub(exist);
+            self.quota
+                .take_bytes(database_name.clone(), view_name.clone(), len)?;
+            len
+        } else {
+            0
+        };
+        if let Err(err) = view_w.store(key.clone(), stored.clone(), force) {
+            if len > 0 {
+                self.quota.give_bytes(database_name, view_name, len);
+            }
+            return Err(err);
+        }
+        let (database, view, value) = (database_name, view_name, stored);
+        if force {
+            self.journal(MutationOp::DiskSet {
+                database,
+                view,
+                key,
+                value,
+            })
+        } else {
+            self.journal(MutationOp::DiskPut {
+                database,
+                view,
+                key,
+                value,
+            })
+        }
+    }
 }
 
 impl TMaster for Task {
@@ -105,15 +853,62 @@ impl TMaster for Task {
         size: u64,
         period: u32,
     ) -> GeorgeResult<()> {
-        self.master.page_create(name, comment, size, period)
+        self.writable()?;
+        if let Some(raft) = self.consensus() {
+            return raft
+                .propose(MutationOp::PageCreate {
+                    name,
+                    comment,
+                    size,
+                    period,
+                })
+                .map(|_| ());
+        }
+        self.master
+            .page_create(name.clone(), comment.clone(), size, period)?;
+        self.journal(MutationOp::PageCreate {
+            name,
+            comment,
+            size,
+            period,
+        })?;
+        Ok(())
     }
 
     fn page_remove(&self, page_name: String) -> GeorgeResult<()> {
-        self.master.page_remove(page_name)
+        self.writable()?;
+        if let Some(raft) = self.consensus() {
+            return raft
+                .propose(MutationOp::PageRemove { name: page_name })
+                .map(|_| ());
+        }
+        self.snapshot_check()?;
+        self.master.page_remove(page_name.clone())?;
+        self.quota.forget(QUOTA_SCOPE_PAGE, page_name.clone());
+        self.journal(MutationOp::PageRemove { name: page_name })?;
+        Ok(())
     }
 
     fn page_modify(&self, page_name: String, page_new_name: String) -> GeorgeResult<()> {
-        self.master.page_modify(page_name, page_new_name)
+        self.writable()?;
+        if let Some(raft) = self.consensus() {
+            return raft
+                .propose(MutationOp::PageModify {
+                    name: page_name,
+                    new_name: page_new_name,
+                })
+                .map(|_| ());
+        }
+        self.snapshot_check()?;
+        self.master
+            .page_modify(page_name.clone(), page_new_name.clone())?;
+        self.quota
+            .rename_page(page_name.clone(), page_new_name.clone());
+        self.journal(MutationOp::PageModify {
+            name: page_name,
+            new_name: page_new_name,
+        })?;
+        Ok(())
     }
 
     fn page(&self, page_name: String) -> GeorgeResult<Arc<RwLock<Page>>> {
@@ -125,11 +920,41 @@ impl TMaster for Task {
     }
 
     fn database_create(&self, database_name: String, database_comment: String) -> GeorgeResult<()> {
-        self.master.database_create(database_name, database_comment)
+        self.writable()?;
+        if let Some(raft) = self.consensus() {
+            return raft
+                .propose(MutationOp::DatabaseCreate {
+                    name: database_name,
+                    comment: database_comment,
+                })
+                .map(|_| ());
+        }
+        self.master
+            .database_create(database_name.clone(), database_comment.clone())?;
+        self.journal(MutationOp::DatabaseCreate {
+            name: database_name,
+            comment: database_comment,
+        })?;
+        Ok(())
     }
 
     fn database_remove(&self, database_name: String) -> GeorgeResult<()> {
-        self.master.database_remove(database_name)
+        self.writable()?;
+        if let Some(raft) = self.consensus() {
+            return raft
+                .propose(MutationOp::DatabaseRemove {
+                    name: database_name,
+                })
+                .map(|_| ());
+        }
+        self.snapshot_check()?;
+        self.master.database_remove(database_name.clone())?;
+        self.quota
+            .forget(QUOTA_SCOPE_DATABASE, database_name.clone());
+        self.journal(MutationOp::DatabaseRemove {
+            name: database_name,
+        })?;
+        Ok(())
     }
 
     fn database_modify(
@@ -138,8 +963,32 @@ impl TMaster for Task {
         database_new_name: String,
         database_comment: String,
     ) -> GeorgeResult<()> {
-        self.master
-            .database_modify(database_name, database_new_name, database_comment)
+        self.writable()?;
+        if let Some(raft) = self.consensus() {
+            return raft
+                .propose(MutationOp::DatabaseModify {
+                    name: database_name,
+                    new_name: database_new_name,
+                    comment: database_comment,
+                })
+                .map(|_| ());
+        }
+        self.snapshot_check()?;
+        self.master.database_modify(
+            database_name.clone(),
+            database_new_name.clone(),
+            database_comment.clone(),
+        )?;
+        self.quota
+            .forget(QUOTA_SCOPE_DATABASE, database_name.clone());
+        self.quota
+            .forget(QUOTA_SCOPE_DATABASE, database_new_name.clone());
+        self.journal(MutationOp::DatabaseModify {
+            name: database_name,
+            new_name: database_new_name,
+            comment: database_comment,
+        })?;
+        Ok(())
     }
 
     fn database(&self, database_name: String) -> GeorgeResult<Arc<RwLock<Database>>> {
@@ -160,8 +1009,30 @@ impl TMaster for Task {
         comment: String,
         with_increment: bool,
     ) -> GeorgeResult<()> {
-        self.master
-            .view_create(database_name, view_name, comment, with_increment)
+        self.writable()?;
+        if let Some(raft) = self.consensus() {
+            return raft
+                .propose(MutationOp::ViewCreate {
+                    database: database_name,
+                    name: view_name,
+                    comment,
+                    with_increment,
+                })
+                .map(|_| ());
+        }
+        self.master.view_create(
+            database_name.clone(),
+            view_name.clone(),
+            comment.clone(),
+            with_increment,
+        )?;
+        self.journal(MutationOp::ViewCreate {
+            database: database_name,
+            name: view_name,
+            comment,
+            with_increment,
+        })?;
+        Ok(())
     }
 
     fn view_modify(
@@ -171,8 +1042,37 @@ impl TMaster for Task {
         view_new_name: String,
         comment: String,
     ) -> GeorgeResult<()> {
-        self.master
-            .view_modify(database_name, view_name, view_new_name, comment)
+        self.writable()?;
+        if let Some(raft) = self.consensus() {
+            return raft
+                .propose(MutationOp::ViewModify {
+                    database: database_name,
+                    name: view_name,
+                    new_name: view_new_name,
+                    comment,
+                })
+                .map(|_| ());
+        }
+        self.snapshot_check()?;
+        self.master.view_modify(
+            database_name.clone(),
+            view_name.clone(),
+            view_new_name.clone(),
+            comment.clone(),
+        )?;
+        self.quota
+            .forget(QUOTA_SCOPE_VIEW, format!("{}/{}", database_name, view_name));
+        self.quota.forget(
+            QUOTA_SCOPE_VIEW,
+            format!("{}/{}", database_name, view_new_name),
+        );
+        self.journal(MutationOp::ViewModify {
+            database: database_name,
+            name: view_name,
+            new_name: view_new_name,
+            comment,
+        })?;
+        Ok(())
     }
 
     fn view_archive(
@@ -181,6 +1081,8 @@ impl TMaster for Task {
         view_name: String,
         archive_file_path: String,
     ) -> GeorgeResult<()> {
+        self.writable()?;
+        self.snapshot_check()?;
         self.master
             .view_archive(database_name, view_name, archive_file_path)
     }
@@ -203,13 +1105,93 @@ impl TMaster for Task {
     }
 
     fn view_remove(&self, database_name: String, view_name: String) -> GeorgeResult<()> {
-        self.master.view_remove(database_name, view_name)
+        self.writable()?;
+        if let Some(raft) = self.consensus() {
+            return raft
+                .propose(MutationOp::ViewRemove {
+                    database: database_name,
+                    name: view_name,
+                })
+                .map(|_| ());
+        }
+        self.snapshot_check()?;
+        self.master
+            .view_remove(database_name.clone(), view_name.clone())?;
+        self.quota
+            .forget(QUOTA_SCOPE_VIEW, format!("{}/{}", database_name, view_name));
+        self.journal(MutationOp::ViewRemove {
+            database: database_name,
+            name: view_name,
+        })?;
+        Ok(())
     }
 
     fn view(&self, database_name: String, view_name: String) -> GeorgeResult<Arc<RwLock<View>>> {
         self.master.view(database_name, view_name)
     }
 
+    fn view_cipher(
+        &self,
+        database_name: String,
+        view_name: String,
+        fields: Vec<String>,
+        deterministic: bool,
+        readers: Vec<String>,
+    ) -> GeorgeResult<()> {
+        self.writable()?;
+        // 由发起变更的节点确定密钥，随变更传播至各副本
+        let (key, iv) = self
+            .master
+            .view(database_name.clone(), view_name.clone())?
+            .read()
+            .unwrap()
+            .cipher_key();
+        if let Some(raft) = self.consensus() {
+            return raft
+                .propose(MutationOp::ViewCipher {
+                    database: database_name,
+                    name: view_name,
+                    fields,
+                    deterministic,
+                    readers,
+                    key,
+                    iv,
+                })
+                .map(|_| ());
+        }
+        self.store_cipher(
+            database_name,
+            view_name,
+            fields,
+            deterministic,
+            readers,
+            key,
+            iv,
+        )
+    }
+
+    fn reveal_disk(
+        &self,
+        database_name: String,
+        view_name: String,
+        principal: String,
+        value: Vec<u8>,
+    ) -> GeorgeResult<Vec<u8>> {
+        self.master
+            .reveal_disk(database_name, view_name, principal, value)
+    }
+
+    fn scan_disk(
+        &self,
+        database_name: String,
+        view_name: String,
+        principal: String,
+        each: &mut dyn FnMut(String, Vec<u8>) -> GeorgeResult<()>,
+    ) -> GeorgeResult<u64> {
+        self.master
+            .scan_disk(database_name, view_name, principal, each)
+    }
+
     fn index_map(
         &self,
         database_name: String,
@@ -229,16 +1211,42 @@ impl TMaster for Task {
         unique: bool,
         null: bool,
     ) -> GeorgeResult<()> {
+        self.writable()?;
+        if let Some(raft) = self.consensus() {
+            return raft
+                .propose(MutationOp::IndexCreate {
+                    database: database_name,
+                    view: view_name,
+                    name: index_name,
+                    engine: Enum::engine_u8(engine),
+                    key_type: Enum::key_type_u8(key_type),
+                    primary,
+                    unique,
+                    null,
+                })
+                .map(|_| ());
+        }
         self.master.index_create(
-            database_name,
-            view_name,
-            index_name,
+            database_name.clone(),
+            view_name.clone(),
+            index_name.clone(),
             engine,
             key_type,
             primary,
             unique,
             null,
-        )
+        )?;
+        self.journal(MutationOp::IndexCreate {
+            database: database_name,
+            view: view_name,
+            name: index_name,
+            engine: Enum::engine_u8(engine),
+            key_type: Enum::key_type_u8(key_type),
+            primary,
+            unique,
+            null,
+        })?;
+        Ok(())
     }
 
     fn index(
@@ -257,7 +1265,7 @@ impl TMaster for Task {
         key: String,
         value: Vec<u8>,
     ) -> GeorgeResult<()> {
-        self.master.put_disk(database_name, view_name, key, value)
+        self.into_disk(database_name, view_name, key, value, false)
     }
 
     fn set_disk(
@@ -267,7 +1275,7 @@ impl TMaster for Task {
         key: String,
         value: Vec<u8>,
     ) -> GeorgeResult<()> {
-        self.master.set_disk(database_name, view_name, key, value)
+        self.into_disk(database_name, view_name, key, value, true)
     }
 
     fn get_disk(
@@ -296,7 +1304,25 @@ impl TMaster for Task {
         view_name: String,
         key: String,
     ) -> GeorgeResult<()> {
-        self.master.remove_disk(database_name, view_name, key)
+        self.writable()?;
+        if let Some(raft) = self.consensus() {
+            return raft
+                .propose(MutationOp::DiskRemove {
+                    database: database_name,
+                    view: view_name,
+                    key,
+                })
+                .map(|_| ());
+        }
+        let view = self.master.view(database_name.clone(), view_name.clone())?;
+        // 删除及变更记录期间独占视图，使变更日志与写入顺序一致
+        let view_w = view.write().unwrap();
+        view_w.remove(key.clone(), vec![])?;
+        self.journal(MutationOp::DiskRemove {
+            database: database_name,
+            view: view_name,
+            key,
+        })
     }
 
     fn select_disk(
@@ -309,22 +1335,60 @@ impl TMaster for Task {
             .select_disk(database_name, view_name, constraint_json_bytes)
     }
 
+    fn select_disk_stream(
+        &self,
+        database_name: String,
+        view_name: String,
+        principal: Option<String>,
+        constraint_json_bytes: Vec<u8>,
+        sink: &mut dyn FnMut(Vec<u8>) -> GeorgeResult<()>,
+    ) -> GeorgeResult<Expectation> {
+        self.master.select_disk_stream(
+            database_name,
+            view_name,
+            principal,
+            constraint_json_bytes,
+            sink,
+        )
+    }
+
     fn delete_disk(
         &self,
         database_name: String,
         view_name: String,
         constraint_json_bytes: Vec<u8>,
     ) -> GeorgeResult<Expectation> {
-        self.master
-            .delete_disk(database_name, view_name, constraint_json_bytes)
+        self.writable()?;
+        if let Some(raft) = self.consensus() {
+            let op = MutationOp::DiskDelete {
+                database: database_name,
+                view: view_name,
+                constraint: constraint_json_bytes,
+            };
+            return match raft.propose(op)? {
+                Some(expectation) => Ok(expectation),
+                None => Err(Errs::str("cluster delete without expectation!")),
+            };
+        }
+        let expectation = self.master.delete_disk(
+            database_name.clone(),
+            view_name.clone(),
+            constraint_json_bytes.clone(),
+        )?;
+        self.journal(MutationOp::DiskDelete {
+            database: database_name,
+            view: view_name,
+            constraint: constraint_json_bytes,
+        })?;
+        Ok(expectation)
     }
 
     fn put_memory(&self, page_name: String, key: String, value: Vec<u8>) -> GeorgeResult<()> {
-        self.master.put_memory(page_name, key, value)
+        self.into_memory(page_name, key, value, false)
     }
 
     fn set_memory(&self, page_name: String, key: String, value: Vec<u8>) -> GeorgeResult<()> {
-        self.master.set_memory(page_name, key, value)
+        self.into_memory(page_name, key, value, true)
     }
 
     fn get_memory(&self, page_name: String, key: String) -> GeorgeResult<Vec<u8>> {
@@ -332,6 +1396,103 @@ impl TMaster for Task {
     }
 
     fn remove_memory(&self, page_name: String, key: String) -> GeorgeResult<()> {
-        self.master.remove_memory(page_name, key)
+        self.writable()?;
+        if let Some(raft) = self.consensus() {
+            return raft
+                .propose(MutationOp::MemoryRemove {
+                    page: page_name,
+                    key,
+                })
+                .map(|_| ());
+        }
+        let page = self.master.page(page_name.clone())?;
+        // 与新增键的预留互斥，键数量与缓存页保持一致，变更日志与删除顺序一致
+        let page_w = page.write().unwrap();
+        if self.quota.keys_limited(page_name.as_str()) {
+            let exist = match page_w.get(key.clone()) {
+                Ok(_) => true,
+                Err(err) if Errs::data_absent(&err) => false,
+                Err(err) => return Err(err),
+            };
+            page_w.remove(key.clone())?;
+            if exist {
+                self.quota.give_key(page_name.clone())
+            }
+        } else {
+            page_w.remove(key.clone())?;
+        }
+        self.journal(MutationOp::MemoryRemove {
+            page: page_name,
+            key,
+        })
+    }
+    fn ledger_map(&self) -> Arc<RwLock<HashMap<String, Arc<RwLock<Ledger>>>>> {
+        self.master.ledger_map()
+    }
+
+    fn ledger_create(&self, name: String, comment: String) -> GeorgeResult<()> {
+        self.writable()?;
+        if let Some(raft) = self.consensus() {
+            return raft
+                .propose(MutationOp::LedgerCreate { name, comment })
+                .map(|_| ());
+        }
+        self.master.ledger_create(name.clone(), comment.clone())?;
+        self.journal(MutationOp::LedgerCreate { name, comment })?;
+        Ok(())
+    }
+
+    fn ledger_remove(&self, ledger_name: String) -> GeorgeResult<()> {
+        self.writable()?;
+        if let Some(raft) = self.consensus() {
+            return raft
+                .propose(MutationOp::LedgerRemove { name: ledger_name })
+                .map(|_| ());
+        }
+        self.snapshot_check()?;
+        self.master.ledger_remove(ledger_name.clone())?;
+        self.journal(MutationOp::LedgerRemove { name: ledger_name })?;
+        Ok(())
+    }
+
+    fn ledger(&self, ledger_name: String) -> GeorgeResult<Arc<RwLock<Ledger>>> {
+        self.master.ledger(ledger_name)
+    }
+
+    fn block_insert(
+        &self,
+        ledger_name: String,
+        timestamp: i64,
+        transactions: Vec<Vec<u8>>,
+    ) -> GeorgeResult<Block> {
+        self.writable()?;
+        if let Some(raft) = self.consensus() {
+            // 已提交的区块通过首笔交易hash取回
+            let tx_hash = match transactions.first() {
+                Some(value) => Transaction::hash(timestamp, 0, value),
+                None => return Err(Errs::str("block must contain at least one transaction!")),
+            };
+            raft.propose(MutationOp::BlockInsert {
+                ledger: ledger_name.clone(),
+                timestamp,
+                transactions,
+            })?;
+            return self
+                .master
+                .ledger(ledger_name)?
+                .read()
+                .unwrap()
+                .transaction(tx_hash)
+                .map(|(block, _)| block);
+        }
+        let block =
+            self.master
+                .block_insert(ledger_name.clone(), timestamp, transactions.clone())?;
+        self.journal(MutationOp::BlockInsert {
+            ledger: ledger_name,
+            timestamp,
+            transactions,
+        })?;
+        Ok(block)
     }
 }
@@ -0,0 +1,405 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Write};
+use std::sync::mpsc::{self, Receiver};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use serde::{Deserialize, Serialize};
+
+use george_comm::errors::{Errs, GeorgeResult};
+use george_comm::io::file::{FilerHandler, FilerNormal};
+use george_comm::io::Filer;
+use george_comm::Time;
+
+use crate::task::{Audit, Secret};
+use crate::utils::Paths;
+
+/// 审计记录签名用途
+const SCOPE_AUDIT: &str = "audit";
+
+/// 审计链起始hash，即首条审计记录的前置hash
+const AUDIT_GENESIS_HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+/// 审计记录
+///
+/// 每条记录都携带前一条记录的hash，并以前置hash与自身内容计算出当前hash，任意记录被篡改都会导致后续校验失败
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditRecord {
+    /// 记录序号，从1开始递增
+    pub seq: u64,
+    /// 记录时间，单位纳秒
+    pub time: i64,
+    /// 操作发起人
+    pub principal: String,
+    /// 操作发起地址
+    pub remote: String,
+    /// 操作名称，如`database.create`
+    pub operation: String,
+    /// 操作对象，如`database_name/view_name`
+    pub target: String,
+    /// 操作是否成功
+    pub success: bool,
+    /// 操作失败时的错误信息
+    pub msg_err: String,
+    /// 操作耗时，单位微秒
+    pub latency: u64,
+    /// 前一条记录的hash
+    pub prev_hash: String,
+    /// 当前记录hash
+    pub hash: String,
+}
+
+impl AuditRecord {
+    /// 根据前置hash及记录内容计算当前记录hash
+    ///
+    /// 以服务主密钥派生的子密钥对记录内容的json数组计算HMAC，未持有主密钥者无法重算整条审计链
+    pub(crate) fn digest(&self, secret: &Secret) -> GeorgeResult<String> {
+        let content = match serde_json::to_vec(&(
+            &self.prev_hash,
+            self.seq,
+            self.time,
+            &self.principal,
+            &self.remote,
+            &self.operation,
+            &self.target,
+            self.success,
+            &self.msg_err,
+            self.latency,
+        )) {
+            Ok(res) => res,
+            Err(err) => return Err(Errs::strs("audit record content to json", err)),
+        };
+        secret.hmac(SCOPE_AUDIT, content.as_slice())
+    }
+
+    /// 校验记录的前置hash是否为前一条记录hash，以及记录内容是否与自身hash相符
+    fn check(&self, prev_hash: &str, secret: &Secret) -> GeorgeResult<()> {
+        if self.prev_hash.ne(prev_hash) {
+            return Err(Errs::string(format!(
+                "audit chain broken at seq {}, prev hash mismatch",
+                self.seq
+            )));
+        }
+        if self.digest(secret)?.ne(&self.hash) {
+            return Err(Errs::string(format!(
+                "audit chain broken at seq {}, record was modified",
+                self.seq
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// 审计链尾信息，记录最新审计记录的序号及hash，同时持有审计文件追加句柄
+#[derive(Debug)]
+pub(crate) struct AuditTail {
+    seq: u64,
+    hash: String,
+    file: File,
+}
+
+/// 投递至审计写入线程的操作
+#[derive(Debug)]
+pub(crate) enum AuditOp {
+    /// 待串联hash并追加写入的审计记录
+    Record(AuditRecord),
+    /// 此前投递的记录全部写入后通过该通道应答
+    Flush(mpsc::Sender<()>),
+}
+
+impl Audit {
+    /// 新建或恢复审计日志
+    ///
+    /// 审计文件存在时会从文件中恢复并校验审计链，得到最新记录的序号及hash，以便后续记录继续串联。
+    /// 记录由独立的写入线程串联hash并追加写入文件，请求处理线程仅投递记录，不阻塞于文件IO
+    pub(crate) fn recovery(secret: Arc<Secret>) -> GeorgeResult<Audit> {
+        let filepath = Paths::audit_filepath();
+        Filer::try_touch(filepath.clone())?;
+        let (seq, hash) = Audit::restore(filepath.clone(), &secret)?;
+        log::info!("audit recovery with seq {}", seq);
+        let tail = Arc::new(Mutex::new(AuditTail {
+            seq,
+            hash,
+            file: Filer::appender(filepath.clone())?,
+        }));
+        let (sender, receiver) = mpsc::channel();
+        let writer_secret = secret.clone();
+        let writer_tail = tail.clone();
+        match thread::Builder::new()
+            .name("george-audit".to_string())
+            .spawn(move || Audit::write(writer_secret, writer_tail, receiver))
+        {
+            Ok(_) => {}
+            Err(err) => return Err(Errs::strs("audit writer spawn", err)),
+        }
+        Ok(Audit {
+            filepath,
+            secret,
+            tail,
+            sender: Mutex::new(sender),
+        })
+    }
+
+    /// 读取审计文件并校验审计链，返回链尾记录的序号及hash
+    ///
+    /// 末行未以换行结尾说明写入时进程中断，该残缺记录会被截断，后续记录从截断处继续追加
+    pub(crate) fn restore(filepath: String, secret: &Secret) -> GeorgeResult<(u64, String)> {
+        let mut reader = BufReader::new(Filer::reader(filepath.clone())?);
+        let mut seq = 0;
+        let mut hash = AUDIT_GENESIS_HASH.to_string();
+        let mut offset: u64 = 0;
+        let mut buf: Vec<u8> = vec![];
+        loop {
+            buf.clear();
+            let len = match reader.read_until(b'\n', &mut buf) {
+                Ok(0) => break,
+                Ok(len) => len,
+                Err(err) => return Err(Errs::strs("audit file read line", err)),
+            };
+            if !buf.ends_with(b"\n") {
+                log::warn!(
+                    "audit file {} has a torn record after seq {}, truncate at {}",
+                    filepath,
+                    seq,
+                    offset
+                );
+                match Filer::reader_writer(filepath.clone())?.set_len(offset) {
+                    Ok(()) => break,
+                    Err(err) => return Err(Errs::strs("audit file truncate", err)),
+                }
+            }
+            offset += len as u64;
+            let line = String::from_utf8_lossy(&buf);
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let record: AuditRecord = match serde_json::from_str(line) {
+                Ok(record) => record,
+                Err(err) => return Err(Errs::strs("audit record from json", err)),
+            };
+            record.check(hash.as_str(), secret)?;
+            seq = record.seq;
+            hash = record.hash;
+        }
+        Ok((seq, hash))
+    }
+
+    /// 审计写入线程，按投递顺序串联hash并追加写入审计记录，所有投递端释放后退出
+    fn write(secret: Arc<Secret>, tail: Arc<Mutex<AuditTail>>, receiver: Receiver<AuditOp>) {
+        for op in receiver.iter() {
+            match op {
+                AuditOp::Record(record) => match Audit::append(&secret, &tail, record) {
+                    Ok(()) => {}
+                    Err(err) => log::error!("audit record failed! error is {}", err),
+                },
+                AuditOp::Flush(sender) => {
+                    let _ = sender.send(());
+                }
+            }
+        }
+    }
+
+    /// 以链尾信息串联记录hash并追加写入审计文件
+    fn append(
+        secret: &Secret,
+        tail: &Mutex<AuditTail>,
+        mut record: AuditRecord,
+    ) -> GeorgeResult<()> {
+        let mut tail = tail.lock().unwrap();
+        record.seq = tail.seq + 1;
+        record.prev_hash = tail.hash.clone();
+        record.hash = record.digest(secret)?;
+        let mut line = match serde_json::to_string(&record) {
+            Ok(res) => res,
+            Err(err) => return Err(Errs::strs("audit record to json", err)),
+        };
+        line.push('\n');
+        match tail.file.write_all(line.as_bytes()) {
+            Ok(()) => {}
+            Err(err) => return Err(Errs::strs("audit record write", err)),
+        }
+        tail.seq = record.seq;
+        tail.hash = record.hash;
+        Ok(())
+    }
+
+    /// 追加审计记录
+    ///
+    /// 记录仅投递至审计写入线程，由其串联hash并写入文件，调用方不阻塞于文件IO
+    ///
+    /// ###Params
+    ///
+    /// * principal 操作发起人
+    /// * remote 操作发起地址
+    /// * operation 操作名称
+    /// * target 操作对象
+    /// * success 操作是否成功
+    /// * msg_err 操作失败时的错误信息
+    /// * latency 操作耗时，单位微秒
+    pub fn record(
+        &self,
+        principal: String,
+        remote: String,
+        operation: String,
+        target: String,
+        success: bool,
+        msg_err: String,
+        latency: u64,
+    ) -> GeorgeResult<()> {
+        let record = AuditRecord {
+            seq: 0,
+            time: Time::now().nanos(),
+            principal,
+            remote,
+            operation,
+            target,
+            success,
+            msg_err,
+            latency,
+            prev_hash: "".to_string(),
+            hash: "".to_string(),
+        };
+        match self.sender.lock().unwrap().send(AuditOp::Record(record)) {
+            Ok(()) => Ok(()),
+            Err(err) => Err(Errs::strs("audit record send", err)),
+        }
+    }
+
+    /// 等待此前投递的审计记录全部写入文件
+    fn flush(&self) -> GeorgeResult<()> {
+        let (sender, receiver) = mpsc::channel();
+        match self.sender.lock().unwrap().send(AuditOp::Flush(sender)) {
+            Ok(()) => {}
+            Err(err) => return Err(Errs::strs("audit flush send", err)),
+        }
+        match receiver.recv() {
+            Ok(()) => Ok(()),
+            Err(err) => Err(Errs::strs("audit flush recv", err)),
+        }
+    }
+
+    /// 检索审计记录，按记录先后顺序返回最近的`limit`条
+    ///
+    /// ###Params
+    ///
+    /// * principal 操作发起人，为空则不限
+    /// * operation 操作名称前缀，为空则不限，如`database`可匹配`database.create`
+    /// * start 起始时间，单位秒，为0则不限
+    /// * end 截止时间，单位秒，为0则不限
+    /// * limit 最多返回条数，为0则不限
+    pub fn records(
+        &self,
+        principal: String,
+        operation: String,
+        start: i64,
+        end: i64,
+        limit: u32,
+    ) -> GeorgeResult<Vec<AuditRecord>> {
+        self.flush()?;
+        let seq = self.tail.lock().unwrap().seq;
+        let mut records: VecDeque<AuditRecord> = VecDeque::new();
+        Audit::scan(self.filepath.clone(), seq, &mut |record| {
+            if !principal.is_empty() && principal.ne(&record.principal) {
+                return Ok(());
+            }
+            if !operation.is_empty() && !record.operation.starts_with(operation.as_str()) {
+                return Ok(());
+            }
+            let secs = record.time / 1_000_000_000;
+            if start > 0 && secs < start {
+                return Ok(());
+            }
+            if end > 0 && secs > end {
+                return Ok(());
+            }
+            records.push_back(record);
+            if limit > 0 && records.len() > limit as usize {
+                records.pop_front();
+            }
+            Ok(())
+        })?;
+        Ok(records.into_iter().collect())
+    }
+
+    /// 校验审计链完整性，返回已校验的记录条数
+    ///
+    /// 任意记录的前置hash与前一条记录hash不符，或记录内容与自身hash不符，都会返回错误
+    pub fn verify(&self) -> GeorgeResult<u64> {
+        self.flush()?;
+        let seq = self.tail.lock().unwrap().seq;
+        let mut hash = AUDIT_GENESIS_HASH.to_string();
+        let mut count = 0;
+        Audit::scan(self.filepath.clone(), seq, &mut |record| {
+            record.check(hash.as_str(), &self.secret)?;
+            hash = record.hash;
+            count += 1;
+            Ok(())
+        })?;
+        Ok(count)
+    }
+
+    /// 将已投递的审计记录写入并同步到磁盘
+    pub fn sync(&self) -> GeorgeResult<()> {
+        self.flush()?;
+        let tail = self.tail.lock().unwrap();
+        match tail.file.sync_all() {
+            Ok(()) => Ok(()),
+            Err(err) => Err(Errs::strs("audit file sync", err)),
+        }
+    }
+
+    /// 审计文件路径
+    pub fn filepath(&self) -> String {
+        self.filepath.clone()
+    }
+
+    /// 逐行读取审计文件，将序号不大于`seq`的记录依次交由`f`处理
+    ///
+    /// 调用方先在锁内取得链尾序号，读取时不再持有锁，避免长时间阻塞新记录追加
+    fn scan(
+        filepath: String,
+        seq: u64,
+        f: &mut dyn FnMut(AuditRecord) -> GeorgeResult<()>,
+    ) -> GeorgeResult<()> {
+        let file = Filer::reader(filepath)?;
+        let mut last = 0;
+        for line in BufReader::new(file).lines() {
+            match line {
+                Ok(line) => {
+                    if line.is_empty() {
+                        continue;
+                    }
+                    let record: AuditRecord = match serde_json::from_str(line.as_str()) {
+                        Ok(record) => record,
+                        // 已读至链尾，其后为读取期间正在追加的记录
+                        Err(_) if last >= seq => break,
+                        Err(err) => return Err(Errs::strs("audit record from json", err)),
+                    };
+                    if record.seq > seq {
+                        break;
+                    }
+                    last = record.seq;
+                    f(record)?;
+                }
+                Err(err) => return Err(Errs::strs("audit file read line", err)),
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,125 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[cfg(test)]
+mod audit {
+    use std::fs;
+
+    use george_comm::io::file::FilerReader;
+    use george_comm::io::Filer;
+
+    use crate::task::audit::AuditRecord;
+    use crate::task::{Audit, Secret};
+    use crate::Task;
+
+    /// 以`secret`串联生成`count`条审计记录的文件内容
+    fn chain(secret: &Secret, count: u64) -> (String, String) {
+        let mut content = String::new();
+        let mut hash = "0".repeat(64);
+        for seq in 1..=count {
+            let mut record = AuditRecord {
+                seq,
+                time: seq as i64,
+                principal: "admin".to_string(),
+                remote: "127.0.0.1:9219".to_string(),
+                operation: "database.create".to_string(),
+                target: format!("database_audit_restore_{}", seq),
+                success: true,
+                msg_err: "".to_string(),
+                latency: 10,
+                prev_hash: hash.clone(),
+                hash: "".to_string(),
+            };
+            record.hash = record.digest(secret).unwrap();
+            hash = record.hash.clone();
+            content.push_str(serde_json::to_string(&record).unwrap().as_str());
+            content.push('\n');
+        }
+        (content, hash)
+    }
+
+    #[test]
+    fn record_and_verify() {
+        let task = Task::default().unwrap();
+        task.audit_record(
+            "admin".to_string(),
+            "127.0.0.1:9219".to_string(),
+            "database.create".to_string(),
+            "database_audit_test".to_string(),
+            true,
+            "".to_string(),
+            100,
+        )
+        .unwrap();
+        task.audit_record(
+            "guest".to_string(),
+            "127.0.0.1:9219".to_string(),
+            "database.remove".to_string(),
+            "database_audit_test".to_string(),
+            false,
+            "database is not exist!".to_string(),
+            50,
+        )
+        .unwrap();
+        let records = task
+            .audit_records("admin".to_string(), "database".to_string(), 0, 0, 1)
+            .unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].principal, "admin");
+        let records = task
+            .audit_records("".to_string(), "".to_string(), 0, 0, 2)
+            .unwrap();
+        assert_eq!(records[1].prev_hash, records[0].hash);
+        assert!(task.audit_verify().unwrap() >= 2);
+    }
+
+    #[test]
+    fn restore_torn_record() {
+        let secret = Secret { key: vec![9; 32] };
+        let dir = "src/test/audit/torn";
+        let filepath = format!("{}/audit.log", dir);
+        let _ = fs::remove_dir_all(dir);
+        fs::create_dir_all(dir).unwrap();
+        let (content, hash) = chain(&secret, 3);
+        fs::write(&filepath, format!("{}{{\"seq\":4,\"time\"", content)).unwrap();
+
+        // 残缺的末条记录被截断，链尾停在最后一条完整记录
+        let (seq, tail) = Audit::restore(filepath.clone(), &secret).unwrap();
+        assert_eq!(seq, 3);
+        assert_eq!(tail, hash);
+        assert_eq!(Filer::read(&filepath).unwrap(), content);
+        assert_eq!(Audit::restore(filepath, &secret).unwrap().0, 3);
+    }
+
+    #[test]
+    fn restore_broken_chain() {
+        let secret = Secret { key: vec![9; 32] };
+        let dir = "src/test/audit/broken";
+        let filepath = format!("{}/audit.log", dir);
+        let _ = fs::remove_dir_all(dir);
+        fs::create_dir_all(dir).unwrap();
+        let (content, _) = chain(&secret, 3);
+        fs::write(
+            &filepath,
+            content.replace("database_audit_restore_2", "database_audit_tampered"),
+        )
+        .unwrap();
+        assert!(Audit::restore(filepath.clone(), &secret).is_err());
+
+        // 以不同主密钥无法通过审计链校验
+        fs::write(&filepath, content).unwrap();
+        let other = Secret { key: vec![7; 32] };
+        assert!(Audit::restore(filepath, &other).is_err());
+    }
+}
@@ -0,0 +1,336 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::fs;
+use std::io::{Read, Write};
+use std::path::{Component, Path};
+
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use serde::{Deserialize, Serialize};
+
+use george_comm::cryptos::hash::HashMD5Handler;
+use george_comm::cryptos::Hash;
+use george_comm::errors::{Errs, GeorgeResult};
+use george_comm::io::file::{FilerHandler, FilerReader, FilerWriter};
+use george_comm::io::Filer;
+use george_comm::Time;
+use george_ge::utils::Snapshot;
+
+use crate::task::{Backup, Cipher};
+use crate::utils::deploy::GLOBAL_CONFIG;
+use crate::utils::Paths;
+
+/// 备份清单文件名
+pub const BACKUP_MANIFEST: &str = "manifest.json";
+/// 备份格式版本
+const BACKUP_VERSION: u32 = 1;
+/// 压缩后的备份文件后缀
+const BACKUP_COMPRESS_SUFFIX: &str = ".gz";
+/// 视图字段加密策略文件名
+const VIEW_CIPHER_FILE: &str = "/cipher.json";
+
+/// 备份清单
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupManifest {
+    /// 备份格式版本
+    pub version: u32,
+    /// 备份时间，单位纳秒
+    pub time: i64,
+    /// 备份时刻的变更日志序号，时间点恢复时重放该序号之后的变更
+    pub seq: u64,
+    /// 结束快照时的变更日志序号，序号不大于该值的变更可能已包含在备份中，重放时允许其因已生效而失败
+    #[serde(default)]
+    pub end_seq: u64,
+    /// 备份文件是否经`gzip`压缩
+    pub compress: bool,
+    /// 备份文件集合
+    pub files: Vec<BackupFile>,
+}
+
+/// 备份文件
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupFile {
+    /// 相对数据存储路径的文件路径
+    pub path: String,
+    /// 原文件字节数
+    pub size: u64,
+    /// 原文件内容`sha256`
+    pub sha256: String,
+}
+
+impl BackupManifest {
+    /// 序列化为备份清单文件中的`json`格式
+    pub fn to_vec(&self) -> GeorgeResult<Vec<u8>> {
+        match serde_json::to_vec_pretty(self) {
+            Ok(res) => Ok(res),
+            Err(err) => Err(Errs::strs("backup manifest to json", err)),
+        }
+    }
+
+    /// 从备份清单文件中的`json`格式反序列化
+    pub fn from_slice(bytes: &[u8]) -> GeorgeResult<BackupManifest> {
+        match serde_json::from_slice(bytes) {
+            Ok(res) => Ok(res),
+            Err(err) => Err(Errs::strs("backup manifest from json", err)),
+        }
+    }
+}
+
+impl Backup {
+    /// 在写入持续进行的同时生成数据存储路径下引导文件、配额策略、缓存页、库、视图及索引文件的一致快照
+    ///
+    /// 视图字段加密策略中的密钥以明文写入备份，使备份可在主密钥不同的节点恢复
+    ///
+    /// ###Params
+    ///
+    /// * dir 备份目录，须为空目录或不存在
+    /// * compress 是否以`gzip`压缩备份文件
+    /// * seq 备份时刻的变更日志序号，须在开启快照前获取，以免遗漏快照后的变更
+    /// * end_seq 获取当前变更日志序号，在结束快照后调用，作为可能已包含在备份中的变更的序号上限
+    pub(crate) fn create(
+        dir: String,
+        compress: bool,
+        seq: u64,
+        end_seq: &dyn Fn() -> u64,
+    ) -> GeorgeResult<BackupManifest> {
+        empty_check(&dir, "backup")?;
+        let data_dir = GLOBAL_CONFIG.read().unwrap().data_dir();
+        let mut filepaths = vec![Paths::bootstrap_filepath()];
+        if Filer::exist(Paths::quota_filepath()) {
+            filepaths.push(Paths::quota_filepath())
+        }
+        walk(Paths::data_path(), &mut filepaths)?;
+        // 策略文件以整体覆盖的方式写入，开启快照时直接读取完整内容
+        let (policies, filepaths): (Vec<String>, Vec<String>) = filepaths
+            .into_iter()
+            .partition(|filepath| filepath.ends_with(".json"));
+        let time = Time::now().nanos();
+        let snapshot = Snapshot::begin(filepaths.clone(), policies.clone())?;
+        let mut files: Vec<BackupFile> = vec![];
+        let result = (|| {
+            for filepath in filepaths.iter().chain(policies.iter()) {
+                let mut content = snapshot.read(filepath.clone())?;
+                // 视图加密密钥以本节点主密钥包装，转为明文保存以便在其它节点恢复
+                if filepath.ends_with(VIEW_CIPHER_FILE) {
+                    content = Cipher::portable(content)?;
+                }
+                files.push(store(&data_dir, &dir, filepath, content, compress)?);
+            }
+            Ok(())
+        })();
+        snapshot.end();
+        result?;
+        let manifest = BackupManifest {
+            version: BACKUP_VERSION,
+            time,
+            seq,
+            end_seq: end_seq(),
+            compress,
+            files,
+        };
+        Filer::write_force(format!("{}/{}", dir, BACKUP_MANIFEST), manifest.to_vec()?)?;
+        log::info!(
+            "backup {} files into {} with journal seq {}",
+            manifest.files.len(),
+            dir,
+            seq
+        );
+        Ok(manifest)
+    }
+
+    /// 根据备份清单校验备份文件并重建数据存储路径
+    ///
+    /// ###Params
+    ///
+    /// * dir 备份目录
+    /// * data_dir 待重建的数据存储路径，不能已存在数据
+    pub fn restore(dir: String, data_dir: String) -> GeorgeResult<BackupManifest> {
+        let manifest = Backup::manifest(dir.clone())?;
+        if manifest.version != BACKUP_VERSION {
+            return Err(Errs::string(format!(
+                "backup version {} is not supported!",
+                manifest.version
+            )));
+        }
+        if Filer::exist(format!("{}/bootstrap.ge", data_dir)) {
+            return Err(Errs::string(format!(
+                "data dir {} already has data!",
+                data_dir
+            )));
+        }
+        empty_check(&format!("{}/data", data_dir), "restore")?;
+        for file in manifest.files.iter() {
+            let content = load(&dir, &manifest, file)?;
+            Filer::write_force(format!("{}/{}", data_dir, file.path), content)?;
+        }
+        log::info!("restore {} files into {}", manifest.files.len(), data_dir);
+        Ok(manifest)
+    }
+
+    /// 根据备份清单校验备份文件，不写入任何数据，用于在清空数据前确认备份可用
+    ///
+    /// ###Params
+    ///
+    /// * dir 备份目录
+    pub fn verify(dir: String) -> GeorgeResult<BackupManifest> {
+        let manifest = Backup::manifest(dir.clone())?;
+        if manifest.version != BACKUP_VERSION {
+            return Err(Errs::string(format!(
+                "backup version {} is not supported!",
+                manifest.version
+            )));
+        }
+        for file in manifest.files.iter() {
+            load(&dir, &manifest, file)?;
+        }
+        Ok(manifest)
+    }
+
+    /// 将备份请求指定的目录解析为备份根路径下的目录
+    ///
+    /// 未配置备份根路径时拒绝备份，`dir`须为不含`..`的相对路径
+    pub(crate) fn resolve(dir: String) -> GeorgeResult<String> {
+        let root = match GLOBAL_CONFIG.read().unwrap().backup_dir() {
+            Some(res) => res,
+            None => {
+                return Err(Errs::str(
+                    "backup dir is not configured, set db.backup_dir first!",
+                ))
+            }
+        };
+        Backup::check_path(&dir)?;
+        Ok(format!("{}/{}", root, dir))
+    }
+
+    /// 校验备份清单或快照中的文件路径，须为不含`..`的相对路径，以免写出目标目录
+    pub fn check_path(path: &str) -> GeorgeResult<()> {
+        let path_buf = Path::new(path);
+        if path.is_empty()
+            || !path_buf
+                .components()
+                .all(|component| matches!(component, Component::Normal(_)))
+        {
+            return Err(Errs::string(format!(
+                "path {} must be relative and without '..'!",
+                path
+            )));
+        }
+        Ok(())
+    }
+
+    /// 读取备份目录中的备份清单
+    pub fn manifest(dir: String) -> GeorgeResult<BackupManifest> {
+        let bytes = Filer::read_bytes(format!("{}/{}", dir, BACKUP_MANIFEST))?;
+        BackupManifest::from_slice(bytes.as_slice())
+    }
+}
+
+/// 读取并解压备份文件，内容须与备份清单中记录的长度及摘要一致
+fn load(dir: &str, manifest: &BackupManifest, file: &BackupFile) -> GeorgeResult<Vec<u8>> {
+    Backup::check_path(&file.path)?;
+    let content = if manifest.compress {
+        let mut content = vec![];
+        let compressed =
+            Filer::read_bytes(format!("{}/{}{}", dir, file.path, BACKUP_COMPRESS_SUFFIX))?;
+        match GzDecoder::new(compressed.as_slice()).read_to_end(&mut content) {
+            Ok(_) => content,
+            Err(err) => return Err(Errs::strs("backup file decompress", err)),
+        }
+    } else {
+        Filer::read_bytes(format!("{}/{}", dir, file.path))?
+    };
+    if content.len() as u64 != file.size || Hash::sha256(content.clone()).ne(&file.sha256) {
+        return Err(Errs::string(format!(
+            "backup file {} checksum mismatch!",
+            file.path
+        )));
+    }
+    Ok(content)
+}
+
+/// 目录须为空目录或不存在
+fn empty_check(dir: &str, action: &str) -> GeorgeResult<()> {
+    match fs::read_dir(dir) {
+        Ok(mut res) => {
+            if res.next().is_some() {
+                Err(Errs::string(format!(
+                    "{} dir {} is not empty!",
+                    action, dir
+                )))
+            } else {
+                Ok(())
+            }
+        }
+        Err(_) => Ok(()),
+    }
+}
+
+/// 递归获取目录下所有文件路径
+fn walk(dir: String, filepaths: &mut Vec<String>) -> GeorgeResult<()> {
+    let entries = match fs::read_dir(&dir) {
+        Ok(res) => res,
+        Err(err) => return Err(Errs::strs("backup read dir", err)),
+    };
+    for entry in entries {
+        let path = match entry {
+            Ok(res) => res.path(),
+            Err(err) => return Err(Errs::strs("backup read dir entry", err)),
+        };
+        let filepath = path.to_string_lossy().to_string();
+        if path.is_dir() {
+            walk(filepath, filepaths)?;
+        } else {
+            filepaths.push(filepath);
+        }
+    }
+    Ok(())
+}
+
+/// 将文件内容写入备份目录，返回备份文件信息
+fn store(
+    data_dir: &str,
+    dir: &str,
+    filepath: &str,
+    content: Vec<u8>,
+    compress: bool,
+) -> GeorgeResult<BackupFile> {
+    let path = match Path::new(filepath).strip_prefix(data_dir) {
+        Ok(res) => res.to_string_lossy().to_string(),
+        Err(err) => return Err(Errs::strs("backup file strip prefix", err)),
+    };
+    let file = BackupFile {
+        path: path.clone(),
+        size: content.len() as u64,
+        sha256: Hash::sha256(content.clone()),
+    };
+    if compress {
+        let mut encoder = GzEncoder::new(vec![], Compression::default());
+        let compressed = match encoder.write_all(content.as_slice()) {
+            Ok(()) => match encoder.finish() {
+                Ok(res) => res,
+                Err(err) => return Err(Errs::strs("backup file compress finish", err)),
+            },
+            Err(err) => return Err(Errs::strs("backup file compress", err)),
+        };
+        Filer::write_force(
+            format!("{}/{}{}", dir, path, BACKUP_COMPRESS_SUFFIX),
+            compressed,
+        )?;
+    } else {
+        Filer::write_force(format!("{}/{}", dir, path), content)?;
+    }
+    Ok(file)
+}
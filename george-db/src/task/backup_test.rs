@@ -0,0 +1,86 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[cfg(test)]
+mod backup {
+    use std::fs;
+
+    use george_comm::io::file::FilerHandler;
+    use george_comm::io::Filer;
+
+    use crate::task::traits::TMaster;
+    use crate::task::Backup;
+    use crate::Task;
+
+    fn backup_and_restore(compress: bool) {
+        let task = Task::default().unwrap();
+        let database_name = "database_backup_test";
+        let view_name = "view_backup_test";
+        let _ = task.database_create(database_name.to_string(), "".to_string());
+        let _ = task.view_create(
+            database_name.to_string(),
+            view_name.to_string(),
+            "".to_string(),
+            true,
+        );
+        task.set_disk(
+            database_name.to_string(),
+            view_name.to_string(),
+            "backup".to_string(),
+            "backup".as_bytes().to_vec(),
+        )
+        .unwrap();
+        let dir = format!("src/test/backup/{}", compress);
+        let data_dir = format!("src/test/restore/{}", compress);
+        let _ = fs::remove_dir_all(&dir);
+        let _ = fs::remove_dir_all(&data_dir);
+        let manifest = task.snapshot(dir.clone(), compress).unwrap();
+        assert!(manifest
+            .files
+            .iter()
+            .any(|file| file.path.eq("bootstrap.ge")));
+        assert!(manifest
+            .files
+            .iter()
+            .any(|file| file.path.contains(view_name)));
+        // 备份目录非空时拒绝再次备份
+        assert!(task.snapshot(dir.clone(), compress).is_err());
+        let restored = Backup::restore(dir.clone(), data_dir.clone()).unwrap();
+        assert_eq!(restored.seq, manifest.seq);
+        for file in restored.files.iter() {
+            assert!(Filer::exist(format!("{}/{}", data_dir, file.path)));
+        }
+        // 数据存储路径已存在数据时拒绝恢复
+        assert!(Backup::restore(dir, data_dir).is_err());
+    }
+
+    #[test]
+    fn backup_path_check() {
+        assert!(Backup::check_path("data/database/view.ge").is_ok());
+        assert!(Backup::check_path("").is_err());
+        assert!(Backup::check_path("../secret.key").is_err());
+        assert!(Backup::check_path("data/../../secret.key").is_err());
+        assert!(Backup::check_path("/etc/passwd").is_err());
+        // 未配置备份根路径时拒绝备份
+        let task = Task::default().unwrap();
+        assert!(task.backup("backup".to_string(), false).is_err());
+    }
+
+    /// 同一时刻仅允许一个备份，两种方式依次执行
+    #[test]
+    fn backup_restore() {
+        backup_and_restore(false);
+        backup_and_restore(true);
+    }
+}
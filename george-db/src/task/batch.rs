@@ -0,0 +1,245 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use george_comm::errors::{Errs, GeorgeResult};
+
+use crate::task::traits::TMaster;
+use crate::task::Batch;
+use crate::utils::comm::INDEX_DISK;
+use crate::Task;
+
+/// 批量写入结果中最多保留的失败数据项数
+const BATCH_ERRORS_MAX: usize = 1000;
+
+/// 批量写入方式
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BatchOp {
+    /// 插入数据，如果存在则该数据项失败
+    Put,
+    /// 插入数据，无论存在与否都会插入或更新数据
+    Set,
+    /// 删除数据
+    Remove,
+}
+
+/// 批量写入目标
+#[derive(Debug, Clone)]
+pub enum BatchTarget {
+    /// 磁盘存储的库及视图
+    Disk {
+        database_name: String,
+        view_name: String,
+    },
+    /// 内存存储的缓存页
+    Memory { page_name: String },
+}
+
+/// 数据项写入前的状态，全部成功或全部失败的批量写入据此回滚
+#[derive(Debug, Clone)]
+enum Undo {
+    /// 写入前不存在，回滚时删除
+    Absent(String),
+    /// 写入前的存储值，即视图加密后的结果，回滚时原样恢复而不再次加密
+    Present(String, Vec<u8>),
+}
+
+/// 批量写入结果
+#[derive(Debug, Clone, Default)]
+pub struct BatchReport {
+    /// 处理数据项数
+    pub total: u64,
+    /// 写入成功数
+    pub succeeded: u64,
+    /// 写入失败数
+    pub failed: u64,
+    /// 已写入的数据项是否已回滚
+    pub rolled_back: bool,
+    /// 写入失败的数据项序号、键及错误信息，最多保留`BATCH_ERRORS_MAX`条
+    pub errors: Vec<(u64, String, String)>,
+    /// 已写入数据项写入前的状态，仅全部成功或全部失败的批量写入记录
+    undo: Vec<Undo>,
+}
+
+impl BatchTarget {
+    /// 配额校验的库名称，缓存页为空
+    pub(crate) fn database_name(&self) -> String {
+        match self {
+            BatchTarget::Disk { database_name, .. } => database_name.clone(),
+            BatchTarget::Memory { .. } => "".to_string(),
+        }
+    }
+
+    /// 写入前的存储值，视图中为加密后的结果，不存在时为None，其它读取错误直接返回
+    fn stored(&self, task: &Task, key: String) -> GeorgeResult<Option<Vec<u8>>> {
+        let result = match self {
+            BatchTarget::Disk {
+                database_name,
+                view_name,
+            } => task
+                .view(database_name.clone(), view_name.clone())?
+                .read()
+                .unwrap()
+                .get(INDEX_DISK, key),
+            BatchTarget::Memory { page_name } => task.get_memory(page_name.clone(), key),
+        };
+        match result {
+            Ok(res) => Ok(Some(res)),
+            Err(err) if Errs::data_absent(&err) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// 恢复写入前的存储值，视图中不再重复加密
+    fn restore(&self, task: &Task, key: String, stored: Vec<u8>) -> GeorgeResult<()> {
+        match self {
+            BatchTarget::Disk {
+                database_name,
+                view_name,
+            } => task.commit_disk(database_name.clone(), view_name.clone(), key, stored, true),
+            BatchTarget::Memory { page_name } => task.set_memory(page_name.clone(), key, stored),
+        }
+    }
+
+    fn put(&self, master: &Task, key: String, value: Vec<u8>) -> GeorgeResult<()> {
+        match self {
+            BatchTarget::Disk {
+                database_name,
+                view_name,
+            } => master.put_disk(database_name.clone(), view_name.clone(), key, value),
+            BatchTarget::Memory { page_name } => master.put_memory(page_name.clone(), key, value),
+        }
+    }
+
+    fn set(&self, master: &Task, key: String, value: Vec<u8>) -> GeorgeResult<()> {
+        match self {
+            BatchTarget::Disk {
+                database_name,
+                view_name,
+            } => master.set_disk(database_name.clone(), view_name.clone(), key, value),
+            BatchTarget::Memory { page_name } => master.set_memory(page_name.clone(), key, value),
+        }
+    }
+
+    fn remove(&self, master: &Task, key: String) -> GeorgeResult<()> {
+        match self {
+            BatchTarget::Disk {
+                database_name,
+                view_name,
+            } => master.remove_disk(database_name.clone(), view_name.clone(), key),
+            BatchTarget::Memory { page_name } => master.remove_memory(page_name.clone(), key),
+        }
+    }
+}
+
+impl Batch {
+    /// 逐项写入一批数据，将结果累加至`report`
+    ///
+    /// 全部成功或全部失败时，任一数据项失败即停止写入，由调用方随后调用[`Batch::rollback`]
+    ///
+    /// ###Params
+    ///
+    /// * target 写入目标
+    /// * op 写入方式
+    /// * atomic 是否全部成功或全部失败
+    /// * items 键及值集合，删除时忽略值
+    /// * report 批量写入结果，多批次写入时累加
+    /// * permit 每个数据项写入前的配额校验，校验失败则该数据项失败
+    pub(crate) fn apply(
+        master: &Task,
+        target: &BatchTarget,
+        op: BatchOp,
+        atomic: bool,
+        items: Vec<(String, Vec<u8>)>,
+        report: &mut BatchReport,
+        permit: &mut dyn FnMut() -> GeorgeResult<()>,
+    ) {
+        for (key, value) in items {
+            if atomic && report.failed > 0 {
+                return;
+            }
+            let index = report.total;
+            report.total += 1;
+            match permit().and_then(|()| write(master, target, op, atomic, key.clone(), value)) {
+                Ok(undo) => {
+                    report.succeeded += 1;
+                    if let Some(undo) = undo {
+                        report.undo.push(undo)
+                    }
+                }
+                Err(err) => {
+                    report.failed += 1;
+                    if report.errors.len() < BATCH_ERRORS_MAX {
+                        report.errors.push((index, key, err.to_string()))
+                    }
+                }
+            }
+        }
+    }
+
+    /// 按写入的相反顺序恢复已写入数据项写入前的状态，恢复失败的数据项不中断回滚，返回首个恢复错误
+    pub(crate) fn rollback(
+        master: &Task,
+        target: &BatchTarget,
+        report: &mut BatchReport,
+    ) -> GeorgeResult<()> {
+        let mut result = Ok(());
+        while let Some(undo) = report.undo.pop() {
+            let restore = match undo {
+                Undo::Absent(key) => target.remove(master, key),
+                Undo::Present(key, stored) => target.restore(master, key, stored),
+            };
+            if let Err(err) = restore {
+                log::error!("batch rollback failed! error is {}", err);
+                if result.is_ok() {
+                    result = Err(Errs::strs("batch rollback", err));
+                }
+            }
+        }
+        report.succeeded = 0;
+        report.rolled_back = true;
+        result
+    }
+}
+
+/// 写入单个数据项，全部成功或全部失败时返回该数据项写入前的状态
+fn write(
+    master: &Task,
+    target: &BatchTarget,
+    op: BatchOp,
+    atomic: bool,
+    key: String,
+    value: Vec<u8>,
+) -> GeorgeResult<Option<Undo>> {
+    let before = if atomic && op != BatchOp::Put {
+        Some(target.stored(master, key.clone())?)
+    } else {
+        None
+    };
+    match op {
+        BatchOp::Put => target.put(master, key.clone(), value)?,
+        BatchOp::Set => target.set(master, key.clone(), value)?,
+        BatchOp::Remove => target.remove(master, key.clone())?,
+    }
+    if !atomic {
+        return Ok(None);
+    }
+    Ok(match (op, before) {
+        // 插入成功说明写入前不存在
+        (BatchOp::Put, _) => Some(Undo::Absent(key)),
+        (_, Some(Some(value))) => Some(Undo::Present(key, value)),
+        // 删除写入前不存在的数据不改变状态
+        (BatchOp::Remove, _) => None,
+        (_, _) => Some(Undo::Absent(key)),
+    })
+}
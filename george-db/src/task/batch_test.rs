@@ -0,0 +1,184 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[cfg(test)]
+mod batch {
+    use crate::task::batch::{BatchOp, BatchReport, BatchTarget};
+    use crate::task::traits::TMaster;
+    use crate::Task;
+
+    fn items(kvs: Vec<(&str, &str)>) -> Vec<(String, Vec<u8>)> {
+        kvs.iter()
+            .map(|(key, value)| (key.to_string(), value.as_bytes().to_vec()))
+            .collect()
+    }
+
+    fn page(task: &Task, page_name: &str) -> BatchTarget {
+        let _ = task.page_remove(page_name.to_string());
+        task.page_create(page_name.to_string(), "".to_string(), 0, 0)
+            .unwrap();
+        BatchTarget::Memory {
+            page_name: page_name.to_string(),
+        }
+    }
+
+    fn get(task: &Task, page_name: &str, key: &str) -> Option<Vec<u8>> {
+        task.get_memory(page_name.to_string(), key.to_string()).ok()
+    }
+
+    #[test]
+    fn partial() {
+        let task = Task::default().unwrap();
+        let page_name = "page_batch_partial_test";
+        let target = page(&task, page_name);
+        let mut report = BatchReport::default();
+        task.batch(
+            &target,
+            BatchOp::Put,
+            false,
+            "batch_test".to_string(),
+            items(vec![("a", "1"), ("b", "2")]),
+            &mut report,
+        );
+        // 多批次写入时累加结果
+        task.batch(
+            &target,
+            BatchOp::Put,
+            false,
+            "batch_test".to_string(),
+            items(vec![("a", "3"), ("c", "4")]),
+            &mut report,
+        );
+        assert_eq!(report.total, 4);
+        assert_eq!(report.succeeded, 3);
+        assert_eq!(report.failed, 1);
+        assert_eq!(report.errors[0].0, 2);
+        assert_eq!(report.errors[0].1, "a");
+        assert_eq!(get(&task, page_name, "a"), Some("1".as_bytes().to_vec()));
+        assert_eq!(get(&task, page_name, "c"), Some("4".as_bytes().to_vec()));
+    }
+
+    #[test]
+    fn atomic() {
+        let task = Task::default().unwrap();
+        let page_name = "page_batch_atomic_test";
+        let target = page(&task, page_name);
+        task.put_memory(
+            page_name.to_string(),
+            "a".to_string(),
+            "1".as_bytes().to_vec(),
+        )
+        .unwrap();
+        let mut report = BatchReport::default();
+        task.batch(
+            &target,
+            BatchOp::Set,
+            true,
+            "batch_test".to_string(),
+            items(vec![("a", "2"), ("b", "3")]),
+            &mut report,
+        );
+        task.batch(
+            &target,
+            BatchOp::Put,
+            true,
+            "batch_test".to_string(),
+            items(vec![("c", "4"), ("a", "5"), ("d", "6")]),
+            &mut report,
+        );
+        // 失败后不再写入后续数据项
+        assert_eq!(report.total, 4);
+        assert_eq!(report.failed, 1);
+        assert!(get(&task, page_name, "c").is_some());
+        task.batch_rollback(&target, &mut report).unwrap();
+        assert!(report.rolled_back);
+        assert_eq!(report.succeeded, 0);
+        assert_eq!(get(&task, page_name, "a"), Some("1".as_bytes().to_vec()));
+        assert_eq!(get(&task, page_name, "b"), None);
+        assert_eq!(get(&task, page_name, "c"), None);
+        assert_eq!(get(&task, page_name, "d"), None);
+    }
+
+    #[test]
+    fn atomic_cipher() {
+        let task = Task::default().unwrap();
+        let database_name = "database_batch_cipher_test";
+        let view_name = "view_batch_cipher_test";
+        let _ = task.database_create(database_name.to_string(), "".to_string());
+        let _ = task.view_create(
+            database_name.to_string(),
+            view_name.to_string(),
+            "".to_string(),
+            false,
+        );
+        task.view_cipher(
+            database_name.to_string(),
+            view_name.to_string(),
+            vec!["phone".to_string()],
+            false,
+            vec!["admin".to_string()],
+        )
+        .unwrap();
+        task.set_disk(
+            database_name.to_string(),
+            view_name.to_string(),
+            "a".to_string(),
+            r#"{"phone":"13800000000"}"#.as_bytes().to_vec(),
+        )
+        .unwrap();
+        let get = |key: &str| {
+            task.get_disk(
+                database_name.to_string(),
+                view_name.to_string(),
+                key.to_string(),
+            )
+        };
+        let stored = get("a").unwrap();
+        let target = BatchTarget::Disk {
+            database_name: database_name.to_string(),
+            view_name: view_name.to_string(),
+        };
+        let mut report = BatchReport::default();
+        task.batch(
+            &target,
+            BatchOp::Set,
+            true,
+            "batch_test".to_string(),
+            items(vec![("a", r#"{"phone":"13900000000"}"#)]),
+            &mut report,
+        );
+        task.batch(
+            &target,
+            BatchOp::Remove,
+            true,
+            "batch_test".to_string(),
+            items(vec![("a", ""), ("b", "")]),
+            &mut report,
+        );
+        task.batch_rollback(&target, &mut report).unwrap();
+        // 恢复写入前的存储值，不再重复加密
+        assert_eq!(get("a").unwrap(), stored);
+        let revealed = task
+            .reveal_disk(
+                database_name.to_string(),
+                view_name.to_string(),
+                "admin".to_string(),
+                stored,
+            )
+            .unwrap();
+        let revealed: serde_json::Value = serde_json::from_slice(&revealed).unwrap();
+        assert_eq!(revealed["phone"], "13800000000");
+        assert!(get("b").is_err());
+    }
+}
@@ -0,0 +1,390 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use george_comm::cryptos::sm4::{SM4Crypt, SM4Handler};
+use george_comm::cryptos::SM4;
+use george_comm::errors::{Errs, GeorgeResult};
+use george_comm::io::file::{FilerHandler, FilerReader};
+use george_comm::io::Filer;
+use george_ge::utils::Snapshot;
+
+use crate::task::{Cipher, Secret, GLOBAL_SECRET};
+use crate::utils::Paths;
+
+/// 确定性加密密文前缀
+const CIPHER_DETERMINISTIC: &str = "sm4d:";
+/// 随机加密密文前缀
+const CIPHER_RANDOM: &str = "sm4r:";
+/// 无解密授权时字段脱敏结果
+const CIPHER_MASK: &str = "******";
+
+/// 视图字段加密策略文件内容
+#[derive(Serialize, Deserialize)]
+struct CipherFile {
+    fields: Vec<String>,
+    deterministic: bool,
+    readers: Vec<String>,
+    /// 以服务主密钥包装的密钥及向量
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    wrapped: Option<String>,
+    /// 明文保存的密钥，hex，见于早期版本及备份、快照中的策略文件
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    key: Option<String>,
+    /// 明文保存的向量，hex
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    iv: Option<String>,
+}
+
+impl Debug for Cipher {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Cipher")
+            .field("fields", &self.fields)
+            .field("deterministic", &self.deterministic)
+            .field("readers", &self.readers)
+            .finish()
+    }
+}
+
+impl Cipher {
+    /// 新建字段加密策略
+    ///
+    /// 密钥及向量由发起变更的节点确定并随变更日志传播，使各副本及重放得到相同的密钥
+    ///
+    /// ###Params
+    ///
+    /// * fields 加密字段集合，仅支持json顶层字段
+    /// * deterministic 是否确定性加密
+    /// * readers 可解密的操作人集合
+    /// * key `sm4`密钥，hex
+    /// * iv 确定性加密使用的`sm4`向量，hex
+    pub(crate) fn new(
+        fields: Vec<String>,
+        deterministic: bool,
+        readers: Vec<String>,
+        key: String,
+        iv: String,
+    ) -> GeorgeResult<Cipher> {
+        let cipher = Cipher {
+            fields,
+            deterministic,
+            readers,
+            key,
+            iv,
+        };
+        cipher.block(cipher.key.clone())?;
+        cipher.block(cipher.iv.clone())?;
+        Ok(cipher)
+    }
+
+    /// 随机生成`sm4`密钥及向量，hex
+    pub(crate) fn rand_key() -> (String, String) {
+        (
+            hex::encode(SM4::rand_block()),
+            hex::encode(SM4::rand_block()),
+        )
+    }
+
+    /// `sm4`密钥及向量，hex
+    pub(crate) fn key(&self) -> (String, String) {
+        (self.key.clone(), self.iv.clone())
+    }
+
+    /// 恢复视图字段加密策略，未设置策略则返回None
+    ///
+    /// 以明文保存密钥的策略文件，如早期版本或由备份及快照恢复的策略文件，在恢复后随即以本节点主密钥包装后重写
+    pub(crate) fn recovery(
+        database_name: String,
+        view_name: String,
+    ) -> GeorgeResult<Option<Cipher>> {
+        let filepath = Paths::view_cipher_filepath(database_name.clone(), view_name.clone());
+        if !Filer::exist(filepath.clone()) {
+            return Ok(None);
+        }
+        let (cipher, plain) = Cipher::load(Filer::read_bytes(filepath)?.as_slice(), &secret()?)?;
+        if plain {
+            cipher.save(database_name, view_name)?;
+        }
+        Ok(Some(cipher))
+    }
+
+    /// 持久化视图字段加密策略，密钥及向量以服务主密钥包装后保存
+    pub(crate) fn save(&self, database_name: String, view_name: String) -> GeorgeResult<()> {
+        Snapshot::write_force(
+            Paths::view_cipher_filepath(database_name, view_name),
+            self.file(&secret()?)?,
+        )?;
+        Ok(())
+    }
+
+    /// 将策略文件内容转为以明文保存密钥的格式，供备份及快照在其它节点恢复
+    ///
+    /// 备份及快照因此包含视图加密密钥，需与数据同等妥善保管
+    pub(crate) fn portable(content: Vec<u8>) -> GeorgeResult<Vec<u8>> {
+        let (cipher, _) = Cipher::load(content.as_slice(), &secret()?)?;
+        to_json(&CipherFile {
+            fields: cipher.fields,
+            deterministic: cipher.deterministic,
+            readers: cipher.readers,
+            wrapped: None,
+            key: Some(cipher.key),
+            iv: Some(cipher.iv),
+        })
+    }
+
+    /// 解析策略文件内容，返回策略及密钥是否以明文保存
+    ///
+    /// 包装后的密钥以`secret`解包
+    pub(crate) fn load(content: &[u8], secret: &Secret) -> GeorgeResult<(Cipher, bool)> {
+        let file: CipherFile = match serde_json::from_slice(content) {
+            Ok(res) => res,
+            Err(err) => return Err(Errs::strs("cipher from json", err)),
+        };
+        let (key, iv, plain) = match (file.wrapped, file.key, file.iv) {
+            (Some(wrapped), _, _) => {
+                let bytes = secret.unwrap(wrapped.as_str())?;
+                if bytes.len() != 32 {
+                    return Err(Errs::str("cipher wrapped key length must be 32!"));
+                }
+                (hex::encode(&bytes[..16]), hex::encode(&bytes[16..]), false)
+            }
+            (None, Some(key), Some(iv)) => (key, iv, true),
+            _ => return Err(Errs::str("cipher key is not exist!")),
+        };
+        let cipher = Cipher::new(file.fields, file.deterministic, file.readers, key, iv)?;
+        Ok((cipher, plain))
+    }
+
+    /// 策略文件内容，密钥及向量以`secret`包装
+    pub(crate) fn file(&self, secret: &Secret) -> GeorgeResult<Vec<u8>> {
+        let mut bytes = self.block(self.key.clone())?.to_vec();
+        bytes.extend_from_slice(&self.block(self.iv.clone())?);
+        to_json(&CipherFile {
+            fields: self.fields.clone(),
+            deterministic: self.deterministic,
+            readers: self.readers.clone(),
+            wrapped: Some(secret.wrap(bytes.as_slice())?),
+            key: None,
+            iv: None,
+        })
+    }
+
+    /// 加密字段集合
+    pub fn fields(&self) -> Vec<String> {
+        self.fields.clone()
+    }
+
+    /// 是否确定性加密
+    pub fn deterministic(&self) -> bool {
+        self.deterministic
+    }
+
+    /// 可解密的操作人集合
+    pub fn readers(&self) -> Vec<String> {
+        self.readers.clone()
+    }
+
+    /// 加密写入数据中的指定字段，非json对象数据原样返回
+    pub(crate) fn encrypt(&self, value: Vec<u8>) -> GeorgeResult<Vec<u8>> {
+        let mut map = match Cipher::object(&value) {
+            Some(res) => res,
+            None => return Ok(value),
+        };
+        for field in self.fields.iter() {
+            if let Some(val) = map.get_mut(field) {
+                if !val.is_null() {
+                    *val = Value::String(self.encrypt_value(val)?);
+                }
+            }
+        }
+        Cipher::bytes(map)
+    }
+
+    /// 按读取人授权处理读取结果，有解密授权则解密指定字段，否则将指定字段脱敏
+    ///
+    /// `principal`须为经登录令牌认证的操作人，匿名请求总是获取脱敏结果
+    pub(crate) fn reveal(&self, principal: &str, value: Vec<u8>) -> GeorgeResult<Vec<u8>> {
+        let mut map = match Cipher::object(&value) {
+            Some(res) => res,
+            None => return Ok(value),
+        };
+        let granted = self.readers.iter().any(|reader| reader.eq(principal));
+        for field in self.fields.iter() {
+            if let Some(val) = map.get_mut(field) {
+                if val.is_null() {
+                    continue;
+                }
+                if !granted {
+                    *val = Value::String(CIPHER_MASK.to_string());
+                } else if let Value::String(text) = val {
+                    // 策略生效前写入的明文数据原样返回
+                    if let Some(res) = self.decrypt_value(text)? {
+                        *val = res
+                    }
+                }
+            }
+        }
+        Cipher::bytes(map)
+    }
+
+    /// 将等值检索的索引值转为密文，使确定性加密字段上的等值索引可用
+    ///
+    /// 非加密字段原样返回
+    pub(crate) fn index_key(&self, index_name: &str, key: String) -> GeorgeResult<String> {
+        if !self.fields.iter().any(|field| field.eq(index_name)) {
+            return Ok(key);
+        }
+        if !self.deterministic {
+            return Err(Errs::string(format!(
+                "field {} is encrypted without deterministic and can not be used as index!",
+                index_name
+            )));
+        }
+        let value = match serde_json::from_str(key.as_str()) {
+            Ok(res) => res,
+            Err(_) => Value::String(key),
+        };
+        Ok(format!("{}", Value::String(self.encrypt_value(&value)?)))
+    }
+
+    /// 将检索条件中作用于加密字段的条件值转为密文
+    ///
+    /// 加密字段仅在确定性加密下支持`eq`及`ne`条件
+    pub(crate) fn constraint(&self, constraint_json_bytes: Vec<u8>) -> GeorgeResult<Vec<u8>> {
+        let mut value: Value = match serde_json::from_slice(constraint_json_bytes.as_slice()) {
+            Ok(res) => res,
+            // 交由检索本身报告解析错误
+            Err(_) => return Ok(constraint_json_bytes),
+        };
+        if let Some(conditions) = value["Conditions"].as_array_mut() {
+            for condition in conditions.iter_mut() {
+                let param = match condition["Param"].as_str() {
+                    Some(res) => res.to_string(),
+                    None => continue,
+                };
+                if !self.fields.iter().any(|field| field.eq(&param)) {
+                    continue;
+                }
+                let cond = condition["Cond"].as_str().unwrap_or("");
+                if !self.deterministic || (cond.ne("eq") && cond.ne("ne")) {
+                    return Err(Errs::string(format!(
+                        "encrypted field {} only support eq and ne conditions with deterministic!",
+                        param
+                    )));
+                }
+                let cipher_text = self.encrypt_value(&condition["Value"])?;
+                condition["Value"] = Value::String(cipher_text);
+            }
+        }
+        match serde_json::to_vec(&value) {
+            Ok(res) => Ok(res),
+            Err(err) => Err(Errs::strs("constraint to json", err)),
+        }
+    }
+
+    /// 加密字段值，确定性加密使用策略固定向量，否则每次随机生成向量并随密文保存
+    fn encrypt_value(&self, value: &Value) -> GeorgeResult<String> {
+        let text = match serde_json::to_vec(value) {
+            Ok(res) => res,
+            Err(err) => return Err(Errs::strs("cipher value to json", err)),
+        };
+        let key = self.block(self.key.clone())?;
+        if self.deterministic {
+            let iv = self.block(self.iv.clone())?;
+            Ok(format!(
+                "{}{}",
+                CIPHER_DETERMINISTIC,
+                hex::encode(SM4::encrypt(key, iv, text.as_slice()))
+            ))
+        } else {
+            let iv = SM4::rand_block();
+            Ok(format!(
+                "{}{}{}",
+                CIPHER_RANDOM,
+                hex::encode(iv),
+                hex::encode(SM4::encrypt(key, iv, text.as_slice()))
+            ))
+        }
+    }
+
+    /// 解密字段值，非本策略生成的密文返回None
+    fn decrypt_value(&self, text: &str) -> GeorgeResult<Option<Value>> {
+        let key = self.block(self.key.clone())?;
+        let (iv, data) = if let Some(res) = text.strip_prefix(CIPHER_DETERMINISTIC) {
+            (self.block(self.iv.clone())?, res)
+        } else if let Some(res) = text.strip_prefix(CIPHER_RANDOM) {
+            if res.len() < 32 {
+                return Ok(None);
+            }
+            (self.block(res[..32].to_string())?, &res[32..])
+        } else {
+            return Ok(None);
+        };
+        let data = match hex::decode(data) {
+            Ok(res) => res,
+            Err(_) => return Ok(None),
+        };
+        match serde_json::from_slice(SM4::decrypt(key, iv, data.as_slice()).as_slice()) {
+            Ok(res) => Ok(Some(res)),
+            Err(err) => Err(Errs::strs("cipher value decrypt", err)),
+        }
+    }
+
+    /// 将hex字符串还原为16字节块
+    fn block(&self, hex_str: String) -> GeorgeResult<[u8; 16]> {
+        let bytes = match hex::decode(hex_str) {
+            Ok(res) => res,
+            Err(err) => return Err(Errs::strs("cipher block decode", err)),
+        };
+        if bytes.len() != 16 {
+            return Err(Errs::str("cipher block length must be 16!"));
+        }
+        let mut block: [u8; 16] = [0; 16];
+        block.copy_from_slice(bytes.as_slice());
+        Ok(block)
+    }
+
+    fn object(value: &Vec<u8>) -> Option<Map<String, Value>> {
+        match serde_json::from_slice(value.as_slice()) {
+            Ok(Value::Object(res)) => Some(res),
+            _ => None,
+        }
+    }
+
+    fn bytes(map: Map<String, Value>) -> GeorgeResult<Vec<u8>> {
+        match serde_json::to_vec(&Value::Object(map)) {
+            Ok(res) => Ok(res),
+            Err(err) => Err(Errs::strs("cipher value to json", err)),
+        }
+    }
+}
+
+fn to_json(file: &CipherFile) -> GeorgeResult<Vec<u8>> {
+    match serde_json::to_vec(file) {
+        Ok(res) => Ok(res),
+        Err(err) => Err(Errs::strs("cipher to json", err)),
+    }
+}
+
+fn secret() -> GeorgeResult<Arc<Secret>> {
+    match GLOBAL_SECRET.get() {
+        Some(res) => Ok(res.clone()),
+        None => Err(Errs::str("secret key is not recovery yet!")),
+    }
+}
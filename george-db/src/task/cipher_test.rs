@@ -0,0 +1,335 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[cfg(test)]
+mod cipher {
+    use std::fs;
+
+    use serde_json::Value;
+
+    use george_comm::io::file::FilerReader;
+    use george_comm::io::Filer;
+
+    use crate::task::journal::MutationOp;
+    use crate::task::traits::TMaster;
+    use crate::task::{Backup, Cipher, Secret};
+    use crate::utils::enums::{Engine, KeyType};
+    use crate::utils::Paths;
+    use crate::Task;
+
+    fn field(value: Vec<u8>, name: &str) -> Value {
+        let value: Value = serde_json::from_slice(value.as_slice()).unwrap();
+        value[name].clone()
+    }
+
+    #[test]
+    fn encrypt_and_reveal() {
+        let plain = r#"{"name":"george","phone":"13800000000","age":18}"#
+            .as_bytes()
+            .to_vec();
+
+        let (key, iv) = Cipher::rand_key();
+        let cipher = Cipher::new(
+            vec!["phone".to_string()],
+            false,
+            vec!["admin".to_string()],
+            key.clone(),
+            iv.clone(),
+        )
+        .unwrap();
+        let value1 = cipher.encrypt(plain.clone()).unwrap();
+        let value2 = cipher.encrypt(plain.clone()).unwrap();
+        assert_ne!(
+            field(value1.clone(), "phone"),
+            field(plain.clone(), "phone")
+        );
+        assert_ne!(field(value1.clone(), "phone"), field(value2, "phone"));
+        assert_eq!(field(value1.clone(), "name"), "george");
+        let revealed = cipher.reveal("admin", value1.clone()).unwrap();
+        assert_eq!(field(revealed, "phone"), "13800000000");
+        let masked = cipher.reveal("guest", value1).unwrap();
+        assert_eq!(field(masked.clone(), "phone"), "******");
+        assert_eq!(field(masked, "age"), 18);
+        assert!(cipher
+            .index_key("phone", "\"13800000000\"".to_string())
+            .is_err());
+
+        let cipher = Cipher::new(
+            vec!["phone".to_string()],
+            true,
+            vec!["admin".to_string()],
+            key,
+            iv,
+        )
+        .unwrap();
+        let value1 = cipher.encrypt(plain.clone()).unwrap();
+        let value2 = cipher.encrypt(plain.clone()).unwrap();
+        assert_eq!(field(value1.clone(), "phone"), field(value2, "phone"));
+        assert_eq!(
+            format!("{}", field(value1, "phone")),
+            cipher
+                .index_key("phone", "\"13800000000\"".to_string())
+                .unwrap()
+        );
+        assert!(cipher
+            .constraint(
+                r#"{"Conditions":[{"Param":"phone","Cond":"gt","Value":"1"}]}"#
+                    .as_bytes()
+                    .to_vec()
+            )
+            .is_err());
+
+        // 非json对象数据不做处理
+        let raw = "13800000000".as_bytes().to_vec();
+        assert_eq!(cipher.encrypt(raw.clone()).unwrap(), raw);
+    }
+
+    #[test]
+    fn view_cipher() {
+        let task = Task::default().unwrap();
+        let database_name = "database_cipher_test";
+        let view_name = "view_cipher_test";
+        let _ = task.database_create(database_name.to_string(), "".to_string());
+        let _ = task.view_create(
+            database_name.to_string(),
+            view_name.to_string(),
+            "".to_string(),
+            false,
+        );
+        let _ = task.index_create(
+            database_name.to_string(),
+            view_name.to_string(),
+            "phone".to_string(),
+            Engine::Disk,
+            KeyType::String,
+            false,
+            true,
+            false,
+        );
+        task.view_cipher(
+            database_name.to_string(),
+            view_name.to_string(),
+            vec!["phone".to_string()],
+            true,
+            vec!["admin".to_string()],
+        )
+        .unwrap();
+        task.set_disk(
+            database_name.to_string(),
+            view_name.to_string(),
+            "george".to_string(),
+            r#"{"name":"george","phone":"13800000000"}"#.as_bytes().to_vec(),
+        )
+        .unwrap();
+
+        let value = task
+            .get_disk(
+                database_name.to_string(),
+                view_name.to_string(),
+                "george".to_string(),
+            )
+            .unwrap();
+        assert_ne!(field(value.clone(), "phone"), "13800000000");
+        let revealed = task
+            .reveal_disk(
+                database_name.to_string(),
+                view_name.to_string(),
+                "admin".to_string(),
+                value.clone(),
+            )
+            .unwrap();
+        assert_eq!(field(revealed, "phone"), "13800000000");
+        let masked = task
+            .reveal_disk(
+                database_name.to_string(),
+                view_name.to_string(),
+                "guest".to_string(),
+                value.clone(),
+            )
+            .unwrap();
+        assert_eq!(field(masked, "phone"), "******");
+
+        let value_by_index = task
+            .get_disk_by_index(
+                database_name.to_string(),
+                view_name.to_string(),
+                "phone".to_string(),
+                "\"13800000000\"".to_string(),
+            )
+            .unwrap();
+        assert_eq!(value, value_by_index);
+
+        let expectation = task
+            .select_disk(
+                database_name.to_string(),
+                view_name.to_string(),
+                r#"{"Conditions":[{"Param":"phone","Cond":"eq","Value":"13800000000"}]}"#
+                    .as_bytes()
+                    .to_vec(),
+            )
+            .unwrap();
+        assert_eq!(expectation.values.len(), 1);
+
+        // 策略文件中仅保存包装后的密钥，恢复后仍可解密
+        let cipher = Cipher::recovery(database_name.to_string(), view_name.to_string())
+            .unwrap()
+            .unwrap();
+        let file = Filer::read(Paths::view_cipher_filepath(
+            database_name.to_string(),
+            view_name.to_string(),
+        ))
+        .unwrap();
+        assert!(!file.contains(cipher.key.as_str()));
+        assert!(!file.contains(cipher.iv.as_str()));
+        assert_eq!(
+            field(cipher.reveal("admin", value).unwrap(), "phone"),
+            "13800000000"
+        );
+    }
+
+    fn encrypted_view(task: &Task, database_name: &str, view_name: &str) {
+        let _ = task.database_create(database_name.to_string(), "".to_string());
+        let _ = task.view_create(
+            database_name.to_string(),
+            view_name.to_string(),
+            "".to_string(),
+            false,
+        );
+        task.view_cipher(
+            database_name.to_string(),
+            view_name.to_string(),
+            vec!["phone".to_string()],
+            false,
+            vec!["admin".to_string()],
+        )
+        .unwrap();
+        task.set_disk(
+            database_name.to_string(),
+            view_name.to_string(),
+            "george".to_string(),
+            r#"{"name":"george","phone":"13800000000"}"#.as_bytes().to_vec(),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn journal_key() {
+        let task = Task::default().unwrap();
+        let database_name = "database_cipher_journal_test";
+        let view_name = "view_cipher_journal_test";
+        encrypted_view(&task, database_name, view_name);
+        let key = task
+            .view(database_name.to_string(), view_name.to_string())
+            .unwrap()
+            .read()
+            .unwrap()
+            .cipher()
+            .unwrap()
+            .key();
+
+        // 副本及重放以变更中的密钥设置策略，得到与发起节点相同的密钥
+        let other_view_name = "view_cipher_journal_other_test";
+        let _ = task.view_create(
+            database_name.to_string(),
+            other_view_name.to_string(),
+            "".to_string(),
+            false,
+        );
+        MutationOp::ViewCipher {
+            database: database_name.to_string(),
+            name: other_view_name.to_string(),
+            fields: vec!["phone".to_string()],
+            deterministic: false,
+            readers: vec!["admin".to_string()],
+            key: key.0.clone(),
+            iv: key.1.clone(),
+        }
+        .execute(&task)
+        .unwrap();
+        let other = task
+            .view(database_name.to_string(), other_view_name.to_string())
+            .unwrap()
+            .read()
+            .unwrap()
+            .cipher()
+            .unwrap();
+        assert_eq!(other.key(), key);
+        let value = task
+            .get_disk(
+                database_name.to_string(),
+                view_name.to_string(),
+                "george".to_string(),
+            )
+            .unwrap();
+        assert_eq!(
+            field(other.reveal("admin", value).unwrap(), "phone"),
+            "13800000000"
+        );
+    }
+
+    #[test]
+    fn restore_other_secret() {
+        let task = Task::default().unwrap();
+        let database_name = "database_cipher_restore_test";
+        let view_name = "view_cipher_restore_test";
+        encrypted_view(&task, database_name, view_name);
+        let dir = "src/test/backup/cipher";
+        let data_dir = "src/test/restore/cipher";
+        let _ = fs::remove_dir_all(dir);
+        let _ = fs::remove_dir_all(data_dir);
+        task.snapshot(dir.to_string(), false).unwrap();
+        Backup::restore(dir.to_string(), data_dir.to_string()).unwrap();
+
+        // 以不同主密钥的节点恢复策略文件，仍可解密备份中的数据
+        let other = Secret { key: vec![7; 32] };
+        let content = Filer::read_bytes(format!(
+            "{}/data/database/{}/{}/cipher.json",
+            data_dir, database_name, view_name
+        ))
+        .unwrap();
+        let (cipher, plain) = Cipher::load(content.as_slice(), &other).unwrap();
+        assert!(plain);
+        let value = task
+            .get_disk(
+                database_name.to_string(),
+                view_name.to_string(),
+                "george".to_string(),
+            )
+            .unwrap();
+        assert_eq!(
+            field(cipher.reveal("admin", value).unwrap(), "phone"),
+            "13800000000"
+        );
+
+        // 恢复后以该节点主密钥重新包装，其它主密钥无法解包
+        let rewrapped = cipher.file(&other).unwrap();
+        let (cipher, plain) = Cipher::load(rewrapped.as_slice(), &other).unwrap();
+        assert!(!plain);
+        let local = Filer::read_bytes(Paths::view_cipher_filepath(
+            database_name.to_string(),
+            view_name.to_string(),
+        ))
+        .unwrap();
+        assert!(Cipher::load(local.as_slice(), &other).is_err());
+        assert_eq!(
+            cipher.key(),
+            Cipher::recovery(database_name.to_string(), view_name.to_string())
+                .unwrap()
+                .unwrap()
+                .key()
+        );
+        let _ = fs::remove_dir_all(dir);
+        let _ = fs::remove_dir_all(data_dir);
+    }
+}
@@ -219,9 +219,10 @@ impl TNode for Node {
         limit: u64,
         delete: bool,
         conditions: Vec<Condition>,
-    ) -> GeorgeResult<(u64, u64, Vec<Vec<u8>>)> {
+        sink: &mut dyn FnMut(Vec<u8>) -> GeorgeResult<()>,
+    ) -> GeorgeResult<(u64, u64)> {
         if left {
-            let (_, _, total, count, values) = self.left_query(
+            let (_, _, total, count) = self.left_query(
                 self.node_bytes(),
                 1,
                 start,
@@ -230,10 +231,11 @@ impl TNode for Node {
                 skip,
                 limit,
                 delete,
+                sink,
             )?;
-            Ok((total, count, values))
+            Ok((total, count))
         } else {
-            let (_, _, total, count, values) = self.right_query(
+            let (_, _, total, count) = self.right_query(
                 self.node_bytes(),
                 1,
                 start,
@@ -242,8 +244,9 @@ impl TNode for Node {
                 skip,
                 limit,
                 delete,
+                sink,
             )?;
-            Ok((total, count, values))
+            Ok((total, count))
         }
     }
 }
@@ -811,6 +814,7 @@ impl Node {
     /// * skip 结果集跳过数量
     /// * limit 结果集限制数量
     /// * delete 是否删除检索结果
+    /// * sink 检索结果回调，返回错误时终止检索
     ///
     /// ###Return
     ///
@@ -818,7 +822,6 @@ impl Node {
     /// * limit 结果集限制数量
     /// * total 检索过程中遍历的总条数（也表示文件读取次数，文件描述符次数远小于该数，一般文件描述符数为1，即共用同一文件描述符）
     /// * count 检索结果过程中遍历的总条数
-    /// * values 检索结果集合
     fn left_query(
         &self,
         node_bytes: Vec<u8>,
@@ -829,10 +832,10 @@ impl Node {
         mut skip: u64,
         mut limit: u64,
         delete: bool,
-    ) -> GeorgeResult<(u64, u64, u64, u64, Vec<Vec<u8>>)> {
+        sink: &mut dyn FnMut(Vec<u8>) -> GeorgeResult<()>,
+    ) -> GeorgeResult<(u64, u64, u64, u64)> {
         let mut total: u64 = 0;
         let mut count: u64 = 0;
-        let mut values: Vec<Vec<u8>> = vec![];
 
         // 如果当前层高为7，则达到最底层，否则递归下一层逻辑
         if level == 7 {
@@ -846,18 +849,18 @@ impl Node {
                 if Vector::is_fill(res.clone()) {
                     // 索引执行插入真实坐标
                     let record_seek = Trans::bytes_2_u48(res)?;
-                    let (s, l, t, c, mut v) = self.record_view_info_seek_valid(
+                    let (s, l, t, c) = self.record_view_info_seek_valid(
                         record_seek,
                         conditions.clone(),
                         skip,
                         limit,
                         delete,
+                        sink,
                     )?;
                     skip = s;
                     limit = l;
                     total += t;
                     count += c;
-                    values.append(&mut v);
                     // 判断是否已经达到limit要求，如果达到要求，则直接返回数据，否则进入循环查询
                     if limit <= 0 {
                         break;
@@ -889,21 +892,21 @@ impl Node {
                 if Vector::is_fill(record_seek_bytes.clone()) {
                     // 索引执行插入真实坐标
                     let record_seek = Trans::bytes_2_u48(record_seek_bytes)?;
-                    let (s, l, t, c, mut v) = self.record_view_info_seek_valid(
+                    let (s, l, t, c) = self.record_view_info_seek_valid(
                         record_seek,
                         conditions.clone(),
                         skip,
                         limit,
                         delete,
+                        sink,
                     )?;
                     skip = s;
                     limit = l;
                     total += t;
                     count += c;
-                    values.append(&mut v);
                     // 判断是否已经达到limit要求，如果达到要求，则直接返回数据，否则进入循环查询
                     if limit <= 0 {
-                        return Ok((skip, limit, total, count, values));
+                        return Ok((skip, limit, total, count));
                     }
                 }
             }
@@ -925,7 +928,7 @@ impl Node {
                     Vector::sub_last(node_bytes.clone(), next_node_start, 8)?;
                 // 如果存在坐标值，则继续，否则新建
                 if Vector::is_fill(next_node_seek_bytes.clone()) {
-                    let (s, l, t, c, mut v) = self.next_left_query(
+                    let (s, l, t, c) = self.next_left_query(
                         level + 1,
                         next_start_key,
                         next_end_key,
@@ -935,15 +938,15 @@ impl Node {
                         skip,
                         limit,
                         delete,
+                        sink,
                     )?;
                     skip = s;
                     limit = l;
                     total += t;
                     count += c;
-                    values.append(&mut v);
                     // 判断是否已经达到limit要求，如果达到要求，则直接返回数据，否则进入循环查询
                     if limit == 0 {
-                        return Ok((skip, limit, total, count, values));
+                        return Ok((skip, limit, total, count));
                     }
                 }
             } else {
@@ -960,7 +963,7 @@ impl Node {
                     Vector::sub_last(node_bytes.clone(), next_node_start, 8)?;
                 // 如果存在坐标值，则继续，否则新建
                 if Vector::is_fill(next_node_seek_bytes.clone()) {
-                    let (s, l, t, c, mut v) = self.next_left_query(
+                    let (s, l, t, c) = self.next_left_query(
                         level + 1,
                         next_start_key,
                         0,
@@ -970,15 +973,15 @@ impl Node {
                         skip,
                         limit,
                         delete,
+                        sink,
                     )?;
                     skip = s;
                     limit = l;
                     total += t;
                     count += c;
-                    values.append(&mut v);
                     // 判断是否已经达到limit要求，如果达到要求，则直接返回数据，否则进入循环查询
                     if limit == 0 {
-                        return Ok((skip, limit, total, count, values));
+                        return Ok((skip, limit, total, count));
                     }
                 }
                 // 首次查询结束
@@ -994,7 +997,7 @@ impl Node {
                         Vector::sub_last(node_bytes.clone(), next_node_start, 8)?;
                     // 如果存在坐标值，则继续，否则新建
                     if Vector::is_fill(next_node_seek_bytes.clone()) {
-                        let (s, l, t, c, mut v) = self.next_left_query(
+                        let (s, l, t, c) = self.next_left_query(
                             level + 1,
                             0,
                             0,
@@ -1004,15 +1007,15 @@ impl Node {
                             skip,
                             limit,
                             delete,
+                            sink,
                         )?;
                         skip = s;
                         limit = l;
                         total += t;
                         count += c;
-                        values.append(&mut v);
                         // 判断是否已经达到limit要求，如果达到要求，则直接返回数据，否则进入循环查询
                         if limit == 0 {
-                            return Ok((skip, limit, total, count, values));
+                            return Ok((skip, limit, total, count));
                         }
                     }
                     // 待检查起始坐标递增1，继续下一轮循环左查询
@@ -1029,7 +1032,7 @@ impl Node {
                 let next_node_seek_bytes = Vector::sub_last(node_bytes.clone(), next_node_end, 8)?;
                 // 如果存在坐标值，则继续，否则新建
                 if Vector::is_fill(next_node_seek_bytes.clone()) {
-                    let (s, l, t, c, mut v) = self.next_left_query(
+                    let (s, l, t, c) = self.next_left_query(
                         level + 1,
                         0,
                         next_end_key,
@@ -1039,15 +1042,15 @@ impl Node {
                         skip,
                         limit,
                         delete,
+                        sink,
                     )?;
                     skip = s;
                     limit = l;
                     total += t;
                     count += c;
-                    values.append(&mut v);
                     // 判断是否已经达到limit要求，如果达到要求，则直接返回数据，否则进入循环查询
                     if limit == 0 {
-                        return Ok((skip, limit, total, count, values));
+                        return Ok((skip, limit, total, count));
                     }
                 }
                 // 末次查询结束
@@ -1066,22 +1069,22 @@ impl Node {
                 if Vector::is_fill(record_seek_bytes.clone()) {
                     // 索引执行插入真实坐标
                     let record_seek = Trans::bytes_2_u48(record_seek_bytes)?;
-                    let (s, l, t, c, mut v) = self.record_view_info_seek_valid(
+                    let (s, l, t, c) = self.record_view_info_seek_valid(
                         record_seek,
                         conditions.clone(),
                         skip,
                         limit,
                         delete,
+                        sink,
                     )?;
                     skip = s;
                     limit = l;
                     total += t;
                     count += c;
-                    values.append(&mut v);
                 }
             }
         }
-        Ok((skip, limit, total, count, values))
+        Ok((skip, limit, total, count))
     }
 
     /// 获取下一左查询结果数据集
@@ -1097,6 +1100,7 @@ impl Node {
     /// * skip 结果集跳过数量
     /// * limit 结果集限制数量
     /// * delete 是否删除检索结果
+    /// * sink 检索结果回调，返回错误时终止检索
     ///
     /// ###Return
     ///
@@ -1104,7 +1108,6 @@ impl Node {
     /// * limit 结果集限制数量
     /// * total 检索过程中遍历的总条数（也表示文件读取次数，文件描述符次数远小于该数，一般文件描述符数为1，即共用同一文件描述符）
     /// * count 检索结果过程中遍历的总条数
-    /// * values 检索结果集合
     fn next_left_query(
         &self,
         level: u8,
@@ -1116,15 +1119,15 @@ impl Node {
         mut skip: u64,
         mut limit: u64,
         delete: bool,
-    ) -> GeorgeResult<(u64, u64, u64, u64, Vec<Vec<u8>>)> {
+        sink: &mut dyn FnMut(Vec<u8>) -> GeorgeResult<()>,
+    ) -> GeorgeResult<(u64, u64, u64, u64)> {
         let mut total: u64 = 0;
         let mut count: u64 = 0;
-        let mut values: Vec<Vec<u8>> = vec![];
         // 下一结点的真实坐标
         let next_node_seek = Trans::bytes_2_u64(next_node_seek_bytes)?;
         // 下一结点字节数组
         let next_node_bytes = self.node_read(next_node_seek, disk_bytes_len)?;
-        let (s, l, t, c, mut v) = self.left_query(
+        let (s, l, t, c) = self.left_query(
             next_node_bytes,
             level,
             start,
@@ -1133,13 +1136,13 @@ impl Node {
             skip,
             limit,
             delete,
+            sink,
         )?;
         skip = s;
         limit = l;
         total += t;
         count += c;
-        values.append(&mut v);
-        Ok((skip, limit, total, count, values))
+        Ok((skip, limit, total, count))
     }
 
     /// 获取由view视图执行save操作时反写进record文件中value
@@ -1150,10 +1153,10 @@ impl Node {
         mut skip: u64,
         mut limit: u64,
         delete: bool,
-    ) -> GeorgeResult<(u64, u64, u64, u64, Vec<Vec<u8>>)> {
+        sink: &mut dyn FnMut(Vec<u8>) -> GeorgeResult<()>,
+    ) -> GeorgeResult<(u64, u64, u64, u64)> {
         let mut total: u64 = 0;
         let mut count: u64 = 0;
-        let mut values: Vec<Vec<u8>> = vec![];
 
         // 读取record中该坐标值
         // record存储固定长度的数据，长度为20，即view版本号(2字节) + view持续长度(4字节) + view偏移量(6字节) + 链式后续数据(8字节)
@@ -1169,7 +1172,7 @@ impl Node {
             if skip <= 0 {
                 limit -= 1;
                 count += 1;
-                values.push(value_bytes)
+                sink(value_bytes)?;
             } else {
                 skip -= 1;
             }
@@ -1188,16 +1191,21 @@ impl Node {
             if Vector::is_fill(record_next_seek_bytes.clone()) {
                 // 索引执行插入真实坐标
                 let record_seek = Trans::bytes_2_u64(record_next_seek_bytes)?;
-                let (s, l, t, c, mut v) =
-                    self.record_view_info_seek_valid(record_seek, conditions, skip, limit, delete)?;
+                let (s, l, t, c) = self.record_view_info_seek_valid(
+                    record_seek,
+                    conditions,
+                    skip,
+                    limit,
+                    delete,
+                    sink,
+                )?;
                 skip = s;
                 limit = l;
                 total += t;
                 count += c;
-                values.append(&mut v);
             }
         }
-        Ok((skip, limit, total, count, values))
+        Ok((skip, limit, total, count))
     }
 
     /// 根据查询起止条件获取待查询结果索引字节数组
@@ -1271,6 +1279,7 @@ impl Node {
     /// * skip 结果集跳过数量
     /// * limit 结果集限制数量
     /// * delete 是否删除检索结果
+    /// * sink 检索结果回调，返回错误时终止检索
     ///
     /// ###Return
     ///
@@ -1278,7 +1287,6 @@ impl Node {
     /// * limit 结果集限制数量
     /// * total 检索过程中遍历的总条数（也表示文件读取次数，文件描述符次数远小于该数，一般文件描述符数为1，即共用同一文件描述符）
     /// * count 检索结果过程中遍历的总条数
-    /// * values 检索结果集合
     fn right_query(
         &self,
         node_bytes: Vec<u8>,
@@ -1289,10 +1297,10 @@ impl Node {
         mut skip: u64,
         mut limit: u64,
         delete: bool,
-    ) -> GeorgeResult<(u64, u64, u64, u64, Vec<Vec<u8>>)> {
+        sink: &mut dyn FnMut(Vec<u8>) -> GeorgeResult<()>,
+    ) -> GeorgeResult<(u64, u64, u64, u64)> {
         let mut total: u64 = 0;
         let mut count: u64 = 0;
-        let mut values: Vec<Vec<u8>> = vec![];
 
         // 如果当前层高为7，则达到最底层，否则递归下一层逻辑
         if level == 7 {
@@ -1311,18 +1319,18 @@ impl Node {
                         if Vector::is_fill(res.clone()) {
                             // 索引执行插入真实坐标
                             let record_seek = Trans::bytes_2_u48(res.clone())?;
-                            let (s, l, t, c, mut v) = self.record_view_info_seek_valid(
+                            let (s, l, t, c) = self.record_view_info_seek_valid(
                                 record_seek,
                                 conditions.clone(),
                                 skip,
                                 limit,
                                 delete,
+                                sink,
                             )?;
                             skip = s;
                             limit = l;
                             total += t;
                             count += c;
-                            values.append(&mut v);
                         }
                     }
                     None => return Err(Errs::str("select bytes get none error")),
@@ -1353,18 +1361,18 @@ impl Node {
                 if Vector::is_fill(record_seek_bytes.clone()) {
                     // 索引执行插入真实坐标
                     let record_seek = Trans::bytes_2_u48(record_seek_bytes)?;
-                    let (s, l, t, c, mut v) = self.record_view_info_seek_valid(
+                    let (s, l, t, c) = self.record_view_info_seek_valid(
                         record_seek,
                         conditions.clone(),
                         skip,
                         limit,
                         delete,
+                        sink,
                     )?;
                     skip = s;
                     limit = l;
                     total += t;
                     count += c;
-                    values.append(&mut v);
                 }
             }
 
@@ -1385,7 +1393,7 @@ impl Node {
                     Vector::sub_last(node_bytes.clone(), next_node_start, 8)?;
                 // 如果存在坐标值，则继续，否则新建
                 if Vector::is_fill(next_node_seek_bytes.clone()) {
-                    let (s, l, t, c, mut v) = self.next_right_query(
+                    let (s, l, t, c) = self.next_right_query(
                         level + 1,
                         next_start_key,
                         next_end_key,
@@ -1395,15 +1403,15 @@ impl Node {
                         skip,
                         limit,
                         delete,
+                        sink,
                     )?;
                     skip = s;
                     limit = l;
                     total += t;
                     count += c;
-                    values.append(&mut v);
                     // 判断是否已经达到limit要求，如果达到要求，则直接返回数据，否则进入循环查询
                     if limit == 0 {
-                        return Ok((skip, limit, total, count, values));
+                        return Ok((skip, limit, total, count));
                     }
                 }
             } else {
@@ -1421,7 +1429,7 @@ impl Node {
                 let next_node_seek_bytes = Vector::sub_last(node_bytes.clone(), next_node_end, 8)?;
                 // 如果存在坐标值，则继续，否则新建
                 if Vector::is_fill(next_node_seek_bytes.clone()) {
-                    let (s, l, t, c, mut v) = self.next_right_query(
+                    let (s, l, t, c) = self.next_right_query(
                         level + 1,
                         0,
                         next_end_key,
@@ -1431,15 +1439,15 @@ impl Node {
                         skip,
                         limit,
                         delete,
+                        sink,
                     )?;
                     skip = s;
                     limit = l;
                     total += t;
                     count += c;
-                    values.append(&mut v);
                     // 判断是否已经达到limit要求，如果达到要求，则直接返回数据，否则进入循环查询
                     if limit <= 0 {
-                        return Ok((skip, limit, total, count, values));
+                        return Ok((skip, limit, total, count));
                     }
                 }
                 // 首次查询结束
@@ -1455,7 +1463,7 @@ impl Node {
                         Vector::sub_last(node_bytes.clone(), next_node_start, 8)?;
                     // 如果存在坐标值，则继续，否则新建
                     if Vector::is_fill(next_node_seek_bytes.clone()) {
-                        let (s, l, t, c, mut v) = self.next_right_query(
+                        let (s, l, t, c) = self.next_right_query(
                             level + 1,
                             0,
                             0,
@@ -1465,15 +1473,15 @@ impl Node {
                             skip,
                             limit,
                             delete,
+                            sink,
                         )?;
                         skip = s;
                         limit = l;
                         total += t;
                         count += c;
-                        values.append(&mut v);
                         // 判断是否已经达到limit要求，如果达到要求，则直接返回数据，否则进入循环查询
                         if limit == 0 {
-                            return Ok((skip, limit, total, count, values));
+                            return Ok((skip, limit, total, count));
                         }
                     }
                     // 待检查起始坐标递减1，继续下一轮循环右查询
@@ -1489,7 +1497,7 @@ impl Node {
                     Vector::sub_last(node_bytes.clone(), next_node_start, 8)?;
                 // 如果存在坐标值，则继续，否则新建
                 if Vector::is_fill(next_node_seek_bytes.clone()) {
-                    let (s, l, t, c, mut v) = self.next_right_query(
+                    let (s, l, t, c) = self.next_right_query(
                         level + 1,
                         next_start_key,
                         0,
@@ -1499,15 +1507,15 @@ impl Node {
                         skip,
                         limit,
                         delete,
+                        sink,
                     )?;
                     skip = s;
                     limit = l;
                     total += t;
                     count += c;
-                    values.append(&mut v);
                     // 判断是否已经达到limit要求，如果达到要求，则直接返回数据，否则进入循环查询
                     if limit == 0 {
-                        return Ok((skip, limit, total, count, values));
+                        return Ok((skip, limit, total, count));
                     }
                 }
                 // 末次查询结束
@@ -1524,26 +1532,26 @@ impl Node {
                 if Vector::is_fill(record_seek_bytes.clone()) {
                     // 索引执行插入真实坐标
                     let record_seek = Trans::bytes_2_u48(record_seek_bytes)?;
-                    let (s, l, t, c, mut v) = self.record_view_info_seek_valid(
+                    let (s, l, t, c) = self.record_view_info_seek_valid(
                         record_seek,
                         conditions.clone(),
                         skip,
                         limit,
                         delete,
+                        sink,
                     )?;
                     skip = s;
                     limit = l;
                     total += t;
                     count += c;
-                    values.append(&mut v);
                     // 判断是否已经达到limit要求，如果达到要求，则直接返回数据，否则进入循环查询
                     if limit <= 0 {
-                        return Ok((skip, limit, total, count, values));
+                        return Ok((skip, limit, total, count));
                     }
                 }
             }
         }
-        Ok((skip, limit, total, count, values))
+        Ok((skip, limit, total, count))
     }
 
     /// 获取下一左查询结果数据集
@@ -1559,6 +1567,7 @@ impl Node {
     /// * skip 结果集跳过数量
     /// * limit 结果集限制数量
     /// * delete 是否删除检索结果
+    /// * sink 检索结果回调，返回错误时终止检索
     ///
     /// ###Return
     ///
@@ -1566,7 +1575,6 @@ impl Node {
     /// * limit 结果集限制数量
     /// * total 检索过程中遍历的总条数（也表示文件读取次数，文件描述符次数远小于该数，一般文件描述符数为1，即共用同一文件描述符）
     /// * count 检索结果过程中遍历的总条数
-    /// * values 检索结果集合
     fn next_right_query(
         &self,
         level: u8,
@@ -1578,15 +1586,15 @@ impl Node {
         mut skip: u64,
         mut limit: u64,
         delete: bool,
-    ) -> GeorgeResult<(u64, u64, u64, u64, Vec<Vec<u8>>)> {
+        sink: &mut dyn FnMut(Vec<u8>) -> GeorgeResult<()>,
+    ) -> GeorgeResult<(u64, u64, u64, u64)> {
         let mut total: u64 = 0;
         let mut count: u64 = 0;
-        let mut values: Vec<Vec<u8>> = vec![];
         // 下一结点的真实坐标
         let next_node_seek = Trans::bytes_2_u64(next_node_seek_bytes)?;
         // 下一结点字节数组
         let next_node_bytes = self.node_read(next_node_seek, disk_bytes_len)?;
-        let (s, l, t, c, mut v) = self.right_query(
+        let (s, l, t, c) = self.right_query(
             next_node_bytes,
             level,
             start,
@@ -1595,12 +1603,12 @@ impl Node {
             skip,
             limit,
             delete,
+            sink,
         )?;
         skip = s;
         limit = l;
         total += t;
         count += c;
-        values.append(&mut v);
-        Ok((skip, limit, total, count, values))
+        Ok((skip, limit, total, count))
     }
 }
@@ -112,11 +112,12 @@ impl TNode for Node {
         limit: u64,
         delete: bool,
         conditions: Vec<Condition>,
-    ) -> GeorgeResult<(u64, u64, Vec<Vec<u8>>)> {
+        sink: &mut dyn FnMut(Vec<u8>) -> GeorgeResult<()>,
+    ) -> GeorgeResult<(u64, u64)> {
         if left {
-            self.left_query(start, end, conditions, skip, limit, delete)
+            self.left_query(start, end, conditions, skip, limit, delete, sink)
         } else {
-            self.right_query(start, end, conditions, skip, limit, delete)
+            self.right_query(start, end, conditions, skip, limit, delete, sink)
         }
     }
 }
@@ -206,13 +207,13 @@ impl Node {
     ///
     /// delete 是否删除检索结果
     ///
+    /// sink 检索结果回调，返回错误时终止检索
+    ///
     /// ###Return
     ///
     /// total 检索过程中遍历的总条数（也表示文件读取次数，文件描述符次数远小于该数，一般文件描述符数为1，即共用同一文件描述符）
     ///
     /// count 检索结果过程中遍历的总条数
-    ///
-    /// values 检索结果集合
     fn left_query(
         &self,
         start: u64,
@@ -221,10 +222,10 @@ impl Node {
         mut skip: u64,
         mut limit: u64,
         delete: bool,
-    ) -> GeorgeResult<(u64, u64, Vec<Vec<u8>>)> {
+        sink: &mut dyn FnMut(Vec<u8>) -> GeorgeResult<()>,
+    ) -> GeorgeResult<(u64, u64)> {
         let mut total: u64 = 0;
         let mut count: u64 = 0;
-        let mut values: Vec<Vec<u8>> = vec![];
 
         // 由`view版本号(2字节) + view持续长度(4字节) + view偏移量(6字节)`组成
         let mut key_start = METADATA_SIZE + start * 12;
@@ -246,7 +247,7 @@ impl Node {
                 if skip <= 0 {
                     limit -= 1;
                     count += 1;
-                    values.push(value_bytes)
+                    sink(value_bytes)?;
                 } else {
                     skip -= 1;
                 }
@@ -255,7 +256,7 @@ impl Node {
             // 由`view版本号(2字节) + view持续长度(4字节) + view偏移量(6字节)`组成
             key_start += 12;
         }
-        Ok((total, count, values))
+        Ok((total, count))
     }
 
     /// 通过右查询约束获取数据集
@@ -272,13 +273,13 @@ impl Node {
     ///
     /// delete 是否删除检索结果
     ///
+    /// sink 检索结果回调，返回错误时终止检索
+    ///
     /// ###Return
     ///
     /// total 检索过程中遍历的总条数（也表示文件读取次数，文件描述符次数远小于该数，一般文件描述符数为1，即共用同一文件描述符）
     ///
     /// count 检索结果过程中遍历的总条数
-    ///
-    /// values 检索结果集合
     fn right_query(
         &self,
         start: u64,
@@ -287,10 +288,10 @@ impl Node {
         mut skip: u64,
         mut limit: u64,
         delete: bool,
-    ) -> GeorgeResult<(u64, u64, Vec<Vec<u8>>)> {
+        sink: &mut dyn FnMut(Vec<u8>) -> GeorgeResult<()>,
+    ) -> GeorgeResult<(u64, u64)> {
         let mut total: u64 = 0;
         let mut count: u64 = 0;
-        let mut values: Vec<Vec<u8>> = vec![];
 
         // 由`view版本号(2字节) + view持续长度(4字节) + view偏移量(6字节)`组成
         let key_start = METADATA_SIZE + start * 12;
@@ -312,7 +313,7 @@ impl Node {
                 if skip <= 0 {
                     limit -= 1;
                     count += 1;
-                    values.push(value_bytes)
+                    sink(value_bytes)?;
                 } else {
                     skip -= 1;
                 }
@@ -320,6 +321,6 @@ impl Node {
             total += 1;
             key_end -= 12;
         }
-        Ok((total, count, values))
+        Ok((total, count))
     }
 }
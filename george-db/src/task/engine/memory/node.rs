@@ -115,6 +115,24 @@ impl Node {
         let flexible_key = IndexKey::hash(KeyType::String, key.clone())?;
         self.del_in_node(1, key, flexible_key)
     }
+
+    /// 结点及其子结点下有效数据的数量
+    pub(crate) fn count(&self) -> u64 {
+        let mut count = 0;
+        if let Some(seeds) = self.seeds() {
+            for seed in seeds.read().unwrap().iter() {
+                if !seed.read().unwrap().is_none() {
+                    count += 1;
+                }
+            }
+        }
+        if let Some(nodes) = self.nodes() {
+            for node in nodes.read().unwrap().iter() {
+                count += node.count();
+            }
+        }
+        count
+    }
 }
 
 impl Node {
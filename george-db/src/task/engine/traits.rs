@@ -99,6 +99,29 @@ pub trait TIndex: Send + Sync + Debug {
         end: u64,
         constraint: Constraint,
     ) -> GeorgeResult<Expectation>;
+    /// 通过查询约束逐条获取数据，数据在索引遍历过程中即交由sink处理，无需全部载入内存
+    ///
+    /// 查询约束中存在排序时，须在得到全部结果并排序后再逐条交由sink处理
+    ///
+    /// ###Params
+    ///
+    /// left 是否左查询
+    ///
+    /// constraint 查询约束
+    ///
+    /// sink 检索结果回调，返回错误时终止检索
+    ///
+    /// ###Return
+    ///
+    /// Expectation 经由Selector后的期望结果，其中检索结果集合为空
+    fn select_stream(
+        &self,
+        left: bool,
+        start: u64,
+        end: u64,
+        constraint: Constraint,
+        sink: &mut dyn FnMut(Vec<u8>) -> GeorgeResult<()>,
+    ) -> GeorgeResult<Expectation>;
 }
 
 /// 结点通用特性，遵循此特性创建结点可以更方便的针对db进行扩展
@@ -144,12 +167,12 @@ pub(crate) trait TNode: Send + Sync + Debug {
     /// * limit 结果集限制数量
     /// * delete 是否删除检索结果
     /// * conditions 条件集合
+    /// * sink 检索结果回调，按索引遍历顺序逐条接收检索结果，返回错误时终止检索
     ///
     /// ###Return
     ///
     /// * total 检索过程中遍历的总条数（也表示文件读取次数，文件描述符次数远小于该数，一般文件描述符数为1，即共用同一文件描述符）
     /// * count 检索结果过程中遍历的总条数
-    /// * values 检索结果集合
     fn select(
         &self,
         left: bool,
@@ -159,7 +182,8 @@ pub(crate) trait TNode: Send + Sync + Debug {
         limit: u64,
         delete: bool,
         conditions: Vec<Condition>,
-    ) -> GeorgeResult<(u64, u64, Vec<Vec<u8>>)>;
+        sink: &mut dyn FnMut(Vec<u8>) -> GeorgeResult<()>,
+    ) -> GeorgeResult<(u64, u64)>;
 }
 
 /// B+Tree索引叶子结点内防hash碰撞数组对象中对象特性
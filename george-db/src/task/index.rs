@@ -13,6 +13,7 @@
  */
 
 use std::sync::{Arc, RwLock};
+use std::time::Instant;
 
 use chrono::Duration;
 
@@ -32,7 +33,7 @@ use crate::task::engine::sequence::Node as NS;
 use crate::task::engine::traits::{TIndex, TNode, TSeed};
 use crate::task::rich::{Constraint, Expectation};
 use crate::task::traits::TForm;
-use crate::task::Index;
+use crate::task::{Index, GLOBAL_METRICS};
 use crate::utils::enums::{Engine, KeyType};
 use crate::utils::Paths;
 use crate::utils::{Enum, EnumHandler};
@@ -136,11 +137,17 @@ impl TIndex for Index {
     }
 
     fn put(&self, key: String, seed: Arc<RwLock<dyn TSeed>>, force: bool) -> GeorgeResult<()> {
-        self.root.put(key, seed, force)
+        let start = Instant::now();
+        let result = self.root.put(key, seed, force);
+        GLOBAL_METRICS.index(self.engine, "put", start);
+        result
     }
 
     fn get(&self, key: String) -> GeorgeResult<DataReal> {
-        self.root.get(key)
+        let start = Instant::now();
+        let result = self.root.get(key);
+        GLOBAL_METRICS.index(self.engine, "get", start);
+        result
     }
 
     fn del(&self, key: String, seed: Arc<RwLock<dyn TSeed>>) -> GeorgeResult<()> {
@@ -169,9 +176,23 @@ impl TIndex for Index {
         let skip = constraint.skip();
         let limit = constraint.limit();
         let delete = constraint.delete();
-        let (total, count, mut values) = self
-            .root
-            .select(left, start, end, skip, limit, delete, conditions)?;
+        let mut values: Vec<Vec<u8>> = vec![];
+        let instant = Instant::now();
+        let result = self.root.select(
+            left,
+            start,
+            end,
+            skip,
+            limit,
+            delete,
+            conditions,
+            &mut |value| {
+                values.push(value);
+                Ok(())
+            },
+        );
+        GLOBAL_METRICS.index(self.engine, "select", instant);
+        let (total, count) = result?;
         match constraint.sort() {
             Some(sort) => {
                 let sort_param = sort.param();
@@ -286,6 +307,44 @@ impl TIndex for Index {
             values,
         })
     }
+
+    fn select_stream(
+        &self,
+        left: bool,
+        start: u64,
+        end: u64,
+        constraint: Constraint,
+        sink: &mut dyn FnMut(Vec<u8>) -> GeorgeResult<()>,
+    ) -> GeorgeResult<Expectation> {
+        // 排序须在得到全部检索结果后进行，此时无法边检索边输出
+        if constraint.sort().is_some() {
+            let mut expectation = self.select(left, start, end, constraint)?;
+            for value in expectation.values.drain(..) {
+                sink(value)?;
+            }
+            return Ok(expectation);
+        }
+        let instant = Instant::now();
+        let result = self.root.select(
+            left,
+            start,
+            end,
+            constraint.skip(),
+            constraint.limit(),
+            constraint.delete(),
+            constraint.conditions(),
+            sink,
+        );
+        GLOBAL_METRICS.index(self.engine, "select", instant);
+        let (total, count) = result?;
+        Ok(Expectation {
+            total,
+            count,
+            index_name: self.name(),
+            asc: left,
+            values: vec![],
+        })
+    }
 }
 
 impl Index {
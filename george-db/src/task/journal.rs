@@ -0,0 +1,652 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::fs::{self, File};
+use std::io::{BufRead, BufReader, Write};
+use std::path::Path;
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use george_comm::errors::{Errs, GeorgeResult};
+use george_comm::io::file::{FilerHandler, FilerNormal, FilerReader};
+use george_comm::io::Filer;
+use george_comm::Time;
+
+use crate::task::rich::Expectation;
+use crate::task::traits::TMaster;
+use crate::task::Journal;
+use crate::utils::enums::{Enum, EnumHandler};
+use crate::utils::Paths;
+use crate::Task;
+
+/// 变更日志文件最大字节数，超出后滚动为以首条记录序号命名的历史分段
+const JOURNAL_SEGMENT_SIZE: u64 = 64 * 1024 * 1024;
+/// 保留的历史分段数，超出后删除最早的分段
+const JOURNAL_SEGMENT_RETAIN: usize = 16;
+
+/// 数据变更
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum MutationOp {
+    PageCreate {
+        name: String,
+        comment: String,
+        size: u64,
+        period: u32,
+    },
+    PageRemove {
+        name: String,
+    },
+    PageModify {
+        name: String,
+        new_name: String,
+    },
+    DatabaseCreate {
+        name: String,
+        comment: String,
+    },
+    DatabaseRemove {
+        name: String,
+    },
+    DatabaseModify {
+        name: String,
+        new_name: String,
+        comment: String,
+    },
+    ViewCreate {
+        database: String,
+        name: String,
+        comment: String,
+        with_increment: bool,
+    },
+    ViewModify {
+        database: String,
+        name: String,
+        new_name: String,
+        comment: String,
+    },
+    ViewRemove {
+        database: String,
+        name: String,
+    },
+    ViewCipher {
+        database: String,
+        name: String,
+        fields: Vec<String>,
+        deterministic: bool,
+        readers: Vec<String>,
+        /// `sm4`密钥，hex，早期记录中为空
+        #[serde(default)]
+        key: String,
+        /// 确定性加密使用的`sm4`向量，hex，早期记录中为空
+        #[serde(default)]
+        iv: String,
+    },
+    IndexCreate {
+        database: String,
+        view: String,
+        name: String,
+        /// 存储引擎字节码
+        engine: u8,
+        /// 索引值类型字节码
+        key_type: u8,
+        primary: bool,
+        unique: bool,
+        null: bool,
+    },
+    DiskPut {
+        database: String,
+        view: String,
+        key: String,
+        /// 视图中的存储值，视图配置加密时为加密后的值，应用时不再重复加密
+        value: Vec<u8>,
+    },
+    DiskSet {
+        database: String,
+        view: String,
+        key: String,
+        /// 视图中的存储值，视图配置加密时为加密后的值，应用时不再重复加密
+        value: Vec<u8>,
+    },
+    DiskRemove {
+        database: String,
+        view: String,
+        key: String,
+    },
+    DiskDelete {
+        database: String,
+        view: String,
+        constraint: Vec<u8>,
+    },
+    MemoryPut {
+        page: String,
+        key: String,
+        value: Vec<u8>,
+    },
+    MemorySet {
+        page: String,
+        key: String,
+        value: Vec<u8>,
+    },
+    MemoryRemove {
+        page: String,
+        key: String,
+    },
+    LedgerCreate {
+        name: String,
+        comment: String,
+    },
+    LedgerRemove {
+        name: String,
+    },
+    BlockInsert {
+        ledger: String,
+        timestamp: i64,
+        transactions: Vec<Vec<u8>>,
+    },
+}
+
+/// 变更记录
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Mutation {
+    /// 记录序号，从1开始递增
+    pub seq: u64,
+    /// 记录时间，单位纳秒
+    pub time: i64,
+    /// 数据变更
+    pub op: MutationOp,
+}
+
+impl Mutation {
+    /// 序列化为变更日志中的`json`格式
+    pub fn to_vec(&self) -> GeorgeResult<Vec<u8>> {
+        match serde_json::to_vec(self) {
+            Ok(res) => Ok(res),
+            Err(err) => Err(Errs::strs("journal mutation to json", err)),
+        }
+    }
+
+    /// 从变更日志中的`json`格式反序列化
+    pub fn from_slice(bytes: &[u8]) -> GeorgeResult<Mutation> {
+        match serde_json::from_slice(bytes) {
+            Ok(res) => Ok(res),
+            Err(err) => Err(Errs::strs("journal mutation from json", err)),
+        }
+    }
+
+    /// 将变更重新作用于指定任务
+    pub fn apply(self, task: &Task) -> GeorgeResult<()> {
+        self.op.execute(task).map(|_| ())
+    }
+}
+
+impl MutationOp {
+    /// 将变更作用于指定任务，删除视图数据时返回删除结果，其余变更返回`None`
+    pub fn execute(self, task: &Task) -> GeorgeResult<Option<Expectation>> {
+        match self {
+            MutationOp::PageCreate {
+                name,
+                comment,
+                size,
+                period,
+            } => task.page_create(name, comment, size, period),
+            MutationOp::PageRemove { name } => task.page_remove(name),
+            MutationOp::PageModify { name, new_name } => task.page_modify(name, new_name),
+            MutationOp::DatabaseCreate { name, comment } => task.database_create(name, comment),
+            MutationOp::DatabaseRemove { name } => task.database_remove(name),
+            MutationOp::DatabaseModify {
+                name,
+                new_name,
+                comment,
+            } => task.database_modify(name, new_name, comment),
+            MutationOp::ViewCreate {
+                database,
+                name,
+                comment,
+                with_increment,
+            } => task.view_create(database, name, comment, with_increment),
+            MutationOp::ViewModify {
+                database,
+                name,
+                new_name,
+                comment,
+            } => task.view_modify(database, name, new_name, comment),
+            MutationOp::ViewRemove { database, name } => task.view_remove(database, name),
+            MutationOp::ViewCipher {
+                database,
+                name,
+                fields,
+                deterministic,
+                readers,
+                key,
+                iv,
+            } => task.store_cipher(database, name, fields, deterministic, readers, key, iv),
+            MutationOp::IndexCreate {
+                database,
+                view,
+                name,
+                engine,
+                key_type,
+                primary,
+                unique,
+                null,
+            } => task.index_create(
+                database,
+                view,
+                name,
+                Enum::engine(engine),
+                Enum::key_type(key_type),
+                primary,
+                unique,
+                null,
+            ),
+            MutationOp::DiskPut {
+                database,
+                view,
+                key,
+                value,
+            } => task.store_disk(database, view, key, value, false),
+            MutationOp::DiskSet {
+                database,
+                view,
+                key,
+                value,
+            } => task.store_disk(database, view, key, value, true),
+            MutationOp::DiskRemove {
+                database,
+                view,
+                key,
+            } => task.remove_disk(database, view, key),
+            MutationOp::DiskDelete {
+                database,
+                view,
+                constraint,
+            } => return task.delete_disk(database, view, constraint).map(Some),
+            MutationOp::MemoryPut { page, key, value } => task.put_memory(page, key, value),
+            MutationOp::MemorySet { page, key, value } => task.set_memory(page, key, value),
+            MutationOp::MemoryRemove { page, key } => task.remove_memory(page, key),
+            MutationOp::LedgerCreate { name, comment } => task.ledger_create(name, comment),
+            MutationOp::LedgerRemove { name } => task.ledger_remove(name),
+            MutationOp::BlockInsert {
+                ledger,
+                timestamp,
+                transactions,
+            } => task
+                .block_insert(ledger, timestamp, transactions)
+                .map(|_| ()),
+        }
+        .map(|_| None)
+    }
+}
+
+/// 变更日志尾信息，记录最新变更记录的序号，同时持有变更日志追加句柄
+#[derive(Debug)]
+pub(crate) struct JournalTail {
+    seq: u64,
+    /// 当前文件首条记录序号，当前文件尚无记录时为下一条记录的序号
+    first: u64,
+    /// 当前文件已写入的字节数
+    size: u64,
+    file: File,
+    /// 历史分段首条记录序号集合，按序号递增
+    segments: Vec<u64>,
+}
+
+/// 变更日志读取游标，随变更日志的追加持续读取新记录，供复制流向从节点发送变更
+#[derive(Debug)]
+pub struct JournalCursor {
+    journal: Arc<Journal>,
+    /// 已读取的最新记录序号
+    seq: u64,
+    /// 正在读取的文件首条记录序号
+    first: u64,
+    reader: BufReader<File>,
+    /// 尚未追加完整的行
+    partial: String,
+}
+
+impl JournalCursor {
+    /// 已读取的最新记录序号
+    pub fn seq(&self) -> u64 {
+        self.seq
+    }
+
+    /// 读取至多`limit`条新记录，暂无新记录时返回空集合
+    pub fn next(&mut self, limit: usize) -> GeorgeResult<Vec<Mutation>> {
+        let mut mutations: Vec<Mutation> = vec![];
+        while mutations.len() < limit {
+            match self.reader.read_line(&mut self.partial) {
+                Ok(0) => {
+                    // 读完当前文件而仍有更新的记录，说明当前文件已滚动为历史分段，改从包含后续记录的文件继续读取
+                    if self.partial.is_empty() && self.journal.seq() > self.seq {
+                        let (reader, first) = self.journal.open(self.seq + 1)?;
+                        if first != self.first {
+                            self.reader = reader;
+                            self.first = first;
+                            continue;
+                        }
+                    }
+                    break;
+                }
+                Ok(_) => {}
+                Err(err) => return Err(Errs::strs("journal cursor read line", err)),
+            }
+            // 读到文件末尾的不完整行时保留已读内容，待追加完成后继续读取
+            if !self.partial.ends_with('\n') {
+                break;
+            }
+            let line = self.partial.split_off(0);
+            if line.trim().is_empty() {
+                continue;
+            }
+            let mutation = Mutation::from_slice(line.trim_end().as_bytes())?;
+            if mutation.seq > self.seq {
+                self.seq = mutation.seq;
+                mutations.push(mutation);
+            }
+        }
+        Ok(mutations)
+    }
+}
+
+impl Journal {
+    /// 新建或恢复变更日志，变更日志文件存在时会从文件中恢复最新记录的序号
+    ///
+    /// 崩溃时未写完整的末条记录将被截除，以免后续追加的记录与其拼接
+    pub(crate) fn recovery() -> GeorgeResult<Journal> {
+        let filepath = Paths::journal_filepath();
+        Filer::try_touch(filepath.clone())?;
+        let segments = Journal::segments(&filepath)?;
+        let (mut first, mut seq) = (None, 0);
+        let size = Journal::scan(&filepath, &mut |mutation| {
+            first.get_or_insert(mutation.seq);
+            seq = mutation.seq;
+            Ok(())
+        })?;
+        if size < Filer::len(&filepath)? {
+            let file = Filer::appender(&filepath)?;
+            if let Err(err) = file.set_len(size) {
+                return Err(Errs::strs("journal truncate torn record", err));
+            }
+        }
+        if first.is_none() {
+            // 当前文件刚滚动尚无记录，最新序号为最近一个历史分段的末条记录序号
+            if let Some(segment) = segments.last() {
+                Journal::scan(&segment_filepath(&filepath, *segment), &mut |mutation| {
+                    seq = mutation.seq;
+                    Ok(())
+                })?;
+            }
+        }
+        log::info!(
+            "journal recovery with seq {} and {} segments",
+            seq,
+            segments.len()
+        );
+        Ok(Journal {
+            filepath: filepath.clone(),
+            tail: Mutex::new(JournalTail {
+                seq,
+                first: first.unwrap_or(seq + 1),
+                size,
+                file: Filer::appender(filepath)?,
+                segments,
+            }),
+            appended: Condvar::new(),
+        })
+    }
+
+    /// 追加变更记录并同步到磁盘，返回该记录序号
+    ///
+    /// 当前文件超出最大字节数后滚动为历史分段，滚动失败仅记录错误日志，下一次追加时重试
+    pub fn record(&self, op: MutationOp) -> GeorgeResult<u64> {
+        let mut tail = self.tail.lock().unwrap();
+        let mutation = Mutation {
+            seq: tail.seq + 1,
+            time: Time::now().nanos(),
+            op,
+        };
+        let mut line = mutation.to_vec()?;
+        line.push(b'\n');
+        let result = match tail.file.write_all(line.as_slice()) {
+            Ok(()) => tail.file.sync_data(),
+            Err(err) => Err(err),
+        };
+        if let Err(err) = result {
+            // 截除未完整写入的内容，以免后续追加的记录与其拼接
+            let _ = tail.file.set_len(tail.size);
+            return Err(Errs::strs("journal mutation write", err));
+        }
+        tail.seq = mutation.seq;
+        tail.size += line.len() as u64;
+        if tail.size >= JOURNAL_SEGMENT_SIZE {
+            if let Err(err) = self.rotate(&mut tail) {
+                log::error!("journal rotate failed! error is {}", err)
+            }
+        }
+        self.appended.notify_all();
+        Ok(mutation.seq)
+    }
+
+    /// 将当前文件滚动为以首条记录序号命名的历史分段，并删除超出保留数量的最早分段
+    fn rotate(&self, tail: &mut JournalTail) -> GeorgeResult<()> {
+        Filer::rename(
+            self.filepath.clone(),
+            segment_filepath(&self.filepath, tail.first),
+        )?;
+        Filer::try_touch(self.filepath.clone())?;
+        tail.file = Filer::appender(self.filepath.clone())?;
+        tail.segments.push(tail.first);
+        tail.first = tail.seq + 1;
+        tail.size = 0;
+        while tail.segments.len() > JOURNAL_SEGMENT_RETAIN {
+            let segment = segment_filepath(&self.filepath, tail.segments.remove(0));
+            if let Err(err) = fs::remove_file(&segment) {
+                log::warn!(
+                    "journal segment {} remove failed! error is {}",
+                    segment,
+                    err
+                )
+            }
+        }
+        Ok(())
+    }
+
+    /// 最新变更记录序号
+    pub fn seq(&self) -> u64 {
+        self.tail.lock().unwrap().seq
+    }
+
+    /// 等待序号大于`seq`的变更记录追加，返回最新变更记录序号，超时未追加则原样返回当前序号
+    pub fn wait(&self, seq: u64, timeout: Duration) -> u64 {
+        let tail = self.tail.lock().unwrap();
+        if tail.seq > seq {
+            return tail.seq;
+        }
+        let (tail, _) = self.appended.wait_timeout(tail, timeout).unwrap();
+        tail.seq
+    }
+
+    /// 新建从序号`seq`之后开始读取的游标
+    ///
+    /// 变更日志未包含`seq`之后的全部记录时返回错误，如变更日志因恢复而重建或所需分段已删除，此时从节点须重新拉取快照
+    pub fn cursor(self: &Arc<Self>, seq: u64) -> GeorgeResult<JournalCursor> {
+        let current = self.seq();
+        if seq > current {
+            return Err(Errs::string(format!(
+                "seq {} is ahead of journal seq {}, journal may be rebuilt, sync from snapshot again!",
+                seq, current
+            )));
+        }
+        let (reader, first) = self.open(seq + 1)?;
+        Ok(JournalCursor {
+            journal: self.clone(),
+            seq,
+            first,
+            reader,
+            partial: String::new(),
+        })
+    }
+
+    /// 打开包含序号`seq`的文件，返回读取句柄及该文件首条记录序号
+    fn open(&self, seq: u64) -> GeorgeResult<(BufReader<File>, u64)> {
+        // 持有尾信息期间打开，以免打开前文件被滚动
+        let tail = self.tail.lock().unwrap();
+        let (filepath, first) = if seq >= tail.first {
+            (self.filepath.clone(), tail.first)
+        } else {
+            match tail.segments.iter().rev().find(|first| **first <= seq) {
+                Some(first) => (segment_filepath(&self.filepath, *first), *first),
+                None => {
+                    return Err(Errs::string(format!(
+                        "journal misses seq {}, sync from snapshot again!",
+                        seq
+                    )))
+                }
+            }
+        };
+        Ok((BufReader::new(Filer::reader(filepath)?), first))
+    }
+
+    /// 将已追加的变更记录同步到磁盘
+    pub fn sync(&self) -> GeorgeResult<()> {
+        let tail = self.tail.lock().unwrap();
+        match tail.file.sync_all() {
+            Ok(()) => Ok(()),
+            Err(err) => Err(Errs::strs("journal file sync", err)),
+        }
+    }
+
+    /// 文件是否为当前实例正在使用的变更日志或其历史分段
+    pub fn owns(&self, filepath: &str) -> GeorgeResult<bool> {
+        let filepath = Filer::absolute(filepath)?;
+        let current = Filer::absolute(&self.filepath)?;
+        Ok(filepath.eq(&current) || filepath.starts_with(&format!("{}.", current)))
+    }
+
+    /// 待重放的变更日志文件集合
+    ///
+    /// `path`为目录时返回目录中按序号排列的历史分段及当前文件，否则仅返回`path`
+    pub fn files(path: String) -> GeorgeResult<Vec<String>> {
+        if !Path::new(&path).is_dir() {
+            return Ok(vec![path]);
+        }
+        let filename = match Path::new(&Paths::journal_filepath()).file_name() {
+            Some(res) => res.to_string_lossy().to_string(),
+            None => return Err(Errs::str("journal filepath has no file name!")),
+        };
+        let filepath = format!("{}/{}", path, filename);
+        let mut files: Vec<String> = Journal::segments(&filepath)?
+            .into_iter()
+            .map(|first| segment_filepath(&filepath, first))
+            .collect();
+        if Filer::exist(&filepath) {
+            files.push(filepath)
+        }
+        Ok(files)
+    }
+
+    /// 逐条读取变更日志文件中的记录并交由sink处理，不将整个文件读入内存
+    ///
+    /// 末条记录未写完整时视为崩溃时的残留并忽略，其余记录无法解析时返回错误
+    pub fn read(
+        filepath: String,
+        sink: &mut dyn FnMut(Mutation) -> GeorgeResult<()>,
+    ) -> GeorgeResult<()> {
+        Journal::scan(&filepath, sink).map(|_| ())
+    }
+
+    /// 逐条读取变更日志文件中的记录，返回完整记录的字节数
+    fn scan(
+        filepath: &str,
+        sink: &mut dyn FnMut(Mutation) -> GeorgeResult<()>,
+    ) -> GeorgeResult<u64> {
+        let mut reader = BufReader::new(Filer::reader(filepath)?);
+        let (mut size, mut line) = (0, vec![]);
+        loop {
+            line.clear();
+            let read = match reader.read_until(b'\n', &mut line) {
+                Ok(0) => return Ok(size),
+                Ok(res) => res as u64,
+                Err(err) => return Err(Errs::strs("journal read line", err)),
+            };
+            let content = String::from_utf8_lossy(line.as_slice()).trim().to_string();
+            if content.is_empty() {
+                size += read;
+                continue;
+            }
+            match Mutation::from_slice(content.as_bytes()) {
+                Ok(mutation) if line.ends_with(b"\n") => {
+                    sink(mutation)?;
+                    size += read;
+                }
+                result => {
+                    let last = match reader.fill_buf() {
+                        Ok(res) => res.is_empty(),
+                        Err(err) => return Err(Errs::strs("journal read line", err)),
+                    };
+                    if !last {
+                        return Err(match result {
+                            Ok(_) => Errs::string(format!(
+                                "journal {} record at {} is not terminated!",
+                                filepath, size
+                            )),
+                            Err(err) => err,
+                        });
+                    }
+                    log::warn!(
+                        "journal {} ends with torn record of {} bytes, ignore it",
+                        filepath,
+                        read
+                    );
+                    return Ok(size);
+                }
+            }
+        }
+    }
+
+    /// 变更日志所在目录中的历史分段首条记录序号集合，按序号递增
+    fn segments(filepath: &str) -> GeorgeResult<Vec<u64>> {
+        let path = Path::new(filepath);
+        let (dir, prefix) = match (path.parent(), path.file_name()) {
+            (Some(dir), Some(name)) => (dir, format!("{}.", name.to_string_lossy())),
+            _ => return Err(Errs::string(format!("journal {} is invalid!", filepath))),
+        };
+        let entries = match fs::read_dir(dir) {
+            Ok(res) => res,
+            Err(err) => return Err(Errs::strs("journal read dir", err)),
+        };
+        let mut segments = vec![];
+        for entry in entries {
+            let name = match entry {
+                Ok(res) => res.file_name().to_string_lossy().to_string(),
+                Err(err) => return Err(Errs::strs("journal read dir entry", err)),
+            };
+            if let Some(first) = name.strip_prefix(&prefix) {
+                if let Ok(first) = first.parse::<u64>() {
+                    segments.push(first)
+                }
+            }
+        }
+        segments.sort_unstable();
+        Ok(segments)
+    }
+}
+
+/// 历史分段文件路径，以分段首条记录序号为后缀
+fn segment_filepath(filepath: &str, first: u64) -> String {
+    format!("{}.{:020}", filepath, first)
+}
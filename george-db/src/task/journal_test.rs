@@ -0,0 +1,84 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[cfg(test)]
+mod journal {
+    use std::fs;
+
+    use george_comm::io::file::FilerWriter;
+    use george_comm::io::Filer;
+
+    use crate::task::journal::{Mutation, MutationOp};
+    use crate::task::Journal;
+
+    fn line(seq: u64) -> Vec<u8> {
+        let mut line = Mutation {
+            seq,
+            time: 0,
+            op: MutationOp::PageRemove {
+                name: "journal_test".to_string(),
+            },
+        }
+        .to_vec()
+        .unwrap();
+        line.push(b'\n');
+        line
+    }
+
+    fn read(filepath: &str) -> Result<Vec<u64>, String> {
+        let mut seqs = vec![];
+        match Journal::read(filepath.to_string(), &mut |mutation| {
+            seqs.push(mutation.seq);
+            Ok(())
+        }) {
+            Ok(()) => Ok(seqs),
+            Err(err) => Err(err.to_string()),
+        }
+    }
+
+    #[test]
+    fn read_torn_tail() {
+        let filepath = "src/test/journal/torn.log";
+        let _ = fs::remove_file(filepath);
+        let mut content = [line(1), line(2)].concat();
+        let torn = line(3);
+        content.extend_from_slice(&torn[..torn.len() / 2]);
+        Filer::write_force(filepath, content).unwrap();
+        assert_eq!(read(filepath).unwrap(), vec![1, 2]);
+    }
+
+    #[test]
+    fn read_corrupt_middle() {
+        let filepath = "src/test/journal/corrupt.log";
+        let _ = fs::remove_file(filepath);
+        let content = [line(1), b"{\"seq\":2\n".to_vec(), line(3)].concat();
+        Filer::write_force(filepath, content).unwrap();
+        assert!(read(filepath).is_err());
+    }
+
+    #[test]
+    fn files_in_dir() {
+        let dir = "src/test/journal/segments";
+        let _ = fs::remove_dir_all(dir);
+        Filer::write_force(format!("{}/journal.log", dir), line(3)).unwrap();
+        Filer::write_force(format!("{}/journal.log.{:020}", dir, 2), line(2)).unwrap();
+        Filer::write_force(format!("{}/journal.log.{:020}", dir, 1), line(1)).unwrap();
+        let files = Journal::files(dir.to_string()).unwrap();
+        let mut seqs = vec![];
+        for filepath in files {
+            seqs.extend(read(&filepath).unwrap());
+        }
+        assert_eq!(seqs, vec![1, 2, 3]);
+    }
+}
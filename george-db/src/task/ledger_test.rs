@@ -0,0 +1,129 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[cfg(test)]
+mod ledger {
+    use std::fs;
+
+    use george_comm::Time;
+
+    use crate::task::ledger::{Block, Transaction};
+    use crate::task::traits::TMaster;
+    use crate::task::Ledger;
+    use crate::utils::Paths;
+    use crate::Task;
+
+    fn insert(task: &Task, ledger_name: &str, values: Vec<&str>) -> Block {
+        let transactions = values
+            .iter()
+            .map(|value| value.as_bytes().to_vec())
+            .collect();
+        task.block_insert(ledger_name.to_string(), Time::now().nanos(), transactions)
+            .unwrap()
+    }
+
+    #[test]
+    fn chain() {
+        let task = Task::default().unwrap();
+        let ledger_name = "ledger_chain_test";
+        let _ = task.ledger_remove(ledger_name.to_string());
+        task.ledger_create(ledger_name.to_string(), "comment".to_string())
+            .unwrap();
+        assert!(task
+            .ledger_create(ledger_name.to_string(), "".to_string())
+            .is_err());
+
+        let block0 = insert(&task, ledger_name, vec!["tx0", "tx1"]);
+        let block1 = insert(&task, ledger_name, vec!["tx2"]);
+        assert_eq!(block0.height, 0);
+        assert_eq!(block1.height, 1);
+        assert!(block0.pre_hash.is_empty());
+        assert_eq!(block1.pre_hash, block0.hash);
+
+        let ledger = task.ledger(ledger_name.to_string()).unwrap();
+        let ledger_r = ledger.read().unwrap();
+        assert_eq!(ledger_r.height(), 2);
+        assert_eq!(ledger_r.hash(), block1.hash);
+        assert_eq!(ledger_r.block(0).unwrap().hash, block0.hash);
+        assert_eq!(
+            ledger_r.block_by_hash(block1.hash.clone()).unwrap().height,
+            1
+        );
+        let (block, tx) = ledger_r
+            .transaction(Transaction::hash(block0.timestamp, 1, "tx1".as_bytes()))
+            .unwrap();
+        assert_eq!(block.hash, block0.hash);
+        assert_eq!(tx.nonce, 1);
+        assert_eq!(tx.value, "tx1".as_bytes().to_vec());
+        assert!(ledger_r.block(2).is_err());
+    }
+
+    #[test]
+    fn reject() {
+        let task = Task::default().unwrap();
+        let ledger_name = "ledger_reject_test";
+        let _ = task.ledger_remove(ledger_name.to_string());
+        task.ledger_create(ledger_name.to_string(), "".to_string())
+            .unwrap();
+        // 空区块
+        assert!(task
+            .block_insert(ledger_name.to_string(), Time::now().nanos(), vec![])
+            .is_err());
+        let block = insert(&task, ledger_name, vec!["tx0"]);
+        // 以相同打包时间重复提交同一区块
+        assert!(task
+            .block_insert(
+                ledger_name.to_string(),
+                block.timestamp,
+                vec!["tx0".as_bytes().to_vec()],
+            )
+            .is_err());
+        // 相同内容的交易可重复记账
+        let block = insert(&task, ledger_name, vec!["tx0", "tx0"]);
+        assert_ne!(block.transactions[0].hash, block.transactions[1].hash);
+        task.ledger_remove(ledger_name.to_string()).unwrap();
+        assert!(task.ledger(ledger_name.to_string()).is_err());
+    }
+
+    #[test]
+    fn recover() {
+        let task = Task::default().unwrap();
+        let ledger_name = "ledger_recover_test";
+        let _ = task.ledger_remove(ledger_name.to_string());
+        task.ledger_create(ledger_name.to_string(), "comment".to_string())
+            .unwrap();
+        insert(&task, ledger_name, vec!["tx0", "tx1"]);
+        let block = insert(&task, ledger_name, vec!["tx2"]);
+        let ledger = Ledger::recover(ledger_name.to_string()).unwrap();
+        assert_eq!(ledger.height(), 2);
+        assert_eq!(ledger.hash(), block.hash);
+        assert_eq!(ledger.comment(), "comment");
+
+        // 篡改交易内容后拒绝恢复，"tx2"的json字节数组末位由'2'(50)改为'3'(51)
+        let filepath = Paths::ledger_filepath(ledger_name.to_string());
+        let bytes = fs::read(&filepath).unwrap();
+        let value = "[116,120,50]".as_bytes();
+        let position = bytes
+            .windows(value.len())
+            .position(|window| window.eq(value))
+            .unwrap();
+        let mut tampered = bytes.clone();
+        tampered[position + value.len() - 2] = b'1';
+        fs::write(&filepath, tampered).unwrap();
+        assert!(Ledger::recover(ledger_name.to_string()).is_err());
+        fs::write(&filepath, bytes).unwrap();
+        assert!(Ledger::recover(ledger_name.to_string()).is_ok());
+        task.ledger_remove(ledger_name.to_string()).unwrap();
+    }
+}
@@ -28,10 +28,11 @@ use george_ge::utils::enums::Tag;
 use george_ge::GeFactory;
 
 use crate::task::engine::traits::TIndex;
+use crate::task::ledger::Block;
 use crate::task::rich::Expectation;
 use crate::task::traits::TMaster;
 use crate::task::{Database, Master};
-use crate::task::{Page, View};
+use crate::task::{Ledger, Page, View};
 use crate::utils::comm::INDEX_DISK;
 use crate::utils::enums::{Engine, KeyType};
 use crate::utils::Paths;
@@ -78,6 +79,7 @@ impl Master {
             init,
             pages: Arc::new(Default::default()),
             databases: Default::default(),
+            ledgers: Default::default(),
             create_time,
         };
         if init {
@@ -104,6 +106,13 @@ impl Master {
             Err(_) => false,
         };
     }
+
+    fn exist_ledger(&self, ledger_name: String) -> bool {
+        return match self.ledger(ledger_name) {
+            Ok(_) => true,
+            Err(_) => false,
+        };
+    }
 }
 
 impl TMaster for Master {
@@ -305,6 +314,46 @@ impl TMaster for Master {
             .view(view_name)
     }
 
+    fn view_cipher(
+        &self,
+        database_name: String,
+        view_name: String,
+        fields: Vec<String>,
+        deterministic: bool,
+        readers: Vec<String>,
+    ) -> GeorgeResult<()> {
+        let view = self.view(database_name, view_name)?;
+        let mut view_w = view.write().unwrap();
+        let (key, iv) = view_w.cipher_key();
+        view_w.modify_cipher(fields, deterministic, readers, key, iv)
+    }
+
+    fn reveal_disk(
+        &self,
+        database_name: String,
+        view_name: String,
+        principal: String,
+        value: Vec<u8>,
+    ) -> GeorgeResult<Vec<u8>> {
+        self.view(database_name, view_name)?
+            .read()
+            .unwrap()
+            .reveal(principal.as_str(), value)
+    }
+
+    fn scan_disk(
+        &self,
+        database_name: String,
+        view_name: String,
+        principal: String,
+        each: &mut dyn FnMut(String, Vec<u8>) -> GeorgeResult<()>,
+    ) -> GeorgeResult<u64> {
+        self.view(database_name, view_name)?
+            .read()
+            .unwrap()
+            .scan(principal.as_str(), each)
+    }
+
     fn index_map(
         &self,
         database_name: String,
@@ -422,6 +471,19 @@ impl TMaster for Master {
             .select(view_name, constraint_json_bytes)
     }
 
+    fn select_disk_stream(
+        &self,
+        database_name: String,
+        view_name: String,
+        principal: Option<String>,
+        constraint_json_bytes: Vec<u8>,
+        sink: &mut dyn FnMut(Vec<u8>) -> GeorgeResult<()>,
+    ) -> GeorgeResult<Expectation> {
+        // 取得视图后即释放库锁，遍历期间仅由视图自身决定持锁范围
+        let view = self.view(database_name, view_name)?;
+        View::select_stream(view, principal, constraint_json_bytes, sink)
+    }
+
     fn delete_disk(
         &self,
         database_name: String,
@@ -449,6 +511,51 @@ impl TMaster for Master {
     fn remove_memory(&self, page_name: String, key: String) -> GeorgeResult<()> {
         self.page(page_name)?.read().unwrap().remove(key)
     }
+
+    fn ledger_map(&self) -> Arc<RwLock<HashMap<String, Arc<RwLock<Ledger>>>>> {
+        self.ledgers.clone()
+    }
+
+    fn ledger_create(&self, name: String, comment: String) -> GeorgeResult<()> {
+        if self.exist_ledger(name.clone()) {
+            return Err(Errs::ledger_exist_error());
+        }
+        let ledger = Ledger::create(name.clone(), comment)?;
+        self.ledger_map()
+            .write()
+            .unwrap()
+            .insert(name.clone(), ledger);
+        log::debug!("create ledger {} success!", name);
+        Ok(())
+    }
+
+    fn ledger_remove(&self, ledger_name: String) -> GeorgeResult<()> {
+        if !self.exist_ledger(ledger_name.clone()) {
+            Err(Errs::ledger_no_exist_error())
+        } else {
+            self.ledger_map().write().unwrap().remove(&ledger_name);
+            Dir::rm(Paths::ledger_path(ledger_name))
+        }
+    }
+
+    fn ledger(&self, ledger_name: String) -> GeorgeResult<Arc<RwLock<Ledger>>> {
+        match self.ledger_map().read().unwrap().get(&ledger_name) {
+            Some(ledger) => Ok(ledger.clone()),
+            None => Err(Errs::ledger_no_exist_error()),
+        }
+    }
+
+    fn block_insert(
+        &self,
+        ledger_name: String,
+        timestamp: i64,
+        transactions: Vec<Vec<u8>>,
+    ) -> GeorgeResult<Block> {
+        self.ledger(ledger_name)?
+            .write()
+            .unwrap()
+            .append(timestamp, transactions)
+    }
 }
 
 impl Master {
@@ -459,7 +566,10 @@ impl Master {
         match read_dir(Paths::data_database_path()) {
             Ok(database_paths) => match self.recovery_databases(database_paths) {
                 Ok(()) => match read_dir(Paths::data_page_path()) {
-                    Ok(page_paths) => self.recovery_pages(page_paths),
+                    Ok(page_paths) => {
+                        self.recovery_pages(page_paths)?;
+                        self.recovery_ledgers()
+                    }
                     Err(err) => Err(Errs::strs("recovery read dir page_paths", err)),
                 },
                 Err(err) => Err(Errs::strs("recovery databases", err)),
@@ -550,4 +660,34 @@ impl Master {
         }
         Ok(())
     }
+
+    /// 恢复ledgers数据，账本目录在首次创建账本时才会生成
+    fn recovery_ledgers(&self) -> GeorgeResult<()> {
+        if !Dir::exist(Paths::data_ledger_path())? {
+            return Ok(());
+        }
+        let paths = match read_dir(Paths::data_ledger_path()) {
+            Ok(paths) => paths,
+            Err(err) => return Err(Errs::strs("recovery read dir ledger_paths", err)),
+        };
+        for path in paths {
+            match path {
+                Ok(dir) => {
+                    if dir.path().is_dir() {
+                        let ledger_name = dir.file_name().to_str().unwrap().to_string();
+                        log::debug!("recovery ledger from {}", ledger_name);
+                        let ledger = Ledger::recover(ledger_name)?;
+                        if !self.exist_ledger(ledger.name()) {
+                            self.ledger_map()
+                                .write()
+                                .unwrap()
+                                .insert(ledger.name(), Arc::new(RwLock::new(ledger)));
+                        }
+                    }
+                }
+                Err(err) => return Err(Errs::strs("recovery ledger path", err)),
+            }
+        }
+        Ok(())
+    }
 }
@@ -893,6 +893,59 @@ mod test {
                 );
             }
 
+            #[test]
+            fn select_disk_stream() {
+                let task = Task::default().unwrap();
+                let database_name = "database_select_base_test";
+                let view_name = "view_base_test";
+                let cond_str0 = r#"
+                                  {
+                                    "Conditions":[
+                                        {
+                                            "Param":"age",
+                                            "Cond":"ge",
+                                            "Value":490
+                                        },
+                                        {
+                                            "Param":"age",
+                                            "Cond":"le",
+                                            "Value":910
+                                        }
+                                    ],
+                                    "Skip":5,
+                                    "Limit":20
+                                  }"#;
+                let mut values: Vec<Vec<u8>> = vec![];
+                match task.select_disk_stream(
+                    database_name.to_string(),
+                    view_name.to_string(),
+                    None,
+                    cond_str0.as_bytes().to_vec(),
+                    &mut |value| {
+                        values.push(value);
+                        Ok(())
+                    },
+                ) {
+                    Ok(e) => {
+                        println!(
+                            "select_stream,total={},count={},index_name={},asc={}",
+                            e.total, e.count, e.index_name, e.asc
+                        );
+                        assert!(e.values.is_empty());
+                        let expect = task
+                            .select_disk(
+                                database_name.to_string(),
+                                view_name.to_string(),
+                                cond_str0.as_bytes().to_vec(),
+                            )
+                            .unwrap();
+                        assert_eq!(expect.count, e.count);
+                        assert_eq!(expect.values, values);
+                    }
+                    Err(ie) => println!("select_stream is {}", ie),
+                }
+            }
+
             #[test]
             fn select_sequence_left() {
                 let task = Task::default().unwrap();
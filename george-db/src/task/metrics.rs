@@ -0,0 +1,186 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use prometheus::core::Collector;
+use prometheus::{
+    Encoder, HistogramOpts, HistogramVec, IntCounterVec, IntGauge, IntGaugeVec, Opts, Registry,
+    TextEncoder,
+};
+
+use george_comm::errors::{Errs, GeorgeResult};
+use george_comm::io::file::FilerHandler;
+use george_comm::io::Filer;
+
+use crate::task::traits::TMaster;
+use crate::task::{Master, Metrics, GLOBAL_THREAD_POOL};
+use crate::utils::enums::Engine;
+
+/// 指标名称前缀
+const METRICS_NAMESPACE: &str = "george";
+/// 缓存页键数量重新统计间隔
+const PAGE_COUNT_INTERVAL: Duration = Duration::from_secs(30);
+
+impl Metrics {
+    pub(crate) fn new() -> GeorgeResult<Metrics> {
+        let registry = Registry::new();
+        let rpc_requests = IntCounterVec::new(
+            Opts::new("rpc_requests_total", "rpc requests count").namespace(METRICS_NAMESPACE),
+            &["operation"],
+        );
+        let rpc_errors = IntCounterVec::new(
+            Opts::new("rpc_errors_total", "rpc errors count").namespace(METRICS_NAMESPACE),
+            &["operation", "kind"],
+        );
+        let rpc_latency = HistogramVec::new(
+            HistogramOpts::new("rpc_latency_seconds", "rpc latency").namespace(METRICS_NAMESPACE),
+            &["operation"],
+        );
+        let index_latency = HistogramVec::new(
+            HistogramOpts::new("index_latency_seconds", "index operation latency")
+                .namespace(METRICS_NAMESPACE)
+                .buckets(vec![
+                    0.00005, 0.0001, 0.00025, 0.0005, 0.001, 0.0025, 0.005, 0.01, 0.025, 0.05, 0.1,
+                    0.25, 0.5, 1.0,
+                ]),
+            &["engine", "operation"],
+        );
+        let page_entries = IntGaugeVec::new(
+            Opts::new("page_entries", "memory page entries count").namespace(METRICS_NAMESPACE),
+            &["page"],
+        );
+        let view_bytes = IntGaugeVec::new(
+            Opts::new("view_file_bytes", "view ge file size").namespace(METRICS_NAMESPACE),
+            &["database", "view"],
+        );
+        let thread_pool_pending = IntGauge::with_opts(
+            Opts::new("thread_pool_pending", "thread pool unfinished tasks count")
+                .namespace(METRICS_NAMESPACE),
+        );
+        let metrics = Metrics {
+            registry,
+            rpc_requests: metric(rpc_requests)?,
+            rpc_errors: metric(rpc_errors)?,
+            rpc_latency: metric(rpc_latency)?,
+            index_latency: metric(index_latency)?,
+            page_entries: metric(page_entries)?,
+            page_counted: Mutex::new(HashMap::new()),
+            view_bytes: metric(view_bytes)?,
+            thread_pool_pending: metric(thread_pool_pending)?,
+        };
+        metrics.register(Box::new(metrics.rpc_requests.clone()))?;
+        metrics.register(Box::new(metrics.rpc_errors.clone()))?;
+        metrics.register(Box::new(metrics.rpc_latency.clone()))?;
+        metrics.register(Box::new(metrics.index_latency.clone()))?;
+        metrics.register(Box::new(metrics.page_entries.clone()))?;
+        metrics.register(Box::new(metrics.view_bytes.clone()))?;
+        metrics.register(Box::new(metrics.thread_pool_pending.clone()))?;
+        Ok(metrics)
+    }
+
+    /// 记录一次请求
+    ///
+    /// ###Params
+    ///
+    /// * operation 操作名称，如`database.create`
+    /// * kind 请求失败时的错误类型，成功则为None
+    /// * latency 请求耗时
+    pub(crate) fn rpc(&self, operation: &str, kind: Option<String>, latency: Duration) {
+        self.rpc_requests.with_label_values(&[operation]).inc();
+        if let Some(kind) = kind {
+            self.rpc_errors
+                .with_label_values(&[operation, kind.as_str()])
+                .inc();
+        }
+        self.rpc_latency
+            .with_label_values(&[operation])
+            .observe(latency.as_secs_f64());
+    }
+
+    /// 记录一次索引操作
+    ///
+    /// ###Params
+    ///
+    /// * engine 存储引擎类型
+    /// * operation 操作名称，如`put`、`get`及`select`
+    /// * start 操作开始时间
+    pub(crate) fn index(&self, engine: Engine, operation: &str, start: Instant) {
+        self.index_latency
+            .with_label_values(&[format!("{:?}", engine).to_lowercase().as_str(), operation])
+            .observe(start.elapsed().as_secs_f64());
+    }
+
+    /// 采集缓存页键数量(按间隔重新统计)、视图文件大小及线程池待执行任务数，并以`Prometheus`文本格式导出全部指标
+    pub(crate) fn export(&self, master: &Master) -> GeorgeResult<String> {
+        self.page_entries.reset();
+        let pages = master.page_map().read().unwrap();
+        let mut page_counted = self.page_counted.lock().unwrap();
+        page_counted.retain(|name, _| pages.contains_key(name));
+        for (name, page) in pages.iter() {
+            let count = match page_counted.get(name) {
+                Some((time, count)) if time.elapsed() < PAGE_COUNT_INTERVAL => *count,
+                _ => {
+                    let count = page.read().unwrap().count();
+                    page_counted.insert(name.clone(), (Instant::now(), count));
+                    count
+                }
+            };
+            self.page_entries
+                .with_label_values(&[name.as_str()])
+                .set(count as i64);
+        }
+        drop(page_counted);
+        drop(pages);
+        self.view_bytes.reset();
+        for (database_name, database) in master.database_map().read().unwrap().iter() {
+            let database = database.read().unwrap();
+            for (view_name, view) in database.view_map().read().unwrap().iter() {
+                // 视图文件可能正在归档，获取失败时跳过
+                if let Ok(len) = Filer::len(view.read().unwrap().filepath()) {
+                    self.view_bytes
+                        .with_label_values(&[database_name.as_str(), view_name.as_str()])
+                        .set(len as i64);
+                }
+            }
+        }
+        self.thread_pool_pending
+            .set(GLOBAL_THREAD_POOL.pending_count() as i64);
+        let mut buffer = vec![];
+        match TextEncoder::new().encode(&self.registry.gather(), &mut buffer) {
+            Ok(()) => {}
+            Err(err) => return Err(Errs::strs("metrics encode", err)),
+        }
+        match String::from_utf8(buffer) {
+            Ok(res) => Ok(res),
+            Err(err) => Err(Errs::strs("metrics from utf8", err)),
+        }
+    }
+
+    fn register(&self, collector: Box<dyn Collector>) -> GeorgeResult<()> {
+        match self.registry.register(collector) {
+            Ok(()) => Ok(()),
+            Err(err) => Err(Errs::strs("metrics register", err)),
+        }
+    }
+}
+
+fn metric<M>(result: prometheus::Result<M>) -> GeorgeResult<M> {
+    match result {
+        Ok(res) => Ok(res),
+        Err(err) => Err(Errs::strs("metrics new", err)),
+    }
+}
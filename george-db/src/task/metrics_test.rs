@@ -0,0 +1,74 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[cfg(test)]
+mod metrics {
+    use std::time::Duration;
+
+    use crate::task::traits::TMaster;
+    use crate::Task;
+
+    #[test]
+    fn export() {
+        let task = Task::default().unwrap();
+        let page_name = "page_metrics_test";
+        let _ = task.page_create(page_name.to_string(), "".to_string(), 0, 0);
+        task.set_memory(page_name.to_string(), "a".to_string(), vec![0x01])
+            .unwrap();
+        task.set_memory(page_name.to_string(), "b".to_string(), vec![0x02])
+            .unwrap();
+
+        let database_name = "database_metrics_test";
+        let view_name = "view_metrics_test";
+        let _ = task.database_create(database_name.to_string(), "".to_string());
+        let _ = task.view_create(
+            database_name.to_string(),
+            view_name.to_string(),
+            "".to_string(),
+            false,
+        );
+        task.set_disk(
+            database_name.to_string(),
+            view_name.to_string(),
+            "a".to_string(),
+            "metrics".as_bytes().to_vec(),
+        )
+        .unwrap();
+        task.get_disk(
+            database_name.to_string(),
+            view_name.to_string(),
+            "a".to_string(),
+        )
+        .unwrap();
+
+        task.metrics_record("metrics.test", None, Duration::from_millis(1));
+        task.metrics_record(
+            "metrics.test",
+            Some("NotFound".to_string()),
+            Duration::from_millis(2),
+        );
+
+        let text = task.metrics().unwrap();
+        println!("{}", text);
+        assert!(text.contains("george_page_entries{page=\"page_metrics_test\"} 2"));
+        assert!(text.contains(
+            "george_view_file_bytes{database=\"database_metrics_test\",view=\"view_metrics_test\"}"
+        ));
+        assert!(text.contains("george_index_latency_seconds_count{engine=\"disk\""));
+        assert!(text.contains("george_rpc_requests_total{operation=\"metrics.test\"} 2"));
+        assert!(text
+            .contains("george_rpc_errors_total{kind=\"NotFound\",operation=\"metrics.test\"} 1"));
+        assert!(text.contains("george_thread_pool_pending"));
+    }
+}
@@ -12,32 +12,68 @@
  * limitations under the License.
  */
 use std::collections::HashMap;
-use std::sync::{Arc, RwLock};
+use std::marker::PhantomData;
+use std::sync::mpsc::Sender;
+use std::sync::{Arc, Condvar, Mutex, RwLock};
+use std::time::{Duration, Instant};
 
-use once_cell::sync::Lazy;
+use once_cell::sync::{Lazy, OnceCell};
+use prometheus::{HistogramVec, IntCounterVec, IntGauge, IntGaugeVec, Registry};
 
 use george_comm::pool::ThreadPool;
+use george_comm::Document;
 use george_comm::Time;
 use george_ge::Ge;
 
+use crate::task::audit::{AuditOp, AuditTail};
 use crate::task::engine::memory::Node;
 use crate::task::engine::traits::{Pigeonhole, TIndex, TNode};
 use crate::task::engine::DataReal;
+use crate::task::journal::JournalTail;
+use crate::task::quota::{QuotaPolicy, TokenBucket};
+use crate::task::raft::{RaftApplier, RaftCore, RaftSnapshotter, RaftTransport};
+use crate::task::replication::ReplicationState;
 use crate::task::seed::IndexPolicy;
 use crate::task::traits::TForm;
 use crate::utils::deploy::GLOBAL_CONFIG;
 use crate::utils::enums::{Engine, KeyType};
+use crate::Task;
 
+pub mod audit;
+mod audit_test;
+pub mod backup;
+mod backup_test;
+pub mod batch;
+mod batch_test;
+mod cipher;
+mod cipher_test;
 mod database;
 pub mod engine;
 mod index;
-mod ledger;
+pub mod journal;
+mod journal_test;
+pub mod ledger;
+mod ledger_test;
 pub mod master;
 mod master_test;
+pub mod metrics;
+mod metrics_test;
 mod page;
+pub mod quota;
+mod quota_test;
+pub mod raft;
+mod raft_test;
+pub mod replication;
+mod replication_test;
 pub mod rich;
+mod secret;
+mod secret_test;
 mod seed;
 pub mod traits;
+pub mod transfer;
+mod transfer_test;
+mod typed;
+mod typed_test;
 mod view;
 
 pub(super) static GLOBAL_THREAD_POOL: Lazy<ThreadPool> = Lazy::new(|| {
@@ -46,6 +82,24 @@ pub(super) static GLOBAL_THREAD_POOL: Lazy<ThreadPool> = Lazy::new(|| {
     ThreadPool::new(worker_threads).expect("thread pool new failed!")
 });
 
+/// 审计日志，进程内唯一，避免多个实例同时追加导致审计链分叉
+pub(super) static GLOBAL_AUDIT: OnceCell<Arc<Audit>> = OnceCell::new();
+
+/// 变更日志，进程内唯一，保证变更记录序号连续
+pub(super) static GLOBAL_JOURNAL: OnceCell<Arc<Journal>> = OnceCell::new();
+
+/// 租户配额，进程内唯一，保证同一租户的令牌桶及存储用量只有一份
+pub(super) static GLOBAL_QUOTA: OnceCell<Arc<Quota>> = OnceCell::new();
+
+/// 复制状态，进程内唯一，保证角色切换对所有实例可见
+pub(super) static GLOBAL_REPLICATION: OnceCell<Arc<Replication>> = OnceCell::new();
+
+/// 服务主密钥，进程内唯一
+pub(super) static GLOBAL_SECRET: OnceCell<Arc<Secret>> = OnceCell::new();
+/// 运行指标，进程内唯一
+pub(super) static GLOBAL_METRICS: Lazy<Metrics> =
+    Lazy::new(|| Metrics::new().expect("metrics new failed!"));
+
 /// 主管员
 #[derive(Debug, Clone)]
 pub struct Master {
@@ -55,10 +109,141 @@ pub struct Master {
     pages: Arc<RwLock<HashMap<String, Arc<RwLock<Page>>>>>,
     /// 库集合
     databases: Arc<RwLock<HashMap<String, Arc<RwLock<Database>>>>>,
+    /// 账本集合
+    ledgers: Arc<RwLock<HashMap<String, Arc<RwLock<Ledger>>>>>,
     /// 创建时间
     create_time: Time,
 }
 
+/// 审计日志
+///
+/// 以追加方式记录每一次操作，各记录间通过hash串联，以便发现篡改
+#[derive(Debug)]
+pub struct Audit {
+    /// 审计文件路径
+    filepath: String,
+    /// 服务主密钥，用于计算审计记录HMAC
+    secret: Arc<Secret>,
+    /// 审计链尾信息，与审计写入线程共享
+    tail: Arc<Mutex<AuditTail>>,
+    /// 审计写入线程的投递端
+    sender: Mutex<Sender<AuditOp>>,
+}
+
+/// 变更日志
+///
+/// 按提交顺序记录每一次成功的数据变更，时间点恢复时在备份之上重放
+#[derive(Debug)]
+pub struct Journal {
+    /// 变更日志文件路径
+    filepath: String,
+    /// 变更日志尾信息
+    tail: Mutex<JournalTail>,
+    /// 追加变更记录后通知等待新记录的复制流
+    appended: Condvar,
+}
+
+/// 服务主密钥
+///
+/// 首次启动时随机生成，保存在数据目录根下独立的密钥文件中，不随备份及复制快照传播。
+/// 用于签发及校验登录令牌、计算审计链HMAC
+pub struct Secret {
+    /// 主密钥
+    key: Vec<u8>,
+}
+
+/// 主从复制状态
+///
+/// 主节点以变更日志向从节点推送变更，从节点按序应用并只读提供服务，可手动提升为主节点
+#[derive(Debug)]
+pub struct Replication {
+    /// 复制状态文件路径
+    filepath: String,
+    /// 复制状态
+    state: RwLock<ReplicationState>,
+}
+
+/// 集群共识
+///
+/// 基于`Raft`选举领导者，数据变更由领导者追加至共识日志并复制到多数节点，提交后各节点按序应用，
+/// 非领导者节点将写入转发至领导者。已应用的条目定期生成快照后从共识日志中丢弃，
+/// 跟随者所需条目已被丢弃时由领导者发送快照，跟随者重启后以快照恢复数据
+pub struct Raft {
+    /// 本节点编号
+    id: u64,
+    /// 共识状态及日志存储目录
+    dir: String,
+    /// 选举超时基准，实际超时在基准与两倍基准间随机
+    election_timeout: Duration,
+    /// 领导者心跳间隔
+    heartbeat_interval: Duration,
+    /// 快照间隔，自上次快照起已应用的条目数
+    snapshot_entries: u64,
+    /// 节点间通信
+    transport: Arc<dyn RaftTransport>,
+    /// 应用已提交的数据变更
+    applier: RaftApplier,
+    /// 生成已应用数据的快照
+    snapshotter: RaftSnapshotter,
+    /// 共识状态
+    core: Mutex<RaftCore>,
+    /// 日志追加、提交、应用及角色变化时通知等待方
+    changed: Condvar,
+}
+
+/// 在线热备份
+///
+/// 备份时开启文件快照，写入不受影响，恢复时依据备份清单校验并重建数据存储路径
+pub struct Backup;
+
+/// 批量写入
+///
+/// 以单次请求逐项写入大量数据，并可选择全部成功或全部失败，失败时按写入前的状态回滚已写入的数据项
+pub struct Batch;
+
+/// 逻辑导出导入
+///
+/// 以`JSON Lines`或`CSV`格式导出视图或整库的视图定义、索引定义及数据，并可分批导入
+pub struct Transfer;
+
+/// 租户配额
+///
+/// 按操作人或库进行令牌桶限流，并限制库及视图的存储字节数、缓存页的键数量，避免单一租户挤占其它租户资源
+#[derive(Debug)]
+pub struct Quota {
+    /// 配额策略文件路径
+    filepath: String,
+    /// 配额策略集合，键为`scope:name`
+    policies: RwLock<HashMap<String, QuotaPolicy>>,
+    /// 令牌桶集合，键同配额策略
+    buckets: Mutex<HashMap<String, TokenBucket>>,
+    /// 存储用量集合，键同配额策略，库及视图为已用字节数，缓存页为已有键数量
+    usages: Mutex<HashMap<String, u64>>,
+}
+
+/// 运行指标
+///
+/// 以`Prometheus`文本格式导出，计数及耗时在请求处理时累加，缓存页键数量、视图文件大小及线程池待执行任务数在导出时采集
+pub struct Metrics {
+    registry: Registry,
+    /// 请求次数，按操作名称区分
+    rpc_requests: IntCounterVec,
+    /// 请求失败次数，按操作名称及错误类型区分
+    rpc_errors: IntCounterVec,
+    /// 请求耗时，按操作名称区分
+    rpc_latency: HistogramVec,
+    /// 索引操作耗时，按存储引擎及操作区分
+    index_latency: HistogramVec,
+    /// 缓存页键数量
+    page_entries: IntGaugeVec,
+    /// 缓存页键数量统计结果及统计时间，统计需遍历缓存页，间隔内的采集直接沿用上次结果
+    page_counted: Mutex<HashMap<String, (Instant, u64)>>,
+    /// 视图当前版本文件大小
+    view_bytes: IntGaugeVec,
+    /// 线程池中尚未完成的任务数
+    thread_pool_pending: IntGauge,
+}
+
 /// 数据库
 #[derive(Debug, Clone)]
 pub struct Database {
@@ -110,37 +295,66 @@ pub struct View {
     indexes: Arc<RwLock<HashMap<String, Arc<dyn TIndex>>>>,
     /// 当前归档版本信息
     pigeonhole: Pigeonhole,
+    /// 字段加密策略
+    cipher: Option<Cipher>,
+}
+
+/// 视图字段加密策略
+///
+/// 写入时加密指定的json字段，读取时仅授权读者可解密，其余读者获取脱敏结果。
+/// 确定性加密下相同明文得到相同密文，以便加密字段上的等值索引及等值检索仍可使用。
+/// 密钥及向量随变更日志传播，各节点以本节点主密钥包装后保存，备份及快照中则以明文保存以便在其它节点恢复
+#[derive(Clone)]
+pub struct Cipher {
+    /// 加密字段集合
+    fields: Vec<String>,
+    /// 是否确定性加密
+    deterministic: bool,
+    /// 可解密的操作人集合
+    readers: Vec<String>,
+    /// `sm4`密钥，hex
+    key: String,
+    /// 确定性加密使用的`sm4`向量，hex
+    iv: String,
 }
 
 /// 账本
+///
+/// 区块以追加方式记录于区块全数据记录文件，区块坐标按块高定长记录于区块坐标记录文件
 #[derive(Debug, Clone)]
 pub struct Ledger {
-    /// 数据库名称
-    pub(crate) database_name: String,
     /// 名称
-    pub(crate) name: String,
+    name: String,
+    /// 描述
+    comment: String,
     /// 创建时间
-    pub(crate) create_time: Time,
+    create_time: Time,
     /// 区块全数据记录文件
     ///
     /// 需要借助对象包裹，以便更新file，避免self为mut
-    pub(crate) ge: Arc<dyn Ge>,
-    /// 区块Header数据记录文件
-    ///
-    /// 需要借助对象包裹，以便更新file，避免self为mut
-    pub(crate) ge_light: Arc<dyn Ge>,
-    /// 区块Header数据以merkle形式进行存储的记录文件
+    ge: Arc<dyn Ge>,
+    /// 区块坐标记录文件，按块高顺序记录每一区块在区块全数据记录文件中的起始坐标及长度
     ///
     /// 需要借助对象包裹，以便更新file，避免self为mut
-    pub(crate) ge_merkle_light: Arc<dyn Ge>,
-    /// 区块高度存储索引，根据块高查询区块
-    pub(crate) index_block_height: Arc<dyn TIndex>,
-    /// 区块hash存储索引，根据块hash查询区块
-    pub(crate) index_block_hash: Arc<dyn TIndex>,
-    /// 交易hash存储索引，根据交易hash查询区块、查询交易
-    pub(crate) index_tx_hash: Arc<dyn TIndex>,
-    /// 索引集合
-    pub(crate) indexes: Arc<RwLock<HashMap<String, Arc<dyn TIndex>>>>,
+    ge_light: Arc<dyn Ge>,
+    /// 最新区块hash
+    hash: String,
+    /// 区块高度，即账本中的区块数量
+    height: u64,
+    /// 区块hash索引，根据块hash查询块高
+    block_hashes: HashMap<String, u64>,
+    /// 交易hash索引，根据交易hash查询所在区块块高
+    tx_hashes: HashMap<String, u64>,
+}
+
+/// 类型化视图
+///
+/// 以`T`读写视图数据，写入时序列化为json存储，读取时反序列化为`T`，视图中的索引由`T`的索引定义创建
+pub struct TypedView<T: Document> {
+    task: Task,
+    database_name: String,
+    view_name: String,
+    document: PhantomData<fn() -> T>,
 }
 
 /// Siam索引
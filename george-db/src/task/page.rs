@@ -138,6 +138,11 @@ impl Page {
         self.node().read().unwrap().get(key)
     }
 
+    /// 缓存页中的键数量
+    pub(crate) fn count(&self) -> u64 {
+        self.node().read().unwrap().count()
+    }
+
     /// 删除数据<p><p>
     ///
     /// ###Params
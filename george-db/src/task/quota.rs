@@ -0,0 +1,415 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::collections::HashMap;
+use std::fs::read_dir;
+use std::path::Path;
+use std::sync::{Mutex, RwLock};
+use std::time::Instant;
+
+use serde::{Deserialize, Serialize};
+
+use george_comm::errors::{Errs, GeorgeResult};
+use george_comm::io::file::{FilerHandler, FilerReader};
+use george_comm::io::Filer;
+use george_ge::utils::Snapshot;
+
+use crate::task::Quota;
+use crate::utils::Paths;
+
+/// 配额作用于操作人，`name`为操作人
+pub const QUOTA_SCOPE_USER: &str = "user";
+/// 配额作用于库，`name`为库名
+pub const QUOTA_SCOPE_DATABASE: &str = "database";
+/// 配额作用于视图，`name`为`database_name/view_name`
+pub const QUOTA_SCOPE_VIEW: &str = "view";
+/// 配额作用于缓存页，`name`为缓存页名
+pub const QUOTA_SCOPE_PAGE: &str = "page";
+
+/// 配额策略
+///
+/// 各限制项为0表示不限制，所有限制项都为0的策略等同于移除策略
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuotaPolicy {
+    /// 作用范围，可选`user`、`database`、`view`及`page`
+    pub scope: String,
+    /// 作用对象名称
+    pub name: String,
+    /// 每秒允许的请求数，仅`user`及`database`可用
+    pub rate: u32,
+    /// 允许突发的请求数，即令牌桶容量，为0则与`rate`相同
+    pub burst: u32,
+    /// 最大存储字节数，仅`database`及`view`可用
+    pub max_bytes: u64,
+    /// 最大键数量，仅`page`可用
+    pub max_keys: u64,
+}
+
+impl QuotaPolicy {
+    /// 策略在配额集合中的键
+    fn key(&self) -> String {
+        quota_key(self.scope.as_str(), self.name.as_str())
+    }
+
+    /// 校验策略各限制项是否适用于作用范围
+    fn check(&self) -> GeorgeResult<()> {
+        if self.name.is_empty() {
+            return Err(Errs::str("quota name can not be empty!"));
+        }
+        let (rate, bytes, keys) = match self.scope.as_str() {
+            QUOTA_SCOPE_USER => (true, false, false),
+            QUOTA_SCOPE_DATABASE => (true, true, false),
+            QUOTA_SCOPE_VIEW => (false, true, false),
+            QUOTA_SCOPE_PAGE => (false, false, true),
+            _ => {
+                return Err(Errs::string(format!(
+                    "quota scope {} is not support!",
+                    self.scope
+                )))
+            }
+        };
+        if (!rate && (self.rate > 0 || self.burst > 0))
+            || (!bytes && self.max_bytes > 0)
+            || (!keys && self.max_keys > 0)
+        {
+            return Err(Errs::string(format!(
+                "quota scope {} only support {}",
+                self.scope,
+                match self.scope.as_str() {
+                    QUOTA_SCOPE_USER => "rate and burst",
+                    QUOTA_SCOPE_DATABASE => "rate, burst and max bytes",
+                    QUOTA_SCOPE_VIEW => "max bytes",
+                    _ => "max keys",
+                }
+            )));
+        }
+        Ok(())
+    }
+
+    /// 是否未设置任何限制项
+    fn unlimited(&self) -> bool {
+        self.rate == 0 && self.max_bytes == 0 && self.max_keys == 0
+    }
+}
+
+/// 令牌桶，按`rate`匀速补充令牌，每次请求消耗一个令牌
+#[derive(Debug)]
+pub(crate) struct TokenBucket {
+    /// 当前令牌数
+    tokens: f64,
+    /// 上次补充令牌的时间
+    refill: Instant,
+}
+
+impl TokenBucket {
+    fn new(capacity: f64) -> TokenBucket {
+        TokenBucket {
+            tokens: capacity,
+            refill: Instant::now(),
+        }
+    }
+
+    /// 补充令牌后尝试消耗一个令牌，令牌不足则返回false
+    fn take(&mut self, rate: f64, capacity: f64) -> bool {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.refill).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * rate).min(capacity);
+        self.refill = now;
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl Quota {
+    /// 新建或恢复配额策略
+    pub(crate) fn recovery() -> GeorgeResult<Quota> {
+        let filepath = Paths::quota_filepath();
+        let mut policies: HashMap<String, QuotaPolicy> = HashMap::new();
+        if Filer::exist(filepath.clone()) {
+            let list: Vec<QuotaPolicy> =
+                match serde_json::from_slice(Filer::read_bytes(filepath.clone())?.as_slice()) {
+                    Ok(res) => res,
+                    Err(err) => return Err(Errs::strs("quota from json", err)),
+                };
+            for policy in list {
+                policies.insert(policy.key(), policy);
+            }
+        }
+        log::info!("quota recovery with {} policies", policies.len());
+        Ok(Quota {
+            filepath,
+            policies: RwLock::new(policies),
+            buckets: Mutex::new(HashMap::new()),
+            usages: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// 设置配额策略，已存在则覆盖，所有限制项都为0则移除策略
+    ///
+    /// 策略变更后对应令牌桶重新计算，存储用量及键数量在下次写入时重新统计
+    pub fn set(&self, policy: QuotaPolicy) -> GeorgeResult<()> {
+        policy.check()?;
+        let key = policy.key();
+        let mut policies = self.policies.write().unwrap();
+        let mut list: Vec<QuotaPolicy> = policies
+            .values()
+            .filter(|res| res.key().ne(&key))
+            .cloned()
+            .collect();
+        if !policy.unlimited() {
+            list.push(policy.clone());
+        }
+        match serde_json::to_vec(&list) {
+            Ok(res) => {
+                Snapshot::write_force(self.filepath.clone(), res)?;
+            }
+            Err(err) => return Err(Errs::strs("quota to json", err)),
+        }
+        if policy.unlimited() {
+            policies.remove(&key);
+        } else {
+            policies.insert(key.clone(), policy.clone());
+        }
+        self.buckets.lock().unwrap().remove(&key);
+        self.usages.lock().unwrap().remove(&key);
+        Ok(())
+    }
+
+    /// 配额策略集合
+    ///
+    /// ###Params
+    ///
+    /// * scope 作用范围，为空则不限
+    pub fn policies(&self, scope: String) -> Vec<QuotaPolicy> {
+        let mut list: Vec<QuotaPolicy> = self
+            .policies
+            .read()
+            .unwrap()
+            .values()
+            .filter(|res| scope.is_empty() || res.scope.eq(&scope))
+            .cloned()
+            .collect();
+        list.sort_by(|a, b| a.key().cmp(&b.key()));
+        list
+    }
+
+    /// 为一次请求消耗操作人及库的令牌，任一令牌不足则返回配额超限错误
+    ///
+    /// ###Params
+    ///
+    /// * principal 经登录令牌认证的操作人
+    /// * database_name 请求所操作的库，为空则仅校验操作人
+    pub fn acquire(&self, principal: String, database_name: String) -> GeorgeResult<()> {
+        self.take_token(QUOTA_SCOPE_USER, principal)?;
+        if !database_name.is_empty() {
+            self.take_token(QUOTA_SCOPE_DATABASE, database_name)?;
+        }
+        Ok(())
+    }
+
+    /// 写入视图时是否需校验库或视图的最大存储字节数
+    pub(crate) fn bytes_limited(&self, database_name: String, view_name: String) -> bool {
+        !self.bytes_limits(database_name, view_name).is_empty()
+    }
+
+    /// 为写入视图预留存储字节数，库或视图超出最大存储字节数则返回配额超限错误
+    ///
+    /// 用量首次使用时按库或视图目录大小统计，其后按存储值增长的字节数累计
+    pub(crate) fn take_bytes(
+        &self,
+        database_name: String,
+        view_name: String,
+        len: u64,
+    ) -> GeorgeResult<()> {
+        let limits = self.bytes_limits(database_name.clone(), view_name.clone());
+        if limits.is_empty() {
+            return Ok(());
+        }
+        let mut usages = self.usages.lock().unwrap();
+        for (key, path, max) in limits.iter() {
+            let used = *usages
+                .entry(key.clone())
+                .or_insert_with(|| dir_size(Path::new(path)));
+            if used + len > *max {
+                return Err(Errs::quota_exceeded_error(format!(
+                    "{} used {} bytes, can not write {} bytes more with max {} bytes",
+                    key, used, len, max
+                )));
+            }
+        }
+        for (key, _, _) in limits {
+            if let Some(used) = usages.get_mut(&key) {
+                *used += len
+            }
+        }
+        Ok(())
+    }
+
+    /// 写入失败时归还预留的存储字节数
+    pub(crate) fn give_bytes(&self, database_name: String, view_name: String, len: u64) {
+        let mut usages = self.usages.lock().unwrap();
+        for (key, _, _) in self.bytes_limits(database_name, view_name) {
+            if let Some(used) = usages.get_mut(&key) {
+                *used = used.saturating_sub(len)
+            }
+        }
+    }
+
+    /// 缓存页是否设置了最大键数量，未设置时不统计键数量
+    pub(crate) fn keys_limited(&self, page_name: &str) -> bool {
+        match self
+            .policies
+            .read()
+            .unwrap()
+            .get(&quota_key(QUOTA_SCOPE_PAGE, page_name))
+        {
+            Some(policy) => policy.max_keys > 0,
+            None => false,
+        }
+    }
+
+    /// 为缓存页新增键预留数量，超出最大键数量则返回配额超限错误
+    ///
+    /// 调用方须独占缓存页，使新增键的判断、预留与写入之间没有同一缓存页的其它写入
+    ///
+    /// ###Params
+    ///
+    /// * page_name 缓存页名称
+    /// * count 统计缓存页已有键数量，仅在策略设置后首次使用时调用
+    pub(crate) fn take_key(&self, page_name: String, count: &dyn Fn() -> u64) -> GeorgeResult<()> {
+        let key = quota_key(QUOTA_SCOPE_PAGE, page_name.as_str());
+        let max = match self.policies.read().unwrap().get(&key) {
+            Some(policy) => policy.max_keys,
+            None => 0,
+        };
+        let mut usages = self.usages.lock().unwrap();
+        let used = usages.entry(key.clone()).or_insert_with(|| count());
+        if max > 0 && *used >= max {
+            return Err(Errs::quota_exceeded_error(format!(
+                "{} has {} keys with max {} keys",
+                key, used, max
+            )));
+        }
+        *used += 1;
+        Ok(())
+    }
+
+    /// 缓存页键被移除或写入失败时归还数量
+    pub(crate) fn give_key(&self, page_name: String) {
+        let key = quota_key(QUOTA_SCOPE_PAGE, page_name.as_str());
+        if let Some(used) = self.usages.lock().unwrap().get_mut(&key) {
+            *used = used.saturating_sub(1)
+        }
+    }
+
+    /// 清除对象的存储用量，用于库、视图或缓存页被删除后
+    ///
+    /// 清除库用量时同时清除其下视图用量
+    pub(crate) fn forget(&self, scope: &str, name: String) {
+        let key = quota_key(scope, name.as_str());
+        let prefix = quota_key(QUOTA_SCOPE_VIEW, format!("{}/", name).as_str());
+        self.usages.lock().unwrap().retain(|res, _| {
+            res.ne(&key) && !(scope.eq(QUOTA_SCOPE_DATABASE) && res.starts_with(&prefix))
+        });
+    }
+
+    /// 缓存页重命名后沿用原键数量
+    pub(crate) fn rename_page(&self, page_name: String, page_new_name: String) {
+        let mut usages = self.usages.lock().unwrap();
+        if let Some(used) = usages.remove(&quota_key(QUOTA_SCOPE_PAGE, page_name.as_str())) {
+            usages.insert(quota_key(QUOTA_SCOPE_PAGE, page_new_name.as_str()), used);
+        }
+    }
+
+    /// 消耗对象令牌桶中的一个令牌，对象未设置速率限制则直接通过
+    fn take_token(&self, scope: &str, name: String) -> GeorgeResult<()> {
+        let key = quota_key(scope, name.as_str());
+        let (rate, capacity) = match self.policies.read().unwrap().get(&key) {
+            Some(policy) if policy.rate > 0 => {
+                let burst = if policy.burst > 0 {
+                    policy.burst
+                } else {
+                    policy.rate
+                };
+                (policy.rate as f64, burst as f64)
+            }
+            _ => return Ok(()),
+        };
+        let mut buckets = self.buckets.lock().unwrap();
+        let bucket = buckets
+            .entry(key.clone())
+            .or_insert_with(|| TokenBucket::new(capacity));
+        if bucket.take(rate, capacity) {
+            Ok(())
+        } else {
+            Err(Errs::quota_exceeded_error(format!(
+                "{} exceeded rate limit {} requests per second",
+                key, rate
+            )))
+        }
+    }
+
+    /// 写入视图时需校验的存储限制集合，元素为(配额键, 统计目录, 最大存储字节数)
+    fn bytes_limits(&self, database_name: String, view_name: String) -> Vec<(String, String, u64)> {
+        let policies = self.policies.read().unwrap();
+        let mut limits = vec![];
+        let key = quota_key(QUOTA_SCOPE_DATABASE, database_name.as_str());
+        if let Some(policy) = policies.get(&key) {
+            if policy.max_bytes > 0 {
+                limits.push((
+                    key,
+                    Paths::database_path(database_name.clone()),
+                    policy.max_bytes,
+                ))
+            }
+        }
+        let key = quota_key(
+            QUOTA_SCOPE_VIEW,
+            format!("{}/{}", database_name, view_name).as_str(),
+        );
+        if let Some(policy) = policies.get(&key) {
+            if policy.max_bytes > 0 {
+                limits.push((
+                    key,
+                    Paths::view_path(database_name, view_name),
+                    policy.max_bytes,
+                ))
+            }
+        }
+        limits
+    }
+}
+
+fn quota_key(scope: &str, name: &str) -> String {
+    format!("{}:{}", scope, name)
+}
+
+/// 统计目录下所有文件大小之和，目录不存在则为0
+fn dir_size(path: &Path) -> u64 {
+    let mut size = 0;
+    if let Ok(entries) = read_dir(path) {
+        for entry in entries.flatten() {
+            match entry.metadata() {
+                Ok(metadata) if metadata.is_dir() => size += dir_size(entry.path().as_path()),
+                Ok(metadata) => size += metadata.len(),
+                Err(_) => {}
+            }
+        }
+    }
+    size
+}
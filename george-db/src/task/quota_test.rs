@@ -0,0 +1,142 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[cfg(test)]
+mod quota {
+    use george_comm::errors::GeorgeError;
+
+    use crate::task::batch::{BatchOp, BatchReport, BatchTarget};
+    use crate::task::quota::{QuotaPolicy, QUOTA_SCOPE_PAGE, QUOTA_SCOPE_USER, QUOTA_SCOPE_VIEW};
+    use crate::task::traits::TMaster;
+    use crate::Task;
+
+    fn policy(scope: &str, name: &str, rate: u32, max_bytes: u64, max_keys: u64) -> QuotaPolicy {
+        QuotaPolicy {
+            scope: scope.to_string(),
+            name: name.to_string(),
+            rate,
+            burst: 0,
+            max_bytes,
+            max_keys,
+        }
+    }
+
+    fn exceeded<T>(result: Result<T, GeorgeError>) -> bool {
+        matches!(result, Err(GeorgeError::QuotaExceededError(_)))
+    }
+
+    #[test]
+    fn rate_limit() {
+        let task = Task::default().unwrap();
+        let principal = "quota_rate_test";
+        assert!(task
+            .quota_set(policy(QUOTA_SCOPE_USER, principal, 0, 1024, 0))
+            .is_err());
+        task.quota_set(policy(QUOTA_SCOPE_USER, principal, 2, 0, 0))
+            .unwrap();
+        assert_eq!(task.quota_policies(QUOTA_SCOPE_USER.to_string()).len(), 1);
+        task.quota_acquire(principal.to_string(), "".to_string())
+            .unwrap();
+        task.quota_acquire(principal.to_string(), "".to_string())
+            .unwrap();
+        assert!(exceeded(
+            task.quota_acquire(principal.to_string(), "".to_string())
+        ));
+        // 其它操作人不受影响
+        task.quota_acquire("quota_rate_other".to_string(), "".to_string())
+            .unwrap();
+        task.quota_set(policy(QUOTA_SCOPE_USER, principal, 0, 0, 0))
+            .unwrap();
+        task.quota_acquire(principal.to_string(), "".to_string())
+            .unwrap();
+    }
+
+    #[test]
+    fn storage_limit() {
+        let task = Task::default().unwrap();
+        let page_name = "page_quota_test";
+        let _ = task.page_create(page_name.to_string(), "".to_string(), 0, 0);
+        task.quota_set(policy(QUOTA_SCOPE_PAGE, page_name, 0, 0, 2))
+            .unwrap();
+        let value = "quota".as_bytes().to_vec();
+        task.set_memory(page_name.to_string(), "a".to_string(), value.clone())
+            .unwrap();
+        task.set_memory(page_name.to_string(), "b".to_string(), value.clone())
+            .unwrap();
+        // 更新已有键不占用数量
+        task.set_memory(page_name.to_string(), "a".to_string(), value.clone())
+            .unwrap();
+        assert!(exceeded(task.set_memory(
+            page_name.to_string(),
+            "c".to_string(),
+            value.clone()
+        )));
+        task.remove_memory(page_name.to_string(), "b".to_string())
+            .unwrap();
+        task.set_memory(page_name.to_string(), "c".to_string(), value)
+            .unwrap();
+
+        let database_name = "database_quota_test";
+        let view_name = "view_quota_test";
+        let _ = task.database_create(database_name.to_string(), "".to_string());
+        let _ = task.view_create(
+            database_name.to_string(),
+            view_name.to_string(),
+            "".to_string(),
+            false,
+        );
+        let name = format!("{}/{}", database_name, view_name);
+        task.quota_set(policy(QUOTA_SCOPE_VIEW, name.as_str(), 0, 1, 0))
+            .unwrap();
+        assert!(exceeded(task.set_disk(
+            database_name.to_string(),
+            view_name.to_string(),
+            "a".to_string(),
+            "quota".as_bytes().to_vec(),
+        )));
+        task.quota_set(policy(QUOTA_SCOPE_VIEW, name.as_str(), 0, 0, 0))
+            .unwrap();
+    }
+
+    #[test]
+    fn batch_rate_limit() {
+        let task = Task::default().unwrap();
+        let principal = "quota_batch_test";
+        let page_name = "page_quota_batch_test";
+        let _ = task.page_create(page_name.to_string(), "".to_string(), 0, 0);
+        task.quota_set(policy(QUOTA_SCOPE_USER, principal, 2, 0, 0))
+            .unwrap();
+        let mut report = BatchReport::default();
+        // 每个数据项各消耗一个令牌
+        task.batch(
+            &BatchTarget::Memory {
+                page_name: page_name.to_string(),
+            },
+            BatchOp::Set,
+            false,
+            principal.to_string(),
+            vec![
+                ("a".to_string(), "1".as_bytes().to_vec()),
+                ("b".to_string(), "2".as_bytes().to_vec()),
+                ("c".to_string(), "3".as_bytes().to_vec()),
+            ],
+            &mut report,
+        );
+        assert_eq!(report.succeeded, 2);
+        assert_eq!(report.failed, 1);
+        assert_eq!(report.errors[0].1, "c");
+        task.quota_set(policy(QUOTA_SCOPE_USER, principal, 0, 0, 0))
+            .unwrap();
+    }
+}
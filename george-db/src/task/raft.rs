@@ -0,0 +1,1708 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::cmp;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use george_comm::errors::{Errs, GeorgeError, GeorgeResult};
+use george_comm::io::file::{FilerHandler, FilerNormal, FilerReader, FilerWriter};
+use george_comm::io::Filer;
+use george_comm::Time;
+
+use crate::task::backup::BACKUP_MANIFEST;
+use crate::task::journal::MutationOp;
+use crate::task::rich::Expectation;
+use crate::task::{Backup, Raft};
+
+/// 跟随者角色，接收领导者复制的日志
+pub const RAFT_ROLE_FOLLOWER: &str = "follower";
+/// 候选者角色，选举超时后发起选举
+pub const RAFT_ROLE_CANDIDATE: &str = "candidate";
+/// 领导者角色，追加并复制日志
+pub const RAFT_ROLE_LEADER: &str = "leader";
+
+/// 共识状态文件名
+const RAFT_STATE_FILE: &str = "state.json";
+/// 共识日志文件名
+const RAFT_LOG_FILE: &str = "log.jsonl";
+/// 快照元数据文件名
+const RAFT_SNAPSHOT_FILE: &str = "snapshot.json";
+/// 快照目录名前缀，后缀为快照包含的最新条目索引
+const RAFT_SNAPSHOT_PREFIX: &str = "snapshot-";
+/// 生成中的快照目录名
+const RAFT_SNAPSHOT_TMP: &str = "snapshot.tmp";
+/// 接收领导者快照的目录名
+const RAFT_INSTALL_DIR: &str = "install";
+/// 快照接收完毕、待重启时恢复的快照元数据文件名
+const RAFT_INSTALL_FILE: &str = "install.json";
+/// 提交变更后等待本节点应用的最长时间
+const PROPOSE_TIMEOUT: Duration = Duration::from_secs(10);
+/// 单次向跟随者复制的最大日志条目数
+const APPEND_BATCH: u64 = 256;
+/// 保留的已应用条目结果数，超出后丢弃最早的结果
+const RESULT_CAPACITY: usize = 1024;
+/// 应用条目发生非确定性失败后的最大重试次数
+const APPLY_RETRIES: u32 = 3;
+/// 应用条目失败后的重试间隔
+const APPLY_RETRY_INTERVAL: Duration = Duration::from_secs(1);
+/// 单次安装快照请求携带的最大字节数
+const INSTALL_CHUNK_SIZE: usize = 1024 * 1024;
+
+/// 应用已提交数据变更的回调，删除视图数据时返回删除结果
+pub type RaftApplier = Box<dyn Fn(MutationOp) -> GeorgeResult<Option<Expectation>> + Send + Sync>;
+
+/// 在指定目录中生成已应用数据的不压缩备份的回调，调用期间不会应用新的条目
+pub type RaftSnapshotter = Box<dyn Fn(String) -> GeorgeResult<()> + Send + Sync>;
+
+/// 共识参数
+#[derive(Debug, Clone)]
+pub struct RaftOptions {
+    /// 选举超时基准，实际超时在基准与两倍基准间随机
+    pub election_timeout: Duration,
+    /// 领导者心跳间隔，须小于选举超时基准
+    pub heartbeat_interval: Duration,
+    /// 快照间隔，自上次快照起每应用该数量的条目生成一次快照并丢弃快照包含的日志条目
+    pub snapshot_entries: u64,
+}
+
+/// 集群节点
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RaftPeer {
+    /// 节点编号
+    pub id: u64,
+    /// 节点服务地址，格式为`host:port`
+    pub addr: String,
+}
+
+/// 共识日志条目内容
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum RaftCommand {
+    /// 领导者当选后追加的空条目，用于提交此前任期的条目
+    Noop,
+    /// 数据变更
+    Mutation { op: MutationOp },
+    /// 集群节点集合，追加后即生效，首个领导者以此记录初始节点集合
+    Members { peers: Vec<RaftPeer> },
+    /// 新增节点请求，领导者追加时转换为新的节点集合
+    Join { peer: RaftPeer },
+    /// 移除节点请求，领导者追加时转换为新的节点集合
+    Leave { id: u64 },
+}
+
+/// 共识日志条目
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RaftEntry {
+    /// 条目索引，从1开始递增
+    pub index: u64,
+    /// 追加条目时领导者的任期
+    pub term: u64,
+    /// 条目内容
+    pub command: RaftCommand,
+}
+
+/// 快照元数据
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RaftSnapshot {
+    /// 快照包含的最新条目索引，为0表示尚无快照
+    pub index: u64,
+    /// 快照包含的最新条目任期
+    pub term: u64,
+    /// 快照包含的最新条目生效时的集群节点集合
+    pub members: Vec<RaftPeer>,
+}
+
+/// 投票请求
+#[derive(Debug, Clone)]
+pub struct VoteRequest {
+    /// 候选者任期
+    pub term: u64,
+    /// 候选者编号
+    pub candidate: u64,
+    /// 候选者最新日志条目索引
+    pub last_index: u64,
+    /// 候选者最新日志条目任期
+    pub last_term: u64,
+}
+
+/// 投票结果
+#[derive(Debug, Clone)]
+pub struct VoteResponse {
+    /// 投票节点当前任期
+    pub term: u64,
+    /// 是否投票给候选者
+    pub granted: bool,
+}
+
+/// 日志复制请求，不携带条目时即为心跳
+#[derive(Debug, Clone)]
+pub struct AppendRequest {
+    /// 领导者任期
+    pub term: u64,
+    /// 领导者编号
+    pub leader: u64,
+    /// 待复制条目前一条目的索引
+    pub prev_index: u64,
+    /// 待复制条目前一条目的任期
+    pub prev_term: u64,
+    /// 待复制条目
+    pub entries: Vec<RaftEntry>,
+    /// 领导者已提交的条目索引
+    pub commit: u64,
+}
+
+/// 日志复制结果
+#[derive(Debug, Clone)]
+pub struct AppendResponse {
+    /// 跟随者当前任期
+    pub term: u64,
+    /// 是否复制成功
+    pub success: bool,
+    /// 复制成功时为与领导者一致的最新条目索引，失败时为领导者下次复制的起始索引减1
+    pub last_index: u64,
+}
+
+/// 安装快照请求，跟随者所需条目已被领导者丢弃时按文件分块发送快照
+#[derive(Debug, Clone)]
+pub struct InstallRequest {
+    /// 领导者任期
+    pub term: u64,
+    /// 领导者编号
+    pub leader: u64,
+    /// 快照元数据
+    pub snapshot: RaftSnapshot,
+    /// 快照文件相对快照目录的路径
+    pub path: String,
+    /// 文件内容分块
+    pub data: Vec<u8>,
+    /// 是否为本次快照的首个分块，跟随者据此清空此前接收的内容
+    pub first: bool,
+    /// 是否已发送全部文件，为`true`时不携带文件内容
+    pub done: bool,
+}
+
+/// 安装快照结果
+#[derive(Debug, Clone)]
+pub struct InstallResponse {
+    /// 跟随者当前任期
+    pub term: u64,
+}
+
+/// 集群状态
+#[derive(Debug, Clone)]
+pub struct RaftStatus {
+    /// 本节点编号
+    pub id: u64,
+    /// 本节点角色
+    pub role: String,
+    /// 当前任期
+    pub term: u64,
+    /// 领导者编号，为0表示尚无领导者
+    pub leader: u64,
+    /// 领导者服务地址
+    pub leader_addr: String,
+    /// 最新日志条目索引
+    pub last_index: u64,
+    /// 已提交的条目索引
+    pub commit: u64,
+    /// 已应用的条目索引
+    pub applied: u64,
+    /// 当前集群节点集合
+    pub members: Vec<RaftPeer>,
+}
+
+/// 节点间通信，由服务层基于`RPC`实现
+pub trait RaftTransport: Send + Sync {
+    /// 向指定节点请求投票
+    fn vote(&self, peer: &RaftPeer, request: VoteRequest) -> GeorgeResult<VoteResponse>;
+
+    /// 向指定节点复制日志
+    fn append(&self, peer: &RaftPeer, request: AppendRequest) -> GeorgeResult<AppendResponse>;
+
+    /// 将条目内容转发至领导者追加，返回条目索引及任期
+    fn forward(&self, peer: &RaftPeer, command: RaftCommand) -> GeorgeResult<(u64, u64)>;
+
+    /// 向指定节点发送快照分块
+    fn install(&self, peer: &RaftPeer, request: InstallRequest) -> GeorgeResult<InstallResponse>;
+}
+
+/// 需持久化的共识状态
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct HardState {
+    /// 当前任期
+    term: u64,
+    /// 当前任期投票的候选者编号，为0表示尚未投票
+    voted_for: u64,
+    /// 已应用的条目索引
+    applied: u64,
+    /// 开始应用的批次中最大的条目索引，重启后重放不大于该索引的条目时，对象已存在或不存在的失败可能源于重启前已生效
+    #[serde(default)]
+    pending: u64,
+}
+
+/// 共识状态，最近快照之后的日志条目全部保存在内存中并同步追加至日志文件
+pub(crate) struct RaftCore {
+    hard: HardState,
+    role: &'static str,
+    /// 领导者编号，为0表示尚无领导者
+    leader: u64,
+    /// 最近一次收到领导者复制请求的时间
+    contact: Instant,
+    /// 选举超时时间点
+    deadline: Instant,
+    /// 最近快照的元数据，快照包含的条目已从日志中丢弃
+    snapshot: RaftSnapshot,
+    /// 快照之后的日志条目，第`i`个条目的索引为`snapshot.index + i + 1`
+    log: Vec<RaftEntry>,
+    /// 各日志条目在日志文件中的起始位置，截断时据此缩短日志文件
+    offsets: Vec<u64>,
+    /// 日志文件长度
+    size: u64,
+    /// 日志文件追加句柄
+    file: File,
+    /// 已提交的条目索引
+    commit: u64,
+    /// 集群初始节点集合
+    initial: Vec<RaftPeer>,
+    /// 当前集群节点集合
+    members: Vec<RaftPeer>,
+    /// 领导者下次向各节点复制的起始索引
+    next: HashMap<u64, u64>,
+    /// 领导者已知各节点与自身一致的最新条目索引
+    matched: HashMap<u64, u64>,
+    /// 候选者已获得的投票
+    votes: Vec<u64>,
+    /// 运行中的复制线程，键为节点编号，值为线程所属任期
+    replicators: HashMap<u64, u64>,
+    /// 已应用条目的结果，键为条目索引，值为条目任期及应用结果
+    results: BTreeMap<u64, (u64, GeorgeResult<Option<Expectation>>)>,
+    /// 正在接收的快照，值为领导者任期及快照包含的最新条目索引
+    installing: Option<(u64, u64)>,
+    stopped: bool,
+}
+
+impl RaftCore {
+    fn last_index(&self) -> u64 {
+        self.snapshot.index + self.log.len() as u64
+    }
+
+    fn last_term(&self) -> u64 {
+        self.log
+            .last()
+            .map_or(self.snapshot.term, |entry| entry.term)
+    }
+
+    /// 指定索引条目的任期，条目已被快照丢弃或不存在时为0
+    fn term_at(&self, index: u64) -> u64 {
+        if index == self.snapshot.index {
+            self.snapshot.term
+        } else if index < self.snapshot.index {
+            0
+        } else {
+            self.log
+                .get((index - self.snapshot.index) as usize - 1)
+                .map_or(0, |entry| entry.term)
+        }
+    }
+
+    /// 索引在`(from, to]`之间的日志条目，`from`不能小于快照包含的最新条目索引
+    fn entries(&self, from: u64, to: u64) -> &[RaftEntry] {
+        &self.log[(from - self.snapshot.index) as usize..(to - self.snapshot.index) as usize]
+    }
+
+    /// 截至索引`index`生效的集群节点集合，日志及快照中均无节点集合时为初始节点集合
+    fn members_at(&self, index: u64) -> Vec<RaftPeer> {
+        let end = cmp::min(
+            index.saturating_sub(self.snapshot.index) as usize,
+            self.log.len(),
+        );
+        for entry in self.log[..end].iter().rev() {
+            if let RaftCommand::Members { peers } = &entry.command {
+                return peers.clone();
+            }
+        }
+        if self.snapshot.members.is_empty() {
+            self.initial.clone()
+        } else {
+            self.snapshot.members.clone()
+        }
+    }
+
+    fn member(&self, id: u64) -> Option<&RaftPeer> {
+        self.members.iter().find(|peer| peer.id == id)
+    }
+
+    fn majority(&self) -> usize {
+        self.members.len() / 2 + 1
+    }
+
+    /// 是否存在尚未提交的节点变更，同一时间仅允许一次节点变更
+    fn changing(&self) -> bool {
+        self.log[(self.commit - self.snapshot.index) as usize..]
+            .iter()
+            .any(|entry| matches!(entry.command, RaftCommand::Members { .. }))
+    }
+}
+
+impl RaftEntry {
+    /// 序列化为共识日志中的`json`格式
+    pub fn to_vec(&self) -> GeorgeResult<Vec<u8>> {
+        match serde_json::to_vec(self) {
+            Ok(res) => Ok(res),
+            Err(err) => Err(Errs::strs("raft entry to json", err)),
+        }
+    }
+
+    /// 从共识日志中的`json`格式反序列化
+    pub fn from_slice(bytes: &[u8]) -> GeorgeResult<RaftEntry> {
+        match serde_json::from_slice(bytes) {
+            Ok(res) => Ok(res),
+            Err(err) => Err(Errs::strs("raft entry from json", err)),
+        }
+    }
+}
+
+impl RaftCommand {
+    /// 序列化为`json`格式，用于转发至领导者
+    pub fn to_vec(&self) -> GeorgeResult<Vec<u8>> {
+        match serde_json::to_vec(self) {
+            Ok(res) => Ok(res),
+            Err(err) => Err(Errs::strs("raft command to json", err)),
+        }
+    }
+
+    /// 从`json`格式反序列化
+    pub fn from_slice(bytes: &[u8]) -> GeorgeResult<RaftCommand> {
+        match serde_json::from_slice(bytes) {
+            Ok(res) => Ok(res),
+            Err(err) => Err(Errs::strs("raft command from json", err)),
+        }
+    }
+}
+
+impl fmt::Debug for Raft {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Raft")
+            .field("id", &self.id)
+            .field("dir", &self.dir)
+            .finish()
+    }
+}
+
+impl Raft {
+    /// 恢复共识状态及日志，恢复后须调用`start`启动选举、复制及应用线程
+    ///
+    /// ###Params
+    ///
+    /// * id 本节点编号，不为0
+    /// * peers 集群初始节点集合，以新节点身份加入已运行的集群时为空
+    /// * dir 共识状态及日志存储目录
+    /// * options 共识参数
+    /// * transport 节点间通信
+    /// * applier 应用已提交数据变更的回调
+    /// * snapshotter 生成已应用数据快照的回调
+    pub fn new(
+        id: u64,
+        peers: Vec<RaftPeer>,
+        dir: String,
+        options: RaftOptions,
+        transport: Arc<dyn RaftTransport>,
+        applier: RaftApplier,
+        snapshotter: RaftSnapshotter,
+    ) -> GeorgeResult<Arc<Raft>> {
+        if id == 0 {
+            return Err(Errs::str("raft node id can not be 0!"));
+        }
+        if options.heartbeat_interval >= options.election_timeout {
+            return Err(Errs::str(
+                "raft heartbeat interval must be less than election timeout!",
+            ));
+        }
+        if options.snapshot_entries == 0 {
+            return Err(Errs::str("raft snapshot entries can not be 0!"));
+        }
+        if let Err(err) = fs::create_dir_all(&dir) {
+            return Err(Errs::strs("raft dir create", err));
+        }
+        if Filer::exist(format!("{}/{}", dir, RAFT_INSTALL_FILE)) {
+            return Err(Errs::str(
+                "raft snapshot received but not restored, restore it before start!",
+            ));
+        }
+        let mut hard: HardState = read_json(&format!("{}/{}", dir, RAFT_STATE_FILE), "state")?;
+        let snapshot: RaftSnapshot =
+            read_json(&format!("{}/{}", dir, RAFT_SNAPSHOT_FILE), "snapshot")?;
+        // 快照包含的条目均已应用
+        hard.applied = cmp::max(hard.applied, snapshot.index);
+        let log_filepath = format!("{}/{}", dir, RAFT_LOG_FILE);
+        let (log, offsets, size) = read_log(&log_filepath, snapshot.index)?;
+        log::info!(
+            "raft recovery node {} with term {}, snapshot index {}, {} entries and applied index {}",
+            id,
+            hard.term,
+            snapshot.index,
+            log.len(),
+            hard.applied
+        );
+        let mut core = RaftCore {
+            hard,
+            role: RAFT_ROLE_FOLLOWER,
+            leader: 0,
+            contact: Instant::now(),
+            deadline: Instant::now() + options.election_timeout,
+            commit: snapshot.index,
+            snapshot,
+            log,
+            offsets,
+            size,
+            file: Filer::appender(&log_filepath)?,
+            initial: peers,
+            members: vec![],
+            next: HashMap::new(),
+            matched: HashMap::new(),
+            votes: vec![],
+            replicators: HashMap::new(),
+            results: BTreeMap::new(),
+            installing: None,
+            stopped: false,
+        };
+        core.members = core.members_at(core.last_index());
+        Ok(Arc::new(Raft {
+            id,
+            dir,
+            election_timeout: options.election_timeout,
+            heartbeat_interval: options.heartbeat_interval,
+            snapshot_entries: options.snapshot_entries,
+            transport,
+            applier,
+            snapshotter,
+            core: Mutex::new(core),
+            changed: Condvar::new(),
+        }))
+    }
+
+    /// 启动前以接收完毕的领导者快照恢复数据，返回是否恢复了快照
+    ///
+    /// 先校验快照，再清空数据存储路径中的引导文件、配额策略及数据目录并以快照重建，随后以快照重置共识日志。
+    /// 恢复中断后再次调用可继续完成恢复
+    ///
+    /// ###Params
+    ///
+    /// * dir 共识状态及日志存储目录
+    /// * data_dir 数据存储路径
+    pub fn restore(dir: String, data_dir: String) -> GeorgeResult<bool> {
+        let install_filepath = format!("{}/{}", dir, RAFT_INSTALL_FILE);
+        if !Filer::exist(&install_filepath) {
+            return Ok(false);
+        }
+        let snapshot: RaftSnapshot = read_json(&install_filepath, "install")?;
+        let install = format!("{}/{}", dir, RAFT_INSTALL_DIR);
+        let target = snapshot_dir(&dir, snapshot.index);
+        // 上次恢复中断于移动快照目录之后时，以已移动的目录恢复
+        let source = if Path::new(&install).exists() {
+            install.clone()
+        } else {
+            target.clone()
+        };
+        Backup::verify(source.clone())?;
+        for filename in ["bootstrap.ge", "quota.json"].iter() {
+            let filepath = format!("{}/{}", data_dir, filename);
+            if Filer::exist(&filepath) {
+                if let Err(err) = fs::remove_file(&filepath) {
+                    return Err(Errs::strs("raft restore remove", err));
+                }
+            }
+        }
+        let data_path = format!("{}/data", data_dir);
+        if Path::new(&data_path).exists() {
+            if let Err(err) = fs::remove_dir_all(&data_path) {
+                return Err(Errs::strs("raft restore remove", err));
+            }
+        }
+        Backup::restore(source.clone(), data_dir)?;
+        if source == install {
+            if Path::new(&target).exists() {
+                if let Err(err) = fs::remove_dir_all(&target) {
+                    return Err(Errs::strs("raft snapshot remove", err));
+                }
+            }
+            if let Err(err) = fs::rename(&install, &target) {
+                return Err(Errs::strs("raft snapshot rename", err));
+            }
+        }
+        write_json(
+            &format!("{}/{}", dir, RAFT_SNAPSHOT_FILE),
+            &snapshot,
+            "snapshot",
+        )?;
+        let state_filepath = format!("{}/{}", dir, RAFT_STATE_FILE);
+        let mut hard: HardState = read_json(&state_filepath, "state")?;
+        hard.applied = snapshot.index;
+        hard.pending = snapshot.index;
+        write_json(&state_filepath, &hard, "state")?;
+        // 快照之后的条目由领导者重新复制
+        Filer::write_force(format!("{}/{}", dir, RAFT_LOG_FILE), vec![])?;
+        if let Err(err) = fs::remove_file(&install_filepath) {
+            return Err(Errs::strs("raft install remove", err));
+        }
+        clean(&dir, snapshot.index);
+        log::info!(
+            "raft restore snapshot at index {} of term {} into {}",
+            snapshot.index,
+            snapshot.term,
+            dir
+        );
+        Ok(true)
+    }
+
+    /// 启动选举计时及已提交条目的应用线程
+    pub fn start(self: &Arc<Self>) -> GeorgeResult<()> {
+        let raft = self.clone();
+        spawn(format!("raft-ticker-{}", self.id), move || raft.tick())?;
+        let raft = self.clone();
+        spawn(format!("raft-applier-{}", self.id), move || raft.apply())
+    }
+
+    /// 停止参与选举及复制，停止后不再接受写入
+    pub fn stop(&self) {
+        let mut core = self.core.lock().unwrap();
+        core.stopped = true;
+        self.changed.notify_all();
+    }
+
+    /// 本节点编号
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// 集群状态
+    pub fn status(&self) -> RaftStatus {
+        let core = self.core.lock().unwrap();
+        RaftStatus {
+            id: self.id,
+            role: core.role.to_string(),
+            term: core.hard.term,
+            leader: core.leader,
+            leader_addr: core
+                .member(core.leader)
+                .map_or_else(String::new, |peer| peer.addr.clone()),
+            last_index: core.last_index(),
+            commit: core.commit,
+            applied: core.hard.applied,
+            members: core.members.clone(),
+        }
+    }
+
+    /// 提交数据变更，待变更在本节点应用后返回应用结果
+    ///
+    /// 领导者直接追加，其它节点转发至领导者追加，本节点应用后返回即可读到该变更
+    pub fn propose(self: &Arc<Self>, op: MutationOp) -> GeorgeResult<Option<Expectation>> {
+        self.submit(RaftCommand::Mutation { op })
+    }
+
+    /// 新增节点，新节点须以加入已运行集群的身份启动
+    pub fn member_add(self: &Arc<Self>, peer: RaftPeer) -> GeorgeResult<()> {
+        self.submit(RaftCommand::Join { peer }).map(|_| ())
+    }
+
+    /// 移除节点，移除领导者时领导者在变更提交后退位
+    pub fn member_remove(self: &Arc<Self>, id: u64) -> GeorgeResult<()> {
+        self.submit(RaftCommand::Leave { id }).map(|_| ())
+    }
+
+    /// 处理候选者的投票请求
+    pub fn handle_vote(&self, request: VoteRequest) -> GeorgeResult<VoteResponse> {
+        let mut core = self.core.lock().unwrap();
+        if core.stopped {
+            return Err(Errs::str("raft stopped!"));
+        }
+        if request.term > core.hard.term {
+            // 仍能收到领导者心跳时拒绝，避免网络隔离后恢复或已被移除的节点打断集群
+            if core.role == RAFT_ROLE_LEADER
+                || (core.leader != 0 && core.contact.elapsed() < self.election_timeout)
+            {
+                return Ok(VoteResponse {
+                    term: core.hard.term,
+                    granted: false,
+                });
+            }
+            self.step_down(&mut core, request.term);
+        }
+        let up_to_date = request.last_term > core.last_term()
+            || (request.last_term == core.last_term() && request.last_index >= core.last_index());
+        let granted = request.term == core.hard.term
+            && (core.hard.voted_for == 0 || core.hard.voted_for == request.candidate)
+            && up_to_date;
+        if granted {
+            core.hard.voted_for = request.candidate;
+            self.store(&core)?;
+            core.deadline = self.deadline();
+        }
+        Ok(VoteResponse {
+            term: core.hard.term,
+            granted,
+        })
+    }
+
+    /// 处理领导者的日志复制请求
+    pub fn handle_append(&self, request: AppendRequest) -> GeorgeResult<AppendResponse> {
+        let mut core = self.core.lock().unwrap();
+        if core.stopped {
+            return Err(Errs::str("raft stopped!"));
+        }
+        if request.term < core.hard.term {
+            return Ok(AppendResponse {
+                term: core.hard.term,
+                success: false,
+                last_index: core.last_index(),
+            });
+        }
+        if request.term > core.hard.term || core.role != RAFT_ROLE_FOLLOWER {
+            self.step_down(&mut core, request.term);
+        }
+        core.leader = request.leader;
+        core.contact = Instant::now();
+        core.deadline = self.deadline();
+        if request.prev_index > core.last_index() {
+            return Ok(AppendResponse {
+                term: core.hard.term,
+                success: false,
+                last_index: core.last_index(),
+            });
+        }
+        // 快照包含的条目均已提交，与领导者一致
+        if request.prev_index >= core.snapshot.index
+            && core.term_at(request.prev_index) != request.prev_term
+        {
+            return Ok(AppendResponse {
+                term: core.hard.term,
+                success: false,
+                last_index: request.prev_index - 1,
+            });
+        }
+        let matched = request.prev_index + request.entries.len() as u64;
+        let mut fresh = vec![];
+        for entry in request.entries {
+            if entry.index <= core.snapshot.index {
+                continue;
+            }
+            if entry.index <= core.last_index() {
+                if core.term_at(entry.index) == entry.term {
+                    continue;
+                }
+                if entry.index <= core.commit {
+                    return Err(Errs::string(format!(
+                        "raft committed entry {} conflict with leader {}!",
+                        entry.index, request.leader
+                    )));
+                }
+                self.truncate(&mut core, entry.index - 1)?;
+            }
+            fresh.push(entry);
+        }
+        if !fresh.is_empty() {
+            self.append(&mut core, fresh)?;
+        }
+        if request.commit > core.commit {
+            core.commit = cmp::max(core.commit, cmp::min(request.commit, matched));
+        }
+        self.changed.notify_all();
+        Ok(AppendResponse {
+            term: core.hard.term,
+            success: true,
+            last_index: matched,
+        })
+    }
+
+    /// 处理领导者的安装快照请求，接收完毕后停止共识，待重启时以快照恢复数据
+    pub fn handle_install(&self, request: InstallRequest) -> GeorgeResult<InstallResponse> {
+        let mut core = self.core.lock().unwrap();
+        if core.stopped {
+            return Err(Errs::str("raft stopped!"));
+        }
+        if request.term < core.hard.term {
+            return Ok(InstallResponse {
+                term: core.hard.term,
+            });
+        }
+        if request.term > core.hard.term || core.role != RAFT_ROLE_FOLLOWER {
+            self.step_down(&mut core, request.term);
+        }
+        core.leader = request.leader;
+        core.contact = Instant::now();
+        core.deadline = self.deadline();
+        let term = core.hard.term;
+        // 已提交的条目与领导者一致，由日志复制补齐快照之后的条目即可
+        if request.snapshot.index <= core.commit {
+            return Ok(InstallResponse { term });
+        }
+        let dir = format!("{}/{}", self.dir, RAFT_INSTALL_DIR);
+        let receiving = (request.term, request.snapshot.index);
+        if request.first {
+            if Path::new(&dir).exists() {
+                if let Err(err) = fs::remove_dir_all(&dir) {
+                    return Err(Errs::strs("raft install remove", err));
+                }
+            }
+            core.installing = Some(receiving);
+        } else if core.installing != Some(receiving) {
+            return Err(Errs::str(
+                "raft snapshot install interrupted, resend from the first chunk!",
+            ));
+        }
+        if !request.done {
+            // 领导者下发的路径须位于接收目录内，避免写出接收目录
+            Backup::check_path(&request.path)?;
+            let filepath = format!("{}/{}", dir, request.path);
+            Filer::try_touch(&filepath)?;
+            Filer::append(&filepath, request.data)?;
+            return Ok(InstallResponse { term });
+        }
+        core.installing = None;
+        Backup::verify(dir)?;
+        write_json(
+            &format!("{}/{}", self.dir, RAFT_INSTALL_FILE),
+            &request.snapshot,
+            "install",
+        )?;
+        core.stopped = true;
+        self.changed.notify_all();
+        log::error!(
+            "raft node {} received snapshot at index {} from leader {}, restart to restore it!",
+            self.id,
+            request.snapshot.index,
+            request.leader
+        );
+        Ok(InstallResponse { term })
+    }
+
+    /// 处理其它节点转发的条目内容，仅领导者可追加，返回条目索引及任期
+    pub fn handle_forward(self: &Arc<Self>, command: RaftCommand) -> GeorgeResult<(u64, u64)> {
+        let mut core = self.core.lock().unwrap();
+        if core.role != RAFT_ROLE_LEADER {
+            return Err(Errs::string(format!(
+                "raft node {} is not leader, try again later!",
+                self.id
+            )));
+        }
+        self.append_local(&mut core, command)
+    }
+
+    /// 追加条目内容并等待本节点应用
+    fn submit(self: &Arc<Self>, command: RaftCommand) -> GeorgeResult<Option<Expectation>> {
+        let leader = {
+            let mut core = self.core.lock().unwrap();
+            if core.stopped {
+                return Err(Errs::str("raft stopped!"));
+            }
+            if core.role == RAFT_ROLE_LEADER {
+                let (index, term) = self.append_local(&mut core, command)?;
+                drop(core);
+                return self.wait(index, term);
+            }
+            core.member(core.leader).cloned()
+        };
+        match leader {
+            Some(peer) => {
+                let (index, term) = self.transport.forward(&peer, command)?;
+                self.wait(index, term)
+            }
+            None => Err(Errs::str(
+                "cluster has no leader at present, try again later!",
+            )),
+        }
+    }
+
+    /// 领导者追加条目内容，返回条目索引及任期
+    fn append_local(
+        self: &Arc<Self>,
+        core: &mut RaftCore,
+        command: RaftCommand,
+    ) -> GeorgeResult<(u64, u64)> {
+        let command = match command {
+            RaftCommand::Join { peer } => {
+                if core.changing() {
+                    return Err(Errs::str("raft membership change in progress!"));
+                }
+                if peer.id == 0 {
+                    return Err(Errs::str("raft node id can not be 0!"));
+                }
+                if core.member(peer.id).is_some() {
+                    return Err(Errs::string(format!(
+                        "raft node {} is already a member!",
+                        peer.id
+                    )));
+                }
+                let mut peers = core.members.clone();
+                peers.push(peer);
+                RaftCommand::Members { peers }
+            }
+            RaftCommand::Leave { id } => {
+                if core.changing() {
+                    return Err(Errs::str("raft membership change in progress!"));
+                }
+                if core.member(id).is_none() {
+                    return Err(Errs::string(format!("raft node {} is not a member!", id)));
+                }
+                if core.members.len() == 1 {
+                    return Err(Errs::str("raft can not remove the last member!"));
+                }
+                let mut peers = core.members.clone();
+                peers.retain(|peer| peer.id != id);
+                RaftCommand::Members { peers }
+            }
+            RaftCommand::Members { .. } => {
+                return Err(Errs::str(
+                    "raft members can only be changed by join or leave!",
+                ))
+            }
+            command => command,
+        };
+        let (index, term) = (core.last_index() + 1, core.hard.term);
+        self.append(
+            core,
+            vec![RaftEntry {
+                index,
+                term,
+                command,
+            }],
+        )?;
+        self.replicators(core);
+        self.advance(core);
+        self.changed.notify_all();
+        Ok((index, term))
+    }
+
+    /// 等待指定条目在本节点应用，条目被新领导者覆盖时返回错误
+    fn wait(&self, index: u64, term: u64) -> GeorgeResult<Option<Expectation>> {
+        let deadline = Instant::now() + PROPOSE_TIMEOUT;
+        let mut core = self.core.lock().unwrap();
+        loop {
+            if let Some((entry_term, result)) = core.results.remove(&index) {
+                return if entry_term == term {
+                    result
+                } else {
+                    Err(Errs::str(
+                        "raft entry overwritten by new leader, try again later!",
+                    ))
+                };
+            }
+            if core.hard.applied >= index {
+                return Err(Errs::string(format!(
+                    "raft result of entry {} expired!",
+                    index
+                )));
+            }
+            if core.stopped {
+                return Err(Errs::str("raft stopped!"));
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                return Err(Errs::string(format!(
+                    "raft wait entry {} timeout, it may be applied later!",
+                    index
+                )));
+            }
+            core = self.changed.wait_timeout(core, deadline - now).unwrap().0;
+        }
+    }
+
+    /// 选举计时，选举超时且本节点为集群成员时发起选举
+    fn tick(self: Arc<Self>) {
+        let interval = self.heartbeat_interval / 2;
+        loop {
+            thread::sleep(interval);
+            let mut core = self.core.lock().unwrap();
+            if core.stopped {
+                return;
+            }
+            if core.role == RAFT_ROLE_LEADER || Instant::now() < core.deadline {
+                continue;
+            }
+            if core.member(self.id).is_some() {
+                self.campaign(&mut core)
+            } else {
+                core.deadline = self.deadline()
+            }
+        }
+    }
+
+    /// 进入新任期并向其它节点请求投票
+    fn campaign(self: &Arc<Self>, core: &mut RaftCore) {
+        core.hard.term += 1;
+        core.hard.voted_for = self.id;
+        core.role = RAFT_ROLE_CANDIDATE;
+        core.leader = 0;
+        core.votes = vec![self.id];
+        core.deadline = self.deadline();
+        if let Err(err) = self.store(core) {
+            log::error!("raft node {} campaign failed! error is {}", self.id, err);
+            return;
+        }
+        log::info!(
+            "raft node {} start election of term {}",
+            self.id,
+            core.hard.term
+        );
+        if self.elected(core) {
+            self.become_leader(core);
+            return;
+        }
+        let request = VoteRequest {
+            term: core.hard.term,
+            candidate: self.id,
+            last_index: core.last_index(),
+            last_term: core.last_term(),
+        };
+        for peer in core.members.iter().filter(|peer| peer.id != self.id) {
+            let (raft, peer, request) = (self.clone(), peer.clone(), request.clone());
+            let name = format!("raft-vote-{}-{}", self.id, peer.id);
+            if let Err(err) = spawn(name, move || raft.request_vote(peer, request)) {
+                log::error!(
+                    "raft node {} request vote failed! error is {}",
+                    self.id,
+                    err
+                )
+            }
+        }
+    }
+
+    fn request_vote(self: Arc<Self>, peer: RaftPeer, request: VoteRequest) {
+        let result = self.transport.vote(&peer, request.clone());
+        let mut core = self.core.lock().unwrap();
+        match result {
+            Ok(resp) => {
+                if resp.term > core.hard.term {
+                    self.step_down(&mut core, resp.term);
+                } else if resp.granted
+                    && core.role == RAFT_ROLE_CANDIDATE
+                    && core.hard.term == request.term
+                {
+                    if !core.votes.contains(&peer.id) {
+                        core.votes.push(peer.id);
+                    }
+                    if self.elected(&core) {
+                        self.become_leader(&mut core);
+                    }
+                }
+            }
+            Err(err) => log::debug!(
+                "raft node {} request vote from {} failed! error is {}",
+                self.id,
+                peer.id,
+                err
+            ),
+        }
+    }
+
+    /// 是否已获得多数节点投票
+    fn elected(&self, core: &RaftCore) -> bool {
+        let count = core
+            .votes
+            .iter()
+            .filter(|id| core.member(**id).is_some())
+            .count();
+        count >= core.majority()
+    }
+
+    fn become_leader(self: &Arc<Self>, core: &mut RaftCore) {
+        core.role = RAFT_ROLE_LEADER;
+        core.leader = self.id;
+        let next = core.last_index() + 1;
+        core.next.clear();
+        core.matched.clear();
+        for id in core
+            .members
+            .iter()
+            .map(|peer| peer.id)
+            .collect::<Vec<u64>>()
+        {
+            core.next.insert(id, next);
+            core.matched.insert(id, 0);
+        }
+        log::info!(
+            "raft node {} become leader of term {}",
+            self.id,
+            core.hard.term
+        );
+        // 日志及快照中尚无节点集合时记录初始节点集合，供此后加入的节点获知全部节点
+        let recorded = !core.snapshot.members.is_empty()
+            || core
+                .log
+                .iter()
+                .any(|entry| matches!(entry.command, RaftCommand::Members { .. }));
+        let command = if recorded {
+            RaftCommand::Noop
+        } else {
+            RaftCommand::Members {
+                peers: core.members.clone(),
+            }
+        };
+        let entry = RaftEntry {
+            index: next,
+            term: core.hard.term,
+            command,
+        };
+        if let Err(err) = self.append(core, vec![entry]) {
+            log::error!(
+                "raft leader {} append noop failed! error is {}",
+                self.id,
+                err
+            );
+            let term = core.hard.term;
+            self.step_down(core, term);
+            return;
+        }
+        self.replicators(core);
+        self.advance(core);
+        self.changed.notify_all();
+    }
+
+    /// 转为跟随者，任期更大时更新任期并清空投票
+    fn step_down(&self, core: &mut RaftCore, term: u64) {
+        if term > core.hard.term {
+            core.hard.term = term;
+            core.hard.voted_for = 0;
+            core.leader = 0;
+            if let Err(err) = self.store(core) {
+                log::error!("raft node {} store state failed! error is {}", self.id, err)
+            }
+        }
+        if core.role != RAFT_ROLE_FOLLOWER {
+            log::info!(
+                "raft node {} step down to follower of term {}",
+                self.id,
+                core.hard.term
+            );
+            core.role = RAFT_ROLE_FOLLOWER;
+            core.deadline = self.deadline();
+        }
+        self.changed.notify_all();
+    }
+
+    /// 为尚无复制线程的节点启动复制线程，复制线程在本节点不再是该任期的领导者时退出
+    fn replicators(self: &Arc<Self>, core: &mut RaftCore) {
+        if core.role != RAFT_ROLE_LEADER {
+            return;
+        }
+        let term = core.hard.term;
+        let ids: Vec<u64> = core
+            .members
+            .iter()
+            .map(|peer| peer.id)
+            .filter(|id| *id != self.id && core.replicators.get(id) != Some(&term))
+            .collect();
+        for id in ids {
+            let next = core.last_index() + 1;
+            core.next.entry(id).or_insert(next);
+            core.matched.entry(id).or_insert(0);
+            let raft = self.clone();
+            let name = format!("raft-replicator-{}-{}", self.id, id);
+            match spawn(name, move || raft.replicate(id, term)) {
+                Ok(()) => {
+                    core.replicators.insert(id, term);
+                }
+                Err(err) => log::error!(
+                    "raft leader {} replicate to {} failed! error is {}",
+                    self.id,
+                    id,
+                    err
+                ),
+            }
+        }
+    }
+
+    /// 向指定节点持续复制日志，无待复制条目时按心跳间隔发送心跳，所需条目已被快照丢弃时发送快照
+    fn replicate(self: Arc<Self>, id: u64, term: u64) {
+        let mut core = self.core.lock().unwrap();
+        loop {
+            if core.stopped || core.role != RAFT_ROLE_LEADER || core.hard.term != term {
+                break;
+            }
+            let peer = match core.member(id) {
+                Some(peer) => peer.clone(),
+                None => break,
+            };
+            let next = core.next.get(&id).copied().unwrap_or(1);
+            if next <= core.snapshot.index {
+                let snapshot = core.snapshot.clone();
+                drop(core);
+                let result = self.install(&peer, term, &snapshot);
+                core = self.core.lock().unwrap();
+                match result {
+                    Ok(resp_term) => {
+                        if resp_term > core.hard.term {
+                            self.step_down(&mut core, resp_term);
+                            break;
+                        }
+                        if core.role != RAFT_ROLE_LEADER || core.hard.term != term {
+                            break;
+                        }
+                        let matched =
+                            cmp::max(core.matched.get(&id).copied().unwrap_or(0), snapshot.index);
+                        core.matched.insert(id, matched);
+                        core.next.insert(id, matched + 1);
+                        self.advance(&mut core);
+                    }
+                    Err(err) => {
+                        log::debug!(
+                            "raft leader {} install snapshot to {} failed! error is {}",
+                            self.id,
+                            id,
+                            err
+                        );
+                        core = self
+                            .changed
+                            .wait_timeout(core, self.heartbeat_interval)
+                            .unwrap()
+                            .0;
+                    }
+                }
+                continue;
+            }
+            let prev_index = next - 1;
+            let end = cmp::min(core.last_index(), prev_index + APPEND_BATCH);
+            let request = AppendRequest {
+                term,
+                leader: self.id,
+                prev_index,
+                prev_term: core.term_at(prev_index),
+                entries: core.entries(prev_index, end).to_vec(),
+                commit: core.commit,
+            };
+            let sent_commit = request.commit;
+            drop(core);
+            let result = self.transport.append(&peer, request);
+            core = self.core.lock().unwrap();
+            match result {
+                Ok(resp) => {
+                    if resp.term > core.hard.term {
+                        self.step_down(&mut core, resp.term);
+                        break;
+                    }
+                    if core.role != RAFT_ROLE_LEADER || core.hard.term != term {
+                        break;
+                    }
+                    if !resp.success {
+                        let next = cmp::max(1, cmp::min(next - 1, resp.last_index + 1));
+                        core.next.insert(id, next);
+                        continue;
+                    }
+                    let matched =
+                        cmp::max(core.matched.get(&id).copied().unwrap_or(0), resp.last_index);
+                    core.matched.insert(id, matched);
+                    core.next.insert(id, matched + 1);
+                    self.advance(&mut core);
+                }
+                Err(err) => {
+                    log::debug!(
+                        "raft leader {} append to {} failed! error is {}",
+                        self.id,
+                        id,
+                        err
+                    );
+                    core = self
+                        .changed
+                        .wait_timeout(core, self.heartbeat_interval)
+                        .unwrap()
+                        .0;
+                    continue;
+                }
+            }
+            // 无待复制条目且提交索引未变化时等待至下次心跳
+            let since = Instant::now();
+            while !core.stopped
+                && core.role == RAFT_ROLE_LEADER
+                && core.hard.term == term
+                && core.next.get(&id).copied().unwrap_or(1) > core.last_index()
+                && core.commit <= sent_commit
+                && since.elapsed() < self.heartbeat_interval
+            {
+                let timeout = self.heartbeat_interval - since.elapsed();
+                core = self.changed.wait_timeout(core, timeout).unwrap().0;
+            }
+        }
+        if core.replicators.get(&id) == Some(&term) {
+            core.replicators.remove(&id);
+        }
+    }
+
+    /// 领导者推进提交索引，仅提交当前任期的条目，此前任期的条目随之提交
+    fn advance(&self, core: &mut RaftCore) {
+        if core.role != RAFT_ROLE_LEADER {
+            return;
+        }
+        let mut index = core.last_index();
+        while index > core.commit {
+            if core.term_at(index) == core.hard.term {
+                let count = core
+                    .members
+                    .iter()
+                    .filter(|peer| {
+                        peer.id == self.id
+                            || core
+                                .matched
+                                .get(&peer.id)
+                                .map_or(false, |res| *res >= index)
+                    })
+                    .count();
+                if count >= core.majority() {
+                    core.commit = index;
+                    self.changed.notify_all();
+                    return;
+                }
+            }
+            index -= 1;
+        }
+    }
+
+    /// 按序应用已提交的条目，并记录应用结果供等待的提交方获取
+    ///
+    /// 开始应用一批条目前记录其中最大的条目索引，每应用一条后记录已应用索引，应用失败且重试无效时停止共识
+    fn apply(self: Arc<Self>) {
+        loop {
+            let mut core = self.core.lock().unwrap();
+            while !core.stopped && core.commit <= core.hard.applied {
+                core = self
+                    .changed
+                    .wait_timeout(core, self.heartbeat_interval)
+                    .unwrap()
+                    .0;
+            }
+            if core.stopped {
+                return;
+            }
+            let entries = core.entries(core.hard.applied, core.commit).to_vec();
+            let replay = core.hard.pending;
+            core.hard.pending = cmp::max(core.hard.pending, core.commit);
+            if let Err(err) = self.store(&core) {
+                let index = core.hard.applied + 1;
+                self.halt(&mut core, index, err);
+                return;
+            }
+            drop(core);
+            for entry in entries {
+                let result = match entry.command {
+                    RaftCommand::Mutation { op } => match self.execute(op, entry.index, replay) {
+                        Ok(res) => res,
+                        Err(err) => {
+                            let mut core = self.core.lock().unwrap();
+                            self.halt(&mut core, entry.index, err);
+                            return;
+                        }
+                    },
+                    _ => Ok(None),
+                };
+                let mut core = self.core.lock().unwrap();
+                core.hard.applied = entry.index;
+                core.results.insert(entry.index, (entry.term, result));
+                while core.results.len() > RESULT_CAPACITY {
+                    let first = *core.results.keys().next().unwrap();
+                    core.results.remove(&first);
+                }
+                if let Err(err) = self.store(&core) {
+                    self.halt(&mut core, entry.index, err);
+                    return;
+                }
+                self.changed.notify_all();
+            }
+            let mut core = self.core.lock().unwrap();
+            // 领导者被移除且变更已提交后退位
+            if core.role == RAFT_ROLE_LEADER && core.member(self.id).is_none() {
+                core.role = RAFT_ROLE_FOLLOWER;
+                core.leader = 0;
+                log::info!("raft leader {} removed, step down", self.id);
+            }
+            self.changed.notify_all();
+            drop(core);
+            // 快照失败不影响已应用的条目，下次应用后重试
+            if let Err(err) = self.compact() {
+                log::error!("raft node {} snapshot failed! error is {}", self.id, err)
+            }
+        }
+    }
+
+    /// 应用数据变更，外层错误表示应用失败，内层结果为应用结果
+    ///
+    /// 对象已存在或不存在等类型明确的失败在各节点一致，作为应用结果返回；其余失败可能仅发生在本节点，
+    /// 重试后仍失败时返回错误，以免本节点跳过变更后与其它节点不一致
+    fn execute(
+        &self,
+        op: MutationOp,
+        index: u64,
+        replay: u64,
+    ) -> GeorgeResult<GeorgeResult<Option<Expectation>>> {
+        let mut retries = 0;
+        loop {
+            let err = match (self.applier)(op.clone()) {
+                Ok(res) => return Ok(Ok(res)),
+                Err(err) => err,
+            };
+            if !matches!(err, GeorgeError::StringError(_)) {
+                if index <= replay && Errs::state_conflict(&err) {
+                    log::warn!(
+                        "raft node {} replay entry {} may be applied before restart! error is {}",
+                        self.id,
+                        index,
+                        err
+                    )
+                }
+                return Ok(Err(err));
+            }
+            if retries >= APPLY_RETRIES {
+                return Err(err);
+            }
+            retries += 1;
+            log::warn!(
+                "raft node {} apply entry {} failed, retry {} in {:?}! error is {}",
+                self.id,
+                index,
+                retries,
+                APPLY_RETRY_INTERVAL,
+                err
+            );
+            thread::sleep(APPLY_RETRY_INTERVAL);
+        }
+    }
+
+    /// 应用条目失败时停止共识，已应用索引停留在失败条目之前，重启后从该条目重试
+    fn halt(&self, core: &mut RaftCore, index: u64, err: GeorgeError) {
+        log::error!(
+            "raft node {} apply entry {} failed and stopped, restart after fixing! error is {}",
+            self.id,
+            index,
+            err
+        );
+        core.stopped = true;
+        core.role = RAFT_ROLE_FOLLOWER;
+        core.leader = 0;
+        self.changed.notify_all();
+    }
+
+    /// 自上次快照起已应用的条目达到快照间隔时生成快照，随后丢弃快照包含的日志条目
+    ///
+    /// 由应用线程在两批条目之间调用，快照与已应用索引一致
+    fn compact(&self) -> GeorgeResult<()> {
+        let snapshot = {
+            let core = self.core.lock().unwrap();
+            if core.hard.applied - core.snapshot.index < self.snapshot_entries {
+                return Ok(());
+            }
+            RaftSnapshot {
+                index: core.hard.applied,
+                term: core.term_at(core.hard.applied),
+                members: core.members_at(core.hard.applied),
+            }
+        };
+        let tmp = format!("{}/{}", self.dir, RAFT_SNAPSHOT_TMP);
+        if Path::new(&tmp).exists() {
+            if let Err(err) = fs::remove_dir_all(&tmp) {
+                return Err(Errs::strs("raft snapshot remove", err));
+            }
+        }
+        (self.snapshotter)(tmp.clone())?;
+        let target = snapshot_dir(&self.dir, snapshot.index);
+        if Path::new(&target).exists() {
+            if let Err(err) = fs::remove_dir_all(&target) {
+                return Err(Errs::strs("raft snapshot remove", err));
+            }
+        }
+        if let Err(err) = fs::rename(&tmp, &target) {
+            return Err(Errs::strs("raft snapshot rename", err));
+        }
+        // 先记录快照再重写日志，重写前中断时恢复日志会跳过快照包含的条目
+        write_json(
+            &format!("{}/{}", self.dir, RAFT_SNAPSHOT_FILE),
+            &snapshot,
+            "snapshot",
+        )?;
+        let mut core = self.core.lock().unwrap();
+        let count = (snapshot.index - core.snapshot.index) as usize;
+        let (file, offsets, size) = self.rewrite(&core.log[count..])?;
+        core.log.drain(..count);
+        core.file = file;
+        core.offsets = offsets;
+        core.size = size;
+        core.snapshot = snapshot;
+        let index = core.snapshot.index;
+        drop(core);
+        clean(&self.dir, index);
+        log::info!(
+            "raft node {} snapshot at index {} and compact log",
+            self.id,
+            index
+        );
+        Ok(())
+    }
+
+    /// 领导者向指定节点按文件分块发送快照，最后发送结束标记，返回跟随者的任期
+    fn install(&self, peer: &RaftPeer, term: u64, snapshot: &RaftSnapshot) -> GeorgeResult<u64> {
+        let dir = snapshot_dir(&self.dir, snapshot.index);
+        let manifest = Backup::manifest(dir.clone())?;
+        let mut paths: Vec<String> = manifest.files.into_iter().map(|file| file.path).collect();
+        paths.push(BACKUP_MANIFEST.to_string());
+        log::info!(
+            "raft leader {} install snapshot at index {} to {}",
+            self.id,
+            snapshot.index,
+            peer.id
+        );
+        let mut first = true;
+        for path in paths {
+            // 快照可能在发送期间被更新的快照替换，此时读取失败，下次复制时重新发送
+            let content = Filer::read_bytes(format!("{}/{}", dir, path))?;
+            // 空文件同样发送一次，以便跟随者创建该文件
+            let mut chunks: Vec<&[u8]> = content.chunks(INSTALL_CHUNK_SIZE).collect();
+            if chunks.is_empty() {
+                chunks.push(&[]);
+            }
+            for chunk in chunks {
+                let resp = self.transport.install(
+                    peer,
+                    InstallRequest {
+                        term,
+                        leader: self.id,
+                        snapshot: snapshot.clone(),
+                        path: path.clone(),
+                        data: chunk.to_vec(),
+                        first,
+                        done: false,
+                    },
+                )?;
+                if resp.term > term {
+                    return Ok(resp.term);
+                }
+                first = false;
+            }
+        }
+        let resp = self.transport.install(
+            peer,
+            InstallRequest {
+                term,
+                leader: self.id,
+                snapshot: snapshot.clone(),
+                path: "".to_string(),
+                data: vec![],
+                first: false,
+                done: true,
+            },
+        )?;
+        Ok(resp.term)
+    }
+
+    /// 追加日志条目并同步至磁盘
+    fn append(&self, core: &mut RaftCore, entries: Vec<RaftEntry>) -> GeorgeResult<()> {
+        let mut bytes = vec![];
+        let mut offsets = vec![];
+        for entry in entries.iter() {
+            offsets.push(core.size + bytes.len() as u64);
+            bytes.append(&mut entry.to_vec()?);
+            bytes.push(b'\n');
+        }
+        if let Err(err) = core.file.write_all(bytes.as_slice()) {
+            return Err(Errs::strs("raft log write", err));
+        }
+        if let Err(err) = core.file.sync_data() {
+            return Err(Errs::strs("raft log sync", err));
+        }
+        let change = entries
+            .iter()
+            .any(|entry| matches!(entry.command, RaftCommand::Members { .. }));
+        core.size += bytes.len() as u64;
+        core.offsets.extend(offsets);
+        core.log.extend(entries);
+        if change {
+            core.members = core.members_at(core.last_index());
+        }
+        Ok(())
+    }
+
+    /// 截断索引`index`之后的日志条目，将日志文件缩短至首个被截断条目的起始位置
+    fn truncate(&self, core: &mut RaftCore, index: u64) -> GeorgeResult<()> {
+        let count = (index - core.snapshot.index) as usize;
+        let size = core.offsets[count];
+        if let Err(err) = core.file.set_len(size) {
+            return Err(Errs::strs("raft log truncate", err));
+        }
+        if let Err(err) = core.file.sync_data() {
+            return Err(Errs::strs("raft log sync", err));
+        }
+        core.log.truncate(count);
+        core.offsets.truncate(count);
+        core.size = size;
+        core.members = core.members_at(core.last_index());
+        log::info!("raft node {} truncate log after index {}", self.id, index);
+        Ok(())
+    }
+
+    /// 以快照之后的条目重写日志文件，返回新的追加句柄、各条目起始位置及文件长度
+    fn rewrite(&self, entries: &[RaftEntry]) -> GeorgeResult<(File, Vec<u64>, u64)> {
+        let filepath = format!("{}/{}", self.dir, RAFT_LOG_FILE);
+        let mut bytes = vec![];
+        let mut offsets = vec![];
+        for entry in entries.iter() {
+            offsets.push(bytes.len() as u64);
+            bytes.append(&mut entry.to_vec()?);
+            bytes.push(b'\n');
+        }
+        let size = bytes.len() as u64;
+        let tmp = format!("{}.tmp", filepath);
+        Filer::write_force(tmp.clone(), bytes)?;
+        if let Err(err) = fs::rename(&tmp, &filepath) {
+            return Err(Errs::strs("raft log rename", err));
+        }
+        Ok((Filer::appender(&filepath)?, offsets, size))
+    }
+
+    /// 持久化共识状态
+    fn store(&self, core: &RaftCore) -> GeorgeResult<()> {
+        write_json(
+            &format!("{}/{}", self.dir, RAFT_STATE_FILE),
+            &core.hard,
+            "state",
+        )
+    }
+
+    /// 下次选举超时时间点，在选举超时基准与两倍基准间随机，避免多个节点同时发起选举
+    fn deadline(&self) -> Instant {
+        let base = self.election_timeout.as_millis() as u64;
+        let jitter = (Time::now().nanos() as u64 / 1000 + self.id * 7919) % (base + 1);
+        Instant::now() + Duration::from_millis(base + jitter)
+    }
+}
+
+/// 读取日志文件中快照之后的条目及其起始位置，末尾未写完整的条目被截断
+///
+/// 快照后重写日志前中断时，日志文件开头可能仍有快照包含的条目，读取时跳过
+fn read_log(filepath: &str, snapshot_index: u64) -> GeorgeResult<(Vec<RaftEntry>, Vec<u64>, u64)> {
+    Filer::try_touch(filepath)?;
+    let bytes = Filer::read_bytes(filepath)?;
+    let (mut log, mut offsets): (Vec<RaftEntry>, Vec<u64>) = (vec![], vec![]);
+    let mut start = 0;
+    while start < bytes.len() {
+        // 缺少换行符的末尾条目未完成同步，与无法解析的条目一并截断
+        let end = match bytes[start..].iter().position(|byte| *byte == b'\n') {
+            Some(res) => start + res,
+            None => {
+                log::warn!("raft log discard incomplete entry at offset {}!", start);
+                break;
+            }
+        };
+        match RaftEntry::from_slice(&bytes[start..end]) {
+            Ok(entry) => {
+                let expect = snapshot_index + log.len() as u64 + 1;
+                if entry.index > snapshot_index {
+                    if entry.index != expect {
+                        return Err(Errs::string(format!(
+                            "raft log expect index {} but read {}!",
+                            expect, entry.index
+                        )));
+                    }
+                    offsets.push(start as u64);
+                    log.push(entry);
+                }
+            }
+            Err(err) => {
+                log::warn!(
+                    "raft log discard entries from offset {}! error is {}",
+                    start,
+                    err
+                );
+                break;
+            }
+        }
+        start = end + 1;
+    }
+    let size = start as u64;
+    if start < bytes.len() {
+        let file = match OpenOptions::new().write(true).open(filepath) {
+            Ok(res) => res,
+            Err(err) => return Err(Errs::strs("raft log open", err)),
+        };
+        if let Err(err) = file.set_len(size) {
+            return Err(Errs::strs("raft log truncate", err));
+        }
+        if let Err(err) = file.sync_data() {
+            return Err(Errs::strs("raft log sync", err));
+        }
+    }
+    Ok((log, offsets, size))
+}
+
+/// 读取`json`格式的状态文件，文件不存在时为默认值
+fn read_json<T: DeserializeOwned + Default>(filepath: &str, name: &str) -> GeorgeResult<T> {
+    if !Filer::exist(filepath) {
+        return Ok(T::default());
+    }
+    match serde_json::from_slice(Filer::read_bytes(filepath)?.as_slice()) {
+        Ok(res) => Ok(res),
+        Err(err) => Err(Errs::strs(&format!("raft {} from json", name), err)),
+    }
+}
+
+/// 以先写临时文件再重命名的方式写入`json`格式的状态文件
+fn write_json<T: Serialize>(filepath: &str, value: &T, name: &str) -> GeorgeResult<()> {
+    let bytes = match serde_json::to_vec(value) {
+        Ok(res) => res,
+        Err(err) => return Err(Errs::strs(&format!("raft {} to json", name), err)),
+    };
+    let tmp = format!("{}.tmp", filepath);
+    Filer::write_force(tmp.clone(), bytes)?;
+    match fs::rename(&tmp, filepath) {
+        Ok(()) => Ok(()),
+        Err(err) => Err(Errs::strs(&format!("raft {} rename", name), err)),
+    }
+}
+
+/// 包含索引`index`及之前条目的快照目录
+fn snapshot_dir(dir: &str, index: u64) -> String {
+    format!("{}/{}{}", dir, RAFT_SNAPSHOT_PREFIX, index)
+}
+
+/// 删除索引`index`对应快照之外的快照目录
+fn clean(dir: &str, index: u64) {
+    let current = format!("{}{}", RAFT_SNAPSHOT_PREFIX, index);
+    let entries = match fs::read_dir(dir) {
+        Ok(res) => res,
+        Err(_) => return,
+    };
+    for entry in entries.flatten() {
+        let name = entry.file_name().to_string_lossy().to_string();
+        if name.starts_with(RAFT_SNAPSHOT_PREFIX) && name != current {
+            if let Err(err) = fs::remove_dir_all(entry.path()) {
+                log::warn!("raft snapshot {} remove failed! error is {}", name, err)
+            }
+        }
+    }
+}
+
+fn spawn<F>(name: String, f: F) -> GeorgeResult<()>
+where
+    F: FnOnce() + Send + 'static,
+{
+    match thread::Builder::new().name(name).spawn(f) {
+        Ok(_) => Ok(()),
+        Err(err) => Err(Errs::strs("raft thread spawn", err)),
+    }
+}
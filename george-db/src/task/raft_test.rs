@@ -0,0 +1,341 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[cfg(test)]
+mod raft {
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex, RwLock};
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    use george_comm::cryptos::hash::HashMD5Handler;
+    use george_comm::cryptos::Hash;
+    use george_comm::errors::{Errs, GeorgeResult};
+    use george_comm::io::file::{FilerHandler, FilerReader, FilerWriter};
+    use george_comm::io::Filer;
+    use george_comm::Time;
+
+    use crate::task::backup::{BackupFile, BackupManifest, BACKUP_MANIFEST};
+    use crate::task::journal::MutationOp;
+    use crate::task::raft::{
+        AppendRequest, AppendResponse, InstallRequest, InstallResponse, RaftCommand, RaftOptions,
+        RaftPeer, RaftTransport, VoteRequest, VoteResponse, RAFT_ROLE_LEADER,
+    };
+    use crate::task::rich::Expectation;
+    use crate::task::Raft;
+
+    /// 进程内网络，被隔离的节点既不可达也无法发出请求
+    #[derive(Default)]
+    struct Network {
+        nodes: RwLock<HashMap<u64, Arc<Raft>>>,
+        isolated: RwLock<Vec<u64>>,
+    }
+
+    struct Link {
+        from: u64,
+        network: Arc<Network>,
+    }
+
+    impl Link {
+        fn node(&self, peer: &RaftPeer) -> GeorgeResult<Arc<Raft>> {
+            let isolated = self.network.isolated.read().unwrap();
+            if isolated.contains(&self.from) || isolated.contains(&peer.id) {
+                return Err(Errs::string(format!("node {} unreachable!", peer.id)));
+            }
+            match self.network.nodes.read().unwrap().get(&peer.id) {
+                Some(res) => Ok(res.clone()),
+                None => Err(Errs::string(format!("node {} not found!", peer.id))),
+            }
+        }
+    }
+
+    impl RaftTransport for Link {
+        fn vote(&self, peer: &RaftPeer, request: VoteRequest) -> GeorgeResult<VoteResponse> {
+            self.node(peer)?.handle_vote(request)
+        }
+
+        fn append(&self, peer: &RaftPeer, request: AppendRequest) -> GeorgeResult<AppendResponse> {
+            self.node(peer)?.handle_append(request)
+        }
+
+        fn forward(&self, peer: &RaftPeer, command: RaftCommand) -> GeorgeResult<(u64, u64)> {
+            self.node(peer)?.handle_forward(command)
+        }
+
+        fn install(
+            &self,
+            peer: &RaftPeer,
+            request: InstallRequest,
+        ) -> GeorgeResult<InstallResponse> {
+            self.node(peer)?.handle_install(request)
+        }
+    }
+
+    type Applied = Arc<Mutex<Vec<MutationOp>>>;
+
+    fn peer(id: u64) -> RaftPeer {
+        RaftPeer {
+            id,
+            addr: format!("node{}", id),
+        }
+    }
+
+    /// 启动节点，名为`broken`的缓存页应用失败，名为`exist`的缓存页视为已存在
+    fn start(
+        network: &Arc<Network>,
+        dir: &str,
+        id: u64,
+        peers: Vec<RaftPeer>,
+        snapshot_entries: u64,
+    ) -> (Arc<Raft>, Applied) {
+        let applied: Applied = Arc::new(Mutex::new(vec![]));
+        let sink = applied.clone();
+        let source = applied.clone();
+        let raft = Raft::new(
+            id,
+            peers,
+            format!("{}/{}", dir, id),
+            RaftOptions {
+                election_timeout: Duration::from_millis(150),
+                heartbeat_interval: Duration::from_millis(30),
+                snapshot_entries,
+            },
+            Arc::new(Link {
+                from: id,
+                network: network.clone(),
+            }),
+            Box::new(move |op: MutationOp| -> GeorgeResult<Option<Expectation>> {
+                if let MutationOp::PageCreate { name, .. } = &op {
+                    match name.as_str() {
+                        "broken" => return Err(Errs::str("disk broken!")),
+                        "exist" => return Err(Errs::page_exist_error()),
+                        _ => {}
+                    }
+                }
+                sink.lock().unwrap().push(op);
+                Ok(None)
+            }),
+            Box::new(move |dir: String| snapshot(dir, source.lock().unwrap().len())),
+        )
+        .unwrap();
+        network.nodes.write().unwrap().insert(id, raft.clone());
+        raft.start().unwrap();
+        (raft, applied)
+    }
+
+    /// 以已应用的变更数作为快照内容，生成与备份目录结构一致的快照
+    fn snapshot(dir: String, count: usize) -> GeorgeResult<()> {
+        let content = count.to_string().into_bytes();
+        let manifest = BackupManifest {
+            version: 1,
+            time: Time::now().nanos(),
+            seq: 0,
+            end_seq: 0,
+            compress: false,
+            files: vec![BackupFile {
+                path: "data/applied".to_string(),
+                size: content.len() as u64,
+                sha256: Hash::sha256(content.clone()),
+            }],
+        };
+        Filer::write_force(format!("{}/data/applied", dir), content)?;
+        Filer::write_force(format!("{}/{}", dir, BACKUP_MANIFEST), manifest.to_vec()?)?;
+        Ok(())
+    }
+
+    fn wait_until(f: impl Fn() -> bool) -> bool {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while Instant::now() < deadline {
+            if f() {
+                return true;
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+        false
+    }
+
+    fn leader(network: &Network) -> Option<Arc<Raft>> {
+        let isolated = network.isolated.read().unwrap();
+        network
+            .nodes
+            .read()
+            .unwrap()
+            .values()
+            .find(|raft| !isolated.contains(&raft.id()) && raft.status().role == RAFT_ROLE_LEADER)
+            .cloned()
+    }
+
+    fn op(name: &str) -> MutationOp {
+        MutationOp::PageCreate {
+            name: name.to_string(),
+            comment: "".to_string(),
+            size: 0,
+            period: 0,
+        }
+    }
+
+    #[test]
+    fn election_replicate() {
+        let dir = format!("src/test/raft/{}", Time::now().nanos());
+        let network = Arc::new(Network::default());
+        let peers = vec![peer(1), peer(2), peer(3)];
+        let nodes: Vec<(Arc<Raft>, Applied)> = (1..=3)
+            .map(|id| start(&network, &dir, id, peers.clone(), 1000))
+            .collect();
+        assert!(wait_until(|| leader(&network).is_some()));
+        let leader_id = leader(&network).unwrap().id();
+        assert!(wait_until(|| nodes
+            .iter()
+            .all(|(raft, _)| raft.status().leader == leader_id)));
+        // 跟随者的写入转发至领导者，返回时本节点已应用
+        let (follower, follower_applied) = nodes
+            .iter()
+            .find(|(raft, _)| raft.id() != leader_id)
+            .unwrap();
+        assert!(follower.propose(op("page1")).unwrap().is_none());
+        assert_eq!(follower_applied.lock().unwrap().len(), 1);
+        assert!(wait_until(|| nodes.iter().all(|(_, applied)| applied
+            .lock()
+            .unwrap()
+            .len()
+            == 1)));
+        // 隔离领导者后剩余节点重新选举并继续提交
+        let term = follower.status().term;
+        network.isolated.write().unwrap().push(leader_id);
+        assert!(wait_until(
+            || leader(&network).map_or(false, |raft| raft.status().term > term)
+        ));
+        let new_leader = leader(&network).unwrap();
+        assert_ne!(new_leader.id(), leader_id);
+        new_leader.propose(op("page2")).unwrap();
+        assert!(wait_until(|| nodes
+            .iter()
+            .filter(|(raft, _)| raft.id() != leader_id)
+            .all(|(_, applied)| applied.lock().unwrap().len() == 2)));
+        // 恢复后原领导者退位并追上日志
+        network.isolated.write().unwrap().clear();
+        assert!(wait_until(|| nodes.iter().all(|(_, applied)| applied
+            .lock()
+            .unwrap()
+            .len()
+            == 2)));
+        for (raft, _) in nodes.iter() {
+            raft.stop()
+        }
+    }
+
+    #[test]
+    fn membership_recovery() {
+        let dir = format!("src/test/raft/{}", Time::now().nanos());
+        let network = Arc::new(Network::default());
+        let (raft1, _) = start(&network, &dir, 1, vec![peer(1)], 1000);
+        assert!(wait_until(|| raft1.status().role == RAFT_ROLE_LEADER));
+        raft1.propose(op("page1")).unwrap();
+        // 新节点以空节点集合启动，加入后从头复制日志
+        let (raft2, applied2) = start(&network, &dir, 2, vec![], 1000);
+        raft1.member_add(peer(2)).unwrap();
+        assert!(raft1.member_add(peer(2)).is_err());
+        assert!(wait_until(|| applied2.lock().unwrap().len() == 1));
+        assert_eq!(raft2.status().members.len(), 2);
+        raft2.propose(op("page2")).unwrap();
+        assert_eq!(applied2.lock().unwrap().len(), 2);
+        raft1.member_remove(2).unwrap();
+        assert_eq!(raft1.status().members, vec![peer(1)]);
+        assert!(raft1.member_remove(1).is_err());
+        raft2.stop();
+        // 重启后恢复任期、日志及已应用索引
+        let status = raft1.status();
+        raft1.stop();
+        network.nodes.write().unwrap().remove(&1);
+        let (raft1, applied1) = start(&network, &dir, 1, vec![peer(1)], 1000);
+        let recovered = raft1.status();
+        assert_eq!(recovered.term, status.term);
+        assert_eq!(recovered.last_index, status.last_index);
+        assert_eq!(recovered.applied, status.applied);
+        assert_eq!(recovered.members, vec![peer(1)]);
+        assert!(wait_until(|| raft1.status().role == RAFT_ROLE_LEADER));
+        raft1.propose(op("page3")).unwrap();
+        assert_eq!(applied1.lock().unwrap().len(), 1);
+        raft1.stop();
+    }
+
+    #[test]
+    fn snapshot_install() {
+        let dir = format!("src/test/raft/{}", Time::now().nanos());
+        let network = Arc::new(Network::default());
+        let (raft1, _) = start(&network, &dir, 1, vec![peer(1)], 3);
+        assert!(wait_until(|| raft1.status().role == RAFT_ROLE_LEADER));
+        for i in 0..6 {
+            raft1.propose(op(&format!("page{}", i))).unwrap();
+        }
+        // 已应用的条目生成快照后从日志中丢弃，重启后从快照之后恢复
+        let snapshot_filepath = format!("{}/1/snapshot.json", dir);
+        assert!(wait_until(|| Filer::exist(&snapshot_filepath)));
+        let status = raft1.status();
+        raft1.stop();
+        network.nodes.write().unwrap().remove(&1);
+        // 等待应用线程完成进行中的快照
+        thread::sleep(Duration::from_millis(200));
+        let (raft1, applied1) = start(&network, &dir, 1, vec![peer(1)], 3);
+        let recovered = raft1.status();
+        assert_eq!(recovered.last_index, status.last_index);
+        assert_eq!(recovered.applied, status.applied);
+        assert!(applied1.lock().unwrap().is_empty());
+        assert!(wait_until(|| raft1.status().role == RAFT_ROLE_LEADER));
+        // 新节点所需条目已被丢弃，由领导者发送快照，接收完毕后停止并待重启恢复
+        let (raft2, _) = start(&network, &dir, 2, vec![], 3);
+        let joining = raft1.clone();
+        let join = thread::spawn(move || joining.member_add(peer(2)));
+        let install_filepath = format!("{}/2/install.json", dir);
+        assert!(wait_until(|| Filer::exist(&install_filepath)));
+        assert!(raft2.propose(op("page6")).is_err());
+        network.nodes.write().unwrap().remove(&2);
+        let data_dir = format!("{}/data2", dir);
+        assert!(Raft::restore(format!("{}/2", dir), data_dir.clone()).unwrap());
+        assert!(!Raft::restore(format!("{}/2", dir), data_dir.clone()).unwrap());
+        assert!(Filer::exist(format!("{}/data/applied", data_dir)));
+        let (raft2, applied2) = start(&network, &dir, 2, vec![], 3);
+        join.join().unwrap().unwrap();
+        assert_eq!(raft2.status().members.len(), 2);
+        raft2.propose(op("page6")).unwrap();
+        // 快照包含的变更不再重放
+        let applied2 = applied2.lock().unwrap();
+        assert!(applied2.len() < 7);
+        assert!(
+            matches!(applied2.last(), Some(MutationOp::PageCreate { name, .. }) if name == "page6")
+        );
+        drop(applied2);
+        raft1.stop();
+        raft2.stop();
+    }
+
+    #[test]
+    fn apply_failure() {
+        let dir = format!("src/test/raft/{}", Time::now().nanos());
+        let network = Arc::new(Network::default());
+        let (raft, applied) = start(&network, &dir, 1, vec![peer(1)], 1000);
+        assert!(wait_until(|| raft.status().role == RAFT_ROLE_LEADER));
+        // 类型明确的失败作为应用结果返回，不影响后续条目
+        assert!(raft.propose(op("exist")).is_err());
+        raft.propose(op("page1")).unwrap();
+        assert_eq!(applied.lock().unwrap().len(), 1);
+        // 重试后仍失败时停止共识，已应用索引停留在失败条目之前
+        let status = raft.status();
+        assert!(raft.propose(op("broken")).is_err());
+        assert!(wait_until(|| raft.status().role != RAFT_ROLE_LEADER));
+        assert_eq!(raft.status().applied, status.applied);
+        assert!(raft.propose(op("page2")).is_err());
+        raft.stop();
+    }
+}
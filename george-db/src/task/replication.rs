@@ -0,0 +1,241 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::fs;
+use std::sync::RwLock;
+
+use serde::{Deserialize, Serialize};
+
+use george_comm::errors::{Errs, GeorgeResult};
+use george_comm::io::file::{FilerHandler, FilerReader, FilerWriter};
+use george_comm::io::Filer;
+use george_comm::Time;
+
+use crate::task::Replication;
+use crate::utils::Paths;
+
+/// 主节点角色，可读写
+pub const REPLICATION_ROLE_PRIMARY: &str = "primary";
+/// 从节点角色，只读，应用主节点推送的变更
+pub const REPLICATION_ROLE_REPLICA: &str = "replica";
+
+/// 复制状态
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplicationState {
+    /// 角色，`primary`或`replica`
+    pub role: String,
+    /// 主节点地址，格式为`host:port`，仅从节点有效
+    pub primary: String,
+    /// 已应用的主节点变更日志序号
+    pub applied: u64,
+    /// 可能已生效的最大主节点变更日志序号，序号不大于此值的变更重复应用时容忍对象已存在或不存在<p>
+    /// 快照中可能已包含快照开始后的变更，应用一批变更前也先行记录，以覆盖应用后、记录已应用序号前的中断
+    #[serde(default)]
+    pub pending: u64,
+    /// 提升为主节点的时间，单位纳秒，为0表示未曾提升
+    pub promoted_time: i64,
+    /// 已知的主节点最新变更日志序号
+    #[serde(skip)]
+    pub primary_seq: u64,
+    /// 最近应用的变更在主节点的提交时间，单位纳秒
+    #[serde(skip)]
+    pub applied_time: i64,
+    /// 最近一次收到主节点推送的时间，单位纳秒
+    #[serde(skip)]
+    pub contact_time: i64,
+}
+
+impl ReplicationState {
+    /// 未参与复制时的初始状态，即主节点
+    fn initial() -> ReplicationState {
+        ReplicationState {
+            role: REPLICATION_ROLE_PRIMARY.to_string(),
+            primary: "".to_string(),
+            applied: 0,
+            pending: 0,
+            promoted_time: 0,
+            primary_seq: 0,
+            applied_time: 0,
+            contact_time: 0,
+        }
+    }
+
+    /// 是否为从节点
+    pub fn replica(&self) -> bool {
+        self.role.eq(REPLICATION_ROLE_REPLICA)
+    }
+
+    /// 落后主节点的变更数，主节点为0
+    pub fn lag(&self) -> u64 {
+        if self.replica() {
+            self.primary_seq.saturating_sub(self.applied)
+        } else {
+            0
+        }
+    }
+
+    /// 落后主节点的时长，单位毫秒，以最近应用的变更在主节点的提交时间估算，未落后为0
+    pub fn lag_millis(&self) -> i64 {
+        if self.lag() == 0 || self.applied_time == 0 {
+            0
+        } else {
+            (Time::now().nanos() - self.applied_time) / 1_000_000
+        }
+    }
+}
+
+impl Replication {
+    /// 恢复复制状态，状态文件不存在则为主节点
+    pub(crate) fn recovery() -> GeorgeResult<Replication> {
+        Replication::from(Paths::replication_filepath())
+    }
+
+    pub(crate) fn from(filepath: String) -> GeorgeResult<Replication> {
+        let state = if Filer::exist(filepath.clone()) {
+            match serde_json::from_slice(Filer::read_bytes(filepath.clone())?.as_slice()) {
+                Ok(res) => res,
+                Err(err) => return Err(Errs::strs("replication state from json", err)),
+            }
+        } else {
+            ReplicationState::initial()
+        };
+        log::info!(
+            "replication recovery as {} with applied seq {}",
+            state.role,
+            state.applied
+        );
+        Ok(Replication {
+            filepath,
+            state: RwLock::new(state),
+        })
+    }
+
+    /// 在数据存储路径中写入从节点初始状态，用于从主节点快照恢复数据后、启动服务前
+    ///
+    /// ###Params
+    ///
+    /// * data_dir 数据存储路径
+    /// * primary 主节点地址
+    /// * applied 快照对应的主节点变更日志序号
+    /// * pending 快照结束时的主节点变更日志序号，其间的变更可能已包含在快照中
+    pub fn seed(data_dir: String, primary: String, applied: u64, pending: u64) -> GeorgeResult<()> {
+        let mut state = ReplicationState::initial();
+        state.role = REPLICATION_ROLE_REPLICA.to_string();
+        state.primary = primary;
+        state.applied = applied;
+        state.pending = pending.max(applied);
+        store(&format!("{}/{}", data_dir, "replication.json"), &state)
+    }
+
+    /// 当前复制状态
+    pub fn state(&self) -> ReplicationState {
+        self.state.read().unwrap().clone()
+    }
+
+    /// 是否为从节点
+    pub fn replica(&self) -> bool {
+        self.state.read().unwrap().replica()
+    }
+
+    /// 作为从节点跟随指定主节点，返回是否跟随
+    ///
+    /// 曾被提升为主节点的实例不再跟随，避免重启后误将已承接写入的实例降为从节点
+    pub(crate) fn follow(&self, primary: String) -> GeorgeResult<bool> {
+        let mut state = self.state.write().unwrap();
+        if state.promoted_time > 0 {
+            log::warn!(
+                "replication has been promoted, ignore following primary {}",
+                primary
+            );
+            return Ok(false);
+        }
+        if !state.replica() || state.primary.ne(&primary) {
+            let mut new_state = state.clone();
+            new_state.role = REPLICATION_ROLE_REPLICA.to_string();
+            new_state.primary = primary;
+            store(&self.filepath, &new_state)?;
+            *state = new_state;
+        }
+        log::info!(
+            "replication follow primary {} from applied seq {}",
+            state.primary,
+            state.applied
+        );
+        Ok(true)
+    }
+
+    /// 记录收到主节点推送，更新已知的主节点最新变更日志序号
+    pub(crate) fn contact(&self, primary_seq: u64) {
+        let mut state = self.state.write().unwrap();
+        state.primary_seq = primary_seq;
+        state.contact_time = Time::now().nanos();
+    }
+
+    /// 应用一批变更前记录可能已生效的最大主节点变更日志序号
+    pub(crate) fn pend(&self, pending: u64) -> GeorgeResult<()> {
+        let mut state = self.state.write().unwrap();
+        if pending <= state.pending {
+            return Ok(());
+        }
+        let mut new_state = state.clone();
+        new_state.pending = pending;
+        store(&self.filepath, &new_state)?;
+        *state = new_state;
+        Ok(())
+    }
+
+    /// 记录已应用的主节点变更日志序号及该变更在主节点的提交时间
+    pub(crate) fn apply(&self, applied: u64, applied_time: i64) -> GeorgeResult<()> {
+        let mut state = self.state.write().unwrap();
+        let mut new_state = state.clone();
+        new_state.applied = applied;
+        new_state.applied_time = applied_time;
+        store(&self.filepath, &new_state)?;
+        *state = new_state;
+        Ok(())
+    }
+
+    /// 提升为主节点，返回提升时已应用的主节点变更日志序号
+    pub(crate) fn promote(&self) -> GeorgeResult<u64> {
+        let mut state = self.state.write().unwrap();
+        if !state.replica() {
+            return Err(Errs::str("only replica can be promoted!"));
+        }
+        let mut new_state = state.clone();
+        new_state.role = REPLICATION_ROLE_PRIMARY.to_string();
+        new_state.promoted_time = Time::now().nanos();
+        store(&self.filepath, &new_state)?;
+        *state = new_state;
+        log::info!(
+            "replication promoted from primary {} with applied seq {}",
+            state.primary,
+            state.applied
+        );
+        Ok(state.applied)
+    }
+}
+
+/// 写入复制状态，先写临时文件再替换，避免写入中断后丢失从节点身份
+fn store(filepath: &str, state: &ReplicationState) -> GeorgeResult<()> {
+    let bytes = match serde_json::to_vec(state) {
+        Ok(res) => res,
+        Err(err) => return Err(Errs::strs("replication state to json", err)),
+    };
+    let tmp = format!("{}.tmp", filepath);
+    Filer::write_force(tmp.clone(), bytes)?;
+    match fs::rename(&tmp, filepath) {
+        Ok(()) => Ok(()),
+        Err(err) => Err(Errs::strs("replication state rename", err)),
+    }
+}
@@ -0,0 +1,78 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[cfg(test)]
+mod replication {
+    use std::fs;
+    use std::time::Duration;
+
+    use george_comm::Time;
+
+    use crate::task::journal::MutationOp;
+    use crate::task::traits::TMaster;
+    use crate::task::Replication;
+    use crate::Task;
+
+    #[test]
+    fn follow_promote() {
+        let data_dir = "src/test/replication";
+        let filepath = format!("{}/replication.json", data_dir);
+        let _ = fs::remove_file(&filepath);
+        let replication = Replication::from(filepath.clone()).unwrap();
+        assert!(!replication.replica());
+        assert!(replication.promote().is_err());
+        assert!(replication.follow("127.0.0.1:9219".to_string()).unwrap());
+        replication.apply(5, Time::now().nanos()).unwrap();
+        replication.contact(8);
+        assert_eq!(replication.state().lag(), 3);
+        // 主节点最新序号不持久化，重新恢复后仍为从节点且已应用序号不变
+        let replication = Replication::from(filepath.clone()).unwrap();
+        assert!(replication.replica());
+        assert_eq!(replication.state().applied, 5);
+        assert_eq!(replication.state().primary, "127.0.0.1:9219");
+        assert_eq!(replication.promote().unwrap(), 5);
+        assert_eq!(replication.state().lag(), 0);
+        // 提升后不再跟随
+        let replication = Replication::from(filepath.clone()).unwrap();
+        assert!(!replication.follow("127.0.0.1:9219".to_string()).unwrap());
+        assert!(!replication.replica());
+        Replication::seed(data_dir.to_string(), "127.0.0.1:9229".to_string(), 7, 9).unwrap();
+        let replication = Replication::from(filepath).unwrap();
+        assert!(replication.replica());
+        assert_eq!(replication.state().applied, 7);
+        assert_eq!(replication.state().pending, 9);
+        assert_eq!(replication.state().promoted_time, 0);
+    }
+
+    #[test]
+    fn journal_cursor() {
+        let task = Task::default().unwrap();
+        let database_name = format!("database_replication_test_{}", Time::now().nanos());
+        let (_, seq) = task.replication();
+        task.database_create(database_name.clone(), "".to_string())
+            .unwrap();
+        let (_, current) = task.replication();
+        assert!(current > seq);
+        assert!(task.journal_wait(seq, Duration::from_millis(10)) >= current);
+        let mut cursor = task.journal_cursor(seq).unwrap();
+        let mutations = cursor.next(usize::MAX).unwrap();
+        assert!(mutations.iter().all(|mutation| mutation.seq > seq));
+        assert!(mutations.iter().any(|mutation| match &mutation.op {
+            MutationOp::DatabaseCreate { name, .. } => name.eq(&database_name),
+            _ => false,
+        }));
+        assert!(cursor.seq() >= current);
+        assert!(task.journal_cursor(current + 1_000_000).is_err());
+    }
+}
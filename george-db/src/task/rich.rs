@@ -597,6 +597,31 @@ impl Selector {
         }
         .exec()
     }
+
+    /// 新建检索选择器并逐条输出检索结果<p>
+    ///
+    /// # param
+    /// * constraint_json_bytes 选择器字节数组，自定义转换策略
+    /// * indexes 索引集合
+    /// * sink 检索结果回调，返回错误时终止检索
+    ///
+    /// # return
+    /// * Expectation 经由Selector后的期望结果，其中检索结果集合为空
+    pub(crate) fn run_stream(
+        constraint_json_bytes: Vec<u8>,
+        indexes: Arc<RwLock<HashMap<String, Arc<dyn TIndex>>>>,
+        sink: &mut dyn FnMut(Vec<u8>) -> GeorgeResult<()>,
+    ) -> GeorgeResult<Expectation> {
+        // 新建查询约束，流式检索不支持删除检索结果
+        let constraint = Constraint::new(constraint_json_bytes, indexes.clone(), false)?;
+        // 新建检索选择器并执行富查询
+        Selector {
+            indexes,
+            constraint,
+        }
+        .exec_stream(sink)
+    }
+
     fn constraint(&self) -> Constraint {
         self.constraint.clone()
     }
@@ -617,6 +642,26 @@ impl Selector {
         )
     }
 
+    /// 执行富查询并逐条输出检索结果<p>
+    ///
+    /// # return
+    /// * Expectation 经由Selector后的期望结果，其中检索结果集合为空
+    fn exec_stream(
+        &mut self,
+        sink: &mut dyn FnMut(Vec<u8>) -> GeorgeResult<()>,
+    ) -> GeorgeResult<Expectation> {
+        // 获取最佳索引
+        let status = self.index()?;
+        self.constraint.conditions = status.conditions;
+        status.index.clone().select_stream(
+            status.asc,
+            status.start,
+            status.end,
+            self.constraint.clone(),
+            sink,
+        )
+    }
+
     /// 获取最佳索引，以减少磁盘读取次数为目的，遵守区间大于一切的准则<p>
     /// # Policy
     /// * 如果有多个闭合区间约束的索引，优先选择区间差最小的，如存在`height`[1..10]和`age`[1..5]时，选择`age`
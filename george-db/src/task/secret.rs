@@ -0,0 +1,259 @@
+/*
+ * Copyright (c) 2020. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::fmt::{Debug, Formatter};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use george_comm::cryptos::hash::HashHmacHandler;
+use george_comm::cryptos::sm4::{SM4Crypt, SM4Handler};
+use george_comm::cryptos::{Hash, SM4};
+use george_comm::errors::{Errs, GeorgeResult};
+#[cfg(not(unix))]
+use george_comm::io::file::FilerWriter;
+use george_comm::io::file::{FilerHandler, FilerReader};
+use george_comm::io::Filer;
+
+use crate::task::Secret;
+use crate::utils::Paths;
+
+/// 登录令牌签名用途
+const SCOPE_TOKEN: &str = "token";
+/// 密钥包装加密用途
+const SCOPE_WRAP: &str = "wrap";
+/// 密钥包装校验用途
+const SCOPE_WRAP_MAC: &str = "wrap-mac";
+/// 节点间认证凭证签名用途
+const SCOPE_PEER: &str = "peer";
+/// 登录令牌有效期，单位秒
+pub(crate) const TOKEN_TTL: u64 = 12 * 60 * 60;
+/// 节点间认证凭证允许的签发时间偏差，单位秒，需容忍节点间的时钟偏差
+const PEER_TTL: u64 = 5 * 60;
+
+impl Debug for Secret {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Secret")
+    }
+}
+
+impl Secret {
+    /// 新建或恢复服务主密钥，首次启动时随机生成并仅允许属主读写
+    pub(crate) fn recovery() -> GeorgeResult<Secret> {
+        let filepath = Paths::secret_filepath();
+        if Filer::exist(filepath.clone()) {
+            let key = match hex::decode(Filer::read(filepath.clone())?.trim()) {
+                Ok(res) => res,
+                Err(err) => return Err(Errs::strs("secret key decode", err)),
+            };
+            if key.len() != 32 {
+                return Err(Errs::string(format!(
+                    "secret key {} must be 32 bytes!",
+                    filepath
+                )));
+            }
+            return Ok(Secret { key });
+        }
+        let mut key = SM4::rand_block().to_vec();
+        key.extend_from_slice(&SM4::rand_block());
+        store(filepath.as_str(), hex::encode(&key))?;
+        log::info!("secret key generate at {}", filepath);
+        Ok(Secret { key })
+    }
+
+    /// 读取节点间认证密钥，各节点配置同一密钥文件，文件内容为`hex`编码的不少于32字节的密钥
+    pub fn peer(filepath: String) -> GeorgeResult<Secret> {
+        let key = match hex::decode(Filer::read(filepath.clone())?.trim()) {
+            Ok(res) => res,
+            Err(err) => return Err(Errs::strs("peer key decode", err)),
+        };
+        if key.len() < 32 {
+            return Err(Errs::string(format!(
+                "peer key {} must be at least 32 bytes!",
+                filepath
+            )));
+        }
+        Ok(Secret { key })
+    }
+
+    /// 签发节点间认证凭证，格式为`hex(nonce).time.hmac`，每次请求前签发
+    pub fn peer_issue(&self) -> GeorgeResult<String> {
+        let body = format!("{}.{}", hex::encode(SM4::rand_block()), now());
+        let hmac = self.hmac(SCOPE_PEER, body.as_bytes())?;
+        Ok(format!("{}.{}", body, hmac))
+    }
+
+    /// 校验节点间认证凭证签名及签发时间
+    pub fn peer_verify(&self, credential: &str) -> GeorgeResult<()> {
+        let parts: Vec<&str> = credential.split('.').collect();
+        if parts.len() != 3 {
+            return Err(Errs::str("peer credential format invalid!"));
+        }
+        let body = format!("{}.{}", parts[0], parts[1]);
+        if !self.hmac_verify(SCOPE_PEER, body.as_bytes(), parts[2])? {
+            return Err(Errs::str("peer credential signature invalid!"));
+        }
+        let time = match parts[1].parse::<u64>() {
+            Ok(res) => res,
+            Err(err) => return Err(Errs::strs("peer credential time parse", err)),
+        };
+        let current = now();
+        if time.max(current) - time.min(current) > PEER_TTL {
+            return Err(Errs::str("peer credential expired!"));
+        }
+        Ok(())
+    }
+
+    /// 以主密钥按用途派生的子密钥计算`data`的HMAC-SHA256
+    ///
+    /// 不同用途使用不同子密钥，避免一处签名被挪用至另一处
+    pub(crate) fn hmac(&self, scope: &str, data: &[u8]) -> GeorgeResult<String> {
+        Hash::hmac_sha256(self.derive(scope)?.as_slice(), data)
+    }
+
+    /// 常量时间校验`data`的HMAC-SHA256
+    pub(crate) fn hmac_verify(&self, scope: &str, data: &[u8], hmac: &str) -> GeorgeResult<bool> {
+        Hash::hmac_sha256_verify(self.derive(scope)?.as_slice(), data, hmac)
+    }
+
+    /// 为已通过密码校验的操作人签发登录令牌，格式为`hex(principal).expire.hmac`
+    ///
+    /// ###Params
+    ///
+    /// * principal 操作人
+    /// * ttl 有效期，单位秒
+    pub(crate) fn token_issue(&self, principal: String, ttl: u64) -> GeorgeResult<String> {
+        let body = format!("{}.{}", hex::encode(principal.as_bytes()), now() + ttl);
+        let hmac = self.hmac(SCOPE_TOKEN, body.as_bytes())?;
+        Ok(format!("{}.{}", body, hmac))
+    }
+
+    /// 校验登录令牌签名及有效期，通过则返回令牌所属操作人
+    pub(crate) fn token_verify(&self, token: &str) -> GeorgeResult<String> {
+        let parts: Vec<&str> = token.split('.').collect();
+        if parts.len() != 3 {
+            return Err(Errs::str("token format invalid!"));
+        }
+        let body = format!("{}.{}", parts[0], parts[1]);
+        if !self.hmac_verify(SCOPE_TOKEN, body.as_bytes(), parts[2])? {
+            return Err(Errs::str("token signature invalid!"));
+        }
+        let expire = match parts[1].parse::<u64>() {
+            Ok(res) => res,
+            Err(err) => return Err(Errs::strs("token expire parse", err)),
+        };
+        if expire < now() {
+            return Err(Errs::str("token expired!"));
+        }
+        match hex::decode(parts[0]) {
+            Ok(res) => match String::from_utf8(res) {
+                Ok(principal) => Ok(principal),
+                Err(err) => Err(Errs::strs("token principal from utf8", err)),
+            },
+            Err(err) => Err(Errs::strs("token principal decode", err)),
+        }
+    }
+
+    /// 以主密钥派生的子密钥加密`data`并附加HMAC，用于落盘保存视图加密密钥等敏感数据
+    ///
+    /// 结果格式为`hex(iv)hex(cipher).hmac`，未持有主密钥者既无法解密也无法篡改
+    pub(crate) fn wrap(&self, data: &[u8]) -> GeorgeResult<String> {
+        let key = self.block(SCOPE_WRAP)?;
+        let iv = SM4::rand_block();
+        let body = format!(
+            "{}{}",
+            hex::encode(iv),
+            hex::encode(SM4::encrypt(key, iv, data))
+        );
+        let hmac = self.hmac(SCOPE_WRAP_MAC, body.as_bytes())?;
+        Ok(format!("{}.{}", body, hmac))
+    }
+
+    /// 校验并解密`wrap`的结果
+    pub(crate) fn unwrap(&self, wrapped: &str) -> GeorgeResult<Vec<u8>> {
+        let (body, hmac) = match wrapped.rfind('.') {
+            Some(index) => (&wrapped[..index], &wrapped[index + 1..]),
+            None => return Err(Errs::str("wrapped format invalid!")),
+        };
+        if !self.hmac_verify(SCOPE_WRAP_MAC, body.as_bytes(), hmac)? {
+            return Err(Errs::str(
+                "wrapped signature invalid, secret key may not match!",
+            ));
+        }
+        let bytes = match hex::decode(body) {
+            Ok(res) => res,
+            Err(err) => return Err(Errs::strs("wrapped decode", err)),
+        };
+        if bytes.len() < 16 {
+            return Err(Errs::str("wrapped format invalid!"));
+        }
+        let mut iv: [u8; 16] = [0; 16];
+        iv.copy_from_slice(&bytes[..16]);
+        Ok(SM4::decrypt(self.block(SCOPE_WRAP)?, iv, &bytes[16..]))
+    }
+
+    /// 按用途派生16字节`sm4`子密钥
+    fn block(&self, scope: &str) -> GeorgeResult<[u8; 16]> {
+        let mut block: [u8; 16] = [0; 16];
+        block.copy_from_slice(&self.derive(scope)?[..16]);
+        Ok(block)
+    }
+
+    fn derive(&self, scope: &str) -> GeorgeResult<Vec<u8>> {
+        let hmac = Hash::hmac_sha256(self.key.as_slice(), scope.as_bytes())?;
+        match hex::decode(hmac) {
+            Ok(res) => Ok(res),
+            Err(err) => Err(Errs::strs("secret derive", err)),
+        }
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|res| res.as_secs())
+        .unwrap_or(0)
+}
+
+/// 写入主密钥文件，unix下创建时即仅允许属主读写，避免写入与修改权限间的窗口期
+#[cfg(unix)]
+fn store(filepath: &str, content: String) -> GeorgeResult<()> {
+    use std::io::Write;
+    use std::os::unix::fs::OpenOptionsExt;
+    if let Some(parent) = std::path::Path::new(filepath).parent() {
+        if let Err(err) = std::fs::create_dir_all(parent) {
+            return Err(Errs::strs("secret key dir create", err));
+        }
+    }
+    let mut file = match std::fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .mode(0o600)
+        .open(filepath)
+    {
+        Ok(res) => res,
+        Err(err) => return Err(Errs::strs("secret key create", err)),
+    };
+    match file
+        .write_all(content.as_bytes())
+        .and_then(|()| file.sync_all())
+    {
+        Ok(()) => Ok(()),
+        Err(err) => Err(Errs::strs("secret key write", err)),
+    }
+}
+
+#[cfg(not(unix))]
+fn store(filepath: &str, content: String) -> GeorgeResult<()> {
+    Filer::write_force(filepath, content)?;
+    Ok(())
+}
@@ -0,0 +1,74 @@
+/*
+ * Copyright (c) 2020. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[cfg(test)]
+mod secret {
+    use george_comm::io::file::FilerWriter;
+    use george_comm::io::Filer;
+
+    use crate::task::{Secret, GLOBAL_SECRET};
+    use crate::Task;
+
+    #[test]
+    fn token_issue_and_verify() {
+        let task = Task::default().unwrap();
+        let token = task.token_issue("admin".to_string()).unwrap();
+        assert_eq!(task.token_verify(token.as_str()).unwrap(), "admin");
+        // 篡改操作人后签名校验失败
+        let forged = format!(
+            "{}{}",
+            hex::encode("guest".as_bytes()),
+            &token[hex::encode("admin".as_bytes()).len()..]
+        );
+        assert!(task.token_verify(forged.as_str()).is_err());
+        assert!(task.token_verify("admin").is_err());
+        assert!(task.token_verify("").is_err());
+    }
+
+    #[test]
+    fn wrap_and_unwrap() {
+        let _task = Task::default().unwrap();
+        let secret = GLOBAL_SECRET.get().unwrap();
+        let wrapped = secret.wrap("key material".as_bytes()).unwrap();
+        assert!(!wrapped.contains(&hex::encode("key material".as_bytes())));
+        assert_eq!(
+            secret.unwrap(wrapped.as_str()).unwrap(),
+            "key material".as_bytes()
+        );
+        // 篡改密文后校验失败
+        let first = if wrapped.starts_with('0') { "1" } else { "0" };
+        let forged = format!("{}{}", first, &wrapped[1..]);
+        assert!(secret.unwrap(forged.as_str()).is_err());
+        assert!(secret.unwrap("key material").is_err());
+    }
+
+    #[test]
+    fn peer_issue_and_verify() {
+        let filepath = "src/test/secret/peer.key";
+        Filer::write_force(filepath, hex::encode([7u8; 32])).unwrap();
+        let peer = Secret::peer(filepath.to_string()).unwrap();
+        let credential = peer.peer_issue().unwrap();
+        assert!(peer.peer_verify(credential.as_str()).is_ok());
+        // 不同密钥签发的凭证校验失败
+        Filer::write_force(filepath, hex::encode([8u8; 32])).unwrap();
+        let other = Secret::peer(filepath.to_string()).unwrap();
+        assert!(other.peer_verify(credential.as_str()).is_err());
+        assert!(peer.peer_verify("").is_err());
+        // 未配置节点间认证密钥时拒绝
+        let task = Task::default().unwrap();
+        assert!(task.peer_verify(credential.as_str()).is_err());
+        Filer::write_force(filepath, hex::encode([7u8; 16])).unwrap();
+        assert!(Secret::peer(filepath.to_string()).is_err());
+    }
+}
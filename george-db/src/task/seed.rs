@@ -17,10 +17,9 @@ use std::sync::{Arc, RwLock};
 use serde::{Deserialize, Serialize};
 
 use george_comm::errors::GeorgeResult;
-use george_comm::io::file::FilerWriter;
-use george_comm::io::Filer;
 use george_comm::vectors::VectorHandler;
 use george_comm::Vector;
+use george_ge::utils::Snapshot;
 use george_ge::METADATA_SIZE;
 
 use crate::task::engine::traits::TSeed;
@@ -155,9 +154,9 @@ impl TSeed for Seed {
         for policy in self.policies.to_vec() {
             match policy.index_type {
                 Engine::None => {
-                    Filer::write_seek(policy.node_file_path(), policy.seek, policy.custom)?
+                    Snapshot::write_seek(policy.node_file_path(), policy.seek, policy.custom)?
                 }
-                _ => Filer::write_seek(
+                _ => Snapshot::write_seek(
                     policy.node_file_path(),
                     policy.seek,
                     view_info_index.clone(),
@@ -173,9 +172,9 @@ impl TSeed for Seed {
         for policy in self.policies.to_vec() {
             match policy.index_type {
                 Engine::None => {
-                    Filer::write_seek(policy.node_file_path(), policy.seek, policy.custom)?
+                    Snapshot::write_seek(policy.node_file_path(), policy.seek, policy.custom)?
                 }
-                _ => Filer::write_seek(
+                _ => Snapshot::write_seek(
                     policy.node_file_path(),
                     policy.seek,
                     Vector::create_empty_bytes(12),
@@ -21,8 +21,9 @@ use george_comm::errors::GeorgeResult;
 use george_comm::Time;
 
 use crate::task::engine::traits::TIndex;
+use crate::task::ledger::Block;
 use crate::task::rich::Expectation;
-use crate::task::{Database, Page, View};
+use crate::task::{Database, Ledger, Page, View};
 use crate::utils::enums::{Engine, KeyType};
 
 pub trait TMaster {
@@ -148,6 +149,45 @@ pub trait TMaster {
     /// 根据视图name获取视图
     fn view(&self, database_name: String, view_name: String) -> GeorgeResult<Arc<RwLock<View>>>;
 
+    /// 设置视图字段加密策略
+    ///
+    /// ###Params
+    /// * fields 加密字段集合，仅支持json顶层字段
+    /// * deterministic 是否确定性加密，需在加密字段上建立索引或等值检索时使用
+    /// * readers 可解密的操作人集合
+    fn view_cipher(
+        &self,
+        database_name: String,
+        view_name: String,
+        fields: Vec<String>,
+        deterministic: bool,
+        readers: Vec<String>,
+    ) -> GeorgeResult<()>;
+
+    /// 按读取人授权处理视图读取结果，有解密授权则解密加密字段，否则将加密字段脱敏
+    ///
+    /// `get_disk`及`select_disk`等读取方法返回的是加密字段的密文，对外输出前需经此处理
+    fn reveal_disk(
+        &self,
+        database_name: String,
+        view_name: String,
+        principal: String,
+        value: Vec<u8>,
+    ) -> GeorgeResult<Vec<u8>>;
+
+    /// 遍历视图中所有有效数据，按读取人授权处理后以键及值回调，返回遍历条数
+    ///
+    /// ###Params
+    /// * principal 读取人
+    /// * each 数据回调，返回错误时终止遍历
+    fn scan_disk(
+        &self,
+        database_name: String,
+        view_name: String,
+        principal: String,
+        each: &mut dyn FnMut(String, Vec<u8>) -> GeorgeResult<()>,
+    ) -> GeorgeResult<u64>;
+
     /// 索引集合
     fn index_map(
         &self,
@@ -297,6 +337,27 @@ pub trait TMaster {
         constraint_json_bytes: Vec<u8>,
     ) -> GeorgeResult<Expectation>;
 
+    /// 条件检索，检索结果在索引遍历过程中逐条交由sink处理，无需全部载入内存
+    ///
+    /// 遍历及sink处理期间不持有库及视图锁，sink可阻塞等待下游消费
+    ///
+    /// ###Params
+    /// * principal 读取人，指定时检索结果按其授权处理，否则为加密字段的密文
+    /// * selector_json_bytes 选择器字节数组，自定义转换策略
+    /// * sink 检索结果回调，返回错误时终止检索
+    ///
+    /// ###Return
+    ///
+    /// Expectation 检索统计，其中检索结果集合为空
+    fn select_disk_stream(
+        &self,
+        database_name: String,
+        view_name: String,
+        principal: Option<String>,
+        constraint_json_bytes: Vec<u8>,
+        sink: &mut dyn FnMut(Vec<u8>) -> GeorgeResult<()>,
+    ) -> GeorgeResult<Expectation>;
+
     /// 条件删除
     ///
     /// selector_json_bytes 选择器字节数组，自定义转换策略
@@ -360,6 +421,43 @@ pub trait TMaster {
     ///
     /// Seed value信息
     fn remove_memory(&self, page_name: String, key: String) -> GeorgeResult<()>;
+
+    /// 账本集合
+    fn ledger_map(&self) -> Arc<RwLock<HashMap<String, Arc<RwLock<Ledger>>>>>;
+
+    /// 创建账本
+    ///
+    /// ###Params
+    ///
+    /// * name 账本名称
+    /// * comment 账本描述
+    fn ledger_create(&self, name: String, comment: String) -> GeorgeResult<()>;
+
+    /// 删除账本
+    fn ledger_remove(&self, ledger_name: String) -> GeorgeResult<()>;
+
+    /// 根据账本name获取账本
+    fn ledger(&self, ledger_name: String) -> GeorgeResult<Arc<RwLock<Ledger>>>;
+
+    /// 将交易集合打包为新区块并追加至账本<p><p>
+    ///
+    /// ###Params
+    ///
+    /// ledger_name 账本名称
+    ///
+    /// timestamp 区块打包时间，单位纳秒
+    ///
+    /// transactions 交易内容集合
+    ///
+    /// ###Return
+    ///
+    /// 新区块
+    fn block_insert(
+        &self,
+        ledger_name: String,
+        timestamp: i64,
+        transactions: Vec<Vec<u8>>,
+    ) -> GeorgeResult<Block>;
 }
 
 /// 表通用特性，遵循此特性创建索引可以更方便的针对进行扩展
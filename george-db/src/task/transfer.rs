@@ -0,0 +1,594 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::io::BufRead;
+
+use serde::{Deserialize, Serialize};
+
+use george_comm::errors::{Errs, GeorgeError, GeorgeResult};
+
+use crate::task::traits::{TForm, TMaster};
+use crate::task::Transfer;
+use crate::utils::comm::{INDEX_DISK, INDEX_INCREMENT};
+use crate::utils::enums::{Enum, EnumHandler};
+
+/// 导入结果中最多保留的错误行数
+const IMPORT_ERRORS_MAX: usize = 1000;
+
+/// 导出导入文件格式
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TransferFormat {
+    /// 每行一个`json`对象
+    Jsonl,
+    /// 逗号分隔，首列为行类型，其余列随行类型而定
+    Csv,
+}
+
+impl TransferFormat {
+    /// 根据文件后缀推断格式，`.csv`为`Csv`，其余均为`Jsonl`
+    pub fn from_filepath(filepath: &str) -> TransferFormat {
+        if filepath.to_lowercase().ends_with(".csv") {
+            TransferFormat::Csv
+        } else {
+            TransferFormat::Jsonl
+        }
+    }
+}
+
+/// 导出导入行，视图及索引定义行位于所属视图的数据行之前
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TransferRow {
+    /// 视图定义
+    View {
+        view: String,
+        comment: String,
+        /// 是否带自增ID
+        increment: bool,
+    },
+    /// 字段加密策略，位于视图定义之后，导入时先于数据生效，使导入数据重新加密
+    Cipher {
+        view: String,
+        fields: Vec<String>,
+        deterministic: bool,
+        readers: Vec<String>,
+    },
+    /// 自定义索引定义
+    Index {
+        view: String,
+        name: String,
+        /// 存储引擎，参见`EnumHandler::engine_u8`
+        engine: u8,
+        /// 索引值类型，参见`EnumHandler::key_type_u8`
+        key_type: u8,
+        primary: bool,
+        unique: bool,
+        null: bool,
+    },
+    /// 值为`utf8`文本的数据
+    Record {
+        view: String,
+        key: String,
+        value: String,
+    },
+    /// 值为非`utf8`字节的数据，值以`hex`编码
+    RecordHex {
+        view: String,
+        key: String,
+        value: String,
+    },
+}
+
+/// 导入结果
+#[derive(Debug, Clone, Default)]
+pub struct ImportReport {
+    /// 处理行数
+    pub rows: u64,
+    /// 成功导入行数
+    pub imported: u64,
+    /// 视图或索引已存在而跳过的行数
+    pub skipped: u64,
+    /// 导入失败行数
+    pub failed: u64,
+    /// 导入失败的行号及错误信息，最多保留`IMPORT_ERRORS_MAX`条
+    pub errors: Vec<(u64, String)>,
+}
+
+impl Transfer {
+    /// 将行编码为指定格式，包含行尾换行符
+    pub fn encode(format: TransferFormat, row: &TransferRow) -> GeorgeResult<Vec<u8>> {
+        let mut line = match format {
+            TransferFormat::Jsonl => match serde_json::to_string(row) {
+                Ok(res) => res,
+                Err(err) => return Err(Errs::strs("transfer row to json", err)),
+            },
+            TransferFormat::Csv => {
+                let fields = match row {
+                    TransferRow::View {
+                        view,
+                        comment,
+                        increment,
+                    } => vec![
+                        "view".to_string(),
+                        view.clone(),
+                        comment.clone(),
+                        increment.to_string(),
+                    ],
+                    TransferRow::Cipher {
+                        view,
+                        fields,
+                        deterministic,
+                        readers,
+                    } => vec![
+                        "cipher".to_string(),
+                        view.clone(),
+                        csv_list(fields)?,
+                        deterministic.to_string(),
+                        csv_list(readers)?,
+                    ],
+                    TransferRow::Index {
+                        view,
+                        name,
+                        engine,
+                        key_type,
+                        primary,
+                        unique,
+                        null,
+                    } => vec![
+                        "index".to_string(),
+                        view.clone(),
+                        name.clone(),
+                        engine.to_string(),
+                        key_type.to_string(),
+                        primary.to_string(),
+                        unique.to_string(),
+                        null.to_string(),
+                    ],
+                    TransferRow::Record { view, key, value } => vec![
+                        "record".to_string(),
+                        view.clone(),
+                        key.clone(),
+                        value.clone(),
+                    ],
+                    TransferRow::RecordHex { view, key, value } => vec![
+                        "record_hex".to_string(),
+                        view.clone(),
+                        key.clone(),
+                        value.clone(),
+                    ],
+                };
+                fields
+                    .iter()
+                    .map(|field| csv_field(field))
+                    .collect::<Vec<String>>()
+                    .join(",")
+            }
+        };
+        line.push('\n');
+        Ok(line.into_bytes())
+    }
+
+    /// 将单行文本按指定格式解码
+    pub fn decode(format: TransferFormat, text: &str) -> GeorgeResult<TransferRow> {
+        match format {
+            TransferFormat::Jsonl => match serde_json::from_str(text) {
+                Ok(res) => Ok(res),
+                Err(err) => Err(Errs::strs("transfer row from json", err)),
+            },
+            TransferFormat::Csv => {
+                let fields = csv_fields(text)?;
+                let expect = match fields[0].as_str() {
+                    "view" => 4,
+                    "cipher" => 5,
+                    "index" => 8,
+                    "record" | "record_hex" => 4,
+                    other => return Err(Errs::string(format!("unknown row type {}!", other))),
+                };
+                if fields.len() != expect {
+                    return Err(Errs::string(format!(
+                        "{} row expect {} fields, but got {}!",
+                        fields[0],
+                        expect,
+                        fields.len()
+                    )));
+                }
+                let mut fields = fields.into_iter();
+                let kind = fields.next().unwrap();
+                let view = fields.next().unwrap();
+                match kind.as_str() {
+                    "view" => Ok(TransferRow::View {
+                        view,
+                        comment: fields.next().unwrap(),
+                        increment: csv_parse(fields.next().unwrap())?,
+                    }),
+                    "cipher" => Ok(TransferRow::Cipher {
+                        view,
+                        fields: csv_list_parse(fields.next().unwrap())?,
+                        deterministic: csv_parse(fields.next().unwrap())?,
+                        readers: csv_list_parse(fields.next().unwrap())?,
+                    }),
+                    "index" => Ok(TransferRow::Index {
+                        view,
+                        name: fields.next().unwrap(),
+                        engine: csv_parse(fields.next().unwrap())?,
+                        key_type: csv_parse(fields.next().unwrap())?,
+                        primary: csv_parse(fields.next().unwrap())?,
+                        unique: csv_parse(fields.next().unwrap())?,
+                        null: csv_parse(fields.next().unwrap())?,
+                    }),
+                    "record" => Ok(TransferRow::Record {
+                        view,
+                        key: fields.next().unwrap(),
+                        value: fields.next().unwrap(),
+                    }),
+                    _ => Ok(TransferRow::RecordHex {
+                        view,
+                        key: fields.next().unwrap(),
+                        value: fields.next().unwrap(),
+                    }),
+                }
+            }
+        }
+    }
+
+    /// 将输入按行拆分，回调行号及行文本，跳过空行
+    ///
+    /// `Csv`格式中引号内的换行属于字段内容，不作为行结束
+    pub fn split<R: BufRead>(
+        format: TransferFormat,
+        reader: R,
+        each: &mut dyn FnMut(u64, String) -> GeorgeResult<()>,
+    ) -> GeorgeResult<()> {
+        let mut text = String::new();
+        let mut start: u64 = 0;
+        let mut quoted = false;
+        for (index, line) in reader.lines().enumerate() {
+            let line = match line {
+                Ok(res) => res,
+                Err(err) => return Err(Errs::strs("transfer read line", err)),
+            };
+            if text.is_empty() && !quoted {
+                start = index as u64 + 1;
+            } else {
+                text.push('\n');
+            }
+            text.push_str(line.as_str());
+            if format == TransferFormat::Csv {
+                quoted ^= line.matches('"').count() % 2 == 1;
+            }
+            if quoted {
+                continue;
+            }
+            let row = text.trim_end_matches('\r').to_string();
+            text.clear();
+            if !row.trim().is_empty() {
+                each(start, row)?;
+            }
+        }
+        if quoted {
+            return Err(Errs::string(format!(
+                "row at line {} has unclosed quote!",
+                start
+            )));
+        }
+        Ok(())
+    }
+
+    /// 按指定格式导出库中视图，视图名称为空则导出库中所有视图
+    ///
+    /// 每个视图依次输出视图定义、字段加密策略、自定义索引定义及所有数据
+    ///
+    /// 带加密策略的视图仅允许有解密授权的读取人导出，数据以明文导出，导入时按策略重新加密，
+    /// 避免脱敏结果被当作真实数据导入
+    pub(crate) fn export<M: TMaster>(
+        master: &M,
+        database_name: String,
+        view_name: String,
+        format: TransferFormat,
+        principal: String,
+        sink: &mut dyn FnMut(Vec<u8>) -> GeorgeResult<()>,
+    ) -> GeorgeResult<u64> {
+        let view_names = if view_name.is_empty() {
+            let mut view_names: Vec<String> = master
+                .view_map(database_name.clone())?
+                .read()
+                .unwrap()
+                .keys()
+                .cloned()
+                .collect();
+            view_names.sort();
+            view_names
+        } else {
+            master.view(database_name.clone(), view_name.clone())?;
+            vec![view_name]
+        };
+        let mut rows: u64 = 0;
+        for view_name in view_names {
+            let (comment, cipher) = {
+                let view = master.view(database_name.clone(), view_name.clone())?;
+                let view_r = view.read().unwrap();
+                (view_r.comment(), view_r.cipher())
+            };
+            if let Some(cipher) = cipher.as_ref() {
+                if !cipher.readers().iter().any(|reader| reader.eq(&principal)) {
+                    return Err(Errs::permission_denied_error(format!(
+                        "view {} has cipher fields, export requires decrypt permission of {}!",
+                        view_name, principal
+                    )));
+                }
+            }
+            let mut indexes: Vec<TransferRow> = vec![];
+            let mut increment = false;
+            for (name, index) in master
+                .index_map(database_name.clone(), view_name.clone())?
+                .read()
+                .unwrap()
+                .iter()
+            {
+                if name.eq(INDEX_INCREMENT) {
+                    increment = true;
+                } else if name.ne(INDEX_DISK) {
+                    indexes.push(TransferRow::Index {
+                        view: view_name.clone(),
+                        name: name.clone(),
+                        engine: Enum::engine_u8(index.engine()),
+                        key_type: Enum::key_type_u8(index.key_type()),
+                        primary: index.primary(),
+                        unique: index.unique(),
+                        null: index.null(),
+                    })
+                }
+            }
+            sink(Transfer::encode(
+                format,
+                &TransferRow::View {
+                    view: view_name.clone(),
+                    comment,
+                    increment,
+                },
+            )?)?;
+            rows += 1;
+            if let Some(cipher) = cipher {
+                sink(Transfer::encode(
+                    format,
+                    &TransferRow::Cipher {
+                        view: view_name.clone(),
+                        fields: cipher.fields(),
+                        deterministic: cipher.deterministic(),
+                        readers: cipher.readers(),
+                    },
+                )?)?;
+                rows += 1;
+            }
+            for index in indexes.iter() {
+                sink(Transfer::encode(format, index)?)?;
+                rows += 1;
+            }
+            rows += master.scan_disk(
+                database_name.clone(),
+                view_name.clone(),
+                principal.clone(),
+                &mut |key, value| {
+                    let row = match String::from_utf8(value) {
+                        Ok(value) => TransferRow::Record {
+                            view: view_name.clone(),
+                            key,
+                            value,
+                        },
+                        Err(err) => TransferRow::RecordHex {
+                            view: view_name.clone(),
+                            key,
+                            value: hex::encode(err.into_bytes()),
+                        },
+                    };
+                    sink(Transfer::encode(format, &row)?)
+                },
+            )?;
+        }
+        Ok(rows)
+    }
+
+    /// 按指定格式导入一批行，逐行记录导入结果
+    ///
+    /// ###Params
+    ///
+    /// * view_name 导入的目标视图，为空则使用行中的视图名称
+    /// * overwrite 数据已存在时是否覆盖，否则该行导入失败
+    /// * rows 行号及行文本集合
+    /// * report 导入结果，多批次导入时累加
+    /// * permit 每行导入前的配额校验，校验失败则该行导入失败
+    pub(crate) fn import<M: TMaster>(
+        master: &M,
+        database_name: String,
+        view_name: String,
+        format: TransferFormat,
+        overwrite: bool,
+        rows: Vec<(u64, String)>,
+        report: &mut ImportReport,
+        permit: &mut dyn FnMut() -> GeorgeResult<()>,
+    ) {
+        for (line, text) in rows {
+            report.rows += 1;
+            let result = permit()
+                .and_then(|()| Transfer::decode(format, text.as_str()))
+                .and_then(|row| {
+                    apply(
+                        master,
+                        database_name.clone(),
+                        view_name.clone(),
+                        overwrite,
+                        row,
+                    )
+                });
+            match result {
+                Ok(true) => report.imported += 1,
+                Ok(false) => report.skipped += 1,
+                Err(err) => {
+                    report.failed += 1;
+                    if report.errors.len() < IMPORT_ERRORS_MAX {
+                        report.errors.push((line, err.to_string()))
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 写入单行，视图或索引已存在时返回false
+fn apply<M: TMaster>(
+    master: &M,
+    database_name: String,
+    view_name: String,
+    overwrite: bool,
+    row: TransferRow,
+) -> GeorgeResult<bool> {
+    let target = |view: String| {
+        if view_name.is_empty() {
+            view
+        } else {
+            view_name.clone()
+        }
+    };
+    let result = match row {
+        TransferRow::View {
+            view,
+            comment,
+            increment,
+        } => master.view_create(database_name, target(view), comment, increment),
+        TransferRow::Cipher {
+            view,
+            fields,
+            deterministic,
+            readers,
+        } => master.view_cipher(database_name, target(view), fields, deterministic, readers),
+        TransferRow::Index {
+            view,
+            name,
+            engine,
+            key_type,
+            primary,
+            unique,
+            null,
+        } => master.index_create(
+            database_name,
+            target(view),
+            name,
+            Enum::engine(engine),
+            Enum::key_type(key_type),
+            primary,
+            unique,
+            null,
+        ),
+        TransferRow::Record { view, key, value } => record(
+            master,
+            database_name,
+            target(view),
+            key,
+            value.into_bytes(),
+            overwrite,
+        ),
+        TransferRow::RecordHex { view, key, value } => match hex::decode(value) {
+            Ok(value) => record(master, database_name, target(view), key, value, overwrite),
+            Err(err) => Err(Errs::strs("record value hex decode", err)),
+        },
+    };
+    match result {
+        Ok(()) => Ok(true),
+        Err(GeorgeError::ViewExistError(_)) | Err(GeorgeError::IndexExistError(_)) => Ok(false),
+        Err(err) => Err(err),
+    }
+}
+
+/// 写入数据
+fn record<M: TMaster>(
+    master: &M,
+    database_name: String,
+    view_name: String,
+    key: String,
+    value: Vec<u8>,
+    overwrite: bool,
+) -> GeorgeResult<()> {
+    if overwrite {
+        master.set_disk(database_name, view_name, key, value)
+    } else {
+        master.put_disk(database_name, view_name, key, value)
+    }
+}
+
+/// 按需为`csv`字段加引号，字段中的引号转义为两个引号
+fn csv_field(field: &str) -> String {
+    if field.contains(|c| c == ',' || c == '"' || c == '\n' || c == '\r') {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// 解析`csv`行中的所有字段
+fn csv_fields(text: &str) -> GeorgeResult<Vec<String>> {
+    let mut fields: Vec<String> = vec![];
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if quoted {
+            if c == '"' {
+                if chars.peek() == Some(&'"') {
+                    field.push('"');
+                    chars.next();
+                } else {
+                    quoted = false;
+                }
+            } else {
+                field.push(c);
+            }
+        } else if c == '"' && field.is_empty() {
+            quoted = true;
+        } else if c == ',' {
+            fields.push(field.clone());
+            field.clear();
+        } else {
+            field.push(c);
+        }
+    }
+    if quoted {
+        return Err(Errs::str("csv row has unclosed quote!"));
+    }
+    fields.push(field);
+    Ok(fields)
+}
+
+/// 将字符串集合编码为单个`csv`字段，以`json`数组表示
+fn csv_list(list: &Vec<String>) -> GeorgeResult<String> {
+    match serde_json::to_string(list) {
+        Ok(res) => Ok(res),
+        Err(err) => Err(Errs::strs("csv list to json", err)),
+    }
+}
+
+/// 解析以`json`数组表示的字符串集合字段
+fn csv_list_parse(field: String) -> GeorgeResult<Vec<String>> {
+    match serde_json::from_str(field.as_str()) {
+        Ok(res) => Ok(res),
+        Err(_) => Err(Errs::string(format!("csv field {} parse failed!", field))),
+    }
+}
+
+/// 解析`csv`字段值
+fn csv_parse<T: std::str::FromStr>(field: String) -> GeorgeResult<T> {
+    match field.parse::<T>() {
+        Ok(res) => Ok(res),
+        Err(_) => Err(Errs::string(format!("csv field {} parse failed!", field))),
+    }
+}
@@ -0,0 +1,288 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[cfg(test)]
+mod transfer {
+    use george_comm::Time;
+
+    use crate::task::traits::TMaster;
+    use crate::task::transfer::{ImportReport, TransferFormat, TransferRow};
+    use crate::task::Transfer;
+    use crate::Task;
+
+    fn rows() -> Vec<TransferRow> {
+        vec![
+            TransferRow::View {
+                view: "view".to_string(),
+                comment: "comment, with \"quote\"".to_string(),
+                increment: true,
+            },
+            TransferRow::Index {
+                view: "view".to_string(),
+                name: "age".to_string(),
+                engine: 3,
+                key_type: 1,
+                primary: false,
+                unique: false,
+                null: true,
+            },
+            TransferRow::Record {
+                view: "view".to_string(),
+                key: "a,b".to_string(),
+                value: "{\"name\":\"line\nbreak\"}".to_string(),
+            },
+            TransferRow::RecordHex {
+                view: "view".to_string(),
+                key: "hex".to_string(),
+                value: hex::encode(vec![0xff, 0x00]),
+            },
+            TransferRow::Cipher {
+                view: "view".to_string(),
+                fields: vec!["phone".to_string(), "id,card".to_string()],
+                deterministic: true,
+                readers: vec!["admin".to_string()],
+            },
+        ]
+    }
+
+    #[test]
+    fn codec() {
+        for format in [TransferFormat::Jsonl, TransferFormat::Csv].iter() {
+            let mut content: Vec<u8> = vec![];
+            for row in rows().iter() {
+                content.append(&mut Transfer::encode(*format, row).unwrap());
+            }
+            let mut decoded: Vec<(u64, TransferRow)> = vec![];
+            Transfer::split(*format, content.as_slice(), &mut |line, text| {
+                decoded.push((line, Transfer::decode(*format, text.as_str())?));
+                Ok(())
+            })
+            .unwrap();
+            assert_eq!(
+                decoded
+                    .iter()
+                    .map(|(_, row)| row.clone())
+                    .collect::<Vec<TransferRow>>(),
+                rows()
+            );
+            // csv中引号内的换行不作为行结束，后续行号随之后移
+            let line = match format {
+                TransferFormat::Jsonl => 4,
+                TransferFormat::Csv => 5,
+            };
+            assert_eq!(decoded[3].0, line);
+        }
+        assert!(Transfer::decode(TransferFormat::Csv, "record,view,key").is_err());
+        assert!(Transfer::decode(TransferFormat::Csv, "unknown,view").is_err());
+    }
+
+    #[test]
+    fn export_import() {
+        let task = Task::default().unwrap();
+        let database_name = "database_transfer_test";
+        // 每次使用新库导入，避免与其它用例中的备份冲突而无法删除旧库
+        let database_import_name =
+            &format!("database_transfer_import_test_{}", Time::now().nanos());
+        let view_name = "view_transfer_test";
+        let _ = task.database_create(database_name.to_string(), "".to_string());
+        let _ = task.view_create(
+            database_name.to_string(),
+            view_name.to_string(),
+            "".to_string(),
+            true,
+        );
+        for key in ["a", "b", "c"].iter() {
+            task.set_disk(
+                database_name.to_string(),
+                view_name.to_string(),
+                key.to_string(),
+                format!("{{\"key\":\"{}\"}}", key).into_bytes(),
+            )
+            .unwrap();
+        }
+        task.remove_disk(
+            database_name.to_string(),
+            view_name.to_string(),
+            "c".to_string(),
+        )
+        .unwrap();
+        let mut lines: Vec<(u64, String)> = vec![];
+        let count = task
+            .export(
+                database_name.to_string(),
+                view_name.to_string(),
+                TransferFormat::Csv,
+                "".to_string(),
+                &mut |line| {
+                    lines.push((
+                        lines.len() as u64 + 1,
+                        String::from_utf8(line).unwrap().trim_end().to_string(),
+                    ));
+                    Ok(())
+                },
+            )
+            .unwrap();
+        // 视图定义1行及有效数据2行
+        assert_eq!(count, 3);
+        assert_eq!(lines.len(), 3);
+
+        task.database_create(database_import_name.to_string(), "".to_string())
+            .unwrap();
+        let mut report = ImportReport::default();
+        task.import(
+            database_import_name.to_string(),
+            "".to_string(),
+            TransferFormat::Csv,
+            false,
+            "transfer_test".to_string(),
+            lines.clone(),
+            &mut report,
+        );
+        assert_eq!(report.imported, 3);
+        assert_eq!(report.failed, 0);
+        assert_eq!(
+            task.get_disk(
+                database_import_name.to_string(),
+                view_name.to_string(),
+                "b".to_string()
+            )
+            .unwrap(),
+            "{\"key\":\"b\"}".as_bytes().to_vec()
+        );
+        assert!(task
+            .get_disk(
+                database_import_name.to_string(),
+                view_name.to_string(),
+                "c".to_string()
+            )
+            .is_err());
+
+        // 再次导入时视图已存在被跳过，数据已存在且不覆盖则逐行报错
+        let mut report = ImportReport::default();
+        task.import(
+            database_import_name.to_string(),
+            "".to_string(),
+            TransferFormat::Csv,
+            false,
+            "transfer_test".to_string(),
+            lines,
+            &mut report,
+        );
+        assert_eq!(report.skipped, 1);
+        assert_eq!(report.failed, 2);
+        assert_eq!(report.errors[0].0, 2);
+    }
+
+    #[test]
+    fn export_cipher() {
+        let task = Task::default().unwrap();
+        let database_name = "database_transfer_cipher_test";
+        let database_import_name = &format!(
+            "database_transfer_cipher_import_test_{}",
+            Time::now().nanos()
+        );
+        let view_name = "view_transfer_cipher_test";
+        let _ = task.database_create(database_name.to_string(), "".to_string());
+        let _ = task.view_create(
+            database_name.to_string(),
+            view_name.to_string(),
+            "".to_string(),
+            false,
+        );
+        task.view_cipher(
+            database_name.to_string(),
+            view_name.to_string(),
+            vec!["phone".to_string()],
+            false,
+            vec!["admin".to_string()],
+        )
+        .unwrap();
+        task.set_disk(
+            database_name.to_string(),
+            view_name.to_string(),
+            "george".to_string(),
+            r#"{"phone":"13800000000"}"#.as_bytes().to_vec(),
+        )
+        .unwrap();
+
+        // 无解密授权时拒绝导出，脱敏数据不会进入导出文件
+        let mut lines: Vec<(u64, String)> = vec![];
+        assert!(task
+            .export(
+                database_name.to_string(),
+                view_name.to_string(),
+                TransferFormat::Jsonl,
+                "guest".to_string(),
+                &mut |line| {
+                    lines.push((0, String::from_utf8(line).unwrap()));
+                    Ok(())
+                },
+            )
+            .is_err());
+        assert!(lines.is_empty());
+
+        let count = task
+            .export(
+                database_name.to_string(),
+                view_name.to_string(),
+                TransferFormat::Jsonl,
+                "admin".to_string(),
+                &mut |line| {
+                    lines.push((
+                        lines.len() as u64 + 1,
+                        String::from_utf8(line).unwrap().trim_end().to_string(),
+                    ));
+                    Ok(())
+                },
+            )
+            .unwrap();
+        // 视图定义、加密策略及数据各1行
+        assert_eq!(count, 3);
+        assert!(lines[2].1.contains("13800000000"));
+
+        task.database_create(database_import_name.to_string(), "".to_string())
+            .unwrap();
+        let mut report = ImportReport::default();
+        task.import(
+            database_import_name.to_string(),
+            "".to_string(),
+            TransferFormat::Jsonl,
+            false,
+            "transfer_test".to_string(),
+            lines,
+            &mut report,
+        );
+        assert_eq!(report.imported, 3);
+        // 导入数据按策略重新加密，授权读取人可解密
+        let value = task
+            .get_disk(
+                database_import_name.to_string(),
+                view_name.to_string(),
+                "george".to_string(),
+            )
+            .unwrap();
+        assert!(!String::from_utf8(value.clone())
+            .unwrap()
+            .contains("13800000000"));
+        let revealed = task
+            .reveal_disk(
+                database_import_name.to_string(),
+                view_name.to_string(),
+                "admin".to_string(),
+                value,
+            )
+            .unwrap();
+        assert_eq!(revealed, r#"{"phone":"13800000000"}"#.as_bytes().to_vec());
+    }
+}
@@ -0,0 +1,171 @@
+/*
+ * Copyright (c) 2020. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::marker::PhantomData;
+
+use george_comm::errors::GeorgeResult;
+use george_comm::json::JsonHandler;
+use george_comm::{Document, Json};
+
+use crate::task::rich::Expectation;
+use crate::task::traits::TMaster;
+use crate::task::TypedView;
+use crate::utils::{Enum, EnumHandler};
+use crate::Task;
+
+impl<T: Document> TypedView<T> {
+    /// 新建类型化视图，首次使用前需调用`init`以确保视图及索引存在
+    pub fn new(task: Task, database_name: String, view_name: String) -> TypedView<T> {
+        TypedView {
+            task,
+            database_name,
+            view_name,
+            document: PhantomData,
+        }
+    }
+
+    /// 在已存在的库中创建不存在的视图及`T`定义的索引，已存在的视图及同名索引保持不变
+    pub fn init(&self) -> GeorgeResult<()> {
+        let view_exist = self
+            .task
+            .view_map(self.database_name.clone())?
+            .read()
+            .unwrap()
+            .contains_key(&self.view_name);
+        if !view_exist {
+            self.task.view_create(
+                self.database_name.clone(),
+                self.view_name.clone(),
+                String::new(),
+                false,
+            )?;
+        }
+        let index_map = self
+            .task
+            .index_map(self.database_name.clone(), self.view_name.clone())?;
+        for define in T::indexes() {
+            let index_exist = index_map.read().unwrap().contains_key(&define.name);
+            if index_exist {
+                continue;
+            }
+            self.task.index_create(
+                self.database_name.clone(),
+                self.view_name.clone(),
+                define.name,
+                Enum::index_engine(define.engine),
+                Enum::index_key_type(define.key_type),
+                define.primary,
+                define.unique,
+                define.null,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// 插入数据，已存在的键写入失败
+    pub fn put(&self, key: String, value: &T) -> GeorgeResult<()> {
+        self.task.put_disk(
+            self.database_name.clone(),
+            self.view_name.clone(),
+            key,
+            Json::obj_2_bytes(value)?,
+        )
+    }
+
+    /// 插入数据，已存在的键将被覆盖
+    pub fn set(&self, key: String, value: &T) -> GeorgeResult<()> {
+        self.task.set_disk(
+            self.database_name.clone(),
+            self.view_name.clone(),
+            key,
+            Json::obj_2_bytes(value)?,
+        )
+    }
+
+    /// 获取数据
+    pub fn get(&self, key: String) -> GeorgeResult<T> {
+        let value = self
+            .task
+            .get_disk(self.database_name.clone(), self.view_name.clone(), key)?;
+        Json::bytes_2_obj(&value)
+    }
+
+    /// 根据指定索引获取数据
+    pub fn get_by_index(&self, index_name: String, key: String) -> GeorgeResult<T> {
+        let value = self.task.get_disk_by_index(
+            self.database_name.clone(),
+            self.view_name.clone(),
+            index_name,
+            key,
+        )?;
+        Json::bytes_2_obj(&value)
+    }
+
+    /// 删除数据
+    pub fn remove(&self, key: String) -> GeorgeResult<()> {
+        self.task
+            .remove_disk(self.database_name.clone(), self.view_name.clone(), key)
+    }
+
+    /// 条件检索
+    pub fn select(&self, constraint_json_bytes: Vec<u8>) -> GeorgeResult<Vec<T>> {
+        let expectation = self.task.select_disk(
+            self.database_name.clone(),
+            self.view_name.clone(),
+            constraint_json_bytes,
+        )?;
+        expectation
+            .values
+            .iter()
+            .map(|value| Json::bytes_2_obj(value))
+            .collect()
+    }
+
+    /// 条件检索，检索结果在索引遍历过程中逐条反序列化后交由sink处理，无需全部载入内存
+    ///
+    /// 返回检索统计，其中检索结果集合为空
+    pub fn select_stream(
+        &self,
+        constraint_json_bytes: Vec<u8>,
+        sink: &mut dyn FnMut(T) -> GeorgeResult<()>,
+    ) -> GeorgeResult<Expectation> {
+        self.task.select_disk_stream(
+            self.database_name.clone(),
+            self.view_name.clone(),
+            None,
+            constraint_json_bytes,
+            &mut |value| sink(Json::bytes_2_obj(&value)?),
+        )
+    }
+
+    /// 条件删除
+    pub fn delete(&self, constraint_json_bytes: Vec<u8>) -> GeorgeResult<Expectation> {
+        self.task.delete_disk(
+            self.database_name.clone(),
+            self.view_name.clone(),
+            constraint_json_bytes,
+        )
+    }
+}
+
+impl<T: Document> Clone for TypedView<T> {
+    fn clone(&self) -> Self {
+        TypedView {
+            task: self.task.clone(),
+            database_name: self.database_name.clone(),
+            view_name: self.view_name.clone(),
+            document: PhantomData,
+        }
+    }
+}
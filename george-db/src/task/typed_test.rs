@@ -0,0 +1,165 @@
+/*
+ * Copyright (c) 2020. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[cfg(test)]
+mod typed {
+    use serde::{Deserialize, Serialize};
+
+    use crate::task::traits::TMaster;
+    use crate::task::TypedView;
+    use crate::{Document, IndexEngine, IndexKeyType, Task};
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Document)]
+    struct User {
+        #[george(index, unique, key_type = "UInt")]
+        id: u32,
+        #[george(index, engine = "Sequence")]
+        age: u64,
+        #[george(index)]
+        #[serde(rename = "nick")]
+        nick_name: Option<String>,
+        remark: String,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Document)]
+    #[serde(rename_all = "camelCase")]
+    struct Order {
+        #[george(index, name = "orderId")]
+        order_id: String,
+        #[george(index)]
+        total_price: f64,
+        #[george(index)]
+        #[serde(rename(serialize = "paid", deserialize = "is_paid"))]
+        is_paid: bool,
+    }
+
+    fn user(id: u32, age: u64) -> User {
+        User {
+            id,
+            age,
+            nick_name: Some(format!("nick{}", id)),
+            remark: "remark".to_string(),
+        }
+    }
+
+    fn view(task: &Task, database_name: &str, view_name: &str) -> TypedView<User> {
+        let _ = task.database_remove(database_name.to_string());
+        task.database_create(database_name.to_string(), "".to_string())
+            .unwrap();
+        TypedView::new(
+            task.clone(),
+            database_name.to_string(),
+            view_name.to_string(),
+        )
+    }
+
+    #[test]
+    fn indexes() {
+        let indexes = User::indexes();
+        assert_eq!(indexes.len(), 3);
+        assert_eq!(indexes[0].name, "id");
+        assert!(matches!(indexes[0].engine, IndexEngine::Disk));
+        assert_eq!(indexes[0].key_type, IndexKeyType::UInt);
+        assert!(indexes[0].unique);
+        assert!(!indexes[0].null);
+        assert_eq!(indexes[1].name, "age");
+        assert!(matches!(indexes[1].engine, IndexEngine::Sequence));
+        assert_eq!(indexes[1].key_type, IndexKeyType::UInt);
+        assert!(!indexes[1].unique);
+        // Option字段默认允许为空
+        assert_eq!(indexes[2].name, "nick");
+        assert_eq!(indexes[2].key_type, IndexKeyType::String);
+        assert!(indexes[2].null);
+    }
+
+    #[test]
+    fn indexes_serde_rename() {
+        let indexes = Order::indexes();
+        assert_eq!(indexes.len(), 3);
+        assert_eq!(indexes[0].name, "orderId");
+        assert_eq!(indexes[0].key_type, IndexKeyType::String);
+        assert_eq!(indexes[1].name, "totalPrice");
+        assert_eq!(indexes[1].key_type, IndexKeyType::Float);
+        // 索引作用于序列化后的json，取serialize指定的名称
+        assert_eq!(indexes[2].name, "paid");
+        assert_eq!(indexes[2].key_type, IndexKeyType::Bool);
+        let order = Order {
+            order_id: "1".to_string(),
+            total_price: 1.5,
+            is_paid: true,
+        };
+        let json = serde_json::to_value(&order).unwrap();
+        assert!(indexes
+            .iter()
+            .all(|index| json.get(index.name.as_str()).is_some()));
+    }
+
+    #[test]
+    fn init() {
+        let task = Task::default().unwrap();
+        let database_name = "database_typed_init_test";
+        let view_name = "view_typed_init_test";
+        let typed = view(&task, database_name, view_name);
+        typed.init().unwrap();
+        // 重复初始化不影响已存在的视图及索引
+        typed.init().unwrap();
+        let index_map = task
+            .index_map(database_name.to_string(), view_name.to_string())
+            .unwrap();
+        let index_map = index_map.read().unwrap();
+        assert!(index_map.contains_key("id"));
+        assert!(index_map.contains_key("age"));
+        assert!(index_map.contains_key("nick"));
+    }
+
+    #[test]
+    fn put_get_select() {
+        let task = Task::default().unwrap();
+        let database_name = "database_typed_put_test";
+        let view_name = "view_typed_put_test";
+        let typed = view(&task, database_name, view_name);
+        typed.init().unwrap();
+        for id in 1..=10 {
+            typed
+                .put(id.to_string(), &user(id, id as u64 + 20))
+                .unwrap();
+        }
+        assert!(typed.put("1".to_string(), &user(1, 21)).is_err());
+        assert_eq!(typed.get("3".to_string()).unwrap(), user(3, 23));
+        assert_eq!(
+            typed
+                .get_by_index("id".to_string(), "5".to_string())
+                .unwrap(),
+            user(5, 25)
+        );
+        typed.set("3".to_string(), &user(3, 33)).unwrap();
+        assert_eq!(typed.get("3".to_string()).unwrap().age, 33);
+
+        let constraint = r#"{"Conditions":[{"Param":"id","Cond":"le","Value":4}]}"#;
+        let users = typed.select(constraint.as_bytes().to_vec()).unwrap();
+        assert_eq!(users.len(), 4);
+        assert!(users.iter().all(|user| user.id <= 4));
+        let mut streamed: Vec<User> = vec![];
+        typed
+            .select_stream(constraint.as_bytes().to_vec(), &mut |user| {
+                streamed.push(user);
+                Ok(())
+            })
+            .unwrap();
+        assert_eq!(streamed, users);
+
+        typed.remove("3".to_string()).unwrap();
+        assert!(typed.get("3".to_string()).is_err());
+    }
+}
@@ -28,18 +28,24 @@ use george_comm::Trans;
 use george_comm::Vector;
 use george_comm::{Strings, Time};
 use george_ge::utils::enums::Tag;
-use george_ge::GeFactory;
+use george_ge::{GeFactory, METADATA_SIZE};
 
 use crate::task::engine::traits::{Pigeonhole, TIndex, TSeed};
+use crate::task::engine::DataReal;
 use crate::task::rich::{Expectation, Selector};
 use crate::task::traits::TForm;
 use crate::task::Seed;
-use crate::task::View;
+use crate::task::{Cipher, View};
 use crate::task::{Index as IndexDefault, GLOBAL_THREAD_POOL};
 use crate::utils::comm::{IndexKey, INDEX_DISK, INDEX_INCREMENT};
 use crate::utils::enums::{Engine, KeyType};
 use crate::utils::Paths;
 
+/// 主键索引record文件中单条数据坐标字节数，即view版本号(2字节) + view持续长度(4字节) + view偏移量(6字节) + 链式后续数据(8字节)
+const SCAN_RECORD_LEN: u64 = 20;
+/// 遍历视图时单次读取的数据坐标数量
+const SCAN_RECORD_BATCH: u64 = 1024;
+
 /// 新建视图
 fn new_view(database_name: String, name: String, comment: String) -> GeorgeResult<View> {
     let time = Time::now();
@@ -59,6 +65,7 @@ fn new_view(database_name: String, name: String, comment: String) -> GeorgeResul
         ge: GeFactory {}.create(Tag::View, filepath, description)?,
         indexes: Default::default(),
         pigeonhole,
+        cipher: None,
     };
     Ok(view)
 }
@@ -239,6 +246,51 @@ impl View {
         }
     }
 
+    /// 字段加密策略
+    pub fn cipher(&self) -> Option<Cipher> {
+        self.cipher.clone()
+    }
+
+    /// 字段加密策略使用的密钥及向量，已有策略时沿用原密钥以保证已加密数据仍可解密，否则随机生成
+    pub(crate) fn cipher_key(&self) -> (String, String) {
+        match &self.cipher {
+            Some(res) => res.key(),
+            None => Cipher::rand_key(),
+        }
+    }
+
+    /// 设置字段加密策略，仅对此后写入的数据生效
+    ///
+    /// ###Params
+    /// * fields 加密字段集合，仅支持json顶层字段
+    /// * deterministic 是否确定性加密，需在加密字段上建立索引或等值检索时使用
+    /// * readers 可解密的操作人集合
+    /// * key `sm4`密钥，hex，由`cipher_key`获取
+    /// * iv 确定性加密使用的`sm4`向量，hex
+    pub(crate) fn modify_cipher(
+        &mut self,
+        fields: Vec<String>,
+        deterministic: bool,
+        readers: Vec<String>,
+        key: String,
+        iv: String,
+    ) -> GeorgeResult<()> {
+        let cipher = Cipher::new(fields, deterministic, readers, key, iv)?;
+        cipher.save(self.database_name(), self.name())?;
+        self.cipher = Some(cipher);
+        Ok(())
+    }
+
+    /// 按读取人授权处理读取结果，有解密授权则解密加密字段，否则将加密字段脱敏
+    ///
+    /// 未设置字段加密策略时原样返回
+    pub(crate) fn reveal(&self, principal: &str, value: Vec<u8>) -> GeorgeResult<Vec<u8>> {
+        match &self.cipher {
+            Some(cipher) => cipher.reveal(principal, value),
+            None => Ok(value),
+        }
+    }
+
     /// 创建索引
     ///
     /// ###Params
@@ -366,6 +418,27 @@ impl View {
         GLOBAL_THREAD_POOL.task_block_on(self.save(key, value, true))
     }
 
+    /// 按字段加密策略将写入值转为存储值，未设置字段加密策略时原样返回
+    pub(crate) fn seal(&self, value: Vec<u8>) -> GeorgeResult<Vec<u8>> {
+        match &self.cipher {
+            Some(cipher) => cipher.encrypt(value),
+            None => Ok(value),
+        }
+    }
+
+    /// 写入已由[`View::seal`]转换的存储值，不再重复加密<p><p>
+    ///
+    /// ###Params
+    ///
+    /// key string
+    ///
+    /// stored 存储值<p><p>
+    ///
+    /// force 如果存在原值，是否覆盖原结果<p><p>
+    pub(crate) fn store(&self, key: String, stored: Vec<u8>, force: bool) -> GeorgeResult<()> {
+        GLOBAL_THREAD_POOL.task_block_on(self.save_sealed(key, stored, force))
+    }
+
     /// 获取数据，返回存储对象<p><p>
     ///
     /// ###Params
@@ -379,7 +452,11 @@ impl View {
     /// Seed value信息
     pub(crate) fn get(&self, index_name: &str, key: String) -> GeorgeResult<Vec<u8>> {
         let index = self.index(index_name)?;
-        Ok(index.get(key.clone())?.value())
+        let key = match &self.cipher {
+            Some(cipher) => cipher.index_key(index_name, key)?,
+            None => key,
+        };
+        Ok(index.get(key)?.value())
     }
 
     /// 删除数据<p><p>
@@ -400,14 +477,98 @@ impl View {
     ///
     /// selector_json_bytes 选择器字节数组，自定义转换策略
     pub(crate) fn select(&self, constraint_json_bytes: Vec<u8>) -> GeorgeResult<Expectation> {
-        Selector::run(constraint_json_bytes, self.indexes.clone(), false)
+        Selector::run(
+            self.constraint(constraint_json_bytes)?,
+            self.indexes.clone(),
+            false,
+        )
+    }
+
+    /// 条件检索，检索结果在索引遍历过程中逐条交由sink处理
+    ///
+    /// 仅在转换检索条件时持有视图读锁，遍历及sink处理期间不持有视图锁，sink可阻塞等待下游消费而不阻塞视图写入
+    ///
+    /// ###Params
+    ///
+    /// * view 视图
+    /// * principal 读取人，指定时按其授权解密或脱敏加密字段后交由sink处理，否则交由sink处理存储值
+    /// * constraint_json_bytes 选择器字节数组，自定义转换策略
+    /// * sink 检索结果回调，返回错误时终止检索
+    pub(crate) fn select_stream(
+        view: Arc<RwLock<View>>,
+        principal: Option<String>,
+        constraint_json_bytes: Vec<u8>,
+        sink: &mut dyn FnMut(Vec<u8>) -> GeorgeResult<()>,
+    ) -> GeorgeResult<Expectation> {
+        let (constraint, indexes, cipher) = {
+            let view_r = view.read().unwrap();
+            (
+                view_r.constraint(constraint_json_bytes)?,
+                view_r.indexes.clone(),
+                view_r.cipher.clone(),
+            )
+        };
+        Selector::run_stream(
+            constraint,
+            indexes,
+            &mut |value| match (&cipher, &principal) {
+                (Some(cipher), Some(principal)) => sink(cipher.reveal(principal, value)?),
+                _ => sink(value),
+            },
+        )
     }
 
     /// 条件删除
     ///
     /// selector_json_bytes 选择器字节数组，自定义转换策略
     pub(crate) fn delete(&self, constraint_json_bytes: Vec<u8>) -> GeorgeResult<Expectation> {
-        Selector::run(constraint_json_bytes, self.indexes.clone(), true)
+        Selector::run(
+            self.constraint(constraint_json_bytes)?,
+            self.indexes.clone(),
+            true,
+        )
+    }
+
+    /// 遍历视图中所有有效数据，按读取人授权处理后以键及值回调，返回遍历条数
+    ///
+    /// 主键索引的record文件按固定长度顺序记录每个键的数据坐标，数据删除后坐标置空，因此顺序读取record文件即可得到所有有效数据
+    pub(crate) fn scan(
+        &self,
+        principal: &str,
+        each: &mut dyn FnMut(String, Vec<u8>) -> GeorgeResult<()>,
+    ) -> GeorgeResult<u64> {
+        let record_filepath = Paths::record_filepath(Paths::index_path(
+            self.database_name(),
+            self.name(),
+            INDEX_DISK.to_string(),
+        ));
+        let len = Filer::len(&record_filepath)?;
+        let mut seek = METADATA_SIZE;
+        let mut count: u64 = 0;
+        while seek + SCAN_RECORD_LEN <= len {
+            let last = ((len - seek) / SCAN_RECORD_LEN).min(SCAN_RECORD_BATCH) * SCAN_RECORD_LEN;
+            let records = Filer::read_sub(&record_filepath, seek, last as usize)?;
+            for record in records.chunks(SCAN_RECORD_LEN as usize) {
+                let view_info_index = record[0..12].to_vec();
+                // 数据已删除或因意外未写入视图
+                if Trans::bytes_2_u48(view_info_index[6..12].to_vec())? == 0 {
+                    continue;
+                }
+                let real = DataReal::from(self.read_content_by_info(view_info_index)?)?;
+                each(real.key(), self.reveal(principal, real.value())?)?;
+                count += 1;
+            }
+            seek += last;
+        }
+        Ok(count)
+    }
+
+    /// 将检索条件中作用于加密字段的条件值转为密文
+    fn constraint(&self, constraint_json_bytes: Vec<u8>) -> GeorgeResult<Vec<u8>> {
+        match &self.cipher {
+            Some(cipher) => cipher.constraint(constraint_json_bytes),
+            None => Ok(constraint_json_bytes),
+        }
     }
 }
 
@@ -426,6 +587,12 @@ impl View {
     ///
     /// IndexResult<()>
     async fn save(&self, key: String, value: Vec<u8>, force: bool) -> GeorgeResult<()> {
+        let value = self.seal(value)?;
+        self.save_sealed(key, value, force).await
+    }
+
+    /// 写入存储值，存储值已按字段加密策略转换
+    async fn save_sealed(&self, key: String, value: Vec<u8>, force: bool) -> GeorgeResult<()> {
         let seed = Seed::create(Arc::new(self.clone()), key.clone(), value.clone());
         let mut receives = Vec::new();
         for (index_name, index) in self.index_map().read().unwrap().iter() {
@@ -670,6 +837,7 @@ impl View {
                     ge,
                     indexes: Arc::new(Default::default()),
                     pigeonhole: pigeonhole.clone(),
+                    cipher: Cipher::recovery(database_name.clone(), name.clone())?,
                 }));
                 log::info!("recovery view {} from database {}", name, database_name,);
                 match read_dir(Paths::view_path(database_name.clone(), name.clone())) {
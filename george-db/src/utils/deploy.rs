@@ -38,6 +38,7 @@ impl Config {
             Some(count) => count,
             None => 100,
         };
+        self.backup_dir = config_db.backup_dir;
     }
     /// 服务数据存储路径
     pub(crate) fn data_dir(&self) -> String {
@@ -47,12 +48,17 @@ impl Config {
     pub(crate) fn thread_count(&self) -> usize {
         self.thread_count
     }
+    /// 备份根路径
+    pub(crate) fn backup_dir(&self) -> Option<String> {
+        self.backup_dir.clone()
+    }
 }
 
 pub static GLOBAL_CONFIG: Lazy<RwLock<Config>> = Lazy::new(|| {
     let config = Config {
         data_dir: "george-db/src/test/george".to_string(),
         thread_count: 100,
+        backup_dir: None,
     };
     RwLock::new(config)
 });
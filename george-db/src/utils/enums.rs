@@ -14,6 +14,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use george_comm::{IndexEngine, IndexKeyType};
+
 use crate::utils::{Enum, EnumHandler};
 
 impl EnumHandler for Enum {
@@ -32,6 +34,23 @@ impl EnumHandler for Enum {
     fn key_type(b: u8) -> KeyType {
         key_type(b)
     }
+
+    fn index_engine(engine: IndexEngine) -> Engine {
+        match engine {
+            IndexEngine::Sequence => Engine::Sequence,
+            IndexEngine::Disk => Engine::Disk,
+        }
+    }
+
+    fn index_key_type(key_type: IndexKeyType) -> KeyType {
+        match key_type {
+            IndexKeyType::String => KeyType::String,
+            IndexKeyType::UInt => KeyType::UInt,
+            IndexKeyType::Int => KeyType::Int,
+            IndexKeyType::Float => KeyType::Float,
+            IndexKeyType::Bool => KeyType::Bool,
+        }
+    }
 }
 
 /// 索引值类型
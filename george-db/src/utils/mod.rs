@@ -14,6 +14,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use george_comm::{IndexEngine, IndexKeyType};
+
 use crate::utils::enums::{Engine, KeyType};
 
 pub mod comm;
@@ -31,6 +33,8 @@ pub trait EnumHandler {
     fn key_type_u8(key_type: KeyType) -> u8;
     fn engine(b: u8) -> Engine;
     fn key_type(b: u8) -> KeyType;
+    fn index_engine(engine: IndexEngine) -> Engine;
+    fn index_key_type(key_type: IndexKeyType) -> KeyType;
 }
 
 pub struct Paths;
@@ -44,4 +48,6 @@ pub struct Config {
     pub data_dir: String,
     /// 限制打开文件描述符次数
     pub thread_count: usize,
+    /// 备份根路径
+    pub backup_dir: Option<String>,
 }
@@ -26,6 +26,36 @@ impl Paths {
         bootstrap_filepath()
     }
 
+    /// 审计日志文件 /var/lib/georgedb/audit.log
+    pub fn audit_filepath() -> String {
+        audit_filepath()
+    }
+
+    /// 配额策略文件 /var/lib/georgedb/quota.json
+    pub fn quota_filepath() -> String {
+        quota_filepath()
+    }
+
+    /// 服务主密钥文件 /var/lib/georgedb/secret.key
+    pub fn secret_filepath() -> String {
+        secret_filepath()
+    }
+
+    /// 变更日志文件 /var/lib/georgedb/journal.log
+    pub fn journal_filepath() -> String {
+        journal_filepath()
+    }
+
+    /// 复制状态文件 /var/lib/georgedb/replication.json
+    pub fn replication_filepath() -> String {
+        replication_filepath()
+    }
+
+    /// 集群共识状态及日志目录 /var/lib/georgedb/raft
+    pub fn raft_path() -> String {
+        raft_path()
+    }
+
     /// 缓存页根目录 /var/lib/georgedb/data/page
     pub fn data_page_path() -> String {
         data_page_path()
@@ -66,19 +96,29 @@ impl Paths {
         view_filepath(database_name, view_name)
     }
 
-    /// 视图根目录 /var/lib/georgedb/data/database/database_name/ledger_name/ledger.ge
-    pub fn ledger_filepath(database_name: String, ledger_name: String) -> String {
-        ledger_filepath(database_name, ledger_name)
+    /// 视图字段加密策略 /var/lib/georgedb/data/database/database_name/view_name/cipher.json
+    pub fn view_cipher_filepath(database_name: String, view_name: String) -> String {
+        view_cipher_filepath(database_name, view_name)
+    }
+
+    /// 账本根目录 /var/lib/georgedb/data/ledger
+    pub fn data_ledger_path() -> String {
+        data_ledger_path()
     }
 
-    /// 视图根目录 /var/lib/georgedb/data/database/database_name/ledger_name/ledger_light.ge
-    pub fn ledger_light_filepath(database_name: String, ledger_name: String) -> String {
-        ledger_light_filepath(database_name, ledger_name)
+    /// 账本根目录 /var/lib/georgedb/data/ledger/ledger_name
+    pub fn ledger_path(ledger_name: String) -> String {
+        ledger_path(ledger_name)
     }
 
-    /// 视图根目录 /var/lib/georgedb/data/database/database_name/ledger_name/ledger_merkle_light.ge
-    pub fn ledger_merkle_light_filepath(database_name: String, ledger_name: String) -> String {
-        ledger_merkle_light_filepath(database_name, ledger_name)
+    /// 区块文件 /var/lib/georgedb/data/ledger/ledger_name/ledger.ge
+    pub fn ledger_filepath(ledger_name: String) -> String {
+        ledger_filepath(ledger_name)
+    }
+
+    /// 区块坐标文件 /var/lib/georgedb/data/ledger/ledger_name/ledger_light.ge
+    pub fn ledger_light_filepath(ledger_name: String) -> String {
+        ledger_light_filepath(ledger_name)
     }
 
     /// 视图根目录 /var/lib/georgedb/data/database/database_name/view_name/index_name
@@ -117,6 +157,11 @@ fn data_database_path() -> String {
     format!("{}/data/database", GLOBAL_CONFIG.read().unwrap().data_dir(),)
 }
 
+/// 账本根目录 /var/lib/georgedb/data/ledger
+fn data_ledger_path() -> String {
+    format!("{}/data/ledger", GLOBAL_CONFIG.read().unwrap().data_dir(),)
+}
+
 /// 缓存页根目录 /var/lib/georgedb/data/page/page_name
 fn page_path(page_name: String) -> String {
     format!(
@@ -135,6 +180,15 @@ fn database_path(database_name: String) -> String {
     )
 }
 
+/// 账本根目录 /var/lib/georgedb/data/ledger/ledger_name
+fn ledger_path(ledger_name: String) -> String {
+    format!(
+        "{}/data/ledger/{}",
+        GLOBAL_CONFIG.read().unwrap().data_dir(),
+        ledger_name
+    )
+}
+
 /// 视图根目录 /var/lib/georgedb/data/database/database_name/view_name
 fn view_path(database_name: String, view_name: String) -> String {
     format!(
@@ -165,6 +219,60 @@ fn bootstrap_filepath() -> String {
     )
 }
 
+/// 审计日志文件 /var/lib/georgedb/audit.log
+fn audit_filepath() -> String {
+    format!(
+        "{}/{}",
+        GLOBAL_CONFIG.read().unwrap().data_dir.clone(),
+        "audit.log"
+    )
+}
+
+/// 服务主密钥文件 /var/lib/georgedb/secret.key
+fn secret_filepath() -> String {
+    format!(
+        "{}/{}",
+        GLOBAL_CONFIG.read().unwrap().data_dir.clone(),
+        "secret.key"
+    )
+}
+
+/// 变更日志文件 /var/lib/georgedb/journal.log
+fn journal_filepath() -> String {
+    format!(
+        "{}/{}",
+        GLOBAL_CONFIG.read().unwrap().data_dir.clone(),
+        "journal.log"
+    )
+}
+
+/// 配额策略文件 /var/lib/georgedb/quota.json
+fn quota_filepath() -> String {
+    format!(
+        "{}/{}",
+        GLOBAL_CONFIG.read().unwrap().data_dir.clone(),
+        "quota.json"
+    )
+}
+
+/// 复制状态文件 /var/lib/georgedb/replication.json
+fn replication_filepath() -> String {
+    format!(
+        "{}/{}",
+        GLOBAL_CONFIG.read().unwrap().data_dir.clone(),
+        "replication.json"
+    )
+}
+
+/// 集群共识状态及日志目录 /var/lib/georgedb/raft
+fn raft_path() -> String {
+    format!(
+        "{}/{}",
+        GLOBAL_CONFIG.read().unwrap().data_dir.clone(),
+        "raft"
+    )
+}
+
 /// 缓存页根目录 /var/lib/georgedb/data/page/page_name/page.ge
 fn page_filepath(page_name: String) -> String {
     format!(
@@ -193,34 +301,23 @@ fn view_filepath(database_name: String, view_name: String) -> String {
     )
 }
 
-/// 视图根目录 /var/lib/georgedb/data/database/database_name/ledger_name/ledger.ge
-fn ledger_filepath(database_name: String, ledger_name: String) -> String {
+fn view_cipher_filepath(database_name: String, view_name: String) -> String {
     format!(
-        "{}/data/database/{}/{}/ledger.ge",
+        "{}/data/database/{}/{}/cipher.json",
         GLOBAL_CONFIG.read().unwrap().data_dir.clone(),
         database_name,
-        ledger_name
+        view_name
     )
 }
 
-/// 视图根目录 /var/lib/georgedb/data/database/database_name/ledger_name/ledger_light.ge
-fn ledger_light_filepath(database_name: String, ledger_name: String) -> String {
-    format!(
-        "{}/data/database/{}/{}/ledger_light.ge",
-        GLOBAL_CONFIG.read().unwrap().data_dir.clone(),
-        database_name,
-        ledger_name
-    )
+/// 区块文件 /var/lib/georgedb/data/ledger/ledger_name/ledger.ge
+fn ledger_filepath(ledger_name: String) -> String {
+    format!("{}/ledger.ge", ledger_path(ledger_name))
 }
 
-/// 视图根目录 /var/lib/georgedb/data/database/database_name/ledger_name/ledger_merkle_light.ge
-fn ledger_merkle_light_filepath(database_name: String, ledger_name: String) -> String {
-    format!(
-        "{}/data/database/{}/{}/ledger_merkle_light.ge",
-        GLOBAL_CONFIG.read().unwrap().data_dir.clone(),
-        database_name,
-        ledger_name
-    )
+/// 区块坐标文件 /var/lib/georgedb/data/ledger/ledger_name/ledger_light.ge
+fn ledger_light_filepath(ledger_name: String) -> String {
+    format!("{}/ledger_light.ge", ledger_path(ledger_name))
 }
 
 /// 索引文件目录 /var/lib/georgedb/data/database/database_name/view_name/index_name/index.ge
@@ -19,6 +19,7 @@ impl ConfigDB {
         ConfigDB {
             data_dir: Some("george-server/src/test/george".to_string()),
             thread_count: Some(10),
+            backup_dir: None,
         }
     }
 
@@ -26,6 +27,7 @@ impl ConfigDB {
         ConfigDB {
             data_dir: Some(data_dir),
             thread_count: Some(thread_count),
+            backup_dir: None,
         }
     }
 
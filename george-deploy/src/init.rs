@@ -19,7 +19,10 @@ use log::LevelFilter;
 use george_comm::errors::GeorgeResult;
 use george_log::LogModule;
 
-use crate::{Conf, Config, ConfigDB, ConfigLog, ConfigServer, Init, LogPolicy};
+use crate::{
+    Conf, Config, ConfigDB, ConfigLog, ConfigServer, ConfigServerListen, ConfigServerPeer,
+    ConfigServerTLS, Init, LogPolicy,
+};
 
 impl Init {
     pub fn from<P: AsRef<Path>>(filepath: P) -> GeorgeResult<Self> {
@@ -49,6 +52,10 @@ impl Init {
         self.conf.log()?.log_dir
     }
 
+    pub fn data_dir(&self) -> Option<String> {
+        self.conf.db()?.data_dir
+    }
+
     pub fn port_unwrap(&self) -> u16 {
         self.conf
             .server()
@@ -57,6 +64,22 @@ impl Init {
             .expect("It's not gonna happen!")
     }
 
+    /// 服务监听集合，未配置时以`127.0.0.1:{port}`及`tls`配置作为唯一监听
+    pub fn listens(&self) -> Vec<ConfigServerListen> {
+        let server = match self.conf.server() {
+            Some(res) => res,
+            None => return vec![],
+        };
+        match server.listens {
+            Some(res) if !res.is_empty() => res,
+            _ => vec![ConfigServerListen {
+                addr: Some(format!("127.0.0.1:{}", self.port_unwrap())),
+                uds: None,
+                tls: server.tls,
+            }],
+        }
+    }
+
     pub fn tls(&self) -> bool {
         match self.conf.server() {
             Some(res) => match res.tls {
@@ -89,6 +112,10 @@ impl Init {
         self.conf.server().unwrap().tls.unwrap().ca
     }
 
+    pub fn tls_crl(&self) -> Option<String> {
+        self.conf.server()?.tls?.crl
+    }
+
     pub fn domain(&self) -> String {
         match self.conf.server().unwrap().tls.unwrap().domain {
             Some(res) => res,
@@ -96,6 +123,97 @@ impl Init {
         }
     }
 
+    pub fn tls_reload_interval(&self) -> Option<u64> {
+        match self.conf.server()?.tls?.reload_interval {
+            Some(0) => None,
+            res => res,
+        }
+    }
+
+    /// 停止服务时等待进行中的请求及索引任务完成的最长时间，单位secs
+    pub fn shutdown_timeout(&self) -> u64 {
+        let server = self.conf.server();
+        server.and_then(|res| res.shutdown_timeout).unwrap_or(30)
+    }
+
+    /// 运行指标监听端口号，为空则不开启运行指标
+    pub fn metrics_port(&self) -> Option<u16> {
+        self.conf.server()?.metrics?.port
+    }
+
+    /// 运行指标访问路径，默认`/metrics`
+    pub fn metrics_path(&self) -> String {
+        let metrics = self.conf.server().and_then(|res| res.metrics);
+        metrics
+            .and_then(|res| res.path)
+            .unwrap_or_else(|| "/metrics".to_string())
+    }
+
+    /// 主从复制的主节点地址，为空则作为主节点运行
+    pub fn replication_primary(&self) -> Option<String> {
+        self.conf.server()?.replication?.primary
+    }
+
+    /// 连接主节点的`TLS`配置
+    pub fn replication_tls(&self) -> Option<ConfigServerTLS> {
+        self.conf.server()?.replication?.tls
+    }
+
+    /// 节点间认证密钥文件路径，为空则拒绝所有节点间请求
+    pub fn peer_key(&self) -> Option<String> {
+        self.conf.server()?.peer_key
+    }
+
+    /// 集群中本节点编号，为空则以单节点运行
+    pub fn cluster_id(&self) -> Option<u64> {
+        self.conf.server()?.cluster?.id
+    }
+
+    /// 集群初始节点集合
+    pub fn cluster_peers(&self) -> Vec<ConfigServerPeer> {
+        self.conf
+            .server()
+            .and_then(|server| server.cluster?.peers)
+            .unwrap_or_default()
+    }
+
+    /// 是否以新节点身份加入已运行的集群
+    pub fn cluster_join(&self) -> bool {
+        self.conf
+            .server()
+            .and_then(|server| server.cluster?.join)
+            .unwrap_or(false)
+    }
+
+    /// 集群选举超时基准，单位ms
+    pub fn cluster_election_timeout(&self) -> u64 {
+        self.conf
+            .server()
+            .and_then(|server| server.cluster?.election_timeout)
+            .unwrap_or(1000)
+    }
+
+    /// 集群领导者心跳间隔，单位ms
+    pub fn cluster_heartbeat_interval(&self) -> u64 {
+        self.conf
+            .server()
+            .and_then(|server| server.cluster?.heartbeat_interval)
+            .unwrap_or(200)
+    }
+
+    /// 集群快照间隔，即自上次快照起已应用的共识日志条目数
+    pub fn cluster_snapshot_entries(&self) -> u64 {
+        self.conf
+            .server()
+            .and_then(|server| server.cluster?.snapshot_entries)
+            .unwrap_or(10000)
+    }
+
+    /// 连接集群其它节点的`TLS`配置
+    pub fn cluster_tls(&self) -> Option<ConfigServerTLS> {
+        self.conf.server()?.cluster?.tls
+    }
+
     pub fn timeout(&self) -> Option<u64> {
         self.conf.server()?.http?.timeout
     }
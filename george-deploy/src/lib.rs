@@ -88,6 +88,8 @@ pub struct ConfigDB {
     pub data_dir: Option<String>,
     /// 限制打开文件描述符次数
     pub thread_count: Option<usize>,
+    /// 备份根路径，备份请求指定的目录均位于该路径下，为空则拒绝备份请求
+    pub backup_dir: Option<String>,
 }
 
 /// 服务配置信息，优先读取环境变量中的结果<p>
@@ -95,12 +97,92 @@ pub struct ConfigDB {
 /// 该配置信息可通过指定路径的文件中进行读取，文件格式支持yaml
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
 pub struct ConfigServer {
-    /// 服务端口号
+    /// 服务端口号，未配置`listens`时监听`127.0.0.1`的该端口
     pub port: Option<u16>,
-    /// `TLS`配置
+    /// 服务监听集合，配置后`port`及`tls`不再生效
+    pub listens: Option<Vec<ConfigServerListen>>,
+    /// 停止服务时等待进行中的请求及索引任务完成的最长时间，单位secs，默认30
+    pub shutdown_timeout: Option<u64>,
+    /// `TLS`配置，未配置`listens`时生效
     pub tls: Option<ConfigServerTLS>,
     /// `HTTP`配置
     pub http: Option<ConfigServerHttp>,
+    /// 运行指标配置，为空则不开启
+    pub metrics: Option<ConfigServerMetrics>,
+    /// 主从复制配置，为空则作为主节点运行
+    pub replication: Option<ConfigServerReplication>,
+    /// 集群配置，为空则以单节点运行，不可与主从复制同时配置
+    pub cluster: Option<ConfigServerCluster>,
+    /// 节点间认证密钥文件路径，文件内容为`hex`编码的不少于32字节的密钥<p>
+    /// 主从复制及集群各节点须配置同一密钥，为空则拒绝所有节点间请求
+    pub peer_key: Option<String>,
+}
+
+/// 服务监听配置信息
+///
+/// `addr`与`uds`二选一，`uds`仅供本机客户端连接，不支持`TLS`
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+pub struct ConfigServerListen {
+    /// 监听地址，格式为`host:port`，如`0.0.0.0:9219`、`[::]:9219`
+    pub addr: Option<String>,
+    /// `Unix domain socket`文件路径
+    pub uds: Option<String>,
+    /// 当前监听的`TLS`配置，仅对`addr`生效
+    pub tls: Option<ConfigServerTLS>,
+}
+
+/// 运行指标配置信息
+///
+/// 开启后以`HTTP`方式导出`Prometheus`文本格式的运行指标
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+pub struct ConfigServerMetrics {
+    /// 运行指标监听端口号
+    pub port: Option<u16>,
+    /// 运行指标访问路径，默认`/metrics`
+    pub path: Option<String>,
+}
+
+/// 主从复制配置信息
+///
+/// 配置后作为从节点运行，数据存储路径为空时先从主节点拉取快照，随后持续应用主节点推送的变更
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+pub struct ConfigServerReplication {
+    /// 主节点地址，格式为`host:port`
+    pub primary: Option<String>,
+    /// 连接主节点的`TLS`配置，为空则不开启<p>
+    /// `ca`为主节点根证书，`domain`为主节点域名，`key`及`cert`为主节点要求校验客户端证书时提供的证书
+    pub tls: Option<ConfigServerTLS>,
+}
+
+/// 集群配置信息
+///
+/// 配置后以集群模式运行，数据变更经`Raft`共识日志复制到多数节点后生效，非领导者节点将写入转发至领导者
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+pub struct ConfigServerCluster {
+    /// 本节点编号，集群内唯一且不为0
+    pub id: Option<u64>,
+    /// 集群初始节点集合，须包含本节点，各节点配置须一致
+    pub peers: Option<Vec<ConfigServerPeer>>,
+    /// 是否以新节点身份加入已运行的集群，默认`false`<p>
+    /// 为`true`时忽略`peers`，等待领导者经成员变更将本节点加入后再参与选举
+    pub join: Option<bool>,
+    /// 选举超时基准，单位ms，默认1000，实际超时在基准与两倍基准间随机
+    pub election_timeout: Option<u64>,
+    /// 领导者心跳间隔，单位ms，默认200，须小于选举超时基准
+    pub heartbeat_interval: Option<u64>,
+    /// 快照间隔，默认10000，自上次快照起每应用该数量的共识日志条目生成一次快照并丢弃快照包含的条目
+    pub snapshot_entries: Option<u64>,
+    /// 连接其它节点的`TLS`配置，为空则不开启，各项含义同主从复制
+    pub tls: Option<ConfigServerTLS>,
+}
+
+/// 集群节点配置信息
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+pub struct ConfigServerPeer {
+    /// 节点编号
+    pub id: u64,
+    /// 节点服务地址，格式为`host:port`
+    pub addr: String,
 }
 
 /// 服务配置信息，优先读取环境变量中的结果<p>
@@ -116,8 +198,17 @@ pub struct ConfigServerTLS {
     pub cert: Option<String>,
     /// 客户端根证书，开启`TLS`后生效
     pub ca: Option<String>,
+    /// 客户端证书是否可选，默认`false`，即配置`ca`后客户端必须提供由其签发的证书<p>
+    /// 为`true`时未提供证书的客户端也可连接，已提供的证书仍须由`ca`签发
+    pub client_optional: Option<bool>,
+    /// 客户端证书吊销列表，须由客户端根证书签发，配置`ca`后生效
+    pub crl: Option<String>,
+    /// 是否接受已超过下一次更新时间的吊销列表，默认`false`，即启动及握手时拒绝过期的吊销列表
+    pub crl_allow_stale: Option<bool>,
     /// 服务端域名，开启`TLS`后生效
     pub domain: Option<String>,
+    /// 证书文件变更检测间隔，单位secs，为空或0则不检测，开启`TLS`后生效
+    pub reload_interval: Option<u64>,
 }
 
 /// 服务配置信息，优先读取环境变量中的结果<p>
@@ -19,7 +19,13 @@ impl ConfigServer {
         ConfigServer {
             tls: None,
             port: Some(9219),
+            listens: None,
+            shutdown_timeout: None,
             http: None,
+            metrics: None,
+            replication: None,
+            cluster: None,
+            peer_key: None,
         }
     }
 
@@ -0,0 +1,369 @@
+/*
+ * Copyright (c) 2020. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    Attribute, Data, DeriveInput, Error, Field, Fields, GenericArgument, Lit, Meta, NestedMeta,
+    Path, PathArguments, Result, Type,
+};
+
+use crate::{DocumentAttr, IndexAttr};
+
+/// 可用的存储引擎类型，`Increment`仅用于视图自增ID，不可由字段指定
+const ENGINES: [&str; 2] = ["Disk", "Sequence"];
+
+/// 可用的索引值类型
+const KEY_TYPES: [&str; 5] = ["String", "UInt", "Int", "Float", "Bool"];
+
+/// serde可用的字段命名规则
+const RENAME_RULES: [&str; 8] = [
+    "lowercase",
+    "UPPERCASE",
+    "PascalCase",
+    "camelCase",
+    "snake_case",
+    "SCREAMING_SNAKE_CASE",
+    "kebab-case",
+    "SCREAMING-KEBAB-CASE",
+];
+
+pub(crate) fn expand(input: DeriveInput) -> Result<TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(Error::new_spanned(
+                    &input.ident,
+                    "Document only supports structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "Document only supports structs",
+            ))
+        }
+    };
+    let document = DocumentAttr::parse(&input.attrs)?;
+    let mut indexes: Vec<IndexAttr> = vec![];
+    for field in fields {
+        if let Some(index) = IndexAttr::parse(field, &document)? {
+            if indexes.iter().any(|res| res.name == index.name) {
+                return Err(Error::new_spanned(
+                    field,
+                    format!("duplicate index name {}", index.name),
+                ));
+            }
+            if index.primary && indexes.iter().any(|res| res.primary) {
+                return Err(Error::new_spanned(field, "only one primary index allowed"));
+            }
+            indexes.push(index);
+        }
+    }
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let krate = &document.krate;
+    let defines = indexes.iter().map(|index| index.define(krate));
+    Ok(quote! {
+        impl #impl_generics #krate::Document for #ident #ty_generics #where_clause {
+            fn indexes() -> Vec<#krate::IndexDefine> {
+                vec![#(#defines),*]
+            }
+        }
+    })
+}
+
+impl DocumentAttr {
+    /// 解析结构体上的`#[george(crate = "...")]`及`#[serde(rename_all = "...")]`属性
+    fn parse(attrs: &[Attribute]) -> Result<DocumentAttr> {
+        let mut krate: Path = syn::parse_quote!(::george_comm);
+        for attr in attrs {
+            if !attr.path.is_ident("george") {
+                continue;
+            }
+            let list = match attr.parse_meta()? {
+                Meta::List(res) => res,
+                meta => return Err(Error::new_spanned(meta, "expected #[george(...)]")),
+            };
+            for nested in list.nested {
+                match nested {
+                    NestedMeta::Meta(Meta::NameValue(pair)) if pair.path.is_ident("crate") => {
+                        krate = match &pair.lit {
+                            Lit::Str(res) => res.parse()?,
+                            lit => return Err(Error::new_spanned(lit, "expected string literal")),
+                        }
+                    }
+                    nested => return Err(Error::new_spanned(nested, "unknown george attribute")),
+                }
+            }
+        }
+        let rename_all = match serde_rename(attrs, "rename_all")? {
+            Some((res, lit)) => {
+                if !RENAME_RULES.contains(&res.as_str()) {
+                    return Err(Error::new_spanned(
+                        lit,
+                        format!("rename_all must be one of {:?}", RENAME_RULES),
+                    ));
+                }
+                Some(res)
+            }
+            None => None,
+        };
+        Ok(DocumentAttr { krate, rename_all })
+    }
+
+    /// 字段序列化为json后的字段名，依次取字段`rename`、结构体`rename_all`规则及字段名
+    fn serialized_name(&self, field: &Field) -> Result<String> {
+        if let Some((res, _)) = serde_rename(&field.attrs, "rename")? {
+            return Ok(res);
+        }
+        let ident = field.ident.as_ref().unwrap().to_string();
+        let ident = ident.trim_start_matches("r#");
+        Ok(match &self.rename_all {
+            Some(rule) => rename(rule, ident),
+            None => ident.to_string(),
+        })
+    }
+}
+
+impl IndexAttr {
+    /// 解析字段上的`#[george(...)]`属性，字段未声明`index`时返回空
+    fn parse(field: &Field, document: &DocumentAttr) -> Result<Option<IndexAttr>> {
+        let mut index = false;
+        let mut name: Option<String> = None;
+        let mut engine: Option<String> = None;
+        let mut key_type: Option<String> = None;
+        let mut primary = false;
+        let mut unique = false;
+        let mut null = false;
+        let mut declared = false;
+        for attr in &field.attrs {
+            if !attr.path.is_ident("george") {
+                continue;
+            }
+            declared = true;
+            let list = match attr.parse_meta()? {
+                Meta::List(res) => res,
+                meta => return Err(Error::new_spanned(meta, "expected #[george(...)]")),
+            };
+            for nested in list.nested {
+                match nested {
+                    NestedMeta::Meta(Meta::Path(path)) => {
+                        if path.is_ident("index") {
+                            index = true
+                        } else if path.is_ident("primary") {
+                            primary = true
+                        } else if path.is_ident("unique") {
+                            unique = true
+                        } else if path.is_ident("null") {
+                            null = true
+                        } else {
+                            return Err(Error::new_spanned(path, "unknown george attribute"));
+                        }
+                    }
+                    NestedMeta::Meta(Meta::NameValue(pair)) => {
+                        let value = match &pair.lit {
+                            Lit::Str(res) => res.value(),
+                            lit => return Err(Error::new_spanned(lit, "expected string literal")),
+                        };
+                        if pair.path.is_ident("name") {
+                            name = Some(value)
+                        } else if pair.path.is_ident("engine") {
+                            if !ENGINES.contains(&value.as_str()) {
+                                return Err(Error::new_spanned(
+                                    &pair.lit,
+                                    format!("engine must be one of {:?}", ENGINES),
+                                ));
+                            }
+                            engine = Some(value)
+                        } else if pair.path.is_ident("key_type") {
+                            if !KEY_TYPES.contains(&value.as_str()) {
+                                return Err(Error::new_spanned(
+                                    &pair.lit,
+                                    format!("key_type must be one of {:?}", KEY_TYPES),
+                                ));
+                            }
+                            key_type = Some(value)
+                        } else {
+                            return Err(Error::new_spanned(&pair.path, "unknown george attribute"));
+                        }
+                    }
+                    nested => return Err(Error::new_spanned(nested, "unknown george attribute")),
+                }
+            }
+        }
+        if !index {
+            if declared {
+                return Err(Error::new_spanned(
+                    field,
+                    "george attribute requires `index`",
+                ));
+            }
+            return Ok(None);
+        }
+        let (inferred, optional) = infer(&field.ty);
+        let key_type = match key_type.or(inferred) {
+            Some(res) => res,
+            None => {
+                return Err(Error::new_spanned(
+                    &field.ty,
+                    "cannot infer key_type from field type, set key_type = \"...\"",
+                ))
+            }
+        };
+        let engine = engine.unwrap_or_else(|| "Disk".to_string());
+        if engine == "Sequence" && key_type != "UInt" {
+            return Err(Error::new_spanned(
+                field,
+                "engine Sequence only supports key_type UInt",
+            ));
+        }
+        let serialized = document.serialized_name(field)?;
+        let name = match name {
+            Some(res) if res != serialized => {
+                return Err(Error::new_spanned(
+                    field,
+                    format!(
+                        "index name {} conflicts with serialized name {}, index name must match the json field name",
+                        res, serialized
+                    ),
+                ))
+            }
+            _ => serialized,
+        };
+        Ok(Some(IndexAttr {
+            name,
+            engine,
+            key_type,
+            primary,
+            unique: unique || primary,
+            null: null || optional,
+        }))
+    }
+
+    /// 生成`IndexDefine`构造表达式
+    fn define(&self, krate: &Path) -> TokenStream {
+        let name = &self.name;
+        let engine = format_ident!("{}", self.engine);
+        let key_type = format_ident!("{}", self.key_type);
+        let primary = self.primary;
+        let unique = self.unique;
+        let null = self.null;
+        quote! {
+            #krate::IndexDefine {
+                name: #name.to_string(),
+                engine: #krate::IndexEngine::#engine,
+                key_type: #krate::IndexKeyType::#key_type,
+                primary: #primary,
+                unique: #unique,
+                null: #null,
+            }
+        }
+    }
+}
+
+/// 读取`#[serde(...)]`中`key = "..."`或`key(serialize = "...")`指定的序列化名称，返回名称及其字面量
+///
+/// 索引作用于序列化后的json，仅`deserialize`指定的名称不影响索引名
+fn serde_rename(attrs: &[Attribute], key: &str) -> Result<Option<(String, Lit)>> {
+    let mut res = None;
+    for attr in attrs {
+        if !attr.path.is_ident("serde") {
+            continue;
+        }
+        // 其余serde属性格式由serde校验
+        let list = match attr.parse_meta() {
+            Ok(Meta::List(res)) => res,
+            _ => continue,
+        };
+        for nested in list.nested {
+            match nested {
+                NestedMeta::Meta(Meta::NameValue(pair)) if pair.path.is_ident(key) => {
+                    res = Some(pair.lit)
+                }
+                NestedMeta::Meta(Meta::List(inner)) if inner.path.is_ident(key) => {
+                    for nested in inner.nested {
+                        if let NestedMeta::Meta(Meta::NameValue(pair)) = nested {
+                            if pair.path.is_ident("serialize") {
+                                res = Some(pair.lit)
+                            }
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+    match res {
+        Some(Lit::Str(value)) => Ok(Some((value.value(), Lit::Str(value)))),
+        Some(lit) => Err(Error::new_spanned(lit, "expected string literal")),
+        None => Ok(None),
+    }
+}
+
+/// 按serde`rename_all`规则转换蛇形命名的字段名
+fn rename(rule: &str, ident: &str) -> String {
+    match rule {
+        "UPPERCASE" | "SCREAMING_SNAKE_CASE" => ident.to_ascii_uppercase(),
+        "PascalCase" | "camelCase" => {
+            let mut res = String::new();
+            let mut capitalize = rule == "PascalCase";
+            for c in ident.chars() {
+                if c == '_' {
+                    capitalize = true
+                } else if capitalize {
+                    res.push(c.to_ascii_uppercase());
+                    capitalize = false
+                } else {
+                    res.push(c)
+                }
+            }
+            res
+        }
+        "kebab-case" => ident.replace('_', "-"),
+        "SCREAMING-KEBAB-CASE" => ident.to_ascii_uppercase().replace('_', "-"),
+        _ => ident.to_string(),
+    }
+}
+
+/// 由字段类型推断索引值类型，返回推断结果及字段是否为`Option`
+fn infer(ty: &Type) -> (Option<String>, bool) {
+    let segment = match ty {
+        Type::Reference(res) => return infer(&res.elem),
+        Type::Path(res) => match res.path.segments.last() {
+            Some(res) => res,
+            None => return (None, false),
+        },
+        _ => return (None, false),
+    };
+    if segment.ident == "Option" {
+        if let PathArguments::AngleBracketed(args) = &segment.arguments {
+            if let Some(GenericArgument::Type(inner)) = args.args.first() {
+                return (infer(inner).0, true);
+            }
+        }
+        return (None, true);
+    }
+    let key_type = match segment.ident.to_string().as_str() {
+        "u8" | "u16" | "u32" | "u64" | "usize" => "UInt",
+        "i8" | "i16" | "i32" | "i64" | "isize" => "Int",
+        "f32" | "f64" => "Float",
+        "bool" => "Bool",
+        "String" | "str" => "String",
+        _ => return (None, false),
+    };
+    (Some(key_type.to_string()), false)
+}
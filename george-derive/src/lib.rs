@@ -0,0 +1,84 @@
+/*
+ * Copyright (c) 2020. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! George DB 类型化文档派生宏
+//!
+//! `#[derive(Document)]`依据字段上的`#[george(...)]`属性为结构体生成索引定义，由`TypedView`在初始化时创建：
+//!
+//! ```ignore
+//! #[derive(Serialize, Deserialize, Document)]
+//! #[serde(rename_all = "camelCase")]
+//! struct User {
+//!     #[george(index, unique, key_type = "UInt")]
+//!     id: u64,
+//!     #[george(index, engine = "Sequence")]
+//!     age: u64,
+//!     #[george(index)]
+//!     #[serde(rename = "nick")]
+//!     nick_name: Option<String>,
+//!     remark: String,
+//! }
+//! ```
+//!
+//! * index 该字段建立索引，其余属性均需与之同时使用
+//! * name 索引名，即字段序列化为json后的字段名，默认依据`#[serde(rename = "...")]`及`#[serde(rename_all = "...")]`得出，
+//! 指定时需与之一致，否则编译失败
+//! * engine 存储引擎类型，可选`Disk`（默认）及`Sequence`，`Sequence`仅支持`UInt`
+//! * key_type 索引值类型，可选`String`、`UInt`、`Int`、`Float`及`Bool`，默认由字段类型推断
+//! * primary 是否主键，主键也是唯一索引
+//! * unique 是否唯一索引
+//! * null 是否允许为空，`Option`字段默认允许为空
+//!
+//! 生成的代码引用`::george_comm`，可在结构体上以`#[george(crate = "george_sdk")]`改为引用重新导出`Document`的crate
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput, Path};
+
+mod document;
+
+/// 由结构体`#[george(...)]`及`#[serde(...)]`属性解析得到的文档定义
+struct DocumentAttr {
+    /// 生成代码引用`Document`及`IndexDefine`的crate路径
+    krate: Path,
+    /// `#[serde(rename_all = "...")]`指定的字段序列化命名规则
+    rename_all: Option<String>,
+}
+
+/// 由字段`#[george(...)]`属性解析得到的索引定义
+struct IndexAttr {
+    /// 索引名
+    name: String,
+    /// 存储引擎类型
+    engine: String,
+    /// 索引值类型
+    key_type: String,
+    /// 是否主键
+    primary: bool,
+    /// 是否唯一索引
+    unique: bool,
+    /// 是否允许为空
+    null: bool,
+}
+
+/// 为结构体派生`george_comm::Document`
+#[proc_macro_derive(Document, attributes(george))]
+pub fn derive_document(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match document::expand(input) {
+        Ok(res) => res.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
@@ -15,14 +15,19 @@
 use std::fs::File;
 use std::io::{Seek, SeekFrom, Write};
 use std::path::Path;
-use std::sync::{Arc, RwLock};
+use std::sync::{Arc, Mutex, RwLock, Weak};
+
+use once_cell::sync::Lazy;
 
 use george_comm::errors::Errs;
 use george_comm::errors::GeorgeResult;
 use george_comm::io::file::{FilerExecutor, FilerHandler, FilerNormal, FilerReader};
 use george_comm::io::Filer;
 
-use crate::utils::Filed;
+use crate::utils::{Filed, Snapshot};
+
+/// 已打开的文件集合，以便服务退出前统一落盘，文件释放后自动失效
+static FILEDS: Lazy<Mutex<Vec<Weak<RwLock<FiledExec>>>>> = Lazy::new(|| Mutex::new(vec![]));
 
 impl Filed {
     pub fn create<P: AsRef<Path>>(filepath: P) -> GeorgeResult<Filed> {
@@ -33,12 +38,40 @@ impl Filed {
     pub fn recovery<P: AsRef<Path>>(filepath: P) -> GeorgeResult<Filed> {
         let writer = Filer::writer(&filepath)?;
         let appender = Filer::appender(&filepath)?;
+        let exec = Arc::new(RwLock::new(FiledExec { writer, appender }));
+        {
+            let mut fileds = FILEDS.lock().unwrap();
+            // 扩容前先清除已释放的文件，并预留与存活文件数相同的空间，清除开销均摊到每次插入
+            if fileds.len() == fileds.capacity() {
+                fileds.retain(|filed| filed.strong_count() > 0);
+                let live = fileds.len();
+                fileds.reserve(live);
+            }
+            fileds.push(Arc::downgrade(&exec));
+        }
         Ok(Filed {
             filepath: Filer::absolute(filepath)?,
-            exec: Arc::new(RwLock::new(FiledExec { writer, appender })),
+            exec,
         })
     }
 
+    /// 将所有已打开文件的内容及元数据同步到磁盘，返回同步的文件数
+    pub fn sync_all() -> GeorgeResult<usize> {
+        let mut fileds = FILEDS.lock().unwrap();
+        fileds.retain(|filed| filed.strong_count() > 0);
+        for filed in fileds.iter() {
+            if let Some(exec) = filed.upgrade() {
+                exec.read().unwrap().sync()?;
+            }
+        }
+        Ok(fileds.len())
+    }
+
+    /// 将文件内容及元数据同步到磁盘
+    pub fn sync(&self) -> GeorgeResult<()> {
+        self.exec.read().unwrap().sync()
+    }
+
     /// 获取文件长度
     pub fn len(&self) -> GeorgeResult<u64> {
         self.exec.read().unwrap().len(self.filepath())
@@ -61,11 +94,15 @@ impl Filed {
 
     /// 在指定坐标后写入content
     pub fn write(&self, seek: u64, content: Vec<u8>) -> GeorgeResult<()> {
-        self.exec.write().unwrap().write(seek, content)
+        let _gate = Snapshot::gate();
+        let exec = self.exec.write().unwrap();
+        Snapshot::preserve(&self.filepath, seek, content.len())?;
+        exec.write(seek, content)
     }
 
     /// 向File中追加content
     pub fn append(&self, content: Vec<u8>) -> GeorgeResult<u64> {
+        let _gate = Snapshot::gate();
         self.exec.write().unwrap().append(content)
     }
 
@@ -77,6 +114,9 @@ impl Filed {
     ///
     /// archive_file_path 归档路径
     pub fn archive(&self, archive_filepath: String) -> GeorgeResult<()> {
+        if Snapshot::active() {
+            return Err(Errs::str("can not archive while snapshot in progress!"));
+        }
         Filer::mv(self.filepath(), archive_filepath)?;
         Filer::touch(self.filepath())?;
         self.exec.write().unwrap().recovery(self.filepath())
@@ -132,6 +172,18 @@ impl FiledExec {
         }
     }
 
+    /// 将文件内容及元数据同步到磁盘
+    fn sync(&self) -> GeorgeResult<()> {
+        match self.writer.sync_all() {
+            Ok(()) => {}
+            Err(err) => return Err(Errs::strs("filed sync writer", err)),
+        }
+        match self.appender.sync_all() {
+            Ok(()) => Ok(()),
+            Err(err) => Err(Errs::strs("filed sync appender", err)),
+        }
+    }
+
     /// 向File中追加content
     fn append(&self, content: Vec<u8>) -> GeorgeResult<u64> {
         match self.appender.try_clone() {
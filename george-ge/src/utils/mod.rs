@@ -11,13 +11,16 @@
  * See the License for the specific language governing permissions and
  * limitations under the License.
  */
-use std::sync::{Arc, RwLock};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, RwLock};
 
 use crate::utils::enums::Tag;
 use crate::utils::filed::FiledExec;
+use crate::utils::snapshot::SnapshotFile;
 
 pub mod enums;
 pub mod filed;
+pub mod snapshot;
 
 /// `ge`文件枚举工具
 pub struct Enum {}
@@ -35,3 +38,15 @@ pub struct Filed {
     filepath: String,
     exec: Arc<RwLock<FiledExec>>,
 }
+
+/// 文件快照
+///
+/// 开启快照时记录各文件长度，快照期间文件仍可正常写入。覆盖写入快照范围内的内容前先保留原内容，
+/// 读取快照时以保留的原内容还原，追加的内容则不在快照范围内，以此得到开启快照时刻一致的文件内容
+#[derive(Debug)]
+pub struct Snapshot {
+    /// 快照文件集合，键为文件绝对路径
+    files: Mutex<HashMap<String, SnapshotFile>>,
+    /// 以整体覆盖方式写入的文件在开启快照时刻的完整内容，键为文件绝对路径
+    captures: HashMap<String, Vec<u8>>,
+}
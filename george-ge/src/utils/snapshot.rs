@@ -0,0 +1,174 @@
+/*
+ * Copyright (c) 2020. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, RwLock, RwLockReadGuard};
+
+use once_cell::sync::Lazy;
+
+use george_comm::errors::{Errs, GeorgeResult};
+use george_comm::io::file::{FilerHandler, FilerReader, FilerWriter};
+use george_comm::io::Filer;
+
+use crate::utils::Snapshot;
+
+/// 当前进行中的快照，同一时刻仅允许一个快照
+static SNAPSHOT: Lazy<RwLock<Option<Arc<Snapshot>>>> = Lazy::new(|| RwLock::new(None));
+
+/// 写入闸门，写入文件时持有读锁，开启快照时持有写锁，保证记录的文件长度不处于写入中途
+static GATE: Lazy<RwLock<()>> = Lazy::new(|| RwLock::new(()));
+
+/// 快照文件信息
+#[derive(Debug)]
+pub(crate) struct SnapshotFile {
+    /// 开启快照时的文件长度
+    len: u64,
+    /// 快照期间被覆盖的原内容，按覆盖先后顺序记录起始偏移量及内容
+    originals: Vec<(u64, Vec<u8>)>,
+}
+
+impl Snapshot {
+    /// 开启快照，已有进行中的快照则返回错误
+    ///
+    /// ###Params
+    ///
+    /// * filepaths 纳入快照的文件路径集合，开启快照后新建的文件不在快照范围内
+    /// * captures 以整体覆盖方式写入的文件路径集合，须经[`Snapshot::write_force`]写入，开启快照时直接读取其完整内容
+    pub fn begin(filepaths: Vec<String>, captures: Vec<String>) -> GeorgeResult<Arc<Snapshot>> {
+        let _gate = GATE.write().unwrap();
+        let mut current = SNAPSHOT.write().unwrap();
+        if current.is_some() {
+            return Err(Errs::str("snapshot already in progress!"));
+        }
+        let mut files = HashMap::new();
+        for filepath in filepaths {
+            let len = Filer::len(&filepath)?;
+            files.insert(
+                Filer::absolute(&filepath)?,
+                SnapshotFile {
+                    len,
+                    originals: vec![],
+                },
+            );
+        }
+        let mut captured = HashMap::new();
+        for filepath in captures {
+            captured.insert(Filer::absolute(&filepath)?, Filer::read_bytes(&filepath)?);
+        }
+        let snapshot = Arc::new(Snapshot {
+            files: Mutex::new(files),
+            captures: captured,
+        });
+        *current = Some(snapshot.clone());
+        Ok(snapshot)
+    }
+
+    /// 结束快照，丢弃快照期间保留的原内容
+    pub fn end(&self) {
+        let mut current = SNAPSHOT.write().unwrap();
+        if let Some(snapshot) = current.as_ref() {
+            if std::ptr::eq(snapshot.as_ref(), self) {
+                *current = None;
+            }
+        }
+    }
+
+    /// 是否有进行中的快照
+    pub fn active() -> bool {
+        SNAPSHOT.read().unwrap().is_some()
+    }
+
+    /// 读取文件在开启快照时刻的内容
+    pub fn read(&self, filepath: String) -> GeorgeResult<Vec<u8>> {
+        let filepath = Filer::absolute(filepath)?;
+        if let Some(content) = self.captures.get(&filepath) {
+            return Ok(content.clone());
+        }
+        let len = match self.files.lock().unwrap().get(&filepath) {
+            Some(res) => res.len,
+            None => {
+                return Err(Errs::string(format!(
+                    "file {} is not in snapshot!",
+                    filepath
+                )))
+            }
+        };
+        let mut content = if len == 0 {
+            vec![]
+        } else {
+            Filer::read_sub(&filepath, 0, len as usize)?
+        };
+        // 读取后再取原内容，读取期间发生的覆盖写入都已保留原内容
+        let files = self.files.lock().unwrap();
+        if let Some(file) = files.get(&filepath) {
+            // 倒序还原，同一位置多次覆盖时以最早保留的原内容为准
+            for (seek, original) in file.originals.iter().rev() {
+                let start = *seek as usize;
+                content[start..start + original.len()].copy_from_slice(original);
+            }
+        }
+        Ok(content)
+    }
+
+    /// 在指定坐标后写入content，有进行中的快照时先保留将被覆盖的原内容
+    pub fn write_seek(filepath: String, seek: u64, content: Vec<u8>) -> GeorgeResult<()> {
+        let _gate = GATE.read().unwrap();
+        Snapshot::preserve(&filepath, seek, content.len())?;
+        Filer::write_seek(filepath, seek, content)
+    }
+
+    /// 以整体覆盖方式写入文件，写入期间持有闸门读锁，使开启快照时读取到的内容不处于写入中途
+    pub fn write_force(filepath: String, content: Vec<u8>) -> GeorgeResult<()> {
+        let _gate = GATE.read().unwrap();
+        Filer::write_force(filepath, content)?;
+        Ok(())
+    }
+
+    /// 文件写入期间持有的闸门读锁
+    pub(crate) fn gate() -> RwLockReadGuard<'static, ()> {
+        GATE.read().unwrap()
+    }
+
+    /// 有进行中的快照且写入范围与快照范围重叠时，保留将被覆盖的原内容，须在写入前调用
+    pub(crate) fn preserve(filepath: &str, seek: u64, len: usize) -> GeorgeResult<()> {
+        let snapshot = match SNAPSHOT.read().unwrap().as_ref() {
+            Some(res) => res.clone(),
+            None => return Ok(()),
+        };
+        let filepath = match Filer::absolute(filepath) {
+            Ok(res) => res,
+            // 文件尚不存在，不在快照范围内
+            Err(_) => return Ok(()),
+        };
+        let mut files = snapshot.files.lock().unwrap();
+        let file = match files.get_mut(&filepath) {
+            Some(res) => res,
+            None => return Ok(()),
+        };
+        if seek >= file.len {
+            return Ok(());
+        }
+        let last = (file.len - seek).min(len as u64) as usize;
+        if file
+            .originals
+            .iter()
+            .any(|(start, original)| *start == seek && original.len() >= last)
+        {
+            return Ok(());
+        }
+        let original = Filer::read_sub(&filepath, seek, last)?;
+        file.originals.push((seek, original));
+        Ok(())
+    }
+}
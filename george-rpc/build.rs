@@ -12,6 +12,9 @@
  * limitations under the License.
  */
 
+use std::env;
+use std::path::PathBuf;
+
 fn comm() -> Result<(), std::io::Error> {
     tonic_build::configure()
         .build_server(true)
@@ -38,16 +41,28 @@ fn db() -> Result<(), std::io::Error> {
         .format(true)
         .compile_well_known_types(true)
         .out_dir("src/protos/db")
+        // 供服务端反射使用
+        .file_descriptor_set_path(
+            PathBuf::from(env::var("OUT_DIR").expect("OUT_DIR not set")).join("db_descriptor.bin"),
+        )
         // .extern_path(".utils", "super")
         .compile(
             &[
+                "db/audit.proto",
+                "db/backup.proto",
+                "db/batch.proto",
+                "db/cluster.proto",
                 "db/database.proto",
                 "db/disk.proto",
                 "db/index.proto",
+                "db/ledger.proto",
                 "db/master.proto",
                 "db/memory.proto",
                 "db/page.proto",
+                "db/quota.proto",
+                "db/replication.proto",
                 "db/service.proto",
+                "db/transfer.proto",
                 "db/user.proto",
                 "db/view.proto",
             ],
@@ -0,0 +1,194 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[cfg(unix)]
+use std::path::Path;
+
+use tonic::Request;
+
+use george_comm::errors::{Errs, GeorgeResult};
+
+use crate::client::db::AuditRpcClient;
+#[cfg(unix)]
+use crate::client::Uds;
+use crate::client::{status_check, with_token, Notls, Openssl, RequestCond, Rustls, TLSType};
+use crate::client::{RpcClient, TLS};
+use crate::protos::db::db::audit_service_client::AuditServiceClient;
+use crate::protos::db::db::{AuditRecord, RequestAuditList};
+use crate::protos::utils::utils::Req;
+
+impl RpcClient for AuditRpcClient {
+    fn new(remote: &str, port: u16, cond_op: Option<RequestCond>) -> GeorgeResult<Self>
+    where
+        Self: Sized,
+    {
+        let (inner, rt) = Notls::make(remote, port, cond_op)?;
+        Ok(AuditRpcClient {
+            client: AuditServiceClient::new(inner),
+            rt,
+            token: "".to_string(),
+        })
+    }
+
+    #[cfg(unix)]
+    fn new_uds<P: AsRef<Path>>(path: P, cond_op: Option<RequestCond>) -> GeorgeResult<Self>
+    where
+        Self: Sized,
+    {
+        let (inner, rt) = Uds::make(path, cond_op)?;
+        Ok(AuditRpcClient {
+            client: AuditServiceClient::new(inner),
+            rt,
+            token: "".to_string(),
+        })
+    }
+
+    fn new_tls_bytes(
+        tls_type: TLSType,
+        remote: &str,
+        port: u16,
+        ca_bytes: Vec<u8>,
+        domain_name: impl Into<String>,
+        cond_op: Option<RequestCond>,
+    ) -> GeorgeResult<Self>
+    where
+        Self: Sized,
+    {
+        let endpoint;
+        match tls_type {
+            TLSType::Rustls => {
+                endpoint = Rustls::new_bytes(remote, port, ca_bytes, domain_name, cond_op)?
+            }
+            TLSType::Openssl => {
+                endpoint = Openssl::new_bytes(remote, port, ca_bytes, domain_name, cond_op)?
+            }
+        }
+        Ok(AuditRpcClient {
+            client: AuditServiceClient::new(endpoint.0),
+            rt: endpoint.1,
+            token: "".to_string(),
+        })
+    }
+
+    fn new_tls_bytes_check(
+        tls_type: TLSType,
+        remote: &str,
+        port: u16,
+        key_bytes: Vec<u8>,
+        cert_bytes: Vec<u8>,
+        ca_bytes: Vec<u8>,
+        domain_name: impl Into<String>,
+        cond_op: Option<RequestCond>,
+    ) -> GeorgeResult<Self>
+    where
+        Self: Sized,
+    {
+        let endpoint;
+        match tls_type {
+            TLSType::Rustls => {
+                endpoint = Rustls::new_bytes_check(
+                    remote,
+                    port,
+                    key_bytes,
+                    cert_bytes,
+                    ca_bytes,
+                    domain_name,
+                    cond_op,
+                )?
+            }
+            TLSType::Openssl => {
+                endpoint = Openssl::new_bytes_check(
+                    remote,
+                    port,
+                    key_bytes,
+                    cert_bytes,
+                    ca_bytes,
+                    domain_name,
+                    cond_op,
+                )?
+            }
+        }
+        Ok(AuditRpcClient {
+            client: AuditServiceClient::new(endpoint.0),
+            rt: endpoint.1,
+            token: "".to_string(),
+        })
+    }
+}
+
+impl AuditRpcClient {
+    /// 设置登录令牌，后续请求都将在请求头中携带该令牌，服务端据此认证操作人
+    pub fn set_token(&mut self, token: String) {
+        self.token = token
+    }
+
+    /// 检索审计记录，按记录先后顺序返回最近的`limit`条
+    ///
+    /// ###Params
+    ///
+    /// * principal 操作发起人，为空则不限
+    /// * operation 操作名称前缀，为空则不限
+    /// * start 起始时间，单位秒，为0则不限
+    /// * end 截止时间，单位秒，为0则不限
+    /// * limit 最多返回条数，为0则不限
+    pub fn list(
+        &mut self,
+        principal: String,
+        operation: String,
+        start: i64,
+        end: i64,
+        limit: u32,
+    ) -> GeorgeResult<Vec<AuditRecord>> {
+        let request = Request::new(RequestAuditList {
+            principal,
+            operation,
+            start,
+            end,
+            limit,
+        });
+        match self
+            .rt
+            .block_on(self.client.list(with_token(request, &self.token)))
+        {
+            Ok(res) => {
+                let resp = res.into_inner();
+                status_check(resp.status, resp.msg_err)?;
+                Ok(resp.records)
+            }
+            Err(err) => Err(Errs::strs(
+                "failed to successfully run the future on RunTime!",
+                err,
+            )),
+        }
+    }
+
+    /// 校验审计链完整性，返回已校验的记录条数
+    pub fn verify(&mut self) -> GeorgeResult<u64> {
+        let request = Request::new(Req {});
+        match self
+            .rt
+            .block_on(self.client.verify(with_token(request, &self.token)))
+        {
+            Ok(res) => {
+                let resp = res.into_inner();
+                status_check(resp.status, resp.msg_err)?;
+                Ok(resp.count)
+            }
+            Err(err) => Err(Errs::strs(
+                "failed to successfully run the future on RunTime!",
+                err,
+            )),
+        }
+    }
+}
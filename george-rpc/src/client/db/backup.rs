@@ -0,0 +1,158 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[cfg(unix)]
+use std::path::Path;
+
+use tonic::Request;
+
+use george_comm::errors::{Errs, GeorgeResult};
+
+use crate::client::db::BackupRpcClient;
+#[cfg(unix)]
+use crate::client::Uds;
+use crate::client::{status_check, with_token, Notls, Openssl, RequestCond, Rustls, TLSType};
+use crate::client::{RpcClient, TLS};
+use crate::protos::db::db::backup_service_client::BackupServiceClient;
+use crate::protos::db::db::{RequestBackup, ResponseBackup};
+
+impl RpcClient for BackupRpcClient {
+    fn new(remote: &str, port: u16, cond_op: Option<RequestCond>) -> GeorgeResult<Self>
+    where
+        Self: Sized,
+    {
+        let (inner, rt) = Notls::make(remote, port, cond_op)?;
+        Ok(BackupRpcClient {
+            client: BackupServiceClient::new(inner),
+            rt,
+            token: "".to_string(),
+        })
+    }
+
+    #[cfg(unix)]
+    fn new_uds<P: AsRef<Path>>(path: P, cond_op: Option<RequestCond>) -> GeorgeResult<Self>
+    where
+        Self: Sized,
+    {
+        let (inner, rt) = Uds::make(path, cond_op)?;
+        Ok(BackupRpcClient {
+            client: BackupServiceClient::new(inner),
+            rt,
+            token: "".to_string(),
+        })
+    }
+
+    fn new_tls_bytes(
+        tls_type: TLSType,
+        remote: &str,
+        port: u16,
+        ca_bytes: Vec<u8>,
+        domain_name: impl Into<String>,
+        cond_op: Option<RequestCond>,
+    ) -> GeorgeResult<Self>
+    where
+        Self: Sized,
+    {
+        let endpoint;
+        match tls_type {
+            TLSType::Rustls => {
+                endpoint = Rustls::new_bytes(remote, port, ca_bytes, domain_name, cond_op)?
+            }
+            TLSType::Openssl => {
+                endpoint = Openssl::new_bytes(remote, port, ca_bytes, domain_name, cond_op)?
+            }
+        }
+        Ok(BackupRpcClient {
+            client: BackupServiceClient::new(endpoint.0),
+            rt: endpoint.1,
+            token: "".to_string(),
+        })
+    }
+
+    fn new_tls_bytes_check(
+        tls_type: TLSType,
+        remote: &str,
+        port: u16,
+        key_bytes: Vec<u8>,
+        cert_bytes: Vec<u8>,
+        ca_bytes: Vec<u8>,
+        domain_name: impl Into<String>,
+        cond_op: Option<RequestCond>,
+    ) -> GeorgeResult<Self>
+    where
+        Self: Sized,
+    {
+        let endpoint;
+        match tls_type {
+            TLSType::Rustls => {
+                endpoint = Rustls::new_bytes_check(
+                    remote,
+                    port,
+                    key_bytes,
+                    cert_bytes,
+                    ca_bytes,
+                    domain_name,
+                    cond_op,
+                )?
+            }
+            TLSType::Openssl => {
+                endpoint = Openssl::new_bytes_check(
+                    remote,
+                    port,
+                    key_bytes,
+                    cert_bytes,
+                    ca_bytes,
+                    domain_name,
+                    cond_op,
+                )?
+            }
+        }
+        Ok(BackupRpcClient {
+            client: BackupServiceClient::new(endpoint.0),
+            rt: endpoint.1,
+            token: "".to_string(),
+        })
+    }
+}
+
+impl BackupRpcClient {
+    /// 设置登录令牌，后续请求都将在请求头中携带该令牌，服务端据此认证操作人
+    pub fn set_token(&mut self, token: String) {
+        self.token = token
+    }
+
+    /// 在写入持续进行的同时将数据一致地备份到服务端指定目录
+    ///
+    /// ###Params
+    ///
+    /// * dir 服务端备份目录，须为空目录或不存在
+    /// * compress 是否以`gzip`压缩备份文件
+    pub fn backup(&mut self, dir: String, compress: bool) -> GeorgeResult<ResponseBackup> {
+        let request = Request::new(RequestBackup { dir, compress });
+        match self
+            .rt
+            .block_on(self.client.backup(with_token(request, &self.token)))
+        {
+            Ok(res) => {
+                let resp = res.into_inner();
+                status_check(resp.status, resp.msg_err.clone())?;
+                Ok(resp)
+            }
+            Err(err) => Err(Errs::strs(
+                "failed to successfully run the future on RunTime!",
+                err,
+            )),
+        }
+    }
+}
@@ -0,0 +1,328 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[cfg(unix)]
+use std::path::Path;
+use std::sync::Arc;
+
+use tonic::Request;
+
+use george_comm::errors::{Errs, GeorgeResult};
+use george_db::task::raft::{
+    AppendRequest, AppendResponse, InstallRequest, InstallResponse, RaftCommand, VoteRequest,
+    VoteResponse,
+};
+use george_db::task::Secret;
+
+use crate::client::db::ClusterRpcClient;
+#[cfg(unix)]
+use crate::client::Uds;
+use crate::client::{
+    status_check, with_peer, with_token, Notls, Openssl, RequestCond, Rustls, TLSType,
+};
+use crate::client::{RpcClient, TLS};
+use crate::protos::db::db::cluster_service_client::ClusterServiceClient;
+use crate::protos::db::db::{
+    ClusterPeer, RequestClusterAppend, RequestClusterForward, RequestClusterInstall,
+    RequestClusterJoin, RequestClusterLeave, RequestClusterStatus, RequestClusterVote,
+    ResponseClusterStatus,
+};
+
+impl RpcClient for ClusterRpcClient {
+    fn new(remote: &str, port: u16, cond_op: Option<RequestCond>) -> GeorgeResult<Self>
+    where
+        Self: Sized,
+    {
+        let (inner, rt) = Notls::make(remote, port, cond_op)?;
+        Ok(ClusterRpcClient {
+            client: ClusterServiceClient::new(inner),
+            rt,
+            token: "".to_string(),
+            peer: None,
+        })
+    }
+
+    #[cfg(unix)]
+    fn new_uds<P: AsRef<Path>>(path: P, cond_op: Option<RequestCond>) -> GeorgeResult<Self>
+    where
+        Self: Sized,
+    {
+        let (inner, rt) = Uds::make(path, cond_op)?;
+        Ok(ClusterRpcClient {
+            client: ClusterServiceClient::new(inner),
+            rt,
+            token: "".to_string(),
+            peer: None,
+        })
+    }
+
+    fn new_tls_bytes(
+        tls_type: TLSType,
+        remote: &str,
+        port: u16,
+        ca_bytes: Vec<u8>,
+        domain_name: impl Into<String>,
+        cond_op: Option<RequestCond>,
+    ) -> GeorgeResult<Self>
+    where
+        Self: Sized,
+    {
+        let endpoint;
+        match tls_type {
+            TLSType::Rustls => {
+                endpoint = Rustls::new_bytes(remote, port, ca_bytes, domain_name, cond_op)?
+            }
+            TLSType::Openssl => {
+                endpoint = Openssl::new_bytes(remote, port, ca_bytes, domain_name, cond_op)?
+            }
+        }
+        Ok(ClusterRpcClient {
+            client: ClusterServiceClient::new(endpoint.0),
+            rt: endpoint.1,
+            token: "".to_string(),
+            peer: None,
+        })
+    }
+
+    fn new_tls_bytes_check(
+        tls_type: TLSType,
+        remote: &str,
+        port: u16,
+        key_bytes: Vec<u8>,
+        cert_bytes: Vec<u8>,
+        ca_bytes: Vec<u8>,
+        domain_name: impl Into<String>,
+        cond_op: Option<RequestCond>,
+    ) -> GeorgeResult<Self>
+    where
+        Self: Sized,
+    {
+        let endpoint;
+        match tls_type {
+            TLSType::Rustls => {
+                endpoint = Rustls::new_bytes_check(
+                    remote,
+                    port,
+                    key_bytes,
+                    cert_bytes,
+                    ca_bytes,
+                    domain_name,
+                    cond_op,
+                )?
+            }
+            TLSType::Openssl => {
+                endpoint = Openssl::new_bytes_check(
+                    remote,
+                    port,
+                    key_bytes,
+                    cert_bytes,
+                    ca_bytes,
+                    domain_name,
+                    cond_op,
+                )?
+            }
+        }
+        Ok(ClusterRpcClient {
+            client: ClusterServiceClient::new(endpoint.0),
+            rt: endpoint.1,
+            token: "".to_string(),
+            peer: None,
+        })
+    }
+}
+
+impl ClusterRpcClient {
+    /// 设置登录令牌，后续请求都将在请求头中携带该令牌，服务端据此认证操作人
+    pub fn set_token(&mut self, token: String) {
+        self.token = token
+    }
+
+    /// 设置节点间认证密钥，后续请求都将在请求头中携带以其签发的凭证，其它节点据此认证本节点
+    pub fn set_peer(&mut self, peer: Arc<Secret>) {
+        self.peer = Some(peer)
+    }
+
+    /// 向节点请求投票
+    pub fn vote(&mut self, request: VoteRequest) -> GeorgeResult<VoteResponse> {
+        let request = Request::new(RequestClusterVote {
+            term: request.term,
+            candidate: request.candidate,
+            last_index: request.last_index,
+            last_term: request.last_term,
+        });
+        let request = with_peer(with_token(request, &self.token), &self.peer)?;
+        match self.rt.block_on(self.client.vote(request)) {
+            Ok(res) => {
+                let resp = res.into_inner();
+                status_check(resp.status, resp.msg_err)?;
+                Ok(VoteResponse {
+                    term: resp.term,
+                    granted: resp.granted,
+                })
+            }
+            Err(err) => Err(Errs::strs(
+                "failed to successfully run the future on RunTime!",
+                err,
+            )),
+        }
+    }
+
+    /// 向节点复制日志
+    pub fn append(&mut self, request: AppendRequest) -> GeorgeResult<AppendResponse> {
+        let mut entries = vec![];
+        for entry in request.entries.iter() {
+            entries.push(entry.to_vec()?);
+        }
+        let request = Request::new(RequestClusterAppend {
+            term: request.term,
+            leader: request.leader,
+            prev_index: request.prev_index,
+            prev_term: request.prev_term,
+            entries,
+            commit: request.commit,
+        });
+        let request = with_peer(with_token(request, &self.token), &self.peer)?;
+        match self.rt.block_on(self.client.append(request)) {
+            Ok(res) => {
+                let resp = res.into_inner();
+                status_check(resp.status, resp.msg_err)?;
+                Ok(AppendResponse {
+                    term: resp.term,
+                    success: resp.success,
+                    last_index: resp.last_index,
+                })
+            }
+            Err(err) => Err(Errs::strs(
+                "failed to successfully run the future on RunTime!",
+                err,
+            )),
+        }
+    }
+
+    /// 将条目内容转发至领导者追加，返回条目索引及任期
+    pub fn forward(&mut self, command: &RaftCommand) -> GeorgeResult<(u64, u64)> {
+        let request = Request::new(RequestClusterForward {
+            command: command.to_vec()?,
+        });
+        let request = with_peer(with_token(request, &self.token), &self.peer)?;
+        match self.rt.block_on(self.client.forward(request)) {
+            Ok(res) => {
+                let resp = res.into_inner();
+                status_check(resp.status, resp.msg_err)?;
+                Ok((resp.index, resp.term))
+            }
+            Err(err) => Err(Errs::strs(
+                "failed to successfully run the future on RunTime!",
+                err,
+            )),
+        }
+    }
+
+    /// 向节点发送快照分块
+    pub fn install(&mut self, request: InstallRequest) -> GeorgeResult<InstallResponse> {
+        let request = Request::new(RequestClusterInstall {
+            term: request.term,
+            leader: request.leader,
+            index: request.snapshot.index,
+            last_term: request.snapshot.term,
+            members: request
+                .snapshot
+                .members
+                .into_iter()
+                .map(|peer| ClusterPeer {
+                    id: peer.id,
+                    addr: peer.addr,
+                })
+                .collect(),
+            path: request.path,
+            data: request.data,
+            first: request.first,
+            done: request.done,
+        });
+        let request = with_peer(with_token(request, &self.token), &self.peer)?;
+        match self.rt.block_on(self.client.install(request)) {
+            Ok(res) => {
+                let resp = res.into_inner();
+                status_check(resp.status, resp.msg_err)?;
+                Ok(InstallResponse { term: resp.term })
+            }
+            Err(err) => Err(Errs::strs(
+                "failed to successfully run the future on RunTime!",
+                err,
+            )),
+        }
+    }
+
+    /// 集群状态
+    pub fn status(&mut self) -> GeorgeResult<ResponseClusterStatus> {
+        let request = Request::new(RequestClusterStatus {});
+        match self
+            .rt
+            .block_on(self.client.status(with_token(request, &self.token)))
+        {
+            Ok(res) => {
+                let resp = res.into_inner();
+                status_check(resp.status, resp.msg_err.clone())?;
+                Ok(resp)
+            }
+            Err(err) => Err(Errs::strs(
+                "failed to successfully run the future on RunTime!",
+                err,
+            )),
+        }
+    }
+
+    /// 新增节点，新节点须以加入已运行集群的身份启动
+    ///
+    /// ###Params
+    ///
+    /// * id 新节点编号
+    /// * addr 新节点服务地址，格式为`host:port`
+    pub fn join(&mut self, id: u64, addr: String) -> GeorgeResult<()> {
+        let request = Request::new(RequestClusterJoin {
+            peer: Some(ClusterPeer { id, addr }),
+        });
+        match self
+            .rt
+            .block_on(self.client.join(with_token(request, &self.token)))
+        {
+            Ok(res) => {
+                let resp = res.into_inner();
+                status_check(resp.status, resp.msg_err)
+            }
+            Err(err) => Err(Errs::strs(
+                "failed to successfully run the future on RunTime!",
+                err,
+            )),
+        }
+    }
+
+    /// 移除节点
+    pub fn leave(&mut self, id: u64) -> GeorgeResult<()> {
+        let request = Request::new(RequestClusterLeave { id });
+        match self
+            .rt
+            .block_on(self.client.leave(with_token(request, &self.token)))
+        {
+            Ok(res) => {
+                let resp = res.into_inner();
+                status_check(resp.status, resp.msg_err)
+            }
+            Err(err) => Err(Errs::strs(
+                "failed to successfully run the future on RunTime!",
+                err,
+            )),
+        }
+    }
+}
@@ -12,12 +12,17 @@
  * limitations under the License.
  */
 
+#[cfg(unix)]
+use std::path::Path;
+
 use tonic::Request;
 
 use george_comm::errors::{Errs, GeorgeResult};
 
 use crate::client::db::DatabaseRpcClient;
-use crate::client::{status_check, Notls, Openssl, RequestCond, Rustls, TLSType};
+#[cfg(unix)]
+use crate::client::Uds;
+use crate::client::{status_check, with_token, Notls, Openssl, RequestCond, Rustls, TLSType};
 use crate::client::{RpcClient, TLS};
 use crate::protos::db::db::database_service_client::DatabaseServiceClient;
 use crate::protos::db::db::{
@@ -35,6 +40,20 @@ impl RpcClient for DatabaseRpcClient {
         Ok(DatabaseRpcClient {
             client: DatabaseServiceClient::new(inner),
             rt,
+            token: "".to_string(),
+        })
+    }
+
+    #[cfg(unix)]
+    fn new_uds<P: AsRef<Path>>(path: P, cond_op: Option<RequestCond>) -> GeorgeResult<Self>
+    where
+        Self: Sized,
+    {
+        let (inner, rt) = Uds::make(path, cond_op)?;
+        Ok(DatabaseRpcClient {
+            client: DatabaseServiceClient::new(inner),
+            rt,
+            token: "".to_string(),
         })
     }
 
@@ -61,6 +80,7 @@ impl RpcClient for DatabaseRpcClient {
         Ok(DatabaseRpcClient {
             client: DatabaseServiceClient::new(endpoint.0),
             rt: endpoint.1,
+            token: "".to_string(),
         })
     }
 
@@ -105,6 +125,7 @@ impl RpcClient for DatabaseRpcClient {
         Ok(DatabaseRpcClient {
             client: DatabaseServiceClient::new(endpoint.0),
             rt: endpoint.1,
+            token: "".to_string(),
         })
     }
 
@@ -123,9 +144,17 @@ impl RpcClient for DatabaseRpcClient {
 }
 
 impl DatabaseRpcClient {
+    /// 设置登录令牌，后续请求都将在请求头中携带该令牌，服务端据此认证操作人
+    pub fn set_token(&mut self, token: String) {
+        self.token = token
+    }
+
     pub fn list(&mut self) -> GeorgeResult<Vec<Database>> {
         let request = Request::new(Req {});
-        match self.rt.block_on(self.client.list(request)) {
+        match self
+            .rt
+            .block_on(self.client.list(with_token(request, &self.token)))
+        {
             Ok(res) => {
                 let resp = res.into_inner();
                 status_check(resp.status, resp.msg_err)?;
@@ -140,7 +169,10 @@ impl DatabaseRpcClient {
 
     pub fn create(&mut self, name: String, comment: String) -> GeorgeResult<()> {
         let request = Request::new(RequestDatabaseCreate { name, comment });
-        match self.rt.block_on(self.client.create(request)) {
+        match self
+            .rt
+            .block_on(self.client.create(with_token(request, &self.token)))
+        {
             Ok(res) => {
                 let resp = res.into_inner();
                 status_check(resp.status, resp.msg_err)
@@ -154,7 +186,10 @@ impl DatabaseRpcClient {
 
     pub fn info(&mut self, name: String) -> GeorgeResult<Database> {
         let request = Request::new(RequestDatabaseInfo { name });
-        match self.rt.block_on(self.client.info(request)) {
+        match self
+            .rt
+            .block_on(self.client.info(with_token(request, &self.token)))
+        {
             Ok(res) => {
                 let resp = res.into_inner();
                 status_check(resp.status, resp.msg_err)?;
@@ -181,7 +216,10 @@ impl DatabaseRpcClient {
             name_new,
             comment: comment_new,
         });
-        match self.rt.block_on(self.client.modify(request)) {
+        match self
+            .rt
+            .block_on(self.client.modify(with_token(request, &self.token)))
+        {
             Ok(res) => {
                 let resp = res.into_inner();
                 status_check(resp.status, resp.msg_err)
@@ -195,7 +233,10 @@ impl DatabaseRpcClient {
 
     pub fn remove(&mut self, name: String) -> GeorgeResult<()> {
         let request = Request::new(RequestDatabaseRemove { name });
-        match self.rt.block_on(self.client.remove(request)) {
+        match self
+            .rt
+            .block_on(self.client.remove(with_token(request, &self.token)))
+        {
             Ok(res) => {
                 let resp = res.into_inner();
                 status_check(resp.status, resp.msg_err)
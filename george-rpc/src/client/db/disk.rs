@@ -12,17 +12,31 @@
  * limitations under the License.
  */
 
-use tonic::Request;
+#[cfg(unix)]
+use std::collections::VecDeque;
+use std::path::Path;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_core::Stream;
+
+use tonic::{Request, Streaming};
 
 use george_comm::errors::{Errs, GeorgeResult};
+use george_db::task::batch::BatchOp;
 
-use crate::client::db::DiskRpcClient;
-use crate::client::{status_check, Notls, Openssl, RequestCond, Rustls, TLSType};
+use crate::client::db::{DiskRpcClient, DiskSelectStream};
+#[cfg(unix)]
+use crate::client::Uds;
+use crate::client::{
+    batch_stream, status_check, with_token, Notls, Openssl, RequestCond, Rustls, TLSType,
+};
 use crate::client::{RpcClient, TLS};
 use crate::protos::db::db::disk_service_client::DiskServiceClient;
 use crate::protos::db::db::{
-    DiskDeleted, DiskSelected, RequestDiskDelete, RequestDiskIOut, RequestDiskInto, RequestDiskOut,
-    RequestDiskRemove, RequestDiskSelect,
+    DiskDeleted, DiskSelected, RequestDiskBatch, RequestDiskDelete, RequestDiskIOut,
+    RequestDiskInto, RequestDiskOut, RequestDiskRemove, RequestDiskSelect, ResponseBatch,
+    ResponseDiskSelectStream,
 };
 
 impl RpcClient for DiskRpcClient {
@@ -34,6 +48,20 @@ impl RpcClient for DiskRpcClient {
         Ok(DiskRpcClient {
             client: DiskServiceClient::new(inner),
             rt,
+            token: "".to_string(),
+        })
+    }
+
+    #[cfg(unix)]
+    fn new_uds<P: AsRef<Path>>(path: P, cond_op: Option<RequestCond>) -> GeorgeResult<Self>
+    where
+        Self: Sized,
+    {
+        let (inner, rt) = Uds::make(path, cond_op)?;
+        Ok(DiskRpcClient {
+            client: DiskServiceClient::new(inner),
+            rt,
+            token: "".to_string(),
         })
     }
 
@@ -60,6 +88,7 @@ impl RpcClient for DiskRpcClient {
         Ok(DiskRpcClient {
             client: DiskServiceClient::new(endpoint.0),
             rt: endpoint.1,
+            token: "".to_string(),
         })
     }
 
@@ -104,11 +133,17 @@ impl RpcClient for DiskRpcClient {
         Ok(DiskRpcClient {
             client: DiskServiceClient::new(endpoint.0),
             rt: endpoint.1,
+            token: "".to_string(),
         })
     }
 }
 
 impl DiskRpcClient {
+    /// 设置登录令牌，后续请求都将在请求头中携带该令牌，服务端据此认证操作人
+    pub fn set_token(&mut self, token: String) {
+        self.token = token
+    }
+
     pub fn put(
         &mut self,
         database_name: String,
@@ -122,7 +157,10 @@ impl DiskRpcClient {
             key,
             value,
         });
-        match self.rt.block_on(self.client.put(request)) {
+        match self
+            .rt
+            .block_on(self.client.put(with_token(request, &self.token)))
+        {
             Ok(res) => {
                 let resp = res.into_inner();
                 status_check(resp.status, resp.msg_err)
@@ -147,7 +185,10 @@ impl DiskRpcClient {
             key,
             value,
         });
-        match self.rt.block_on(self.client.set(request)) {
+        match self
+            .rt
+            .block_on(self.client.set(with_token(request, &self.token)))
+        {
             Ok(res) => {
                 let resp = res.into_inner();
                 status_check(resp.status, resp.msg_err)
@@ -170,7 +211,10 @@ impl DiskRpcClient {
             view_name,
             key,
         });
-        match self.rt.block_on(self.client.get(request)) {
+        match self
+            .rt
+            .block_on(self.client.get(with_token(request, &self.token)))
+        {
             Ok(res) => {
                 let resp = res.into_inner();
                 status_check(resp.status, resp.msg_err)?;
@@ -196,7 +240,10 @@ impl DiskRpcClient {
             index_name,
             key,
         });
-        match self.rt.block_on(self.client.get_by_index(request)) {
+        match self
+            .rt
+            .block_on(self.client.get_by_index(with_token(request, &self.token)))
+        {
             Ok(res) => {
                 let resp = res.into_inner();
                 status_check(resp.status, resp.msg_err)?;
@@ -220,7 +267,10 @@ impl DiskRpcClient {
             view_name,
             key,
         });
-        match self.rt.block_on(self.client.remove(request)) {
+        match self
+            .rt
+            .block_on(self.client.remove(with_token(request, &self.token)))
+        {
             Ok(res) => {
                 let resp = res.into_inner();
                 status_check(resp.status, resp.msg_err)
@@ -243,7 +293,10 @@ impl DiskRpcClient {
             view_name,
             constraint_json_bytes,
         });
-        match self.rt.block_on(self.client.select(request)) {
+        match self
+            .rt
+            .block_on(self.client.select(with_token(request, &self.token)))
+        {
             Ok(res) => {
                 let resp = res.into_inner();
                 status_check(resp.status, resp.msg_err)?;
@@ -259,6 +312,32 @@ impl DiskRpcClient {
         }
     }
 
+    /// 条件检索，检索结果在服务端检索过程中分批返回，无需全部载入内存
+    ///
+    /// 客户端读取慢于检索时服务端检索随之阻塞
+    pub fn select_stream(
+        &mut self,
+        database_name: String,
+        view_name: String,
+        constraint_json_bytes: Vec<u8>,
+    ) -> GeorgeResult<DiskSelectStream> {
+        let request = Request::new(RequestDiskSelect {
+            database_name,
+            view_name,
+            constraint_json_bytes,
+        });
+        match self
+            .rt
+            .block_on(self.client.select_stream(with_token(request, &self.token)))
+        {
+            Ok(res) => Ok(DiskSelectStream::new(res.into_inner())),
+            Err(err) => Err(Errs::strs(
+                "failed to successfully run the future on RunTime!",
+                err,
+            )),
+        }
+    }
+
     pub fn delete(
         &mut self,
         database_name: String,
@@ -270,7 +349,10 @@ impl DiskRpcClient {
             view_name,
             constraint_json_bytes,
         });
-        match self.rt.block_on(self.client.delete(request)) {
+        match self
+            .rt
+            .block_on(self.client.delete(with_token(request, &self.token)))
+        {
             Ok(res) => {
                 let resp = res.into_inner();
                 status_check(resp.status, resp.msg_err)?;
@@ -285,4 +367,126 @@ impl DiskRpcClient {
             )),
         }
     }
+
+    /// 批量插入数据，已存在的键写入失败
+    ///
+    /// 数据项由独立线程逐项读取并按批发送，读取失败时中断本次批量写入。atomic为true时任一数据项失败，
+    /// 服务端将回滚已写入的数据项
+    pub fn put_batch<I>(
+        &mut self,
+        database_name: String,
+        view_name: String,
+        atomic: bool,
+        items: I,
+    ) -> GeorgeResult<ResponseBatch>
+    where
+        I: IntoIterator<Item = GeorgeResult<(String, Vec<u8>)>>,
+        I::IntoIter: Send + 'static,
+    {
+        self.batch(BatchOp::Put, database_name, view_name, atomic, items)
+    }
+
+    /// 批量插入数据，已存在的键将被覆盖
+    pub fn set_batch<I>(
+        &mut self,
+        database_name: String,
+        view_name: String,
+        atomic: bool,
+        items: I,
+    ) -> GeorgeResult<ResponseBatch>
+    where
+        I: IntoIterator<Item = GeorgeResult<(String, Vec<u8>)>>,
+        I::IntoIter: Send + 'static,
+    {
+        self.batch(BatchOp::Set, database_name, view_name, atomic, items)
+    }
+
+    /// 批量删除数据，数据项中的值将被忽略
+    pub fn remove_batch<I>(
+        &mut self,
+        database_name: String,
+        view_name: String,
+        atomic: bool,
+        items: I,
+    ) -> GeorgeResult<ResponseBatch>
+    where
+        I: IntoIterator<Item = GeorgeResult<(String, Vec<u8>)>>,
+        I::IntoIter: Send + 'static,
+    {
+        self.batch(BatchOp::Remove, database_name, view_name, atomic, items)
+    }
+
+    fn batch<I>(
+        &mut self,
+        op: BatchOp,
+        database_name: String,
+        view_name: String,
+        atomic: bool,
+        items: I,
+    ) -> GeorgeResult<ResponseBatch>
+    where
+        I: IntoIterator<Item = GeorgeResult<(String, Vec<u8>)>>,
+        I::IntoIter: Send + 'static,
+    {
+        let stream = batch_stream(items, move |items| RequestDiskBatch {
+            database_name: database_name.clone(),
+            view_name: view_name.clone(),
+            atomic,
+            items,
+        });
+        let client = &mut self.client;
+        let token = self.token.clone();
+        stream.run(&self.rt, |requests| async move {
+            let request = with_token(Request::new(requests), &token);
+            match op {
+                BatchOp::Put => client.put_batch(request).await,
+                BatchOp::Set => client.set_batch(request).await,
+                BatchOp::Remove => client.remove_batch(request).await,
+            }
+        })
+    }
+}
+
+impl DiskSelectStream {
+    /// 由`select_stream`响应流新建流式条件检索结果
+    pub fn new(stream: Streaming<ResponseDiskSelectStream>) -> Self {
+        DiskSelectStream {
+            stream,
+            values: VecDeque::new(),
+            selected: None,
+        }
+    }
+
+    /// 检索统计，检索结果读取完毕后可用，其中检索结果集合为空
+    pub fn selected(&self) -> Option<DiskSelected> {
+        self.selected.clone()
+    }
+}
+
+impl Stream for DiskSelectStream {
+    type Item = GeorgeResult<Vec<u8>>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            if let Some(value) = self.values.pop_front() {
+                return Poll::Ready(Some(Ok(value)));
+            }
+            match Pin::new(&mut self.stream).poll_next(cx) {
+                Poll::Ready(Some(Ok(resp))) => {
+                    if let Err(err) = status_check(resp.status, resp.msg_err) {
+                        return Poll::Ready(Some(Err(err)));
+                    }
+                    self.values.extend(resp.values);
+                    if resp.selected.is_some() {
+                        self.selected = resp.selected;
+                    }
+                }
+                Poll::Ready(Some(Err(err))) => {
+                    return Poll::Ready(Some(Err(Errs::strs("select stream", err))))
+                }
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
 }
@@ -12,12 +12,17 @@
  * limitations under the License.
  */
 
+#[cfg(unix)]
+use std::path::Path;
+
 use tonic::Request;
 
 use george_comm::errors::{Errs, GeorgeResult};
 
 use crate::client::db::IndexRpcClient;
-use crate::client::{status_check, Notls, Openssl, RequestCond, Rustls, TLSType};
+#[cfg(unix)]
+use crate::client::Uds;
+use crate::client::{status_check, with_token, Notls, Openssl, RequestCond, Rustls, TLSType};
 use crate::client::{RpcClient, TLS};
 use crate::protos::db::db::index_service_client::IndexServiceClient;
 use crate::protos::db::db::{
@@ -33,6 +38,20 @@ impl RpcClient for IndexRpcClient {
         Ok(IndexRpcClient {
             client: IndexServiceClient::new(inner),
             rt,
+            token: "".to_string(),
+        })
+    }
+
+    #[cfg(unix)]
+    fn new_uds<P: AsRef<Path>>(path: P, cond_op: Option<RequestCond>) -> GeorgeResult<Self>
+    where
+        Self: Sized,
+    {
+        let (inner, rt) = Uds::make(path, cond_op)?;
+        Ok(IndexRpcClient {
+            client: IndexServiceClient::new(inner),
+            rt,
+            token: "".to_string(),
         })
     }
 
@@ -59,6 +78,7 @@ impl RpcClient for IndexRpcClient {
         Ok(IndexRpcClient {
             client: IndexServiceClient::new(endpoint.0),
             rt: endpoint.1,
+            token: "".to_string(),
         })
     }
 
@@ -103,17 +123,26 @@ impl RpcClient for IndexRpcClient {
         Ok(IndexRpcClient {
             client: IndexServiceClient::new(endpoint.0),
             rt: endpoint.1,
+            token: "".to_string(),
         })
     }
 }
 
 impl IndexRpcClient {
+    /// 设置登录令牌，后续请求都将在请求头中携带该令牌，服务端据此认证操作人
+    pub fn set_token(&mut self, token: String) {
+        self.token = token
+    }
+
     pub fn list(&mut self, database_name: String, view_name: String) -> GeorgeResult<Vec<Index>> {
         let request = Request::new(RequestIndexList {
             database_name,
             view_name,
         });
-        match self.rt.block_on(self.client.list(request)) {
+        match self
+            .rt
+            .block_on(self.client.list(with_token(request, &self.token)))
+        {
             Ok(res) => {
                 let resp = res.into_inner();
                 status_check(resp.status, resp.msg_err)?;
@@ -147,7 +176,10 @@ impl IndexRpcClient {
             null,
             key_type: key_type as i32,
         });
-        match self.rt.block_on(self.client.create(request)) {
+        match self
+            .rt
+            .block_on(self.client.create(with_token(request, &self.token)))
+        {
             Ok(res) => {
                 let resp = res.into_inner();
                 status_check(resp.status, resp.msg_err)
@@ -170,7 +202,10 @@ impl IndexRpcClient {
             view_name,
             name,
         });
-        match self.rt.block_on(self.client.info(request)) {
+        match self
+            .rt
+            .block_on(self.client.info(with_token(request, &self.token)))
+        {
             Ok(res) => {
                 let resp = res.into_inner();
                 status_check(resp.status, resp.msg_err)?;
@@ -0,0 +1,305 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[cfg(unix)]
+use std::path::Path;
+
+use tonic::Request;
+
+use george_comm::errors::{Errs, GeorgeResult};
+
+use crate::client::db::LedgerRpcClient;
+#[cfg(unix)]
+use crate::client::Uds;
+use crate::client::{status_check, with_token, Notls, Openssl, RequestCond, Rustls, TLSType};
+use crate::client::{RpcClient, TLS};
+use crate::protos::db::db::ledger_service_client::LedgerServiceClient;
+use crate::protos::db::db::request_block_get::Get;
+use crate::protos::db::db::{
+    Ledger, LedgerBlock, LedgerTransaction, RequestBlockGet, RequestBlockInsert,
+    RequestLedgerCreate, RequestLedgerInfo, RequestLedgerRemove, RequestTransactionGet,
+};
+use crate::protos::utils::utils::Req;
+
+impl RpcClient for LedgerRpcClient {
+    fn new(remote: &str, port: u16, cond_op: Option<RequestCond>) -> GeorgeResult<Self>
+    where
+        Self: Sized,
+    {
+        let (inner, rt) = Notls::make(remote, port, cond_op)?;
+        Ok(LedgerRpcClient {
+            client: LedgerServiceClient::new(inner),
+            rt,
+            token: "".to_string(),
+        })
+    }
+
+    #[cfg(unix)]
+    fn new_uds<P: AsRef<Path>>(path: P, cond_op: Option<RequestCond>) -> GeorgeResult<Self>
+    where
+        Self: Sized,
+    {
+        let (inner, rt) = Uds::make(path, cond_op)?;
+        Ok(LedgerRpcClient {
+            client: LedgerServiceClient::new(inner),
+            rt,
+            token: "".to_string(),
+        })
+    }
+
+    fn new_tls_bytes(
+        tls_type: TLSType,
+        remote: &str,
+        port: u16,
+        ca_bytes: Vec<u8>,
+        domain_name: impl Into<String>,
+        cond_op: Option<RequestCond>,
+    ) -> GeorgeResult<Self>
+    where
+        Self: Sized,
+    {
+        let endpoint;
+        match tls_type {
+            TLSType::Rustls => {
+                endpoint = Rustls::new_bytes(remote, port, ca_bytes, domain_name, cond_op)?
+            }
+            TLSType::Openssl => {
+                endpoint = Openssl::new_bytes(remote, port, ca_bytes, domain_name, cond_op)?
+            }
+        }
+        Ok(LedgerRpcClient {
+            client: LedgerServiceClient::new(endpoint.0),
+            rt: endpoint.1,
+            token: "".to_string(),
+        })
+    }
+
+    fn new_tls_bytes_check(
+        tls_type: TLSType,
+        remote: &str,
+        port: u16,
+        key_bytes: Vec<u8>,
+        cert_bytes: Vec<u8>,
+        ca_bytes: Vec<u8>,
+        domain_name: impl Into<String>,
+        cond_op: Option<RequestCond>,
+    ) -> GeorgeResult<Self>
+    where
+        Self: Sized,
+    {
+        let endpoint;
+        match tls_type {
+            TLSType::Rustls => {
+                endpoint = Rustls::new_bytes_check(
+                    remote,
+                    port,
+                    key_bytes,
+                    cert_bytes,
+                    ca_bytes,
+                    domain_name,
+                    cond_op,
+                )?
+            }
+            TLSType::Openssl => {
+                endpoint = Openssl::new_bytes_check(
+                    remote,
+                    port,
+                    key_bytes,
+                    cert_bytes,
+                    ca_bytes,
+                    domain_name,
+                    cond_op,
+                )?
+            }
+        }
+        Ok(LedgerRpcClient {
+            client: LedgerServiceClient::new(endpoint.0),
+            rt: endpoint.1,
+            token: "".to_string(),
+        })
+    }
+}
+
+impl LedgerRpcClient {
+    /// 设置登录令牌，后续请求都将在请求头中携带该令牌，服务端据此认证操作人
+    pub fn set_token(&mut self, token: String) {
+        self.token = token
+    }
+
+    pub fn list(&mut self) -> GeorgeResult<Vec<Ledger>> {
+        let request = Request::new(Req {});
+        match self
+            .rt
+            .block_on(self.client.list(with_token(request, &self.token)))
+        {
+            Ok(res) => {
+                let resp = res.into_inner();
+                status_check(resp.status, resp.msg_err)?;
+                Ok(resp.ledgers)
+            }
+            Err(err) => Err(Errs::strs(
+                "failed to successfully run the future on RunTime!",
+                err,
+            )),
+        }
+    }
+
+    pub fn create(&mut self, name: String, comment: String) -> GeorgeResult<()> {
+        let request = Request::new(RequestLedgerCreate { name, comment });
+        match self
+            .rt
+            .block_on(self.client.create(with_token(request, &self.token)))
+        {
+            Ok(res) => {
+                let resp = res.into_inner();
+                status_check(resp.status, resp.msg_err)
+            }
+            Err(err) => Err(Errs::strs(
+                "failed to successfully run the future on RunTime!",
+                err,
+            )),
+        }
+    }
+
+    pub fn info(&mut self, name: String) -> GeorgeResult<Ledger> {
+        let request = Request::new(RequestLedgerInfo { name });
+        match self
+            .rt
+            .block_on(self.client.info(with_token(request, &self.token)))
+        {
+            Ok(res) => {
+                let resp = res.into_inner();
+                status_check(resp.status, resp.msg_err)?;
+                match resp.ledger {
+                    Some(res) => Ok(res),
+                    None => Err(Errs::ledger_no_exist_error()),
+                }
+            }
+            Err(err) => Err(Errs::strs(
+                "failed to successfully run the future on RunTime!",
+                err,
+            )),
+        }
+    }
+
+    pub fn remove(&mut self, name: String) -> GeorgeResult<()> {
+        let request = Request::new(RequestLedgerRemove { name });
+        match self
+            .rt
+            .block_on(self.client.remove(with_token(request, &self.token)))
+        {
+            Ok(res) => {
+                let resp = res.into_inner();
+                status_check(resp.status, resp.msg_err)
+            }
+            Err(err) => Err(Errs::strs(
+                "failed to successfully run the future on RunTime!",
+                err,
+            )),
+        }
+    }
+
+    /// 将交易集合打包为新区块并追加至账本，返回新区块
+    pub fn insert(
+        &mut self,
+        ledger_name: String,
+        transactions: Vec<Vec<u8>>,
+    ) -> GeorgeResult<LedgerBlock> {
+        let request = Request::new(RequestBlockInsert {
+            ledger_name,
+            transactions,
+        });
+        match self
+            .rt
+            .block_on(self.client.insert(with_token(request, &self.token)))
+        {
+            Ok(res) => {
+                let resp = res.into_inner();
+                status_check(resp.status, resp.msg_err)?;
+                match resp.block {
+                    Some(res) => Ok(res),
+                    None => Err(Errs::str("block is not exist!")),
+                }
+            }
+            Err(err) => Err(Errs::strs(
+                "failed to successfully run the future on RunTime!",
+                err,
+            )),
+        }
+    }
+
+    /// 根据区块高度获取区块
+    pub fn block(&mut self, ledger_name: String, height: u64) -> GeorgeResult<LedgerBlock> {
+        self.block_get(ledger_name, Get::Height(height))
+    }
+
+    /// 根据区块hash获取区块
+    pub fn block_by_hash(
+        &mut self,
+        ledger_name: String,
+        hash: String,
+    ) -> GeorgeResult<LedgerBlock> {
+        self.block_get(ledger_name, Get::Hash(hash))
+    }
+
+    fn block_get(&mut self, ledger_name: String, get: Get) -> GeorgeResult<LedgerBlock> {
+        let request = Request::new(RequestBlockGet {
+            ledger_name,
+            get: Some(get),
+        });
+        match self
+            .rt
+            .block_on(self.client.block(with_token(request, &self.token)))
+        {
+            Ok(res) => {
+                let resp = res.into_inner();
+                status_check(resp.status, resp.msg_err)?;
+                match resp.block {
+                    Some(res) => Ok(res),
+                    None => Err(Errs::str("block is not exist!")),
+                }
+            }
+            Err(err) => Err(Errs::strs(
+                "failed to successfully run the future on RunTime!",
+                err,
+            )),
+        }
+    }
+
+    /// 根据交易hash获取交易，同时返回所在区块高度及区块hash
+    pub fn transaction(
+        &mut self,
+        ledger_name: String,
+        hash: String,
+    ) -> GeorgeResult<(u64, String, LedgerTransaction)> {
+        let request = Request::new(RequestTransactionGet { ledger_name, hash });
+        match self
+            .rt
+            .block_on(self.client.transaction(with_token(request, &self.token)))
+        {
+            Ok(res) => {
+                let resp = res.into_inner();
+                status_check(resp.status, resp.msg_err)?;
+                match resp.transaction {
+                    Some(res) => Ok((resp.height, resp.block_hash, res)),
+                    None => Err(Errs::str("transaction is not exist!")),
+                }
+            }
+            Err(err) => Err(Errs::strs(
+                "failed to successfully run the future on RunTime!",
+                err,
+            )),
+        }
+    }
+}
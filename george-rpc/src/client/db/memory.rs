@@ -12,17 +12,25 @@
  * limitations under the License.
  */
 
+#[cfg(unix)]
+use std::path::Path;
+
 use tonic::Request;
 
 use george_comm::errors::{Errs, GeorgeResult};
+use george_db::task::batch::BatchOp;
 
 use crate::client::db::MemoryRpcClient;
-use crate::client::{status_check, Notls, Openssl, RequestCond, Rustls, TLSType};
+#[cfg(unix)]
+use crate::client::Uds;
+use crate::client::{
+    batch_stream, status_check, with_token, Notls, Openssl, RequestCond, Rustls, TLSType,
+};
 use crate::client::{RpcClient, TLS};
 use crate::protos::db::db::memory_service_client::MemoryServiceClient;
 use crate::protos::db::db::{
-    RequestMemoryInto, RequestMemoryOut, RequestMemoryPInto, RequestMemoryPOut,
-    RequestMemoryPRemove, RequestMemoryRemove,
+    RequestMemoryBatch, RequestMemoryInto, RequestMemoryOut, RequestMemoryPInto, RequestMemoryPOut,
+    RequestMemoryPRemove, RequestMemoryRemove, ResponseBatch,
 };
 
 impl RpcClient for MemoryRpcClient {
@@ -34,6 +42,20 @@ impl RpcClient for MemoryRpcClient {
         Ok(MemoryRpcClient {
             client: MemoryServiceClient::new(inner),
             rt,
+            token: "".to_string(),
+        })
+    }
+
+    #[cfg(unix)]
+    fn new_uds<P: AsRef<Path>>(path: P, cond_op: Option<RequestCond>) -> GeorgeResult<Self>
+    where
+        Self: Sized,
+    {
+        let (inner, rt) = Uds::make(path, cond_op)?;
+        Ok(MemoryRpcClient {
+            client: MemoryServiceClient::new(inner),
+            rt,
+            token: "".to_string(),
         })
     }
 
@@ -60,6 +82,7 @@ impl RpcClient for MemoryRpcClient {
         Ok(MemoryRpcClient {
             client: MemoryServiceClient::new(endpoint.0),
             rt: endpoint.1,
+            token: "".to_string(),
         })
     }
 
@@ -104,14 +127,23 @@ impl RpcClient for MemoryRpcClient {
         Ok(MemoryRpcClient {
             client: MemoryServiceClient::new(endpoint.0),
             rt: endpoint.1,
+            token: "".to_string(),
         })
     }
 }
 
 impl MemoryRpcClient {
+    /// 设置登录令牌，后续请求都将在请求头中携带该令牌，服务端据此认证操作人
+    pub fn set_token(&mut self, token: String) {
+        self.token = token
+    }
+
     pub fn put(&mut self, key: String, value: Vec<u8>) -> GeorgeResult<()> {
         let request = Request::new(RequestMemoryInto { key, value });
-        match self.rt.block_on(self.client.put(request)) {
+        match self
+            .rt
+            .block_on(self.client.put(with_token(request, &self.token)))
+        {
             Ok(res) => {
                 let resp = res.into_inner();
                 status_check(resp.status, resp.msg_err)
@@ -125,7 +157,10 @@ impl MemoryRpcClient {
 
     pub fn set(&mut self, key: String, value: Vec<u8>) -> GeorgeResult<()> {
         let request = Request::new(RequestMemoryInto { key, value });
-        match self.rt.block_on(self.client.set(request)) {
+        match self
+            .rt
+            .block_on(self.client.set(with_token(request, &self.token)))
+        {
             Ok(res) => {
                 let resp = res.into_inner();
                 status_check(resp.status, resp.msg_err)
@@ -139,7 +174,10 @@ impl MemoryRpcClient {
 
     pub fn get(&mut self, key: String) -> GeorgeResult<Vec<u8>> {
         let request = Request::new(RequestMemoryOut { key });
-        match self.rt.block_on(self.client.get(request)) {
+        match self
+            .rt
+            .block_on(self.client.get(with_token(request, &self.token)))
+        {
             Ok(res) => {
                 let resp = res.into_inner();
                 status_check(resp.status, resp.msg_err)?;
@@ -154,7 +192,10 @@ impl MemoryRpcClient {
 
     pub fn remove(&mut self, key: String) -> GeorgeResult<()> {
         let request = Request::new(RequestMemoryRemove { key });
-        match self.rt.block_on(self.client.remove(request)) {
+        match self
+            .rt
+            .block_on(self.client.remove(with_token(request, &self.token)))
+        {
             Ok(res) => {
                 let resp = res.into_inner();
                 status_check(resp.status, resp.msg_err)
@@ -177,7 +218,10 @@ impl MemoryRpcClient {
             key,
             value,
         });
-        match self.rt.block_on(self.client.put_by_page(request)) {
+        match self
+            .rt
+            .block_on(self.client.put_by_page(with_token(request, &self.token)))
+        {
             Ok(res) => {
                 let resp = res.into_inner();
                 status_check(resp.status, resp.msg_err)
@@ -200,7 +244,10 @@ impl MemoryRpcClient {
             key,
             value,
         });
-        match self.rt.block_on(self.client.set_by_page(request)) {
+        match self
+            .rt
+            .block_on(self.client.set_by_page(with_token(request, &self.token)))
+        {
             Ok(res) => {
                 let resp = res.into_inner();
                 status_check(resp.status, resp.msg_err)
@@ -214,7 +261,10 @@ impl MemoryRpcClient {
 
     pub fn fetch_by_page(&mut self, page_name: String, key: String) -> GeorgeResult<Vec<u8>> {
         let request = Request::new(RequestMemoryPOut { page_name, key });
-        match self.rt.block_on(self.client.get_by_page(request)) {
+        match self
+            .rt
+            .block_on(self.client.get_by_page(with_token(request, &self.token)))
+        {
             Ok(res) => {
                 let resp = res.into_inner();
                 status_check(resp.status, resp.msg_err)?;
@@ -229,7 +279,10 @@ impl MemoryRpcClient {
 
     pub fn remove_by_page(&mut self, page_name: String, key: String) -> GeorgeResult<()> {
         let request = Request::new(RequestMemoryPRemove { page_name, key });
-        match self.rt.block_on(self.client.remove_by_page(request)) {
+        match self
+            .rt
+            .block_on(self.client.remove_by_page(with_token(request, &self.token)))
+        {
             Ok(res) => {
                 let resp = res.into_inner();
                 status_check(resp.status, resp.msg_err)
@@ -240,4 +293,77 @@ impl MemoryRpcClient {
             )),
         }
     }
+
+    /// 批量插入数据，已存在的键写入失败
+    ///
+    /// 数据项由独立线程逐项读取并按批发送，读取失败时中断本次批量写入。atomic为true时任一数据项失败，
+    /// 服务端将回滚已写入的数据项
+    pub fn put_batch<I>(
+        &mut self,
+        page_name: String,
+        atomic: bool,
+        items: I,
+    ) -> GeorgeResult<ResponseBatch>
+    where
+        I: IntoIterator<Item = GeorgeResult<(String, Vec<u8>)>>,
+        I::IntoIter: Send + 'static,
+    {
+        self.batch(BatchOp::Put, page_name, atomic, items)
+    }
+
+    /// 批量插入数据，已存在的键将被覆盖
+    pub fn set_batch<I>(
+        &mut self,
+        page_name: String,
+        atomic: bool,
+        items: I,
+    ) -> GeorgeResult<ResponseBatch>
+    where
+        I: IntoIterator<Item = GeorgeResult<(String, Vec<u8>)>>,
+        I::IntoIter: Send + 'static,
+    {
+        self.batch(BatchOp::Set, page_name, atomic, items)
+    }
+
+    /// 批量删除数据，数据项中的值将被忽略
+    pub fn remove_batch<I>(
+        &mut self,
+        page_name: String,
+        atomic: bool,
+        items: I,
+    ) -> GeorgeResult<ResponseBatch>
+    where
+        I: IntoIterator<Item = GeorgeResult<(String, Vec<u8>)>>,
+        I::IntoIter: Send + 'static,
+    {
+        self.batch(BatchOp::Remove, page_name, atomic, items)
+    }
+
+    fn batch<I>(
+        &mut self,
+        op: BatchOp,
+        page_name: String,
+        atomic: bool,
+        items: I,
+    ) -> GeorgeResult<ResponseBatch>
+    where
+        I: IntoIterator<Item = GeorgeResult<(String, Vec<u8>)>>,
+        I::IntoIter: Send + 'static,
+    {
+        let stream = batch_stream(items, move |items| RequestMemoryBatch {
+            page_name: page_name.clone(),
+            atomic,
+            items,
+        });
+        let client = &mut self.client;
+        let token = self.token.clone();
+        stream.run(&self.rt, |requests| async move {
+            let request = with_token(Request::new(requests), &token);
+            match op {
+                BatchOp::Put => client.put_batch(request).await,
+                BatchOp::Set => client.set_batch(request).await,
+                BatchOp::Remove => client.remove_batch(request).await,
+            }
+        })
+    }
 }
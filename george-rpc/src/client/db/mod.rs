@@ -12,58 +12,157 @@
  * limitations under the License.
  */
 
+use std::collections::VecDeque;
+use std::sync::Arc;
+
 use tokio::runtime::Runtime;
 use tonic::transport::Channel;
+use tonic::Streaming;
+
+use george_db::task::Secret;
 
+use crate::protos::db::db::audit_service_client::AuditServiceClient;
+use crate::protos::db::db::backup_service_client::BackupServiceClient;
+use crate::protos::db::db::cluster_service_client::ClusterServiceClient;
 use crate::protos::db::db::database_service_client::DatabaseServiceClient;
 use crate::protos::db::db::disk_service_client::DiskServiceClient;
 use crate::protos::db::db::index_service_client::IndexServiceClient;
+use crate::protos::db::db::ledger_service_client::LedgerServiceClient;
 use crate::protos::db::db::memory_service_client::MemoryServiceClient;
 use crate::protos::db::db::page_service_client::PageServiceClient;
+use crate::protos::db::db::quota_service_client::QuotaServiceClient;
+use crate::protos::db::db::replication_service_client::ReplicationServiceClient;
+use crate::protos::db::db::transfer_service_client::TransferServiceClient;
 use crate::protos::db::db::user_service_client::UserServiceClient;
 use crate::protos::db::db::view_service_client::ViewServiceClient;
+use crate::protos::db::db::{DiskSelected, ResponseDiskSelectStream};
 
+mod audit;
+mod backup;
+mod cluster;
 mod database;
 mod database_test;
 mod disk;
 mod index;
+mod ledger;
 mod memory;
 mod page;
 mod page_test;
+mod quota;
+mod replication;
+mod transfer;
 mod user;
 mod view;
 
 pub struct DatabaseRpcClient {
     client: DatabaseServiceClient<Channel>,
     rt: Runtime,
+    /// 登录令牌
+    token: String,
 }
 
 pub struct PageRpcClient {
     client: PageServiceClient<Channel>,
     rt: Runtime,
+    /// 登录令牌
+    token: String,
 }
 
 pub struct ViewRpcClient {
     client: ViewServiceClient<Channel>,
     rt: Runtime,
+    /// 登录令牌
+    token: String,
 }
 
 pub struct IndexRpcClient {
     client: IndexServiceClient<Channel>,
     rt: Runtime,
+    /// 登录令牌
+    token: String,
 }
 
 pub struct DiskRpcClient {
     client: DiskServiceClient<Channel>,
     rt: Runtime,
+    /// 登录令牌
+    token: String,
+}
+
+/// 流式条件检索结果，按检索顺序逐条返回检索结果，检索结束后可获取检索统计
+///
+/// 可在任意异步运行时中读取，丢弃时即断开流式检索，服务端随之终止检索
+pub struct DiskSelectStream {
+    stream: Streaming<ResponseDiskSelectStream>,
+    /// 已接收但尚未返回的检索结果
+    values: VecDeque<Vec<u8>>,
+    /// 检索统计，由服务端在最后一条中返回
+    selected: Option<DiskSelected>,
+}
+
+pub struct LedgerRpcClient {
+    client: LedgerServiceClient<Channel>,
+    rt: Runtime,
+    /// 登录令牌
+    token: String,
 }
 
 pub struct MemoryRpcClient {
     client: MemoryServiceClient<Channel>,
     rt: Runtime,
+    /// 登录令牌
+    token: String,
 }
 
 pub struct UserRpcClient {
     client: UserServiceClient<Channel>,
     rt: Runtime,
+    /// 登录令牌
+    token: String,
+}
+
+pub struct AuditRpcClient {
+    client: AuditServiceClient<Channel>,
+    rt: Runtime,
+    /// 登录令牌
+    token: String,
+}
+
+pub struct QuotaRpcClient {
+    client: QuotaServiceClient<Channel>,
+    rt: Runtime,
+    /// 登录令牌
+    token: String,
+}
+
+pub struct BackupRpcClient {
+    client: BackupServiceClient<Channel>,
+    rt: Runtime,
+    /// 登录令牌
+    token: String,
+}
+
+pub struct TransferRpcClient {
+    client: TransferServiceClient<Channel>,
+    rt: Runtime,
+    /// 登录令牌
+    token: String,
+}
+
+pub struct ReplicationRpcClient {
+    client: ReplicationServiceClient<Channel>,
+    rt: Runtime,
+    /// 登录令牌
+    token: String,
+    /// 节点间认证密钥，从节点跟随主节点及拉取快照时使用
+    peer: Option<Arc<Secret>>,
+}
+
+pub struct ClusterRpcClient {
+    client: ClusterServiceClient<Channel>,
+    rt: Runtime,
+    /// 登录令牌
+    token: String,
+    /// 节点间认证密钥，投票、日志复制、快照安装及写入转发时使用
+    peer: Option<Arc<Secret>>,
 }
@@ -12,12 +12,17 @@
  * limitations under the License.
  */
 
+#[cfg(unix)]
+use std::path::Path;
+
 use tonic::Request;
 
 use george_comm::errors::{Errs, GeorgeResult};
 
 use crate::client::db::PageRpcClient;
-use crate::client::{status_check, Notls, Openssl, RequestCond, Rustls, TLSType};
+#[cfg(unix)]
+use crate::client::Uds;
+use crate::client::{status_check, with_token, Notls, Openssl, RequestCond, Rustls, TLSType};
 use crate::client::{RpcClient, TLS};
 use crate::protos::db::db::page_service_client::PageServiceClient;
 use crate::protos::db::db::{
@@ -34,6 +39,20 @@ impl RpcClient for PageRpcClient {
         Ok(PageRpcClient {
             client: PageServiceClient::new(inner),
             rt,
+            token: "".to_string(),
+        })
+    }
+
+    #[cfg(unix)]
+    fn new_uds<P: AsRef<Path>>(path: P, cond_op: Option<RequestCond>) -> GeorgeResult<Self>
+    where
+        Self: Sized,
+    {
+        let (inner, rt) = Uds::make(path, cond_op)?;
+        Ok(PageRpcClient {
+            client: PageServiceClient::new(inner),
+            rt,
+            token: "".to_string(),
         })
     }
 
@@ -60,6 +79,7 @@ impl RpcClient for PageRpcClient {
         Ok(PageRpcClient {
             client: PageServiceClient::new(endpoint.0),
             rt: endpoint.1,
+            token: "".to_string(),
         })
     }
 
@@ -104,14 +124,23 @@ impl RpcClient for PageRpcClient {
         Ok(PageRpcClient {
             client: PageServiceClient::new(endpoint.0),
             rt: endpoint.1,
+            token: "".to_string(),
         })
     }
 }
 
 impl PageRpcClient {
+    /// 设置登录令牌，后续请求都将在请求头中携带该令牌，服务端据此认证操作人
+    pub fn set_token(&mut self, token: String) {
+        self.token = token
+    }
+
     pub fn list(&mut self) -> GeorgeResult<Vec<Page>> {
         let request = Request::new(Req {});
-        match self.rt.block_on(self.client.list(request)) {
+        match self
+            .rt
+            .block_on(self.client.list(with_token(request, &self.token)))
+        {
             Ok(res) => {
                 let resp = res.into_inner();
                 status_check(resp.status, resp.msg_err)?;
@@ -137,7 +166,10 @@ impl PageRpcClient {
             size,
             period,
         });
-        match self.rt.block_on(self.client.create(request)) {
+        match self
+            .rt
+            .block_on(self.client.create(with_token(request, &self.token)))
+        {
             Ok(res) => {
                 let resp = res.into_inner();
                 status_check(resp.status, resp.msg_err)
@@ -151,7 +183,10 @@ impl PageRpcClient {
 
     pub fn info(&mut self, name: String) -> GeorgeResult<Page> {
         let request = Request::new(RequestPageInfo { name });
-        match self.rt.block_on(self.client.info(request)) {
+        match self
+            .rt
+            .block_on(self.client.info(with_token(request, &self.token)))
+        {
             Ok(res) => {
                 let resp = res.into_inner();
                 status_check(resp.status, resp.msg_err)?;
@@ -169,7 +204,10 @@ impl PageRpcClient {
 
     pub fn modify(&mut self, name: String, name_new: String) -> GeorgeResult<()> {
         let request = Request::new(RequestPageModify { name, name_new });
-        match self.rt.block_on(self.client.modify(request)) {
+        match self
+            .rt
+            .block_on(self.client.modify(with_token(request, &self.token)))
+        {
             Ok(res) => {
                 let resp = res.into_inner();
                 status_check(resp.status, resp.msg_err)
@@ -183,7 +221,10 @@ impl PageRpcClient {
 
     pub fn remove(&mut self, name: String) -> GeorgeResult<()> {
         let request = Request::new(RequestPageRemove { name });
-        match self.rt.block_on(self.client.remove(request)) {
+        match self
+            .rt
+            .block_on(self.client.remove(with_token(request, &self.token)))
+        {
             Ok(res) => {
                 let resp = res.into_inner();
                 status_check(resp.status, resp.msg_err)
@@ -0,0 +1,201 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[cfg(unix)]
+use std::path::Path;
+
+use tonic::Request;
+
+use george_comm::errors::{Errs, GeorgeResult};
+
+use crate::client::db::QuotaRpcClient;
+#[cfg(unix)]
+use crate::client::Uds;
+use crate::client::{status_check, with_token, Notls, Openssl, RequestCond, Rustls, TLSType};
+use crate::client::{RpcClient, TLS};
+use crate::protos::db::db::quota_service_client::QuotaServiceClient;
+use crate::protos::db::db::{QuotaPolicy, RequestQuotaList, RequestQuotaSet};
+
+impl RpcClient for QuotaRpcClient {
+    fn new(remote: &str, port: u16, cond_op: Option<RequestCond>) -> GeorgeResult<Self>
+    where
+        Self: Sized,
+    {
+        let (inner, rt) = Notls::make(remote, port, cond_op)?;
+        Ok(QuotaRpcClient {
+            client: QuotaServiceClient::new(inner),
+            rt,
+            token: "".to_string(),
+        })
+    }
+
+    #[cfg(unix)]
+    fn new_uds<P: AsRef<Path>>(path: P, cond_op: Option<RequestCond>) -> GeorgeResult<Self>
+    where
+        Self: Sized,
+    {
+        let (inner, rt) = Uds::make(path, cond_op)?;
+        Ok(QuotaRpcClient {
+            client: QuotaServiceClient::new(inner),
+            rt,
+            token: "".to_string(),
+        })
+    }
+
+    fn new_tls_bytes(
+        tls_type: TLSType,
+        remote: &str,
+        port: u16,
+        ca_bytes: Vec<u8>,
+        domain_name: impl Into<String>,
+        cond_op: Option<RequestCond>,
+    ) -> GeorgeResult<Self>
+    where
+        Self: Sized,
+    {
+        let endpoint;
+        match tls_type {
+            TLSType::Rustls => {
+                endpoint = Rustls::new_bytes(remote, port, ca_bytes, domain_name, cond_op)?
+            }
+            TLSType::Openssl => {
+                endpoint = Openssl::new_bytes(remote, port, ca_bytes, domain_name, cond_op)?
+            }
+        }
+        Ok(QuotaRpcClient {
+            client: QuotaServiceClient::new(endpoint.0),
+            rt: endpoint.1,
+            token: "".to_string(),
+        })
+    }
+
+    fn new_tls_bytes_check(
+        tls_type: TLSType,
+        remote: &str,
+        port: u16,
+        key_bytes: Vec<u8>,
+        cert_bytes: Vec<u8>,
+        ca_bytes: Vec<u8>,
+        domain_name: impl Into<String>,
+        cond_op: Option<RequestCond>,
+    ) -> GeorgeResult<Self>
+    where
+        Self: Sized,
+    {
+        let endpoint;
+        match tls_type {
+            TLSType::Rustls => {
+                endpoint = Rustls::new_bytes_check(
+                    remote,
+                    port,
+                    key_bytes,
+                    cert_bytes,
+                    ca_bytes,
+                    domain_name,
+                    cond_op,
+                )?
+            }
+            TLSType::Openssl => {
+                endpoint = Openssl::new_bytes_check(
+                    remote,
+                    port,
+                    key_bytes,
+                    cert_bytes,
+                    ca_bytes,
+                    domain_name,
+                    cond_op,
+                )?
+            }
+        }
+        Ok(QuotaRpcClient {
+            client: QuotaServiceClient::new(endpoint.0),
+            rt: endpoint.1,
+            token: "".to_string(),
+        })
+    }
+}
+
+impl QuotaRpcClient {
+    /// 设置登录令牌，后续请求都将在请求头中携带该令牌，服务端据此认证操作人
+    pub fn set_token(&mut self, token: String) {
+        self.token = token
+    }
+
+    /// 设置配额策略，已存在则覆盖，所有限制项都为0则移除策略
+    ///
+    /// ###Params
+    ///
+    /// * scope 作用范围，可选`user`、`database`、`view`及`page`
+    /// * name 作用对象名称，`view`为`database_name/view_name`
+    /// * rate 每秒允许的请求数，仅`user`及`database`可用
+    /// * burst 允许突发的请求数，为0则与`rate`相同
+    /// * max_bytes 最大存储字节数，仅`database`及`view`可用
+    /// * max_keys 最大键数量，仅`page`可用
+    pub fn set(
+        &mut self,
+        scope: String,
+        name: String,
+        rate: u32,
+        burst: u32,
+        max_bytes: u64,
+        max_keys: u64,
+    ) -> GeorgeResult<()> {
+        let request = Request::new(RequestQuotaSet {
+            policy: Some(QuotaPolicy {
+                scope,
+                name,
+                rate,
+                burst,
+                max_bytes,
+                max_keys,
+            }),
+        });
+        match self
+            .rt
+            .block_on(self.client.set(with_token(request, &self.token)))
+        {
+            Ok(res) => {
+                let resp = res.into_inner();
+                status_check(resp.status, resp.msg_err)
+            }
+            Err(err) => Err(Errs::strs(
+                "failed to successfully run the future on RunTime!",
+                err,
+            )),
+        }
+    }
+
+    /// 配额策略集合
+    ///
+    /// ###Params
+    ///
+    /// * scope 作用范围，为空则不限
+    pub fn list(&mut self, scope: String) -> GeorgeResult<Vec<QuotaPolicy>> {
+        let request = Request::new(RequestQuotaList { scope });
+        match self
+            .rt
+            .block_on(self.client.list(with_token(request, &self.token)))
+        {
+            Ok(res) => {
+                let resp = res.into_inner();
+                status_check(resp.status, resp.msg_err)?;
+                Ok(resp.policies)
+            }
+            Err(err) => Err(Errs::strs(
+                "failed to successfully run the future on RunTime!",
+                err,
+            )),
+        }
+    }
+}
@@ -0,0 +1,281 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[cfg(unix)]
+use std::path::Path;
+use std::sync::Arc;
+
+use tonic::Request;
+
+use george_comm::errors::{Errs, GeorgeResult};
+use george_comm::io::file::{FilerHandler, FilerWriter};
+use george_comm::io::Filer;
+use george_db::task::backup::{BackupManifest, BACKUP_MANIFEST};
+use george_db::task::journal::Mutation;
+use george_db::task::{Backup, Secret};
+
+use crate::client::db::ReplicationRpcClient;
+#[cfg(unix)]
+use crate::client::Uds;
+use crate::client::{
+    status_check, with_peer, with_token, Notls, Openssl, RequestCond, Rustls, TLSType,
+};
+use crate::client::{RpcClient, TLS};
+use crate::protos::db::db::replication_service_client::ReplicationServiceClient;
+use crate::protos::db::db::{
+    RequestReplicationFollow, RequestReplicationPromote, RequestReplicationSnapshot,
+    RequestReplicationStatus, ResponseReplicationStatus,
+};
+
+impl RpcClient for ReplicationRpcClient {
+    fn new(remote: &str, port: u16, cond_op: Option<RequestCond>) -> GeorgeResult<Self>
+    where
+        Self: Sized,
+    {
+        let (inner, rt) = Notls::make(remote, port, cond_op)?;
+        Ok(ReplicationRpcClient {
+            client: ReplicationServiceClient::new(inner),
+            rt,
+            token: "".to_string(),
+            peer: None,
+        })
+    }
+
+    #[cfg(unix)]
+    fn new_uds<P: AsRef<Path>>(path: P, cond_op: Option<RequestCond>) -> GeorgeResult<Self>
+    where
+        Self: Sized,
+    {
+        let (inner, rt) = Uds::make(path, cond_op)?;
+        Ok(ReplicationRpcClient {
+            client: ReplicationServiceClient::new(inner),
+            rt,
+            token: "".to_string(),
+            peer: None,
+        })
+    }
+
+    fn new_tls_bytes(
+        tls_type: TLSType,
+        remote: &str,
+        port: u16,
+        ca_bytes: Vec<u8>,
+        domain_name: impl Into<String>,
+        cond_op: Option<RequestCond>,
+    ) -> GeorgeResult<Self>
+    where
+        Self: Sized,
+    {
+        let endpoint;
+        match tls_type {
+            TLSType::Rustls => {
+                endpoint = Rustls::new_bytes(remote, port, ca_bytes, domain_name, cond_op)?
+            }
+            TLSType::Openssl => {
+                endpoint = Openssl::new_bytes(remote, port, ca_bytes, domain_name, cond_op)?
+            }
+        }
+        Ok(ReplicationRpcClient {
+            client: ReplicationServiceClient::new(endpoint.0),
+            rt: endpoint.1,
+            token: "".to_string(),
+            peer: None,
+        })
+    }
+
+    fn new_tls_bytes_check(
+        tls_type: TLSType,
+        remote: &str,
+        port: u16,
+        key_bytes: Vec<u8>,
+        cert_bytes: Vec<u8>,
+        ca_bytes: Vec<u8>,
+        domain_name: impl Into<String>,
+        cond_op: Option<RequestCond>,
+    ) -> GeorgeResult<Self>
+    where
+        Self: Sized,
+    {
+        let endpoint;
+        match tls_type {
+            TLSType::Rustls => {
+                endpoint = Rustls::new_bytes_check(
+                    remote,
+                    port,
+                    key_bytes,
+                    cert_bytes,
+                    ca_bytes,
+                    domain_name,
+                    cond_op,
+                )?
+            }
+            TLSType::Openssl => {
+                endpoint = Openssl::new_bytes_check(
+                    remote,
+                    port,
+                    key_bytes,
+                    cert_bytes,
+                    ca_bytes,
+                    domain_name,
+                    cond_op,
+                )?
+            }
+        }
+        Ok(ReplicationRpcClient {
+            client: ReplicationServiceClient::new(endpoint.0),
+            rt: endpoint.1,
+            token: "".to_string(),
+            peer: None,
+        })
+    }
+}
+
+impl ReplicationRpcClient {
+    /// 设置登录令牌，后续请求都将在请求头中携带该令牌，服务端据此认证操作人
+    pub fn set_token(&mut self, token: String) {
+        self.token = token
+    }
+
+    /// 设置节点间认证密钥，后续请求都将在请求头中携带以其签发的凭证，主节点据此认证从节点
+    pub fn set_peer(&mut self, peer: Arc<Secret>) {
+        self.peer = Some(peer)
+    }
+
+    /// 跟随主节点，持续接收序号`seq`之后的变更，直至连接断开或回调返回错误
+    ///
+    /// ###Params
+    ///
+    /// * seq 已应用的主节点变更日志序号
+    /// * each 变更回调，参数为主节点最新变更日志序号及本次推送的变更，无新变更时变更为空
+    pub fn follow(
+        &mut self,
+        seq: u64,
+        each: &mut dyn FnMut(u64, Vec<Mutation>) -> GeorgeResult<()>,
+    ) -> GeorgeResult<()> {
+        let request = with_peer(
+            with_token(Request::new(RequestReplicationFollow { seq }), &self.token),
+            &self.peer,
+        )?;
+        let client = &mut self.client;
+        self.rt.block_on(async move {
+            let mut stream = match client.follow(request).await {
+                Ok(res) => res.into_inner(),
+                Err(err) => {
+                    return Err(Errs::strs(
+                        "failed to successfully run the future on RunTime!",
+                        err,
+                    ))
+                }
+            };
+            loop {
+                match stream.message().await {
+                    Ok(Some(resp)) => {
+                        status_check(resp.status, resp.msg_err)?;
+                        let mut mutations: Vec<Mutation> = vec![];
+                        for bytes in resp.mutations.iter() {
+                            mutations.push(Mutation::from_slice(bytes.as_slice())?);
+                        }
+                        each(resp.primary_seq, mutations)?;
+                    }
+                    Ok(None) => return Err(Errs::str("follow stream closed by primary!")),
+                    Err(err) => return Err(Errs::strs("follow stream", err)),
+                }
+            }
+        })
+    }
+
+    /// 拉取主节点快照至本地目录，目录结构同备份目录，可直接用于恢复
+    ///
+    /// ###Params
+    ///
+    /// * dir 本地快照目录，须为空目录或不存在
+    pub fn snapshot(&mut self, dir: String) -> GeorgeResult<BackupManifest> {
+        let request = with_peer(
+            with_token(Request::new(RequestReplicationSnapshot {}), &self.token),
+            &self.peer,
+        )?;
+        let client = &mut self.client;
+        self.rt.block_on(async move {
+            let mut stream = match client.snapshot(request).await {
+                Ok(res) => res.into_inner(),
+                Err(err) => {
+                    return Err(Errs::strs(
+                        "failed to successfully run the future on RunTime!",
+                        err,
+                    ))
+                }
+            };
+            loop {
+                match stream.message().await {
+                    Ok(Some(resp)) => {
+                        status_check(resp.status, resp.msg_err)?;
+                        if !resp.manifest.is_empty() {
+                            let manifest = BackupManifest::from_slice(resp.manifest.as_slice())?;
+                            Filer::write_force(
+                                format!("{}/{}", dir, BACKUP_MANIFEST),
+                                resp.manifest,
+                            )?;
+                            return Ok(manifest);
+                        }
+                        // 主节点下发的路径须位于快照目录内，避免写出快照目录
+                        Backup::check_path(&resp.path)?;
+                        let filepath = format!("{}/{}", dir, resp.path);
+                        Filer::try_touch(&filepath)?;
+                        Filer::append(&filepath, resp.data)?;
+                    }
+                    Ok(None) => return Err(Errs::str("snapshot stream closed before manifest!")),
+                    Err(err) => return Err(Errs::strs("snapshot stream", err)),
+                }
+            }
+        })
+    }
+
+    /// 复制状态
+    pub fn status(&mut self) -> GeorgeResult<ResponseReplicationStatus> {
+        let request = Request::new(RequestReplicationStatus {});
+        match self
+            .rt
+            .block_on(self.client.status(with_token(request, &self.token)))
+        {
+            Ok(res) => {
+                let resp = res.into_inner();
+                status_check(resp.status, resp.msg_err.clone())?;
+                Ok(resp)
+            }
+            Err(err) => Err(Errs::strs(
+                "failed to successfully run the future on RunTime!",
+                err,
+            )),
+        }
+    }
+
+    /// 将从节点提升为主节点，返回提升时已应用的主节点变更日志序号
+    pub fn promote(&mut self) -> GeorgeResult<u64> {
+        let request = with_peer(
+            with_token(Request::new(RequestReplicationPromote {}), &self.token),
+            &self.peer,
+        )?;
+        match self.rt.block_on(self.client.promote(request)) {
+            Ok(res) => {
+                let resp = res.into_inner();
+                status_check(resp.status, resp.msg_err)?;
+                Ok(resp.applied)
+            }
+            Err(err) => Err(Errs::strs(
+                "failed to successfully run the future on RunTime!",
+                err,
+            )),
+        }
+    }
+}
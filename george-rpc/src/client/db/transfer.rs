@@ -0,0 +1,280 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::io::BufReader;
+#[cfg(unix)]
+use std::path::Path;
+use std::thread;
+
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::Request;
+
+use george_comm::errors::{Errs, GeorgeResult};
+use george_comm::io::file::{FilerHandler, FilerNormal, FilerWriter};
+use george_comm::io::Filer;
+use george_db::task::transfer::TransferFormat as Format;
+use george_db::task::Transfer;
+
+use crate::client::db::TransferRpcClient;
+#[cfg(unix)]
+use crate::client::Uds;
+use crate::client::{status_check, with_token, Notls, Openssl, RequestCond, Rustls, TLSType};
+use crate::client::{RpcClient, TLS};
+use crate::protos::db::db::transfer_service_client::TransferServiceClient;
+use crate::protos::db::db::{
+    ImportRow, RequestExport, RequestImport, ResponseImport, TransferFormat,
+};
+
+/// 导入时单次请求携带的最大行数
+const IMPORT_BATCH: usize = 500;
+/// 导入时等待发送的最大请求数，服务端处理慢于读取时读取随之阻塞
+const IMPORT_CHANNEL_SIZE: usize = 8;
+
+impl RpcClient for TransferRpcClient {
+    fn new(remote: &str, port: u16, cond_op: Option<RequestCond>) -> GeorgeResult<Self>
+    where
+        Self: Sized,
+    {
+        let (inner, rt) = Notls::make(remote, port, cond_op)?;
+        Ok(TransferRpcClient {
+            client: TransferServiceClient::new(inner),
+            rt,
+            token: "".to_string(),
+        })
+    }
+
+    #[cfg(unix)]
+    fn new_uds<P: AsRef<Path>>(path: P, cond_op: Option<RequestCond>) -> GeorgeResult<Self>
+    where
+        Self: Sized,
+    {
+        let (inner, rt) = Uds::make(path, cond_op)?;
+        Ok(TransferRpcClient {
+            client: TransferServiceClient::new(inner),
+            rt,
+            token: "".to_string(),
+        })
+    }
+
+    fn new_tls_bytes(
+        tls_type: TLSType,
+        remote: &str,
+        port: u16,
+        ca_bytes: Vec<u8>,
+        domain_name: impl Into<String>,
+        cond_op: Option<RequestCond>,
+    ) -> GeorgeResult<Self>
+    where
+        Self: Sized,
+    {
+        let endpoint;
+        match tls_type {
+            TLSType::Rustls => {
+                endpoint = Rustls::new_bytes(remote, port, ca_bytes, domain_name, cond_op)?
+            }
+            TLSType::Openssl => {
+                endpoint = Openssl::new_bytes(remote, port, ca_bytes, domain_name, cond_op)?
+            }
+        }
+        Ok(TransferRpcClient {
+            client: TransferServiceClient::new(endpoint.0),
+            rt: endpoint.1,
+            token: "".to_string(),
+        })
+    }
+
+    fn new_tls_bytes_check(
+        tls_type: TLSType,
+        remote: &str,
+        port: u16,
+        key_bytes: Vec<u8>,
+        cert_bytes: Vec<u8>,
+        ca_bytes: Vec<u8>,
+        domain_name: impl Into<String>,
+        cond_op: Option<RequestCond>,
+    ) -> GeorgeResult<Self>
+    where
+        Self: Sized,
+    {
+        let endpoint;
+        match tls_type {
+            TLSType::Rustls => {
+                endpoint = Rustls::new_bytes_check(
+                    remote,
+                    port,
+                    key_bytes,
+                    cert_bytes,
+                    ca_bytes,
+                    domain_name,
+                    cond_op,
+                )?
+            }
+            TLSType::Openssl => {
+                endpoint = Openssl::new_bytes_check(
+                    remote,
+                    port,
+                    key_bytes,
+                    cert_bytes,
+                    ca_bytes,
+                    domain_name,
+                    cond_op,
+                )?
+            }
+        }
+        Ok(TransferRpcClient {
+            client: TransferServiceClient::new(endpoint.0),
+            rt: endpoint.1,
+            token: "".to_string(),
+        })
+    }
+}
+
+impl TransferRpcClient {
+    /// 设置登录令牌，后续请求都将在请求头中携带该令牌，服务端据此认证操作人
+    pub fn set_token(&mut self, token: String) {
+        self.token = token
+    }
+
+    /// 导出库或视图至本地文件，文件已存在则覆盖，返回写入字节数
+    ///
+    /// 文件后缀为`.csv`时以`Csv`格式导出，否则以`JSON Lines`格式导出
+    ///
+    /// ###Params
+    ///
+    /// * database_name 库名称
+    /// * view_name 视图名称，为空则导出库中所有视图
+    /// * filepath 导出文件路径
+    pub fn export(
+        &mut self,
+        database_name: String,
+        view_name: String,
+        filepath: String,
+    ) -> GeorgeResult<u64> {
+        let request = Request::new(RequestExport {
+            database_name,
+            view_name,
+            format: format(Format::from_filepath(filepath.as_str())),
+        });
+        Filer::rm(&filepath)?;
+        Filer::touch(&filepath)?;
+        let client = &mut self.client;
+        let token = &self.token;
+        self.rt.block_on(async move {
+            let mut stream = match client.export(with_token(request, token)).await {
+                Ok(res) => res.into_inner(),
+                Err(err) => {
+                    return Err(Errs::strs(
+                        "failed to successfully run the future on RunTime!",
+                        err,
+                    ))
+                }
+            };
+            let mut bytes: u64 = 0;
+            loop {
+                match stream.message().await {
+                    Ok(Some(resp)) => {
+                        status_check(resp.status, resp.msg_err)?;
+                        if !resp.data.is_empty() {
+                            bytes += resp.data.len() as u64;
+                            Filer::append(&filepath, resp.data)?;
+                        }
+                    }
+                    Ok(None) => return Ok(bytes),
+                    Err(err) => return Err(Errs::strs("export stream", err)),
+                }
+            }
+        })
+    }
+
+    /// 从本地文件导入至库中，文件格式由文件后缀推断，同`export`
+    ///
+    /// 单行导入失败不中断导入，失败行号及错误信息在返回结果中给出
+    ///
+    /// ###Params
+    ///
+    /// * database_name 库名称
+    /// * view_name 导入目标视图名称，为空则导入至文件中各行所属视图
+    /// * overwrite 数据已存在时是否覆盖，否则记为失败行
+    /// * filepath 导入文件路径
+    pub fn import(
+        &mut self,
+        database_name: String,
+        view_name: String,
+        overwrite: bool,
+        filepath: String,
+    ) -> GeorgeResult<ResponseImport> {
+        let file_format = Format::from_filepath(filepath.as_str());
+        let file = Filer::reader(&filepath)?;
+        let (sender, receiver) = mpsc::channel(IMPORT_CHANNEL_SIZE);
+        let head = RequestImport {
+            database_name,
+            view_name,
+            format: format(file_format),
+            overwrite,
+            rows: vec![],
+        };
+        // 读取文件与发送请求并行，文件无需全部载入内存
+        let reader = thread::spawn(move || -> GeorgeResult<()> {
+            let mut rows: Vec<ImportRow> = vec![];
+            let send = |rows: Vec<ImportRow>| -> GeorgeResult<()> {
+                let mut request = head.clone();
+                request.rows = rows;
+                match sender.blocking_send(request) {
+                    Ok(()) => Ok(()),
+                    Err(_) => Err(Errs::str("import sender closed!")),
+                }
+            };
+            Transfer::split(file_format, BufReader::new(file), &mut |line, text| {
+                rows.push(ImportRow { line, text });
+                if rows.len() >= IMPORT_BATCH {
+                    send(rows.split_off(0))
+                } else {
+                    Ok(())
+                }
+            })?;
+            // 即便文件为空也需发送一次请求，服务端据此确定导入目标
+            send(rows)
+        });
+        let request = Request::new(ReceiverStream::new(receiver));
+        let result = self
+            .rt
+            .block_on(self.client.import(with_token(request, &self.token)));
+        // 服务端导入失败时读取线程随之失败，此时以服务端错误为准
+        let read = match reader.join() {
+            Ok(res) => res,
+            Err(_) => Err(Errs::str("import reader thread panicked!")),
+        };
+        match result {
+            Ok(res) => {
+                let resp = res.into_inner();
+                status_check(resp.status, resp.msg_err.clone())?;
+                read?;
+                Ok(resp)
+            }
+            Err(err) => Err(Errs::strs(
+                "failed to successfully run the future on RunTime!",
+                err,
+            )),
+        }
+    }
+}
+
+/// 导出导入格式转换为请求中的格式
+fn format(format: Format) -> i32 {
+    match format {
+        Format::Jsonl => TransferFormat::Jsonl as i32,
+        Format::Csv => TransferFormat::Csv as i32,
+    }
+}
@@ -12,12 +12,17 @@
  * limitations under the License.
  */
 
+#[cfg(unix)]
+use std::path::Path;
+
 use tonic::Request;
 
 use george_comm::errors::{Errs, GeorgeResult};
 
 use crate::client::db::UserRpcClient;
-use crate::client::{status_check, Notls, Openssl, RequestCond, Rustls, TLSType};
+#[cfg(unix)]
+use crate::client::Uds;
+use crate::client::{status_check, with_token, Notls, Openssl, RequestCond, Rustls, TLSType};
 use crate::client::{RpcClient, TLS};
 use crate::protos::db::db::user_service_client::UserServiceClient;
 use crate::protos::db::db::RequestLogin;
@@ -31,6 +36,20 @@ impl RpcClient for UserRpcClient {
         Ok(UserRpcClient {
             client: UserServiceClient::new(inner),
             rt,
+            token: "".to_string(),
+        })
+    }
+
+    #[cfg(unix)]
+    fn new_uds<P: AsRef<Path>>(path: P, cond_op: Option<RequestCond>) -> GeorgeResult<Self>
+    where
+        Self: Sized,
+    {
+        let (inner, rt) = Uds::make(path, cond_op)?;
+        Ok(UserRpcClient {
+            client: UserServiceClient::new(inner),
+            rt,
+            token: "".to_string(),
         })
     }
 
@@ -57,6 +76,7 @@ impl RpcClient for UserRpcClient {
         Ok(UserRpcClient {
             client: UserServiceClient::new(endpoint.0),
             rt: endpoint.1,
+            token: "".to_string(),
         })
     }
 
@@ -101,17 +121,28 @@ impl RpcClient for UserRpcClient {
         Ok(UserRpcClient {
             client: UserServiceClient::new(endpoint.0),
             rt: endpoint.1,
+            token: "".to_string(),
         })
     }
 }
 
 impl UserRpcClient {
-    pub fn login(&mut self, name: String, pass: String) -> GeorgeResult<()> {
+    /// 设置登录令牌，后续请求都将在请求头中携带该令牌，服务端据此认证操作人
+    pub fn set_token(&mut self, token: String) {
+        self.token = token
+    }
+
+    /// 登录并返回登录令牌，令牌需通过各客户端的`set_token`设置后随请求携带
+    pub fn login(&mut self, name: String, pass: String) -> GeorgeResult<String> {
         let request = Request::new(RequestLogin { name, pass });
-        match self.rt.block_on(self.client.login(request)) {
+        match self
+            .rt
+            .block_on(self.client.login(with_token(request, &self.token)))
+        {
             Ok(res) => {
                 let resp = res.into_inner();
-                status_check(resp.status, resp.msg_err)
+                status_check(resp.status, resp.msg_err)?;
+                Ok(resp.token)
             }
             Err(err) => Err(Errs::strs(
                 "failed to successfully run the future on RunTime!",
@@ -12,17 +12,22 @@
  * limitations under the License.
  */
 
+#[cfg(unix)]
+use std::path::Path;
+
 use tonic::Request;
 
 use george_comm::errors::{Errs, GeorgeResult};
 
 use crate::client::db::ViewRpcClient;
-use crate::client::{status_check, Notls, Openssl, RequestCond, Rustls, TLSType};
+#[cfg(unix)]
+use crate::client::Uds;
+use crate::client::{status_check, with_token, Notls, Openssl, RequestCond, Rustls, TLSType};
 use crate::client::{RpcClient, TLS};
 use crate::protos::db::db::view_service_client::ViewServiceClient;
 use crate::protos::db::db::{
-    RequestViewArchive, RequestViewCreate, RequestViewInfo, RequestViewList, RequestViewModify,
-    RequestViewRecord, RequestViewRecords, RequestViewRemove, View, ViewRecord,
+    RequestViewArchive, RequestViewCipher, RequestViewCreate, RequestViewInfo, RequestViewList,
+    RequestViewModify, RequestViewRecord, RequestViewRecords, RequestViewRemove, View, ViewRecord,
 };
 
 impl RpcClient for ViewRpcClient {
@@ -34,6 +39,20 @@ impl RpcClient for ViewRpcClient {
         Ok(ViewRpcClient {
             client: ViewServiceClient::new(inner),
             rt,
+            token: "".to_string(),
+        })
+    }
+
+    #[cfg(unix)]
+    fn new_uds<P: AsRef<Path>>(path: P, cond_op: Option<RequestCond>) -> GeorgeResult<Self>
+    where
+        Self: Sized,
+    {
+        let (inner, rt) = Uds::make(path, cond_op)?;
+        Ok(ViewRpcClient {
+            client: ViewServiceClient::new(inner),
+            rt,
+            token: "".to_string(),
         })
     }
 
@@ -60,6 +79,7 @@ impl RpcClient for ViewRpcClient {
         Ok(ViewRpcClient {
             client: ViewServiceClient::new(endpoint.0),
             rt: endpoint.1,
+            token: "".to_string(),
         })
     }
 
@@ -104,14 +124,23 @@ impl RpcClient for ViewRpcClient {
         Ok(ViewRpcClient {
             client: ViewServiceClient::new(endpoint.0),
             rt: endpoint.1,
+            token: "".to_string(),
         })
     }
 }
 
 impl ViewRpcClient {
+    /// 设置登录令牌，后续请求都将在请求头中携带该令牌，服务端据此认证操作人
+    pub fn set_token(&mut self, token: String) {
+        self.token = token
+    }
+
     pub fn list(&mut self, database_name: String) -> GeorgeResult<Vec<View>> {
         let request = Request::new(RequestViewList { database_name });
-        match self.rt.block_on(self.client.list(request)) {
+        match self
+            .rt
+            .block_on(self.client.list(with_token(request, &self.token)))
+        {
             Ok(res) => {
                 let resp = res.into_inner();
                 status_check(resp.status, resp.msg_err)?;
@@ -137,7 +166,10 @@ impl ViewRpcClient {
             comment,
             with_increment: increment,
         });
-        match self.rt.block_on(self.client.create(request)) {
+        match self
+            .rt
+            .block_on(self.client.create(with_token(request, &self.token)))
+        {
             Ok(res) => {
                 let resp = res.into_inner();
                 status_check(resp.status, resp.msg_err)
@@ -154,7 +186,10 @@ impl ViewRpcClient {
             database_name,
             name,
         });
-        match self.rt.block_on(self.client.info(request)) {
+        match self
+            .rt
+            .block_on(self.client.info(with_token(request, &self.token)))
+        {
             Ok(res) => {
                 let resp = res.into_inner();
                 status_check(resp.status, resp.msg_err)?;
@@ -183,7 +218,10 @@ impl ViewRpcClient {
             name_new,
             comment,
         });
-        match self.rt.block_on(self.client.modify(request)) {
+        match self
+            .rt
+            .block_on(self.client.modify(with_token(request, &self.token)))
+        {
             Ok(res) => {
                 let resp = res.into_inner();
                 status_check(resp.status, resp.msg_err)
@@ -200,7 +238,45 @@ impl ViewRpcClient {
             database_name,
             name,
         });
-        match self.rt.block_on(self.client.remove(request)) {
+        match self
+            .rt
+            .block_on(self.client.remove(with_token(request, &self.token)))
+        {
+            Ok(res) => {
+                let resp = res.into_inner();
+                status_check(resp.status, resp.msg_err)
+            }
+            Err(err) => Err(Errs::strs(
+                "failed to successfully run the future on RunTime!",
+                err,
+            )),
+        }
+    }
+
+    /// 设置视图字段加密策略
+    ///
+    /// * fields 加密字段集合，仅支持json顶层字段
+    /// * deterministic 是否确定性加密，需在加密字段上建立索引或等值检索时使用
+    /// * readers 可解密的操作人集合
+    pub fn cipher(
+        &mut self,
+        database_name: String,
+        name: String,
+        fields: Vec<String>,
+        deterministic: bool,
+        readers: Vec<String>,
+    ) -> GeorgeResult<()> {
+        let request = Request::new(RequestViewCipher {
+            database_name,
+            name,
+            fields,
+            deterministic,
+            readers,
+        });
+        match self
+            .rt
+            .block_on(self.client.cipher(with_token(request, &self.token)))
+        {
             Ok(res) => {
                 let resp = res.into_inner();
                 status_check(resp.status, resp.msg_err)
@@ -223,7 +299,10 @@ impl ViewRpcClient {
             name,
             archive_file_path,
         });
-        match self.rt.block_on(self.client.archive(request)) {
+        match self
+            .rt
+            .block_on(self.client.archive(with_token(request, &self.token)))
+        {
             Ok(res) => {
                 let resp = res.into_inner();
                 status_check(resp.status, resp.msg_err)
@@ -246,7 +325,10 @@ impl ViewRpcClient {
             name,
             version,
         });
-        match self.rt.block_on(self.client.record(request)) {
+        match self
+            .rt
+            .block_on(self.client.record(with_token(request, &self.token)))
+        {
             Ok(res) => {
                 let resp = res.into_inner();
                 status_check(resp.status, resp.msg_err)?;
@@ -271,7 +353,10 @@ impl ViewRpcClient {
             database_name,
             name,
         });
-        match self.rt.block_on(self.client.records(request)) {
+        match self
+            .rt
+            .block_on(self.client.records(with_token(request, &self.token)))
+        {
             Ok(res) => {
                 let resp = res.into_inner();
                 status_check(resp.status, resp.msg_err)?;
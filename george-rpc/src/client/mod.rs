@@ -12,24 +12,37 @@
  * limitations under the License.
  */
 
+use std::future::Future;
 use std::option::Option::{None, Some};
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::thread;
+use std::thread::JoinHandle;
 use std::time::Duration;
 
 use tokio::runtime::{Builder, Runtime};
+use tokio::sync::{mpsc, oneshot};
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::metadata::MetadataValue;
 use tonic::transport::{Channel, Endpoint, Uri};
+use tonic::Request;
 
 use george_comm::errors::{Errs, GeorgeResult};
 use george_comm::io::file::FilerReader;
 use george_comm::io::Filer;
+use george_db::task::Secret;
 
+use crate::protos::db::db::{BatchItem, ResponseBatch};
 use crate::protos::utils::utils::Status;
+use crate::tools::audit::{AUTHORIZATION_KEY, BEARER_PREFIX, PEER_KEY};
 use crate::tools::Trans;
 
 pub mod db;
 mod notls;
 mod openssl;
 mod rustls;
+#[cfg(unix)]
+mod uds;
 
 /// `TLS`请求新建公共方法
 pub trait TLS {
@@ -76,6 +89,17 @@ pub trait RpcClient {
     where
         Self: Sized;
 
+    /// 通过`Unix domain socket`连接本机服务
+    ///
+    /// ###Params
+    ///
+    /// * path 服务监听的`socket`文件路径
+    /// * cond_op 请求条件设置
+    #[cfg(unix)]
+    fn new_uds<P: AsRef<Path>>(path: P, cond_op: Option<RequestCond>) -> GeorgeResult<Self>
+    where
+        Self: Sized;
+
     fn new_tls<P: AsRef<Path>>(
         tls_type: TLSType,
         remote: &str,
@@ -156,6 +180,11 @@ struct Openssl {
     uri: Uri,
 }
 
+#[cfg(unix)]
+struct Uds {
+    path: PathBuf,
+}
+
 /// TLS请求类型
 #[derive(Debug, Clone, Copy)]
 pub enum TLSType {
@@ -251,13 +280,142 @@ fn runtime() -> GeorgeResult<Runtime> {
     }
 }
 
-fn status_check(status_i32: i32, msg_err: String) -> GeorgeResult<()> {
+/// 校验响应中的执行结果状态，非成功状态转为错误
+pub fn status_check(status_i32: i32, msg_err: String) -> GeorgeResult<()> {
     let status = Trans::i32_2_status(status_i32)?;
     match status {
         Status::Ok => Ok(()),
+        Status::ResourceExhausted => Err(Errs::quota_exceeded_error(msg_err)),
+        Status::PermissionDenied => Err(Errs::permission_denied_error(msg_err)),
         _ => Err(Errs::string(format!(
             "failed! status is {}, error is {}",
             status_i32, msg_err
         ))),
     }
 }
+
+/// 在请求头中以`Bearer`方式携带登录令牌，服务端校验令牌后得到操作人，据此鉴权及记录审计日志
+///
+/// 令牌为空或无法作为请求头时不携带，服务端视为匿名请求
+pub fn with_token<T>(mut request: Request<T>, token: &str) -> Request<T> {
+    if !token.is_empty() {
+        if let Ok(res) = MetadataValue::from_str(format!("{}{}", BEARER_PREFIX, token).as_str()) {
+            request.metadata_mut().insert(AUTHORIZATION_KEY, res);
+        }
+    }
+    request
+}
+
+/// 在请求头中携带节点间认证凭证，凭证在每次请求前以节点间认证密钥签发，未设置密钥时不携带
+pub fn with_peer<T>(
+    mut request: Request<T>,
+    peer: &Option<Arc<Secret>>,
+) -> GeorgeResult<Request<T>> {
+    if let Some(peer) = peer {
+        match MetadataValue::from_str(peer.peer_issue()?.as_str()) {
+            Ok(res) => {
+                request.metadata_mut().insert(PEER_KEY, res);
+            }
+            Err(err) => return Err(Errs::strs("peer credential to metadata", err)),
+        }
+    }
+    Ok(request)
+}
+
+/// 批量写入时单次请求携带的最大数据项数
+const BATCH_CHUNK: usize = 1000;
+/// 批量写入时等待发送的最大请求数，服务端写入慢于读取时读取随之阻塞
+const BATCH_CHANNEL_SIZE: usize = 8;
+
+/// 批量写入的请求流
+struct BatchStream<Req> {
+    /// 按批组装的请求
+    requests: ReceiverStream<Req>,
+    /// 读取数据项失败时收到通知
+    abort: oneshot::Receiver<()>,
+    /// 读取数据项的线程
+    reader: JoinHandle<GeorgeResult<()>>,
+}
+
+/// 在独立线程中读取数据项并按批组装请求，数据项无需全部载入内存
+///
+/// 读取数据项失败时先通知中断请求再结束请求流，避免服务端将已读取的部分视为完整的批量写入
+fn batch_stream<Req, I, F>(items: I, request: F) -> BatchStream<Req>
+where
+    Req: Send + 'static,
+    I: IntoIterator<Item = GeorgeResult<(String, Vec<u8>)>>,
+    I::IntoIter: Send + 'static,
+    F: Fn(Vec<BatchItem>) -> Req + Send + 'static,
+{
+    let items = items.into_iter();
+    let (sender, receiver) = mpsc::channel(BATCH_CHANNEL_SIZE);
+    let (abort_sender, abort) = oneshot::channel();
+    let reader = thread::spawn(move || -> GeorgeResult<()> {
+        let send = |batch: Vec<BatchItem>| -> GeorgeResult<()> {
+            match sender.blocking_send(request(batch)) {
+                Ok(()) => Ok(()),
+                Err(_) => Err(Errs::str("batch sender closed!")),
+            }
+        };
+        let mut batch: Vec<BatchItem> = vec![];
+        for item in items {
+            match item {
+                Ok((key, value)) => batch.push(BatchItem { key, value }),
+                Err(err) => {
+                    let _ = abort_sender.send(());
+                    return Err(err);
+                }
+            }
+            if batch.len() >= BATCH_CHUNK {
+                send(batch.split_off(0))?
+            }
+        }
+        // 即便没有数据项也需发送一次请求，服务端据此确定写入目标
+        send(batch)
+    });
+    BatchStream {
+        requests: ReceiverStream::new(receiver),
+        abort,
+        reader,
+    }
+}
+
+impl<Req> BatchStream<Req> {
+    /// 发送请求流并等待批量写入结果，读取数据项失败时中断请求，以读取错误为准
+    fn run<F, Fut>(self, rt: &Runtime, call: F) -> GeorgeResult<ResponseBatch>
+    where
+        F: FnOnce(ReceiverStream<Req>) -> Fut,
+        Fut: Future<Output = Result<tonic::Response<ResponseBatch>, tonic::Status>>,
+    {
+        let abort = self.abort;
+        let call = call(self.requests);
+        let result = rt.block_on(async move {
+            tokio::select! {
+                biased;
+                Ok(()) = abort => None,
+                res = call => Some(res),
+            }
+        });
+        // 服务端写入失败时读取线程随之失败，此时以服务端错误为准
+        let read = match self.reader.join() {
+            Ok(res) => res,
+            Err(_) => Err(Errs::str("batch reader thread panicked!")),
+        };
+        match result {
+            Some(Ok(res)) => {
+                let resp = res.into_inner();
+                status_check(resp.status, resp.msg_err.clone())?;
+                read?;
+                Ok(resp)
+            }
+            Some(Err(err)) => Err(Errs::strs(
+                "failed to successfully run the future on RunTime!",
+                err,
+            )),
+            None => match read {
+                Ok(()) => Err(Errs::str("batch aborted!")),
+                Err(err) => Err(err),
+            },
+        }
+    }
+}
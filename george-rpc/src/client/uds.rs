@@ -0,0 +1,60 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::path::Path;
+
+use tokio::net::UnixStream;
+use tokio::runtime::Runtime;
+use tonic::transport::{Channel, Endpoint, Uri};
+use tower::service_fn;
+
+use george_comm::errors::{Errs, GeorgeResult};
+
+use crate::client::{runtime, RequestCond, Uds};
+
+/// 连接器不使用该地址，仅用于构建请求
+const UDS_URI: &str = "http://[::]:9219";
+
+impl Uds {
+    fn new<P: AsRef<Path>>(path: P) -> Uds {
+        Uds {
+            path: path.as_ref().to_path_buf(),
+        }
+    }
+
+    fn block_on(
+        &self,
+        mut endpoint: Endpoint,
+        cond_op: Option<RequestCond>,
+    ) -> GeorgeResult<(Channel, Runtime)> {
+        let rt = runtime()?;
+        endpoint = crate::client::endpoint(endpoint, cond_op)?;
+        let path = self.path.clone();
+        let future = endpoint
+            .connect_with_connector(service_fn(move |_: Uri| UnixStream::connect(path.clone())));
+        match rt.block_on(future) {
+            Ok(res) => Ok((res, rt)),
+            Err(err) => Err(Errs::strs("endpoint connect with uds", err)),
+        }
+    }
+
+    pub(crate) fn make<P: AsRef<Path>>(
+        path: P,
+        cond_op: Option<RequestCond>,
+    ) -> GeorgeResult<(Channel, Runtime)> {
+        let uds = Uds::new(path);
+        let endpoint = Endpoint::from_static(UDS_URI);
+        uds.block_on(endpoint, cond_op)
+    }
+}
@@ -1,3 +1,385 @@
+/// 审计记录
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AuditRecord {
+    /// 记录序号，从1开始递增
+    #[prost(uint64, tag = "1")]
+    pub seq: u64,
+    /// 记录时间
+    #[prost(message, optional, tag = "2")]
+    pub time: ::core::option::Option<super::utils::Timestamp>,
+    /// 操作发起人
+    #[prost(string, tag = "3")]
+    pub principal: ::prost::alloc::string::String,
+    /// 操作发起地址
+    #[prost(string, tag = "4")]
+    pub remote: ::prost::alloc::string::String,
+    /// 操作名称，如`database.create`
+    #[prost(string, tag = "5")]
+    pub operation: ::prost::alloc::string::String,
+    /// 操作对象，如`database_name/view_name`
+    #[prost(string, tag = "6")]
+    pub target: ::prost::alloc::string::String,
+    /// 操作是否成功
+    #[prost(bool, tag = "7")]
+    pub success: bool,
+    /// 操作失败时的错误信息
+    #[prost(string, tag = "8")]
+    pub msg_err: ::prost::alloc::string::String,
+    /// 操作耗时，单位微秒
+    #[prost(uint64, tag = "9")]
+    pub latency: u64,
+    /// 前一条记录的hash
+    #[prost(string, tag = "10")]
+    pub prev_hash: ::prost::alloc::string::String,
+    /// 当前记录hash
+    #[prost(string, tag = "11")]
+    pub hash: ::prost::alloc::string::String,
+}
+/// 请求审计记录集合
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RequestAuditList {
+    /// 操作发起人，为空则不限
+    #[prost(string, tag = "1")]
+    pub principal: ::prost::alloc::string::String,
+    /// 操作名称前缀，为空则不限
+    #[prost(string, tag = "2")]
+    pub operation: ::prost::alloc::string::String,
+    /// 起始时间，单位秒，为0则不限
+    #[prost(int64, tag = "3")]
+    pub start: i64,
+    /// 截止时间，单位秒，为0则不限
+    #[prost(int64, tag = "4")]
+    pub end: i64,
+    /// 最多返回条数，为0则不限
+    #[prost(uint32, tag = "5")]
+    pub limit: u32,
+}
+/// 审计记录集合
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ResponseAuditList {
+    #[prost(enumeration = "super::utils::Status", tag = "1")]
+    pub status: i32,
+    #[prost(string, tag = "2")]
+    pub msg_err: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "3")]
+    pub records: ::prost::alloc::vec::Vec<AuditRecord>,
+}
+/// 审计链校验结果
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ResponseAuditVerify {
+    #[prost(enumeration = "super::utils::Status", tag = "1")]
+    pub status: i32,
+    #[prost(string, tag = "2")]
+    pub msg_err: ::prost::alloc::string::String,
+    /// 已校验的记录条数
+    #[prost(uint64, tag = "3")]
+    pub count: u64,
+}
+/// 请求在线备份
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RequestBackup {
+    /// 服务端备份目录，须为空目录或不存在
+    #[prost(string, tag = "1")]
+    pub dir: ::prost::alloc::string::String,
+    /// 是否以`gzip`压缩备份文件
+    #[prost(bool, tag = "2")]
+    pub compress: bool,
+}
+/// 备份结果
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ResponseBackup {
+    #[prost(enumeration = "super::utils::Status", tag = "1")]
+    pub status: i32,
+    #[prost(string, tag = "2")]
+    pub msg_err: ::prost::alloc::string::String,
+    /// 备份时刻的变更日志序号，时间点恢复时重放该序号之后的变更
+    #[prost(uint64, tag = "3")]
+    pub seq: u64,
+    /// 备份文件数量
+    #[prost(uint32, tag = "4")]
+    pub files: u32,
+    /// 备份文件原始字节数
+    #[prost(uint64, tag = "5")]
+    pub bytes: u64,
+}
+/// 批量写入的数据项，删除时忽略value
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BatchItem {
+    #[prost(string, tag = "1")]
+    pub key: ::prost::alloc::string::String,
+    #[prost(bytes = "vec", tag = "2")]
+    pub value: ::prost::alloc::vec::Vec<u8>,
+}
+/// 请求批量写入磁盘数据，首个请求确定目标库、视图及是否全部成功或全部失败，后续请求仅读取其中数据项
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RequestDiskBatch {
+    /// 数据库名称
+    #[prost(string, tag = "1")]
+    pub database_name: ::prost::alloc::string::String,
+    /// 视图名称
+    #[prost(string, tag = "2")]
+    pub view_name: ::prost::alloc::string::String,
+    /// 是否全部成功或全部失败，为true时任一数据项失败即停止写入并回滚已写入的数据项
+    #[prost(bool, tag = "3")]
+    pub atomic: bool,
+    #[prost(message, repeated, tag = "4")]
+    pub items: ::prost::alloc::vec::Vec<BatchItem>,
+}
+/// 请求批量写入内存数据，首个请求确定目标缓存页及是否全部成功或全部失败，后续请求仅读取其中数据项
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RequestMemoryBatch {
+    /// 缓存页名称，为空则使用默认缓存页
+    #[prost(string, tag = "1")]
+    pub page_name: ::prost::alloc::string::String,
+    /// 是否全部成功或全部失败，为true时任一数据项失败即停止写入并回滚已写入的数据项
+    #[prost(bool, tag = "2")]
+    pub atomic: bool,
+    #[prost(message, repeated, tag = "3")]
+    pub items: ::prost::alloc::vec::Vec<BatchItem>,
+}
+/// 写入失败的数据项
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BatchError {
+    /// 数据项在本次批量写入中的序号，从0开始
+    #[prost(uint64, tag = "1")]
+    pub index: u64,
+    #[prost(string, tag = "2")]
+    pub key: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub msg_err: ::prost::alloc::string::String,
+}
+/// 批量写入结果
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ResponseBatch {
+    #[prost(enumeration = "super::utils::Status", tag = "1")]
+    pub status: i32,
+    #[prost(string, tag = "2")]
+    pub msg_err: ::prost::alloc::string::String,
+    /// 处理数据项数
+    #[prost(uint64, tag = "3")]
+    pub total: u64,
+    /// 写入成功数
+    #[prost(uint64, tag = "4")]
+    pub succeeded: u64,
+    /// 写入失败数
+    #[prost(uint64, tag = "5")]
+    pub failed: u64,
+    /// 已写入的数据项是否已回滚
+    #[prost(bool, tag = "6")]
+    pub rolled_back: bool,
+    /// 写入失败的数据项，最多保留1000条
+    #[prost(message, repeated, tag = "7")]
+    pub errors: ::prost::alloc::vec::Vec<BatchError>,
+}
+/// 集群节点
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ClusterPeer {
+    /// 节点编号
+    #[prost(uint64, tag = "1")]
+    pub id: u64,
+    /// 节点服务地址，格式为`host:port`
+    #[prost(string, tag = "2")]
+    pub addr: ::prost::alloc::string::String,
+}
+/// 候选者请求投票
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RequestClusterVote {
+    /// 候选者任期
+    #[prost(uint64, tag = "1")]
+    pub term: u64,
+    /// 候选者编号
+    #[prost(uint64, tag = "2")]
+    pub candidate: u64,
+    /// 候选者最新日志条目索引
+    #[prost(uint64, tag = "3")]
+    pub last_index: u64,
+    /// 候选者最新日志条目任期
+    #[prost(uint64, tag = "4")]
+    pub last_term: u64,
+}
+/// 投票结果
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ResponseClusterVote {
+    #[prost(enumeration = "super::utils::Status", tag = "1")]
+    pub status: i32,
+    #[prost(string, tag = "2")]
+    pub msg_err: ::prost::alloc::string::String,
+    /// 投票节点当前任期
+    #[prost(uint64, tag = "3")]
+    pub term: u64,
+    /// 是否投票给候选者
+    #[prost(bool, tag = "4")]
+    pub granted: bool,
+}
+/// 领导者复制日志，不携带条目时即为心跳
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RequestClusterAppend {
+    /// 领导者任期
+    #[prost(uint64, tag = "1")]
+    pub term: u64,
+    /// 领导者编号
+    #[prost(uint64, tag = "2")]
+    pub leader: u64,
+    /// 待复制条目前一条目的索引
+    #[prost(uint64, tag = "3")]
+    pub prev_index: u64,
+    /// 待复制条目前一条目的任期
+    #[prost(uint64, tag = "4")]
+    pub prev_term: u64,
+    /// 待复制条目，格式同共识日志中的`json`行
+    #[prost(bytes = "vec", repeated, tag = "5")]
+    pub entries: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    /// 领导者已提交的条目索引
+    #[prost(uint64, tag = "6")]
+    pub commit: u64,
+}
+/// 日志复制结果
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ResponseClusterAppend {
+    #[prost(enumeration = "super::utils::Status", tag = "1")]
+    pub status: i32,
+    #[prost(string, tag = "2")]
+    pub msg_err: ::prost::alloc::string::String,
+    /// 跟随者当前任期
+    #[prost(uint64, tag = "3")]
+    pub term: u64,
+    /// 是否复制成功
+    #[prost(bool, tag = "4")]
+    pub success: bool,
+    /// 复制成功时为与领导者一致的最新条目索引，失败时为领导者下次复制的起始索引减1
+    #[prost(uint64, tag = "5")]
+    pub last_index: u64,
+}
+/// 非领导者节点将写入转发至领导者
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RequestClusterForward {
+    /// 条目内容，`json`格式
+    #[prost(bytes = "vec", tag = "1")]
+    pub command: ::prost::alloc::vec::Vec<u8>,
+}
+/// 领导者追加结果
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ResponseClusterForward {
+    #[prost(enumeration = "super::utils::Status", tag = "1")]
+    pub status: i32,
+    #[prost(string, tag = "2")]
+    pub msg_err: ::prost::alloc::string::String,
+    /// 条目索引
+    #[prost(uint64, tag = "3")]
+    pub index: u64,
+    /// 条目任期
+    #[prost(uint64, tag = "4")]
+    pub term: u64,
+}
+/// 领导者发送快照分块，跟随者接收完毕后停止共识，重启时以快照恢复数据
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RequestClusterInstall {
+    /// 领导者任期
+    #[prost(uint64, tag = "1")]
+    pub term: u64,
+    /// 领导者编号
+    #[prost(uint64, tag = "2")]
+    pub leader: u64,
+    /// 快照包含的最新条目索引
+    #[prost(uint64, tag = "3")]
+    pub index: u64,
+    /// 快照包含的最新条目任期
+    #[prost(uint64, tag = "4")]
+    pub last_term: u64,
+    /// 快照包含的最新条目生效时的集群节点集合
+    #[prost(message, repeated, tag = "5")]
+    pub members: ::prost::alloc::vec::Vec<ClusterPeer>,
+    /// 快照文件相对快照目录的路径
+    #[prost(string, tag = "6")]
+    pub path: ::prost::alloc::string::String,
+    /// 文件内容分块
+    #[prost(bytes = "vec", tag = "7")]
+    pub data: ::prost::alloc::vec::Vec<u8>,
+    /// 是否为本次快照的首个分块
+    #[prost(bool, tag = "8")]
+    pub first: bool,
+    /// 是否已发送全部文件，为`true`时不携带文件内容
+    #[prost(bool, tag = "9")]
+    pub done: bool,
+}
+/// 快照分块接收结果
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ResponseClusterInstall {
+    #[prost(enumeration = "super::utils::Status", tag = "1")]
+    pub status: i32,
+    #[prost(string, tag = "2")]
+    pub msg_err: ::prost::alloc::string::String,
+    /// 跟随者当前任期
+    #[prost(uint64, tag = "3")]
+    pub term: u64,
+}
+/// 请求集群状态
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RequestClusterStatus {}
+/// 集群状态
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ResponseClusterStatus {
+    #[prost(enumeration = "super::utils::Status", tag = "1")]
+    pub status: i32,
+    #[prost(string, tag = "2")]
+    pub msg_err: ::prost::alloc::string::String,
+    /// 本节点编号
+    #[prost(uint64, tag = "3")]
+    pub id: u64,
+    /// 本节点角色，`follower`、`candidate`或`leader`
+    #[prost(string, tag = "4")]
+    pub role: ::prost::alloc::string::String,
+    /// 当前任期
+    #[prost(uint64, tag = "5")]
+    pub term: u64,
+    /// 领导者编号，为0表示尚无领导者
+    #[prost(uint64, tag = "6")]
+    pub leader: u64,
+    /// 领导者服务地址
+    #[prost(string, tag = "7")]
+    pub leader_addr: ::prost::alloc::string::String,
+    /// 最新日志条目索引
+    #[prost(uint64, tag = "8")]
+    pub last_index: u64,
+    /// 已提交的条目索引
+    #[prost(uint64, tag = "9")]
+    pub commit: u64,
+    /// 已应用的条目索引
+    #[prost(uint64, tag = "10")]
+    pub applied: u64,
+    /// 当前集群节点集合
+    #[prost(message, repeated, tag = "11")]
+    pub members: ::prost::alloc::vec::Vec<ClusterPeer>,
+}
+/// 请求新增节点，新节点须以加入已运行集群的身份启动
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RequestClusterJoin {
+    #[prost(message, optional, tag = "1")]
+    pub peer: ::core::option::Option<ClusterPeer>,
+}
+/// 新增节点结果
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ResponseClusterJoin {
+    #[prost(enumeration = "super::utils::Status", tag = "1")]
+    pub status: i32,
+    #[prost(string, tag = "2")]
+    pub msg_err: ::prost::alloc::string::String,
+}
+/// 请求移除节点
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RequestClusterLeave {
+    /// 节点编号
+    #[prost(uint64, tag = "1")]
+    pub id: u64,
+}
+/// 移除节点结果
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ResponseClusterLeave {
+    #[prost(enumeration = "super::utils::Status", tag = "1")]
+    pub status: i32,
+    #[prost(string, tag = "2")]
+    pub msg_err: ::prost::alloc::string::String,
+}
 /// 索引
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Index {
@@ -227,6 +609,25 @@ pub struct RequestViewRemove {
     #[prost(string, tag = "2")]
     pub name: ::prost::alloc::string::String,
 }
+/// 设置视图字段加密策略
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RequestViewCipher {
+    /// 数据库名称
+    #[prost(string, tag = "1")]
+    pub database_name: ::prost::alloc::string::String,
+    /// 名称
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+    /// 加密字段集合，仅支持json顶层字段
+    #[prost(string, repeated, tag = "3")]
+    pub fields: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// 是否确定性加密，需在加密字段上建立索引或等值检索时使用
+    #[prost(bool, tag = "4")]
+    pub deterministic: bool,
+    /// 可解密的操作人集合
+    #[prost(string, repeated, tag = "5")]
+    pub readers: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
 /// 整理归档
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RequestViewArchive {
@@ -475,6 +876,20 @@ pub struct DiskSelected {
     #[prost(bytes = "vec", repeated, tag = "6")]
     pub values: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
 }
+/// 流式返回查询数据，检索结果按检索顺序分多次返回，最后一条携带检索状态及统计
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ResponseDiskSelectStream {
+    #[prost(enumeration = "super::utils::Status", tag = "1")]
+    pub status: i32,
+    #[prost(string, tag = "2")]
+    pub msg_err: ::prost::alloc::string::String,
+    /// 本次返回的检索结果
+    #[prost(bytes = "vec", repeated, tag = "3")]
+    pub values: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    /// 检索统计，仅最后一条携带，其中检索结果集合为空
+    #[prost(message, optional, tag = "4")]
+    pub selected: ::core::option::Option<DiskSelected>,
+}
 /// 请求删除数据
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RequestDiskDelete {
@@ -518,6 +933,169 @@ pub struct DiskDeleted {
     #[prost(bytes = "vec", repeated, tag = "6")]
     pub values: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
 }
+/// 账本
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Ledger {
+    /// 名称
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// 描述
+    #[prost(string, tag = "2")]
+    pub comment: ::prost::alloc::string::String,
+    /// 创建时间
+    #[prost(message, optional, tag = "3")]
+    pub create_time: ::core::option::Option<super::utils::Timestamp>,
+    /// 区块高度，即账本中的区块数量
+    #[prost(uint64, tag = "4")]
+    pub height: u64,
+    /// 最新区块hash，账本中尚无区块时为空
+    #[prost(string, tag = "5")]
+    pub hash: ::prost::alloc::string::String,
+}
+/// 交易
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LedgerTransaction {
+    /// 交易hash，所在区块打包时间、交易序号及交易内容的sm3摘要
+    #[prost(string, tag = "1")]
+    pub hash: ::prost::alloc::string::String,
+    /// 交易内容
+    #[prost(bytes = "vec", tag = "2")]
+    pub value: ::prost::alloc::vec::Vec<u8>,
+    /// 交易在区块中的序号
+    #[prost(uint32, tag = "3")]
+    pub nonce: u32,
+}
+/// 区块
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LedgerBlock {
+    /// 区块高度，首个区块为0
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    /// 上一区块hash，首个区块为空
+    #[prost(string, tag = "2")]
+    pub pre_hash: ::prost::alloc::string::String,
+    /// 当前区块hash
+    #[prost(string, tag = "3")]
+    pub hash: ::prost::alloc::string::String,
+    /// 区块中交易hash组成的默克尔树根hash
+    #[prost(string, tag = "4")]
+    pub tx_root: ::prost::alloc::string::String,
+    /// 区块打包时间
+    #[prost(message, optional, tag = "5")]
+    pub timestamp: ::core::option::Option<super::utils::Timestamp>,
+    /// 交易集合
+    #[prost(message, repeated, tag = "6")]
+    pub transactions: ::prost::alloc::vec::Vec<LedgerTransaction>,
+}
+/// 账本集合
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ResponseLedgerList {
+    #[prost(enumeration = "super::utils::Status", tag = "1")]
+    pub status: i32,
+    #[prost(string, tag = "2")]
+    pub msg_err: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "3")]
+    pub ledgers: ::prost::alloc::vec::Vec<Ledger>,
+}
+/// 请求新建账本
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RequestLedgerCreate {
+    /// 名称
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// 描述
+    #[prost(string, tag = "2")]
+    pub comment: ::prost::alloc::string::String,
+}
+/// 请求账本信息
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RequestLedgerInfo {
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+}
+/// 请求账本信息
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ResponseLedgerInfo {
+    #[prost(enumeration = "super::utils::Status", tag = "1")]
+    pub status: i32,
+    #[prost(string, tag = "2")]
+    pub msg_err: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub ledger: ::core::option::Option<Ledger>,
+}
+/// 请求账本删除
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RequestLedgerRemove {
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+}
+/// 请求将交易集合打包为新区块
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RequestBlockInsert {
+    /// 账本名称
+    #[prost(string, tag = "1")]
+    pub ledger_name: ::prost::alloc::string::String,
+    /// 交易内容集合
+    #[prost(bytes = "vec", repeated, tag = "2")]
+    pub transactions: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+}
+/// 请求获取区块
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RequestBlockGet {
+    /// 账本名称
+    #[prost(string, tag = "1")]
+    pub ledger_name: ::prost::alloc::string::String,
+    #[prost(oneof = "request_block_get::Get", tags = "2, 3")]
+    pub get: ::core::option::Option<request_block_get::Get>,
+}
+/// Nested message and enum types in `RequestBlockGet`.
+pub mod request_block_get {
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Get {
+        /// 区块高度
+        #[prost(uint64, tag = "2")]
+        Height(u64),
+        /// 区块hash
+        #[prost(string, tag = "3")]
+        Hash(::prost::alloc::string::String),
+    }
+}
+/// 区块
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ResponseBlock {
+    #[prost(enumeration = "super::utils::Status", tag = "1")]
+    pub status: i32,
+    #[prost(string, tag = "2")]
+    pub msg_err: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub block: ::core::option::Option<LedgerBlock>,
+}
+/// 请求获取交易
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RequestTransactionGet {
+    /// 账本名称
+    #[prost(string, tag = "1")]
+    pub ledger_name: ::prost::alloc::string::String,
+    /// 交易hash
+    #[prost(string, tag = "2")]
+    pub hash: ::prost::alloc::string::String,
+}
+/// 交易及所在区块
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ResponseTransaction {
+    #[prost(enumeration = "super::utils::Status", tag = "1")]
+    pub status: i32,
+    #[prost(string, tag = "2")]
+    pub msg_err: ::prost::alloc::string::String,
+    /// 所在区块高度
+    #[prost(uint64, tag = "3")]
+    pub height: u64,
+    /// 所在区块hash
+    #[prost(string, tag = "4")]
+    pub block_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "5")]
+    pub transaction: ::core::option::Option<LedgerTransaction>,
+}
 /// 缓存页
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Page {
@@ -680,7 +1258,145 @@ pub struct RequestMemoryPRemove {
     #[prost(string, tag = "2")]
     pub key: ::prost::alloc::string::String,
 }
-/// 请求登录数据库
+/// 配额策略，各限制项为0表示不限制
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QuotaPolicy {
+    /// 作用范围，可选`user`、`database`、`view`及`page`
+    #[prost(string, tag = "1")]
+    pub scope: ::prost::alloc::string::String,
+    /// 作用对象名称，`view`为`database_name/view_name`
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+    /// 每秒允许的请求数，仅`user`及`database`可用
+    #[prost(uint32, tag = "3")]
+    pub rate: u32,
+    /// 允许突发的请求数，为0则与`rate`相同
+    #[prost(uint32, tag = "4")]
+    pub burst: u32,
+    /// 最大存储字节数，仅`database`及`view`可用
+    #[prost(uint64, tag = "5")]
+    pub max_bytes: u64,
+    /// 最大键数量，仅`page`可用
+    #[prost(uint64, tag = "6")]
+    pub max_keys: u64,
+}
+/// 设置配额策略，所有限制项都为0则移除策略
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RequestQuotaSet {
+    #[prost(message, optional, tag = "1")]
+    pub policy: ::core::option::Option<QuotaPolicy>,
+}
+/// 请求配额策略集合
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RequestQuotaList {
+    /// 作用范围，为空则不限
+    #[prost(string, tag = "1")]
+    pub scope: ::prost::alloc::string::String,
+}
+/// 配额策略集合
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ResponseQuotaList {
+    #[prost(enumeration = "super::utils::Status", tag = "1")]
+    pub status: i32,
+    #[prost(string, tag = "2")]
+    pub msg_err: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "3")]
+    pub policies: ::prost::alloc::vec::Vec<QuotaPolicy>,
+}
+/// 从节点请求跟随主节点变更
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RequestReplicationFollow {
+    /// 从节点已应用的主节点变更日志序号，主节点推送该序号之后的变更
+    #[prost(uint64, tag = "1")]
+    pub seq: u64,
+}
+/// 主节点推送的变更，无新变更时定期推送空变更以告知最新序号
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ResponseReplicationFollow {
+    #[prost(enumeration = "super::utils::Status", tag = "1")]
+    pub status: i32,
+    #[prost(string, tag = "2")]
+    pub msg_err: ::prost::alloc::string::String,
+    /// 主节点最新变更日志序号
+    #[prost(uint64, tag = "3")]
+    pub primary_seq: u64,
+    /// 变更记录，格式同变更日志中的`json`行
+    #[prost(bytes = "vec", repeated, tag = "4")]
+    pub mutations: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+}
+/// 从节点请求拉取主节点快照
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RequestReplicationSnapshot {}
+/// 主节点快照内容，先按文件分块推送，最后推送快照清单
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ResponseReplicationSnapshot {
+    #[prost(enumeration = "super::utils::Status", tag = "1")]
+    pub status: i32,
+    #[prost(string, tag = "2")]
+    pub msg_err: ::prost::alloc::string::String,
+    /// 相对数据存储路径的文件路径
+    #[prost(string, tag = "3")]
+    pub path: ::prost::alloc::string::String,
+    /// 文件内容分块，同一文件的分块按序推送
+    #[prost(bytes = "vec", tag = "4")]
+    pub data: ::prost::alloc::vec::Vec<u8>,
+    /// 快照清单，格式同备份清单，仅最后一次推送携带
+    #[prost(bytes = "vec", tag = "5")]
+    pub manifest: ::prost::alloc::vec::Vec<u8>,
+}
+/// 请求复制状态
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RequestReplicationStatus {}
+/// 复制状态
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ResponseReplicationStatus {
+    #[prost(enumeration = "super::utils::Status", tag = "1")]
+    pub status: i32,
+    #[prost(string, tag = "2")]
+    pub msg_err: ::prost::alloc::string::String,
+    /// 角色，`primary`或`replica`
+    #[prost(string, tag = "3")]
+    pub role: ::prost::alloc::string::String,
+    /// 主节点地址，仅从节点有效
+    #[prost(string, tag = "4")]
+    pub primary: ::prost::alloc::string::String,
+    /// 本地变更日志最新序号
+    #[prost(uint64, tag = "5")]
+    pub seq: u64,
+    /// 已应用的主节点变更日志序号
+    #[prost(uint64, tag = "6")]
+    pub applied: u64,
+    /// 已知的主节点最新变更日志序号
+    #[prost(uint64, tag = "7")]
+    pub primary_seq: u64,
+    /// 落后主节点的变更数
+    #[prost(uint64, tag = "8")]
+    pub lag: u64,
+    /// 落后主节点的时长，单位毫秒
+    #[prost(int64, tag = "9")]
+    pub lag_millis: i64,
+    /// 最近一次收到主节点推送的时间，单位纳秒
+    #[prost(int64, tag = "10")]
+    pub contact_time: i64,
+    /// 提升为主节点的时间，单位纳秒，为0表示未曾提升
+    #[prost(int64, tag = "11")]
+    pub promoted_time: i64,
+}
+/// 请求将从节点提升为主节点
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RequestReplicationPromote {}
+/// 提升结果
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ResponseReplicationPromote {
+    #[prost(enumeration = "super::utils::Status", tag = "1")]
+    pub status: i32,
+    #[prost(string, tag = "2")]
+    pub msg_err: ::prost::alloc::string::String,
+    /// 提升时已应用的主节点变更日志序号
+    #[prost(uint64, tag = "3")]
+    pub applied: u64,
+}
+/// 请求登录数据库
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RequestLogin {
     /// 名称
@@ -690,6 +1406,103 @@ pub struct RequestLogin {
     #[prost(string, tag = "2")]
     pub pass: ::prost::alloc::string::String,
 }
+/// 登录结果
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ResponseLogin {
+    #[prost(enumeration = "super::utils::Status", tag = "1")]
+    pub status: i32,
+    #[prost(string, tag = "2")]
+    pub msg_err: ::prost::alloc::string::String,
+    /// 登录令牌，后续请求以`Bearer`方式在请求头中携带
+    #[prost(string, tag = "3")]
+    pub token: ::prost::alloc::string::String,
+}
+/// 请求导出
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RequestExport {
+    #[prost(string, tag = "1")]
+    pub database_name: ::prost::alloc::string::String,
+    /// 视图名称，为空则导出库中所有视图
+    #[prost(string, tag = "2")]
+    pub view_name: ::prost::alloc::string::String,
+    #[prost(enumeration = "TransferFormat", tag = "3")]
+    pub format: i32,
+}
+/// 导出内容，每条包含若干完整行
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ResponseExport {
+    #[prost(enumeration = "super::utils::Status", tag = "1")]
+    pub status: i32,
+    #[prost(string, tag = "2")]
+    pub msg_err: ::prost::alloc::string::String,
+    #[prost(bytes = "vec", tag = "3")]
+    pub data: ::prost::alloc::vec::Vec<u8>,
+}
+/// 待导入行
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ImportRow {
+    /// 行在导入文件中的起始行号
+    #[prost(uint64, tag = "1")]
+    pub line: u64,
+    #[prost(string, tag = "2")]
+    pub text: ::prost::alloc::string::String,
+}
+/// 请求导入，同一导入流中各请求的库、视图、格式及是否覆盖以首个请求为准
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RequestImport {
+    #[prost(string, tag = "1")]
+    pub database_name: ::prost::alloc::string::String,
+    /// 导入的目标视图，为空则使用行中的视图名称
+    #[prost(string, tag = "2")]
+    pub view_name: ::prost::alloc::string::String,
+    #[prost(enumeration = "TransferFormat", tag = "3")]
+    pub format: i32,
+    /// 数据已存在时是否覆盖
+    #[prost(bool, tag = "4")]
+    pub overwrite: bool,
+    #[prost(message, repeated, tag = "5")]
+    pub rows: ::prost::alloc::vec::Vec<ImportRow>,
+}
+/// 导入失败行
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ImportError {
+    #[prost(uint64, tag = "1")]
+    pub line: u64,
+    #[prost(string, tag = "2")]
+    pub msg_err: ::prost::alloc::string::String,
+}
+/// 导入结果
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ResponseImport {
+    #[prost(enumeration = "super::utils::Status", tag = "1")]
+    pub status: i32,
+    #[prost(string, tag = "2")]
+    pub msg_err: ::prost::alloc::string::String,
+    /// 处理行数
+    #[prost(uint64, tag = "3")]
+    pub rows: u64,
+    /// 成功导入行数
+    #[prost(uint64, tag = "4")]
+    pub imported: u64,
+    /// 视图或索引已存在而跳过的行数
+    #[prost(uint64, tag = "5")]
+    pub skipped: u64,
+    /// 导入失败行数
+    #[prost(uint64, tag = "6")]
+    pub failed: u64,
+    /// 导入失败行，最多保留1000条
+    #[prost(message, repeated, tag = "7")]
+    pub errors: ::prost::alloc::vec::Vec<ImportError>,
+}
+/// 导出导入文件格式
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum TransferFormat {
+    /// 每行一个json对象
+    Jsonl = 0,
+    /// 逗号分隔，首列为行类型
+    Csv = 1,
+}
 #[doc = r" Generated client implementations."]
 pub mod user_service_client {
     #![allow(unused_variables, dead_code, missing_docs)]
@@ -754,7 +1567,7 @@ pub mod user_service_client {
         pub async fn login(
             &mut self,
             request: impl tonic::IntoRequest<super::RequestLogin>,
-        ) -> Result<tonic::Response<super::super::utils::Resp>, tonic::Status> {
+        ) -> Result<tonic::Response<super::ResponseLogin>, tonic::Status> {
             self.inner.ready().await.map_err(|e| {
                 tonic::Status::new(
                     tonic::Code::Unknown,
@@ -1221,6 +2034,21 @@ pub mod view_service_client {
             let path = http::uri::PathAndQuery::from_static("/db.ViewService/records");
             self.inner.unary(request.into_request(), path, codec).await
         }
+        #[doc = " 设置视图字段加密策略"]
+        pub async fn cipher(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RequestViewCipher>,
+        ) -> Result<tonic::Response<super::super::utils::Resp>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/db.ViewService/cipher");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
     }
 }
 #[doc = r" Generated client implementations."]
@@ -1480,6 +2308,26 @@ pub mod disk_service_client {
             let path = http::uri::PathAndQuery::from_static("/db.DiskService/select");
             self.inner.unary(request.into_request(), path, codec).await
         }
+        #[doc = " 条件检索，检索结果在检索过程中分批返回"]
+        pub async fn select_stream(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RequestDiskSelect>,
+        ) -> Result<
+            tonic::Response<tonic::codec::Streaming<super::ResponseDiskSelectStream>>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/db.DiskService/select_stream");
+            self.inner
+                .server_streaming(request.into_request(), path, codec)
+                .await
+        }
         #[doc = " 条件删除"]
         pub async fn delete(
             &mut self,
@@ -1495,6 +2343,57 @@ pub mod disk_service_client {
             let path = http::uri::PathAndQuery::from_static("/db.DiskService/delete");
             self.inner.unary(request.into_request(), path, codec).await
         }
+        #[doc = " 批量插入数据，如果存在则该数据项失败"]
+        pub async fn put_batch(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<Message = super::RequestDiskBatch>,
+        ) -> Result<tonic::Response<super::ResponseBatch>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/db.DiskService/put_batch");
+            self.inner
+                .client_streaming(request.into_streaming_request(), path, codec)
+                .await
+        }
+        #[doc = " 批量插入数据，无论存在与否都会插入或更新数据"]
+        pub async fn set_batch(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<Message = super::RequestDiskBatch>,
+        ) -> Result<tonic::Response<super::ResponseBatch>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/db.DiskService/set_batch");
+            self.inner
+                .client_streaming(request.into_streaming_request(), path, codec)
+                .await
+        }
+        #[doc = " 批量删除数据"]
+        pub async fn remove_batch(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<Message = super::RequestDiskBatch>,
+        ) -> Result<tonic::Response<super::ResponseBatch>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/db.DiskService/remove_batch");
+            self.inner
+                .client_streaming(request.into_streaming_request(), path, codec)
+                .await
+        }
     }
 }
 #[doc = r" Generated client implementations."]
@@ -1677,72 +2576,2042 @@ pub mod memory_service_client {
             let path = http::uri::PathAndQuery::from_static("/db.MemoryService/remove_by_page");
             self.inner.unary(request.into_request(), path, codec).await
         }
+        #[doc = " 批量插入数据，如果存在则该数据项失败"]
+        pub async fn put_batch(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<Message = super::RequestMemoryBatch>,
+        ) -> Result<tonic::Response<super::ResponseBatch>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/db.MemoryService/put_batch");
+            self.inner
+                .client_streaming(request.into_streaming_request(), path, codec)
+                .await
+        }
+        #[doc = " 批量插入数据，无论存在与否都会插入或更新数据"]
+        pub async fn set_batch(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<Message = super::RequestMemoryBatch>,
+        ) -> Result<tonic::Response<super::ResponseBatch>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/db.MemoryService/set_batch");
+            self.inner
+                .client_streaming(request.into_streaming_request(), path, codec)
+                .await
+        }
+        #[doc = " 批量删除数据"]
+        pub async fn remove_batch(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<Message = super::RequestMemoryBatch>,
+        ) -> Result<tonic::Response<super::ResponseBatch>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/db.MemoryService/remove_batch");
+            self.inner
+                .client_streaming(request.into_streaming_request(), path, codec)
+                .await
+        }
     }
 }
-#[doc = r" Generated server implementations."]
-pub mod user_service_server {
+#[doc = r" Generated client implementations."]
+pub mod audit_service_client {
     #![allow(unused_variables, dead_code, missing_docs)]
     use tonic::codegen::*;
-    #[doc = "Generated trait containing gRPC methods that should be implemented for use with UserServiceServer."]
-    #[async_trait]
-    pub trait UserService: Send + Sync + 'static {
-        #[doc = " 数据库用户登录"]
-        async fn login(
-            &self,
-            request: tonic::Request<super::RequestLogin>,
-        ) -> Result<tonic::Response<super::super::utils::Resp>, tonic::Status>;
+    #[derive(Debug, Clone)]
+    pub struct AuditServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
     }
-    #[derive(Debug)]
-    pub struct UserServiceServer<T: UserService> {
-        inner: _Inner<T>,
-        accept_compression_encodings: (),
-        send_compression_encodings: (),
+    impl AuditServiceClient<tonic::transport::Channel> {
+        #[doc = r" Attempt to create a new client by connecting to a given endpoint."]
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: std::convert::TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
     }
-    struct _Inner<T>(Arc<T>);
-    impl<T: UserService> UserServiceServer<T> {
+    impl<T> AuditServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::ResponseBody: Body + Send + Sync + 'static,
+        T::Error: Into<StdError>,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
         pub fn new(inner: T) -> Self {
-            let inner = Arc::new(inner);
-            let inner = _Inner(inner);
-            Self {
-                inner,
-                accept_compression_encodings: Default::default(),
-                send_compression_encodings: Default::default(),
-            }
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
         }
-        pub fn with_interceptor<F>(inner: T, interceptor: F) -> InterceptedService<Self, F>
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> AuditServiceClient<InterceptedService<T, F>>
         where
             F: FnMut(tonic::Request<()>) -> Result<tonic::Request<()>, tonic::Status>,
+            T: Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as Service<http::Request<tonic::body::BoxBody>>>::Error:
+                Into<StdError> + Send + Sync,
         {
-            InterceptedService::new(Self::new(inner), interceptor)
+            AuditServiceClient::new(InterceptedService::new(inner, interceptor))
         }
-    }
-    impl<T, B> Service<http::Request<B>> for UserServiceServer<T>
-    where
-        T: UserService,
-        B: Body + Send + Sync + 'static,
-        B::Error: Into<StdError> + Send + 'static,
-    {
-        type Response = http::Response<tonic::body::BoxBody>;
-        type Error = Never;
-        type Future = BoxFuture<Self::Response, Self::Error>;
-        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
-            Poll::Ready(Ok(()))
+        #[doc = r" Compress requests with `gzip`."]
+        #[doc = r""]
+        #[doc = r" This requires the server to support it otherwise it might respond with an"]
+        #[doc = r" error."]
+        pub fn send_gzip(mut self) -> Self {
+            self.inner = self.inner.send_gzip();
+            self
         }
-        fn call(&mut self, req: http::Request<B>) -> Self::Future {
-            let inner = self.inner.clone();
+        #[doc = r" Enable decompressing responses with `gzip`."]
+        pub fn accept_gzip(mut self) -> Self {
+            self.inner = self.inner.accept_gzip();
+            self
+        }
+        #[doc = " 审计记录集合"]
+        pub async fn list(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RequestAuditList>,
+        ) -> Result<tonic::Response<super::ResponseAuditList>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/db.AuditService/list");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        #[doc = " 校验审计链完整性"]
+        pub async fn verify(
+            &mut self,
+            request: impl tonic::IntoRequest<super::super::utils::Req>,
+        ) -> Result<tonic::Response<super::ResponseAuditVerify>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/db.AuditService/verify");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+    }
+}
+#[doc = r" Generated client implementations."]
+pub mod quota_service_client {
+    #![allow(unused_variables, dead_code, missing_docs)]
+    use tonic::codegen::*;
+    #[derive(Debug, Clone)]
+    pub struct QuotaServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl QuotaServiceClient<tonic::transport::Channel> {
+        #[doc = r" Attempt to create a new client by connecting to a given endpoint."]
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: std::convert::TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> QuotaServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::ResponseBody: Body + Send + Sync + 'static,
+        T::Error: Into<StdError>,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> QuotaServiceClient<InterceptedService<T, F>>
+        where
+            F: FnMut(tonic::Request<()>) -> Result<tonic::Request<()>, tonic::Status>,
+            T: Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as Service<http::Request<tonic::body::BoxBody>>>::Error:
+                Into<StdError> + Send + Sync,
+        {
+            QuotaServiceClient::new(InterceptedService::new(inner, interceptor))
+        }
+        #[doc = r" Compress requests with `gzip`."]
+        #[doc = r""]
+        #[doc = r" This requires the server to support it otherwise it might respond with an"]
+        #[doc = r" error."]
+        pub fn send_gzip(mut self) -> Self {
+            self.inner = self.inner.send_gzip();
+            self
+        }
+        #[doc = r" Enable decompressing responses with `gzip`."]
+        pub fn accept_gzip(mut self) -> Self {
+            self.inner = self.inner.accept_gzip();
+            self
+        }
+        #[doc = " 设置配额策略，已存在则覆盖，所有限制项都为0则移除策略"]
+        pub async fn set(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RequestQuotaSet>,
+        ) -> Result<tonic::Response<super::super::utils::Resp>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/db.QuotaService/set");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        #[doc = " 配额策略集合"]
+        pub async fn list(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RequestQuotaList>,
+        ) -> Result<tonic::Response<super::ResponseQuotaList>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/db.QuotaService/list");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+    }
+}
+#[doc = r" Generated client implementations."]
+pub mod backup_service_client {
+    #![allow(unused_variables, dead_code, missing_docs)]
+    use tonic::codegen::*;
+    #[derive(Debug, Clone)]
+    pub struct BackupServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl BackupServiceClient<tonic::transport::Channel> {
+        #[doc = r" Attempt to create a new client by connecting to a given endpoint."]
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: std::convert::TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> BackupServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::ResponseBody: Body + Send + Sync + 'static,
+        T::Error: Into<StdError>,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> BackupServiceClient<InterceptedService<T, F>>
+        where
+            F: FnMut(tonic::Request<()>) -> Result<tonic::Request<()>, tonic::Status>,
+            T: Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as Service<http::Request<tonic::body::BoxBody>>>::Error:
+                Into<StdError> + Send + Sync,
+        {
+            BackupServiceClient::new(InterceptedService::new(inner, interceptor))
+        }
+        #[doc = r" Compress requests with `gzip`."]
+        #[doc = r""]
+        #[doc = r" This requires the server to support it otherwise it might respond with an"]
+        #[doc = r" error."]
+        pub fn send_gzip(mut self) -> Self {
+            self.inner = self.inner.send_gzip();
+            self
+        }
+        #[doc = r" Enable decompressing responses with `gzip`."]
+        pub fn accept_gzip(mut self) -> Self {
+            self.inner = self.inner.accept_gzip();
+            self
+        }
+        #[doc = " 在写入持续进行的同时将数据一致地备份到服务端指定目录"]
+        pub async fn backup(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RequestBackup>,
+        ) -> Result<tonic::Response<super::ResponseBackup>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/db.BackupService/backup");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+    }
+}
+#[doc = r" Generated client implementations."]
+pub mod transfer_service_client {
+    #![allow(unused_variables, dead_code, missing_docs)]
+    use tonic::codegen::*;
+    #[derive(Debug, Clone)]
+    pub struct TransferServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl TransferServiceClient<tonic::transport::Channel> {
+        #[doc = r" Attempt to create a new client by connecting to a given endpoint."]
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: std::convert::TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> TransferServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::ResponseBody: Body + Send + Sync + 'static,
+        T::Error: Into<StdError>,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> TransferServiceClient<InterceptedService<T, F>>
+        where
+            F: FnMut(tonic::Request<()>) -> Result<tonic::Request<()>, tonic::Status>,
+            T: Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as Service<http::Request<tonic::body::BoxBody>>>::Error:
+                Into<StdError> + Send + Sync,
+        {
+            TransferServiceClient::new(InterceptedService::new(inner, interceptor))
+        }
+        #[doc = r" Compress requests with `gzip`."]
+        #[doc = r""]
+        #[doc = r" This requires the server to support it otherwise it might respond with an"]
+        #[doc = r" error."]
+        pub fn send_gzip(mut self) -> Self {
+            self.inner = self.inner.send_gzip();
+            self
+        }
+        #[doc = r" Enable decompressing responses with `gzip`."]
+        pub fn accept_gzip(mut self) -> Self {
+            self.inner = self.inner.accept_gzip();
+            self
+        }
+        #[doc = " 以JSON Lines或CSV格式流式导出视图或整库的视图定义、索引定义及数据"]
+        pub async fn export(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RequestExport>,
+        ) -> Result<tonic::Response<tonic::codec::Streaming<super::ResponseExport>>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/db.TransferService/export");
+            self.inner
+                .server_streaming(request.into_request(), path, codec)
+                .await
+        }
+        #[doc = " 流式分批导入，逐行返回导入失败原因"]
+        pub async fn import(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<Message = super::RequestImport>,
+        ) -> Result<tonic::Response<super::ResponseImport>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/db.TransferService/import");
+            self.inner
+                .client_streaming(request.into_streaming_request(), path, codec)
+                .await
+        }
+    }
+}
+#[doc = r" Generated client implementations."]
+pub mod replication_service_client {
+    #![allow(unused_variables, dead_code, missing_docs)]
+    use tonic::codegen::*;
+    #[derive(Debug, Clone)]
+    pub struct ReplicationServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl ReplicationServiceClient<tonic::transport::Channel> {
+        #[doc = r" Attempt to create a new client by connecting to a given endpoint."]
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: std::convert::TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> ReplicationServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::ResponseBody: Body + Send + Sync + 'static,
+        T::Error: Into<StdError>,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> ReplicationServiceClient<InterceptedService<T, F>>
+        where
+            F: FnMut(tonic::Request<()>) -> Result<tonic::Request<()>, tonic::Status>,
+            T: Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as Service<http::Request<tonic::body::BoxBody>>>::Error:
+                Into<StdError> + Send + Sync,
+        {
+            ReplicationServiceClient::new(InterceptedService::new(inner, interceptor))
+        }
+        #[doc = r" Compress requests with `gzip`."]
+        #[doc = r""]
+        #[doc = r" This requires the server to support it otherwise it might respond with an"]
+        #[doc = r" error."]
+        pub fn send_gzip(mut self) -> Self {
+            self.inner = self.inner.send_gzip();
+            self
+        }
+        #[doc = r" Enable decompressing responses with `gzip`."]
+        pub fn accept_gzip(mut self) -> Self {
+            self.inner = self.inner.accept_gzip();
+            self
+        }
+        #[doc = " 从节点跟随主节点，主节点持续推送指定序号之后的变更"]
+        pub async fn follow(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RequestReplicationFollow>,
+        ) -> Result<
+            tonic::Response<tonic::codec::Streaming<super::ResponseReplicationFollow>>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/db.ReplicationService/follow");
+            self.inner
+                .server_streaming(request.into_request(), path, codec)
+                .await
+        }
+        #[doc = " 从节点拉取主节点快照，用于空数据从节点的初始同步"]
+        pub async fn snapshot(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RequestReplicationSnapshot>,
+        ) -> Result<
+            tonic::Response<tonic::codec::Streaming<super::ResponseReplicationSnapshot>>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/db.ReplicationService/snapshot");
+            self.inner
+                .server_streaming(request.into_request(), path, codec)
+                .await
+        }
+        #[doc = " 复制状态，包括角色及复制延迟"]
+        pub async fn status(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RequestReplicationStatus>,
+        ) -> Result<tonic::Response<super::ResponseReplicationStatus>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/db.ReplicationService/status");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        #[doc = " 将从节点提升为主节点"]
+        pub async fn promote(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RequestReplicationPromote>,
+        ) -> Result<tonic::Response<super::ResponseReplicationPromote>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/db.ReplicationService/promote");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+    }
+}
+#[doc = r" Generated client implementations."]
+pub mod cluster_service_client {
+    #![allow(unused_variables, dead_code, missing_docs)]
+    use tonic::codegen::*;
+    #[derive(Debug, Clone)]
+    pub struct ClusterServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl ClusterServiceClient<tonic::transport::Channel> {
+        #[doc = r" Attempt to create a new client by connecting to a given endpoint."]
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: std::convert::TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> ClusterServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::ResponseBody: Body + Send + Sync + 'static,
+        T::Error: Into<StdError>,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> ClusterServiceClient<InterceptedService<T, F>>
+        where
+            F: FnMut(tonic::Request<()>) -> Result<tonic::Request<()>, tonic::Status>,
+            T: Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as Service<http::Request<tonic::body::BoxBody>>>::Error:
+                Into<StdError> + Send + Sync,
+        {
+            ClusterServiceClient::new(InterceptedService::new(inner, interceptor))
+        }
+        #[doc = r" Compress requests with `gzip`."]
+        #[doc = r""]
+        #[doc = r" This requires the server to support it otherwise it might respond with an"]
+        #[doc = r" error."]
+        pub fn send_gzip(mut self) -> Self {
+            self.inner = self.inner.send_gzip();
+            self
+        }
+        #[doc = r" Enable decompressing responses with `gzip`."]
+        pub fn accept_gzip(mut self) -> Self {
+            self.inner = self.inner.accept_gzip();
+            self
+        }
+        #[doc = " 候选者请求投票"]
+        pub async fn vote(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RequestClusterVote>,
+        ) -> Result<tonic::Response<super::ResponseClusterVote>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/db.ClusterService/vote");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        #[doc = " 领导者复制日志及心跳"]
+        pub async fn append(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RequestClusterAppend>,
+        ) -> Result<tonic::Response<super::ResponseClusterAppend>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/db.ClusterService/append");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        #[doc = " 非领导者节点将写入转发至领导者"]
+        pub async fn forward(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RequestClusterForward>,
+        ) -> Result<tonic::Response<super::ResponseClusterForward>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/db.ClusterService/forward");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        #[doc = " 领导者向所需条目已被丢弃的跟随者分块发送快照"]
+        pub async fn install(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RequestClusterInstall>,
+        ) -> Result<tonic::Response<super::ResponseClusterInstall>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/db.ClusterService/install");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        #[doc = " 集群状态，包括角色、任期、提交进度及节点集合"]
+        pub async fn status(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RequestClusterStatus>,
+        ) -> Result<tonic::Response<super::ResponseClusterStatus>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/db.ClusterService/status");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        #[doc = " 新增节点"]
+        pub async fn join(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RequestClusterJoin>,
+        ) -> Result<tonic::Response<super::ResponseClusterJoin>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/db.ClusterService/join");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        #[doc = " 移除节点"]
+        pub async fn leave(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RequestClusterLeave>,
+        ) -> Result<tonic::Response<super::ResponseClusterLeave>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/db.ClusterService/leave");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+    }
+}
+#[doc = r" Generated client implementations."]
+pub mod ledger_service_client {
+    #![allow(unused_variables, dead_code, missing_docs)]
+    use tonic::codegen::*;
+    #[derive(Debug, Clone)]
+    pub struct LedgerServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl LedgerServiceClient<tonic::transport::Channel> {
+        #[doc = r" Attempt to create a new client by connecting to a given endpoint."]
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: std::convert::TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> LedgerServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::ResponseBody: Body + Send + Sync + 'static,
+        T::Error: Into<StdError>,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> LedgerServiceClient<InterceptedService<T, F>>
+        where
+            F: FnMut(tonic::Request<()>) -> Result<tonic::Request<()>, tonic::Status>,
+            T: Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as Service<http::Request<tonic::body::BoxBody>>>::Error:
+                Into<StdError> + Send + Sync,
+        {
+            LedgerServiceClient::new(InterceptedService::new(inner, interceptor))
+        }
+        #[doc = r" Compress requests with `gzip`."]
+        #[doc = r""]
+        #[doc = r" This requires the server to support it otherwise it might respond with an"]
+        #[doc = r" error."]
+        pub fn send_gzip(mut self) -> Self {
+            self.inner = self.inner.send_gzip();
+            self
+        }
+        #[doc = r" Enable decompressing responses with `gzip`."]
+        pub fn accept_gzip(mut self) -> Self {
+            self.inner = self.inner.accept_gzip();
+            self
+        }
+        #[doc = " 账本集合"]
+        pub async fn list(
+            &mut self,
+            request: impl tonic::IntoRequest<super::super::utils::Req>,
+        ) -> Result<tonic::Response<super::ResponseLedgerList>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/db.LedgerService/list");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        #[doc = " 创建账本"]
+        pub async fn create(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RequestLedgerCreate>,
+        ) -> Result<tonic::Response<super::super::utils::Resp>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/db.LedgerService/create");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        #[doc = " 获取账本详情"]
+        pub async fn info(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RequestLedgerInfo>,
+        ) -> Result<tonic::Response<super::ResponseLedgerInfo>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/db.LedgerService/info");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        #[doc = " 删除账本"]
+        pub async fn remove(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RequestLedgerRemove>,
+        ) -> Result<tonic::Response<super::super::utils::Resp>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/db.LedgerService/remove");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        #[doc = " 将交易集合打包为新区块追加至账本"]
+        pub async fn insert(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RequestBlockInsert>,
+        ) -> Result<tonic::Response<super::ResponseBlock>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/db.LedgerService/insert");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        #[doc = " 根据区块高度或hash获取区块"]
+        pub async fn block(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RequestBlockGet>,
+        ) -> Result<tonic::Response<super::ResponseBlock>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/db.LedgerService/block");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        #[doc = " 根据交易hash获取交易及所在区块"]
+        pub async fn transaction(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RequestTransactionGet>,
+        ) -> Result<tonic::Response<super::ResponseTransaction>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/db.LedgerService/transaction");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+    }
+}
+#[doc = r" Generated server implementations."]
+pub mod user_service_server {
+    #![allow(unused_variables, dead_code, missing_docs)]
+    use tonic::codegen::*;
+    #[doc = "Generated trait containing gRPC methods that should be implemented for use with UserServiceServer."]
+    #[async_trait]
+    pub trait UserService: Send + Sync + 'static {
+        #[doc = " 数据库用户登录"]
+        async fn login(
+            &self,
+            request: tonic::Request<super::RequestLogin>,
+        ) -> Result<tonic::Response<super::ResponseLogin>, tonic::Status>;
+    }
+    #[derive(Debug)]
+    pub struct UserServiceServer<T: UserService> {
+        inner: _Inner<T>,
+        accept_compression_encodings: (),
+        send_compression_encodings: (),
+    }
+    struct _Inner<T>(Arc<T>);
+    impl<T: UserService> UserServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            let inner = Arc::new(inner);
+            let inner = _Inner(inner);
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+            }
+        }
+        pub fn with_interceptor<F>(inner: T, interceptor: F) -> InterceptedService<Self, F>
+        where
+            F: FnMut(tonic::Request<()>) -> Result<tonic::Request<()>, tonic::Status>,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+    }
+    impl<T, B> Service<http::Request<B>> for UserServiceServer<T>
+    where
+        T: UserService,
+        B: Body + Send + Sync + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = Never;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let inner = self.inner.clone();
+            match req.uri().path() {
+                "/db.UserService/login" => {
+                    #[allow(non_camel_case_types)]
+                    struct loginSvc<T: UserService>(pub Arc<T>);
+                    impl<T: UserService> tonic::server::UnaryService<super::RequestLogin> for loginSvc<T> {
+                        type Response = super::ResponseLogin;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RequestLogin>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).login(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = loginSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
+                            accept_compression_encodings,
+                            send_compression_encodings,
+                        );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => Box::pin(async move {
+                    Ok(http::Response::builder()
+                        .status(200)
+                        .header("grpc-status", "12")
+                        .header("content-type", "application/grpc")
+                        .body(empty_body())
+                        .unwrap())
+                }),
+            }
+        }
+    }
+    impl<T: UserService> Clone for UserServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+            }
+        }
+    }
+    impl<T: UserService> Clone for _Inner<T> {
+        fn clone(&self) -> Self {
+            Self(self.0.clone())
+        }
+    }
+    impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:?}", self.0)
+        }
+    }
+    impl<T: UserService> tonic::transport::NamedService for UserServiceServer<T> {
+        const NAME: &'static str = "db.UserService";
+    }
+}
+#[doc = r" Generated server implementations."]
+pub mod page_service_server {
+    #![allow(unused_variables, dead_code, missing_docs)]
+    use tonic::codegen::*;
+    #[doc = "Generated trait containing gRPC methods that should be implemented for use with PageServiceServer."]
+    #[async_trait]
+    pub trait PageService: Send + Sync + 'static {
+        #[doc = " 缓存页集合"]
+        async fn list(
+            &self,
+            request: tonic::Request<super::super::utils::Req>,
+        ) -> Result<tonic::Response<super::ResponsePageList>, tonic::Status>;
+        #[doc = " 创建缓存页"]
+        async fn create(
+            &self,
+            request: tonic::Request<super::RequestPageCreate>,
+        ) -> Result<tonic::Response<super::super::utils::Resp>, tonic::Status>;
+        #[doc = " 修改缓存页"]
+        async fn modify(
+            &self,
+            request: tonic::Request<super::RequestPageModify>,
+        ) -> Result<tonic::Response<super::super::utils::Resp>, tonic::Status>;
+        #[doc = " 获取缓存页详情"]
+        async fn info(
+            &self,
+            request: tonic::Request<super::RequestPageInfo>,
+        ) -> Result<tonic::Response<super::ResponsePageInfo>, tonic::Status>;
+        #[doc = " 删除缓存页"]
+        async fn remove(
+            &self,
+            request: tonic::Request<super::RequestPageRemove>,
+        ) -> Result<tonic::Response<super::super::utils::Resp>, tonic::Status>;
+    }
+    #[derive(Debug)]
+    pub struct PageServiceServer<T: PageService> {
+        inner: _Inner<T>,
+        accept_compression_encodings: (),
+        send_compression_encodings: (),
+    }
+    struct _Inner<T>(Arc<T>);
+    impl<T: PageService> PageServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            let inner = Arc::new(inner);
+            let inner = _Inner(inner);
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+            }
+        }
+        pub fn with_interceptor<F>(inner: T, interceptor: F) -> InterceptedService<Self, F>
+        where
+            F: FnMut(tonic::Request<()>) -> Result<tonic::Request<()>, tonic::Status>,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+    }
+    impl<T, B> Service<http::Request<B>> for PageServiceServer<T>
+    where
+        T: PageService,
+        B: Body + Send + Sync + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = Never;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let inner = self.inner.clone();
+            match req.uri().path() {
+                "/db.PageService/list" => {
+                    #[allow(non_camel_case_types)]
+                    struct listSvc<T: PageService>(pub Arc<T>);
+                    impl<T: PageService> tonic::server::UnaryService<super::super::utils::Req> for listSvc<T> {
+                        type Response = super::ResponsePageList;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::super::utils::Req>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).list(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = listSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
+                            accept_compression_encodings,
+                            send_compression_encodings,
+                        );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/db.PageService/create" => {
+                    #[allow(non_camel_case_types)]
+                    struct createSvc<T: PageService>(pub Arc<T>);
+                    impl<T: PageService> tonic::server::UnaryService<super::RequestPageCreate> for createSvc<T> {
+                        type Response = super::super::utils::Resp;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RequestPageCreate>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).create(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = createSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
+                            accept_compression_encodings,
+                            send_compression_encodings,
+                        );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/db.PageService/modify" => {
+                    #[allow(non_camel_case_types)]
+                    struct modifySvc<T: PageService>(pub Arc<T>);
+                    impl<T: PageService> tonic::server::UnaryService<super::RequestPageModify> for modifySvc<T> {
+                        type Response = super::super::utils::Resp;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RequestPageModify>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).modify(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = modifySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
+                            accept_compression_encodings,
+                            send_compression_encodings,
+                        );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/db.PageService/info" => {
+                    #[allow(non_camel_case_types)]
+                    struct infoSvc<T: PageService>(pub Arc<T>);
+                    impl<T: PageService> tonic::server::UnaryService<super::RequestPageInfo> for infoSvc<T> {
+                        type Response = super::ResponsePageInfo;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RequestPageInfo>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).info(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = infoSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
+                            accept_compression_encodings,
+                            send_compression_encodings,
+                        );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/db.PageService/remove" => {
+                    #[allow(non_camel_case_types)]
+                    struct removeSvc<T: PageService>(pub Arc<T>);
+                    impl<T: PageService> tonic::server::UnaryService<super::RequestPageRemove> for removeSvc<T> {
+                        type Response = super::super::utils::Resp;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RequestPageRemove>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).remove(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = removeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
+                            accept_compression_encodings,
+                            send_compression_encodings,
+                        );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => Box::pin(async move {
+                    Ok(http::Response::builder()
+                        .status(200)
+                        .header("grpc-status", "12")
+                        .header("content-type", "application/grpc")
+                        .body(empty_body())
+                        .unwrap())
+                }),
+            }
+        }
+    }
+    impl<T: PageService> Clone for PageServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+            }
+        }
+    }
+    impl<T: PageService> Clone for _Inner<T> {
+        fn clone(&self) -> Self {
+            Self(self.0.clone())
+        }
+    }
+    impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:?}", self.0)
+        }
+    }
+    impl<T: PageService> tonic::transport::NamedService for PageServiceServer<T> {
+        const NAME: &'static str = "db.PageService";
+    }
+}
+#[doc = r" Generated server implementations."]
+pub mod database_service_server {
+    #![allow(unused_variables, dead_code, missing_docs)]
+    use tonic::codegen::*;
+    #[doc = "Generated trait containing gRPC methods that should be implemented for use with DatabaseServiceServer."]
+    #[async_trait]
+    pub trait DatabaseService: Send + Sync + 'static {
+        #[doc = " 数据库集合"]
+        async fn list(
+            &self,
+            request: tonic::Request<super::super::utils::Req>,
+        ) -> Result<tonic::Response<super::ResponseDatabaseList>, tonic::Status>;
+        #[doc = " 创建数据库"]
+        async fn create(
+            &self,
+            request: tonic::Request<super::RequestDatabaseCreate>,
+        ) -> Result<tonic::Response<super::super::utils::Resp>, tonic::Status>;
+        #[doc = " 修改数据库"]
+        async fn modify(
+            &self,
+            request: tonic::Request<super::RequestDatabaseModify>,
+        ) -> Result<tonic::Response<super::super::utils::Resp>, tonic::Status>;
+        #[doc = " 获取数据库详情"]
+        async fn info(
+            &self,
+            request: tonic::Request<super::RequestDatabaseInfo>,
+        ) -> Result<tonic::Response<super::ResponseDatabaseInfo>, tonic::Status>;
+        #[doc = " 删除数据库"]
+        async fn remove(
+            &self,
+            request: tonic::Request<super::RequestDatabaseRemove>,
+        ) -> Result<tonic::Response<super::super::utils::Resp>, tonic::Status>;
+    }
+    #[derive(Debug)]
+    pub struct DatabaseServiceServer<T: DatabaseService> {
+        inner: _Inner<T>,
+        accept_compression_encodings: (),
+        send_compression_encodings: (),
+    }
+    struct _Inner<T>(Arc<T>);
+    impl<T: DatabaseService> DatabaseServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            let inner = Arc::new(inner);
+            let inner = _Inner(inner);
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+            }
+        }
+        pub fn with_interceptor<F>(inner: T, interceptor: F) -> InterceptedService<Self, F>
+        where
+            F: FnMut(tonic::Request<()>) -> Result<tonic::Request<()>, tonic::Status>,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+    }
+    impl<T, B> Service<http::Request<B>> for DatabaseServiceServer<T>
+    where
+        T: DatabaseService,
+        B: Body + Send + Sync + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = Never;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let inner = self.inner.clone();
+            match req.uri().path() {
+                "/db.DatabaseService/list" => {
+                    #[allow(non_camel_case_types)]
+                    struct listSvc<T: DatabaseService>(pub Arc<T>);
+                    impl<T: DatabaseService> tonic::server::UnaryService<super::super::utils::Req> for listSvc<T> {
+                        type Response = super::ResponseDatabaseList;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::super::utils::Req>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).list(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = listSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
+                            accept_compression_encodings,
+                            send_compression_encodings,
+                        );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/db.DatabaseService/create" => {
+                    #[allow(non_camel_case_types)]
+                    struct createSvc<T: DatabaseService>(pub Arc<T>);
+                    impl<T: DatabaseService>
+                        tonic::server::UnaryService<super::RequestDatabaseCreate> for createSvc<T>
+                    {
+                        type Response = super::super::utils::Resp;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RequestDatabaseCreate>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).create(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = createSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
+                            accept_compression_encodings,
+                            send_compression_encodings,
+                        );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/db.DatabaseService/modify" => {
+                    #[allow(non_camel_case_types)]
+                    struct modifySvc<T: DatabaseService>(pub Arc<T>);
+                    impl<T: DatabaseService>
+                        tonic::server::UnaryService<super::RequestDatabaseModify> for modifySvc<T>
+                    {
+                        type Response = super::super::utils::Resp;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RequestDatabaseModify>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).modify(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = modifySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
+                            accept_compression_encodings,
+                            send_compression_encodings,
+                        );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/db.DatabaseService/info" => {
+                    #[allow(non_camel_case_types)]
+                    struct infoSvc<T: DatabaseService>(pub Arc<T>);
+                    impl<T: DatabaseService> tonic::server::UnaryService<super::RequestDatabaseInfo> for infoSvc<T> {
+                        type Response = super::ResponseDatabaseInfo;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RequestDatabaseInfo>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).info(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = infoSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
+                            accept_compression_encodings,
+                            send_compression_encodings,
+                        );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/db.DatabaseService/remove" => {
+                    #[allow(non_camel_case_types)]
+                    struct removeSvc<T: DatabaseService>(pub Arc<T>);
+                    impl<T: DatabaseService>
+                        tonic::server::UnaryService<super::RequestDatabaseRemove> for removeSvc<T>
+                    {
+                        type Response = super::super::utils::Resp;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RequestDatabaseRemove>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).remove(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = removeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
+                            accept_compression_encodings,
+                            send_compression_encodings,
+                        );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => Box::pin(async move {
+                    Ok(http::Response::builder()
+                        .status(200)
+                        .header("grpc-status", "12")
+                        .header("content-type", "application/grpc")
+                        .body(empty_body())
+                        .unwrap())
+                }),
+            }
+        }
+    }
+    impl<T: DatabaseService> Clone for DatabaseServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+            }
+        }
+    }
+    impl<T: DatabaseService> Clone for _Inner<T> {
+        fn clone(&self) -> Self {
+            Self(self.0.clone())
+        }
+    }
+    impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:?}", self.0)
+        }
+    }
+    impl<T: DatabaseService> tonic::transport::NamedService for DatabaseServiceServer<T> {
+        const NAME: &'static str = "db.DatabaseService";
+    }
+}
+#[doc = r" Generated server implementations."]
+pub mod view_service_server {
+    #![allow(unused_variables, dead_code, missing_docs)]
+    use tonic::codegen::*;
+    #[doc = "Generated trait containing gRPC methods that should be implemented for use with ViewServiceServer."]
+    #[async_trait]
+    pub trait ViewService: Send + Sync + 'static {
+        #[doc = " 视图集合"]
+        async fn list(
+            &self,
+            request: tonic::Request<super::RequestViewList>,
+        ) -> Result<tonic::Response<super::ResponseViewList>, tonic::Status>;
+        #[doc = " 创建视图"]
+        async fn create(
+            &self,
+            request: tonic::Request<super::RequestViewCreate>,
+        ) -> Result<tonic::Response<super::super::utils::Resp>, tonic::Status>;
+        #[doc = " 修改视图"]
+        async fn modify(
+            &self,
+            request: tonic::Request<super::RequestViewModify>,
+        ) -> Result<tonic::Response<super::super::utils::Resp>, tonic::Status>;
+        #[doc = " 获取视图详情"]
+        async fn info(
+            &self,
+            request: tonic::Request<super::RequestViewInfo>,
+        ) -> Result<tonic::Response<super::ResponseViewInfo>, tonic::Status>;
+        #[doc = " 删除视图"]
+        async fn remove(
+            &self,
+            request: tonic::Request<super::RequestViewRemove>,
+        ) -> Result<tonic::Response<super::super::utils::Resp>, tonic::Status>;
+        #[doc = " 整理归档"]
+        async fn archive(
+            &self,
+            request: tonic::Request<super::RequestViewArchive>,
+        ) -> Result<tonic::Response<super::super::utils::Resp>, tonic::Status>;
+        #[doc = " 读取指定归档版本信息"]
+        async fn record(
+            &self,
+            request: tonic::Request<super::RequestViewRecord>,
+        ) -> Result<tonic::Response<super::ResponseViewRecord>, tonic::Status>;
+        #[doc = " 读取所有归档版本信息"]
+        async fn records(
+            &self,
+            request: tonic::Request<super::RequestViewRecords>,
+        ) -> Result<tonic::Response<super::ResponseViewRecords>, tonic::Status>;
+        #[doc = " 设置视图字段加密策略"]
+        async fn cipher(
+            &self,
+            request: tonic::Request<super::RequestViewCipher>,
+        ) -> Result<tonic::Response<super::super::utils::Resp>, tonic::Status>;
+    }
+    #[derive(Debug)]
+    pub struct ViewServiceServer<T: ViewService> {
+        inner: _Inner<T>,
+        accept_compression_encodings: (),
+        send_compression_encodings: (),
+    }
+    struct _Inner<T>(Arc<T>);
+    impl<T: ViewService> ViewServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            let inner = Arc::new(inner);
+            let inner = _Inner(inner);
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+            }
+        }
+        pub fn with_interceptor<F>(inner: T, interceptor: F) -> InterceptedService<Self, F>
+        where
+            F: FnMut(tonic::Request<()>) -> Result<tonic::Request<()>, tonic::Status>,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+    }
+    impl<T, B> Service<http::Request<B>> for ViewServiceServer<T>
+    where
+        T: ViewService,
+        B: Body + Send + Sync + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = Never;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let inner = self.inner.clone();
+            match req.uri().path() {
+                "/db.ViewService/list" => {
+                    #[allow(non_camel_case_types)]
+                    struct listSvc<T: ViewService>(pub Arc<T>);
+                    impl<T: ViewService> tonic::server::UnaryService<super::RequestViewList> for listSvc<T> {
+                        type Response = super::ResponseViewList;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RequestViewList>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).list(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = listSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
+                            accept_compression_encodings,
+                            send_compression_encodings,
+                        );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/db.ViewService/create" => {
+                    #[allow(non_camel_case_types)]
+                    struct createSvc<T: ViewService>(pub Arc<T>);
+                    impl<T: ViewService> tonic::server::UnaryService<super::RequestViewCreate> for createSvc<T> {
+                        type Response = super::super::utils::Resp;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RequestViewCreate>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).create(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = createSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
+                            accept_compression_encodings,
+                            send_compression_encodings,
+                        );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/db.ViewService/modify" => {
+                    #[allow(non_camel_case_types)]
+                    struct modifySvc<T: ViewService>(pub Arc<T>);
+                    impl<T: ViewService> tonic::server::UnaryService<super::RequestViewModify> for modifySvc<T> {
+                        type Response = super::super::utils::Resp;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RequestViewModify>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).modify(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = modifySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
+                            accept_compression_encodings,
+                            send_compression_encodings,
+                        );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/db.ViewService/info" => {
+                    #[allow(non_camel_case_types)]
+                    struct infoSvc<T: ViewService>(pub Arc<T>);
+                    impl<T: ViewService> tonic::server::UnaryService<super::RequestViewInfo> for infoSvc<T> {
+                        type Response = super::ResponseViewInfo;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RequestViewInfo>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).info(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = infoSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
+                            accept_compression_encodings,
+                            send_compression_encodings,
+                        );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/db.ViewService/remove" => {
+                    #[allow(non_camel_case_types)]
+                    struct removeSvc<T: ViewService>(pub Arc<T>);
+                    impl<T: ViewService> tonic::server::UnaryService<super::RequestViewRemove> for removeSvc<T> {
+                        type Response = super::super::utils::Resp;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RequestViewRemove>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).remove(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = removeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
+                            accept_compression_encodings,
+                            send_compression_encodings,
+                        );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/db.ViewService/archive" => {
+                    #[allow(non_camel_case_types)]
+                    struct archiveSvc<T: ViewService>(pub Arc<T>);
+                    impl<T: ViewService> tonic::server::UnaryService<super::RequestViewArchive> for archiveSvc<T> {
+                        type Response = super::super::utils::Resp;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RequestViewArchive>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).archive(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = archiveSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
+                            accept_compression_encodings,
+                            send_compression_encodings,
+                        );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/db.ViewService/record" => {
+                    #[allow(non_camel_case_types)]
+                    struct recordSvc<T: ViewService>(pub Arc<T>);
+                    impl<T: ViewService> tonic::server::UnaryService<super::RequestViewRecord> for recordSvc<T> {
+                        type Response = super::ResponseViewRecord;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RequestViewRecord>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).record(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = recordSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
+                            accept_compression_encodings,
+                            send_compression_encodings,
+                        );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/db.ViewService/records" => {
+                    #[allow(non_camel_case_types)]
+                    struct recordsSvc<T: ViewService>(pub Arc<T>);
+                    impl<T: ViewService> tonic::server::UnaryService<super::RequestViewRecords> for recordsSvc<T> {
+                        type Response = super::ResponseViewRecords;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RequestViewRecords>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).records(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = recordsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
+                            accept_compression_encodings,
+                            send_compression_encodings,
+                        );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/db.ViewService/cipher" => {
+                    #[allow(non_camel_case_types)]
+                    struct cipherSvc<T: ViewService>(pub Arc<T>);
+                    impl<T: ViewService> tonic::server::UnaryService<super::RequestViewCipher> for cipherSvc<T> {
+                        type Response = super::super::utils::Resp;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RequestViewCipher>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).cipher(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = cipherSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
+                            accept_compression_encodings,
+                            send_compression_encodings,
+                        );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => Box::pin(async move {
+                    Ok(http::Response::builder()
+                        .status(200)
+                        .header("grpc-status", "12")
+                        .header("content-type", "application/grpc")
+                        .body(empty_body())
+                        .unwrap())
+                }),
+            }
+        }
+    }
+    impl<T: ViewService> Clone for ViewServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+            }
+        }
+    }
+    impl<T: ViewService> Clone for _Inner<T> {
+        fn clone(&self) -> Self {
+            Self(self.0.clone())
+        }
+    }
+    impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:?}", self.0)
+        }
+    }
+    impl<T: ViewService> tonic::transport::NamedService for ViewServiceServer<T> {
+        const NAME: &'static str = "db.ViewService";
+    }
+}
+#[doc = r" Generated server implementations."]
+pub mod index_service_server {
+    #![allow(unused_variables, dead_code, missing_docs)]
+    use tonic::codegen::*;
+    #[doc = "Generated trait containing gRPC methods that should be implemented for use with IndexServiceServer."]
+    #[async_trait]
+    pub trait IndexService: Send + Sync + 'static {
+        #[doc = " 索引集合"]
+        async fn list(
+            &self,
+            request: tonic::Request<super::RequestIndexList>,
+        ) -> Result<tonic::Response<super::ResponseIndexList>, tonic::Status>;
+        #[doc = " 创建索引"]
+        async fn create(
+            &self,
+            request: tonic::Request<super::RequestIndexCreate>,
+        ) -> Result<tonic::Response<super::super::utils::Resp>, tonic::Status>;
+        #[doc = " 获取索引详情"]
+        async fn info(
+            &self,
+            request: tonic::Request<super::RequestIndexInfo>,
+        ) -> Result<tonic::Response<super::ResponseIndexInfo>, tonic::Status>;
+    }
+    #[derive(Debug)]
+    pub struct IndexServiceServer<T: IndexService> {
+        inner: _Inner<T>,
+        accept_compression_encodings: (),
+        send_compression_encodings: (),
+    }
+    struct _Inner<T>(Arc<T>);
+    impl<T: IndexService> IndexServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            let inner = Arc::new(inner);
+            let inner = _Inner(inner);
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+            }
+        }
+        pub fn with_interceptor<F>(inner: T, interceptor: F) -> InterceptedService<Self, F>
+        where
+            F: FnMut(tonic::Request<()>) -> Result<tonic::Request<()>, tonic::Status>,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+    }
+    impl<T, B> Service<http::Request<B>> for IndexServiceServer<T>
+    where
+        T: IndexService,
+        B: Body + Send + Sync + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = Never;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let inner = self.inner.clone();
             match req.uri().path() {
-                "/db.UserService/login" => {
+                "/db.IndexService/list" => {
                     #[allow(non_camel_case_types)]
-                    struct loginSvc<T: UserService>(pub Arc<T>);
-                    impl<T: UserService> tonic::server::UnaryService<super::RequestLogin> for loginSvc<T> {
+                    struct listSvc<T: IndexService>(pub Arc<T>);
+                    impl<T: IndexService> tonic::server::UnaryService<super::RequestIndexList> for listSvc<T> {
+                        type Response = super::ResponseIndexList;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RequestIndexList>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).list(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = listSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
+                            accept_compression_encodings,
+                            send_compression_encodings,
+                        );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/db.IndexService/create" => {
+                    #[allow(non_camel_case_types)]
+                    struct createSvc<T: IndexService>(pub Arc<T>);
+                    impl<T: IndexService> tonic::server::UnaryService<super::RequestIndexCreate> for createSvc<T> {
                         type Response = super::super::utils::Resp;
                         type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::RequestLogin>,
+                            request: tonic::Request<super::RequestIndexCreate>,
                         ) -> Self::Future {
                             let inner = self.0.clone();
-                            let fut = async move { (*inner).login(request).await };
+                            let fut = async move { (*inner).create(request).await };
                             Box::pin(fut)
                         }
                     }
@@ -1751,7 +4620,38 @@ pub mod user_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = loginSvc(inner);
+                        let method = createSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
+                            accept_compression_encodings,
+                            send_compression_encodings,
+                        );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/db.IndexService/info" => {
+                    #[allow(non_camel_case_types)]
+                    struct infoSvc<T: IndexService>(pub Arc<T>);
+                    impl<T: IndexService> tonic::server::UnaryService<super::RequestIndexInfo> for infoSvc<T> {
+                        type Response = super::ResponseIndexInfo;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RequestIndexInfo>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).info(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = infoSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
                             accept_compression_encodings,
@@ -1773,7 +4673,7 @@ pub mod user_service_server {
             }
         }
     }
-    impl<T: UserService> Clone for UserServiceServer<T> {
+    impl<T: IndexService> Clone for IndexServiceServer<T> {
         fn clone(&self) -> Self {
             let inner = self.inner.clone();
             Self {
@@ -1783,7 +4683,7 @@ pub mod user_service_server {
             }
         }
     }
-    impl<T: UserService> Clone for _Inner<T> {
+    impl<T: IndexService> Clone for _Inner<T> {
         fn clone(&self) -> Self {
             Self(self.0.clone())
         }
@@ -1793,51 +4693,86 @@ pub mod user_service_server {
             write!(f, "{:?}", self.0)
         }
     }
-    impl<T: UserService> tonic::transport::NamedService for UserServiceServer<T> {
-        const NAME: &'static str = "db.UserService";
+    impl<T: IndexService> tonic::transport::NamedService for IndexServiceServer<T> {
+        const NAME: &'static str = "db.IndexService";
     }
 }
 #[doc = r" Generated server implementations."]
-pub mod page_service_server {
+pub mod disk_service_server {
     #![allow(unused_variables, dead_code, missing_docs)]
     use tonic::codegen::*;
-    #[doc = "Generated trait containing gRPC methods that should be implemented for use with PageServiceServer."]
+    #[doc = "Generated trait containing gRPC methods that should be implemented for use with DiskServiceServer."]
     #[async_trait]
-    pub trait PageService: Send + Sync + 'static {
-        #[doc = " 缓存页集合"]
-        async fn list(
-            &self,
-            request: tonic::Request<super::super::utils::Req>,
-        ) -> Result<tonic::Response<super::ResponsePageList>, tonic::Status>;
-        #[doc = " 创建缓存页"]
-        async fn create(
+    pub trait DiskService: Send + Sync + 'static {
+        #[doc = " 插入数据，如果存在则返回已存在"]
+        async fn put(
             &self,
-            request: tonic::Request<super::RequestPageCreate>,
+            request: tonic::Request<super::RequestDiskInto>,
         ) -> Result<tonic::Response<super::super::utils::Resp>, tonic::Status>;
-        #[doc = " 修改缓存页"]
-        async fn modify(
+        #[doc = " 插入数据，无论存在与否都会插入或更新数据"]
+        async fn set(
             &self,
-            request: tonic::Request<super::RequestPageModify>,
+            request: tonic::Request<super::RequestDiskInto>,
         ) -> Result<tonic::Response<super::super::utils::Resp>, tonic::Status>;
-        #[doc = " 获取缓存页详情"]
-        async fn info(
+        #[doc = " 获取数据，返回存储对象"]
+        async fn get(
             &self,
-            request: tonic::Request<super::RequestPageInfo>,
-        ) -> Result<tonic::Response<super::ResponsePageInfo>, tonic::Status>;
-        #[doc = " 删除缓存页"]
+            request: tonic::Request<super::RequestDiskOut>,
+        ) -> Result<tonic::Response<super::ResponseDiskOut>, tonic::Status>;
+        #[doc = " 根据指定索引名称获取数据，返回存储对象"]
+        async fn get_by_index(
+            &self,
+            request: tonic::Request<super::RequestDiskIOut>,
+        ) -> Result<tonic::Response<super::ResponseDiskOut>, tonic::Status>;
+        #[doc = " 删除数据"]
         async fn remove(
             &self,
-            request: tonic::Request<super::RequestPageRemove>,
+            request: tonic::Request<super::RequestDiskRemove>,
         ) -> Result<tonic::Response<super::super::utils::Resp>, tonic::Status>;
+        #[doc = " 条件检索"]
+        async fn select(
+            &self,
+            request: tonic::Request<super::RequestDiskSelect>,
+        ) -> Result<tonic::Response<super::ResponseDiskSelect>, tonic::Status>;
+        #[doc = "Server streaming response type for the select_stream method."]
+        type select_streamStream: futures_core::Stream<Item = Result<super::ResponseDiskSelectStream, tonic::Status>>
+            + Send
+            + Sync
+            + 'static;
+        #[doc = " 条件检索，检索结果在检索过程中分批返回"]
+        async fn select_stream(
+            &self,
+            request: tonic::Request<super::RequestDiskSelect>,
+        ) -> Result<tonic::Response<Self::select_streamStream>, tonic::Status>;
+        #[doc = " 条件删除"]
+        async fn delete(
+            &self,
+            request: tonic::Request<super::RequestDiskDelete>,
+        ) -> Result<tonic::Response<super::ResponseDiskDelete>, tonic::Status>;
+        #[doc = " 批量插入数据，如果存在则该数据项失败"]
+        async fn put_batch(
+            &self,
+            request: tonic::Request<tonic::Streaming<super::RequestDiskBatch>>,
+        ) -> Result<tonic::Response<super::ResponseBatch>, tonic::Status>;
+        #[doc = " 批量插入数据，无论存在与否都会插入或更新数据"]
+        async fn set_batch(
+            &self,
+            request: tonic::Request<tonic::Streaming<super::RequestDiskBatch>>,
+        ) -> Result<tonic::Response<super::ResponseBatch>, tonic::Status>;
+        #[doc = " 批量删除数据"]
+        async fn remove_batch(
+            &self,
+            request: tonic::Request<tonic::Streaming<super::RequestDiskBatch>>,
+        ) -> Result<tonic::Response<super::ResponseBatch>, tonic::Status>;
     }
     #[derive(Debug)]
-    pub struct PageServiceServer<T: PageService> {
+    pub struct DiskServiceServer<T: DiskService> {
         inner: _Inner<T>,
         accept_compression_encodings: (),
         send_compression_encodings: (),
     }
     struct _Inner<T>(Arc<T>);
-    impl<T: PageService> PageServiceServer<T> {
+    impl<T: DiskService> DiskServiceServer<T> {
         pub fn new(inner: T) -> Self {
             let inner = Arc::new(inner);
             let inner = _Inner(inner);
@@ -1854,9 +4789,9 @@ pub mod page_service_server {
             InterceptedService::new(Self::new(inner), interceptor)
         }
     }
-    impl<T, B> Service<http::Request<B>> for PageServiceServer<T>
+    impl<T, B> Service<http::Request<B>> for DiskServiceServer<T>
     where
-        T: PageService,
+        T: DiskService,
         B: Body + Send + Sync + 'static,
         B::Error: Into<StdError> + Send + 'static,
     {
@@ -1869,18 +4804,18 @@ pub mod page_service_server {
         fn call(&mut self, req: http::Request<B>) -> Self::Future {
             let inner = self.inner.clone();
             match req.uri().path() {
-                "/db.PageService/list" => {
+                "/db.DiskService/put" => {
                     #[allow(non_camel_case_types)]
-                    struct listSvc<T: PageService>(pub Arc<T>);
-                    impl<T: PageService> tonic::server::UnaryService<super::super::utils::Req> for listSvc<T> {
-                        type Response = super::ResponsePageList;
+                    struct putSvc<T: DiskService>(pub Arc<T>);
+                    impl<T: DiskService> tonic::server::UnaryService<super::RequestDiskInto> for putSvc<T> {
+                        type Response = super::super::utils::Resp;
                         type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::super::utils::Req>,
+                            request: tonic::Request<super::RequestDiskInto>,
                         ) -> Self::Future {
                             let inner = self.0.clone();
-                            let fut = async move { (*inner).list(request).await };
+                            let fut = async move { (*inner).put(request).await };
                             Box::pin(fut)
                         }
                     }
@@ -1889,29 +4824,220 @@ pub mod page_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = listSvc(inner);
+                        let method = putSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
+                            accept_compression_encodings,
+                            send_compression_encodings,
+                        );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/db.DiskService/set" => {
+                    #[allow(non_camel_case_types)]
+                    struct setSvc<T: DiskService>(pub Arc<T>);
+                    impl<T: DiskService> tonic::server::UnaryService<super::RequestDiskInto> for setSvc<T> {
+                        type Response = super::super::utils::Resp;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RequestDiskInto>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).set(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = setSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
+                            accept_compression_encodings,
+                            send_compression_encodings,
+                        );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/db.DiskService/get" => {
+                    #[allow(non_camel_case_types)]
+                    struct getSvc<T: DiskService>(pub Arc<T>);
+                    impl<T: DiskService> tonic::server::UnaryService<super::RequestDiskOut> for getSvc<T> {
+                        type Response = super::ResponseDiskOut;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RequestDiskOut>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).get(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = getSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
+                            accept_compression_encodings,
+                            send_compression_encodings,
+                        );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/db.DiskService/get_by_index" => {
+                    #[allow(non_camel_case_types)]
+                    struct get_by_indexSvc<T: DiskService>(pub Arc<T>);
+                    impl<T: DiskService> tonic::server::UnaryService<super::RequestDiskIOut> for get_by_indexSvc<T> {
+                        type Response = super::ResponseDiskOut;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RequestDiskIOut>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).get_by_index(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = get_by_indexSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
+                            accept_compression_encodings,
+                            send_compression_encodings,
+                        );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/db.DiskService/remove" => {
+                    #[allow(non_camel_case_types)]
+                    struct removeSvc<T: DiskService>(pub Arc<T>);
+                    impl<T: DiskService> tonic::server::UnaryService<super::RequestDiskRemove> for removeSvc<T> {
+                        type Response = super::super::utils::Resp;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RequestDiskRemove>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).remove(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = removeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
+                            accept_compression_encodings,
+                            send_compression_encodings,
+                        );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/db.DiskService/select" => {
+                    #[allow(non_camel_case_types)]
+                    struct selectSvc<T: DiskService>(pub Arc<T>);
+                    impl<T: DiskService> tonic::server::UnaryService<super::RequestDiskSelect> for selectSvc<T> {
+                        type Response = super::ResponseDiskSelect;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RequestDiskSelect>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).select(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = selectSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
+                            accept_compression_encodings,
+                            send_compression_encodings,
+                        );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/db.DiskService/select_stream" => {
+                    #[allow(non_camel_case_types)]
+                    struct select_streamSvc<T: DiskService>(pub Arc<T>);
+                    impl<T: DiskService>
+                        tonic::server::ServerStreamingService<super::RequestDiskSelect>
+                        for select_streamSvc<T>
+                    {
+                        type Response = super::ResponseDiskSelectStream;
+                        type ResponseStream = T::select_streamStream;
+                        type Future =
+                            BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RequestDiskSelect>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).select_stream(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = select_streamSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
                             accept_compression_encodings,
                             send_compression_encodings,
                         );
-                        let res = grpc.unary(method, req).await;
+                        let res = grpc.server_streaming(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/db.PageService/create" => {
+                "/db.DiskService/delete" => {
                     #[allow(non_camel_case_types)]
-                    struct createSvc<T: PageService>(pub Arc<T>);
-                    impl<T: PageService> tonic::server::UnaryService<super::RequestPageCreate> for createSvc<T> {
-                        type Response = super::super::utils::Resp;
+                    struct deleteSvc<T: DiskService>(pub Arc<T>);
+                    impl<T: DiskService> tonic::server::UnaryService<super::RequestDiskDelete> for deleteSvc<T> {
+                        type Response = super::ResponseDiskDelete;
                         type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::RequestPageCreate>,
+                            request: tonic::Request<super::RequestDiskDelete>,
                         ) -> Self::Future {
                             let inner = self.0.clone();
-                            let fut = async move { (*inner).create(request).await };
+                            let fut = async move { (*inner).delete(request).await };
                             Box::pin(fut)
                         }
                     }
@@ -1920,7 +5046,7 @@ pub mod page_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = createSvc(inner);
+                        let method = deleteSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
                             accept_compression_encodings,
@@ -1931,18 +5057,21 @@ pub mod page_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/db.PageService/modify" => {
+                "/db.DiskService/put_batch" => {
                     #[allow(non_camel_case_types)]
-                    struct modifySvc<T: PageService>(pub Arc<T>);
-                    impl<T: PageService> tonic::server::UnaryService<super::RequestPageModify> for modifySvc<T> {
-                        type Response = super::super::utils::Resp;
+                    struct put_batchSvc<T: DiskService>(pub Arc<T>);
+                    impl<T: DiskService>
+                        tonic::server::ClientStreamingService<super::RequestDiskBatch>
+                        for put_batchSvc<T>
+                    {
+                        type Response = super::ResponseBatch;
                         type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::RequestPageModify>,
+                            request: tonic::Request<tonic::Streaming<super::RequestDiskBatch>>,
                         ) -> Self::Future {
                             let inner = self.0.clone();
-                            let fut = async move { (*inner).modify(request).await };
+                            let fut = async move { (*inner).put_batch(request).await };
                             Box::pin(fut)
                         }
                     }
@@ -1951,29 +5080,32 @@ pub mod page_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = modifySvc(inner);
+                        let method = put_batchSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
                             accept_compression_encodings,
                             send_compression_encodings,
                         );
-                        let res = grpc.unary(method, req).await;
+                        let res = grpc.client_streaming(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/db.PageService/info" => {
+                "/db.DiskService/set_batch" => {
                     #[allow(non_camel_case_types)]
-                    struct infoSvc<T: PageService>(pub Arc<T>);
-                    impl<T: PageService> tonic::server::UnaryService<super::RequestPageInfo> for infoSvc<T> {
-                        type Response = super::ResponsePageInfo;
+                    struct set_batchSvc<T: DiskService>(pub Arc<T>);
+                    impl<T: DiskService>
+                        tonic::server::ClientStreamingService<super::RequestDiskBatch>
+                        for set_batchSvc<T>
+                    {
+                        type Response = super::ResponseBatch;
                         type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::RequestPageInfo>,
+                            request: tonic::Request<tonic::Streaming<super::RequestDiskBatch>>,
                         ) -> Self::Future {
                             let inner = self.0.clone();
-                            let fut = async move { (*inner).info(request).await };
+                            let fut = async move { (*inner).set_batch(request).await };
                             Box::pin(fut)
                         }
                     }
@@ -1982,29 +5114,32 @@ pub mod page_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = infoSvc(inner);
+                        let method = set_batchSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
                             accept_compression_encodings,
                             send_compression_encodings,
                         );
-                        let res = grpc.unary(method, req).await;
+                        let res = grpc.client_streaming(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/db.PageService/remove" => {
+                "/db.DiskService/remove_batch" => {
                     #[allow(non_camel_case_types)]
-                    struct removeSvc<T: PageService>(pub Arc<T>);
-                    impl<T: PageService> tonic::server::UnaryService<super::RequestPageRemove> for removeSvc<T> {
-                        type Response = super::super::utils::Resp;
+                    struct remove_batchSvc<T: DiskService>(pub Arc<T>);
+                    impl<T: DiskService>
+                        tonic::server::ClientStreamingService<super::RequestDiskBatch>
+                        for remove_batchSvc<T>
+                    {
+                        type Response = super::ResponseBatch;
                         type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::RequestPageRemove>,
+                            request: tonic::Request<tonic::Streaming<super::RequestDiskBatch>>,
                         ) -> Self::Future {
                             let inner = self.0.clone();
-                            let fut = async move { (*inner).remove(request).await };
+                            let fut = async move { (*inner).remove_batch(request).await };
                             Box::pin(fut)
                         }
                     }
@@ -2013,13 +5148,13 @@ pub mod page_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = removeSvc(inner);
+                        let method = remove_batchSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
                             accept_compression_encodings,
                             send_compression_encodings,
                         );
-                        let res = grpc.unary(method, req).await;
+                        let res = grpc.client_streaming(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
@@ -2035,7 +5170,7 @@ pub mod page_service_server {
             }
         }
     }
-    impl<T: PageService> Clone for PageServiceServer<T> {
+    impl<T: DiskService> Clone for DiskServiceServer<T> {
         fn clone(&self) -> Self {
             let inner = self.inner.clone();
             Self {
@@ -2045,7 +5180,7 @@ pub mod page_service_server {
             }
         }
     }
-    impl<T: PageService> Clone for _Inner<T> {
+    impl<T: DiskService> Clone for _Inner<T> {
         fn clone(&self) -> Self {
             Self(self.0.clone())
         }
@@ -2055,51 +5190,81 @@ pub mod page_service_server {
             write!(f, "{:?}", self.0)
         }
     }
-    impl<T: PageService> tonic::transport::NamedService for PageServiceServer<T> {
-        const NAME: &'static str = "db.PageService";
+    impl<T: DiskService> tonic::transport::NamedService for DiskServiceServer<T> {
+        const NAME: &'static str = "db.DiskService";
     }
 }
 #[doc = r" Generated server implementations."]
-pub mod database_service_server {
+pub mod memory_service_server {
     #![allow(unused_variables, dead_code, missing_docs)]
     use tonic::codegen::*;
-    #[doc = "Generated trait containing gRPC methods that should be implemented for use with DatabaseServiceServer."]
+    #[doc = "Generated trait containing gRPC methods that should be implemented for use with MemoryServiceServer."]
     #[async_trait]
-    pub trait DatabaseService: Send + Sync + 'static {
-        #[doc = " 数据库集合"]
-        async fn list(
-            &self,
-            request: tonic::Request<super::super::utils::Req>,
-        ) -> Result<tonic::Response<super::ResponseDatabaseList>, tonic::Status>;
-        #[doc = " 创建数据库"]
-        async fn create(
+    pub trait MemoryService: Send + Sync + 'static {
+        #[doc = " 插入数据，如果存在则返回已存在"]
+        async fn put(
             &self,
-            request: tonic::Request<super::RequestDatabaseCreate>,
+            request: tonic::Request<super::RequestMemoryInto>,
         ) -> Result<tonic::Response<super::super::utils::Resp>, tonic::Status>;
-        #[doc = " 修改数据库"]
-        async fn modify(
+        #[doc = " 插入数据，无论存在与否都会插入或更新数据"]
+        async fn set(
             &self,
-            request: tonic::Request<super::RequestDatabaseModify>,
+            request: tonic::Request<super::RequestMemoryInto>,
         ) -> Result<tonic::Response<super::super::utils::Resp>, tonic::Status>;
-        #[doc = " 获取数据库详情"]
-        async fn info(
+        #[doc = " 获取数据，返回存储对象"]
+        async fn get(
             &self,
-            request: tonic::Request<super::RequestDatabaseInfo>,
-        ) -> Result<tonic::Response<super::ResponseDatabaseInfo>, tonic::Status>;
-        #[doc = " 删除数据库"]
+            request: tonic::Request<super::RequestMemoryOut>,
+        ) -> Result<tonic::Response<super::ResponseMemoryOut>, tonic::Status>;
+        #[doc = " 删除数据"]
         async fn remove(
             &self,
-            request: tonic::Request<super::RequestDatabaseRemove>,
+            request: tonic::Request<super::RequestMemoryRemove>,
+        ) -> Result<tonic::Response<super::super::utils::Resp>, tonic::Status>;
+        #[doc = " 在指定缓存页中插入数据，如果存在则返回已存在"]
+        async fn put_by_page(
+            &self,
+            request: tonic::Request<super::RequestMemoryPInto>,
+        ) -> Result<tonic::Response<super::super::utils::Resp>, tonic::Status>;
+        #[doc = " 在指定缓存页中插入数据，无论存在与否都会插入或更新数据"]
+        async fn set_by_page(
+            &self,
+            request: tonic::Request<super::RequestMemoryPInto>,
+        ) -> Result<tonic::Response<super::super::utils::Resp>, tonic::Status>;
+        #[doc = " 在指定缓存页中获取数据，返回存储对象"]
+        async fn get_by_page(
+            &self,
+            request: tonic::Request<super::RequestMemoryPOut>,
+        ) -> Result<tonic::Response<super::ResponseMemoryPOut>, tonic::Status>;
+        #[doc = " 在指定缓存页中删除数据"]
+        async fn remove_by_page(
+            &self,
+            request: tonic::Request<super::RequestMemoryPRemove>,
         ) -> Result<tonic::Response<super::super::utils::Resp>, tonic::Status>;
+        #[doc = " 批量插入数据，如果存在则该数据项失败"]
+        async fn put_batch(
+            &self,
+            request: tonic::Request<tonic::Streaming<super::RequestMemoryBatch>>,
+        ) -> Result<tonic::Response<super::ResponseBatch>, tonic::Status>;
+        #[doc = " 批量插入数据，无论存在与否都会插入或更新数据"]
+        async fn set_batch(
+            &self,
+            request: tonic::Request<tonic::Streaming<super::RequestMemoryBatch>>,
+        ) -> Result<tonic::Response<super::ResponseBatch>, tonic::Status>;
+        #[doc = " 批量删除数据"]
+        async fn remove_batch(
+            &self,
+            request: tonic::Request<tonic::Streaming<super::RequestMemoryBatch>>,
+        ) -> Result<tonic::Response<super::ResponseBatch>, tonic::Status>;
     }
     #[derive(Debug)]
-    pub struct DatabaseServiceServer<T: DatabaseService> {
+    pub struct MemoryServiceServer<T: MemoryService> {
         inner: _Inner<T>,
         accept_compression_encodings: (),
         send_compression_encodings: (),
     }
     struct _Inner<T>(Arc<T>);
-    impl<T: DatabaseService> DatabaseServiceServer<T> {
+    impl<T: MemoryService> MemoryServiceServer<T> {
         pub fn new(inner: T) -> Self {
             let inner = Arc::new(inner);
             let inner = _Inner(inner);
@@ -2116,9 +5281,9 @@ pub mod database_service_server {
             InterceptedService::new(Self::new(inner), interceptor)
         }
     }
-    impl<T, B> Service<http::Request<B>> for DatabaseServiceServer<T>
+    impl<T, B> Service<http::Request<B>> for MemoryServiceServer<T>
     where
-        T: DatabaseService,
+        T: MemoryService,
         B: Body + Send + Sync + 'static,
         B::Error: Into<StdError> + Send + 'static,
     {
@@ -2131,18 +5296,177 @@ pub mod database_service_server {
         fn call(&mut self, req: http::Request<B>) -> Self::Future {
             let inner = self.inner.clone();
             match req.uri().path() {
-                "/db.DatabaseService/list" => {
+                "/db.MemoryService/put" => {
+                    #[allow(non_camel_case_types)]
+                    struct putSvc<T: MemoryService>(pub Arc<T>);
+                    impl<T: MemoryService> tonic::server::UnaryService<super::RequestMemoryInto> for putSvc<T> {
+                        type Response = super::super::utils::Resp;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RequestMemoryInto>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).put(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = putSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
+                            accept_compression_encodings,
+                            send_compression_encodings,
+                        );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/db.MemoryService/set" => {
+                    #[allow(non_camel_case_types)]
+                    struct setSvc<T: MemoryService>(pub Arc<T>);
+                    impl<T: MemoryService> tonic::server::UnaryService<super::RequestMemoryInto> for setSvc<T> {
+                        type Response = super::super::utils::Resp;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RequestMemoryInto>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).set(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = setSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
+                            accept_compression_encodings,
+                            send_compression_encodings,
+                        );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/db.MemoryService/get" => {
+                    #[allow(non_camel_case_types)]
+                    struct getSvc<T: MemoryService>(pub Arc<T>);
+                    impl<T: MemoryService> tonic::server::UnaryService<super::RequestMemoryOut> for getSvc<T> {
+                        type Response = super::ResponseMemoryOut;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RequestMemoryOut>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).get(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = getSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
+                            accept_compression_encodings,
+                            send_compression_encodings,
+                        );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/db.MemoryService/remove" => {
+                    #[allow(non_camel_case_types)]
+                    struct removeSvc<T: MemoryService>(pub Arc<T>);
+                    impl<T: MemoryService> tonic::server::UnaryService<super::RequestMemoryRemove> for removeSvc<T> {
+                        type Response = super::super::utils::Resp;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RequestMemoryRemove>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).remove(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = removeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
+                            accept_compression_encodings,
+                            send_compression_encodings,
+                        );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/db.MemoryService/put_by_page" => {
+                    #[allow(non_camel_case_types)]
+                    struct put_by_pageSvc<T: MemoryService>(pub Arc<T>);
+                    impl<T: MemoryService> tonic::server::UnaryService<super::RequestMemoryPInto>
+                        for put_by_pageSvc<T>
+                    {
+                        type Response = super::super::utils::Resp;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RequestMemoryPInto>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).put_by_page(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = put_by_pageSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
+                            accept_compression_encodings,
+                            send_compression_encodings,
+                        );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/db.MemoryService/set_by_page" => {
                     #[allow(non_camel_case_types)]
-                    struct listSvc<T: DatabaseService>(pub Arc<T>);
-                    impl<T: DatabaseService> tonic::server::UnaryService<super::super::utils::Req> for listSvc<T> {
-                        type Response = super::ResponseDatabaseList;
+                    struct set_by_pageSvc<T: MemoryService>(pub Arc<T>);
+                    impl<T: MemoryService> tonic::server::UnaryService<super::RequestMemoryPInto>
+                        for set_by_pageSvc<T>
+                    {
+                        type Response = super::super::utils::Resp;
                         type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::super::utils::Req>,
+                            request: tonic::Request<super::RequestMemoryPInto>,
                         ) -> Self::Future {
                             let inner = self.0.clone();
-                            let fut = async move { (*inner).list(request).await };
+                            let fut = async move { (*inner).set_by_page(request).await };
                             Box::pin(fut)
                         }
                     }
@@ -2151,7 +5475,7 @@ pub mod database_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = listSvc(inner);
+                        let method = set_by_pageSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
                             accept_compression_encodings,
@@ -2162,20 +5486,18 @@ pub mod database_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/db.DatabaseService/create" => {
+                "/db.MemoryService/get_by_page" => {
                     #[allow(non_camel_case_types)]
-                    struct createSvc<T: DatabaseService>(pub Arc<T>);
-                    impl<T: DatabaseService>
-                        tonic::server::UnaryService<super::RequestDatabaseCreate> for createSvc<T>
-                    {
-                        type Response = super::super::utils::Resp;
+                    struct get_by_pageSvc<T: MemoryService>(pub Arc<T>);
+                    impl<T: MemoryService> tonic::server::UnaryService<super::RequestMemoryPOut> for get_by_pageSvc<T> {
+                        type Response = super::ResponseMemoryPOut;
                         type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::RequestDatabaseCreate>,
+                            request: tonic::Request<super::RequestMemoryPOut>,
                         ) -> Self::Future {
                             let inner = self.0.clone();
-                            let fut = async move { (*inner).create(request).await };
+                            let fut = async move { (*inner).get_by_page(request).await };
                             Box::pin(fut)
                         }
                     }
@@ -2184,7 +5506,7 @@ pub mod database_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = createSvc(inner);
+                        let method = get_by_pageSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
                             accept_compression_encodings,
@@ -2195,20 +5517,20 @@ pub mod database_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/db.DatabaseService/modify" => {
+                "/db.MemoryService/remove_by_page" => {
                     #[allow(non_camel_case_types)]
-                    struct modifySvc<T: DatabaseService>(pub Arc<T>);
-                    impl<T: DatabaseService>
-                        tonic::server::UnaryService<super::RequestDatabaseModify> for modifySvc<T>
+                    struct remove_by_pageSvc<T: MemoryService>(pub Arc<T>);
+                    impl<T: MemoryService> tonic::server::UnaryService<super::RequestMemoryPRemove>
+                        for remove_by_pageSvc<T>
                     {
                         type Response = super::super::utils::Resp;
                         type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::RequestDatabaseModify>,
+                            request: tonic::Request<super::RequestMemoryPRemove>,
                         ) -> Self::Future {
                             let inner = self.0.clone();
-                            let fut = async move { (*inner).modify(request).await };
+                            let fut = async move { (*inner).remove_by_page(request).await };
                             Box::pin(fut)
                         }
                     }
@@ -2217,7 +5539,7 @@ pub mod database_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = modifySvc(inner);
+                        let method = remove_by_pageSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
                             accept_compression_encodings,
@@ -2228,18 +5550,21 @@ pub mod database_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/db.DatabaseService/info" => {
+                "/db.MemoryService/put_batch" => {
                     #[allow(non_camel_case_types)]
-                    struct infoSvc<T: DatabaseService>(pub Arc<T>);
-                    impl<T: DatabaseService> tonic::server::UnaryService<super::RequestDatabaseInfo> for infoSvc<T> {
-                        type Response = super::ResponseDatabaseInfo;
+                    struct put_batchSvc<T: MemoryService>(pub Arc<T>);
+                    impl<T: MemoryService>
+                        tonic::server::ClientStreamingService<super::RequestMemoryBatch>
+                        for put_batchSvc<T>
+                    {
+                        type Response = super::ResponseBatch;
                         type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::RequestDatabaseInfo>,
+                            request: tonic::Request<tonic::Streaming<super::RequestMemoryBatch>>,
                         ) -> Self::Future {
                             let inner = self.0.clone();
-                            let fut = async move { (*inner).info(request).await };
+                            let fut = async move { (*inner).put_batch(request).await };
                             Box::pin(fut)
                         }
                     }
@@ -2248,31 +5573,32 @@ pub mod database_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = infoSvc(inner);
+                        let method = put_batchSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
                             accept_compression_encodings,
                             send_compression_encodings,
                         );
-                        let res = grpc.unary(method, req).await;
+                        let res = grpc.client_streaming(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/db.DatabaseService/remove" => {
+                "/db.MemoryService/set_batch" => {
                     #[allow(non_camel_case_types)]
-                    struct removeSvc<T: DatabaseService>(pub Arc<T>);
-                    impl<T: DatabaseService>
-                        tonic::server::UnaryService<super::RequestDatabaseRemove> for removeSvc<T>
+                    struct set_batchSvc<T: MemoryService>(pub Arc<T>);
+                    impl<T: MemoryService>
+                        tonic::server::ClientStreamingService<super::RequestMemoryBatch>
+                        for set_batchSvc<T>
                     {
-                        type Response = super::super::utils::Resp;
+                        type Response = super::ResponseBatch;
                         type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::RequestDatabaseRemove>,
+                            request: tonic::Request<tonic::Streaming<super::RequestMemoryBatch>>,
                         ) -> Self::Future {
                             let inner = self.0.clone();
-                            let fut = async move { (*inner).remove(request).await };
+                            let fut = async move { (*inner).set_batch(request).await };
                             Box::pin(fut)
                         }
                     }
@@ -2281,13 +5607,47 @@ pub mod database_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = removeSvc(inner);
+                        let method = set_batchSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
                             accept_compression_encodings,
                             send_compression_encodings,
                         );
-                        let res = grpc.unary(method, req).await;
+                        let res = grpc.client_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/db.MemoryService/remove_batch" => {
+                    #[allow(non_camel_case_types)]
+                    struct remove_batchSvc<T: MemoryService>(pub Arc<T>);
+                    impl<T: MemoryService>
+                        tonic::server::ClientStreamingService<super::RequestMemoryBatch>
+                        for remove_batchSvc<T>
+                    {
+                        type Response = super::ResponseBatch;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<tonic::Streaming<super::RequestMemoryBatch>>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).remove_batch(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = remove_batchSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
+                            accept_compression_encodings,
+                            send_compression_encodings,
+                        );
+                        let res = grpc.client_streaming(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
@@ -2303,7 +5663,7 @@ pub mod database_service_server {
             }
         }
     }
-    impl<T: DatabaseService> Clone for DatabaseServiceServer<T> {
+    impl<T: MemoryService> Clone for MemoryServiceServer<T> {
         fn clone(&self) -> Self {
             let inner = self.inner.clone();
             Self {
@@ -2313,7 +5673,7 @@ pub mod database_service_server {
             }
         }
     }
-    impl<T: DatabaseService> Clone for _Inner<T> {
+    impl<T: MemoryService> Clone for _Inner<T> {
         fn clone(&self) -> Self {
             Self(self.0.clone())
         }
@@ -2323,66 +5683,36 @@ pub mod database_service_server {
             write!(f, "{:?}", self.0)
         }
     }
-    impl<T: DatabaseService> tonic::transport::NamedService for DatabaseServiceServer<T> {
-        const NAME: &'static str = "db.DatabaseService";
+    impl<T: MemoryService> tonic::transport::NamedService for MemoryServiceServer<T> {
+        const NAME: &'static str = "db.MemoryService";
     }
 }
 #[doc = r" Generated server implementations."]
-pub mod view_service_server {
+pub mod audit_service_server {
     #![allow(unused_variables, dead_code, missing_docs)]
     use tonic::codegen::*;
-    #[doc = "Generated trait containing gRPC methods that should be implemented for use with ViewServiceServer."]
+    #[doc = "Generated trait containing gRPC methods that should be implemented for use with AuditServiceServer."]
     #[async_trait]
-    pub trait ViewService: Send + Sync + 'static {
-        #[doc = " 视图集合"]
+    pub trait AuditService: Send + Sync + 'static {
+        #[doc = " 审计记录集合"]
         async fn list(
             &self,
-            request: tonic::Request<super::RequestViewList>,
-        ) -> Result<tonic::Response<super::ResponseViewList>, tonic::Status>;
-        #[doc = " 创建视图"]
-        async fn create(
-            &self,
-            request: tonic::Request<super::RequestViewCreate>,
-        ) -> Result<tonic::Response<super::super::utils::Resp>, tonic::Status>;
-        #[doc = " 修改视图"]
-        async fn modify(
-            &self,
-            request: tonic::Request<super::RequestViewModify>,
-        ) -> Result<tonic::Response<super::super::utils::Resp>, tonic::Status>;
-        #[doc = " 获取视图详情"]
-        async fn info(
-            &self,
-            request: tonic::Request<super::RequestViewInfo>,
-        ) -> Result<tonic::Response<super::ResponseViewInfo>, tonic::Status>;
-        #[doc = " 删除视图"]
-        async fn remove(
-            &self,
-            request: tonic::Request<super::RequestViewRemove>,
-        ) -> Result<tonic::Response<super::super::utils::Resp>, tonic::Status>;
-        #[doc = " 整理归档"]
-        async fn archive(
-            &self,
-            request: tonic::Request<super::RequestViewArchive>,
-        ) -> Result<tonic::Response<super::super::utils::Resp>, tonic::Status>;
-        #[doc = " 读取指定归档版本信息"]
-        async fn record(
-            &self,
-            request: tonic::Request<super::RequestViewRecord>,
-        ) -> Result<tonic::Response<super::ResponseViewRecord>, tonic::Status>;
-        #[doc = " 读取所有归档版本信息"]
-        async fn records(
+            request: tonic::Request<super::RequestAuditList>,
+        ) -> Result<tonic::Response<super::ResponseAuditList>, tonic::Status>;
+        #[doc = " 校验审计链完整性"]
+        async fn verify(
             &self,
-            request: tonic::Request<super::RequestViewRecords>,
-        ) -> Result<tonic::Response<super::ResponseViewRecords>, tonic::Status>;
+            request: tonic::Request<super::super::utils::Req>,
+        ) -> Result<tonic::Response<super::ResponseAuditVerify>, tonic::Status>;
     }
     #[derive(Debug)]
-    pub struct ViewServiceServer<T: ViewService> {
+    pub struct AuditServiceServer<T: AuditService> {
         inner: _Inner<T>,
         accept_compression_encodings: (),
         send_compression_encodings: (),
     }
     struct _Inner<T>(Arc<T>);
-    impl<T: ViewService> ViewServiceServer<T> {
+    impl<T: AuditService> AuditServiceServer<T> {
         pub fn new(inner: T) -> Self {
             let inner = Arc::new(inner);
             let inner = _Inner(inner);
@@ -2399,9 +5729,9 @@ pub mod view_service_server {
             InterceptedService::new(Self::new(inner), interceptor)
         }
     }
-    impl<T, B> Service<http::Request<B>> for ViewServiceServer<T>
+    impl<T, B> Service<http::Request<B>> for AuditServiceServer<T>
     where
-        T: ViewService,
+        T: AuditService,
         B: Body + Send + Sync + 'static,
         B::Error: Into<StdError> + Send + 'static,
     {
@@ -2414,15 +5744,15 @@ pub mod view_service_server {
         fn call(&mut self, req: http::Request<B>) -> Self::Future {
             let inner = self.inner.clone();
             match req.uri().path() {
-                "/db.ViewService/list" => {
+                "/db.AuditService/list" => {
                     #[allow(non_camel_case_types)]
-                    struct listSvc<T: ViewService>(pub Arc<T>);
-                    impl<T: ViewService> tonic::server::UnaryService<super::RequestViewList> for listSvc<T> {
-                        type Response = super::ResponseViewList;
+                    struct listSvc<T: AuditService>(pub Arc<T>);
+                    impl<T: AuditService> tonic::server::UnaryService<super::RequestAuditList> for listSvc<T> {
+                        type Response = super::ResponseAuditList;
                         type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::RequestViewList>,
+                            request: tonic::Request<super::RequestAuditList>,
                         ) -> Self::Future {
                             let inner = self.0.clone();
                             let fut = async move { (*inner).list(request).await };
@@ -2445,49 +5775,18 @@ pub mod view_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/db.ViewService/create" => {
-                    #[allow(non_camel_case_types)]
-                    struct createSvc<T: ViewService>(pub Arc<T>);
-                    impl<T: ViewService> tonic::server::UnaryService<super::RequestViewCreate> for createSvc<T> {
-                        type Response = super::super::utils::Resp;
-                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
-                        fn call(
-                            &mut self,
-                            request: tonic::Request<super::RequestViewCreate>,
-                        ) -> Self::Future {
-                            let inner = self.0.clone();
-                            let fut = async move { (*inner).create(request).await };
-                            Box::pin(fut)
-                        }
-                    }
-                    let accept_compression_encodings = self.accept_compression_encodings;
-                    let send_compression_encodings = self.send_compression_encodings;
-                    let inner = self.inner.clone();
-                    let fut = async move {
-                        let inner = inner.0;
-                        let method = createSvc(inner);
-                        let codec = tonic::codec::ProstCodec::default();
-                        let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
-                            accept_compression_encodings,
-                            send_compression_encodings,
-                        );
-                        let res = grpc.unary(method, req).await;
-                        Ok(res)
-                    };
-                    Box::pin(fut)
-                }
-                "/db.ViewService/modify" => {
+                "/db.AuditService/verify" => {
                     #[allow(non_camel_case_types)]
-                    struct modifySvc<T: ViewService>(pub Arc<T>);
-                    impl<T: ViewService> tonic::server::UnaryService<super::RequestViewModify> for modifySvc<T> {
-                        type Response = super::super::utils::Resp;
+                    struct verifySvc<T: AuditService>(pub Arc<T>);
+                    impl<T: AuditService> tonic::server::UnaryService<super::super::utils::Req> for verifySvc<T> {
+                        type Response = super::ResponseAuditVerify;
                         type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::RequestViewModify>,
+                            request: tonic::Request<super::super::utils::Req>,
                         ) -> Self::Future {
                             let inner = self.0.clone();
-                            let fut = async move { (*inner).modify(request).await };
+                            let fut = async move { (*inner).verify(request).await };
                             Box::pin(fut)
                         }
                     }
@@ -2496,7 +5795,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = modifySvc(inner);
+                        let method = verifySvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
                             accept_compression_encodings,
@@ -2507,18 +5806,110 @@ pub mod view_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/db.ViewService/info" => {
+                _ => Box::pin(async move {
+                    Ok(http::Response::builder()
+                        .status(200)
+                        .header("grpc-status", "12")
+                        .header("content-type", "application/grpc")
+                        .body(empty_body())
+                        .unwrap())
+                }),
+            }
+        }
+    }
+    impl<T: AuditService> Clone for AuditServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+            }
+        }
+    }
+    impl<T: AuditService> Clone for _Inner<T> {
+        fn clone(&self) -> Self {
+            Self(self.0.clone())
+        }
+    }
+    impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:?}", self.0)
+        }
+    }
+    impl<T: AuditService> tonic::transport::NamedService for AuditServiceServer<T> {
+        const NAME: &'static str = "db.AuditService";
+    }
+}
+#[doc = r" Generated server implementations."]
+pub mod quota_service_server {
+    #![allow(unused_variables, dead_code, missing_docs)]
+    use tonic::codegen::*;
+    #[doc = "Generated trait containing gRPC methods that should be implemented for use with QuotaServiceServer."]
+    #[async_trait]
+    pub trait QuotaService: Send + Sync + 'static {
+        #[doc = " 设置配额策略，已存在则覆盖，所有限制项都为0则移除策略"]
+        async fn set(
+            &self,
+            request: tonic::Request<super::RequestQuotaSet>,
+        ) -> Result<tonic::Response<super::super::utils::Resp>, tonic::Status>;
+        #[doc = " 配额策略集合"]
+        async fn list(
+            &self,
+            request: tonic::Request<super::RequestQuotaList>,
+        ) -> Result<tonic::Response<super::ResponseQuotaList>, tonic::Status>;
+    }
+    #[derive(Debug)]
+    pub struct QuotaServiceServer<T: QuotaService> {
+        inner: _Inner<T>,
+        accept_compression_encodings: (),
+        send_compression_encodings: (),
+    }
+    struct _Inner<T>(Arc<T>);
+    impl<T: QuotaService> QuotaServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            let inner = Arc::new(inner);
+            let inner = _Inner(inner);
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+            }
+        }
+        pub fn with_interceptor<F>(inner: T, interceptor: F) -> InterceptedService<Self, F>
+        where
+            F: FnMut(tonic::Request<()>) -> Result<tonic::Request<()>, tonic::Status>,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+    }
+    impl<T, B> Service<http::Request<B>> for QuotaServiceServer<T>
+    where
+        T: QuotaService,
+        B: Body + Send + Sync + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = Never;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let inner = self.inner.clone();
+            match req.uri().path() {
+                "/db.QuotaService/set" => {
                     #[allow(non_camel_case_types)]
-                    struct infoSvc<T: ViewService>(pub Arc<T>);
-                    impl<T: ViewService> tonic::server::UnaryService<super::RequestViewInfo> for infoSvc<T> {
-                        type Response = super::ResponseViewInfo;
+                    struct setSvc<T: QuotaService>(pub Arc<T>);
+                    impl<T: QuotaService> tonic::server::UnaryService<super::RequestQuotaSet> for setSvc<T> {
+                        type Response = super::super::utils::Resp;
                         type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::RequestViewInfo>,
+                            request: tonic::Request<super::RequestQuotaSet>,
                         ) -> Self::Future {
                             let inner = self.0.clone();
-                            let fut = async move { (*inner).info(request).await };
+                            let fut = async move { (*inner).set(request).await };
                             Box::pin(fut)
                         }
                     }
@@ -2527,7 +5918,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = infoSvc(inner);
+                        let method = setSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
                             accept_compression_encodings,
@@ -2538,18 +5929,18 @@ pub mod view_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/db.ViewService/remove" => {
+                "/db.QuotaService/list" => {
                     #[allow(non_camel_case_types)]
-                    struct removeSvc<T: ViewService>(pub Arc<T>);
-                    impl<T: ViewService> tonic::server::UnaryService<super::RequestViewRemove> for removeSvc<T> {
-                        type Response = super::super::utils::Resp;
+                    struct listSvc<T: QuotaService>(pub Arc<T>);
+                    impl<T: QuotaService> tonic::server::UnaryService<super::RequestQuotaList> for listSvc<T> {
+                        type Response = super::ResponseQuotaList;
                         type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::RequestViewRemove>,
+                            request: tonic::Request<super::RequestQuotaList>,
                         ) -> Self::Future {
                             let inner = self.0.clone();
-                            let fut = async move { (*inner).remove(request).await };
+                            let fut = async move { (*inner).list(request).await };
                             Box::pin(fut)
                         }
                     }
@@ -2558,7 +5949,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = removeSvc(inner);
+                        let method = listSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
                             accept_compression_encodings,
@@ -2569,18 +5960,105 @@ pub mod view_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/db.ViewService/archive" => {
+                _ => Box::pin(async move {
+                    Ok(http::Response::builder()
+                        .status(200)
+                        .header("grpc-status", "12")
+                        .header("content-type", "application/grpc")
+                        .body(empty_body())
+                        .unwrap())
+                }),
+            }
+        }
+    }
+    impl<T: QuotaService> Clone for QuotaServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+            }
+        }
+    }
+    impl<T: QuotaService> Clone for _Inner<T> {
+        fn clone(&self) -> Self {
+            Self(self.0.clone())
+        }
+    }
+    impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:?}", self.0)
+        }
+    }
+    impl<T: QuotaService> tonic::transport::NamedService for QuotaServiceServer<T> {
+        const NAME: &'static str = "db.QuotaService";
+    }
+}
+#[doc = r" Generated server implementations."]
+pub mod backup_service_server {
+    #![allow(unused_variables, dead_code, missing_docs)]
+    use tonic::codegen::*;
+    #[doc = "Generated trait containing gRPC methods that should be implemented for use with BackupServiceServer."]
+    #[async_trait]
+    pub trait BackupService: Send + Sync + 'static {
+        #[doc = " 在写入持续进行的同时将数据一致地备份到服务端指定目录"]
+        async fn backup(
+            &self,
+            request: tonic::Request<super::RequestBackup>,
+        ) -> Result<tonic::Response<super::ResponseBackup>, tonic::Status>;
+    }
+    #[derive(Debug)]
+    pub struct BackupServiceServer<T: BackupService> {
+        inner: _Inner<T>,
+        accept_compression_encodings: (),
+        send_compression_encodings: (),
+    }
+    struct _Inner<T>(Arc<T>);
+    impl<T: BackupService> BackupServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            let inner = Arc::new(inner);
+            let inner = _Inner(inner);
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+            }
+        }
+        pub fn with_interceptor<F>(inner: T, interceptor: F) -> InterceptedService<Self, F>
+        where
+            F: FnMut(tonic::Request<()>) -> Result<tonic::Request<()>, tonic::Status>,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+    }
+    impl<T, B> Service<http::Request<B>> for BackupServiceServer<T>
+    where
+        T: BackupService,
+        B: Body + Send + Sync + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = Never;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let inner = self.inner.clone();
+            match req.uri().path() {
+                "/db.BackupService/backup" => {
                     #[allow(non_camel_case_types)]
-                    struct archiveSvc<T: ViewService>(pub Arc<T>);
-                    impl<T: ViewService> tonic::server::UnaryService<super::RequestViewArchive> for archiveSvc<T> {
-                        type Response = super::super::utils::Resp;
+                    struct backupSvc<T: BackupService>(pub Arc<T>);
+                    impl<T: BackupService> tonic::server::UnaryService<super::RequestBackup> for backupSvc<T> {
+                        type Response = super::ResponseBackup;
                         type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::RequestViewArchive>,
+                            request: tonic::Request<super::RequestBackup>,
                         ) -> Self::Future {
                             let inner = self.0.clone();
-                            let fut = async move { (*inner).archive(request).await };
+                            let fut = async move { (*inner).backup(request).await };
                             Box::pin(fut)
                         }
                     }
@@ -2589,7 +6067,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = archiveSvc(inner);
+                        let method = backupSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
                             accept_compression_encodings,
@@ -2600,18 +6078,120 @@ pub mod view_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/db.ViewService/record" => {
+                _ => Box::pin(async move {
+                    Ok(http::Response::builder()
+                        .status(200)
+                        .header("grpc-status", "12")
+                        .header("content-type", "application/grpc")
+                        .body(empty_body())
+                        .unwrap())
+                }),
+            }
+        }
+    }
+    impl<T: BackupService> Clone for BackupServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+            }
+        }
+    }
+    impl<T: BackupService> Clone for _Inner<T> {
+        fn clone(&self) -> Self {
+            Self(self.0.clone())
+        }
+    }
+    impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:?}", self.0)
+        }
+    }
+    impl<T: BackupService> tonic::transport::NamedService for BackupServiceServer<T> {
+        const NAME: &'static str = "db.BackupService";
+    }
+}
+#[doc = r" Generated server implementations."]
+pub mod transfer_service_server {
+    #![allow(unused_variables, dead_code, missing_docs)]
+    use tonic::codegen::*;
+    #[doc = "Generated trait containing gRPC methods that should be implemented for use with TransferServiceServer."]
+    #[async_trait]
+    pub trait TransferService: Send + Sync + 'static {
+        #[doc = "Server streaming response type for the export method."]
+        type exportStream: futures_core::Stream<Item = Result<super::ResponseExport, tonic::Status>>
+            + Send
+            + Sync
+            + 'static;
+        #[doc = " 以JSON Lines或CSV格式流式导出视图或整库的视图定义、索引定义及数据"]
+        async fn export(
+            &self,
+            request: tonic::Request<super::RequestExport>,
+        ) -> Result<tonic::Response<Self::exportStream>, tonic::Status>;
+        #[doc = " 流式分批导入，逐行返回导入失败原因"]
+        async fn import(
+            &self,
+            request: tonic::Request<tonic::Streaming<super::RequestImport>>,
+        ) -> Result<tonic::Response<super::ResponseImport>, tonic::Status>;
+    }
+    #[derive(Debug)]
+    pub struct TransferServiceServer<T: TransferService> {
+        inner: _Inner<T>,
+        accept_compression_encodings: (),
+        send_compression_encodings: (),
+    }
+    struct _Inner<T>(Arc<T>);
+    impl<T: TransferService> TransferServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            let inner = Arc::new(inner);
+            let inner = _Inner(inner);
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+            }
+        }
+        pub fn with_interceptor<F>(inner: T, interceptor: F) -> InterceptedService<Self, F>
+        where
+            F: FnMut(tonic::Request<()>) -> Result<tonic::Request<()>, tonic::Status>,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+    }
+    impl<T, B> Service<http::Request<B>> for TransferServiceServer<T>
+    where
+        T: TransferService,
+        B: Body + Send + Sync + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = Never;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let inner = self.inner.clone();
+            match req.uri().path() {
+                "/db.TransferService/export" => {
                     #[allow(non_camel_case_types)]
-                    struct recordSvc<T: ViewService>(pub Arc<T>);
-                    impl<T: ViewService> tonic::server::UnaryService<super::RequestViewRecord> for recordSvc<T> {
-                        type Response = super::ResponseViewRecord;
-                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                    struct exportSvc<T: TransferService>(pub Arc<T>);
+                    impl<T: TransferService>
+                        tonic::server::ServerStreamingService<super::RequestExport>
+                        for exportSvc<T>
+                    {
+                        type Response = super::ResponseExport;
+                        type ResponseStream = T::exportStream;
+                        type Future =
+                            BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::RequestViewRecord>,
+                            request: tonic::Request<super::RequestExport>,
                         ) -> Self::Future {
                             let inner = self.0.clone();
-                            let fut = async move { (*inner).record(request).await };
+                            let fut = async move { (*inner).export(request).await };
                             Box::pin(fut)
                         }
                     }
@@ -2620,29 +6200,32 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = recordSvc(inner);
+                        let method = exportSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
                             accept_compression_encodings,
                             send_compression_encodings,
                         );
-                        let res = grpc.unary(method, req).await;
+                        let res = grpc.server_streaming(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/db.ViewService/records" => {
+                "/db.TransferService/import" => {
                     #[allow(non_camel_case_types)]
-                    struct recordsSvc<T: ViewService>(pub Arc<T>);
-                    impl<T: ViewService> tonic::server::UnaryService<super::RequestViewRecords> for recordsSvc<T> {
-                        type Response = super::ResponseViewRecords;
+                    struct importSvc<T: TransferService>(pub Arc<T>);
+                    impl<T: TransferService>
+                        tonic::server::ClientStreamingService<super::RequestImport>
+                        for importSvc<T>
+                    {
+                        type Response = super::ResponseImport;
                         type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::RequestViewRecords>,
+                            request: tonic::Request<tonic::Streaming<super::RequestImport>>,
                         ) -> Self::Future {
                             let inner = self.0.clone();
-                            let fut = async move { (*inner).records(request).await };
+                            let fut = async move { (*inner).import(request).await };
                             Box::pin(fut)
                         }
                     }
@@ -2651,13 +6234,13 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = recordsSvc(inner);
+                        let method = importSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
                             accept_compression_encodings,
                             send_compression_encodings,
                         );
-                        let res = grpc.unary(method, req).await;
+                        let res = grpc.client_streaming(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
@@ -2673,7 +6256,7 @@ pub mod view_service_server {
             }
         }
     }
-    impl<T: ViewService> Clone for ViewServiceServer<T> {
+    impl<T: TransferService> Clone for TransferServiceServer<T> {
         fn clone(&self) -> Self {
             let inner = self.inner.clone();
             Self {
@@ -2683,7 +6266,7 @@ pub mod view_service_server {
             }
         }
     }
-    impl<T: ViewService> Clone for _Inner<T> {
+    impl<T: TransferService> Clone for _Inner<T> {
         fn clone(&self) -> Self {
             Self(self.0.clone())
         }
@@ -2693,41 +6276,56 @@ pub mod view_service_server {
             write!(f, "{:?}", self.0)
         }
     }
-    impl<T: ViewService> tonic::transport::NamedService for ViewServiceServer<T> {
-        const NAME: &'static str = "db.ViewService";
+    impl<T: TransferService> tonic::transport::NamedService for TransferServiceServer<T> {
+        const NAME: &'static str = "db.TransferService";
     }
 }
 #[doc = r" Generated server implementations."]
-pub mod index_service_server {
+pub mod replication_service_server {
     #![allow(unused_variables, dead_code, missing_docs)]
     use tonic::codegen::*;
-    #[doc = "Generated trait containing gRPC methods that should be implemented for use with IndexServiceServer."]
+    #[doc = "Generated trait containing gRPC methods that should be implemented for use with ReplicationServiceServer."]
     #[async_trait]
-    pub trait IndexService: Send + Sync + 'static {
-        #[doc = " 索引集合"]
-        async fn list(
+    pub trait ReplicationService: Send + Sync + 'static {
+        #[doc = "Server streaming response type for the follow method."]
+        type followStream: futures_core::Stream<Item = Result<super::ResponseReplicationFollow, tonic::Status>>
+            + Send
+            + Sync
+            + 'static;
+        #[doc = " 从节点跟随主节点，主节点持续推送指定序号之后的变更"]
+        async fn follow(
             &self,
-            request: tonic::Request<super::RequestIndexList>,
-        ) -> Result<tonic::Response<super::ResponseIndexList>, tonic::Status>;
-        #[doc = " 创建索引"]
-        async fn create(
+            request: tonic::Request<super::RequestReplicationFollow>,
+        ) -> Result<tonic::Response<Self::followStream>, tonic::Status>;
+        #[doc = "Server streaming response type for the snapshot method."]
+        type snapshotStream: futures_core::Stream<Item = Result<super::ResponseReplicationSnapshot, tonic::Status>>
+            + Send
+            + Sync
+            + 'static;
+        #[doc = " 从节点拉取主节点快照，用于空数据从节点的初始同步"]
+        async fn snapshot(
             &self,
-            request: tonic::Request<super::RequestIndexCreate>,
-        ) -> Result<tonic::Response<super::super::utils::Resp>, tonic::Status>;
-        #[doc = " 获取索引详情"]
-        async fn info(
+            request: tonic::Request<super::RequestReplicationSnapshot>,
+        ) -> Result<tonic::Response<Self::snapshotStream>, tonic::Status>;
+        #[doc = " 复制状态，包括角色及复制延迟"]
+        async fn status(
             &self,
-            request: tonic::Request<super::RequestIndexInfo>,
-        ) -> Result<tonic::Response<super::ResponseIndexInfo>, tonic::Status>;
+            request: tonic::Request<super::RequestReplicationStatus>,
+        ) -> Result<tonic::Response<super::ResponseReplicationStatus>, tonic::Status>;
+        #[doc = " 将从节点提升为主节点"]
+        async fn promote(
+            &self,
+            request: tonic::Request<super::RequestReplicationPromote>,
+        ) -> Result<tonic::Response<super::ResponseReplicationPromote>, tonic::Status>;
     }
     #[derive(Debug)]
-    pub struct IndexServiceServer<T: IndexService> {
+    pub struct ReplicationServiceServer<T: ReplicationService> {
         inner: _Inner<T>,
         accept_compression_encodings: (),
         send_compression_encodings: (),
     }
     struct _Inner<T>(Arc<T>);
-    impl<T: IndexService> IndexServiceServer<T> {
+    impl<T: ReplicationService> ReplicationServiceServer<T> {
         pub fn new(inner: T) -> Self {
             let inner = Arc::new(inner);
             let inner = _Inner(inner);
@@ -2744,9 +6342,9 @@ pub mod index_service_server {
             InterceptedService::new(Self::new(inner), interceptor)
         }
     }
-    impl<T, B> Service<http::Request<B>> for IndexServiceServer<T>
+    impl<T, B> Service<http::Request<B>> for ReplicationServiceServer<T>
     where
-        T: IndexService,
+        T: ReplicationService,
         B: Body + Send + Sync + 'static,
         B::Error: Into<StdError> + Send + 'static,
     {
@@ -2759,18 +6357,59 @@ pub mod index_service_server {
         fn call(&mut self, req: http::Request<B>) -> Self::Future {
             let inner = self.inner.clone();
             match req.uri().path() {
-                "/db.IndexService/list" => {
+                "/db.ReplicationService/follow" => {
+                    #[allow(non_camel_case_types)]
+                    struct followSvc<T: ReplicationService>(pub Arc<T>);
+                    impl<T: ReplicationService>
+                        tonic::server::ServerStreamingService<super::RequestReplicationFollow>
+                        for followSvc<T>
+                    {
+                        type Response = super::ResponseReplicationFollow;
+                        type ResponseStream = T::followStream;
+                        type Future =
+                            BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RequestReplicationFollow>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).follow(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = followSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
+                            accept_compression_encodings,
+                            send_compression_encodings,
+                        );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/db.ReplicationService/snapshot" => {
                     #[allow(non_camel_case_types)]
-                    struct listSvc<T: IndexService>(pub Arc<T>);
-                    impl<T: IndexService> tonic::server::UnaryService<super::RequestIndexList> for listSvc<T> {
-                        type Response = super::ResponseIndexList;
-                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                    struct snapshotSvc<T: ReplicationService>(pub Arc<T>);
+                    impl<T: ReplicationService>
+                        tonic::server::ServerStreamingService<super::RequestReplicationSnapshot>
+                        for snapshotSvc<T>
+                    {
+                        type Response = super::ResponseReplicationSnapshot;
+                        type ResponseStream = T::snapshotStream;
+                        type Future =
+                            BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::RequestIndexList>,
+                            request: tonic::Request<super::RequestReplicationSnapshot>,
                         ) -> Self::Future {
                             let inner = self.0.clone();
-                            let fut = async move { (*inner).list(request).await };
+                            let fut = async move { (*inner).snapshot(request).await };
                             Box::pin(fut)
                         }
                     }
@@ -2779,29 +6418,32 @@ pub mod index_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = listSvc(inner);
+                        let method = snapshotSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
                             accept_compression_encodings,
                             send_compression_encodings,
                         );
-                        let res = grpc.unary(method, req).await;
+                        let res = grpc.server_streaming(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/db.IndexService/create" => {
+                "/db.ReplicationService/status" => {
                     #[allow(non_camel_case_types)]
-                    struct createSvc<T: IndexService>(pub Arc<T>);
-                    impl<T: IndexService> tonic::server::UnaryService<super::RequestIndexCreate> for createSvc<T> {
-                        type Response = super::super::utils::Resp;
+                    struct statusSvc<T: ReplicationService>(pub Arc<T>);
+                    impl<T: ReplicationService>
+                        tonic::server::UnaryService<super::RequestReplicationStatus>
+                        for statusSvc<T>
+                    {
+                        type Response = super::ResponseReplicationStatus;
                         type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::RequestIndexCreate>,
+                            request: tonic::Request<super::RequestReplicationStatus>,
                         ) -> Self::Future {
                             let inner = self.0.clone();
-                            let fut = async move { (*inner).create(request).await };
+                            let fut = async move { (*inner).status(request).await };
                             Box::pin(fut)
                         }
                     }
@@ -2810,7 +6452,7 @@ pub mod index_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = createSvc(inner);
+                        let method = statusSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
                             accept_compression_encodings,
@@ -2821,18 +6463,21 @@ pub mod index_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/db.IndexService/info" => {
+                "/db.ReplicationService/promote" => {
                     #[allow(non_camel_case_types)]
-                    struct infoSvc<T: IndexService>(pub Arc<T>);
-                    impl<T: IndexService> tonic::server::UnaryService<super::RequestIndexInfo> for infoSvc<T> {
-                        type Response = super::ResponseIndexInfo;
+                    struct promoteSvc<T: ReplicationService>(pub Arc<T>);
+                    impl<T: ReplicationService>
+                        tonic::server::UnaryService<super::RequestReplicationPromote>
+                        for promoteSvc<T>
+                    {
+                        type Response = super::ResponseReplicationPromote;
                         type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::RequestIndexInfo>,
+                            request: tonic::Request<super::RequestReplicationPromote>,
                         ) -> Self::Future {
                             let inner = self.0.clone();
-                            let fut = async move { (*inner).info(request).await };
+                            let fut = async move { (*inner).promote(request).await };
                             Box::pin(fut)
                         }
                     }
@@ -2841,7 +6486,7 @@ pub mod index_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = infoSvc(inner);
+                        let method = promoteSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
                             accept_compression_encodings,
@@ -2863,7 +6508,7 @@ pub mod index_service_server {
             }
         }
     }
-    impl<T: IndexService> Clone for IndexServiceServer<T> {
+    impl<T: ReplicationService> Clone for ReplicationServiceServer<T> {
         fn clone(&self) -> Self {
             let inner = self.inner.clone();
             Self {
@@ -2873,7 +6518,7 @@ pub mod index_service_server {
             }
         }
     }
-    impl<T: IndexService> Clone for _Inner<T> {
+    impl<T: ReplicationService> Clone for _Inner<T> {
         fn clone(&self) -> Self {
             Self(self.0.clone())
         }
@@ -2883,61 +6528,61 @@ pub mod index_service_server {
             write!(f, "{:?}", self.0)
         }
     }
-    impl<T: IndexService> tonic::transport::NamedService for IndexServiceServer<T> {
-        const NAME: &'static str = "db.IndexService";
+    impl<T: ReplicationService> tonic::transport::NamedService for ReplicationServiceServer<T> {
+        const NAME: &'static str = "db.ReplicationService";
     }
 }
 #[doc = r" Generated server implementations."]
-pub mod disk_service_server {
+pub mod cluster_service_server {
     #![allow(unused_variables, dead_code, missing_docs)]
     use tonic::codegen::*;
-    #[doc = "Generated trait containing gRPC methods that should be implemented for use with DiskServiceServer."]
+    #[doc = "Generated trait containing gRPC methods that should be implemented for use with ClusterServiceServer."]
     #[async_trait]
-    pub trait DiskService: Send + Sync + 'static {
-        #[doc = " 插入数据，如果存在则返回已存在"]
-        async fn put(
+    pub trait ClusterService: Send + Sync + 'static {
+        #[doc = " 候选者请求投票"]
+        async fn vote(
             &self,
-            request: tonic::Request<super::RequestDiskInto>,
-        ) -> Result<tonic::Response<super::super::utils::Resp>, tonic::Status>;
-        #[doc = " 插入数据，无论存在与否都会插入或更新数据"]
-        async fn set(
+            request: tonic::Request<super::RequestClusterVote>,
+        ) -> Result<tonic::Response<super::ResponseClusterVote>, tonic::Status>;
+        #[doc = " 领导者复制日志及心跳"]
+        async fn append(
             &self,
-            request: tonic::Request<super::RequestDiskInto>,
-        ) -> Result<tonic::Response<super::super::utils::Resp>, tonic::Status>;
-        #[doc = " 获取数据，返回存储对象"]
-        async fn get(
+            request: tonic::Request<super::RequestClusterAppend>,
+        ) -> Result<tonic::Response<super::ResponseClusterAppend>, tonic::Status>;
+        #[doc = " 非领导者节点将写入转发至领导者"]
+        async fn forward(
             &self,
-            request: tonic::Request<super::RequestDiskOut>,
-        ) -> Result<tonic::Response<super::ResponseDiskOut>, tonic::Status>;
-        #[doc = " 根据指定索引名称获取数据，返回存储对象"]
-        async fn get_by_index(
+            request: tonic::Request<super::RequestClusterForward>,
+        ) -> Result<tonic::Response<super::ResponseClusterForward>, tonic::Status>;
+        #[doc = " 领导者向所需条目已被丢弃的跟随者分块发送快照"]
+        async fn install(
             &self,
-            request: tonic::Request<super::RequestDiskIOut>,
-        ) -> Result<tonic::Response<super::ResponseDiskOut>, tonic::Status>;
-        #[doc = " 删除数据"]
-        async fn remove(
+            request: tonic::Request<super::RequestClusterInstall>,
+        ) -> Result<tonic::Response<super::ResponseClusterInstall>, tonic::Status>;
+        #[doc = " 集群状态，包括角色、任期、提交进度及节点集合"]
+        async fn status(
             &self,
-            request: tonic::Request<super::RequestDiskRemove>,
-        ) -> Result<tonic::Response<super::super::utils::Resp>, tonic::Status>;
-        #[doc = " 条件检索"]
-        async fn select(
+            request: tonic::Request<super::RequestClusterStatus>,
+        ) -> Result<tonic::Response<super::ResponseClusterStatus>, tonic::Status>;
+        #[doc = " 新增节点"]
+        async fn join(
             &self,
-            request: tonic::Request<super::RequestDiskSelect>,
-        ) -> Result<tonic::Response<super::ResponseDiskSelect>, tonic::Status>;
-        #[doc = " 条件删除"]
-        async fn delete(
+            request: tonic::Request<super::RequestClusterJoin>,
+        ) -> Result<tonic::Response<super::ResponseClusterJoin>, tonic::Status>;
+        #[doc = " 移除节点"]
+        async fn leave(
             &self,
-            request: tonic::Request<super::RequestDiskDelete>,
-        ) -> Result<tonic::Response<super::ResponseDiskDelete>, tonic::Status>;
+            request: tonic::Request<super::RequestClusterLeave>,
+        ) -> Result<tonic::Response<super::ResponseClusterLeave>, tonic::Status>;
     }
     #[derive(Debug)]
-    pub struct DiskServiceServer<T: DiskService> {
+    pub struct ClusterServiceServer<T: ClusterService> {
         inner: _Inner<T>,
         accept_compression_encodings: (),
         send_compression_encodings: (),
     }
     struct _Inner<T>(Arc<T>);
-    impl<T: DiskService> DiskServiceServer<T> {
+    impl<T: ClusterService> ClusterServiceServer<T> {
         pub fn new(inner: T) -> Self {
             let inner = Arc::new(inner);
             let inner = _Inner(inner);
@@ -2954,9 +6599,9 @@ pub mod disk_service_server {
             InterceptedService::new(Self::new(inner), interceptor)
         }
     }
-    impl<T, B> Service<http::Request<B>> for DiskServiceServer<T>
+    impl<T, B> Service<http::Request<B>> for ClusterServiceServer<T>
     where
-        T: DiskService,
+        T: ClusterService,
         B: Body + Send + Sync + 'static,
         B::Error: Into<StdError> + Send + 'static,
     {
@@ -2969,18 +6614,18 @@ pub mod disk_service_server {
         fn call(&mut self, req: http::Request<B>) -> Self::Future {
             let inner = self.inner.clone();
             match req.uri().path() {
-                "/db.DiskService/put" => {
+                "/db.ClusterService/vote" => {
                     #[allow(non_camel_case_types)]
-                    struct putSvc<T: DiskService>(pub Arc<T>);
-                    impl<T: DiskService> tonic::server::UnaryService<super::RequestDiskInto> for putSvc<T> {
-                        type Response = super::super::utils::Resp;
+                    struct voteSvc<T: ClusterService>(pub Arc<T>);
+                    impl<T: ClusterService> tonic::server::UnaryService<super::RequestClusterVote> for voteSvc<T> {
+                        type Response = super::ResponseClusterVote;
                         type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::RequestDiskInto>,
+                            request: tonic::Request<super::RequestClusterVote>,
                         ) -> Self::Future {
                             let inner = self.0.clone();
-                            let fut = async move { (*inner).put(request).await };
+                            let fut = async move { (*inner).vote(request).await };
                             Box::pin(fut)
                         }
                     }
@@ -2989,7 +6634,7 @@ pub mod disk_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = putSvc(inner);
+                        let method = voteSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
                             accept_compression_encodings,
@@ -3000,18 +6645,18 @@ pub mod disk_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/db.DiskService/set" => {
+                "/db.ClusterService/append" => {
                     #[allow(non_camel_case_types)]
-                    struct setSvc<T: DiskService>(pub Arc<T>);
-                    impl<T: DiskService> tonic::server::UnaryService<super::RequestDiskInto> for setSvc<T> {
-                        type Response = super::super::utils::Resp;
+                    struct appendSvc<T: ClusterService>(pub Arc<T>);
+                    impl<T: ClusterService> tonic::server::UnaryService<super::RequestClusterAppend> for appendSvc<T> {
+                        type Response = super::ResponseClusterAppend;
                         type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::RequestDiskInto>,
+                            request: tonic::Request<super::RequestClusterAppend>,
                         ) -> Self::Future {
                             let inner = self.0.clone();
-                            let fut = async move { (*inner).set(request).await };
+                            let fut = async move { (*inner).append(request).await };
                             Box::pin(fut)
                         }
                     }
@@ -3020,7 +6665,7 @@ pub mod disk_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = setSvc(inner);
+                        let method = appendSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
                             accept_compression_encodings,
@@ -3031,18 +6676,21 @@ pub mod disk_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/db.DiskService/get" => {
+                "/db.ClusterService/forward" => {
                     #[allow(non_camel_case_types)]
-                    struct getSvc<T: DiskService>(pub Arc<T>);
-                    impl<T: DiskService> tonic::server::UnaryService<super::RequestDiskOut> for getSvc<T> {
-                        type Response = super::ResponseDiskOut;
+                    struct forwardSvc<T: ClusterService>(pub Arc<T>);
+                    impl<T: ClusterService>
+                        tonic::server::UnaryService<super::RequestClusterForward>
+                        for forwardSvc<T>
+                    {
+                        type Response = super::ResponseClusterForward;
                         type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::RequestDiskOut>,
+                            request: tonic::Request<super::RequestClusterForward>,
                         ) -> Self::Future {
                             let inner = self.0.clone();
-                            let fut = async move { (*inner).get(request).await };
+                            let fut = async move { (*inner).forward(request).await };
                             Box::pin(fut)
                         }
                     }
@@ -3051,7 +6699,7 @@ pub mod disk_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = getSvc(inner);
+                        let method = forwardSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
                             accept_compression_encodings,
@@ -3062,18 +6710,21 @@ pub mod disk_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/db.DiskService/get_by_index" => {
+                "/db.ClusterService/install" => {
                     #[allow(non_camel_case_types)]
-                    struct get_by_indexSvc<T: DiskService>(pub Arc<T>);
-                    impl<T: DiskService> tonic::server::UnaryService<super::RequestDiskIOut> for get_by_indexSvc<T> {
-                        type Response = super::ResponseDiskOut;
+                    struct installSvc<T: ClusterService>(pub Arc<T>);
+                    impl<T: ClusterService>
+                        tonic::server::UnaryService<super::RequestClusterInstall>
+                        for installSvc<T>
+                    {
+                        type Response = super::ResponseClusterInstall;
                         type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::RequestDiskIOut>,
+                            request: tonic::Request<super::RequestClusterInstall>,
                         ) -> Self::Future {
                             let inner = self.0.clone();
-                            let fut = async move { (*inner).get_by_index(request).await };
+                            let fut = async move { (*inner).install(request).await };
                             Box::pin(fut)
                         }
                     }
@@ -3082,7 +6733,7 @@ pub mod disk_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = get_by_indexSvc(inner);
+                        let method = installSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
                             accept_compression_encodings,
@@ -3093,18 +6744,18 @@ pub mod disk_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/db.DiskService/remove" => {
+                "/db.ClusterService/status" => {
                     #[allow(non_camel_case_types)]
-                    struct removeSvc<T: DiskService>(pub Arc<T>);
-                    impl<T: DiskService> tonic::server::UnaryService<super::RequestDiskRemove> for removeSvc<T> {
-                        type Response = super::super::utils::Resp;
+                    struct statusSvc<T: ClusterService>(pub Arc<T>);
+                    impl<T: ClusterService> tonic::server::UnaryService<super::RequestClusterStatus> for statusSvc<T> {
+                        type Response = super::ResponseClusterStatus;
                         type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::RequestDiskRemove>,
+                            request: tonic::Request<super::RequestClusterStatus>,
                         ) -> Self::Future {
                             let inner = self.0.clone();
-                            let fut = async move { (*inner).remove(request).await };
+                            let fut = async move { (*inner).status(request).await };
                             Box::pin(fut)
                         }
                     }
@@ -3113,7 +6764,7 @@ pub mod disk_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = removeSvc(inner);
+                        let method = statusSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
                             accept_compression_encodings,
@@ -3124,18 +6775,18 @@ pub mod disk_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/db.DiskService/select" => {
+                "/db.ClusterService/join" => {
                     #[allow(non_camel_case_types)]
-                    struct selectSvc<T: DiskService>(pub Arc<T>);
-                    impl<T: DiskService> tonic::server::UnaryService<super::RequestDiskSelect> for selectSvc<T> {
-                        type Response = super::ResponseDiskSelect;
+                    struct joinSvc<T: ClusterService>(pub Arc<T>);
+                    impl<T: ClusterService> tonic::server::UnaryService<super::RequestClusterJoin> for joinSvc<T> {
+                        type Response = super::ResponseClusterJoin;
                         type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::RequestDiskSelect>,
+                            request: tonic::Request<super::RequestClusterJoin>,
                         ) -> Self::Future {
                             let inner = self.0.clone();
-                            let fut = async move { (*inner).select(request).await };
+                            let fut = async move { (*inner).join(request).await };
                             Box::pin(fut)
                         }
                     }
@@ -3144,7 +6795,7 @@ pub mod disk_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = selectSvc(inner);
+                        let method = joinSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
                             accept_compression_encodings,
@@ -3155,18 +6806,18 @@ pub mod disk_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/db.DiskService/delete" => {
+                "/db.ClusterService/leave" => {
                     #[allow(non_camel_case_types)]
-                    struct deleteSvc<T: DiskService>(pub Arc<T>);
-                    impl<T: DiskService> tonic::server::UnaryService<super::RequestDiskDelete> for deleteSvc<T> {
-                        type Response = super::ResponseDiskDelete;
+                    struct leaveSvc<T: ClusterService>(pub Arc<T>);
+                    impl<T: ClusterService> tonic::server::UnaryService<super::RequestClusterLeave> for leaveSvc<T> {
+                        type Response = super::ResponseClusterLeave;
                         type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::RequestDiskDelete>,
+                            request: tonic::Request<super::RequestClusterLeave>,
                         ) -> Self::Future {
                             let inner = self.0.clone();
-                            let fut = async move { (*inner).delete(request).await };
+                            let fut = async move { (*inner).leave(request).await };
                             Box::pin(fut)
                         }
                     }
@@ -3175,7 +6826,7 @@ pub mod disk_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = deleteSvc(inner);
+                        let method = leaveSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
                             accept_compression_encodings,
@@ -3197,7 +6848,7 @@ pub mod disk_service_server {
             }
         }
     }
-    impl<T: DiskService> Clone for DiskServiceServer<T> {
+    impl<T: ClusterService> Clone for ClusterServiceServer<T> {
         fn clone(&self) -> Self {
             let inner = self.inner.clone();
             Self {
@@ -3207,7 +6858,7 @@ pub mod disk_service_server {
             }
         }
     }
-    impl<T: DiskService> Clone for _Inner<T> {
+    impl<T: ClusterService> Clone for _Inner<T> {
         fn clone(&self) -> Self {
             Self(self.0.clone())
         }
@@ -3217,66 +6868,61 @@ pub mod disk_service_server {
             write!(f, "{:?}", self.0)
         }
     }
-    impl<T: DiskService> tonic::transport::NamedService for DiskServiceServer<T> {
-        const NAME: &'static str = "db.DiskService";
+    impl<T: ClusterService> tonic::transport::NamedService for ClusterServiceServer<T> {
+        const NAME: &'static str = "db.ClusterService";
     }
 }
 #[doc = r" Generated server implementations."]
-pub mod memory_service_server {
+pub mod ledger_service_server {
     #![allow(unused_variables, dead_code, missing_docs)]
     use tonic::codegen::*;
-    #[doc = "Generated trait containing gRPC methods that should be implemented for use with MemoryServiceServer."]
+    #[doc = "Generated trait containing gRPC methods that should be implemented for use with LedgerServiceServer."]
     #[async_trait]
-    pub trait MemoryService: Send + Sync + 'static {
-        #[doc = " 插入数据，如果存在则返回已存在"]
-        async fn put(
+    pub trait LedgerService: Send + Sync + 'static {
+        #[doc = " 账本集合"]
+        async fn list(
             &self,
-            request: tonic::Request<super::RequestMemoryInto>,
-        ) -> Result<tonic::Response<super::super::utils::Resp>, tonic::Status>;
-        #[doc = " 插入数据，无论存在与否都会插入或更新数据"]
-        async fn set(
+            request: tonic::Request<super::super::utils::Req>,
+        ) -> Result<tonic::Response<super::ResponseLedgerList>, tonic::Status>;
+        #[doc = " 创建账本"]
+        async fn create(
             &self,
-            request: tonic::Request<super::RequestMemoryInto>,
+            request: tonic::Request<super::RequestLedgerCreate>,
         ) -> Result<tonic::Response<super::super::utils::Resp>, tonic::Status>;
-        #[doc = " 获取数据，返回存储对象"]
-        async fn get(
+        #[doc = " 获取账本详情"]
+        async fn info(
             &self,
-            request: tonic::Request<super::RequestMemoryOut>,
-        ) -> Result<tonic::Response<super::ResponseMemoryOut>, tonic::Status>;
-        #[doc = " 删除数据"]
+            request: tonic::Request<super::RequestLedgerInfo>,
+        ) -> Result<tonic::Response<super::ResponseLedgerInfo>, tonic::Status>;
+        #[doc = " 删除账本"]
         async fn remove(
             &self,
-            request: tonic::Request<super::RequestMemoryRemove>,
-        ) -> Result<tonic::Response<super::super::utils::Resp>, tonic::Status>;
-        #[doc = " 在指定缓存页中插入数据，如果存在则返回已存在"]
-        async fn put_by_page(
-            &self,
-            request: tonic::Request<super::RequestMemoryPInto>,
+            request: tonic::Request<super::RequestLedgerRemove>,
         ) -> Result<tonic::Response<super::super::utils::Resp>, tonic::Status>;
-        #[doc = " 在指定缓存页中插入数据，无论存在与否都会插入或更新数据"]
-        async fn set_by_page(
+        #[doc = " 将交易集合打包为新区块追加至账本"]
+        async fn insert(
             &self,
-            request: tonic::Request<super::RequestMemoryPInto>,
-        ) -> Result<tonic::Response<super::super::utils::Resp>, tonic::Status>;
-        #[doc = " 在指定缓存页中获取数据，返回存储对象"]
-        async fn get_by_page(
+            request: tonic::Request<super::RequestBlockInsert>,
+        ) -> Result<tonic::Response<super::ResponseBlock>, tonic::Status>;
+        #[doc = " 根据区块高度或hash获取区块"]
+        async fn block(
             &self,
-            request: tonic::Request<super::RequestMemoryPOut>,
-        ) -> Result<tonic::Response<super::ResponseMemoryPOut>, tonic::Status>;
-        #[doc = " 在指定缓存页中删除数据"]
-        async fn remove_by_page(
+            request: tonic::Request<super::RequestBlockGet>,
+        ) -> Result<tonic::Response<super::ResponseBlock>, tonic::Status>;
+        #[doc = " 根据交易hash获取交易及所在区块"]
+        async fn transaction(
             &self,
-            request: tonic::Request<super::RequestMemoryPRemove>,
-        ) -> Result<tonic::Response<super::super::utils::Resp>, tonic::Status>;
+            request: tonic::Request<super::RequestTransactionGet>,
+        ) -> Result<tonic::Response<super::ResponseTransaction>, tonic::Status>;
     }
     #[derive(Debug)]
-    pub struct MemoryServiceServer<T: MemoryService> {
+    pub struct LedgerServiceServer<T: LedgerService> {
         inner: _Inner<T>,
         accept_compression_encodings: (),
         send_compression_encodings: (),
     }
     struct _Inner<T>(Arc<T>);
-    impl<T: MemoryService> MemoryServiceServer<T> {
+    impl<T: LedgerService> LedgerServiceServer<T> {
         pub fn new(inner: T) -> Self {
             let inner = Arc::new(inner);
             let inner = _Inner(inner);
@@ -3293,9 +6939,9 @@ pub mod memory_service_server {
             InterceptedService::new(Self::new(inner), interceptor)
         }
     }
-    impl<T, B> Service<http::Request<B>> for MemoryServiceServer<T>
+    impl<T, B> Service<http::Request<B>> for LedgerServiceServer<T>
     where
-        T: MemoryService,
+        T: LedgerService,
         B: Body + Send + Sync + 'static,
         B::Error: Into<StdError> + Send + 'static,
     {
@@ -3308,18 +6954,18 @@ pub mod memory_service_server {
         fn call(&mut self, req: http::Request<B>) -> Self::Future {
             let inner = self.inner.clone();
             match req.uri().path() {
-                "/db.MemoryService/put" => {
+                "/db.LedgerService/list" => {
                     #[allow(non_camel_case_types)]
-                    struct putSvc<T: MemoryService>(pub Arc<T>);
-                    impl<T: MemoryService> tonic::server::UnaryService<super::RequestMemoryInto> for putSvc<T> {
-                        type Response = super::super::utils::Resp;
+                    struct listSvc<T: LedgerService>(pub Arc<T>);
+                    impl<T: LedgerService> tonic::server::UnaryService<super::super::utils::Req> for listSvc<T> {
+                        type Response = super::ResponseLedgerList;
                         type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::RequestMemoryInto>,
+                            request: tonic::Request<super::super::utils::Req>,
                         ) -> Self::Future {
                             let inner = self.0.clone();
-                            let fut = async move { (*inner).put(request).await };
+                            let fut = async move { (*inner).list(request).await };
                             Box::pin(fut)
                         }
                     }
@@ -3328,7 +6974,7 @@ pub mod memory_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = putSvc(inner);
+                        let method = listSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
                             accept_compression_encodings,
@@ -3339,18 +6985,18 @@ pub mod memory_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/db.MemoryService/set" => {
+                "/db.LedgerService/create" => {
                     #[allow(non_camel_case_types)]
-                    struct setSvc<T: MemoryService>(pub Arc<T>);
-                    impl<T: MemoryService> tonic::server::UnaryService<super::RequestMemoryInto> for setSvc<T> {
+                    struct createSvc<T: LedgerService>(pub Arc<T>);
+                    impl<T: LedgerService> tonic::server::UnaryService<super::RequestLedgerCreate> for createSvc<T> {
                         type Response = super::super::utils::Resp;
                         type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::RequestMemoryInto>,
+                            request: tonic::Request<super::RequestLedgerCreate>,
                         ) -> Self::Future {
                             let inner = self.0.clone();
-                            let fut = async move { (*inner).set(request).await };
+                            let fut = async move { (*inner).create(request).await };
                             Box::pin(fut)
                         }
                     }
@@ -3359,7 +7005,7 @@ pub mod memory_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = setSvc(inner);
+                        let method = createSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
                             accept_compression_encodings,
@@ -3370,18 +7016,18 @@ pub mod memory_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/db.MemoryService/get" => {
+                "/db.LedgerService/info" => {
                     #[allow(non_camel_case_types)]
-                    struct getSvc<T: MemoryService>(pub Arc<T>);
-                    impl<T: MemoryService> tonic::server::UnaryService<super::RequestMemoryOut> for getSvc<T> {
-                        type Response = super::ResponseMemoryOut;
+                    struct infoSvc<T: LedgerService>(pub Arc<T>);
+                    impl<T: LedgerService> tonic::server::UnaryService<super::RequestLedgerInfo> for infoSvc<T> {
+                        type Response = super::ResponseLedgerInfo;
                         type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::RequestMemoryOut>,
+                            request: tonic::Request<super::RequestLedgerInfo>,
                         ) -> Self::Future {
                             let inner = self.0.clone();
-                            let fut = async move { (*inner).get(request).await };
+                            let fut = async move { (*inner).info(request).await };
                             Box::pin(fut)
                         }
                     }
@@ -3390,7 +7036,7 @@ pub mod memory_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = getSvc(inner);
+                        let method = infoSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
                             accept_compression_encodings,
@@ -3401,15 +7047,15 @@ pub mod memory_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/db.MemoryService/remove" => {
+                "/db.LedgerService/remove" => {
                     #[allow(non_camel_case_types)]
-                    struct removeSvc<T: MemoryService>(pub Arc<T>);
-                    impl<T: MemoryService> tonic::server::UnaryService<super::RequestMemoryRemove> for removeSvc<T> {
+                    struct removeSvc<T: LedgerService>(pub Arc<T>);
+                    impl<T: LedgerService> tonic::server::UnaryService<super::RequestLedgerRemove> for removeSvc<T> {
                         type Response = super::super::utils::Resp;
                         type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::RequestMemoryRemove>,
+                            request: tonic::Request<super::RequestLedgerRemove>,
                         ) -> Self::Future {
                             let inner = self.0.clone();
                             let fut = async move { (*inner).remove(request).await };
@@ -3432,53 +7078,18 @@ pub mod memory_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/db.MemoryService/put_by_page" => {
-                    #[allow(non_camel_case_types)]
-                    struct put_by_pageSvc<T: MemoryService>(pub Arc<T>);
-                    impl<T: MemoryService> tonic::server::UnaryService<super::RequestMemoryPInto>
-                        for put_by_pageSvc<T>
-                    {
-                        type Response = super::super::utils::Resp;
-                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
-                        fn call(
-                            &mut self,
-                            request: tonic::Request<super::RequestMemoryPInto>,
-                        ) -> Self::Future {
-                            let inner = self.0.clone();
-                            let fut = async move { (*inner).put_by_page(request).await };
-                            Box::pin(fut)
-                        }
-                    }
-                    let accept_compression_encodings = self.accept_compression_encodings;
-                    let send_compression_encodings = self.send_compression_encodings;
-                    let inner = self.inner.clone();
-                    let fut = async move {
-                        let inner = inner.0;
-                        let method = put_by_pageSvc(inner);
-                        let codec = tonic::codec::ProstCodec::default();
-                        let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
-                            accept_compression_encodings,
-                            send_compression_encodings,
-                        );
-                        let res = grpc.unary(method, req).await;
-                        Ok(res)
-                    };
-                    Box::pin(fut)
-                }
-                "/db.MemoryService/set_by_page" => {
+                "/db.LedgerService/insert" => {
                     #[allow(non_camel_case_types)]
-                    struct set_by_pageSvc<T: MemoryService>(pub Arc<T>);
-                    impl<T: MemoryService> tonic::server::UnaryService<super::RequestMemoryPInto>
-                        for set_by_pageSvc<T>
-                    {
-                        type Response = super::super::utils::Resp;
+                    struct insertSvc<T: LedgerService>(pub Arc<T>);
+                    impl<T: LedgerService> tonic::server::UnaryService<super::RequestBlockInsert> for insertSvc<T> {
+                        type Response = super::ResponseBlock;
                         type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::RequestMemoryPInto>,
+                            request: tonic::Request<super::RequestBlockInsert>,
                         ) -> Self::Future {
                             let inner = self.0.clone();
-                            let fut = async move { (*inner).set_by_page(request).await };
+                            let fut = async move { (*inner).insert(request).await };
                             Box::pin(fut)
                         }
                     }
@@ -3487,7 +7098,7 @@ pub mod memory_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = set_by_pageSvc(inner);
+                        let method = insertSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
                             accept_compression_encodings,
@@ -3498,18 +7109,18 @@ pub mod memory_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/db.MemoryService/get_by_page" => {
+                "/db.LedgerService/block" => {
                     #[allow(non_camel_case_types)]
-                    struct get_by_pageSvc<T: MemoryService>(pub Arc<T>);
-                    impl<T: MemoryService> tonic::server::UnaryService<super::RequestMemoryPOut> for get_by_pageSvc<T> {
-                        type Response = super::ResponseMemoryPOut;
+                    struct blockSvc<T: LedgerService>(pub Arc<T>);
+                    impl<T: LedgerService> tonic::server::UnaryService<super::RequestBlockGet> for blockSvc<T> {
+                        type Response = super::ResponseBlock;
                         type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::RequestMemoryPOut>,
+                            request: tonic::Request<super::RequestBlockGet>,
                         ) -> Self::Future {
                             let inner = self.0.clone();
-                            let fut = async move { (*inner).get_by_page(request).await };
+                            let fut = async move { (*inner).block(request).await };
                             Box::pin(fut)
                         }
                     }
@@ -3518,7 +7129,7 @@ pub mod memory_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = get_by_pageSvc(inner);
+                        let method = blockSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
                             accept_compression_encodings,
@@ -3529,20 +7140,20 @@ pub mod memory_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/db.MemoryService/remove_by_page" => {
+                "/db.LedgerService/transaction" => {
                     #[allow(non_camel_case_types)]
-                    struct remove_by_pageSvc<T: MemoryService>(pub Arc<T>);
-                    impl<T: MemoryService> tonic::server::UnaryService<super::RequestMemoryPRemove>
-                        for remove_by_pageSvc<T>
+                    struct transactionSvc<T: LedgerService>(pub Arc<T>);
+                    impl<T: LedgerService> tonic::server::UnaryService<super::RequestTransactionGet>
+                        for transactionSvc<T>
                     {
-                        type Response = super::super::utils::Resp;
+                        type Response = super::ResponseTransaction;
                         type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::RequestMemoryPRemove>,
+                            request: tonic::Request<super::RequestTransactionGet>,
                         ) -> Self::Future {
                             let inner = self.0.clone();
-                            let fut = async move { (*inner).remove_by_page(request).await };
+                            let fut = async move { (*inner).transaction(request).await };
                             Box::pin(fut)
                         }
                     }
@@ -3551,7 +7162,7 @@ pub mod memory_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = remove_by_pageSvc(inner);
+                        let method = transactionSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
                             accept_compression_encodings,
@@ -3573,7 +7184,7 @@ pub mod memory_service_server {
             }
         }
     }
-    impl<T: MemoryService> Clone for MemoryServiceServer<T> {
+    impl<T: LedgerService> Clone for LedgerServiceServer<T> {
         fn clone(&self) -> Self {
             let inner = self.inner.clone();
             Self {
@@ -3583,7 +7194,7 @@ pub mod memory_service_server {
             }
         }
     }
-    impl<T: MemoryService> Clone for _Inner<T> {
+    impl<T: LedgerService> Clone for _Inner<T> {
         fn clone(&self) -> Self {
             Self(self.0.clone())
         }
@@ -3593,7 +7204,7 @@ pub mod memory_service_server {
             write!(f, "{:?}", self.0)
         }
     }
-    impl<T: MemoryService> tonic::transport::NamedService for MemoryServiceServer<T> {
-        const NAME: &'static str = "db.MemoryService";
+    impl<T: LedgerService> tonic::transport::NamedService for LedgerServiceServer<T> {
+        const NAME: &'static str = "db.LedgerService";
     }
 }
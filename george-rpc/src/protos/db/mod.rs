@@ -15,3 +15,7 @@
 use super::utils::*;
 
 pub mod db;
+
+/// `protos/db`下所有服务及消息的描述集合，供服务端反射使用
+pub const FILE_DESCRIPTOR_SET: &[u8] =
+    include_bytes!(concat!(env!("OUT_DIR"), "/db_descriptor.bin"));
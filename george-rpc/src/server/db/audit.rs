@@ -0,0 +1,115 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::sync::Arc;
+
+use tonic::{Request, Response, Status};
+
+use george_db::Task;
+
+use crate::protos::db::db::audit_service_server::AuditService;
+use crate::protos::db::db::{
+    AuditRecord, RequestAuditList, ResponseAuditList, ResponseAuditVerify,
+};
+use crate::protos::utils::utils::{Req, Timestamp};
+use crate::server::db::AuditServer;
+use crate::tools::{Results, Trace};
+
+impl AuditServer {
+    pub fn new(task: Arc<Task>) -> Self {
+        AuditServer { task }
+    }
+}
+
+#[tonic::async_trait]
+impl AuditService for AuditServer {
+    async fn list(
+        &self,
+        request: Request<RequestAuditList>,
+    ) -> Result<Response<ResponseAuditList>, Status> {
+        let trace = Trace::new(
+            &self.task,
+            &request,
+            "audit.list",
+            request.get_ref().principal.clone(),
+        );
+        let resp;
+        match trace.admin().and_then(|()| {
+            self.task.audit_records(
+                request.get_ref().principal.clone(),
+                request.get_ref().operation.clone(),
+                request.get_ref().start,
+                request.get_ref().end,
+                request.get_ref().limit,
+            )
+        }) {
+            Ok(res) => {
+                let mut records: Vec<AuditRecord> = vec![];
+                for record in res {
+                    records.push(AuditRecord {
+                        seq: record.seq,
+                        time: Some(Timestamp {
+                            seconds: record.time / 1_000_000_000,
+                            nanos: (record.time % 1_000_000_000) as i32,
+                        }),
+                        principal: record.principal,
+                        remote: record.remote,
+                        operation: record.operation,
+                        target: record.target,
+                        success: record.success,
+                        msg_err: record.msg_err,
+                        latency: record.latency,
+                        prev_hash: record.prev_hash,
+                        hash: record.hash,
+                    })
+                }
+                resp = ResponseAuditList {
+                    status: Results::success_status(),
+                    msg_err: "".to_string(),
+                    records,
+                }
+            }
+            Err(err) => {
+                resp = ResponseAuditList {
+                    status: trace.failed_status(err.clone()),
+                    msg_err: err.to_string(),
+                    records: vec![],
+                };
+            }
+        }
+        trace.finish(&self.task, Results::response(resp))
+    }
+
+    async fn verify(&self, request: Request<Req>) -> Result<Response<ResponseAuditVerify>, Status> {
+        let trace = Trace::new(&self.task, &request, "audit.verify", "".to_string());
+        let resp;
+        match trace.admin().and_then(|()| self.task.audit_verify()) {
+            Ok(count) => {
+                resp = ResponseAuditVerify {
+                    status: Results::success_status(),
+                    msg_err: "".to_string(),
+                    count,
+                }
+            }
+            Err(err) => {
+                resp = ResponseAuditVerify {
+                    status: trace.failed_status(err.clone()),
+                    msg_err: err.to_string(),
+                    count: 0,
+                };
+            }
+        }
+        trace.finish(&self.task, Results::response(resp))
+    }
+}
@@ -0,0 +1,70 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::sync::Arc;
+
+use tonic::{Request, Response, Status};
+
+use george_db::Task;
+
+use crate::protos::db::db::backup_service_server::BackupService;
+use crate::protos::db::db::{RequestBackup, ResponseBackup};
+use crate::server::db::BackupServer;
+use crate::tools::{Results, Trace};
+
+impl BackupServer {
+    pub fn new(task: Arc<Task>) -> Self {
+        BackupServer { task }
+    }
+}
+
+#[tonic::async_trait]
+impl BackupService for BackupServer {
+    async fn backup(
+        &self,
+        request: Request<RequestBackup>,
+    ) -> Result<Response<ResponseBackup>, Status> {
+        let trace = Trace::new(
+            &self.task,
+            &request,
+            "backup.backup",
+            request.get_ref().dir.clone(),
+        );
+        let resp;
+        match trace.admin().and_then(|()| {
+            self.task
+                .backup(request.get_ref().dir.clone(), request.get_ref().compress)
+        }) {
+            Ok(manifest) => {
+                resp = ResponseBackup {
+                    status: Results::success_status(),
+                    msg_err: "".to_string(),
+                    seq: manifest.seq,
+                    files: manifest.files.len() as u32,
+                    bytes: manifest.files.iter().map(|file| file.size).sum(),
+                }
+            }
+            Err(err) => {
+                resp = ResponseBackup {
+                    status: trace.failed_status(err.clone()),
+                    msg_err: err.to_string(),
+                    seq: 0,
+                    files: 0,
+                    bytes: 0,
+                };
+            }
+        }
+        trace.finish(&self.task, Results::response(resp))
+    }
+}
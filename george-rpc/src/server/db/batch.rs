@@ -0,0 +1,190 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::sync::Arc;
+
+use tonic::{Response, Status, Streaming};
+
+use george_comm::errors::{Errs, GeorgeResult};
+use george_db::task::batch::{BatchOp, BatchReport, BatchTarget};
+use george_db::Task;
+
+use crate::protos::db::db::{BatchError, RequestDiskBatch, RequestMemoryBatch, ResponseBatch};
+use crate::server::db::DATABASE_SYS;
+use crate::tools::{Results, Trace};
+
+/// 批量写入请求，首个请求确定写入目标及是否全部成功或全部失败
+pub(crate) trait BatchRequest {
+    /// 写入目标
+    fn target(&self) -> BatchTarget;
+
+    /// 审计记录中的操作对象
+    fn trace_target(&self) -> String;
+
+    /// 是否全部成功或全部失败
+    fn atomic(&self) -> bool;
+
+    /// 键及值集合
+    fn items(self) -> Vec<(String, Vec<u8>)>;
+}
+
+impl BatchRequest for RequestDiskBatch {
+    fn target(&self) -> BatchTarget {
+        BatchTarget::Disk {
+            database_name: self.database_name.clone(),
+            view_name: self.view_name.clone(),
+        }
+    }
+
+    fn trace_target(&self) -> String {
+        format!("{}/{}", self.database_name, self.view_name)
+    }
+
+    fn atomic(&self) -> bool {
+        self.atomic
+    }
+
+    fn items(self) -> Vec<(String, Vec<u8>)> {
+        self.items
+            .into_iter()
+            .map(|item| (item.key, item.value))
+            .collect()
+    }
+}
+
+impl BatchRequest for RequestMemoryBatch {
+    fn target(&self) -> BatchTarget {
+        BatchTarget::Memory {
+            page_name: self.trace_target(),
+        }
+    }
+
+    fn trace_target(&self) -> String {
+        if self.page_name.is_empty() {
+            DATABASE_SYS.to_string()
+        } else {
+            self.page_name.clone()
+        }
+    }
+
+    fn atomic(&self) -> bool {
+        self.atomic
+    }
+
+    fn items(self) -> Vec<(String, Vec<u8>)> {
+        self.items
+            .into_iter()
+            .map(|item| (item.key, item.value))
+            .collect()
+    }
+}
+
+/// 逐个读取批量写入请求并写入其中的数据项
+///
+/// 全部成功或全部失败时，任一数据项失败或请求流中断即停止读取，并回滚已写入的数据项。
+/// 写入及回滚为阻塞操作，在阻塞线程中执行，避免占用异步运行时的工作线程
+pub(crate) async fn batch<R: BatchRequest + Send + 'static>(
+    task: &Arc<Task>,
+    mut trace: Trace,
+    mut stream: Streaming<R>,
+    op: BatchOp,
+) -> Result<Response<ResponseBatch>, Status> {
+    let mut report = BatchReport::default();
+    let result: GeorgeResult<()> = match stream.message().await {
+        Ok(Some(head)) => {
+            trace.target(head.trace_target());
+            let target = head.target();
+            let atomic = head.atomic();
+            let mut message = Some(head);
+            let result = loop {
+                let req = match message {
+                    Some(req) => req,
+                    None => break Ok(()),
+                };
+                let (task, target) = (task.clone(), target.clone());
+                let principal = trace.principal();
+                let mut batch = report;
+                report = match tokio::task::spawn_blocking(move || {
+                    task.batch(&target, op, atomic, principal, req.items(), &mut batch);
+                    batch
+                })
+                .await
+                {
+                    Ok(res) => res,
+                    Err(err) => {
+                        report = BatchReport::default();
+                        break Err(Errs::strs("batch task", err));
+                    }
+                };
+                if atomic && report.failed > 0 {
+                    let (index, key, msg_err) = report.errors[0].clone();
+                    break Err(Errs::string(format!(
+                        "batch item {} with key {} failed! error is {}",
+                        index, key, msg_err
+                    )));
+                }
+                message = match stream.message().await {
+                    Ok(res) => res,
+                    Err(err) => break Err(Errs::strs("batch stream", err)),
+                };
+            };
+            if atomic && result.is_err() {
+                // 回滚失败时以回滚错误为准
+                let task = task.clone();
+                let mut batch = report;
+                match tokio::task::spawn_blocking(move || {
+                    let rollback = task.batch_rollback(&target, &mut batch);
+                    (rollback, batch)
+                })
+                .await
+                {
+                    Ok((rollback, batch)) => {
+                        report = batch;
+                        rollback.and(result)
+                    }
+                    Err(err) => {
+                        report = BatchReport::default();
+                        Err(Errs::strs("batch rollback task", err))
+                    }
+                }
+            } else {
+                result
+            }
+        }
+        Ok(None) => Err(Errs::str("batch request can not be empty!")),
+        Err(err) => Err(Errs::strs("batch stream", err)),
+    };
+    let (status, msg_err) = match result {
+        Ok(()) => (Results::success_status(), "".to_string()),
+        Err(err) => (trace.failed_status(err.clone()), err.to_string()),
+    };
+    let resp = ResponseBatch {
+        status,
+        msg_err,
+        total: report.total,
+        succeeded: report.succeeded,
+        failed: report.failed,
+        rolled_back: report.rolled_back,
+        errors: report
+            .errors
+            .into_iter()
+            .map(|(index, key, msg_err)| BatchError {
+                index,
+                key,
+                msg_err,
+            })
+            .collect(),
+    };
+    trace.finish(task, Results::response(resp))
+}
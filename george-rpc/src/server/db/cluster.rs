@@ -0,0 +1,287 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::sync::Arc;
+
+use tonic::{Request, Response, Status};
+
+use george_comm::errors::{Errs, GeorgeResult};
+use george_db::task::raft::{
+    AppendRequest, InstallRequest, RaftCommand, RaftEntry, RaftPeer, RaftSnapshot, VoteRequest,
+};
+use george_db::task::Raft;
+use george_db::Task;
+
+use crate::protos::db::db::cluster_service_server::ClusterService;
+use crate::protos::db::db::{
+    ClusterPeer, RequestClusterAppend, RequestClusterForward, RequestClusterInstall,
+    RequestClusterJoin, RequestClusterLeave, RequestClusterStatus, RequestClusterVote,
+    ResponseClusterAppend, ResponseClusterForward, ResponseClusterInstall, ResponseClusterJoin,
+    ResponseClusterLeave, ResponseClusterStatus, ResponseClusterVote,
+};
+use crate::server::db::ClusterServer;
+use crate::tools::{Results, Trace};
+
+impl ClusterServer {
+    pub fn new(task: Arc<Task>) -> Self {
+        ClusterServer { task }
+    }
+
+    fn raft(&self) -> GeorgeResult<Arc<Raft>> {
+        match self.task.cluster() {
+            Some(res) => Ok(res),
+            None => Err(Errs::str("server is not running in cluster mode!")),
+        }
+    }
+}
+
+/// 投票、日志复制、快照安装及写入转发为节点间请求，须携带节点间认证凭证，心跳频繁且写入已在发起节点审计，因此不记入审计日志
+#[tonic::async_trait]
+impl ClusterService for ClusterServer {
+    async fn vote(
+        &self,
+        request: Request<RequestClusterVote>,
+    ) -> Result<Response<ResponseClusterVote>, Status> {
+        let trace = Trace::new(&self.task, &request, "cluster.vote", "".to_string());
+        let req = request.into_inner();
+        let result = trace.peer().and_then(|()| self.raft()).and_then(|raft| {
+            raft.handle_vote(VoteRequest {
+                term: req.term,
+                candidate: req.candidate,
+                last_index: req.last_index,
+                last_term: req.last_term,
+            })
+        });
+        let resp = match result {
+            Ok(res) => ResponseClusterVote {
+                status: Results::success_status(),
+                msg_err: "".to_string(),
+                term: res.term,
+                granted: res.granted,
+            },
+            Err(err) => ResponseClusterVote {
+                status: trace.failed_status(err.clone()),
+                msg_err: err.to_string(),
+                term: 0,
+                granted: false,
+            },
+        };
+        Results::response(resp)
+    }
+
+    async fn append(
+        &self,
+        request: Request<RequestClusterAppend>,
+    ) -> Result<Response<ResponseClusterAppend>, Status> {
+        let trace = Trace::new(&self.task, &request, "cluster.append", "".to_string());
+        let req = request.into_inner();
+        let result = trace.peer().and_then(|()| self.raft()).and_then(|raft| {
+            let mut entries = vec![];
+            for bytes in req.entries.iter() {
+                entries.push(RaftEntry::from_slice(bytes.as_slice())?);
+            }
+            raft.handle_append(AppendRequest {
+                term: req.term,
+                leader: req.leader,
+                prev_index: req.prev_index,
+                prev_term: req.prev_term,
+                entries,
+                commit: req.commit,
+            })
+        });
+        let resp = match result {
+            Ok(res) => ResponseClusterAppend {
+                status: Results::success_status(),
+                msg_err: "".to_string(),
+                term: res.term,
+                success: res.success,
+                last_index: res.last_index,
+            },
+            Err(err) => ResponseClusterAppend {
+                status: trace.failed_status(err.clone()),
+                msg_err: err.to_string(),
+                term: 0,
+                success: false,
+                last_index: 0,
+            },
+        };
+        Results::response(resp)
+    }
+
+    async fn forward(
+        &self,
+        request: Request<RequestClusterForward>,
+    ) -> Result<Response<ResponseClusterForward>, Status> {
+        let trace = Trace::new(&self.task, &request, "cluster.forward", "".to_string());
+        let req = request.into_inner();
+        let result = trace
+            .peer()
+            .and_then(|()| self.raft())
+            .and_then(|raft| raft.handle_forward(RaftCommand::from_slice(req.command.as_slice())?));
+        let resp = match result {
+            Ok((index, term)) => ResponseClusterForward {
+                status: Results::success_status(),
+                msg_err: "".to_string(),
+                index,
+                term,
+            },
+            Err(err) => ResponseClusterForward {
+                status: trace.failed_status(err.clone()),
+                msg_err: err.to_string(),
+                index: 0,
+                term: 0,
+            },
+        };
+        Results::response(resp)
+    }
+
+    async fn install(
+        &self,
+        request: Request<RequestClusterInstall>,
+    ) -> Result<Response<ResponseClusterInstall>, Status> {
+        let trace = Trace::new(&self.task, &request, "cluster.install", "".to_string());
+        let req = request.into_inner();
+        let result = trace.peer().and_then(|()| self.raft()).and_then(|raft| {
+            let request = InstallRequest {
+                term: req.term,
+                leader: req.leader,
+                snapshot: RaftSnapshot {
+                    index: req.index,
+                    term: req.last_term,
+                    members: req
+                        .members
+                        .into_iter()
+                        .map(|peer| RaftPeer {
+                            id: peer.id,
+                            addr: peer.addr,
+                        })
+                        .collect(),
+                },
+                path: req.path,
+                data: req.data,
+                first: req.first,
+                done: req.done,
+            };
+            raft.handle_install(request)
+        });
+        let resp = match result {
+            Ok(res) => ResponseClusterInstall {
+                status: Results::success_status(),
+                msg_err: "".to_string(),
+                term: res.term,
+            },
+            Err(err) => ResponseClusterInstall {
+                status: trace.failed_status(err.clone()),
+                msg_err: err.to_string(),
+                term: 0,
+            },
+        };
+        Results::response(resp)
+    }
+
+    async fn status(
+        &self,
+        request: Request<RequestClusterStatus>,
+    ) -> Result<Response<ResponseClusterStatus>, Status> {
+        let trace = Trace::new(&self.task, &request, "cluster.status", "".to_string());
+        let resp = match self.raft() {
+            Ok(raft) => {
+                let status = raft.status();
+                ResponseClusterStatus {
+                    status: Results::success_status(),
+                    msg_err: "".to_string(),
+                    id: status.id,
+                    role: status.role,
+                    term: status.term,
+                    leader: status.leader,
+                    leader_addr: status.leader_addr,
+                    last_index: status.last_index,
+                    commit: status.commit,
+                    applied: status.applied,
+                    members: status
+                        .members
+                        .into_iter()
+                        .map(|peer| ClusterPeer {
+                            id: peer.id,
+                            addr: peer.addr,
+                        })
+                        .collect(),
+                }
+            }
+            Err(err) => ResponseClusterStatus {
+                status: trace.failed_status(err.clone()),
+                msg_err: err.to_string(),
+                id: 0,
+                role: "".to_string(),
+                term: 0,
+                leader: 0,
+                leader_addr: "".to_string(),
+                last_index: 0,
+                commit: 0,
+                applied: 0,
+                members: vec![],
+            },
+        };
+        trace.finish(&self.task, Results::response(resp))
+    }
+
+    async fn join(
+        &self,
+        request: Request<RequestClusterJoin>,
+    ) -> Result<Response<ResponseClusterJoin>, Status> {
+        let peer = request.get_ref().peer.clone().unwrap_or_default();
+        let trace = Trace::new(
+            &self.task,
+            &request,
+            "cluster.join",
+            format!("{}@{}", peer.id, peer.addr),
+        );
+        let result = self.raft().and_then(|raft| {
+            raft.member_add(RaftPeer {
+                id: peer.id,
+                addr: peer.addr,
+            })
+        });
+        let resp = match result {
+            Ok(()) => ResponseClusterJoin {
+                status: Results::success_status(),
+                msg_err: "".to_string(),
+            },
+            Err(err) => ResponseClusterJoin {
+                status: trace.failed_status(err.clone()),
+                msg_err: err.to_string(),
+            },
+        };
+        trace.finish(&self.task, Results::response(resp))
+    }
+
+    async fn leave(
+        &self,
+        request: Request<RequestClusterLeave>,
+    ) -> Result<Response<ResponseClusterLeave>, Status> {
+        let id = request.get_ref().id;
+        let trace = Trace::new(&self.task, &request, "cluster.leave", id.to_string());
+        let resp = match self.raft().and_then(|raft| raft.member_remove(id)) {
+            Ok(()) => ResponseClusterLeave {
+                status: Results::success_status(),
+                msg_err: "".to_string(),
+            },
+            Err(err) => ResponseClusterLeave {
+                status: trace.failed_status(err.clone()),
+                msg_err: err.to_string(),
+            },
+        };
+        trace.finish(&self.task, Results::response(resp))
+    }
+}
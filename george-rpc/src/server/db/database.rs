@@ -26,7 +26,7 @@ use crate::protos::db::db::{
 };
 use crate::protos::utils::utils::{Req, Resp};
 use crate::server::db::DatabaseServer;
-use crate::tools::{Children, Results, Trans};
+use crate::tools::{Children, Results, Trace, Trans};
 
 impl DatabaseServer {
     pub fn new(task: Arc<Task>) -> Self {
@@ -36,7 +36,8 @@ impl DatabaseServer {
 
 #[tonic::async_trait]
 impl DatabaseService for DatabaseServer {
-    async fn list(&self, _request: Request<Req>) -> Result<Response<ResponseDatabaseList>, Status> {
+    async fn list(&self, request: Request<Req>) -> Result<Response<ResponseDatabaseList>, Status> {
+        let trace = Trace::new(&self.task, &request, "database.list", "".to_string());
         let mut databases: Vec<Database> = vec![];
         let db_map = self.task.database_map();
         let db_map_r = db_map.read().unwrap();
@@ -51,44 +52,65 @@ impl DatabaseService for DatabaseServer {
             };
             databases.push(database);
         }
-        Results::response(ResponseDatabaseList {
+        let result = Results::response(ResponseDatabaseList {
             status: Results::success_status(),
             msg_err: "".to_string(),
             databases,
-        })
+        });
+        trace.finish(&self.task, result)
     }
 
     async fn create(
         &self,
         request: Request<RequestDatabaseCreate>,
     ) -> Result<Response<Resp>, Status> {
-        match self.task.database_create(
+        let trace = Trace::new(
+            &self.task,
+            &request,
+            "database.create",
+            request.get_ref().name.clone(),
+        );
+        let result = match self.task.database_create(
             request.get_ref().name.clone(),
             request.get_ref().comment.clone(),
         ) {
             Ok(()) => Results::success(),
-            Err(err) => Results::failed_errs(err),
-        }
+            Err(err) => trace.failed_errs(err),
+        };
+        trace.finish(&self.task, result)
     }
 
     async fn modify(
         &self,
         request: Request<RequestDatabaseModify>,
     ) -> Result<Response<Resp>, Status> {
-        match self.task.database_modify(
+        let trace = Trace::new(
+            &self.task,
+            &request,
+            "database.modify",
+            request.get_ref().name.clone(),
+        );
+        let result = match self.task.database_modify(
             request.get_ref().name.clone(),
             request.get_ref().name_new.clone(),
             request.get_ref().comment.clone(),
         ) {
             Ok(()) => Results::success(),
-            Err(err) => Results::failed_errs(err),
-        }
+            Err(err) => trace.failed_errs(err),
+        };
+        trace.finish(&self.task, result)
     }
 
     async fn info(
         &self,
         request: Request<RequestDatabaseInfo>,
     ) -> Result<Response<ResponseDatabaseInfo>, Status> {
+        let trace = Trace::new(
+            &self.task,
+            &request,
+            "database.info",
+            request.get_ref().name.clone(),
+        );
         let resp;
         match self.task.database(request.get_ref().name.clone()) {
             Ok(res) => {
@@ -107,22 +129,29 @@ impl DatabaseService for DatabaseServer {
             }
             Err(err) => {
                 resp = ResponseDatabaseInfo {
-                    status: Results::failed_status(err.clone()),
+                    status: trace.failed_status(err.clone()),
                     msg_err: err.to_string(),
                     database: None,
                 };
             }
         }
-        Results::response(resp)
+        trace.finish(&self.task, Results::response(resp))
     }
 
     async fn remove(
         &self,
         request: Request<RequestDatabaseRemove>,
     ) -> Result<Response<Resp>, Status> {
-        match self.task.database_remove(request.get_ref().name.clone()) {
+        let trace = Trace::new(
+            &self.task,
+            &request,
+            "database.remove",
+            request.get_ref().name.clone(),
+        );
+        let result = match self.task.database_remove(request.get_ref().name.clone()) {
             Ok(()) => Results::success(),
-            Err(err) => Results::failed_err(err),
-        }
+            Err(err) => trace.failed_err(err),
+        };
+        trace.finish(&self.task, result)
     }
 }
@@ -14,62 +14,149 @@
 
 use std::sync::Arc;
 
-use tonic::{Request, Response, Status};
+use tokio::sync::mpsc;
+use tokio::sync::mpsc::Sender;
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::{Request, Response, Status, Streaming};
 
+use george_comm::errors::{Errs, GeorgeResult};
+use george_db::task::batch::BatchOp;
 use george_db::task::traits::TMaster;
 use george_db::Task;
 
 use crate::protos::db::db::disk_service_server::DiskService;
 use crate::protos::db::db::{
-    DiskDeleted, DiskSelected, RequestDiskDelete, RequestDiskIOut, RequestDiskInto, RequestDiskOut,
-    RequestDiskRemove, RequestDiskSelect, ResponseDiskDelete, ResponseDiskOut, ResponseDiskSelect,
+    DiskDeleted, DiskSelected, RequestDiskBatch, RequestDiskDelete, RequestDiskIOut,
+    RequestDiskInto, RequestDiskOut, RequestDiskRemove, RequestDiskSelect, ResponseBatch,
+    ResponseDiskDelete, ResponseDiskOut, ResponseDiskSelect, ResponseDiskSelectStream,
 };
 use crate::protos::utils::utils::Resp;
+use crate::server::db::batch::batch;
 use crate::server::db::DiskServer;
-use crate::tools::Results;
+use crate::tools::{Results, Trace};
+
+/// 流式检索结果累积到该字节数后发送
+const SELECT_CHUNK_SIZE: usize = 64 * 1024;
+/// 流式检索时等待发送的最大条数，客户端读取慢于检索时检索随之阻塞
+const SELECT_CHANNEL_SIZE: usize = 16;
 
 impl DiskServer {
     pub fn new(task: Arc<Task>) -> Self {
         DiskServer { task }
     }
+
+    /// 按操作人授权处理检索结果，加密字段仅对授权读者解密，其余读者获取脱敏结果
+    fn reveal(
+        &self,
+        database_name: String,
+        view_name: String,
+        principal: String,
+        values: Vec<Vec<u8>>,
+    ) -> GeorgeResult<Vec<Vec<u8>>> {
+        let mut revealed = vec![];
+        for value in values {
+            revealed.push(self.task.reveal_disk(
+                database_name.clone(),
+                view_name.clone(),
+                principal.clone(),
+                value,
+            )?);
+        }
+        Ok(revealed)
+    }
 }
 
 #[tonic::async_trait]
 impl DiskService for DiskServer {
     async fn put(&self, request: Request<RequestDiskInto>) -> Result<Response<Resp>, Status> {
-        match self.task.put_disk(
-            request.get_ref().database_name.clone(),
-            request.get_ref().view_name.clone(),
-            request.get_ref().key.clone(),
-            request.get_ref().value.clone(),
-        ) {
+        let trace = Trace::new(
+            &self.task,
+            &request,
+            "disk.put",
+            format!(
+                "{}/{}/{}",
+                request.get_ref().database_name,
+                request.get_ref().view_name,
+                request.get_ref().key
+            ),
+        );
+        let result = match trace
+            .acquire(&self.task, request.get_ref().database_name.clone())
+            .and_then(|()| {
+                self.task.put_disk(
+                    request.get_ref().database_name.clone(),
+                    request.get_ref().view_name.clone(),
+                    request.get_ref().key.clone(),
+                    request.get_ref().value.clone(),
+                )
+            }) {
             Ok(()) => Results::success(),
-            Err(err) => Results::failed_errs(err),
-        }
+            Err(err) => trace.failed_err(err),
+        };
+        trace.finish(&self.task, result)
     }
 
     async fn set(&self, request: Request<RequestDiskInto>) -> Result<Response<Resp>, Status> {
-        match self.task.set_disk(
-            request.get_ref().database_name.clone(),
-            request.get_ref().view_name.clone(),
-            request.get_ref().key.clone(),
-            request.get_ref().value.clone(),
-        ) {
+        let trace = Trace::new(
+            &self.task,
+            &request,
+            "disk.set",
+            format!(
+                "{}/{}/{}",
+                request.get_ref().database_name,
+                request.get_ref().view_name,
+                request.get_ref().key
+            ),
+        );
+        let result = match trace
+            .acquire(&self.task, request.get_ref().database_name.clone())
+            .and_then(|()| {
+                self.task.set_disk(
+                    request.get_ref().database_name.clone(),
+                    request.get_ref().view_name.clone(),
+                    request.get_ref().key.clone(),
+                    request.get_ref().value.clone(),
+                )
+            }) {
             Ok(()) => Results::success(),
-            Err(err) => Results::failed_errs(err),
-        }
+            Err(err) => trace.failed_err(err),
+        };
+        trace.finish(&self.task, result)
     }
 
     async fn get(
         &self,
         request: Request<RequestDiskOut>,
     ) -> Result<Response<ResponseDiskOut>, Status> {
+        let trace = Trace::new(
+            &self.task,
+            &request,
+            "disk.get",
+            format!(
+                "{}/{}/{}",
+                request.get_ref().database_name,
+                request.get_ref().view_name,
+                request.get_ref().key
+            ),
+        );
         let resp;
-        match self.task.get_disk(
-            request.get_ref().database_name.clone(),
-            request.get_ref().view_name.clone(),
-            request.get_ref().key.clone(),
-        ) {
+        match trace
+            .acquire(&self.task, request.get_ref().database_name.clone())
+            .and_then(|()| {
+                self.task.get_disk(
+                    request.get_ref().database_name.clone(),
+                    request.get_ref().view_name.clone(),
+                    request.get_ref().key.clone(),
+                )
+            })
+            .and_then(|v8s| {
+                self.task.reveal_disk(
+                    request.get_ref().database_name.clone(),
+                    request.get_ref().view_name.clone(),
+                    trace.principal(),
+                    v8s,
+                )
+            }) {
             Ok(v8s) => {
                 resp = ResponseDiskOut {
                     status: Results::success_status(),
@@ -79,26 +166,49 @@ impl DiskService for DiskServer {
             }
             Err(err) => {
                 resp = ResponseDiskOut {
-                    status: Results::failed_status(err.clone()),
+                    status: trace.failed_status(err.clone()),
                     msg_err: err.to_string(),
                     value: vec![],
                 };
             }
         }
-        Results::response(resp)
+        trace.finish(&self.task, Results::response(resp))
     }
 
     async fn get_by_index(
         &self,
         request: Request<RequestDiskIOut>,
     ) -> Result<Response<ResponseDiskOut>, Status> {
+        let trace = Trace::new(
+            &self.task,
+            &request,
+            "disk.get_by_index",
+            format!(
+                "{}/{}/{}",
+                request.get_ref().database_name,
+                request.get_ref().view_name,
+                request.get_ref().key
+            ),
+        );
         let resp;
-        match self.task.get_disk_by_index(
-            request.get_ref().database_name.clone(),
-            request.get_ref().view_name.clone(),
-            request.get_ref().index_name.clone(),
-            request.get_ref().key.clone(),
-        ) {
+        match trace
+            .acquire(&self.task, request.get_ref().database_name.clone())
+            .and_then(|()| {
+                self.task.get_disk_by_index(
+                    request.get_ref().database_name.clone(),
+                    request.get_ref().view_name.clone(),
+                    request.get_ref().index_name.clone(),
+                    request.get_ref().key.clone(),
+                )
+            })
+            .and_then(|v8s| {
+                self.task.reveal_disk(
+                    request.get_ref().database_name.clone(),
+                    request.get_ref().view_name.clone(),
+                    trace.principal(),
+                    v8s,
+                )
+            }) {
             Ok(v8s) => {
                 resp = ResponseDiskOut {
                     status: Results::success_status(),
@@ -108,36 +218,75 @@ impl DiskService for DiskServer {
             }
             Err(err) => {
                 resp = ResponseDiskOut {
-                    status: Results::failed_status(err.clone()),
+                    status: trace.failed_status(err.clone()),
                     msg_err: err.to_string(),
                     value: vec![],
                 };
             }
         }
-        Results::response(resp)
+        trace.finish(&self.task, Results::response(resp))
     }
 
     async fn remove(&self, request: Request<RequestDiskRemove>) -> Result<Response<Resp>, Status> {
-        match self.task.remove_disk(
-            request.get_ref().database_name.clone(),
-            request.get_ref().view_name.clone(),
-            request.get_ref().key.clone(),
-        ) {
+        let trace = Trace::new(
+            &self.task,
+            &request,
+            "disk.remove",
+            format!(
+                "{}/{}/{}",
+                request.get_ref().database_name,
+                request.get_ref().view_name,
+                request.get_ref().key
+            ),
+        );
+        let result = match trace
+            .acquire(&self.task, request.get_ref().database_name.clone())
+            .and_then(|()| {
+                self.task.remove_disk(
+                    request.get_ref().database_name.clone(),
+                    request.get_ref().view_name.clone(),
+                    request.get_ref().key.clone(),
+                )
+            }) {
             Ok(()) => Results::success(),
-            Err(err) => Results::failed_errs(err),
-        }
+            Err(err) => trace.failed_err(err),
+        };
+        trace.finish(&self.task, result)
     }
 
     async fn select(
         &self,
         request: Request<RequestDiskSelect>,
     ) -> Result<Response<ResponseDiskSelect>, Status> {
+        let trace = Trace::new(
+            &self.task,
+            &request,
+            "disk.select",
+            format!(
+                "{}/{}",
+                request.get_ref().database_name,
+                request.get_ref().view_name
+            ),
+        );
         let resp;
-        match self.task.select_disk(
-            request.get_ref().database_name.clone(),
-            request.get_ref().view_name.clone(),
-            request.get_ref().constraint_json_bytes.clone(),
-        ) {
+        match trace
+            .acquire(&self.task, request.get_ref().database_name.clone())
+            .and_then(|()| {
+                self.task.select_disk(
+                    request.get_ref().database_name.clone(),
+                    request.get_ref().view_name.clone(),
+                    request.get_ref().constraint_json_bytes.clone(),
+                )
+            })
+            .and_then(|mut exp| {
+                exp.values = self.reveal(
+                    request.get_ref().database_name.clone(),
+                    request.get_ref().view_name.clone(),
+                    trace.principal(),
+                    exp.values,
+                )?;
+                Ok(exp)
+            }) {
             Ok(exp) => {
                 let selected = DiskSelected {
                     total: exp.total,
@@ -154,25 +303,128 @@ impl DiskService for DiskServer {
             }
             Err(err) => {
                 resp = ResponseDiskSelect {
-                    status: Results::failed_status(err.clone()),
+                    status: trace.failed_status(err.clone()),
                     msg_err: err.to_string(),
                     selected: None,
                 };
             }
         }
-        Results::response(resp)
+        trace.finish(&self.task, Results::response(resp))
+    }
+
+    type select_streamStream = ReceiverStream<Result<ResponseDiskSelectStream, Status>>;
+
+    async fn select_stream(
+        &self,
+        request: Request<RequestDiskSelect>,
+    ) -> Result<Response<Self::select_streamStream>, Status> {
+        let trace = Trace::new(
+            &self.task,
+            &request,
+            "disk.select_stream",
+            format!(
+                "{}/{}",
+                request.get_ref().database_name,
+                request.get_ref().view_name
+            ),
+        );
+        let (sender, receiver) = mpsc::channel(SELECT_CHANNEL_SIZE);
+        let task = self.task.clone();
+        let RequestDiskSelect {
+            database_name,
+            view_name,
+            constraint_json_bytes,
+        } = request.into_inner();
+        tokio::task::spawn_blocking(move || {
+            let mut values: Vec<Vec<u8>> = vec![];
+            let mut size: usize = 0;
+            let result = trace
+                .acquire(&task, database_name.clone())
+                .and_then(|()| {
+                    // 检索结果由视图按读取人授权处理，遍历期间不持有库及视图锁，发送可阻塞等待客户端消费
+                    task.select_disk_stream(
+                        database_name.clone(),
+                        view_name.clone(),
+                        Some(trace.principal()),
+                        constraint_json_bytes,
+                        &mut |value| {
+                            size += value.len();
+                            values.push(value);
+                            if size >= SELECT_CHUNK_SIZE {
+                                size = 0;
+                                send(&sender, values.split_off(0))
+                            } else {
+                                Ok(())
+                            }
+                        },
+                    )
+                })
+                .and_then(|exp| {
+                    if !values.is_empty() {
+                        send(&sender, values.split_off(0))?;
+                    }
+                    Ok(exp)
+                });
+            // 最后一条不携带检索结果，仅携带检索状态及统计
+            let resp = match result {
+                Ok(exp) => ResponseDiskSelectStream {
+                    status: Results::success_status(),
+                    msg_err: "".to_string(),
+                    values: vec![],
+                    selected: Some(DiskSelected {
+                        total: exp.total,
+                        count: exp.count,
+                        index_name: exp.index_name,
+                        asc: exp.asc,
+                        values: vec![],
+                    }),
+                },
+                Err(err) => ResponseDiskSelectStream {
+                    status: trace.failed_status(err.clone()),
+                    msg_err: err.to_string(),
+                    values: vec![],
+                    selected: None,
+                },
+            };
+            let _ = sender.blocking_send(Ok(resp.clone()));
+            let _ = trace.finish(&task, Results::response(resp));
+        });
+        Ok(Response::new(ReceiverStream::new(receiver)))
     }
 
     async fn delete(
         &self,
         request: Request<RequestDiskDelete>,
     ) -> Result<Response<ResponseDiskDelete>, Status> {
+        let trace = Trace::new(
+            &self.task,
+            &request,
+            "disk.delete",
+            format!(
+                "{}/{}",
+                request.get_ref().database_name,
+                request.get_ref().view_name
+            ),
+        );
         let resp;
-        match self.task.delete_disk(
-            request.get_ref().database_name.clone(),
-            request.get_ref().view_name.clone(),
-            request.get_ref().constraint_json_bytes.clone(),
-        ) {
+        match trace
+            .acquire(&self.task, request.get_ref().database_name.clone())
+            .and_then(|()| {
+                self.task.delete_disk(
+                    request.get_ref().database_name.clone(),
+                    request.get_ref().view_name.clone(),
+                    request.get_ref().constraint_json_bytes.clone(),
+                )
+            })
+            .and_then(|mut exp| {
+                exp.values = self.reveal(
+                    request.get_ref().database_name.clone(),
+                    request.get_ref().view_name.clone(),
+                    trace.principal(),
+                    exp.values,
+                )?;
+                Ok(exp)
+            }) {
             Ok(exp) => {
                 let deleted = DiskDeleted {
                     total: exp.total,
@@ -189,12 +441,52 @@ impl DiskService for DiskServer {
             }
             Err(err) => {
                 resp = ResponseDiskDelete {
-                    status: Results::failed_status(err.clone()),
+                    status: trace.failed_status(err.clone()),
                     msg_err: err.to_string(),
                     deleted: None,
                 };
             }
         }
-        Results::response(resp)
+        trace.finish(&self.task, Results::response(resp))
+    }
+    async fn put_batch(
+        &self,
+        request: Request<Streaming<RequestDiskBatch>>,
+    ) -> Result<Response<ResponseBatch>, Status> {
+        let trace = Trace::new(&self.task, &request, "disk.put_batch", "".to_string());
+        batch(&self.task, trace, request.into_inner(), BatchOp::Put).await
+    }
+
+    async fn set_batch(
+        &self,
+        request: Request<Streaming<RequestDiskBatch>>,
+    ) -> Result<Response<ResponseBatch>, Status> {
+        let trace = Trace::new(&self.task, &request, "disk.set_batch", "".to_string());
+        batch(&self.task, trace, request.into_inner(), BatchOp::Set).await
+    }
+
+    async fn remove_batch(
+        &self,
+        request: Request<Streaming<RequestDiskBatch>>,
+    ) -> Result<Response<ResponseBatch>, Status> {
+        let trace = Trace::new(&self.task, &request, "disk.remove_batch", "".to_string());
+        batch(&self.task, trace, request.into_inner(), BatchOp::Remove).await
+    }
+}
+
+/// 发送一批检索结果，客户端已断开时终止检索
+fn send(
+    sender: &Sender<Result<ResponseDiskSelectStream, Status>>,
+    values: Vec<Vec<u8>>,
+) -> GeorgeResult<()> {
+    let resp = ResponseDiskSelectStream {
+        status: Results::success_status(),
+        msg_err: "".to_string(),
+        values,
+        selected: None,
+    };
+    match sender.blocking_send(Ok(resp)) {
+        Ok(()) => Ok(()),
+        Err(_) => Err(Errs::str("select receiver closed!")),
     }
 }
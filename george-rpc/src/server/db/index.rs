@@ -26,7 +26,7 @@ use crate::protos::db::db::{
 };
 use crate::protos::utils::utils::Resp;
 use crate::server::db::IndexServer;
-use crate::tools::{Results, Trans};
+use crate::tools::{Results, Trace, Trans};
 
 impl IndexServer {
     pub fn new(task: Arc<Task>) -> Self {
@@ -40,6 +40,16 @@ impl IndexService for IndexServer {
         &self,
         request: Request<RequestIndexList>,
     ) -> Result<Response<ResponseIndexList>, Status> {
+        let trace = Trace::new(
+            &self.task,
+            &request,
+            "index.list",
+            format!(
+                "{}/{}",
+                request.get_ref().database_name,
+                request.get_ref().view_name
+            ),
+        );
         let resp;
         match self.task.index_map(
             request.get_ref().database_name.clone(),
@@ -67,27 +77,44 @@ impl IndexService for IndexServer {
             }
             Err(err) => {
                 resp = ResponseIndexList {
-                    status: Results::failed_status(err.clone()),
+                    status: trace.failed_status(err.clone()),
                     msg_err: err.to_string(),
                     indexes: vec![],
                 };
             }
         }
-        Results::response(resp)
+        trace.finish(&self.task, Results::response(resp))
     }
 
     async fn create(&self, request: Request<RequestIndexCreate>) -> Result<Response<Resp>, Status> {
+        let trace = Trace::new(
+            &self.task,
+            &request,
+            "index.create",
+            format!(
+                "{}/{}/{}",
+                request.get_ref().database_name,
+                request.get_ref().view_name,
+                request.get_ref().name
+            ),
+        );
         let engine;
         match Trans::i32_2_db_engine(request.get_ref().engine) {
             Ok(res) => engine = res,
-            Err(err) => return Results::failed_err(err),
+            Err(err) => {
+                let result = trace.failed_err(err);
+                return trace.finish(&self.task, result);
+            }
         }
         let key_type;
         match Trans::i32_2_db_key_type(request.get_ref().key_type) {
             Ok(res) => key_type = res,
-            Err(err) => return Results::failed_err(err),
+            Err(err) => {
+                let result = trace.failed_err(err);
+                return trace.finish(&self.task, result);
+            }
         }
-        match self.task.index_create(
+        let result = match self.task.index_create(
             request.get_ref().database_name.clone(),
             request.get_ref().view_name.clone(),
             request.get_ref().name.clone(),
@@ -98,14 +125,26 @@ impl IndexService for IndexServer {
             request.get_ref().null,
         ) {
             Ok(()) => Results::success(),
-            Err(err) => Results::failed_err(err),
-        }
+            Err(err) => trace.failed_err(err),
+        };
+        trace.finish(&self.task, result)
     }
 
     async fn info(
         &self,
         request: Request<RequestIndexInfo>,
     ) -> Result<Response<ResponseIndexInfo>, Status> {
+        let trace = Trace::new(
+            &self.task,
+            &request,
+            "index.info",
+            format!(
+                "{}/{}/{}",
+                request.get_ref().database_name,
+                request.get_ref().view_name,
+                request.get_ref().name
+            ),
+        );
         let resp;
         match self.task.index(
             request.get_ref().database_name.clone(),
@@ -129,12 +168,12 @@ impl IndexService for IndexServer {
             }
             Err(err) => {
                 resp = ResponseIndexInfo {
-                    status: Results::failed_status(err.clone()),
+                    status: trace.failed_status(err.clone()),
                     msg_err: err.to_string(),
                     index: None,
                 };
             }
         }
-        Results::response(resp)
+        trace.finish(&self.task, Results::response(resp))
     }
 }
@@ -0,0 +1,250 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::sync::Arc;
+
+use tonic::{Request, Response, Status};
+
+use george_comm::errors::Errs;
+use george_comm::Time;
+use george_db::task::ledger::Block;
+use george_db::task::traits::TMaster;
+use george_db::Task;
+
+use crate::protos::db::db::ledger_service_server::LedgerService;
+use crate::protos::db::db::request_block_get::Get;
+use crate::protos::db::db::{
+    Ledger, LedgerBlock, LedgerTransaction, RequestBlockGet, RequestBlockInsert,
+    RequestLedgerCreate, RequestLedgerInfo, RequestLedgerRemove, RequestTransactionGet,
+    ResponseBlock, ResponseLedgerInfo, ResponseLedgerList, ResponseTransaction,
+};
+use crate::protos::utils::utils::{Req, Resp, Timestamp};
+use crate::server::db::LedgerServer;
+use crate::tools::{Results, Trace, Trans};
+
+impl LedgerServer {
+    pub fn new(task: Arc<Task>) -> Self {
+        LedgerServer { task }
+    }
+
+    fn ledger(ledger: &george_db::task::Ledger) -> Ledger {
+        Ledger {
+            name: ledger.name(),
+            comment: ledger.comment(),
+            create_time: Some(Trans::time_2_grpc_timestamp(ledger.create_time())),
+            height: ledger.height(),
+            hash: ledger.hash(),
+        }
+    }
+
+    fn block(block: Block) -> LedgerBlock {
+        LedgerBlock {
+            height: block.height,
+            pre_hash: block.pre_hash,
+            hash: block.hash,
+            tx_root: block.tx_root,
+            timestamp: Some(Timestamp {
+                seconds: block.timestamp / 1_000_000_000,
+                nanos: (block.timestamp % 1_000_000_000) as i32,
+            }),
+            transactions: block
+                .transactions
+                .into_iter()
+                .map(|tx| LedgerTransaction {
+                    hash: tx.hash,
+                    value: tx.value,
+                    nonce: tx.nonce,
+                })
+                .collect(),
+        }
+    }
+
+    fn response_block(
+        result: george_comm::errors::GeorgeResult<Block>,
+    ) -> Result<Response<ResponseBlock>, Status> {
+        let resp = match result {
+            Ok(block) => ResponseBlock {
+                status: Results::success_status(),
+                msg_err: "".to_string(),
+                block: Some(LedgerServer::block(block)),
+            },
+            Err(err) => ResponseBlock {
+                status: trace.failed_status(err.clone()),
+                msg_err: err.to_string(),
+                block: None,
+            },
+        };
+        Results::response(resp)
+    }
+}
+
+#[tonic::async_trait]
+impl LedgerService for LedgerServer {
+    async fn list(&self, request: Request<Req>) -> Result<Response<ResponseLedgerList>, Status> {
+        let trace = Trace::new(&self.task, &request, "ledger.list", "".to_string());
+        let mut ledgers: Vec<Ledger> = vec![];
+        let ledger_map = self.task.ledger_map();
+        let ledger_map_r = ledger_map.read().unwrap();
+        for ledger in ledger_map_r.values() {
+            ledgers.push(LedgerServer::ledger(&ledger.read().unwrap()));
+        }
+        let result = Results::response(ResponseLedgerList {
+            status: Results::success_status(),
+            msg_err: "".to_string(),
+            ledgers,
+        });
+        trace.finish(&self.task, result)
+    }
+
+    async fn create(
+        &self,
+        request: Request<RequestLedgerCreate>,
+    ) -> Result<Response<Resp>, Status> {
+        let trace = Trace::new(
+            &self.task,
+            &request,
+            "ledger.create",
+            request.get_ref().name.clone(),
+        );
+        let result = match self.task.ledger_create(
+            request.get_ref().name.clone(),
+            request.get_ref().comment.clone(),
+        ) {
+            Ok(()) => Results::success(),
+            Err(err) => trace.failed_err(err),
+        };
+        trace.finish(&self.task, result)
+    }
+
+    async fn info(
+        &self,
+        request: Request<RequestLedgerInfo>,
+    ) -> Result<Response<ResponseLedgerInfo>, Status> {
+        let trace = Trace::new(
+            &self.task,
+            &request,
+            "ledger.info",
+            request.get_ref().name.clone(),
+        );
+        let resp = match self.task.ledger(request.get_ref().name.clone()) {
+            Ok(res) => ResponseLedgerInfo {
+                status: Results::success_status(),
+                msg_err: "".to_string(),
+                ledger: Some(LedgerServer::ledger(&res.read().unwrap())),
+            },
+            Err(err) => ResponseLedgerInfo {
+                status: trace.failed_status(err.clone()),
+                msg_err: err.to_string(),
+                ledger: None,
+            },
+        };
+        trace.finish(&self.task, Results::response(resp))
+    }
+
+    async fn remove(
+        &self,
+        request: Request<RequestLedgerRemove>,
+    ) -> Result<Response<Resp>, Status> {
+        let trace = Trace::new(
+            &self.task,
+            &request,
+            "ledger.remove",
+            request.get_ref().name.clone(),
+        );
+        let result = match self.task.ledger_remove(request.get_ref().name.clone()) {
+            Ok(()) => Results::success(),
+            Err(err) => trace.failed_err(err),
+        };
+        trace.finish(&self.task, result)
+    }
+
+    async fn insert(
+        &self,
+        request: Request<RequestBlockInsert>,
+    ) -> Result<Response<ResponseBlock>, Status> {
+        let trace = Trace::new(
+            &self.task,
+            &request,
+            "ledger.insert",
+            request.get_ref().ledger_name.clone(),
+        );
+        let result = self.task.block_insert(
+            request.get_ref().ledger_name.clone(),
+            Time::now().nanos(),
+            request.get_ref().transactions.clone(),
+        );
+        trace.finish(&self.task, LedgerServer::response_block(result))
+    }
+
+    async fn block(
+        &self,
+        request: Request<RequestBlockGet>,
+    ) -> Result<Response<ResponseBlock>, Status> {
+        let trace = Trace::new(
+            &self.task,
+            &request,
+            "ledger.block",
+            request.get_ref().ledger_name.clone(),
+        );
+        let result = match self.task.ledger(request.get_ref().ledger_name.clone()) {
+            Ok(ledger) => match request.get_ref().get.clone() {
+                Some(Get::Height(height)) => ledger.read().unwrap().block(height),
+                Some(Get::Hash(hash)) => ledger.read().unwrap().block_by_hash(hash),
+                None => Err(Errs::str("block height or hash must be assigned!")),
+            },
+            Err(err) => Err(err),
+        };
+        trace.finish(&self.task, LedgerServer::response_block(result))
+    }
+
+    async fn transaction(
+        &self,
+        request: Request<RequestTransactionGet>,
+    ) -> Result<Response<ResponseTransaction>, Status> {
+        let trace = Trace::new(
+            &self.task,
+            &request,
+            "ledger.transaction",
+            request.get_ref().ledger_name.clone(),
+        );
+        let result = match self.task.ledger(request.get_ref().ledger_name.clone()) {
+            Ok(ledger) => ledger
+                .read()
+                .unwrap()
+                .transaction(request.get_ref().hash.clone()),
+            Err(err) => Err(err),
+        };
+        let resp = match result {
+            Ok((block, tx)) => ResponseTransaction {
+                status: Results::success_status(),
+                msg_err: "".to_string(),
+                height: block.height,
+                block_hash: block.hash,
+                transaction: Some(LedgerTransaction {
+                    hash: tx.hash,
+                    value: tx.value,
+                    nonce: tx.nonce,
+                }),
+            },
+            Err(err) => ResponseTransaction {
+                status: trace.failed_status(err.clone()),
+                msg_err: err.to_string(),
+                height: 0,
+                block_hash: "".to_string(),
+                transaction: None,
+            },
+        };
+        trace.finish(&self.task, Results::response(resp))
+    }
+}
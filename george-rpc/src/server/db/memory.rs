@@ -14,18 +14,21 @@
 
 use std::sync::Arc;
 
-use tonic::{Request, Response, Status};
+use tonic::{Request, Response, Status, Streaming};
 
+use george_db::task::batch::BatchOp;
 use george_db::Task;
 
 use crate::protos::db::db::memory_service_server::MemoryService;
 use crate::protos::db::db::{
-    RequestMemoryInto, RequestMemoryOut, RequestMemoryPInto, RequestMemoryPOut,
-    RequestMemoryPRemove, RequestMemoryRemove, ResponseMemoryOut, ResponseMemoryPOut,
+    RequestMemoryBatch, RequestMemoryInto, RequestMemoryOut, RequestMemoryPInto, RequestMemoryPOut,
+    RequestMemoryPRemove, RequestMemoryRemove, ResponseBatch, ResponseMemoryOut,
+    ResponseMemoryPOut,
 };
 use crate::protos::utils::utils::Resp;
+use crate::server::db::batch::batch;
 use crate::server::db::{MemoryServer, DATABASE_SYS};
-use crate::tools::Results;
+use crate::tools::{Results, Trace};
 use george_db::task::traits::TMaster;
 
 impl MemoryServer {
@@ -37,36 +40,60 @@ impl MemoryServer {
 #[tonic::async_trait]
 impl MemoryService for MemoryServer {
     async fn put(&self, request: Request<RequestMemoryInto>) -> Result<Response<Resp>, Status> {
-        match self.task.put_memory(
-            DATABASE_SYS.to_string(),
-            request.get_ref().key.clone(),
-            request.get_ref().value.clone(),
-        ) {
+        let trace = Trace::new(
+            &self.task,
+            &request,
+            "memory.put",
+            format!("{}/{}", DATABASE_SYS, request.get_ref().key),
+        );
+        let result = match trace.acquire(&self.task, "".to_string()).and_then(|()| {
+            self.task.put_memory(
+                DATABASE_SYS.to_string(),
+                request.get_ref().key.clone(),
+                request.get_ref().value.clone(),
+            )
+        }) {
             Ok(()) => Results::success(),
-            Err(err) => Results::failed_errs(err),
-        }
+            Err(err) => trace.failed_err(err),
+        };
+        trace.finish(&self.task, result)
     }
 
     async fn set(&self, request: Request<RequestMemoryInto>) -> Result<Response<Resp>, Status> {
-        match self.task.set_memory(
-            DATABASE_SYS.to_string(),
-            request.get_ref().key.clone(),
-            request.get_ref().value.clone(),
-        ) {
+        let trace = Trace::new(
+            &self.task,
+            &request,
+            "memory.set",
+            format!("{}/{}", DATABASE_SYS, request.get_ref().key),
+        );
+        let result = match trace.acquire(&self.task, "".to_string()).and_then(|()| {
+            self.task.set_memory(
+                DATABASE_SYS.to_string(),
+                request.get_ref().key.clone(),
+                request.get_ref().value.clone(),
+            )
+        }) {
             Ok(()) => Results::success(),
-            Err(err) => Results::failed_errs(err),
-        }
+            Err(err) => trace.failed_err(err),
+        };
+        trace.finish(&self.task, result)
     }
 
     async fn get(
         &self,
         request: Request<RequestMemoryOut>,
     ) -> Result<Response<ResponseMemoryOut>, Status> {
+        let trace = Trace::new(
+            &self.task,
+            &request,
+            "memory.get",
+            format!("{}/{}", DATABASE_SYS, request.get_ref().key),
+        );
         let resp;
-        match self
-            .task
-            .get_memory(DATABASE_SYS.to_string(), request.get_ref().key.clone())
-        {
+        match trace.acquire(&self.task, "".to_string()).and_then(|()| {
+            self.task
+                .get_memory(DATABASE_SYS.to_string(), request.get_ref().key.clone())
+        }) {
             Ok(v8s) => {
                 resp = ResponseMemoryOut {
                     status: Results::success_status(),
@@ -76,65 +103,98 @@ impl MemoryService for MemoryServer {
             }
             Err(err) => {
                 resp = ResponseMemoryOut {
-                    status: Results::failed_status(err.clone()),
+                    status: trace.failed_status(err.clone()),
                     msg_err: err.to_string(),
                     value: vec![],
                 };
             }
         }
-        Results::response(resp)
+        trace.finish(&self.task, Results::response(resp))
     }
 
     async fn remove(
         &self,
         request: Request<RequestMemoryRemove>,
     ) -> Result<Response<Resp>, Status> {
-        match self
-            .task
-            .remove_memory(DATABASE_SYS.to_string(), request.get_ref().key.clone())
-        {
+        let trace = Trace::new(
+            &self.task,
+            &request,
+            "memory.remove",
+            format!("{}/{}", DATABASE_SYS, request.get_ref().key),
+        );
+        let result = match trace.acquire(&self.task, "".to_string()).and_then(|()| {
+            self.task
+                .remove_memory(DATABASE_SYS.to_string(), request.get_ref().key.clone())
+        }) {
             Ok(()) => Results::success(),
-            Err(err) => Results::failed_errs(err),
-        }
+            Err(err) => trace.failed_err(err),
+        };
+        trace.finish(&self.task, result)
     }
 
     async fn put_by_page(
         &self,
         request: Request<RequestMemoryPInto>,
     ) -> Result<Response<Resp>, Status> {
-        match self.task.put_memory(
-            request.get_ref().page_name.clone(),
-            request.get_ref().key.clone(),
-            request.get_ref().value.clone(),
-        ) {
+        let trace = Trace::new(
+            &self.task,
+            &request,
+            "memory.put_by_page",
+            format!("{}/{}", request.get_ref().page_name, request.get_ref().key),
+        );
+        let result = match trace.acquire(&self.task, "".to_string()).and_then(|()| {
+            self.task.put_memory(
+                request.get_ref().page_name.clone(),
+                request.get_ref().key.clone(),
+                request.get_ref().value.clone(),
+            )
+        }) {
             Ok(()) => Results::success(),
-            Err(err) => Results::failed_errs(err),
-        }
+            Err(err) => trace.failed_err(err),
+        };
+        trace.finish(&self.task, result)
     }
 
     async fn set_by_page(
         &self,
         request: Request<RequestMemoryPInto>,
     ) -> Result<Response<Resp>, Status> {
-        match self.task.set_memory(
-            request.get_ref().page_name.clone(),
-            request.get_ref().key.clone(),
-            request.get_ref().value.clone(),
-        ) {
+        let trace = Trace::new(
+            &self.task,
+            &request,
+            "memory.set_by_page",
+            format!("{}/{}", request.get_ref().page_name, request.get_ref().key),
+        );
+        let result = match trace.acquire(&self.task, "".to_string()).and_then(|()| {
+            self.task.set_memory(
+                request.get_ref().page_name.clone(),
+                request.get_ref().key.clone(),
+                request.get_ref().value.clone(),
+            )
+        }) {
             Ok(()) => Results::success(),
-            Err(err) => Results::failed_errs(err),
-        }
+            Err(err) => trace.failed_err(err),
+        };
+        trace.finish(&self.task, result)
     }
 
     async fn get_by_page(
         &self,
         request: Request<RequestMemoryPOut>,
     ) -> Result<Response<ResponseMemoryPOut>, Status> {
+        let trace = Trace::new(
+            &self.task,
+            &request,
+            "memory.get_by_page",
+            format!("{}/{}", request.get_ref().page_name, request.get_ref().key),
+        );
         let resp;
-        match self.task.get_memory(
-            request.get_ref().page_name.clone(),
-            request.get_ref().key.clone(),
-        ) {
+        match trace.acquire(&self.task, "".to_string()).and_then(|()| {
+            self.task.get_memory(
+                request.get_ref().page_name.clone(),
+                request.get_ref().key.clone(),
+            )
+        }) {
             Ok(v8s) => {
                 resp = ResponseMemoryPOut {
                     status: Results::success_status(),
@@ -144,25 +204,57 @@ impl MemoryService for MemoryServer {
             }
             Err(err) => {
                 resp = ResponseMemoryPOut {
-                    status: Results::failed_status(err.clone()),
+                    status: trace.failed_status(err.clone()),
                     msg_err: err.to_string(),
                     value: vec![],
                 };
             }
         }
-        Results::response(resp)
+        trace.finish(&self.task, Results::response(resp))
     }
 
     async fn remove_by_page(
         &self,
         request: Request<RequestMemoryPRemove>,
     ) -> Result<Response<Resp>, Status> {
-        match self.task.remove_memory(
-            request.get_ref().page_name.clone(),
-            request.get_ref().key.clone(),
-        ) {
+        let trace = Trace::new(
+            &self.task,
+            &request,
+            "memory.remove_by_page",
+            format!("{}/{}", request.get_ref().page_name, request.get_ref().key),
+        );
+        let result = match trace.acquire(&self.task, "".to_string()).and_then(|()| {
+            self.task.remove_memory(
+                request.get_ref().page_name.clone(),
+                request.get_ref().key.clone(),
+            )
+        }) {
             Ok(()) => Results::success(),
-            Err(err) => Results::failed_errs(err),
-        }
+            Err(err) => trace.failed_err(err),
+        };
+        trace.finish(&self.task, result)
+    }
+    async fn put_batch(
+        &self,
+        request: Request<Streaming<RequestMemoryBatch>>,
+    ) -> Result<Response<ResponseBatch>, Status> {
+        let trace = Trace::new(&self.task, &request, "memory.put_batch", "".to_string());
+        batch(&self.task, trace, request.into_inner(), BatchOp::Put).await
+    }
+
+    async fn set_batch(
+        &self,
+        request: Request<Streaming<RequestMemoryBatch>>,
+    ) -> Result<Response<ResponseBatch>, Status> {
+        let trace = Trace::new(&self.task, &request, "memory.set_batch", "".to_string());
+        batch(&self.task, trace, request.into_inner(), BatchOp::Set).await
+    }
+
+    async fn remove_batch(
+        &self,
+        request: Request<Streaming<RequestMemoryBatch>>,
+    ) -> Result<Response<ResponseBatch>, Status> {
+        let trace = Trace::new(&self.task, &request, "memory.remove_batch", "".to_string());
+        batch(&self.task, trace, request.into_inner(), BatchOp::Remove).await
     }
 }
@@ -19,14 +19,37 @@ pub const DATABASE_SYS: &str = "sys";
 pub const VIEW_USER: &str = "user";
 pub const DEFAULT_COMMENT: &str = "system default";
 
+pub mod audit;
+pub mod backup;
+mod batch;
+pub mod cluster;
 pub mod database;
 pub mod disk;
 pub mod index;
+pub mod ledger;
 pub mod memory;
 pub mod page;
+pub mod quota;
+pub mod replication;
+pub mod transfer;
 pub mod user;
 pub mod view;
 
+#[derive(Debug, Clone)]
+pub struct AuditServer {
+    pub task: Arc<Task>,
+}
+
+#[derive(Debug, Clone)]
+pub struct BackupServer {
+    pub task: Arc<Task>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ClusterServer {
+    pub task: Arc<Task>,
+}
+
 #[derive(Debug, Clone)]
 pub struct DatabaseServer {
     pub task: Arc<Task>,
@@ -42,6 +65,11 @@ pub struct IndexServer {
     pub task: Arc<Task>,
 }
 
+#[derive(Debug, Clone)]
+pub struct LedgerServer {
+    pub task: Arc<Task>,
+}
+
 #[derive(Debug, Clone)]
 pub struct MemoryServer {
     pub task: Arc<Task>,
@@ -52,6 +80,21 @@ pub struct PageServer {
     pub task: Arc<Task>,
 }
 
+#[derive(Debug, Clone)]
+pub struct QuotaServer {
+    pub task: Arc<Task>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ReplicationServer {
+    pub task: Arc<Task>,
+}
+
+#[derive(Debug, Clone)]
+pub struct TransferServer {
+    pub task: Arc<Task>,
+}
+
 #[derive(Debug, Clone)]
 pub struct UserServer {
     pub task: Arc<Task>,
@@ -26,7 +26,7 @@ use crate::protos::db::db::{
 };
 use crate::protos::utils::utils::{Req, Resp};
 use crate::server::db::PageServer;
-use crate::tools::{Results, Trans};
+use crate::tools::{Results, Trace, Trans};
 
 impl PageServer {
     pub fn new(task: Arc<Task>) -> Self {
@@ -36,7 +36,8 @@ impl PageServer {
 
 #[tonic::async_trait]
 impl PageService for PageServer {
-    async fn list(&self, _request: Request<Req>) -> Result<Response<ResponsePageList>, Status> {
+    async fn list(&self, request: Request<Req>) -> Result<Response<ResponsePageList>, Status> {
+        let trace = Trace::new(&self.task, &request, "page.list", "".to_string());
         let mut pages: Vec<Page> = vec![];
         let page_map = self.task.page_map();
         let page_map_r = page_map.read().unwrap();
@@ -50,39 +51,60 @@ impl PageService for PageServer {
                 create_time: Some(Trans::time_2_grpc_timestamp(page_r.create_time())),
             });
         }
-        Results::response(ResponsePageList {
+        let result = Results::response(ResponsePageList {
             status: Results::success_status(),
             msg_err: "".to_string(),
             pages,
-        })
+        });
+        trace.finish(&self.task, result)
     }
 
     async fn create(&self, request: Request<RequestPageCreate>) -> Result<Response<Resp>, Status> {
-        match self.task.page_create(
+        let trace = Trace::new(
+            &self.task,
+            &request,
+            "page.create",
+            request.get_ref().name.clone(),
+        );
+        let result = match self.task.page_create(
             request.get_ref().name.clone(),
             request.get_ref().comment.clone(),
             request.get_ref().size,
             request.get_ref().period,
         ) {
             Ok(()) => Results::success(),
-            Err(err) => Results::failed_errs(err),
-        }
+            Err(err) => trace.failed_errs(err),
+        };
+        trace.finish(&self.task, result)
     }
 
     async fn modify(&self, request: Request<RequestPageModify>) -> Result<Response<Resp>, Status> {
-        match self.task.page_modify(
+        let trace = Trace::new(
+            &self.task,
+            &request,
+            "page.modify",
+            request.get_ref().name.clone(),
+        );
+        let result = match self.task.page_modify(
             request.get_ref().name.clone(),
             request.get_ref().name_new.clone(),
         ) {
             Ok(()) => Results::success(),
-            Err(err) => Results::failed_errs(err),
-        }
+            Err(err) => trace.failed_errs(err),
+        };
+        trace.finish(&self.task, result)
     }
 
     async fn info(
         &self,
         request: Request<RequestPageInfo>,
     ) -> Result<Response<ResponsePageInfo>, Status> {
+        let trace = Trace::new(
+            &self.task,
+            &request,
+            "page.info",
+            request.get_ref().name.clone(),
+        );
         let resp;
         match self.task.page(request.get_ref().name.clone()) {
             Ok(res) => {
@@ -101,19 +123,26 @@ impl PageService for PageServer {
             }
             Err(err) => {
                 resp = ResponsePageInfo {
-                    status: Results::failed_status(err.clone()),
+                    status: trace.failed_status(err.clone()),
                     msg_err: err.to_string(),
                     page: None,
                 };
             }
         }
-        Results::response(resp)
+        trace.finish(&self.task, Results::response(resp))
     }
 
     async fn remove(&self, request: Request<RequestPageRemove>) -> Result<Response<Resp>, Status> {
-        match self.task.page_remove(request.get_ref().name.clone()) {
+        let trace = Trace::new(
+            &self.task,
+            &request,
+            "page.remove",
+            request.get_ref().name.clone(),
+        );
+        let result = match self.task.page_remove(request.get_ref().name.clone()) {
             Ok(()) => Results::success(),
-            Err(err) => Results::failed_err(err),
-        }
+            Err(err) => trace.failed_err(err),
+        };
+        trace.finish(&self.task, result)
     }
 }
@@ -0,0 +1,90 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::sync::Arc;
+
+use tonic::{Request, Response, Status};
+
+use george_comm::errors::Errs;
+use george_db::task::quota;
+use george_db::Task;
+
+use crate::protos::db::db::quota_service_server::QuotaService;
+use crate::protos::db::db::{QuotaPolicy, RequestQuotaList, RequestQuotaSet, ResponseQuotaList};
+use crate::protos::utils::utils::Resp;
+use crate::server::db::QuotaServer;
+use crate::tools::{Results, Trace};
+
+impl QuotaServer {
+    pub fn new(task: Arc<Task>) -> Self {
+        QuotaServer { task }
+    }
+}
+
+#[tonic::async_trait]
+impl QuotaService for QuotaServer {
+    async fn set(&self, request: Request<RequestQuotaSet>) -> Result<Response<Resp>, Status> {
+        let target = match &request.get_ref().policy {
+            Some(policy) => format!("{}:{}", policy.scope, policy.name),
+            None => "".to_string(),
+        };
+        let trace = Trace::new(&self.task, &request, "quota.set", target);
+        let result = match trace
+            .admin()
+            .and_then(|()| match request.get_ref().policy.clone() {
+                Some(policy) => self.task.quota_set(quota::QuotaPolicy {
+                    scope: policy.scope,
+                    name: policy.name,
+                    rate: policy.rate,
+                    burst: policy.burst,
+                    max_bytes: policy.max_bytes,
+                    max_keys: policy.max_keys,
+                }),
+                None => Err(Errs::str("quota policy can not be empty!")),
+            }) {
+            Ok(()) => Results::success(),
+            Err(err) => trace.failed_err(err),
+        };
+        trace.finish(&self.task, result)
+    }
+
+    async fn list(
+        &self,
+        request: Request<RequestQuotaList>,
+    ) -> Result<Response<ResponseQuotaList>, Status> {
+        let trace = Trace::new(
+            &self.task,
+            &request,
+            "quota.list",
+            request.get_ref().scope.clone(),
+        );
+        let mut policies: Vec<QuotaPolicy> = vec![];
+        for policy in self.task.quota_policies(request.get_ref().scope.clone()) {
+            policies.push(QuotaPolicy {
+                scope: policy.scope,
+                name: policy.name,
+                rate: policy.rate,
+                burst: policy.burst,
+                max_bytes: policy.max_bytes,
+                max_keys: policy.max_keys,
+            })
+        }
+        let resp = ResponseQuotaList {
+            status: Results::success_status(),
+            msg_err: "".to_string(),
+            policies,
+        };
+        trace.finish(&self.task, Results::response(resp))
+    }
+}
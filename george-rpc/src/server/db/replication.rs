@@ -0,0 +1,242 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::env;
+use std::fs;
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::mpsc;
+use tokio::sync::mpsc::Sender;
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::{Request, Response, Status};
+
+use george_comm::errors::{Errs, GeorgeResult};
+use george_comm::io::file::FilerReader;
+use george_comm::io::Filer;
+use george_comm::Time;
+use george_db::Task;
+
+use crate::protos::db::db::replication_service_server::ReplicationService;
+use crate::protos::db::db::{
+    RequestReplicationFollow, RequestReplicationPromote, RequestReplicationSnapshot,
+    RequestReplicationStatus, ResponseReplicationFollow, ResponseReplicationPromote,
+    ResponseReplicationSnapshot, ResponseReplicationStatus,
+};
+use crate::server::db::ReplicationServer;
+use crate::tools::{Results, Trace};
+
+/// 单次推送的最大变更数
+const FOLLOW_BATCH: usize = 512;
+/// 无新变更时推送空变更的间隔，同时用于及时发现已断开的从节点
+const FOLLOW_HEARTBEAT: Duration = Duration::from_secs(1);
+/// 等待发送的最大推送数，从节点应用慢于推送时推送随之阻塞
+const FOLLOW_CHANNEL_SIZE: usize = 16;
+/// 快照文件分块字节数
+const SNAPSHOT_CHUNK_SIZE: usize = 1024 * 1024;
+
+impl ReplicationServer {
+    pub fn new(task: Arc<Task>) -> Self {
+        ReplicationServer { task }
+    }
+}
+
+#[tonic::async_trait]
+impl ReplicationService for ReplicationServer {
+    type followStream = ReceiverStream<Result<ResponseReplicationFollow, Status>>;
+
+    async fn follow(
+        &self,
+        request: Request<RequestReplicationFollow>,
+    ) -> Result<Response<Self::followStream>, Status> {
+        let seq = request.get_ref().seq;
+        let trace = Trace::new(&self.task, &request, "replication.follow", seq.to_string());
+        let (sender, receiver) = mpsc::channel(FOLLOW_CHANNEL_SIZE);
+        let task = self.task.clone();
+        tokio::task::spawn_blocking(move || {
+            // 仅在无法继续推送时结束，从节点断开属于正常结束
+            let resp = match trace.peer().and_then(|()| push(&task, seq, &sender)) {
+                Ok(()) => ResponseReplicationFollow {
+                    status: Results::success_status(),
+                    msg_err: "".to_string(),
+                    primary_seq: 0,
+                    mutations: vec![],
+                },
+                Err(err) => {
+                    let resp = ResponseReplicationFollow {
+                        status: trace.failed_status(err.clone()),
+                        msg_err: err.to_string(),
+                        primary_seq: 0,
+                        mutations: vec![],
+                    };
+                    let _ = sender.blocking_send(Ok(resp.clone()));
+                    resp
+                }
+            };
+            let _ = trace.finish(&task, Results::response(resp));
+        });
+        Ok(Response::new(ReceiverStream::new(receiver)))
+    }
+
+    type snapshotStream = ReceiverStream<Result<ResponseReplicationSnapshot, Status>>;
+
+    async fn snapshot(
+        &self,
+        request: Request<RequestReplicationSnapshot>,
+    ) -> Result<Response<Self::snapshotStream>, Status> {
+        let trace = Trace::new(&self.task, &request, "replication.snapshot", "".to_string());
+        let (sender, receiver) = mpsc::channel(FOLLOW_CHANNEL_SIZE);
+        let task = self.task.clone();
+        tokio::task::spawn_blocking(move || {
+            let dir = env::temp_dir()
+                .join(format!("george-snapshot-{}", Time::now().nanos()))
+                .to_string_lossy()
+                .to_string();
+            let result = trace
+                .peer()
+                .and_then(|()| snapshot(&task, dir.clone(), &sender));
+            let _ = fs::remove_dir_all(&dir);
+            let resp = match result {
+                Ok(manifest) => ResponseReplicationSnapshot {
+                    status: Results::success_status(),
+                    msg_err: "".to_string(),
+                    path: "".to_string(),
+                    data: vec![],
+                    manifest,
+                },
+                Err(err) => ResponseReplicationSnapshot {
+                    status: trace.failed_status(err.clone()),
+                    msg_err: err.to_string(),
+                    path: "".to_string(),
+                    data: vec![],
+                    manifest: vec![],
+                },
+            };
+            let _ = sender.blocking_send(Ok(resp.clone()));
+            let _ = trace.finish(&task, Results::response(resp));
+        });
+        Ok(Response::new(ReceiverStream::new(receiver)))
+    }
+
+    async fn status(
+        &self,
+        request: Request<RequestReplicationStatus>,
+    ) -> Result<Response<ResponseReplicationStatus>, Status> {
+        let trace = Trace::new(&self.task, &request, "replication.status", "".to_string());
+        let (state, seq) = self.task.replication();
+        let resp = ResponseReplicationStatus {
+            status: Results::success_status(),
+            msg_err: "".to_string(),
+            role: state.role.clone(),
+            primary: state.primary.clone(),
+            seq,
+            applied: state.applied,
+            primary_seq: state.primary_seq,
+            lag: state.lag(),
+            lag_millis: state.lag_millis(),
+            contact_time: state.contact_time,
+            promoted_time: state.promoted_time,
+        };
+        trace.finish(&self.task, Results::response(resp))
+    }
+
+    async fn promote(
+        &self,
+        request: Request<RequestReplicationPromote>,
+    ) -> Result<Response<ResponseReplicationPromote>, Status> {
+        let trace = Trace::new(&self.task, &request, "replication.promote", "".to_string());
+        // 由管理员或持有节点间认证密钥的运维工具发起
+        let resp = match trace
+            .admin()
+            .or_else(|_| trace.peer())
+            .and_then(|()| self.task.promote())
+        {
+            Ok(applied) => ResponseReplicationPromote {
+                status: Results::success_status(),
+                msg_err: "".to_string(),
+                applied,
+            },
+            Err(err) => ResponseReplicationPromote {
+                status: trace.failed_status(err.clone()),
+                msg_err: err.to_string(),
+                applied: 0,
+            },
+        };
+        trace.finish(&self.task, Results::response(resp))
+    }
+}
+
+/// 持续推送序号`seq`之后的变更，直至从节点断开
+fn push(
+    task: &Task,
+    seq: u64,
+    sender: &Sender<Result<ResponseReplicationFollow, Status>>,
+) -> GeorgeResult<()> {
+    let mut cursor = task.journal_cursor(seq)?;
+    loop {
+        let mutations = cursor.next(FOLLOW_BATCH)?;
+        if mutations.is_empty() {
+            let primary_seq = task.journal_wait(cursor.seq(), FOLLOW_HEARTBEAT);
+            // 等待期间有新变更则直接读取，否则推送空变更
+            if primary_seq > cursor.seq() {
+                continue;
+            }
+        }
+        let mut resp = ResponseReplicationFollow {
+            status: Results::success_status(),
+            msg_err: "".to_string(),
+            primary_seq: 0,
+            mutations: vec![],
+        };
+        for mutation in mutations {
+            resp.mutations.push(mutation.to_vec()?);
+        }
+        let (_, primary_seq) = task.replication();
+        resp.primary_seq = primary_seq.max(cursor.seq());
+        if sender.blocking_send(Ok(resp)).is_err() {
+            log::info!("replication follower disconnected at seq {}", cursor.seq());
+            return Ok(());
+        }
+    }
+}
+
+/// 生成快照并按文件分块推送，返回快照清单
+fn snapshot(
+    task: &Task,
+    dir: String,
+    sender: &Sender<Result<ResponseReplicationSnapshot, Status>>,
+) -> GeorgeResult<Vec<u8>> {
+    let manifest = task.snapshot(dir.clone(), false)?;
+    for file in manifest.files.iter() {
+        let content = Filer::read_bytes(format!("{}/{}", dir, file.path))?;
+        // 空文件同样推送一次，以便从节点创建该文件
+        let mut chunks: Vec<&[u8]> = content.chunks(SNAPSHOT_CHUNK_SIZE).collect();
+        if chunks.is_empty() {
+            chunks.push(&[]);
+        }
+        for chunk in chunks {
+            let resp = ResponseReplicationSnapshot {
+                status: Results::success_status(),
+                msg_err: "".to_string(),
+                path: file.path.clone(),
+                data: chunk.to_vec(),
+                manifest: vec![],
+            };
+            if sender.blocking_send(Ok(resp)).is_err() {
+                return Err(Errs::str("snapshot receiver closed!"));
+            }
+        }
+    }
+    manifest.to_vec()
+}
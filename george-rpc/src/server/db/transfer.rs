@@ -0,0 +1,206 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::sync::Arc;
+
+use tokio::sync::mpsc;
+use tokio::sync::mpsc::Sender;
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::{Request, Response, Status, Streaming};
+
+use george_comm::errors::{Errs, GeorgeResult};
+use george_db::task::transfer;
+use george_db::task::transfer::ImportReport;
+use george_db::Task;
+
+use crate::protos::db::db::transfer_service_server::TransferService;
+use crate::protos::db::db::{
+    ImportError, RequestExport, RequestImport, ResponseExport, ResponseImport, TransferFormat,
+};
+use crate::server::db::TransferServer;
+use crate::tools::{Results, Trace};
+
+/// 单条导出内容累积到该字节数后发送
+const EXPORT_CHUNK_SIZE: usize = 64 * 1024;
+/// 导出时等待发送的最大内容条数，客户端读取慢于导出时导出随之阻塞
+const EXPORT_CHANNEL_SIZE: usize = 16;
+
+impl TransferServer {
+    pub fn new(task: Arc<Task>) -> Self {
+        TransferServer { task }
+    }
+}
+
+#[tonic::async_trait]
+impl TransferService for TransferServer {
+    type exportStream = ReceiverStream<Result<ResponseExport, Status>>;
+
+    async fn export(
+        &self,
+        request: Request<RequestExport>,
+    ) -> Result<Response<Self::exportStream>, Status> {
+        let trace = Trace::new(
+            &self.task,
+            &request,
+            "transfer.export",
+            format!(
+                "{}/{}",
+                request.get_ref().database_name,
+                request.get_ref().view_name
+            ),
+        );
+        let (sender, receiver) = mpsc::channel(EXPORT_CHANNEL_SIZE);
+        let task = self.task.clone();
+        let request = request.into_inner();
+        tokio::task::spawn_blocking(move || {
+            let mut data: Vec<u8> = vec![];
+            let result = trace
+                .acquire(&task, request.database_name.clone())
+                .and_then(|()| {
+                    task.export(
+                        request.database_name,
+                        request.view_name,
+                        format(request.format),
+                        trace.principal(),
+                        &mut |mut line| {
+                            data.append(&mut line);
+                            if data.len() >= EXPORT_CHUNK_SIZE {
+                                send(&sender, data.split_off(0))
+                            } else {
+                                Ok(())
+                            }
+                        },
+                    )
+                })
+                .and_then(|_| {
+                    if data.is_empty() {
+                        Ok(())
+                    } else {
+                        send(&sender, data.split_off(0))
+                    }
+                });
+            // 最后一条内容为空，仅携带导出结果
+            let resp = match result {
+                Ok(()) => ResponseExport {
+                    status: Results::success_status(),
+                    msg_err: "".to_string(),
+                    data: vec![],
+                },
+                Err(err) => ResponseExport {
+                    status: trace.failed_status(err.clone()),
+                    msg_err: err.to_string(),
+                    data: vec![],
+                },
+            };
+            let _ = sender.blocking_send(Ok(resp.clone()));
+            let _ = trace.finish(&task, Results::response(resp));
+        });
+        Ok(Response::new(ReceiverStream::new(receiver)))
+    }
+
+    async fn import(
+        &self,
+        request: Request<Streaming<RequestImport>>,
+    ) -> Result<Response<ResponseImport>, Status> {
+        let mut trace = Trace::new(&self.task, &request, "transfer.import", "".to_string());
+        let mut stream = request.into_inner();
+        let mut report = ImportReport::default();
+        let result: GeorgeResult<()> = match stream.message().await {
+            Ok(Some(head)) => {
+                trace.target(format!("{}/{}", head.database_name, head.view_name));
+                let mut message = Some(head.clone());
+                loop {
+                    let req = match message {
+                        Some(req) => req,
+                        None => break Ok(()),
+                    };
+                    // 逐行写入为阻塞操作，避免占用异步运行时的工作线程
+                    let task = self.task.clone();
+                    let head = head.clone();
+                    let principal = trace.principal();
+                    let mut batch = report;
+                    report = match tokio::task::spawn_blocking(move || {
+                        task.import(
+                            head.database_name,
+                            head.view_name,
+                            format(head.format),
+                            head.overwrite,
+                            principal,
+                            req.rows
+                                .into_iter()
+                                .map(|row| (row.line, row.text))
+                                .collect(),
+                            &mut batch,
+                        );
+                        batch
+                    })
+                    .await
+                    {
+                        Ok(res) => res,
+                        Err(err) => {
+                            report = ImportReport::default();
+                            break Err(Errs::strs("import task", err));
+                        }
+                    };
+                    message = match stream.message().await {
+                        Ok(res) => res,
+                        Err(err) => break Err(Errs::strs("import stream", err)),
+                    };
+                }
+            }
+            Ok(None) => Err(Errs::str("import request can not be empty!")),
+            Err(err) => Err(Errs::strs("import stream", err)),
+        };
+        let (status, msg_err) = match result {
+            Ok(()) => (Results::success_status(), "".to_string()),
+            Err(err) => (trace.failed_status(err.clone()), err.to_string()),
+        };
+        let resp = ResponseImport {
+            status,
+            msg_err,
+            rows: report.rows,
+            imported: report.imported,
+            skipped: report.skipped,
+            failed: report.failed,
+            errors: report
+                .errors
+                .into_iter()
+                .map(|(line, msg_err)| ImportError { line, msg_err })
+                .collect(),
+        };
+        trace.finish(&self.task, Results::response(resp))
+    }
+}
+
+/// 请求中的格式转换为导出导入格式
+fn format(format: i32) -> transfer::TransferFormat {
+    if format == TransferFormat::Csv as i32 {
+        transfer::TransferFormat::Csv
+    } else {
+        transfer::TransferFormat::Jsonl
+    }
+}
+
+/// 发送导出内容，客户端已断开时终止导出
+fn send(sender: &Sender<Result<ResponseExport, Status>>, data: Vec<u8>) -> GeorgeResult<()> {
+    let resp = ResponseExport {
+        status: Results::success_status(),
+        msg_err: "".to_string(),
+        data,
+    };
+    match sender.blocking_send(Ok(resp)) {
+        Ok(()) => Ok(()),
+        Err(_) => Err(Errs::str("export receiver closed!")),
+    }
+}
@@ -20,10 +20,10 @@ use george_db::task::traits::TMaster;
 use george_db::Task;
 
 use crate::protos::db::db::user_service_server::UserService;
-use crate::protos::db::db::RequestLogin;
-use crate::protos::utils::utils::Resp;
+use crate::protos::db::db::{RequestLogin, ResponseLogin};
+use crate::protos::utils::utils::Status as RespStatus;
 use crate::server::db::{UserServer, DATABASE_SYS, VIEW_USER};
-use crate::tools::Results;
+use crate::tools::{Results, Trace};
 
 impl UserServer {
     pub fn new(task: Arc<Task>) -> Self {
@@ -33,8 +33,28 @@ impl UserServer {
 
 #[tonic::async_trait]
 impl UserService for UserServer {
-    async fn login(&self, request: Request<RequestLogin>) -> Result<Response<Resp>, Status> {
-        match self.task.get_disk(
+    async fn login(
+        &self,
+        request: Request<RequestLogin>,
+    ) -> Result<Response<ResponseLogin>, Status> {
+        let trace = Trace::new(
+            &self.task,
+            &request,
+            "user.login",
+            request.get_ref().name.clone(),
+        );
+        let failed = |status: i32, msg_err: String| ResponseLogin {
+            status,
+            msg_err,
+            token: "".to_string(),
+        };
+        let denied = || {
+            failed(
+                RespStatus::Unauthenticated as i32,
+                "user is not exist or pass is wrong!".to_string(),
+            )
+        };
+        let resp = match self.task.get_disk(
             DATABASE_SYS.to_string(),
             VIEW_USER.to_string(),
             request.get_ref().name.clone(),
@@ -42,14 +62,22 @@ impl UserService for UserServer {
             Ok(res) => match String::from_utf8(res) {
                 Ok(res) => {
                     if res.eq(&request.get_ref().pass) {
-                        Results::success()
+                        match self.task.token_issue(request.get_ref().name.clone()) {
+                            Ok(token) => ResponseLogin {
+                                status: Results::success_status(),
+                                msg_err: "".to_string(),
+                                token,
+                            },
+                            Err(err) => failed(trace.failed_status(err.clone()), err.to_string()),
+                        }
                     } else {
-                        Results::failed_custom("user is not exist or pass is wrong!".to_string())
+                        denied()
                     }
                 }
-                Err(err) => Results::failed_errs(err),
+                Err(_) => denied(),
             },
-            Err(err) => Results::failed_err(err),
-        }
+            Err(_) => denied(),
+        };
+        trace.finish(&self.task, Results::response(resp))
     }
 }
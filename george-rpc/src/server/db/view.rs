@@ -21,13 +21,13 @@ use george_db::Task;
 
 use crate::protos::db::db::view_service_server::ViewService;
 use crate::protos::db::db::{
-    RequestViewArchive, RequestViewCreate, RequestViewInfo, RequestViewList, RequestViewModify,
-    RequestViewRecord, RequestViewRecords, RequestViewRemove, ResponseViewInfo, ResponseViewList,
-    ResponseViewRecord, ResponseViewRecords, View, ViewRecord,
+    RequestViewArchive, RequestViewCipher, RequestViewCreate, RequestViewInfo, RequestViewList,
+    RequestViewModify, RequestViewRecord, RequestViewRecords, RequestViewRemove, ResponseViewInfo,
+    ResponseViewList, ResponseViewRecord, ResponseViewRecords, View, ViewRecord,
 };
 use crate::protos::utils::utils::Resp;
 use crate::server::db::ViewServer;
-use crate::tools::{Children, Results, Trans};
+use crate::tools::{Children, Results, Trace, Trans};
 
 impl ViewServer {
     pub fn new(task: Arc<Task>) -> Self {
@@ -41,6 +41,12 @@ impl ViewService for ViewServer {
         &self,
         request: Request<RequestViewList>,
     ) -> Result<Response<ResponseViewList>, Status> {
+        let trace = Trace::new(
+            &self.task,
+            &request,
+            "view.list",
+            request.get_ref().database_name.clone(),
+        );
         let resp;
         let mut views: Vec<View> = vec![];
         match self.task.view_map(request.get_ref().database_name.clone()) {
@@ -66,43 +72,75 @@ impl ViewService for ViewServer {
             }
             Err(err) => {
                 resp = ResponseViewList {
-                    status: Results::failed_status(err.clone()),
+                    status: trace.failed_status(err.clone()),
                     msg_err: err.to_string(),
                     views,
                 };
             }
         }
-        Results::response(resp)
+        trace.finish(&self.task, Results::response(resp))
     }
 
     async fn create(&self, request: Request<RequestViewCreate>) -> Result<Response<Resp>, Status> {
-        match self.task.view_create(
+        let trace = Trace::new(
+            &self.task,
+            &request,
+            "view.create",
+            format!(
+                "{}/{}",
+                request.get_ref().database_name,
+                request.get_ref().name
+            ),
+        );
+        let result = match self.task.view_create(
             request.get_ref().database_name.clone(),
             request.get_ref().name.clone(),
             request.get_ref().comment.clone(),
             request.get_ref().with_increment,
         ) {
             Ok(()) => Results::success(),
-            Err(err) => Results::failed_err(err),
-        }
+            Err(err) => trace.failed_err(err),
+        };
+        trace.finish(&self.task, result)
     }
 
     async fn modify(&self, request: Request<RequestViewModify>) -> Result<Response<Resp>, Status> {
-        match self.task.view_modify(
+        let trace = Trace::new(
+            &self.task,
+            &request,
+            "view.modify",
+            format!(
+                "{}/{}",
+                request.get_ref().database_name,
+                request.get_ref().name
+            ),
+        );
+        let result = match self.task.view_modify(
             request.get_ref().database_name.clone(),
             request.get_ref().name.clone(),
             request.get_ref().name_new.clone(),
             request.get_ref().comment.clone(),
         ) {
             Ok(()) => Results::success(),
-            Err(err) => Results::failed_err(err),
-        }
+            Err(err) => trace.failed_err(err),
+        };
+        trace.finish(&self.task, result)
     }
 
     async fn info(
         &self,
         request: Request<RequestViewInfo>,
     ) -> Result<Response<ResponseViewInfo>, Status> {
+        let trace = Trace::new(
+            &self.task,
+            &request,
+            "view.info",
+            format!(
+                "{}/{}",
+                request.get_ref().database_name,
+                request.get_ref().name
+            ),
+        );
         let resp;
         match self.task.view(
             request.get_ref().database_name.clone(),
@@ -126,43 +164,75 @@ impl ViewService for ViewServer {
             }
             Err(err) => {
                 resp = ResponseViewInfo {
-                    status: Results::failed_status(err.clone()),
+                    status: trace.failed_status(err.clone()),
                     msg_err: err.to_string(),
                     view: None,
                 };
             }
         }
-        Results::response(resp)
+        trace.finish(&self.task, Results::response(resp))
     }
 
     async fn remove(&self, request: Request<RequestViewRemove>) -> Result<Response<Resp>, Status> {
-        match self.task.view_remove(
+        let trace = Trace::new(
+            &self.task,
+            &request,
+            "view.remove",
+            format!(
+                "{}/{}",
+                request.get_ref().database_name,
+                request.get_ref().name
+            ),
+        );
+        let result = match self.task.view_remove(
             request.get_ref().database_name.clone(),
             request.get_ref().name.clone(),
         ) {
             Ok(()) => Results::success(),
-            Err(err) => Results::failed_err(err),
-        }
+            Err(err) => trace.failed_err(err),
+        };
+        trace.finish(&self.task, result)
     }
 
     async fn archive(
         &self,
         request: Request<RequestViewArchive>,
     ) -> Result<Response<Resp>, Status> {
-        match self.task.view_archive(
+        let trace = Trace::new(
+            &self.task,
+            &request,
+            "view.archive",
+            format!(
+                "{}/{}",
+                request.get_ref().database_name,
+                request.get_ref().name
+            ),
+        );
+        let result = match self.task.view_archive(
             request.get_ref().database_name.clone(),
             request.get_ref().name.clone(),
             request.get_ref().archive_file_path.clone(),
         ) {
             Ok(()) => Results::success(),
-            Err(err) => Results::failed_err(err),
-        }
+            Err(err) => trace.failed_err(err),
+        };
+        trace.finish(&self.task, result)
     }
 
     async fn record(
         &self,
         request: Request<RequestViewRecord>,
     ) -> Result<Response<ResponseViewRecord>, Status> {
+        let trace = Trace::new(
+            &self.task,
+            &request,
+            "view.record",
+            format!(
+                "{}/{}",
+                request.get_ref().database_name,
+                request.get_ref().name
+            ),
+        );
         let resp;
         match self.task.view_record(
             request.get_ref().database_name.clone(),
@@ -182,19 +252,29 @@ impl ViewService for ViewServer {
             }
             Err(err) => {
                 resp = ResponseViewRecord {
-                    status: Results::failed_status(err.clone()),
+                    status: trace.failed_status(err.clone()),
                     msg_err: err.to_string(),
                     record: None,
                 };
             }
         }
-        Results::response(resp)
+        trace.finish(&self.task, Results::response(resp))
     }
 
     async fn records(
         &self,
         request: Request<RequestViewRecords>,
     ) -> Result<Response<ResponseViewRecords>, Status> {
+        let trace = Trace::new(
+            &self.task,
+            &request,
+            "view.records",
+            format!(
+                "{}/{}",
+                request.get_ref().database_name,
+                request.get_ref().name
+            ),
+        );
         let resp;
         let mut records: Vec<ViewRecord> = vec![];
         match self.task.view_records(
@@ -217,12 +297,38 @@ impl ViewService for ViewServer {
             }
             Err(err) => {
                 resp = ResponseViewRecords {
-                    status: Results::failed_status(err.clone()),
+                    status: trace.failed_status(err.clone()),
                     msg_err: err.to_string(),
                     records,
                 };
             }
         }
-        Results::response(resp)
+        trace.finish(&self.task, Results::response(resp))
+    }
+
+    async fn cipher(&self, request: Request<RequestViewCipher>) -> Result<Response<Resp>, Status> {
+        let trace = Trace::new(
+            &self.task,
+            &request,
+            "view.cipher",
+            format!(
+                "{}/{}",
+                request.get_ref().database_name,
+                request.get_ref().name
+            ),
+        );
+        let result = match trace.admin().and_then(|()| {
+            self.task.view_cipher(
+                request.get_ref().database_name.clone(),
+                request.get_ref().name.clone(),
+                request.get_ref().fields.clone(),
+                request.get_ref().deterministic,
+                request.get_ref().readers.clone(),
+            )
+        }) {
+            Ok(()) => Results::success(),
+            Err(err) => trace.failed_err(err),
+        };
+        trace.finish(&self.task, result)
     }
 }
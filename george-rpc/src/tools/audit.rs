@@ -0,0 +1,284 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::sync::Mutex;
+use std::time::Instant;
+
+use tonic::{Request, Response, Status};
+
+use george_comm::errors::{Errs, GeorgeError, GeorgeResult};
+use george_db::Task;
+
+use crate::protos::db::db::{
+    ResponseAuditList, ResponseAuditVerify, ResponseBackup, ResponseBatch, ResponseBlock,
+    ResponseClusterJoin, ResponseClusterLeave, ResponseClusterStatus, ResponseDatabaseInfo,
+    ResponseDatabaseList, ResponseDiskDelete, ResponseDiskOut, ResponseDiskSelect,
+    ResponseDiskSelectStream, ResponseExport, ResponseImport, ResponseIndexInfo, ResponseIndexList,
+    ResponseLedgerInfo, ResponseLedgerList, ResponseLogin, ResponseMemoryOut, ResponseMemoryPOut,
+    ResponsePageInfo, ResponsePageList, ResponseQuotaList, ResponseReplicationFollow,
+    ResponseReplicationPromote, ResponseReplicationSnapshot, ResponseReplicationStatus,
+    ResponseTransaction, ResponseViewInfo, ResponseViewList, ResponseViewRecord,
+    ResponseViewRecords,
+};
+use crate::protos::utils::utils::{Resp, Status as RespStatus};
+use crate::tools::{Results, Trace};
+
+/// 请求头中携带登录令牌的键
+pub const AUTHORIZATION_KEY: &str = "authorization";
+/// 登录令牌在请求头中的前缀
+pub const BEARER_PREFIX: &str = "Bearer ";
+/// 请求头未携带登录令牌或令牌无效时的操作人
+pub const PRINCIPAL_ANONYMOUS: &str = "anonymous";
+/// 管理员，仅管理员可调用备份、配额、审计、复制及集群等管理接口
+pub const PRINCIPAL_ADMIN: &str = "admin";
+/// 请求头中携带节点间认证凭证的键
+pub const PEER_KEY: &str = "george-peer";
+/// 携带有效节点间认证凭证且未登录的请求的操作人
+pub const PRINCIPAL_PEER: &str = "peer";
+
+/// 携带执行结果的响应，用于审计时获取操作是否成功及错误信息
+pub trait Audited {
+    /// 执行结果状态
+    fn status(&self) -> i32;
+
+    /// 执行失败时的错误信息
+    fn msg_err(&self) -> String;
+}
+
+macro_rules! audited {
+    ($($resp:ty),*) => {
+        $(impl Audited for $resp {
+            fn status(&self) -> i32 {
+                self.status
+            }
+
+            fn msg_err(&self) -> String {
+                self.msg_err.clone()
+            }
+        })*
+    };
+}
+
+audited!(
+    Resp,
+    ResponseAuditList,
+    ResponseAuditVerify,
+    ResponseBackup,
+    ResponseBatch,
+    ResponseBlock,
+    ResponseClusterJoin,
+    ResponseClusterLeave,
+    ResponseClusterStatus,
+    ResponseDatabaseInfo,
+    ResponseDatabaseList,
+    ResponseDiskDelete,
+    ResponseDiskOut,
+    ResponseDiskSelect,
+    ResponseDiskSelectStream,
+    ResponseExport,
+    ResponseImport,
+    ResponseIndexInfo,
+    ResponseIndexList,
+    ResponseLedgerInfo,
+    ResponseLedgerList,
+    ResponseLogin,
+    ResponseMemoryOut,
+    ResponseMemoryPOut,
+    ResponsePageInfo,
+    ResponsePageList,
+    ResponseQuotaList,
+    ResponseReplicationFollow,
+    ResponseReplicationPromote,
+    ResponseReplicationSnapshot,
+    ResponseReplicationStatus,
+    ResponseTransaction,
+    ResponseViewInfo,
+    ResponseViewList,
+    ResponseViewRecord,
+    ResponseViewRecords
+);
+
+impl Trace {
+    /// 开始追踪请求
+    ///
+    /// ###Params
+    ///
+    /// * task 任务对象，用于校验登录令牌
+    /// * request 请求对象，从请求头中读取登录令牌并校验得到操作人，从连接中读取操作发起地址
+    /// * operation 操作名称，如`database.create`
+    /// * target 操作对象，如`database_name/view_name`
+    pub fn new<T>(task: &Task, request: &Request<T>, operation: &str, target: String) -> Trace {
+        let principal = match request
+            .metadata()
+            .get(AUTHORIZATION_KEY)
+            .and_then(|res| res.to_str().ok())
+            .and_then(|res| res.strip_prefix(BEARER_PREFIX))
+        {
+            Some(token) => match task.token_verify(token) {
+                Ok(res) => res,
+                Err(err) => {
+                    log::debug!("{} token verify failed! error is {}", operation, err);
+                    PRINCIPAL_ANONYMOUS.to_string()
+                }
+            },
+            None => PRINCIPAL_ANONYMOUS.to_string(),
+        };
+        let peer = match request
+            .metadata()
+            .get(PEER_KEY)
+            .and_then(|res| res.to_str().ok())
+        {
+            Some(credential) => match task.peer_verify(credential) {
+                Ok(()) => true,
+                Err(err) => {
+                    log::warn!("{} peer verify failed! error is {}", operation, err);
+                    false
+                }
+            },
+            None => false,
+        };
+        let principal = if peer && principal.eq(PRINCIPAL_ANONYMOUS) {
+            PRINCIPAL_PEER.to_string()
+        } else {
+            principal
+        };
+        let remote = match request.remote_addr() {
+            Some(res) => res.to_string(),
+            None => "".to_string(),
+        };
+        Trace {
+            principal,
+            peer,
+            remote,
+            operation: operation.to_string(),
+            target,
+            start: Instant::now(),
+            kind: Mutex::new(None),
+        }
+    }
+
+    /// 操作发起人
+    pub fn principal(&self) -> String {
+        self.principal.clone()
+    }
+
+    /// 校验操作发起人是否为管理员，非管理员返回权限不足错误
+    pub fn admin(&self) -> GeorgeResult<()> {
+        if self.principal.eq(PRINCIPAL_ADMIN) {
+            Ok(())
+        } else {
+            Err(Errs::permission_denied_error(format!(
+                "{} require admin, but principal is {}",
+                self.operation, self.principal
+            )))
+        }
+    }
+
+    /// 校验请求是否携带有效的节点间认证凭证，否则返回权限不足错误
+    pub fn peer(&self) -> GeorgeResult<()> {
+        if self.peer {
+            Ok(())
+        } else {
+            Err(Errs::permission_denied_error(format!(
+                "{} require peer credential, but principal is {}",
+                self.operation, self.principal
+            )))
+        }
+    }
+
+    /// 修改操作对象，用于操作对象需从流式请求内容中获取的场景
+    pub fn target(&mut self, target: String) {
+        self.target = target;
+    }
+
+    /// 按操作发起人及所操作的库消耗请求令牌，令牌不足则返回配额超限错误
+    ///
+    /// ###Params
+    ///
+    /// * task 任务对象
+    /// * database_name 所操作的库，为空则仅校验操作发起人
+    pub fn acquire(&self, task: &Task, database_name: String) -> GeorgeResult<()> {
+        task.quota_acquire(self.principal.clone(), database_name)
+    }
+
+    /// 记录请求失败的错误类型并返回失败响应
+    pub fn failed_err(&self, err: GeorgeError) -> Result<Response<Resp>, Status> {
+        self.failed(&err);
+        Results::failed_err(err)
+    }
+
+    /// 记录请求失败的错误类型并返回自定义失败响应
+    pub fn failed_errs(&self, err: GeorgeError) -> Result<Response<Resp>, Status> {
+        self.failed(&err);
+        Results::failed_errs(err)
+    }
+
+    /// 记录请求失败的错误类型并返回失败状态
+    pub fn failed_status(&self, err: GeorgeError) -> i32 {
+        self.failed(&err);
+        Results::failed_status(err)
+    }
+
+    fn failed(&self, err: &GeorgeError) {
+        *self.kind.lock().unwrap() = Some(Errs::kind(err));
+    }
+
+    /// 结束追踪，记录本次请求的运行指标并写入审计日志，原样返回请求结果
+    ///
+    /// 审计日志写入失败不影响请求结果，仅记录错误日志
+    pub fn finish<R: Audited>(
+        self,
+        task: &Task,
+        result: Result<Response<R>, Status>,
+    ) -> Result<Response<R>, Status> {
+        let (success, msg_err, kind) = match &result {
+            Ok(res) => {
+                let status = res.get_ref().status();
+                if status == Results::success_status() {
+                    (true, res.get_ref().msg_err(), None)
+                } else {
+                    // 优先采用失败时记录的错误类型，未记录时以响应状态区分
+                    let kind = match *self.kind.lock().unwrap() {
+                        Some(kind) => kind.to_string(),
+                        None => match RespStatus::from_i32(status) {
+                            Some(res) => format!("{:?}", res),
+                            None => status.to_string(),
+                        },
+                    };
+                    (false, res.get_ref().msg_err(), Some(kind))
+                }
+            }
+            Err(err) => (
+                false,
+                err.message().to_string(),
+                Some(format!("{:?}", err.code())),
+            ),
+        };
+        let latency = self.start.elapsed();
+        task.metrics_record(self.operation.as_str(), kind, latency);
+        match task.audit_record(
+            self.principal,
+            self.remote,
+            self.operation,
+            self.target,
+            success,
+            msg_err,
+            latency.as_micros() as u64,
+        ) {
+            Ok(()) => {}
+            Err(err) => log::error!("audit record failed! error is {}", err),
+        }
+        result
+    }
+}
@@ -12,10 +12,15 @@
  * limitations under the License.
  */
 
+use std::sync::Mutex;
+use std::time::Instant;
+
+pub mod audit;
 pub mod child;
 pub mod results;
 pub mod trans;
 pub mod trim;
+mod trim_test;
 
 pub struct Children;
 
@@ -24,3 +29,21 @@ pub struct Results;
 pub struct Trans;
 
 pub struct Trim;
+
+/// 单次请求的审计追踪信息，请求处理结束后写入审计日志
+pub struct Trace {
+    /// 操作发起人
+    principal: String,
+    /// 是否携带有效的节点间认证凭证
+    peer: bool,
+    /// 操作发起地址
+    remote: String,
+    /// 操作名称
+    operation: String,
+    /// 操作对象
+    target: String,
+    /// 请求开始处理时间
+    start: Instant,
+    /// 请求失败时的错误类型，用于按错误类型统计失败请求
+    kind: Mutex<Option<&'static str>>,
+}
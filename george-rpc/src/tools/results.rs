@@ -88,6 +88,7 @@ fn status(err: GeorgeError) -> Status {
         | GeorgeError::PageExistError(_)
         | GeorgeError::DatabaseExistError(_)
         | GeorgeError::ViewExistError(_)
+        | GeorgeError::LedgerExistError(_)
         | GeorgeError::IndexExistError(_) => Status::AlreadyExists,
         GeorgeError::PageNoExistError(_)
         | GeorgeError::DirNoExistError(_)
@@ -95,9 +96,12 @@ fn status(err: GeorgeError) -> Status {
         | GeorgeError::DataNoExistError(_)
         | GeorgeError::DatabaseNoExistError(_)
         | GeorgeError::ViewNoExistError(_)
+        | GeorgeError::LedgerNoExistError(_)
         | GeorgeError::IndexNoExistError(_)
         | GeorgeError::NoneError(_) => Status::NotFound,
         GeorgeError::MethodNoSupportError(_) => Status::Unimplemented,
+        GeorgeError::QuotaExceededError(_) => Status::ResourceExhausted,
+        GeorgeError::PermissionDeniedError(_) => Status::PermissionDenied,
         GeorgeError::StringError(_) => Status::Custom,
     }
 }
@@ -13,7 +13,7 @@
  */
 
 use george_comm::errors::{Errs, GeorgeResult};
-use george_comm::Time;
+use george_comm::{IndexEngine, IndexKeyType, Time};
 
 use crate::protos::db::db::{Engine, KeyType};
 use crate::protos::utils::utils::{Status, Timestamp};
@@ -117,6 +117,23 @@ impl Trans {
         }
     }
 
+    pub fn comm_2_engine(e: IndexEngine) -> Engine {
+        match e {
+            IndexEngine::Disk => Engine::Disk,
+            IndexEngine::Sequence => Engine::Sequence,
+        }
+    }
+
+    pub fn comm_2_key_type(e: IndexKeyType) -> KeyType {
+        match e {
+            IndexKeyType::String => KeyType::String,
+            IndexKeyType::UInt => KeyType::UInt,
+            IndexKeyType::Int => KeyType::Int,
+            IndexKeyType::Bool => KeyType::Bool,
+            IndexKeyType::Float => KeyType::Float,
+        }
+    }
+
     pub fn key_type_2_db(e: KeyType) -> george_db::utils::enums::KeyType {
         match e {
             KeyType::Nonsupport => george_db::utils::enums::KeyType::None,
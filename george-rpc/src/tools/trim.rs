@@ -36,40 +36,38 @@ impl Trim {
     }
 }
 
+/// 仅规整空白，不改变输入的大小写，命令关键字由各解析方忽略大小写匹配，名称及引号内的字面量保留原样
 fn trim_str(str: String) -> String {
-    let str = str.to_lowercase();
     trim_parse(str)
 }
 
+/// 合并引号外的连续空白及换行为一个空格并去除首尾空白，引号内的内容原样保留
 fn trim_parse(str: String) -> String {
-    let str = trim_n(str);
-    let str = trim_t(str);
-    match str.strip_suffix(" ") {
-        Some(str) => match str.strip_prefix(" ") {
-            Some(str) => str.to_string(),
-            None => str.to_string(),
-        },
-        None => match str.strip_suffix(" ") {
-            Some(str) => str.to_string(),
-            None => str.to_string(),
-        },
-    }
-}
-
-fn trim_n(str: String) -> String {
-    if str.contains("\n") {
-        let str = str.replace("\n", " ");
-        trim_n(str)
-    } else {
-        str.to_string()
-    }
-}
-
-fn trim_t(str: String) -> String {
-    if str.contains("  ") {
-        let str = str.replace("  ", " ");
-        trim_t(str)
-    } else {
-        str.to_string()
+    let mut res = String::new();
+    let mut quote: Option<char> = None;
+    let mut space = false;
+    for c in str.trim().chars() {
+        match quote {
+            Some(q) => {
+                if c == q {
+                    quote = None;
+                }
+            }
+            None => {
+                if c.is_whitespace() {
+                    space = true;
+                    continue;
+                }
+                if c == '\'' || c == '"' {
+                    quote = Some(c);
+                }
+            }
+        }
+        if space {
+            res.push(' ');
+            space = false;
+        }
+        res.push(c);
     }
+    res
 }
@@ -0,0 +1,39 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[cfg(test)]
+mod trim {
+    use crate::tools::Trim;
+
+    #[test]
+    fn str_test() {
+        assert_eq!(
+            Trim::str("  SELECT * FROM User\n  WHERE name = 'A  B'  ".to_string()),
+            "SELECT * FROM User WHERE name = 'A  B'"
+        );
+        assert_eq!(Trim::str("EXIT".to_string()), "EXIT");
+        assert_eq!(
+            Trim::str("put v1 k1 \"x\n\ty\"".to_string()),
+            "put v1 k1 \"x\n\ty\""
+        );
+    }
+
+    #[test]
+    fn parse_test() {
+        assert_eq!(
+            Trim::parse("Use Database Sys;".to_string()),
+            "Use Database Sys"
+        );
+    }
+}
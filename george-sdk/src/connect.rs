@@ -0,0 +1,241 @@
+/*
+ * Copyright (c) 2020. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::future::Future;
+use std::time::{Duration, Instant};
+use std::vec::IntoIter;
+
+use tonic::transport::{Certificate, Channel, ClientTlsConfig, Endpoint, Identity};
+use tonic::{Request, Response, Status};
+
+use george_comm::errors::{Errs, GeorgeResult};
+use george_rpc::client::{status_check, with_token};
+use george_rpc::protos::db::db::BatchItem;
+use george_rpc::protos::utils::utils::Status as RespStatus;
+use george_rpc::tools::audit::Audited;
+
+use crate::{ClientConfig, GeorgeClient, Retry};
+
+/// 默认连接池大小
+const POOL_SIZE: usize = 2;
+
+/// 批量写入时单个请求携带的数据项数
+const BATCH_CHUNK: usize = 1000;
+
+impl GeorgeClient {
+    /// 按配置建立连接池并新建客户端
+    ///
+    /// 新建时先建立一次连接以尽早发现地址或证书错误，连接池中的连接在首次请求时建立，断开后自动重连。
+    /// 配置了用户名及密码时随即登录，登录失败返回错误
+    pub async fn connect(config: ClientConfig) -> GeorgeResult<GeorgeClient> {
+        let endpoint = GeorgeClient::endpoint(&config)?;
+        let probe = endpoint.connect();
+        let result = match config.connect_timeout {
+            Some(res) => match tokio::time::timeout(Duration::from_millis(res), probe).await {
+                Ok(res) => res,
+                Err(_) => return Err(Errs::string(format!("connect timeout after {}ms!", res))),
+            },
+            None => probe.await,
+        };
+        if let Err(err) = result {
+            return Err(Errs::strs("endpoint connect", err));
+        }
+        let pool_size = config.pool_size.unwrap_or(POOL_SIZE).max(1);
+        let channel = Channel::balance_list((0..pool_size).map(|_| endpoint.clone()));
+        let client = GeorgeClient {
+            channel,
+            token: "".to_string(),
+            retry: config.retry.unwrap_or_default(),
+            deadline: config.deadline.map(Duration::from_millis),
+        };
+        match (config.name, config.pass) {
+            (Some(name), Some(pass)) => client.login(name, pass).await,
+            _ => Ok(client),
+        }
+    }
+
+    /// 新建共用同一连接池、以`deadline`为请求截止时长的客户端，用于为单次或部分请求单独设置截止时长
+    pub fn with_deadline(&self, deadline: Duration) -> GeorgeClient {
+        let mut client = self.clone();
+        client.deadline = Some(deadline);
+        client
+    }
+
+    /// 新建共用同一连接池、以`retry`为重试策略的客户端
+    pub fn with_retry(&self, retry: Retry) -> GeorgeClient {
+        let mut client = self.clone();
+        client.retry = retry;
+        client
+    }
+
+    fn endpoint(config: &ClientConfig) -> GeorgeResult<Endpoint> {
+        let dst = format!("{}://{}:{}", "http", config.remote, config.port);
+        let mut endpoint = match Endpoint::from_shared(dst) {
+            Ok(res) => res,
+            Err(err) => return Err(Errs::strs("endpoint from shared", err)),
+        };
+        if let Some(tls) = &config.tls {
+            let mut tls_config = ClientTlsConfig::new().domain_name(tls.domain_name.clone());
+            if let (Some(cert_bytes), Some(key_bytes)) = (&tls.cert_bytes, &tls.key_bytes) {
+                let identity = Identity::from_pem(cert_bytes, key_bytes);
+                tls_config = tls_config.identity(identity);
+            }
+            let cert = Certificate::from_pem(tls.ca_bytes.clone());
+            tls_config = tls_config.ca_certificate(cert);
+            endpoint = match endpoint.tls_config(tls_config) {
+                Ok(res) => res,
+                Err(err) => return Err(Errs::strs("endpoint tls config", err)),
+            };
+        }
+        Ok(endpoint)
+    }
+
+    /// 发起请求并校验响应中的执行结果
+    ///
+    /// * idempotent 是否幂等，幂等请求在连接类错误时按重试策略重试
+    /// * call 以共享通道及携带登录令牌的请求发起调用，重试时被再次调用
+    pub(crate) async fn call<Req, Resp, F, Fut>(
+        &self,
+        idempotent: bool,
+        request: Req,
+        call: F,
+    ) -> GeorgeResult<Resp>
+    where
+        Req: Clone,
+        Resp: Audited,
+        F: Fn(Channel, Request<Req>) -> Fut,
+        Fut: Future<Output = Result<Response<Resp>, Status>>,
+    {
+        let resp = self.send(idempotent, request, call).await?;
+        status_check(resp.status(), resp.msg_err())?;
+        Ok(resp)
+    }
+
+    /// 发起删除请求并校验响应中的执行结果
+    ///
+    /// 删除请求经过重试时，此前的尝试可能已删除成功但响应丢失，此时删除对象不存在视为删除成功
+    pub(crate) async fn call_remove<Req, Resp, F, Fut>(
+        &self,
+        request: Req,
+        call: F,
+    ) -> GeorgeResult<Resp>
+    where
+        Req: Clone,
+        Resp: Audited,
+        F: Fn(Channel, Request<Req>) -> Fut,
+        Fut: Future<Output = Result<Response<Resp>, Status>>,
+    {
+        let (resp, attempts) = self.attempt(true, request, call).await?;
+        if attempts > 1 && resp.status() == RespStatus::NotFound as i32 {
+            log::debug!("rpc remove retried and target is already absent!");
+            return Ok(resp);
+        }
+        status_check(resp.status(), resp.msg_err())?;
+        Ok(resp)
+    }
+
+    /// 发起请求，截止时长包含重试在内，超出截止时长时放弃请求
+    pub(crate) async fn send<Req, Resp, F, Fut>(
+        &self,
+        idempotent: bool,
+        request: Req,
+        call: F,
+    ) -> GeorgeResult<Resp>
+    where
+        Req: Clone,
+        F: Fn(Channel, Request<Req>) -> Fut,
+        Fut: Future<Output = Result<Response<Resp>, Status>>,
+    {
+        self.attempt(idempotent, request, call)
+            .await
+            .map(|(resp, _)| resp)
+    }
+
+    /// 发起请求，返回响应及尝试次数
+    ///
+    /// 设置截止时长时，每次尝试均以剩余时长作为`grpc-timeout`请求头告知服务端，截止时长包含重试在内
+    async fn attempt<Req, Resp, F, Fut>(
+        &self,
+        idempotent: bool,
+        request: Req,
+        call: F,
+    ) -> GeorgeResult<(Resp, u32)>
+    where
+        Req: Clone,
+        F: Fn(Channel, Request<Req>) -> Fut,
+        Fut: Future<Output = Result<Response<Resp>, Status>>,
+    {
+        let attempts = if idempotent {
+            self.retry.attempts.max(1)
+        } else {
+            1
+        };
+        let start = Instant::now();
+        let future = async {
+            let mut attempt: u32 = 0;
+            loop {
+                attempt += 1;
+                let mut req = with_token(Request::new(request.clone()), &self.token);
+                if let Some(deadline) = self.deadline {
+                    req.set_timeout(deadline.saturating_sub(start.elapsed()));
+                }
+                match call(self.channel.clone(), req).await {
+                    Ok(res) => return Ok((res.into_inner(), attempt)),
+                    Err(err) => {
+                        if attempt >= attempts || !Retry::retryable(&err, self.deadline.is_some()) {
+                            return Err(Errs::strs("george rpc", err));
+                        }
+                        log::debug!("rpc attempt {} failed and retry! error is {}", attempt, err);
+                        tokio::time::sleep(self.retry.delay(attempt)).await;
+                    }
+                }
+            }
+        };
+        match self.deadline {
+            Some(deadline) => match tokio::time::timeout(deadline, future).await {
+                Ok(res) => res,
+                Err(_) => Err(Errs::string(format!(
+                    "rpc deadline {}ms exceeded!",
+                    deadline.as_millis()
+                ))),
+            },
+            None => future.await,
+        }
+    }
+}
+
+/// 将批量写入的数据项按`BATCH_CHUNK`分组，数据项为空时仍返回一组，首个请求需携带目标信息
+pub(crate) fn batch_chunks(items: Vec<(String, Vec<u8>)>) -> Vec<Vec<BatchItem>> {
+    let mut chunks = vec![];
+    let mut chunk = Vec::with_capacity(BATCH_CHUNK.min(items.len()));
+    for (key, value) in items {
+        chunk.push(BatchItem { key, value });
+        if chunk.len() >= BATCH_CHUNK {
+            chunks.push(chunk);
+            chunk = vec![];
+        }
+    }
+    if !chunk.is_empty() || chunks.is_empty() {
+        chunks.push(chunk);
+    }
+    chunks
+}
+
+/// 将携带请求集合的请求转为流式请求，保留请求头，用于重试时重新发送
+pub(crate) fn streaming<T>(request: Request<Vec<T>>) -> Request<tokio_stream::Iter<IntoIter<T>>> {
+    let metadata = request.metadata().clone();
+    let mut req = Request::new(tokio_stream::iter(request.into_inner()));
+    *req.metadata_mut() = metadata;
+    req
+}
@@ -0,0 +1,48 @@
+/*
+ * Copyright (c) 2020. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[cfg(test)]
+mod connect {
+    use crate::connect::batch_chunks;
+
+    fn items(count: usize) -> Vec<(String, Vec<u8>)> {
+        (0..count)
+            .map(|i| (i.to_string(), i.to_string().into_bytes()))
+            .collect()
+    }
+
+    #[test]
+    fn batch_chunks_empty() {
+        let chunks = batch_chunks(items(0));
+        assert_eq!(chunks.len(), 1);
+        assert!(chunks[0].is_empty());
+    }
+
+    #[test]
+    fn batch_chunks_split() {
+        let chunks = batch_chunks(items(2500));
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks[0].len(), 1000);
+        assert_eq!(chunks[1].len(), 1000);
+        assert_eq!(chunks[2].len(), 500);
+        assert_eq!(chunks[2][499].key, "2499");
+    }
+
+    #[test]
+    fn batch_chunks_exact() {
+        let chunks = batch_chunks(items(2000));
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[1].len(), 1000);
+    }
+}
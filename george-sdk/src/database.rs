@@ -0,0 +1,69 @@
+/*
+ * Copyright (c) 2020. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use george_comm::errors::{Errs, GeorgeResult};
+use george_rpc::protos::db::db::database_service_client::DatabaseServiceClient;
+use george_rpc::protos::db::db::{
+    Database, RequestDatabaseCreate, RequestDatabaseInfo, RequestDatabaseRemove,
+};
+use george_rpc::protos::utils::utils::Req;
+
+use crate::GeorgeClient;
+
+impl GeorgeClient {
+    /// 数据库集合
+    pub async fn database_list(&self) -> GeorgeResult<Vec<Database>> {
+        let request = Req {};
+        let resp = self
+            .call(true, request, |channel, request| async move {
+                DatabaseServiceClient::new(channel).list(request).await
+            })
+            .await?;
+        Ok(resp.databases)
+    }
+
+    /// 创建数据库
+    pub async fn database_create(&self, name: String, comment: String) -> GeorgeResult<()> {
+        let request = RequestDatabaseCreate { name, comment };
+        self.call(false, request, |channel, request| async move {
+            DatabaseServiceClient::new(channel).create(request).await
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// 获取数据库详情
+    pub async fn database(&self, name: String) -> GeorgeResult<Database> {
+        let request = RequestDatabaseInfo { name };
+        let resp = self
+            .call(true, request, |channel, request| async move {
+                DatabaseServiceClient::new(channel).info(request).await
+            })
+            .await?;
+        match resp.database {
+            Some(res) => Ok(res),
+            None => Err(Errs::database_no_exist_error()),
+        }
+    }
+
+    /// 删除数据库
+    pub async fn database_remove(&self, name: String) -> GeorgeResult<()> {
+        let request = RequestDatabaseRemove { name };
+        self.call(false, request, |channel, request| async move {
+            DatabaseServiceClient::new(channel).remove(request).await
+        })
+        .await?;
+        Ok(())
+    }
+}
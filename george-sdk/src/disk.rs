@@ -0,0 +1,267 @@
+/*
+ * Copyright (c) 2020. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use george_comm::errors::{Errs, GeorgeResult};
+use george_rpc::client::db::DiskSelectStream;
+use george_rpc::protos::db::db::disk_service_client::DiskServiceClient;
+use george_rpc::protos::db::db::{
+    DiskDeleted, DiskSelected, RequestDiskBatch, RequestDiskDelete, RequestDiskIOut,
+    RequestDiskInto, RequestDiskOut, RequestDiskRemove, RequestDiskSelect, ResponseBatch,
+};
+
+use crate::connect::{batch_chunks, streaming};
+use crate::GeorgeClient;
+
+impl GeorgeClient {
+    /// 插入数据，已存在的键写入失败
+    pub async fn put_disk(
+        &self,
+        database_name: String,
+        view_name: String,
+        key: String,
+        value: Vec<u8>,
+    ) -> GeorgeResult<()> {
+        let request = RequestDiskInto {
+            database_name,
+            view_name,
+            key,
+            value,
+        };
+        self.call(false, request, |channel, request| async move {
+            DiskServiceClient::new(channel).put(request).await
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// 插入数据，已存在的键将被覆盖
+    pub async fn set_disk(
+        &self,
+        database_name: String,
+        view_name: String,
+        key: String,
+        value: Vec<u8>,
+    ) -> GeorgeResult<()> {
+        let request = RequestDiskInto {
+            database_name,
+            view_name,
+            key,
+            value,
+        };
+        self.call(true, request, |channel, request| async move {
+            DiskServiceClient::new(channel).set(request).await
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// 获取数据
+    pub async fn get_disk(
+        &self,
+        database_name: String,
+        view_name: String,
+        key: String,
+    ) -> GeorgeResult<Vec<u8>> {
+        let request = RequestDiskOut {
+            database_name,
+            view_name,
+            key,
+        };
+        let resp = self
+            .call(true, request, |channel, request| async move {
+                DiskServiceClient::new(channel).get(request).await
+            })
+            .await?;
+        Ok(resp.value)
+    }
+
+    /// 根据指定索引获取数据
+    pub async fn get_disk_by_index(
+        &self,
+        database_name: String,
+        view_name: String,
+        index_name: String,
+        key: String,
+    ) -> GeorgeResult<Vec<u8>> {
+        let request = RequestDiskIOut {
+            database_name,
+            view_name,
+            index_name,
+            key,
+        };
+        let resp = self
+            .call(true, request, |channel, request| async move {
+                DiskServiceClient::new(channel).get_by_index(request).await
+            })
+            .await?;
+        Ok(resp.value)
+    }
+
+    /// 删除数据
+    pub async fn remove_disk(
+        &self,
+        database_name: String,
+        view_name: String,
+        key: String,
+    ) -> GeorgeResult<()> {
+        let request = RequestDiskRemove {
+            database_name,
+            view_name,
+            key,
+        };
+        self.call_remove(request, |channel, request| async move {
+            DiskServiceClient::new(channel).remove(request).await
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// 条件检索
+    pub async fn select_disk(
+        &self,
+        database_name: String,
+        view_name: String,
+        constraint_json_bytes: Vec<u8>,
+    ) -> GeorgeResult<DiskSelected> {
+        let request = RequestDiskSelect {
+            database_name,
+            view_name,
+            constraint_json_bytes,
+        };
+        let resp = self
+            .call(true, request, |channel, request| async move {
+                DiskServiceClient::new(channel).select(request).await
+            })
+            .await?;
+        match resp.selected {
+            Some(res) => Ok(res),
+            None => Err(Errs::data_no_exist_error()),
+        }
+    }
+
+    /// 条件检索，检索结果在服务端检索过程中分批返回，无需全部载入内存
+    ///
+    /// 截止时长及重试仅作用于建立响应流，不包含读取检索结果的过程
+    pub async fn select_disk_stream(
+        &self,
+        database_name: String,
+        view_name: String,
+        constraint_json_bytes: Vec<u8>,
+    ) -> GeorgeResult<DiskSelectStream> {
+        let request = RequestDiskSelect {
+            database_name,
+            view_name,
+            constraint_json_bytes,
+        };
+        let stream = self
+            .send(true, request, |channel, request| async move {
+                DiskServiceClient::new(channel).select_stream(request).await
+            })
+            .await?;
+        Ok(DiskSelectStream::new(stream))
+    }
+
+    /// 条件删除
+    pub async fn delete_disk(
+        &self,
+        database_name: String,
+        view_name: String,
+        constraint_json_bytes: Vec<u8>,
+    ) -> GeorgeResult<DiskDeleted> {
+        let request = RequestDiskDelete {
+            database_name,
+            view_name,
+            constraint_json_bytes,
+        };
+        let resp = self
+            .call(false, request, |channel, request| async move {
+                DiskServiceClient::new(channel).delete(request).await
+            })
+            .await?;
+        match resp.deleted {
+            Some(res) => Ok(res),
+            None => Err(Errs::data_no_exist_error()),
+        }
+    }
+
+    /// 批量插入数据，已存在的键写入失败
+    ///
+    /// atomic为true时任一数据项失败，服务端将回滚已写入的数据项
+    pub async fn put_disk_batch(
+        &self,
+        database_name: String,
+        view_name: String,
+        atomic: bool,
+        items: Vec<(String, Vec<u8>)>,
+    ) -> GeorgeResult<ResponseBatch> {
+        let requests = disk_batch(database_name, view_name, atomic, items);
+        self.call(false, requests, |channel, request| async move {
+            DiskServiceClient::new(channel)
+                .put_batch(streaming(request))
+                .await
+        })
+        .await
+    }
+
+    /// 批量插入数据，已存在的键将被覆盖
+    pub async fn set_disk_batch(
+        &self,
+        database_name: String,
+        view_name: String,
+        atomic: bool,
+        items: Vec<(String, Vec<u8>)>,
+    ) -> GeorgeResult<ResponseBatch> {
+        let requests = disk_batch(database_name, view_name, atomic, items);
+        self.call(true, requests, |channel, request| async move {
+            DiskServiceClient::new(channel)
+                .set_batch(streaming(request))
+                .await
+        })
+        .await
+    }
+
+    /// 批量删除数据，数据项中的值将被忽略
+    pub async fn remove_disk_batch(
+        &self,
+        database_name: String,
+        view_name: String,
+        atomic: bool,
+        items: Vec<(String, Vec<u8>)>,
+    ) -> GeorgeResult<ResponseBatch> {
+        let requests = disk_batch(database_name, view_name, atomic, items);
+        self.call(true, requests, |channel, request| async move {
+            DiskServiceClient::new(channel)
+                .remove_batch(streaming(request))
+                .await
+        })
+        .await
+    }
+}
+
+fn disk_batch(
+    database_name: String,
+    view_name: String,
+    atomic: bool,
+    items: Vec<(String, Vec<u8>)>,
+) -> Vec<RequestDiskBatch> {
+    batch_chunks(items)
+        .into_iter()
+        .map(|items| RequestDiskBatch {
+            database_name: database_name.clone(),
+            view_name: view_name.clone(),
+            atomic,
+            items,
+        })
+        .collect()
+}
@@ -0,0 +1,97 @@
+/*
+ * Copyright (c) 2020. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use george_comm::errors::{Errs, GeorgeResult};
+use george_rpc::protos::db::db::index_service_client::IndexServiceClient;
+use george_rpc::protos::db::db::{
+    Engine, Index, KeyType, RequestIndexCreate, RequestIndexInfo, RequestIndexList,
+};
+
+use crate::GeorgeClient;
+
+impl GeorgeClient {
+    /// 索引集合
+    pub async fn index_list(
+        &self,
+        database_name: String,
+        view_name: String,
+    ) -> GeorgeResult<Vec<Index>> {
+        let request = RequestIndexList {
+            database_name,
+            view_name,
+        };
+        let resp = self
+            .call(true, request, |channel, request| async move {
+                IndexServiceClient::new(channel).list(request).await
+            })
+            .await?;
+        Ok(resp.indexes)
+    }
+
+    /// 创建索引
+    ///
+    /// * unique 是否唯一索引
+    /// * primary 是否主键
+    /// * null 是否允许为空
+    pub async fn index_create(
+        &self,
+        database_name: String,
+        view_name: String,
+        name: String,
+        unique: bool,
+        primary: bool,
+        null: bool,
+        key_type: KeyType,
+        engine: Engine,
+    ) -> GeorgeResult<()> {
+        let request = RequestIndexCreate {
+            database_name,
+            view_name,
+            name,
+            engine: engine as i32,
+            primary,
+            unique,
+            null,
+            key_type: key_type as i32,
+        };
+        self.call(false, request, |channel, request| async move {
+            IndexServiceClient::new(channel).create(request).await
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// 获取索引详情
+    pub async fn index(
+        &self,
+        database_name: String,
+        view_name: String,
+        name: String,
+    ) -> GeorgeResult<Index> {
+        let request = RequestIndexInfo {
+            database_name,
+            view_name,
+            name,
+        };
+        let resp = self
+            .call(true, request, |channel, request| async move {
+                IndexServiceClient::new(channel).info(request).await
+            })
+            .await?;
+        match resp.index {
+            Some(res) => Ok(res),
+            None => Err(Errs::index_no_exist_error()),
+        }
+    }
+}
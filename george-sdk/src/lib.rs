@@ -0,0 +1,110 @@
+/*
+ * Copyright (c) 2020. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! George DB 异步客户端
+//!
+//! 所有服务共用同一`GeorgeClient`，其内部连接池中的连接由各请求共享，克隆`GeorgeClient`不会新建连接
+
+use std::marker::PhantomData;
+use std::time::Duration;
+
+use tonic::transport::Channel;
+
+pub use george_comm::{Document, IndexDefine, IndexEngine, IndexKeyType};
+pub use george_derive::Document;
+
+mod connect;
+mod connect_test;
+mod database;
+mod disk;
+mod index;
+mod memory;
+mod page;
+mod retry;
+mod retry_test;
+mod typed;
+mod user;
+mod view;
+
+/// 统一的异步客户端
+///
+/// 幂等请求（读取、覆盖写入等）在连接类错误时按`Retry`策略重试，非幂等请求仅发送一次
+#[derive(Debug, Clone)]
+pub struct GeorgeClient {
+    /// 共享通道，连接池中的多个连接在此之上均衡请求
+    channel: Channel,
+    /// 登录令牌，服务端校验后得到操作人，据此鉴权及记录审计日志
+    token: String,
+    /// 幂等请求失败重试策略
+    retry: Retry,
+    /// 请求截止时长，包含重试在内，为空则不限制
+    deadline: Option<Duration>,
+}
+
+/// 客户端连接配置
+#[derive(Debug, Clone, Default)]
+pub struct ClientConfig {
+    /// 服务地址
+    pub remote: String,
+    /// 服务端口
+    pub port: u16,
+    /// TLS配置，为空则不使用TLS
+    pub tls: Option<ClientTLS>,
+    /// 连接池大小，默认2
+    pub pool_size: Option<usize>,
+    /// 建立连接超时时长，单位ms
+    pub connect_timeout: Option<u64>,
+    /// 请求截止时长，单位ms，包含重试在内，为空则不限制
+    pub deadline: Option<u64>,
+    /// 登录用户名，与`pass`同时设置时连接后即登录
+    pub name: Option<String>,
+    /// 登录密码
+    pub pass: Option<String>,
+    /// 幂等请求失败重试策略，为空则使用默认策略
+    pub retry: Option<Retry>,
+}
+
+/// 客户端TLS配置
+#[derive(Debug, Clone)]
+pub struct ClientTLS {
+    /// 根证书
+    pub ca_bytes: Vec<u8>,
+    /// 服务端证书中的域名
+    pub domain_name: String,
+    /// 客户端证书，服务端要求双向认证时与`key_bytes`同时设置
+    pub cert_bytes: Option<Vec<u8>>,
+    /// 客户端私钥
+    pub key_bytes: Option<Vec<u8>>,
+}
+
+/// 幂等请求失败重试策略，重试等待时长自`backoff`起逐次翻倍，不超过`backoff_max`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Retry {
+    /// 最大尝试次数，包含首次请求，为1时不重试
+    pub attempts: u32,
+    /// 首次重试前的等待时长，单位ms
+    pub backoff: u64,
+    /// 重试等待时长上限，单位ms
+    pub backoff_max: u64,
+}
+
+/// 异步类型化视图
+///
+/// 以`T`读写视图数据，写入时序列化为json存储，读取时反序列化为`T`，视图中的索引由`T`的索引定义创建
+pub struct TypedView<T: Document> {
+    client: GeorgeClient,
+    database_name: String,
+    view_name: String,
+    document: PhantomData<fn() -> T>,
+}
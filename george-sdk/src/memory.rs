@@ -0,0 +1,193 @@
+/*
+ * Copyright (c) 2020. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use george_comm::errors::GeorgeResult;
+use george_rpc::protos::db::db::memory_service_client::MemoryServiceClient;
+use george_rpc::protos::db::db::{
+    RequestMemoryBatch, RequestMemoryInto, RequestMemoryOut, RequestMemoryPInto, RequestMemoryPOut,
+    RequestMemoryPRemove, RequestMemoryRemove, ResponseBatch,
+};
+
+use crate::connect::{batch_chunks, streaming};
+use crate::GeorgeClient;
+
+impl GeorgeClient {
+    /// 插入缓存数据，已存在的键写入失败
+    ///
+    /// * page_name 缓存页名称，为空则写入默认缓存页
+    pub async fn put_memory(
+        &self,
+        page_name: String,
+        key: String,
+        value: Vec<u8>,
+    ) -> GeorgeResult<()> {
+        if page_name.is_empty() {
+            let request = RequestMemoryInto { key, value };
+            self.call(false, request, |channel, request| async move {
+                MemoryServiceClient::new(channel).put(request).await
+            })
+            .await?;
+        } else {
+            let request = RequestMemoryPInto {
+                page_name,
+                key,
+                value,
+            };
+            self.call(false, request, |channel, request| async move {
+                MemoryServiceClient::new(channel).put_by_page(request).await
+            })
+            .await?;
+        }
+        Ok(())
+    }
+
+    /// 插入缓存数据，已存在的键将被覆盖
+    ///
+    /// * page_name 缓存页名称，为空则写入默认缓存页
+    pub async fn set_memory(
+        &self,
+        page_name: String,
+        key: String,
+        value: Vec<u8>,
+    ) -> GeorgeResult<()> {
+        if page_name.is_empty() {
+            let request = RequestMemoryInto { key, value };
+            self.call(true, request, |channel, request| async move {
+                MemoryServiceClient::new(channel).set(request).await
+            })
+            .await?;
+        } else {
+            let request = RequestMemoryPInto {
+                page_name,
+                key,
+                value,
+            };
+            self.call(true, request, |channel, request| async move {
+                MemoryServiceClient::new(channel).set_by_page(request).await
+            })
+            .await?;
+        }
+        Ok(())
+    }
+
+    /// 获取缓存数据
+    ///
+    /// * page_name 缓存页名称，为空则读取默认缓存页
+    pub async fn get_memory(&self, page_name: String, key: String) -> GeorgeResult<Vec<u8>> {
+        if page_name.is_empty() {
+            let request = RequestMemoryOut { key };
+            let resp = self
+                .call(true, request, |channel, request| async move {
+                    MemoryServiceClient::new(channel).get(request).await
+                })
+                .await?;
+            Ok(resp.value)
+        } else {
+            let request = RequestMemoryPOut { page_name, key };
+            let resp = self
+                .call(true, request, |channel, request| async move {
+                    MemoryServiceClient::new(channel).get_by_page(request).await
+                })
+                .await?;
+            Ok(resp.value)
+        }
+    }
+
+    /// 删除缓存数据
+    ///
+    /// * page_name 缓存页名称，为空则删除默认缓存页中的数据
+    pub async fn remove_memory(&self, page_name: String, key: String) -> GeorgeResult<()> {
+        if page_name.is_empty() {
+            let request = RequestMemoryRemove { key };
+            self.call_remove(request, |channel, request| async move {
+                MemoryServiceClient::new(channel).remove(request).await
+            })
+            .await?;
+        } else {
+            let request = RequestMemoryPRemove { page_name, key };
+            self.call_remove(request, |channel, request| async move {
+                MemoryServiceClient::new(channel)
+                    .remove_by_page(request)
+                    .await
+            })
+            .await?;
+        }
+        Ok(())
+    }
+
+    /// 批量插入缓存数据，已存在的键写入失败
+    ///
+    /// atomic为true时任一数据项失败，服务端将回滚已写入的数据项
+    pub async fn put_memory_batch(
+        &self,
+        page_name: String,
+        atomic: bool,
+        items: Vec<(String, Vec<u8>)>,
+    ) -> GeorgeResult<ResponseBatch> {
+        let requests = memory_batch(page_name, atomic, items);
+        self.call(false, requests, |channel, request| async move {
+            MemoryServiceClient::new(channel)
+                .put_batch(streaming(request))
+                .await
+        })
+        .await
+    }
+
+    /// 批量插入缓存数据，已存在的键将被覆盖
+    pub async fn set_memory_batch(
+        &self,
+        page_name: String,
+        atomic: bool,
+        items: Vec<(String, Vec<u8>)>,
+    ) -> GeorgeResult<ResponseBatch> {
+        let requests = memory_batch(page_name, atomic, items);
+        self.call(true, requests, |channel, request| async move {
+            MemoryServiceClient::new(channel)
+                .set_batch(streaming(request))
+                .await
+        })
+        .await
+    }
+
+    /// 批量删除缓存数据，数据项中的值将被忽略
+    pub async fn remove_memory_batch(
+        &self,
+        page_name: String,
+        atomic: bool,
+        items: Vec<(String, Vec<u8>)>,
+    ) -> GeorgeResult<ResponseBatch> {
+        let requests = memory_batch(page_name, atomic, items);
+        self.call(true, requests, |channel, request| async move {
+            MemoryServiceClient::new(channel)
+                .remove_batch(streaming(request))
+                .await
+        })
+        .await
+    }
+}
+
+fn memory_batch(
+    page_name: String,
+    atomic: bool,
+    items: Vec<(String, Vec<u8>)>,
+) -> Vec<RequestMemoryBatch> {
+    batch_chunks(items)
+        .into_iter()
+        .map(|items| RequestMemoryBatch {
+            page_name: page_name.clone(),
+            atomic,
+            items,
+        })
+        .collect()
+}
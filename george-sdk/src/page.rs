@@ -0,0 +1,81 @@
+/*
+ * Copyright (c) 2020. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use george_comm::errors::{Errs, GeorgeResult};
+use george_rpc::protos::db::db::page_service_client::PageServiceClient;
+use george_rpc::protos::db::db::{Page, RequestPageCreate, RequestPageInfo, RequestPageRemove};
+use george_rpc::protos::utils::utils::Req;
+
+use crate::GeorgeClient;
+
+impl GeorgeClient {
+    /// 缓存页集合
+    pub async fn page_list(&self) -> GeorgeResult<Vec<Page>> {
+        let request = Req {};
+        let resp = self
+            .call(true, request, |channel, request| async move {
+                PageServiceClient::new(channel).list(request).await
+            })
+            .await?;
+        Ok(resp.pages)
+    }
+
+    /// 创建缓存页
+    ///
+    /// * size 可使用内存大小，单位Mb，为0则不限
+    /// * period 默认有效期，单位秒，为0则永久有效
+    pub async fn page_create(
+        &self,
+        name: String,
+        comment: String,
+        size: u64,
+        period: u32,
+    ) -> GeorgeResult<()> {
+        let request = RequestPageCreate {
+            name,
+            comment,
+            size,
+            period,
+        };
+        self.call(false, request, |channel, request| async move {
+            PageServiceClient::new(channel).create(request).await
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// 获取缓存页详情
+    pub async fn page(&self, name: String) -> GeorgeResult<Page> {
+        let request = RequestPageInfo { name };
+        let resp = self
+            .call(true, request, |channel, request| async move {
+                PageServiceClient::new(channel).info(request).await
+            })
+            .await?;
+        match resp.page {
+            Some(res) => Ok(res),
+            None => Err(Errs::page_no_exist_error()),
+        }
+    }
+
+    /// 删除缓存页
+    pub async fn page_remove(&self, name: String) -> GeorgeResult<()> {
+        let request = RequestPageRemove { name };
+        self.call(false, request, |channel, request| async move {
+            PageServiceClient::new(channel).remove(request).await
+        })
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,70 @@
+/*
+ * Copyright (c) 2020. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::time::Duration;
+
+use tonic::{Code, Status};
+
+use crate::Retry;
+
+/// 默认最大尝试次数
+const RETRY_ATTEMPTS: u32 = 3;
+/// 默认首次重试前的等待时长，单位ms
+const RETRY_BACKOFF: u64 = 100;
+/// 默认重试等待时长上限，单位ms
+const RETRY_BACKOFF_MAX: u64 = 2000;
+
+impl Default for Retry {
+    fn default() -> Self {
+        Retry {
+            attempts: RETRY_ATTEMPTS,
+            backoff: RETRY_BACKOFF,
+            backoff_max: RETRY_BACKOFF_MAX,
+        }
+    }
+}
+
+impl Retry {
+    /// 不重试
+    pub fn none() -> Retry {
+        Retry {
+            attempts: 1,
+            ..Retry::default()
+        }
+    }
+
+    /// 第`attempt`次请求失败后，重试前的等待时长
+    pub(crate) fn delay(&self, attempt: u32) -> Duration {
+        let times = attempt.max(1).min(32) - 1;
+        let millis = self
+            .backoff
+            .saturating_mul(1u64 << times)
+            .min(self.backoff_max);
+        Duration::from_millis(millis)
+    }
+
+    /// 请求失败是否可重试
+    ///
+    /// 仅连接不可用及单次请求超时可重试，其余错误可能已由服务端处理，重试无法改变结果。
+    /// 设置了截止时长时每次尝试的`grpc-timeout`即为剩余时长，超时意味着整体截止时长已耗尽，不再重试
+    ///
+    /// * deadline 是否设置了包含重试在内的截止时长
+    pub(crate) fn retryable(status: &Status, deadline: bool) -> bool {
+        match status.code() {
+            Code::Unavailable => true,
+            Code::DeadlineExceeded => !deadline,
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,75 @@
+/*
+ * Copyright (c) 2020. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[cfg(test)]
+mod retry {
+    use std::time::Duration;
+
+    use tonic::Status;
+
+    use crate::Retry;
+
+    #[test]
+    fn delay() {
+        let retry = Retry::default();
+        assert_eq!(retry.delay(1), Duration::from_millis(100));
+        assert_eq!(retry.delay(2), Duration::from_millis(200));
+        assert_eq!(retry.delay(3), Duration::from_millis(400));
+        assert_eq!(retry.delay(6), Duration::from_millis(2000));
+        assert_eq!(retry.delay(100), Duration::from_millis(2000));
+    }
+
+    #[test]
+    fn retryable() {
+        assert!(Retry::retryable(
+            &Status::unavailable("connection refused"),
+            false
+        ));
+        assert!(Retry::retryable(
+            &Status::deadline_exceeded("timeout"),
+            false
+        ));
+        assert!(!Retry::retryable(
+            &Status::unknown("transport error"),
+            false
+        ));
+        assert!(!Retry::retryable(&Status::internal("internal"), false));
+        assert!(!Retry::retryable(
+            &Status::invalid_argument("bad request"),
+            false
+        ));
+        assert!(!Retry::retryable(
+            &Status::resource_exhausted("quota"),
+            false
+        ));
+    }
+
+    #[test]
+    fn retryable_with_deadline() {
+        // 截止时长内连接不可用仍可重试，超时说明截止时长已耗尽
+        assert!(Retry::retryable(
+            &Status::unavailable("connection refused"),
+            true
+        ));
+        assert!(!Retry::retryable(
+            &Status::deadline_exceeded("timeout"),
+            true
+        ));
+    }
+
+    #[test]
+    fn none() {
+        assert_eq!(Retry::none().attempts, 1);
+    }
+}
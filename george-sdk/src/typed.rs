@@ -0,0 +1,287 @@
+/*
+ * Copyright (c) 2020. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::marker::PhantomData;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use tokio_stream::{Stream, StreamExt};
+
+use george_comm::errors::GeorgeResult;
+use george_comm::json::JsonHandler;
+use george_comm::{Document, Json};
+use george_rpc::protos::db::db::DiskDeleted;
+use george_rpc::tools::Trans;
+
+use crate::{GeorgeClient, TypedView};
+
+impl GeorgeClient {
+    /// 将`value`序列化为JSON后插入视图，已存在的键写入失败
+    pub async fn put_disk_json<T: Serialize>(
+        &self,
+        database_name: String,
+        view_name: String,
+        key: String,
+        value: &T,
+    ) -> GeorgeResult<()> {
+        let value = Json::obj_2_bytes(value)?;
+        self.put_disk(database_name, view_name, key, value).await
+    }
+
+    /// 将`value`序列化为JSON后插入视图，已存在的键将被覆盖
+    pub async fn set_disk_json<T: Serialize>(
+        &self,
+        database_name: String,
+        view_name: String,
+        key: String,
+        value: &T,
+    ) -> GeorgeResult<()> {
+        let value = Json::obj_2_bytes(value)?;
+        self.set_disk(database_name, view_name, key, value).await
+    }
+
+    /// 获取视图数据并反序列化为`T`
+    pub async fn get_disk_json<T: DeserializeOwned>(
+        &self,
+        database_name: String,
+        view_name: String,
+        key: String,
+    ) -> GeorgeResult<T> {
+        let value = self.get_disk(database_name, view_name, key).await?;
+        Json::bytes_2_obj(&value)
+    }
+
+    /// 根据指定索引获取视图数据并反序列化为`T`
+    pub async fn get_disk_by_index_json<T: DeserializeOwned>(
+        &self,
+        database_name: String,
+        view_name: String,
+        index_name: String,
+        key: String,
+    ) -> GeorgeResult<T> {
+        let value = self
+            .get_disk_by_index(database_name, view_name, index_name, key)
+            .await?;
+        Json::bytes_2_obj(&value)
+    }
+
+    /// 条件检索并将检索结果逐条反序列化为`T`
+    pub async fn select_disk_json<T: DeserializeOwned>(
+        &self,
+        database_name: String,
+        view_name: String,
+        constraint_json_bytes: Vec<u8>,
+    ) -> GeorgeResult<Vec<T>> {
+        let selected = self
+            .select_disk(database_name, view_name, constraint_json_bytes)
+            .await?;
+        selected
+            .values
+            .iter()
+            .map(|value| Json::bytes_2_obj(value))
+            .collect()
+    }
+
+    /// 将`value`序列化为JSON后插入缓存页，已存在的键写入失败
+    ///
+    /// * page_name 缓存页名称，为空则写入默认缓存页
+    pub async fn put_memory_json<T: Serialize>(
+        &self,
+        page_name: String,
+        key: String,
+        value: &T,
+    ) -> GeorgeResult<()> {
+        let value = Json::obj_2_bytes(value)?;
+        self.put_memory(page_name, key, value).await
+    }
+
+    /// 将`value`序列化为JSON后插入缓存页，已存在的键将被覆盖
+    ///
+    /// * page_name 缓存页名称，为空则写入默认缓存页
+    pub async fn set_memory_json<T: Serialize>(
+        &self,
+        page_name: String,
+        key: String,
+        value: &T,
+    ) -> GeorgeResult<()> {
+        let value = Json::obj_2_bytes(value)?;
+        self.set_memory(page_name, key, value).await
+    }
+
+    /// 获取缓存数据并反序列化为`T`
+    ///
+    /// * page_name 缓存页名称，为空则读取默认缓存页
+    pub async fn get_memory_json<T: DeserializeOwned>(
+        &self,
+        page_name: String,
+        key: String,
+    ) -> GeorgeResult<T> {
+        let value = self.get_memory(page_name, key).await?;
+        Json::bytes_2_obj(&value)
+    }
+}
+
+impl GeorgeClient {
+    /// 新建共用当前连接池的类型化视图，首次使用前需调用`init`以确保视图及索引存在
+    pub fn typed_view<T: Document>(
+        &self,
+        database_name: String,
+        view_name: String,
+    ) -> TypedView<T> {
+        TypedView {
+            client: self.clone(),
+            database_name,
+            view_name,
+            document: PhantomData,
+        }
+    }
+}
+
+impl<T: Document> TypedView<T> {
+    /// 在已存在的库中创建不存在的视图及`T`定义的索引，已存在的视图及同名索引保持不变
+    pub async fn init(&self) -> GeorgeResult<()> {
+        let views = self.client.view_list(self.database_name.clone()).await?;
+        if !views.iter().any(|view| view.name == self.view_name) {
+            self.client
+                .view_create(
+                    self.database_name.clone(),
+                    self.view_name.clone(),
+                    String::new(),
+                    false,
+                )
+                .await?;
+        }
+        let indexes = self
+            .client
+            .index_list(self.database_name.clone(), self.view_name.clone())
+            .await?;
+        for define in T::indexes() {
+            if indexes.iter().any(|index| index.name == define.name) {
+                continue;
+            }
+            self.client
+                .index_create(
+                    self.database_name.clone(),
+                    self.view_name.clone(),
+                    define.name,
+                    define.unique,
+                    define.primary,
+                    define.null,
+                    Trans::comm_2_key_type(define.key_type),
+                    Trans::comm_2_engine(define.engine),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// 插入数据，已存在的键写入失败
+    pub async fn put(&self, key: String, value: &T) -> GeorgeResult<()> {
+        self.client
+            .put_disk_json(
+                self.database_name.clone(),
+                self.view_name.clone(),
+                key,
+                value,
+            )
+            .await
+    }
+
+    /// 插入数据，已存在的键将被覆盖
+    pub async fn set(&self, key: String, value: &T) -> GeorgeResult<()> {
+        self.client
+            .set_disk_json(
+                self.database_name.clone(),
+                self.view_name.clone(),
+                key,
+                value,
+            )
+            .await
+    }
+
+    /// 获取数据
+    pub async fn get(&self, key: String) -> GeorgeResult<T> {
+        self.client
+            .get_disk_json(self.database_name.clone(), self.view_name.clone(), key)
+            .await
+    }
+
+    /// 根据指定索引获取数据
+    pub async fn get_by_index(&self, index_name: String, key: String) -> GeorgeResult<T> {
+        self.client
+            .get_disk_by_index_json(
+                self.database_name.clone(),
+                self.view_name.clone(),
+                index_name,
+                key,
+            )
+            .await
+    }
+
+    /// 删除数据
+    pub async fn remove(&self, key: String) -> GeorgeResult<()> {
+        self.client
+            .remove_disk(self.database_name.clone(), self.view_name.clone(), key)
+            .await
+    }
+
+    /// 条件检索
+    pub async fn select(&self, constraint_json_bytes: Vec<u8>) -> GeorgeResult<Vec<T>> {
+        self.client
+            .select_disk_json(
+                self.database_name.clone(),
+                self.view_name.clone(),
+                constraint_json_bytes,
+            )
+            .await
+    }
+
+    /// 条件检索，检索结果在服务端检索过程中分批返回并逐条反序列化为`T`
+    pub async fn select_stream(
+        &self,
+        constraint_json_bytes: Vec<u8>,
+    ) -> GeorgeResult<impl Stream<Item = GeorgeResult<T>>> {
+        let stream = self
+            .client
+            .select_disk_stream(
+                self.database_name.clone(),
+                self.view_name.clone(),
+                constraint_json_bytes,
+            )
+            .await?;
+        Ok(stream.map(|res| res.and_then(|value| Json::bytes_2_obj(&value))))
+    }
+
+    /// 条件删除
+    pub async fn delete(&self, constraint_json_bytes: Vec<u8>) -> GeorgeResult<DiskDeleted> {
+        self.client
+            .delete_disk(
+                self.database_name.clone(),
+                self.view_name.clone(),
+                constraint_json_bytes,
+            )
+            .await
+    }
+}
+
+impl<T: Document> Clone for TypedView<T> {
+    fn clone(&self) -> Self {
+        TypedView {
+            client: self.client.clone(),
+            database_name: self.database_name.clone(),
+            view_name: self.view_name.clone(),
+            document: PhantomData,
+        }
+    }
+}
@@ -0,0 +1,34 @@
+/*
+ * Copyright (c) 2020. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use george_comm::errors::GeorgeResult;
+use george_rpc::protos::db::db::user_service_client::UserServiceClient;
+use george_rpc::protos::db::db::RequestLogin;
+
+use crate::GeorgeClient;
+
+impl GeorgeClient {
+    /// 登录并新建共用同一连接池、携带所得登录令牌的客户端，服务端据令牌认证操作人
+    pub async fn login(&self, name: String, pass: String) -> GeorgeResult<GeorgeClient> {
+        let request = RequestLogin { name, pass };
+        let resp = self
+            .call(false, request, |channel, request| async move {
+                UserServiceClient::new(channel).login(request).await
+            })
+            .await?;
+        let mut client = self.clone();
+        client.token = resp.token;
+        Ok(client)
+    }
+}
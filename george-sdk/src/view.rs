@@ -0,0 +1,87 @@
+/*
+ * Copyright (c) 2020. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use george_comm::errors::{Errs, GeorgeResult};
+use george_rpc::protos::db::db::view_service_client::ViewServiceClient;
+use george_rpc::protos::db::db::{
+    RequestViewCreate, RequestViewInfo, RequestViewList, RequestViewRemove, View,
+};
+
+use crate::GeorgeClient;
+
+impl GeorgeClient {
+    /// 视图集合
+    pub async fn view_list(&self, database_name: String) -> GeorgeResult<Vec<View>> {
+        let request = RequestViewList { database_name };
+        let resp = self
+            .call(true, request, |channel, request| async move {
+                ViewServiceClient::new(channel).list(request).await
+            })
+            .await?;
+        Ok(resp.views)
+    }
+
+    /// 创建视图
+    ///
+    /// * with_increment 是否带自增ID
+    pub async fn view_create(
+        &self,
+        database_name: String,
+        name: String,
+        comment: String,
+        with_increment: bool,
+    ) -> GeorgeResult<()> {
+        let request = RequestViewCreate {
+            database_name,
+            name,
+            comment,
+            with_increment,
+        };
+        self.call(false, request, |channel, request| async move {
+            ViewServiceClient::new(channel).create(request).await
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// 获取视图详情
+    pub async fn view(&self, database_name: String, name: String) -> GeorgeResult<View> {
+        let request = RequestViewInfo {
+            database_name,
+            name,
+        };
+        let resp = self
+            .call(true, request, |channel, request| async move {
+                ViewServiceClient::new(channel).info(request).await
+            })
+            .await?;
+        match resp.view {
+            Some(res) => Ok(res),
+            None => Err(Errs::view_no_exist_error()),
+        }
+    }
+
+    /// 删除视图
+    pub async fn view_remove(&self, database_name: String, name: String) -> GeorgeResult<()> {
+        let request = RequestViewRemove {
+            database_name,
+            name,
+        };
+        self.call(false, request, |channel, request| async move {
+            ViewServiceClient::new(channel).remove(request).await
+        })
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,234 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use george_comm::errors::{Errs, GeorgeResult};
+use george_comm::io::file::FilerReader;
+use george_comm::io::Filer;
+use george_db::task::raft::{
+    AppendRequest, AppendResponse, InstallRequest, InstallResponse, RaftCommand, RaftOptions,
+    RaftPeer, RaftTransport, VoteRequest, VoteResponse,
+};
+use george_db::task::{Raft, Secret};
+use george_db::Task;
+use george_deploy::{ConfigServerTLS, Init};
+use george_rpc::client::db::ClusterRpcClient;
+use george_rpc::client::{RpcClient, TLSType};
+
+use crate::cmd::{Cluster, Peers};
+
+impl Cluster {
+    /// 配置了集群节点编号时以集群模式运行
+    pub(crate) fn new(init: &Init) -> Option<Cluster> {
+        Some(Cluster {
+            id: init.cluster_id()?,
+            peers: init.cluster_peers(),
+            join: init.cluster_join(),
+            election_timeout: init.cluster_election_timeout(),
+            heartbeat_interval: init.cluster_heartbeat_interval(),
+            snapshot_entries: init.cluster_snapshot_entries(),
+            tls: init.cluster_tls(),
+            peer_key: init.peer_key(),
+        })
+    }
+
+    /// 数据恢复前调用，存在接收完毕的领导者快照时以其重建数据存储路径
+    pub(crate) fn bootstrap(&self, init: &Init) -> GeorgeResult<()> {
+        let data_dir = match init.data_dir() {
+            Some(res) => res,
+            None => return Err(Errs::str("data dir must be assign!")),
+        };
+        if Raft::restore(format!("{}/raft", data_dir), data_dir)? {
+            log::info!("cluster node {} restored snapshot from leader", self.id);
+        }
+        Ok(())
+    }
+
+    /// 启动共识模块
+    ///
+    /// 以新成员身份加入时不使用初始集群成员，等待领导者将其加入集群后同步全部成员
+    pub(crate) fn start(self, task: Arc<Task>) -> GeorgeResult<()> {
+        let peers = if self.join {
+            vec![]
+        } else {
+            if !self.peers.iter().any(|peer| peer.id == self.id) {
+                return Err(Errs::string(format!(
+                    "cluster peers must contain self id {}!",
+                    self.id
+                )));
+            }
+            self.peers
+                .iter()
+                .map(|peer| RaftPeer {
+                    id: peer.id,
+                    addr: peer.addr.clone(),
+                })
+                .collect()
+        };
+        let peer_key = match &self.peer_key {
+            Some(res) => res.clone(),
+            None => return Err(Errs::str("cluster requires server peer_key!")),
+        };
+        let transport = Arc::new(Peers {
+            tls: self.tls,
+            peer: Arc::new(Secret::peer(peer_key)?),
+            clients: Mutex::new(HashMap::new()),
+        });
+        let raft = task.cluster_start(
+            self.id,
+            peers,
+            RaftOptions {
+                election_timeout: Duration::from_millis(self.election_timeout),
+                heartbeat_interval: Duration::from_millis(self.heartbeat_interval),
+                snapshot_entries: self.snapshot_entries,
+            },
+            transport,
+        )?;
+        log::info!(
+            "cluster node {} started, status: {:?}",
+            self.id,
+            raft.status()
+        );
+        Ok(())
+    }
+}
+
+impl Peers {
+    /// 在独立线程中向指定节点发起请求
+    ///
+    /// 客户端内部以自身运行时阻塞等待请求结果，而转发写入的请求可能发起于服务的异步运行时中，
+    /// 因此每次请求均在独立线程中完成
+    fn call<T, F>(&self, peer: &RaftPeer, f: F) -> GeorgeResult<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&mut ClusterRpcClient) -> GeorgeResult<T> + Send + 'static,
+    {
+        let cached = self.clients.lock().unwrap().get(&peer.id).cloned();
+        let addr = peer.addr.clone();
+        let tls = self.tls.clone();
+        let secret = self.peer.clone();
+        let handle = match thread::Builder::new()
+            .name("cluster-rpc".to_string())
+            .spawn(move || {
+                let client = match cached {
+                    Some(res) => res,
+                    None => {
+                        let mut client = connect(&addr, &tls)?;
+                        client.set_peer(secret);
+                        Arc::new(Mutex::new(client))
+                    }
+                };
+                let result = f(&mut client.lock().unwrap());
+                result.map(|res| (client, res))
+            }) {
+            Ok(res) => res,
+            Err(err) => return Err(Errs::strs("cluster rpc thread spawn", err)),
+        };
+        let result = match handle.join() {
+            Ok(res) => res,
+            Err(_) => Err(Errs::str("cluster rpc thread panicked!")),
+        };
+        let mut clients = self.clients.lock().unwrap();
+        match result {
+            Ok((client, res)) => {
+                clients.insert(peer.id, client);
+                Ok(res)
+            }
+            Err(err) => {
+                clients.remove(&peer.id);
+                Err(err)
+            }
+        }
+    }
+}
+
+impl RaftTransport for Peers {
+    fn vote(&self, peer: &RaftPeer, request: VoteRequest) -> GeorgeResult<VoteResponse> {
+        self.call(peer, move |client| client.vote(request))
+    }
+
+    fn append(&self, peer: &RaftPeer, request: AppendRequest) -> GeorgeResult<AppendResponse> {
+        self.call(peer, move |client| client.append(request))
+    }
+
+    fn forward(&self, peer: &RaftPeer, command: RaftCommand) -> GeorgeResult<(u64, u64)> {
+        self.call(peer, move |client| client.forward(&command))
+    }
+
+    fn install(&self, peer: &RaftPeer, request: InstallRequest) -> GeorgeResult<InstallResponse> {
+        self.call(peer, move |client| client.install(request))
+    }
+}
+
+/// 连接指定节点
+fn connect(addr: &str, tls: &Option<ConfigServerTLS>) -> GeorgeResult<ClusterRpcClient> {
+    let (remote, port) = match addr.rsplit_once(':') {
+        Some((remote, port)) => match port.parse::<u16>() {
+            Ok(port) => (remote, port),
+            Err(err) => return Err(Errs::strs("cluster peer port parse", err)),
+        },
+        None => {
+            return Err(Errs::string(format!(
+                "cluster peer {} must be host:port!",
+                addr
+            )))
+        }
+    };
+    match tls {
+        Some(tls) => {
+            let tls_type = if tls.rust_tls.unwrap_or(false) {
+                TLSType::Rustls
+            } else {
+                TLSType::Openssl
+            };
+            let ca_bytes = Filer::read_bytes(tls_require(&tls.ca, "ca")?)?;
+            let domain_name = tls.domain.clone().unwrap_or_default();
+            match (&tls.key, &tls.cert) {
+                (Some(key), Some(cert)) => ClusterRpcClient::new_tls_bytes_check(
+                    tls_type,
+                    remote,
+                    port,
+                    Filer::read_bytes(key)?,
+                    Filer::read_bytes(cert)?,
+                    ca_bytes,
+                    domain_name,
+                    None,
+                ),
+                _ => ClusterRpcClient::new_tls_bytes(
+                    tls_type,
+                    remote,
+                    port,
+                    ca_bytes,
+                    domain_name,
+                    None,
+                ),
+            }
+        }
+        None => ClusterRpcClient::new(remote, port, None),
+    }
+}
+
+fn tls_require(filepath: &Option<String>, name: &str) -> GeorgeResult<String> {
+    match filepath {
+        Some(res) => Ok(res.clone()),
+        None => Err(Errs::string(format!(
+            "cluster tls {} must be assign!",
+            name
+        ))),
+    }
+}
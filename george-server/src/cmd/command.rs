@@ -12,7 +12,7 @@
  * limitations under the License.
  */
 
-use crate::cmd::{Command, Restart, Start, Status, Stop};
+use crate::cmd::{Command, Restart, Restore, Start, Status, Stop};
 use clap::{App, ArgMatches};
 use george_deploy::Builder;
 
@@ -28,6 +28,7 @@ impl Command {
                 .subcommand(Restart::subcommand())
                 .subcommand(Stop::subcommand())
                 .subcommand(Status::subcommand())
+                .subcommand(Restore::subcommand())
                 .get_matches(),
         )
     }
@@ -39,8 +40,10 @@ fn match_value(matches: ArgMatches) {
     } else if matches.is_present("restart") {
         Restart::matches(matches.subcommand_matches("restart").unwrap())
     } else if matches.is_present("stop") {
-        Stop::matches()
+        Stop::matches(matches.subcommand_matches("stop").unwrap())
     } else if matches.is_present("status") {
-        Status::matches()
+        Status::matches(matches.subcommand_matches("status").unwrap())
+    } else if matches.is_present("restore") {
+        Restore::matches(matches.subcommand_matches("restore").unwrap())
     }
 }
@@ -0,0 +1,368 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::process::Child;
+use std::time::{Duration, Instant};
+use std::{fs, process, thread};
+
+use george_comm::errors::{Errs, GeorgeResult};
+use george_comm::io::file::{FilerHandler, FilerReader};
+use george_comm::io::Filer;
+use george_comm::Time;
+use george_deploy::Init;
+
+use crate::cmd::Daemon;
+
+/// PID文件名
+const PID_FILENAME: &str = "george-server.pid";
+/// PID锁文件名后缀，服务进程存活期间持有该文件的独占锁
+const PID_LOCK_SUFFIX: &str = ".lock";
+/// 等待服务进程退出时的检测间隔，单位ms
+const PID_CHECK_INTERVAL: u64 = 200;
+
+impl Daemon {
+    /// PID文件路径，位于数据目录下，未配置数据目录则位于当前目录
+    pub(crate) fn filepath(init: &Init) -> String {
+        match init.data_dir() {
+            Some(res) => format!("{}/{}", res, PID_FILENAME),
+            None => PID_FILENAME.to_string(),
+        }
+    }
+
+    /// 写入当前进程信息
+    ///
+    /// 先取得PID锁文件的独占锁，已有存活的服务进程持有该锁则返回错误，进程异常退出残留的PID文件将被覆盖。
+    /// PID文件先写入临时文件再重命名，其它进程读取时不会读到写入一半的内容
+    ///
+    /// ###Params
+    ///
+    /// * init 服务配置
+    /// * addr 服务监听地址，多个监听以`, `分隔
+    pub(crate) fn create(init: &Init, addr: String) -> GeorgeResult<Daemon> {
+        let filepath = Daemon::filepath(init);
+        let lock = match lock(format!("{}{}", filepath, PID_LOCK_SUFFIX)) {
+            Ok(res) => res,
+            Err(err) => {
+                return Err(match Daemon::load(filepath) {
+                    Ok(Some(daemon)) => Errs::string(format!(
+                        "george-server already started with pid {}, try to use restart instead!",
+                        daemon.pid
+                    )),
+                    _ => err,
+                })
+            }
+        };
+        if let Some(daemon) = Daemon::load(filepath.clone())? {
+            log::warn!("remove stale pid file {} of pid {}", filepath, daemon.pid);
+        }
+        let daemon = Daemon {
+            filepath: filepath.clone(),
+            pid: process::id(),
+            start_time: Time::now().secs(),
+            addr,
+            data_dir: init.data_dir().unwrap_or_default(),
+            lock: Some(lock),
+        };
+        daemon.save()?;
+        log::info!(
+            "george-server pid {} written to {}",
+            daemon.pid,
+            daemon.filepath
+        );
+        Ok(daemon)
+    }
+
+    /// 读取PID文件中的服务进程信息，PID文件不存在则返回None
+    pub(crate) fn load(filepath: String) -> GeorgeResult<Option<Daemon>> {
+        if !Filer::exist(filepath.clone()) {
+            return Ok(None);
+        }
+        let content = Filer::read(filepath.clone())?;
+        let mut pid = None;
+        let mut start_time = 0;
+        let mut addr = String::from("");
+        let mut data_dir = String::from("");
+        for line in content.lines() {
+            let mut split = line.splitn(2, ':');
+            let key = split.next().unwrap_or("").trim();
+            let value = split.next().unwrap_or("").trim();
+            match key {
+                "pid" => match value.parse::<u32>() {
+                    Ok(res) => pid = Some(res),
+                    Err(err) => return Err(Errs::strs("pid file parse pid", err)),
+                },
+                "start_time" => match value.parse::<i64>() {
+                    Ok(res) => start_time = res,
+                    Err(err) => return Err(Errs::strs("pid file parse start time", err)),
+                },
+                "addr" => addr = value.to_string(),
+                "data_dir" => data_dir = value.to_string(),
+                _ => {}
+            }
+        }
+        match pid {
+            Some(pid) => Ok(Some(Daemon {
+                filepath,
+                pid,
+                start_time,
+                addr,
+                data_dir,
+                lock: None,
+            })),
+            None => Err(Errs::string(format!("pid file {} has no pid!", filepath))),
+        }
+    }
+
+    /// 服务进程号
+    pub(crate) fn pid(&self) -> u32 {
+        self.pid
+    }
+
+    /// 监听地址
+    pub(crate) fn addr(&self) -> String {
+        self.addr.clone()
+    }
+
+    /// 数据目录
+    pub(crate) fn data_dir(&self) -> String {
+        self.data_dir.clone()
+    }
+
+    /// 运行时长，单位秒
+    pub(crate) fn uptime(&self) -> i64 {
+        Time::now().secs() - self.start_time
+    }
+
+    /// 服务进程是否存活
+    ///
+    /// 仅在PID锁文件的锁被占用时才按进程号检测，避免PID文件残留且进程号被复用时将无关进程视为服务进程
+    pub(crate) fn alive(&self) -> bool {
+        locked(format!("{}{}", self.filepath, PID_LOCK_SUFFIX)) && alive(self.pid)
+    }
+
+    /// 向服务进程发送`SIGTERM`信号以优雅退出，并等待进程退出
+    ///
+    /// ###Params
+    ///
+    /// * timeout 等待进程退出的最长时间，超时返回错误
+    pub(crate) fn stop(&self, timeout: Duration) -> GeorgeResult<()> {
+        // 发送信号前再次确认PID锁仍被持有，锁已释放说明服务进程已退出，该进程号可能已属于其它进程
+        if !self.alive() {
+            return Err(Errs::string(format!(
+                "pid lock of george-server with pid {} is not held, refuse to signal it!",
+                self.pid
+            )));
+        }
+        terminate(self.pid)?;
+        let start = Instant::now();
+        while self.alive() {
+            if start.elapsed() > timeout {
+                return Err(Errs::string(format!(
+                    "george-server with pid {} still running after {} secs!",
+                    self.pid,
+                    timeout.as_secs()
+                )));
+            }
+            thread::sleep(Duration::from_millis(PID_CHECK_INTERVAL));
+        }
+        // 进程被强制结束时不会移除PID文件
+        if Filer::exist(self.filepath.clone()) {
+            Filer::rm(self.filepath.clone())?;
+        }
+        Ok(())
+    }
+
+    /// 在`timeout`内等待后台启动的服务进程写入PID文件，用于确认启动结果
+    ///
+    /// 服务进程提前退出或超时未写入则返回错误
+    pub(crate) fn wait(
+        filepath: String,
+        child: &mut Child,
+        timeout: Duration,
+    ) -> GeorgeResult<Daemon> {
+        let start = Instant::now();
+        loop {
+            if let Ok(Some(daemon)) = Daemon::load(filepath.clone()) {
+                if daemon.pid == child.id() {
+                    return Ok(daemon);
+                }
+            }
+            match child.try_wait() {
+                Ok(Some(status)) => {
+                    return Err(Errs::string(format!(
+                        "george-server with pid {} exited with {}, see log for detail!",
+                        child.id(),
+                        status
+                    )))
+                }
+                Ok(None) => {}
+                Err(err) => return Err(Errs::strs("wait george-server", err)),
+            }
+            if start.elapsed() > timeout {
+                return Err(Errs::string(format!(
+                    "george-server with pid {} not started after {} secs!",
+                    child.id(),
+                    timeout.as_secs()
+                )));
+            }
+            thread::sleep(Duration::from_millis(PID_CHECK_INTERVAL));
+        }
+    }
+
+    /// 将进程信息写入临时文件并同步到磁盘后重命名为PID文件
+    fn save(&self) -> GeorgeResult<()> {
+        let tmp_filepath = format!("{}.tmp", self.filepath);
+        let result = OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(&tmp_filepath)
+            .and_then(|mut file| {
+                file.write_all(self.content().as_bytes())?;
+                file.sync_all()
+            })
+            .and_then(|()| fs::rename(&tmp_filepath, &self.filepath));
+        match result {
+            Ok(()) => Ok(()),
+            Err(err) => Err(Errs::strs("pid file write", err)),
+        }
+    }
+
+    fn content(&self) -> String {
+        format!(
+            "pid: {}\nstart_time: {}\naddr: {}\ndata_dir: {}\n",
+            self.pid, self.start_time, self.addr, self.data_dir
+        )
+    }
+}
+
+impl Drop for Daemon {
+    /// 仅由持有PID锁的服务进程自身在退出时移除PID文件，锁文件保留，锁随文件关闭释放
+    fn drop(&mut self) {
+        if self.lock.is_none() {
+            return;
+        }
+        match Filer::rm(self.filepath.clone()) {
+            Ok(()) => log::info!("george-server pid file {} removed", self.filepath),
+            Err(err) => log::error!("remove pid file failed! error is {}", err),
+        }
+    }
+}
+
+/// 将运行时长格式化为`1d 2h 3m 4s`
+pub(crate) fn uptime_string(secs: i64) -> String {
+    let (days, secs) = (secs / 86400, secs % 86400);
+    let (hours, secs) = (secs / 3600, secs % 3600);
+    let (minutes, secs) = (secs / 60, secs % 60);
+    if days > 0 {
+        format!("{}d {}h {}m {}s", days, hours, minutes, secs)
+    } else if hours > 0 {
+        format!("{}h {}m {}s", hours, minutes, secs)
+    } else if minutes > 0 {
+        format!("{}m {}s", minutes, secs)
+    } else {
+        format!("{}s", secs)
+    }
+}
+
+/// 打开并以非阻塞方式独占锁定PID锁文件，已被其它进程锁定则返回错误
+#[cfg(unix)]
+fn lock(filepath: String) -> GeorgeResult<File> {
+    use nix::fcntl::{flock, FlockArg};
+    use std::os::unix::io::AsRawFd;
+
+    let file = match OpenOptions::new().write(true).create(true).open(&filepath) {
+        Ok(res) => res,
+        Err(err) => return Err(Errs::strs("pid lock file open", err)),
+    };
+    match flock(file.as_raw_fd(), FlockArg::LockExclusiveNonblock) {
+        Ok(()) => Ok(file),
+        Err(err) => Err(Errs::strings(
+            format!("pid lock file {} lock", filepath),
+            err,
+        )),
+    }
+}
+
+#[cfg(not(unix))]
+fn lock(filepath: String) -> GeorgeResult<File> {
+    match OpenOptions::new().write(true).create(true).open(&filepath) {
+        Ok(res) => Ok(res),
+        Err(err) => Err(Errs::strs("pid lock file open", err)),
+    }
+}
+
+/// 检测PID锁文件的锁是否被其它进程持有，锁文件不存在或可取得锁均视为未持有
+///
+/// 试探取得的共享锁随文件关闭立即释放
+#[cfg(unix)]
+fn locked(filepath: String) -> bool {
+    use nix::errno::Errno;
+    use nix::fcntl::{flock, FlockArg};
+    use std::os::unix::io::AsRawFd;
+
+    let file = match File::open(&filepath) {
+        Ok(res) => res,
+        Err(_) => return false,
+    };
+    match flock(file.as_raw_fd(), FlockArg::LockSharedNonblock) {
+        Ok(()) => false,
+        Err(err) => err == Errno::EWOULDBLOCK,
+    }
+}
+
+#[cfg(not(unix))]
+fn locked(_filepath: String) -> bool {
+    false
+}
+
+/// 以空信号检测进程是否存在，进程存在但无权发送信号时同样视为存活
+#[cfg(unix)]
+fn alive(pid: u32) -> bool {
+    use nix::errno::Errno;
+    use nix::sys::signal::kill;
+    use nix::unistd::Pid;
+
+    match kill(Pid::from_raw(pid as i32), None) {
+        Ok(()) => true,
+        Err(err) => err == Errno::EPERM,
+    }
+}
+
+#[cfg(not(unix))]
+fn alive(_pid: u32) -> bool {
+    false
+}
+
+/// 向进程发送`SIGTERM`信号
+#[cfg(unix)]
+fn terminate(pid: u32) -> GeorgeResult<()> {
+    use nix::sys::signal::{kill, Signal};
+    use nix::unistd::Pid;
+
+    match kill(Pid::from_raw(pid as i32), Signal::SIGTERM) {
+        Ok(()) => Ok(()),
+        Err(err) => Err(Errs::strings(format!("kill -TERM {}", pid), err)),
+    }
+}
+
+#[cfg(not(unix))]
+fn terminate(pid: u32) -> GeorgeResult<()> {
+    Err(Errs::string(format!(
+        "stop george-server with pid {} is only supported on unix!",
+        pid
+    )))
+}
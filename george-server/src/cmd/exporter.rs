@@ -0,0 +1,100 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::convert::Infallible;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+
+use hyper::header::{HeaderValue, CONTENT_TYPE};
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Request, Response, Server, StatusCode};
+
+use george_comm::errors::{Errs, GeorgeResult};
+use george_db::Task;
+use george_deploy::Init;
+
+use crate::cmd::Exporter;
+
+/// `Prometheus`文本格式内容类型
+const CONTENT_TYPE_TEXT: &str = "text/plain; version=0.0.4";
+
+impl Exporter {
+    /// 配置运行指标端口后，在后台启动运行指标导出服务
+    ///
+    /// ###Params
+    ///
+    /// * init 服务配置
+    /// * task 任务对象
+    /// * ip 监听地址，与服务监听地址一致
+    pub(crate) fn spawn(init: &Init, task: Arc<Task>, ip: IpAddr) -> GeorgeResult<()> {
+        let port = match init.metrics_port() {
+            Some(res) => res,
+            None => return Ok(()),
+        };
+        let addr = SocketAddr::new(ip, port);
+        let path = init.metrics_path();
+        let make_service = make_service_fn(move |_| {
+            let task = task.clone();
+            let path = path.clone();
+            async move {
+                Ok::<_, Infallible>(service_fn(move |request| {
+                    Exporter::handle(task.clone(), path.clone(), request)
+                }))
+            }
+        });
+        let server = match Server::try_bind(&addr) {
+            Ok(res) => res.serve(make_service),
+            Err(err) => return Err(Errs::strs("metrics listener bind", err)),
+        };
+        log::info!("metrics listener: {}", addr);
+        tokio::spawn(async move {
+            if let Err(err) = server.await {
+                log::error!("metrics listener failed! error is {}", err);
+            }
+        });
+        Ok(())
+    }
+
+    async fn handle(
+        task: Arc<Task>,
+        path: String,
+        request: Request<Body>,
+    ) -> Result<Response<Body>, Infallible> {
+        if request.uri().path().ne(path.as_str()) {
+            return Ok(Exporter::response(StatusCode::NOT_FOUND, "".to_string()));
+        }
+        match tokio::task::spawn_blocking(move || task.metrics()).await {
+            Ok(Ok(res)) => Ok(Exporter::response(StatusCode::OK, res)),
+            Ok(Err(err)) => Ok(Exporter::response(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                err.to_string(),
+            )),
+            Err(err) => Ok(Exporter::response(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                err.to_string(),
+            )),
+        }
+    }
+
+    fn response(status: StatusCode, body: String) -> Response<Body> {
+        let mut response = Response::new(Body::from(body));
+        *response.status_mut() = status;
+        if status == StatusCode::OK {
+            response
+                .headers_mut()
+                .insert(CONTENT_TYPE, HeaderValue::from_static(CONTENT_TYPE_TEXT));
+        }
+        response
+    }
+}
@@ -0,0 +1,168 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::sync::Arc;
+
+use once_cell::sync::OnceCell;
+use tonic::codegen::{empty_body, http, BoxFuture, Context, Never, Poll, Service};
+use tonic::transport::NamedService;
+use tonic_health::proto::health_server::{Health as THealth, HealthServer};
+use tonic_health::server::health_reporter;
+use tonic_health::ServingStatus;
+use tonic_reflection::server::{ServerReflection, ServerReflectionServer};
+
+use george_comm::errors::{Errs, GeorgeResult};
+use george_db::Task;
+use george_rpc::protos::db::db::audit_service_server::AuditServiceServer;
+use george_rpc::protos::db::db::backup_service_server::BackupServiceServer;
+use george_rpc::protos::db::db::cluster_service_server::ClusterServiceServer;
+use george_rpc::protos::db::db::database_service_server::DatabaseServiceServer;
+use george_rpc::protos::db::db::disk_service_server::DiskServiceServer;
+use george_rpc::protos::db::db::index_service_server::IndexServiceServer;
+use george_rpc::protos::db::db::ledger_service_server::LedgerServiceServer;
+use george_rpc::protos::db::db::memory_service_server::MemoryServiceServer;
+use george_rpc::protos::db::db::page_service_server::PageServiceServer;
+use george_rpc::protos::db::db::quota_service_server::QuotaServiceServer;
+use george_rpc::protos::db::db::replication_service_server::ReplicationServiceServer;
+use george_rpc::protos::db::db::transfer_service_server::TransferServiceServer;
+use george_rpc::protos::db::db::user_service_server::UserServiceServer;
+use george_rpc::protos::db::db::view_service_server::ViewServiceServer;
+use george_rpc::protos::db::FILE_DESCRIPTOR_SET;
+use george_rpc::server::db::{
+    AuditServer, BackupServer, ClusterServer, DatabaseServer, DiskServer, IndexServer,
+    LedgerServer, MemoryServer, PageServer, QuotaServer, ReplicationServer, TransferServer,
+    UserServer, ViewServer,
+};
+
+use crate::cmd::{Health, Pending};
+
+/// 接受健康检查的服务名集合，空名称表示服务整体状态
+const SERVICE_NAMES: [&str; 15] = [
+    "",
+    <AuditServiceServer<AuditServer> as NamedService>::NAME,
+    <BackupServiceServer<BackupServer> as NamedService>::NAME,
+    <ClusterServiceServer<ClusterServer> as NamedService>::NAME,
+    <DatabaseServiceServer<DatabaseServer> as NamedService>::NAME,
+    <DiskServiceServer<DiskServer> as NamedService>::NAME,
+    <IndexServiceServer<IndexServer> as NamedService>::NAME,
+    <LedgerServiceServer<LedgerServer> as NamedService>::NAME,
+    <MemoryServiceServer<MemoryServer> as NamedService>::NAME,
+    <PageServiceServer<PageServer> as NamedService>::NAME,
+    <QuotaServiceServer<QuotaServer> as NamedService>::NAME,
+    <ReplicationServiceServer<ReplicationServer> as NamedService>::NAME,
+    <TransferServiceServer<TransferServer> as NamedService>::NAME,
+    <UserServiceServer<UserServer> as NamedService>::NAME,
+    <ViewServiceServer<ViewServer> as NamedService>::NAME,
+];
+
+impl Health {
+    /// 新建健康检查状态及`grpc.health.v1.Health`服务，所有服务初始状态为`NOT_SERVING`
+    pub(crate) async fn new() -> (Health, HealthServer<impl THealth>) {
+        let (reporter, service) = health_reporter();
+        let health = Health { reporter };
+        health.set(ServingStatus::NotServing).await;
+        (health, service)
+    }
+
+    /// 数据恢复及初始化完成，开始对外提供服务
+    pub(crate) async fn serving(&self) {
+        self.set(ServingStatus::Serving).await;
+        log::info!("health status serving!");
+    }
+
+    /// 即将停止服务，不再接收新请求
+    pub(crate) async fn not_serving(&self) {
+        self.set(ServingStatus::NotServing).await;
+        log::info!("health status not serving!");
+    }
+
+    /// `protos/db`下所有服务的反射服务，供`grpcurl`等工具发现接口
+    pub(crate) fn reflection() -> GeorgeResult<ServerReflectionServer<impl ServerReflection>> {
+        match tonic_reflection::server::Builder::configure()
+            .register_encoded_file_descriptor_set(FILE_DESCRIPTOR_SET)
+            .build()
+        {
+            Ok(res) => Ok(res),
+            Err(err) => Err(Errs::strs("reflection service build", err)),
+        }
+    }
+
+    async fn set(&self, status: ServingStatus) {
+        let mut reporter = self.reporter.clone();
+        for name in SERVICE_NAMES.iter() {
+            reporter.set_service_status(*name, status).await;
+        }
+    }
+}
+
+impl<S> Pending<S> {
+    /// 新建恢复完成前挂起的服务
+    ///
+    /// ###Params
+    ///
+    /// * task 恢复完成后写入的任务对象
+    /// * build 以任务对象构建服务
+    pub(crate) fn new<F>(task: Arc<OnceCell<Arc<Task>>>, build: F) -> Pending<S>
+    where
+        F: Fn(Arc<Task>) -> S + Send + Sync + 'static,
+    {
+        Pending {
+            task,
+            build: Arc::new(build),
+        }
+    }
+}
+
+impl<S> Clone for Pending<S> {
+    fn clone(&self) -> Self {
+        Pending {
+            task: self.task.clone(),
+            build: self.build.clone(),
+        }
+    }
+}
+
+impl<S: NamedService> NamedService for Pending<S> {
+    const NAME: &'static str = S::NAME;
+}
+
+impl<S, B> Service<http::Request<B>> for Pending<S>
+where
+    S: Service<http::Request<B>, Response = http::Response<tonic::body::BoxBody>, Error = Never>,
+    S::Future: Send + 'static,
+{
+    type Response = http::Response<tonic::body::BoxBody>;
+    type Error = Never;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: http::Request<B>) -> Self::Future {
+        match self.task.get() {
+            // 生成的服务始终就绪，无需等待`poll_ready`
+            Some(task) => Box::pin((self.build)(task.clone()).call(req)),
+            None => Box::pin(async move {
+                Ok(http::Response::builder()
+                    .status(200)
+                    .header("grpc-status", "14")
+                    .header("grpc-message", "george-server is recovering")
+                    .header("content-type", "application/grpc")
+                    .body(empty_body())
+                    .unwrap())
+            }),
+        }
+    }
+}
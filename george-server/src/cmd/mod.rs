@@ -11,16 +11,161 @@
  * See the License for the specific language governing permissions and
  * limitations under the License.
  */
+use std::collections::HashMap;
+use std::fs::File;
+use std::sync::{Arc, Mutex, RwLock};
+
+use once_cell::sync::OnceCell;
+#[cfg(unix)]
+use tokio::net::UnixStream;
+use tokio_rustls::TlsAcceptor;
+use tonic_health::server::HealthReporter;
+
+use george_comm::cryptos::ca::CRL;
+use george_comm::errors::GeorgeResult;
+use george_db::task::Secret;
+use george_db::Task;
+use george_deploy::{ConfigServerPeer, ConfigServerTLS};
+use george_rpc::client::db::ClusterRpcClient;
+
+mod cluster;
 mod command;
+mod daemon;
+mod exporter;
+mod health;
+mod reload;
+mod replicator;
 mod restart;
+mod restore;
 mod service;
+mod service_test;
 mod start;
 mod status;
 mod stop;
+#[cfg(unix)]
+mod uds;
 
 pub struct Command;
 struct Start;
 struct Restart;
+struct Restore;
 struct Stop;
 struct Status;
 pub struct Service;
+
+/// 服务进程信息
+///
+/// 服务启动时写入PID文件，服务进程退出时移除，供`stop`、`status`及`restart`定位服务进程
+struct Daemon {
+    /// PID文件路径
+    filepath: String,
+    /// 服务进程号
+    pid: u32,
+    /// 启动时间，单位秒
+    start_time: i64,
+    /// 监听地址
+    addr: String,
+    /// 数据目录
+    data_dir: String,
+    /// 服务进程持有的PID锁文件，进程退出时由系统释放，读取的进程信息为None
+    lock: Option<File>,
+}
+
+/// 主从复制从节点
+///
+/// 数据存储路径为空时先从主节点拉取快照恢复，随后持续应用主节点推送的变更，断开后自动重连
+struct Replicator {
+    /// 主节点地址，格式为`host:port`
+    primary: String,
+    /// 连接主节点的`TLS`配置
+    tls: Option<ConfigServerTLS>,
+    /// 节点间认证密钥文件路径，主节点据此认证从节点
+    peer_key: Option<String>,
+}
+
+/// 集群节点
+///
+/// 配置集群节点编号后以集群模式运行，所有写入经多数节点确认后方才生效
+struct Cluster {
+    /// 当前节点编号
+    id: u64,
+    /// 初始集群成员，包括当前节点
+    peers: Vec<ConfigServerPeer>,
+    /// 是否以新成员身份加入已有集群，加入时由领导者同步集群成员
+    join: bool,
+    /// 选举超时时间，单位ms
+    election_timeout: u64,
+    /// 心跳间隔，单位ms
+    heartbeat_interval: u64,
+    /// 快照间隔，自上次快照起已应用的共识日志条目数
+    snapshot_entries: u64,
+    /// 连接其它节点的`TLS`配置
+    tls: Option<ConfigServerTLS>,
+    /// 节点间认证密钥文件路径，各节点据此相互认证
+    peer_key: Option<String>,
+}
+
+/// 基于`rpc`的节点间通信
+///
+/// 按节点编号缓存连接，请求失败后丢弃对应连接，下次请求时重新建立
+struct Peers {
+    /// 连接其它节点的`TLS`配置
+    tls: Option<ConfigServerTLS>,
+    /// 节点间认证密钥
+    peer: Arc<Secret>,
+    /// 已建立的节点连接
+    clients: Mutex<HashMap<u64, Arc<Mutex<ClusterRpcClient>>>>,
+}
+
+/// 运行指标导出服务
+///
+/// 配置运行指标端口后启动，以`HTTP`方式导出`Prometheus`文本格式的运行指标
+struct Exporter;
+
+/// 健康检查状态
+///
+/// 数据恢复及初始化完成前为`NOT_SERVING`，完成后为`SERVING`，收到停止信号后重新置为`NOT_SERVING`
+#[derive(Clone)]
+struct Health {
+    reporter: HealthReporter,
+}
+
+/// 恢复完成前挂起的服务
+///
+/// 数据恢复完成前直接返回`UNAVAILABLE`，完成后以恢复的[`Task`]构建服务处理请求，使健康检查在恢复期间即可访问
+struct Pending<S> {
+    /// 恢复完成后的任务对象
+    task: Arc<OnceCell<Arc<Task>>>,
+    /// 以任务对象构建服务
+    build: Arc<dyn Fn(Arc<Task>) -> S + Send + Sync>,
+}
+
+/// 可热加载的`TLS`接收器
+///
+/// 证书文件变更或收到`SIGHUP`信号后重新构建接收器，仅对新建连接生效，已建立的连接不受影响
+struct Reloader<A> {
+    /// 监听的`TLS`配置
+    tls: ConfigServerTLS,
+    /// 当前使用的接收器
+    acceptor: Arc<RwLock<A>>,
+    /// 根据配置构建接收器
+    build: fn(&ConfigServerTLS) -> GeorgeResult<A>,
+}
+
+/// `rustls`接收器
+///
+/// `rustls`无法在握手时校验吊销列表，因此随接收器一并持有客户端证书吊销列表，在握手完成后校验
+#[derive(Clone)]
+struct RustlsAcceptor {
+    acceptor: TlsAcceptor,
+    /// 客户端证书吊销列表
+    crl: Option<Arc<CRL>>,
+    /// 是否接受已超过下一次更新时间的吊销列表
+    crl_allow_stale: bool,
+}
+
+/// `Unix domain socket`连接
+///
+/// 为`tonic`提供所需的`Connected`实现，本机连接不携带额外连接信息
+#[cfg(unix)]
+struct UdsStream(UnixStream);
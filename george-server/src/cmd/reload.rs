@@ -0,0 +1,114 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::fs;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, SystemTime};
+
+use george_comm::errors::GeorgeResult;
+use george_deploy::ConfigServerTLS;
+
+use crate::cmd::Reloader;
+
+impl<A: Clone + Send + Sync + 'static> Reloader<A> {
+    /// 根据配置构建接收器，并以此新建可热加载的接收器
+    pub(crate) fn new(
+        tls: ConfigServerTLS,
+        build: fn(&ConfigServerTLS) -> GeorgeResult<A>,
+    ) -> GeorgeResult<Arc<Self>> {
+        let acceptor = build(&tls)?;
+        Ok(Arc::new(Reloader {
+            tls,
+            acceptor: Arc::new(RwLock::new(acceptor)),
+            build,
+        }))
+    }
+
+    /// 当前使用的接收器
+    pub(crate) fn acceptor(&self) -> A {
+        self.acceptor.read().unwrap().clone()
+    }
+
+    /// 重新加载证书并替换接收器，加载失败时继续使用原接收器
+    pub(crate) fn reload(&self) -> GeorgeResult<()> {
+        let acceptor = (self.build)(&self.tls)?;
+        *self.acceptor.write().unwrap() = acceptor;
+        log::info!("tls certificates reload success!");
+        Ok(())
+    }
+
+    /// 启动证书文件变更检测及`SIGHUP`信号监听，需在`tokio`运行时中调用
+    pub(crate) fn watch(reloader: Arc<Self>) {
+        if let Some(interval) = reloader.tls.reload_interval.filter(|res| *res > 0) {
+            log::info!("tls certificates watch every {} secs!", interval);
+            let poller = reloader.clone();
+            tokio::spawn(async move { poller.poll(interval).await });
+        }
+        #[cfg(unix)]
+        tokio::spawn(async move { reloader.hangup().await });
+    }
+
+    /// 定时比对证书文件修改时间，有变更则重新加载
+    ///
+    /// 加载失败时不更新已记录的修改时间，以便证书文件未完整写入时在下一周期重试
+    async fn poll(&self, interval: u64) {
+        let mut modified = self.modified();
+        let mut ticker = tokio::time::interval(Duration::from_secs(interval));
+        loop {
+            ticker.tick().await;
+            let current = self.modified();
+            if current.ne(&modified) {
+                log::info!("tls certificates changed, reloading!");
+                match self.reload() {
+                    Ok(()) => modified = current,
+                    Err(err) => log::error!("tls certificates reload failed! error is {}", err),
+                }
+            }
+        }
+    }
+
+    /// 收到`SIGHUP`信号后重新加载证书
+    #[cfg(unix)]
+    async fn hangup(&self) {
+        use tokio::signal::unix::{signal, SignalKind};
+
+        let mut hangup = match signal(SignalKind::hangup()) {
+            Ok(res) => res,
+            Err(err) => {
+                log::error!("listen signal hangup failed! error is {}", err);
+                return;
+            }
+        };
+        while hangup.recv().await.is_some() {
+            log::info!("receive signal hangup, tls certificates reloading!");
+            match self.reload() {
+                Ok(()) => {}
+                Err(err) => log::error!("tls certificates reload failed! error is {}", err),
+            }
+        }
+    }
+
+    /// 证书文件修改时间，依次为`key`、`cert`、客户端根证书及客户端证书吊销列表
+    fn modified(&self) -> Vec<Option<SystemTime>> {
+        let tls = &self.tls;
+        vec![&tls.key, &tls.cert, &tls.ca, &tls.crl]
+            .into_iter()
+            .flatten()
+            .map(|filepath| match fs::metadata(filepath) {
+                Ok(res) => res.modified().ok(),
+                Err(_) => None,
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,183 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::fs;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use george_comm::errors::{Errs, GeorgeResult};
+use george_comm::io::file::{FilerHandler, FilerReader};
+use george_comm::io::Filer;
+use george_db::task::Backup;
+use george_db::task::Replication;
+use george_db::task::Secret;
+use george_db::Task;
+use george_deploy::{ConfigServerTLS, Init};
+use george_rpc::client::db::ReplicationRpcClient;
+use george_rpc::client::{RpcClient, TLSType};
+
+use crate::cmd::Replicator;
+
+/// 与主节点断开后重连的间隔
+const RECONNECT_INTERVAL: Duration = Duration::from_secs(3);
+
+impl Replicator {
+    /// 配置了主节点地址时作为从节点运行
+    pub(crate) fn new(init: &Init) -> Option<Replicator> {
+        Some(Replicator {
+            primary: init.replication_primary()?,
+            tls: init.replication_tls(),
+            peer_key: init.peer_key(),
+        })
+    }
+
+    /// 数据存储路径尚无数据时从主节点拉取快照并恢复，恢复后的实例从快照对应的变更日志序号开始跟随
+    pub(crate) fn bootstrap(&self, init: &Init) -> GeorgeResult<()> {
+        let data_dir = match init.data_dir() {
+            Some(res) => res,
+            None => return Err(Errs::str("data dir must be assign!")),
+        };
+        if Filer::exist(format!("{}/bootstrap.ge", data_dir)) {
+            return Ok(());
+        }
+        let dir = format!("{}/snapshot", data_dir);
+        let _ = fs::remove_dir_all(&dir);
+        log::info!("replication pull snapshot from primary {}", self.primary);
+        let manifest = self.client()?.snapshot(dir.clone())?;
+        Backup::restore(dir.clone(), data_dir.clone())?;
+        Replication::seed(
+            data_dir,
+            self.primary.clone(),
+            manifest.seq,
+            manifest.end_seq,
+        )?;
+        let _ = fs::remove_dir_all(&dir);
+        log::info!(
+            "replication restore {} files from snapshot with seq {}",
+            manifest.files.len(),
+            manifest.seq
+        );
+        Ok(())
+    }
+
+    /// 启动复制线程，持续应用主节点推送的变更，断开后自动重连，提升为主节点后退出
+    pub(crate) fn spawn(self, task: Arc<Task>) -> GeorgeResult<()> {
+        if !task.replication_follow(self.primary.clone())? {
+            return Ok(());
+        }
+        match thread::Builder::new()
+            .name("replicator".to_string())
+            .spawn(move || self.run(task))
+        {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Errs::strs("replicator thread spawn", err)),
+        }
+    }
+
+    fn run(&self, task: Arc<Task>) {
+        loop {
+            let (state, _) = task.replication();
+            if !state.replica() {
+                log::info!("replication promoted, stop following {}", self.primary);
+                return;
+            }
+            let result = self.client().and_then(|mut client| {
+                client.follow(state.applied, &mut |primary_seq, mutations| {
+                    task.replicate(primary_seq, mutations)
+                })
+            });
+            if let Err(err) = result {
+                if task.replication().0.replica() {
+                    // 应用失败时已应用序号停留在失败变更之前，重连后从该变更重试
+                    log::warn!(
+                        "replication follow {} failed, reconnect in {:?}! error is {}",
+                        self.primary,
+                        RECONNECT_INTERVAL,
+                        err
+                    );
+                    thread::sleep(RECONNECT_INTERVAL);
+                }
+            }
+        }
+    }
+
+    /// 连接主节点，请求携带以节点间认证密钥签发的凭证
+    fn client(&self) -> GeorgeResult<ReplicationRpcClient> {
+        let peer_key = match &self.peer_key {
+            Some(res) => res.clone(),
+            None => return Err(Errs::str("replication requires server peer_key!")),
+        };
+        let mut client = self.connect()?;
+        client.set_peer(Arc::new(Secret::peer(peer_key)?));
+        Ok(client)
+    }
+
+    fn connect(&self) -> GeorgeResult<ReplicationRpcClient> {
+        let (remote, port) = match self.primary.rsplit_once(':') {
+            Some((remote, port)) => match port.parse::<u16>() {
+                Ok(port) => (remote, port),
+                Err(err) => return Err(Errs::strs("replication primary port parse", err)),
+            },
+            None => {
+                return Err(Errs::string(format!(
+                    "replication primary {} must be host:port!",
+                    self.primary
+                )))
+            }
+        };
+        match &self.tls {
+            Some(tls) => {
+                let tls_type = if tls.rust_tls.unwrap_or(false) {
+                    TLSType::Rustls
+                } else {
+                    TLSType::Openssl
+                };
+                let ca_bytes = Filer::read_bytes(tls_require(&tls.ca, "ca")?)?;
+                let domain_name = tls.domain.clone().unwrap_or_default();
+                match (&tls.key, &tls.cert) {
+                    (Some(key), Some(cert)) => ReplicationRpcClient::new_tls_bytes_check(
+                        tls_type,
+                        remote,
+                        port,
+                        Filer::read_bytes(key)?,
+                        Filer::read_bytes(cert)?,
+                        ca_bytes,
+                        domain_name,
+                        None,
+                    ),
+                    _ => ReplicationRpcClient::new_tls_bytes(
+                        tls_type,
+                        remote,
+                        port,
+                        ca_bytes,
+                        domain_name,
+                        None,
+                    ),
+                }
+            }
+            None => ReplicationRpcClient::new(remote, port, None),
+        }
+    }
+}
+
+fn tls_require(filepath: &Option<String>, name: &str) -> GeorgeResult<String> {
+    match filepath {
+        Some(res) => Ok(res.clone()),
+        None => Err(Errs::string(format!(
+            "replication tls {} must be assign!",
+            name
+        ))),
+    }
+}
@@ -12,29 +12,67 @@
  * limitations under the License.
  */
 
-use crate::cmd::Restart;
+use std::process;
+
 use clap::{App, Arg, ArgMatches, SubCommand};
+
 use george_deploy::Builder;
 
+use crate::cmd::stop::STOP_TIMEOUT_SECS;
+use crate::cmd::{Restart, Service, Start, Stop};
+
 impl Restart {
     pub fn subcommand() -> App<'static, 'static> {
         SubCommand::with_name("restart")
             .version(Builder::version())
-            .about("this is restart")
+            .about("stop george-server if running and start it again")
             .arg(
                 Arg::with_name("file")
                     .short("f")
-                    .long("config-filename")
-                    .help("this is file")
+                    .long("config-filepath")
+                    .help("config filepath")
                     .takes_value(true),
             )
+            .arg(
+                Arg::with_name("timeout")
+                    .short("t")
+                    .long("timeout")
+                    .help("secs to wait for george-server to exit")
+                    .default_value(STOP_TIMEOUT_SECS)
+                    .takes_value(true),
+            )
+            .arg(
+                Arg::with_name("daemon")
+                    .short("d")
+                    .long("daemon")
+                    .help("run in background and return after george-server started"),
+            )
     }
 
     pub fn matches(matches: &ArgMatches) {
-        if let Some(res) = matches.value_of("file") {
-            println!("restart file out: {}", res);
+        let filepath = Start::filepath(matches);
+        match Stop::timeout(matches).and_then(|timeout| Stop::stop(filepath.clone(), timeout)) {
+            Ok(Some(pid)) => println!("george-server with pid {} stopped!", pid),
+            Ok(None) => println!("george-server is not running, starting..."),
+            Err(err) => {
+                println!("george-server restart failed! error is {}", err);
+                process::exit(1)
+            }
+        }
+        if matches.is_present("daemon") {
+            match Start::daemon(filepath) {
+                Ok(daemon) => println!(
+                    "george-server restarted, [PID] {} running on {}...",
+                    daemon.pid(),
+                    daemon.addr()
+                ),
+                Err(err) => {
+                    println!("george-server restart failed! error is {}", err);
+                    process::exit(1)
+                }
+            }
         } else {
-            println!("restart default");
+            Service::start(filepath)
         }
     }
 }
@@ -0,0 +1,113 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::process;
+use std::time::Duration;
+
+use clap::{App, Arg, ArgMatches, SubCommand};
+
+use george_comm::errors::{Errs, GeorgeResult};
+use george_db::task::Backup;
+use george_db::Task;
+use george_deploy::{Builder, Init};
+
+use crate::cmd::{Daemon, Restore, Start};
+
+impl Restore {
+    pub fn subcommand() -> App<'static, 'static> {
+        SubCommand::with_name("restore")
+            .version(Builder::version())
+            .about("restore data dir from backup, then replay journal to a point in time if set")
+            .arg(
+                Arg::with_name("file")
+                    .short("f")
+                    .long("config-filepath")
+                    .help("config filepath which george-server will start with")
+                    .takes_value(true),
+            )
+            .arg(
+                Arg::with_name("backup")
+                    .short("b")
+                    .long("backup-dir")
+                    .help("backup dir")
+                    .takes_value(true)
+                    .required(true),
+            )
+            .arg(
+                Arg::with_name("journal")
+                    .short("j")
+                    .long("journal")
+                    .help("journal filepath or dir with journal segments to replay after restore, move it out of data dir first")
+                    .takes_value(true),
+            )
+            .arg(
+                Arg::with_name("until")
+                    .short("u")
+                    .long("until")
+                    .help("replay journal until the unix timestamp in secs, replay all if not set")
+                    .takes_value(true),
+            )
+    }
+
+    pub fn matches(matches: &ArgMatches) {
+        match Restore::restore(matches) {
+            Ok(()) => println!("george-server restore success!"),
+            Err(err) => {
+                println!("george-server restore failed! error is {}", err);
+                process::exit(1)
+            }
+        }
+    }
+
+    /// 从备份重建数据目录，指定变更日志时继续重放备份后的变更
+    fn restore(matches: &ArgMatches) -> GeorgeResult<()> {
+        let until = match matches.value_of("until") {
+            Some(res) => match res.parse::<i64>() {
+                Ok(res) => res,
+                Err(err) => return Err(Errs::strs("until parse", err)),
+            },
+            None => 0,
+        };
+        let init = Init::from(Start::filepath(matches))?;
+        if let Some(daemon) = Daemon::load(Daemon::filepath(&init))? {
+            if daemon.alive() {
+                return Err(Errs::string(format!(
+                    "george-server is running with pid {}, stop it first!",
+                    daemon.pid()
+                )));
+            }
+        }
+        let data_dir = match init.data_dir() {
+            Some(res) => res,
+            None => return Err(Errs::str("data dir must be assign!")),
+        };
+        let manifest = Backup::restore(matches.value_of("backup").unwrap().to_string(), data_dir)?;
+        println!(
+            "restore {} files with journal seq {}",
+            manifest.files.len(),
+            manifest.seq
+        );
+        if let Some(journal) = matches.value_of("journal") {
+            let task = Task::new(init.clone())?;
+            let (applied, skipped) =
+                task.journal_replay(journal.to_string(), manifest.seq, manifest.end_seq, until)?;
+            println!(
+                "replay {} mutations, {} skipped as already in backup",
+                applied, skipped
+            );
+            task.shutdown(Duration::from_secs(init.shutdown_timeout()))?;
+        }
+        Ok(())
+    }
+}
@@ -12,89 +12,313 @@
  * limitations under the License.
  */
 
-use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::fs;
+use std::future::Future;
+use std::io::Cursor;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr, ToSocketAddrs};
 use std::option::Option::Some;
 use std::path::Path;
+use std::pin::Pin;
+use std::process;
 use std::sync::Arc;
 use std::time::Duration;
 
-use openssl::ssl::{select_next_proto, AlpnError, SslAcceptor, SslFiletype, SslMethod};
-use tokio::net::TcpListener;
+use futures::future::try_join_all;
+use once_cell::sync::OnceCell;
+use openssl::ssl::{
+    select_next_proto, AlpnError, SslAcceptor, SslFiletype, SslMethod, SslVerifyMode,
+};
+use openssl::x509::X509;
+#[cfg(unix)]
+use tokio::net::UnixListener;
+use tokio::net::{TcpListener, TcpStream};
 use tokio::runtime::Runtime;
-use tonic::transport::{Certificate, Identity, ServerTlsConfig};
+use tokio::sync::{mpsc, watch};
+use tokio_rustls::rustls::internal::pemfile;
+use tokio_rustls::rustls::{
+    AllowAnyAnonymousOrAuthenticatedClient, AllowAnyAuthenticatedClient, NoClientAuth,
+    RootCertStore, ServerConfig, Session,
+};
+use tokio_rustls::server::TlsStream;
+use tokio_rustls::TlsAcceptor;
+use tokio_stream::wrappers::TcpListenerStream;
+use tokio_stream::Stream;
+use tonic::transport::Server;
 
+use george_comm::cryptos::ca::CRL;
+use george_comm::cryptos::Cert;
 use george_comm::errors::{Errs, GeorgeResult};
 use george_comm::io::file::FilerReader;
 use george_comm::io::Filer;
 use george_comm::openssl::tonic::ALPN_H2_WIRE;
 use george_db::task::traits::TMaster;
 use george_db::Task;
-use george_deploy::{Init, LogPolicy};
+use george_deploy::{ConfigServerListen, ConfigServerTLS, Init, LogPolicy};
+use george_rpc::protos::db::db::audit_service_server::AuditServiceServer;
+use george_rpc::protos::db::db::backup_service_server::BackupServiceServer;
+use george_rpc::protos::db::db::cluster_service_server::ClusterServiceServer;
 use george_rpc::protos::db::db::database_service_server::DatabaseServiceServer;
 use george_rpc::protos::db::db::disk_service_server::DiskServiceServer;
 use george_rpc::protos::db::db::index_service_server::IndexServiceServer;
+use george_rpc::protos::db::db::ledger_service_server::LedgerServiceServer;
 use george_rpc::protos::db::db::memory_service_server::MemoryServiceServer;
 use george_rpc::protos::db::db::page_service_server::PageServiceServer;
+use george_rpc::protos::db::db::quota_service_server::QuotaServiceServer;
+use george_rpc::protos::db::db::replication_service_server::ReplicationServiceServer;
+use george_rpc::protos::db::db::transfer_service_server::TransferServiceServer;
 use george_rpc::protos::db::db::user_service_server::UserServiceServer;
 use george_rpc::protos::db::db::view_service_server::ViewServiceServer;
 use george_rpc::server::db::{
-    DatabaseServer, DiskServer, IndexServer, MemoryServer, PageServer, UserServer, ViewServer,
+    AuditServer, BackupServer, ClusterServer, DatabaseServer, DiskServer, IndexServer,
+    LedgerServer, MemoryServer, PageServer, QuotaServer, ReplicationServer, TransferServer,
+    UserServer, ViewServer,
 };
 use george_rpc::server::db::{DATABASE_SYS, DEFAULT_COMMENT, VIEW_USER};
 
-use crate::cmd::Service;
+#[cfg(unix)]
+use crate::cmd::UdsStream;
+use crate::cmd::{
+    Cluster, Daemon, Exporter, Health, Pending, Reloader, Replicator, RustlsAcceptor, Service,
+};
+
+/// `h2`的`alpn`标识
+const ALPN_H2: &[u8] = b"h2";
+/// `TLS`握手超时时长，超时未完成握手的连接将被关闭，避免占用握手资源
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+/// 已完成握手、等待服务接收的最大连接数
+const HANDSHAKE_BACKLOG: usize = 128;
+
+/// 单个监听的服务运行
+type Serve = Pin<Box<dyn Future<Output = Result<(), tonic::transport::Error>>>>;
+
+/// 以指定的构建器注册所有服务，除健康检查及反射服务外均在数据恢复完成后方才处理请求
+macro_rules! router {
+    ($server:expr, $task:expr, $health:expr) => {
+        $server
+            .add_service(Pending::new($task.clone(), |task| {
+                AuditServiceServer::new(AuditServer::new(task))
+            }))
+            .add_service(Pending::new($task.clone(), |task| {
+                BackupServiceServer::new(BackupServer::new(task))
+            }))
+            .add_service(Pending::new($task.clone(), |task| {
+                ClusterServiceServer::new(ClusterServer::new(task))
+            }))
+            .add_service(Pending::new($task.clone(), |task| {
+                DatabaseServiceServer::new(DatabaseServer::new(task))
+            }))
+            .add_service(Pending::new($task.clone(), |task| {
+                DiskServiceServer::new(DiskServer::new(task))
+            }))
+            .add_service(Pending::new($task.clone(), |task| {
+                IndexServiceServer::new(IndexServer::new(task))
+            }))
+            .add_service(Pending::new($task.clone(), |task| {
+                LedgerServiceServer::new(LedgerServer::new(task))
+            }))
+            .add_service(Pending::new($task.clone(), |task| {
+                MemoryServiceServer::new(MemoryServer::new(task))
+            }))
+            .add_service(Pending::new($task.clone(), |task| {
+                PageServiceServer::new(PageServer::new(task))
+            }))
+            .add_service(Pending::new($task.clone(), |task| {
+                QuotaServiceServer::new(QuotaServer::new(task))
+            }))
+            .add_service(Pending::new($task.clone(), |task| {
+                ReplicationServiceServer::new(ReplicationServer::new(task))
+            }))
+            .add_service(Pending::new($task.clone(), |task| {
+                TransferServiceServer::new(TransferServer::new(task))
+            }))
+            .add_service(Pending::new($task.clone(), |task| {
+                UserServiceServer::new(UserServer::new(task))
+            }))
+            .add_service(Pending::new($task.clone(), |task| {
+                ViewServiceServer::new(ViewServer::new(task))
+            }))
+            .add_service($health.clone())
+            .add_service(Health::reflection()?)
+    };
+}
 
 impl Service {
     /// filepath e.g: `server/src/example/conf.yaml` | `server/src/example/conf_tls.yaml`
     pub fn start<P: AsRef<Path>>(filepath: P) {
-        let (init, task, addr) = run_prepare(filepath).unwrap();
+        let (init, daemon) = match prepare(filepath) {
+            Ok(res) => res,
+            Err(err) => {
+                println!("george-server start failed! error is {}", err);
+                process::exit(1)
+            }
+        };
+        let timeout = Duration::from_secs(init.shutdown_timeout());
+        let recovered: Arc<OnceCell<Arc<Task>>> = Arc::new(OnceCell::new());
         let rt = Runtime::new().expect("failed to obtain a new RunTime object");
-        if init.rustls() {
-            log::info!("checkout tls: rustls!");
-            rt.block_on(run(init, task, addr))
-                .expect("failed to successfully run the future on RunTime");
-        } else {
-            log::info!("checkout tls: openssl!");
-            rt.block_on(run_with_openssl(init, task, addr))
-                .expect("failed to successfully run the future on RunTime");
+        let result = rt.block_on(run(init.clone(), recovered.clone()));
+        // 放弃仍未结束的连接，避免其阻塞进程退出
+        rt.shutdown_timeout(Duration::from_secs(1));
+        for listen in init.listens() {
+            if let Some(path) = listen.uds {
+                let _ = fs::remove_file(path);
+            }
+        }
+        let task = match (recovered.get(), result) {
+            (Some(task), Ok(())) => task.clone(),
+            (Some(task), Err(err)) => {
+                log::error!("george-server run failed! error is {}", err);
+                task.clone()
+            }
+            (None, result) => {
+                let err = match result {
+                    Ok(()) => Errs::str("stopped before recovery finished"),
+                    Err(err) => err,
+                };
+                log::error!("george-server start failed! error is {}", err);
+                println!("george-server start failed! error is {}", err);
+                // 进程直接退出不会执行析构，先移除PID文件
+                drop(daemon);
+                process::exit(1)
+            }
+        };
+        match task.shutdown(timeout) {
+            Ok(()) => log::info!("george-server shutdown success!"),
+            Err(err) => log::error!("george-server shutdown failed! error is {}", err),
         }
     }
 }
 
-fn run_prepare<P: AsRef<Path>>(filepath: P) -> GeorgeResult<(Init, Arc<Task>, SocketAddr)> {
+/// 读取配置并写入PID文件
+fn prepare<P: AsRef<Path>>(filepath: P) -> GeorgeResult<(Init, Daemon)> {
     let init = Init::from(filepath)?;
     log_policy(init.clone());
+    let listens = init.listens();
+    if listens.is_empty() {
+        return Err(Errs::str("server listens must be assign!"));
+    }
+    let addr = listens.iter().map(listen_name).collect::<Vec<String>>();
+    log::info!("listener: {}", addr.join(", "));
+    // 先写入PID文件，避免重复启动的服务进程同时操作数据
+    let daemon = Daemon::create(&init, addr.join(", "))?;
+    Ok((init, daemon))
+}
+
+/// 恢复数据，并按配置以从节点或集群模式启动
+fn run_prepare(init: Init) -> GeorgeResult<Arc<Task>> {
+    let replicator = Replicator::new(&init);
+    let cluster = Cluster::new(&init);
+    if replicator.is_some() && cluster.is_some() {
+        return Err(Errs::str(
+            "replication and cluster can not be assign at the same time!",
+        ));
+    }
+    if let Some(replicator) = &replicator {
+        replicator.bootstrap(&init)?;
+    }
+    if let Some(cluster) = &cluster {
+        cluster.bootstrap(&init)?;
+    }
     let task = Arc::new(Task::new(init.clone())?);
     init_data(task.clone())?;
-
-    log::info!("listener port: {}", init.port_unwrap());
-    let addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), init.port_unwrap());
-
-    Ok((init, task, addr))
+    if let Some(replicator) = replicator {
+        replicator.spawn(task.clone())?;
+    }
+    if let Some(cluster) = cluster {
+        cluster.start(task.clone())?;
+    }
+    Ok(task)
 }
 
-async fn run(init: Init, task: Arc<Task>, addr: SocketAddr) -> GeorgeResult<()> {
-    let mut server = tonic::transport::Server::builder();
-    if init.tls() {
-        let mut tls_config = ServerTlsConfig::new();
-        let key = Filer::read_bytes(init.tls_key_unwrap())?;
-        let cert = Filer::read_bytes(init.tls_cert_unwrap())?;
-        let identity = Identity::from_pem(cert, key);
-        tls_config = tls_config.identity(identity);
-        log::info!("listener tls config identity success!");
+/// 在阻塞线程中恢复数据，完成后写入任务对象并启动运行指标导出服务
+async fn recover(init: Init, recovered: Arc<OnceCell<Arc<Task>>>) -> GeorgeResult<()> {
+    let prepare = init.clone();
+    let task = match tokio::task::spawn_blocking(move || run_prepare(prepare)).await {
+        Ok(res) => res?,
+        Err(err) => return Err(Errs::strs("recovery", err)),
+    };
+    Exporter::spawn(&init, task.clone(), metrics_ip(&init))?;
+    let _ = recovered.set(task);
+    Ok(())
+}
 
-        match init.tls_ca() {
-            Some(res) => {
-                let client_ca = Filer::read_bytes(res)?;
-                let cert = Certificate::from_pem(client_ca);
-                tls_config = tls_config.client_ca_root(cert);
-                log::info!("listener tls config client ca root success!");
+/// 在所有监听上运行服务，收到停止信号后所有监听同时停止接收新连接
+///
+/// 监听在数据恢复前即开始接收连接，恢复期间健康状态为`NOT_SERVING`，恢复完成后置为`SERVING`
+async fn run(init: Init, task: Arc<OnceCell<Arc<Task>>>) -> GeorgeResult<()> {
+    let (health, health_service) = Health::new().await;
+    let timeout = Duration::from_secs(init.shutdown_timeout());
+    let (notify, signaled) = watch::channel(false);
+    let mut serves: Vec<Serve> = vec![];
+    for listen in init.listens() {
+        let name = listen_name(&listen);
+        let signal = wait(signaled.clone());
+        let serve: Serve = match (listen.addr, listen.uds, listen.tls) {
+            (Some(addr), _, None) => {
+                let addr = socket_addr(addr)?;
+                Box::pin(
+                    router!(builder(&init), task, health_service).serve_with_shutdown(addr, signal),
+                )
             }
-            _ => {}
-        }
-        server = server.tls_config(tls_config).unwrap();
-        log::info!("listener tls open!");
+            (Some(addr), _, Some(tls)) if tls.rust_tls.unwrap_or(false) => {
+                log::info!("listener {} checkout tls: rustls!", name);
+                let reloader = Reloader::new(tls, rustls_acceptor)?;
+                Reloader::watch(reloader.clone());
+                let listener = tcp_bind(socket_addr(addr)?).await?;
+                Box::pin(
+                    router!(builder(&init), task, health_service)
+                        .serve_with_incoming_shutdown(rustls_incoming(listener, reloader), signal),
+                )
+            }
+            (Some(addr), _, Some(tls)) => {
+                log::info!("listener {} checkout tls: openssl!", name);
+                let reloader = Reloader::new(tls, openssl_acceptor)?;
+                Reloader::watch(reloader.clone());
+                let listener = tcp_bind(socket_addr(addr)?).await?;
+                let incoming = george_comm::openssl::tonic::incoming_with(
+                    TcpListenerStream::new(listener),
+                    move || reloader.acceptor(),
+                    HANDSHAKE_TIMEOUT,
+                );
+                Box::pin(
+                    router!(builder(&init), task, health_service)
+                        .serve_with_incoming_shutdown(incoming, signal),
+                )
+            }
+            (None, Some(_), Some(_)) => {
+                return Err(Errs::string(format!(
+                    "listener {} can not assign tls, uds only serves plaintext!",
+                    name
+                )))
+            }
+            #[cfg(unix)]
+            (None, Some(path), None) => {
+                let incoming = uds_incoming(path)?;
+                Box::pin(
+                    router!(builder(&init), task, health_service)
+                        .serve_with_incoming_shutdown(incoming, signal),
+                )
+            }
+            _ => return Err(Errs::str("listen must be assign with addr or uds!")),
+        };
+        log::info!("listener {} open!", name);
+        serves.push(serve);
     }
+    tokio::spawn(shutdown_signal(health.clone(), notify));
+    let serve = async move { try_join_all(serves).await.map(|_| ()) };
+    let serve = drain(serve, signaled, timeout);
+    tokio::pin!(serve);
+    tokio::select! {
+        res = &mut serve => return res,
+        res = recover(init.clone(), task) => res?,
+    }
+    log::info!("george-server recovery finished!");
+    health.serving().await;
+    serve.await
+}
+
+/// 根据配置新建服务构建器
+fn builder(init: &Init) -> Server {
+    let mut server = Server::builder();
     if let Some(res) = init.timeout() {
         server.timeout(Duration::from_secs(res));
     }
@@ -125,84 +349,361 @@ async fn run(init: Init, task: Arc<Task>, addr: SocketAddr) -> GeorgeResult<()>
     if let Some(res) = init.max_frame_size() {
         server = server.max_frame_size(res);
     }
+    server
+}
+
+/// 监听名称，`Unix domain socket`以`unix:`为前缀
+fn listen_name(listen: &ConfigServerListen) -> String {
+    match (&listen.addr, &listen.uds) {
+        (Some(addr), _) => addr.clone(),
+        (None, Some(path)) => format!("unix:{}", path),
+        _ => "".to_string(),
+    }
+}
+
+/// 解析`host:port`格式的监听地址，支持`IPv4`及`IPv6`
+fn socket_addr(addr: String) -> GeorgeResult<SocketAddr> {
+    match addr.to_socket_addrs() {
+        Ok(mut res) => match res.next() {
+            Some(res) => Ok(res),
+            None => Err(Errs::string(format!(
+                "listen addr {} resolve nothing!",
+                addr
+            ))),
+        },
+        Err(err) => Err(Errs::strs(
+            format!("listen addr {} parse", addr).as_str(),
+            err,
+        )),
+    }
+}
+
+async fn tcp_bind(addr: SocketAddr) -> GeorgeResult<TcpListener> {
+    match TcpListener::bind(addr).await {
+        Ok(res) => Ok(res),
+        Err(err) => Err(Errs::strs("tcp listener bind", err)),
+    }
+}
+
+/// 运行指标监听地址，与首个`host:port`监听地址一致，无则为`127.0.0.1`
+fn metrics_ip(init: &Init) -> IpAddr {
+    for listen in init.listens() {
+        if let Some(addr) = listen.addr {
+            if let Ok(res) = socket_addr(addr) {
+                return res.ip();
+            }
+        }
+    }
+    IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1))
+}
+
+/// 等待`SIGINT`或`SIGTERM`信号，收到后将健康状态置为`NOT_SERVING`，并通知所有监听停止接收新连接
+async fn shutdown_signal(health: Health, notify: watch::Sender<bool>) {
+    #[cfg(unix)]
+    let terminate = async {
+        use tokio::signal::unix::{signal, SignalKind};
+
+        match signal(SignalKind::terminate()) {
+            Ok(mut res) => {
+                res.recv().await;
+            }
+            Err(err) => {
+                log::error!("listen signal terminate failed! error is {}", err);
+                std::future::pending::<()>().await
+            }
+        }
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+    tokio::select! {
+        _ = tokio::signal::ctrl_c() => log::info!("receive signal interrupt, george-server shutting down!"),
+        _ = terminate => log::info!("receive signal terminate, george-server shutting down!"),
+    }
+    health.not_serving().await;
+    let _ = notify.send(true);
+}
+
+/// 等待停止通知
+async fn wait(mut signaled: watch::Receiver<bool>) {
+    while !*signaled.borrow() {
+        if signaled.changed().await.is_err() {
+            return;
+        }
+    }
+}
 
-    let database_service = DatabaseServiceServer::new(DatabaseServer::new(task.clone()));
-    let disk_service = DiskServiceServer::new(DiskServer::new(task.clone()));
-    let index_service = IndexServiceServer::new(IndexServer::new(task.clone()));
-    let memory_service = MemoryServiceServer::new(MemoryServer::new(task.clone()));
-    let page_service = PageServiceServer::new(PageServer::new(task.clone()));
-    let user_service = UserServiceServer::new(UserServer::new(task.clone()));
-    let view_service = ViewServiceServer::new(ViewServer::new(task.clone()));
-
-    match server
-        .add_service(database_service)
-        .add_service(disk_service)
-        .add_service(index_service)
-        .add_service(memory_service)
-        .add_service(page_service)
-        .add_service(user_service)
-        .add_service(view_service)
-        .serve(addr)
-        .await
-    {
+/// 运行服务直至停止，收到停止信号后在`timeout`内等待进行中的请求完成，超时则不再等待
+async fn drain<F>(serve: F, signaled: watch::Receiver<bool>, timeout: Duration) -> GeorgeResult<()>
+where
+    F: Future<Output = Result<(), tonic::transport::Error>>,
+{
+    tokio::pin!(serve);
+    let result = tokio::select! {
+        res = &mut serve => res,
+        _ = wait(signaled) => match tokio::time::timeout(timeout, &mut serve).await {
+            Ok(res) => res,
+            Err(_) => {
+                log::warn!("in-flight requests not finished within {:?}, give up waiting", timeout);
+                Ok(())
+            }
+        },
+    };
+    match result {
         Ok(()) => Ok(()),
         Err(err) => Err(Errs::strs("serve with incoming", err)),
     }
 }
 
-async fn run_with_openssl(init: Init, task: Arc<Task>, addr: SocketAddr) -> GeorgeResult<()> {
-    let mut server = tonic::transport::Server::builder();
-
-    let mut acceptor_builder = SslAcceptor::mozilla_intermediate(SslMethod::tls()).unwrap();
-    // match init.tls_ca() {
-    //     Some(res) => {
-    //         acceptor_builder.set_verify(SslVerifyMode::PEER);
-    //         acceptor_builder.set_ca_file(res).unwrap();
-    //     }
-    //     None => {}
-    // }
-    acceptor_builder
-        .set_private_key_file(init.tls_key_unwrap(), SslFiletype::PEM)
-        .unwrap();
-    acceptor_builder
-        .set_certificate_chain_file(init.tls_cert_unwrap())
-        .unwrap();
-    acceptor_builder.check_private_key().unwrap();
-    acceptor_builder.set_alpn_protos(ALPN_H2_WIRE).unwrap();
+/// 根据配置构建`rustls`接收器
+pub(crate) fn rustls_acceptor(tls: &ConfigServerTLS) -> GeorgeResult<RustlsAcceptor> {
+    let key = Filer::read_bytes(tls_require(&tls.key, "key")?)?;
+    let cert = Filer::read_bytes(tls_require(&tls.cert, "cert")?)?;
+    let certs = match pemfile::certs(&mut Cursor::new(cert)) {
+        Ok(res) => res,
+        Err(()) => return Err(Errs::str("tls cert parse failed!")),
+    };
+    let key = match pemfile::pkcs8_private_keys(&mut Cursor::new(key.clone())) {
+        Ok(mut res) if !res.is_empty() => res.remove(0),
+        _ => match pemfile::rsa_private_keys(&mut Cursor::new(key)) {
+            Ok(mut res) if !res.is_empty() => res.remove(0),
+            _ => return Err(Errs::str("tls key parse failed!")),
+        },
+    };
+    let mut config;
+    match &tls.ca {
+        Some(res) => {
+            let mut roots = RootCertStore::empty();
+            match roots.add_pem_file(&mut Cursor::new(Filer::read_bytes(res)?)) {
+                Ok(_) => {}
+                Err(()) => return Err(Errs::str("tls client ca parse failed!")),
+            }
+            if tls.client_optional.unwrap_or(false) {
+                config = ServerConfig::new(AllowAnyAnonymousOrAuthenticatedClient::new(roots));
+            } else {
+                config = ServerConfig::new(AllowAnyAuthenticatedClient::new(roots));
+            }
+            log::info!("listener tls config client ca root success!");
+        }
+        None => config = ServerConfig::new(NoClientAuth::new()),
+    }
+    match config.set_single_cert(certs, key) {
+        Ok(()) => {}
+        Err(err) => return Err(Errs::strs("tls config identity", err)),
+    }
+    config.set_protocols(&[ALPN_H2.to_vec()]);
+    log::info!("listener tls config identity success!");
+    Ok(RustlsAcceptor {
+        acceptor: TlsAcceptor::from(Arc::new(config)),
+        crl: client_crl(tls)?.map(Arc::new),
+        crl_allow_stale: tls.crl_allow_stale.unwrap_or(false),
+    })
+}
+
+/// 根据配置构建`openssl`接收器
+pub(crate) fn openssl_acceptor(tls: &ConfigServerTLS) -> GeorgeResult<SslAcceptor> {
+    let mut acceptor_builder = match SslAcceptor::mozilla_intermediate(SslMethod::tls()) {
+        Ok(res) => res,
+        Err(err) => return Err(Errs::strs("ssl acceptor", err)),
+    };
+    let crl = client_crl(tls)?;
+    if let Some(ca) = &tls.ca {
+        match acceptor_builder.set_ca_file(ca) {
+            Ok(()) => {}
+            Err(err) => return Err(Errs::strs("ssl set ca file", err)),
+        }
+        let mode = if tls.client_optional.unwrap_or(false) {
+            SslVerifyMode::PEER
+        } else {
+            SslVerifyMode::PEER | SslVerifyMode::FAIL_IF_NO_PEER_CERT
+        };
+        match crl {
+            // 吊销列表已过期，或证书链中任意证书出现在吊销列表中即拒绝握手
+            Some(crl) => {
+                let allow_stale = tls.crl_allow_stale.unwrap_or(false);
+                acceptor_builder.set_verify_callback(mode, move |ok, ctx| {
+                    ok && crl_fresh(&crl, allow_stale)
+                        && match ctx.current_cert() {
+                            Some(x509) => !crl.revoked(x509),
+                            None => true,
+                        }
+                });
+                log::info!("listener tls config client crl success!");
+            }
+            None => acceptor_builder.set_verify(mode),
+        }
+        log::info!("listener tls config client ca root success!");
+    }
+    match acceptor_builder.set_private_key_file(tls_require(&tls.key, "key")?, SslFiletype::PEM) {
+        Ok(()) => {}
+        Err(err) => return Err(Errs::strs("ssl set private key file", err)),
+    }
+    match acceptor_builder.set_certificate_chain_file(tls_require(&tls.cert, "cert")?) {
+        Ok(()) => {}
+        Err(err) => return Err(Errs::strs("ssl set certificate chain file", err)),
+    }
+    match acceptor_builder.check_private_key() {
+        Ok(()) => {}
+        Err(err) => return Err(Errs::strs("ssl check private key", err)),
+    }
+    match acceptor_builder.set_alpn_protos(ALPN_H2_WIRE) {
+        Ok(()) => {}
+        Err(err) => return Err(Errs::strs("ssl set alpn protos", err)),
+    }
     acceptor_builder.set_alpn_select_callback(|_ssl, alpn| {
         select_next_proto(ALPN_H2_WIRE, alpn).ok_or(AlpnError::ALERT_FATAL)
     });
-    let acceptor = acceptor_builder.build();
-
-    let listener = TcpListener::bind(addr).await.unwrap();
-    let listener = listener.into_std().unwrap();
-    let tcp_listener_stream = tokio_stream::wrappers::TcpListenerStream::new(
-        tokio::net::TcpListener::from_std(listener).unwrap(),
-    );
-    let incoming = george_comm::openssl::tonic::incoming(tcp_listener_stream, acceptor);
-
-    let database_service = DatabaseServiceServer::new(DatabaseServer::new(task.clone()));
-    let disk_service = DiskServiceServer::new(DiskServer::new(task.clone()));
-    let index_service = IndexServiceServer::new(IndexServer::new(task.clone()));
-    let memory_service = MemoryServiceServer::new(MemoryServer::new(task.clone()));
-    let page_service = PageServiceServer::new(PageServer::new(task.clone()));
-    let user_service = UserServiceServer::new(UserServer::new(task.clone()));
-    let view_service = ViewServiceServer::new(ViewServer::new(task.clone()));
-
-    match server
-        .add_service(database_service)
-        .add_service(disk_service)
-        .add_service(index_service)
-        .add_service(memory_service)
-        .add_service(page_service)
-        .add_service(user_service)
-        .add_service(view_service)
-        .serve_with_incoming(incoming)
-        .await
-    {
-        Ok(()) => Ok(()),
-        Err(err) => Err(Errs::strs("serve with incoming", err)),
+    Ok(acceptor_builder.build())
+}
+
+/// 读取并校验客户端证书吊销列表，吊销列表须由客户端根证书签发
+fn client_crl(tls: &ConfigServerTLS) -> GeorgeResult<Option<CRL>> {
+    let filepath = match &tls.crl {
+        Some(res) => res,
+        None => return Ok(None),
+    };
+    let ca = match &tls.ca {
+        Some(res) => res,
+        None => return Err(Errs::str("tls client crl must be configured with ca!")),
+    };
+    let crl = CRL::load_pem_file(filepath)?;
+    let mut signed = false;
+    for x509 in Cert::load_chain_pem(ca)? {
+        if crl.verify(&x509)? {
+            signed = true;
+            break;
+        }
+    }
+    if !signed {
+        return Err(Errs::str("tls client crl is not signed by client ca!"));
     }
+    if crl.expired()? {
+        if !tls.crl_allow_stale.unwrap_or(false) {
+            return Err(Errs::str("tls client crl has passed its next update time!"));
+        }
+        log::warn!("tls client crl has passed its next update time!");
+    }
+    Ok(Some(crl))
+}
+
+/// 吊销列表是否仍可使用，过期且不允许使用过期吊销列表时拒绝握手，无法判断是否过期时同样拒绝
+fn crl_fresh(crl: &CRL, allow_stale: bool) -> bool {
+    if allow_stale {
+        return true;
+    }
+    match crl.expired() {
+        Ok(false) => true,
+        Ok(true) => {
+            log::warn!("tls client crl has passed its next update time, reject handshake!");
+            false
+        }
+        Err(err) => {
+            log::error!("tls client crl check expired failed! error is {}", err);
+            false
+        }
+    }
+}
+
+/// 开启`TLS`后必须配置的文件路径
+fn tls_require(filepath: &Option<String>, name: &str) -> GeorgeResult<String> {
+    match filepath {
+        Some(res) => Ok(res.clone()),
+        None => Err(Errs::string(format!("tls {} must be assign!", name))),
+    }
+}
+
+/// 客户端证书链中是否存在已吊销证书
+fn rustls_revoked(crl: &CRL, stream: &TlsStream<TcpStream>) -> bool {
+    match stream.get_ref().1.get_peer_certificates() {
+        Some(certs) => certs.iter().any(|cert| match X509::from_der(&cert.0) {
+            Ok(x509) => crl.revoked(&x509),
+            Err(_) => true,
+        }),
+        None => false,
+    }
+}
+
+/// 以当前`rustls`接收器完成新建连接的握手，握手失败、超时或客户端证书已吊销的连接将被丢弃
+///
+/// 每次握手在独立任务中进行，避免慢速或停滞的客户端阻塞后续连接的接收
+pub(crate) fn rustls_incoming(
+    listener: TcpListener,
+    reloader: Arc<Reloader<RustlsAcceptor>>,
+) -> impl Stream<Item = Result<TlsStream<TcpStream>, std::io::Error>> {
+    let (tx, mut rx) = mpsc::channel(HANDSHAKE_BACKLOG);
+    tokio::spawn(async move {
+        loop {
+            let (stream, remote) = match listener.accept().await {
+                Ok(res) => res,
+                Err(err) => {
+                    let _ = tx.send(Err(err)).await;
+                    break;
+                }
+            };
+            let rustls = reloader.acceptor();
+            let tx = tx.clone();
+            tokio::spawn(async move {
+                match tokio::time::timeout(HANDSHAKE_TIMEOUT, rustls.acceptor.accept(stream)).await
+                {
+                    Ok(Ok(res)) => match &rustls.crl {
+                        Some(crl) if !crl_fresh(crl, rustls.crl_allow_stale) => {}
+                        Some(crl) if rustls_revoked(crl, &res) => {
+                            log::warn!("tls client {} certificate revoked!", remote)
+                        }
+                        _ => {
+                            let _ = tx.send(Ok(res)).await;
+                        }
+                    },
+                    Ok(Err(err)) => {
+                        log::warn!("tls client {} accept failed! error is {}", remote, err)
+                    }
+                    Err(_) => log::warn!(
+                        "tls client {} accept timeout after {:?}!",
+                        remote,
+                        HANDSHAKE_TIMEOUT
+                    ),
+                }
+            });
+        }
+    });
+    async_stream::stream! {
+        while let Some(res) = rx.recv().await {
+            yield res;
+        }
+    }
+}
+
+/// 绑定`Unix domain socket`并接收新建连接
+///
+/// 路径上残留的套接字文件将被移除，非套接字文件则返回错误，避免误删
+#[cfg(unix)]
+fn uds_incoming(
+    path: String,
+) -> GeorgeResult<impl Stream<Item = Result<UdsStream, std::io::Error>>> {
+    use std::os::unix::fs::FileTypeExt;
+
+    if let Ok(res) = fs::symlink_metadata(&path) {
+        if !res.file_type().is_socket() {
+            return Err(Errs::string(format!(
+                "uds {} exists and is not a socket!",
+                path
+            )));
+        }
+        if let Err(err) = fs::remove_file(&path) {
+            return Err(Errs::strs("uds remove stale socket", err));
+        }
+    }
+    let listener = match UnixListener::bind(&path) {
+        Ok(res) => res,
+        Err(err) => return Err(Errs::strs("uds listener bind", err)),
+    };
+    Ok(async_stream::stream! {
+        loop {
+            match listener.accept().await {
+                Ok((stream, _)) => yield Ok(UdsStream(stream)),
+                Err(err) => yield Err(err),
+            }
+        }
+    })
 }
 
 fn log_policy(init: Init) {
@@ -0,0 +1,570 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[cfg(test)]
+mod service {
+    use std::fs;
+    use std::io::{Cursor, Read, Write};
+    use std::net::{TcpListener, TcpStream};
+    use std::sync::Arc;
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    use openssl::hash::MessageDigest;
+    use openssl::ssl::{SslAcceptor, SslConnector, SslFiletype, SslMethod, SslVerifyMode};
+    use tokio::runtime::Runtime;
+    use tokio_rustls::rustls::internal::pemfile;
+    use tokio_rustls::rustls::ClientConfig;
+    use tokio_rustls::webpki::DNSNameRef;
+    use tokio_rustls::TlsConnector;
+    use tokio_stream::StreamExt;
+
+    use george_comm::cryptos::ca::{MsbOptionCA, X509NameInfo, CRL, SAN};
+    use george_comm::cryptos::Cert;
+    use george_comm::cryptos::RSA;
+    use george_comm::io::file::{FilerReader, FilerWriter};
+    use george_comm::io::Filer;
+    use george_comm::openssl::tonic::ALPN_H2_WIRE;
+    use george_deploy::ConfigServerTLS;
+
+    use crate::cmd::service::{openssl_acceptor, rustls_acceptor, rustls_incoming};
+    use crate::cmd::Reloader;
+
+    /// 测试证书文件路径
+    struct Certs {
+        /// 签发服务端及客户端证书的根证书
+        ca: String,
+        server_key: String,
+        server_cert: String,
+        client_key: String,
+        client_cert: String,
+        /// 由其它根证书签发的客户端证书
+        rogue_key: String,
+        rogue_cert: String,
+        /// 已被吊销的客户端证书
+        revoked_key: String,
+        revoked_cert: String,
+        /// 根证书签发的吊销列表，吊销`revoked_cert`
+        crl: String,
+        /// 无法解析的吊销列表
+        bad_crl: String,
+    }
+
+    /// 在指定目录下生成根证书、服务端证书、客户端证书、非信任根证书签发的客户端证书、已吊销的客户端证书及吊销列表
+    fn generate(dir: &str) -> Certs {
+        let certs = Certs {
+            ca: format!("{}/ca.pem", dir),
+            server_key: format!("{}/server.key", dir),
+            server_cert: format!("{}/server.pem", dir),
+            client_key: format!("{}/client.key", dir),
+            client_cert: format!("{}/client.pem", dir),
+            rogue_key: format!("{}/rogue.key", dir),
+            rogue_cert: format!("{}/rogue.pem", dir),
+            revoked_key: format!("{}/revoked.key", dir),
+            revoked_cert: format!("{}/revoked.pem", dir),
+            crl: format!("{}/ca.crl", dir),
+            bad_crl: format!("{}/bad.crl", dir),
+        };
+        let ca_rsa = RSA::new(2048).unwrap();
+        let ca = root(&ca_rsa, "CNRoot");
+        ca.save_pem(&certs.ca).unwrap();
+        user(
+            &ca,
+            &ca_rsa,
+            "localhost",
+            &certs.server_key,
+            &certs.server_cert,
+            false,
+        );
+        user(
+            &ca,
+            &ca_rsa,
+            "client",
+            &certs.client_key,
+            &certs.client_cert,
+            true,
+        );
+
+        user(
+            &ca,
+            &ca_rsa,
+            "revoked",
+            &certs.revoked_key,
+            &certs.revoked_cert,
+            true,
+        );
+        CRL::sign(
+            ca_rsa.sk(),
+            Cert {
+                x509: ca.x509.clone(),
+            },
+            vec![Cert::load_pem_file(&certs.revoked_cert).unwrap().x509],
+            7,
+            MessageDigest::sha256(),
+        )
+        .unwrap()
+        .save_pem(&certs.crl)
+        .unwrap();
+        Filer::write_force(&certs.bad_crl, "not a crl").unwrap();
+
+        let rogue_rsa = RSA::new(2048).unwrap();
+        let rogue = root(&rogue_rsa, "CNRogue");
+        user(
+            &rogue,
+            &rogue_rsa,
+            "rogue",
+            &certs.rogue_key,
+            &certs.rogue_cert,
+            true,
+        );
+        certs
+    }
+
+    fn root(rsa: &RSA, common_name: &str) -> Cert {
+        let subject_info = X509NameInfo::new(common_name.to_string(), "CN".to_string()).unwrap();
+        Cert::sign_root_256(
+            MsbOptionCA::MaybeZero,
+            true,
+            rsa.sk(),
+            rsa.pk(),
+            subject_info.as_ref(),
+            2,
+            0,
+            365,
+            None,
+            MessageDigest::sha256(),
+        )
+        .unwrap()
+    }
+
+    fn user(ca: &Cert, ca_rsa: &RSA, common_name: &str, key: &str, cert: &str, client_auth: bool) {
+        let sign = if client_auth {
+            Cert::sign_client_256
+        } else {
+            Cert::sign_user_256
+        };
+        let rsa = RSA::new(2048).unwrap();
+        Filer::write_force(key, rsa.sk_pkcs8_pem().unwrap()).unwrap();
+        let subject_info = X509NameInfo::new(common_name.to_string(), "CN".to_string()).unwrap();
+        let san = Some(SAN {
+            dns_names: vec![common_name.to_string()],
+            email_addresses: vec![],
+            ip_addresses: vec!["127.0.0.1".to_string()],
+            uris: vec![],
+        });
+        sign(
+            ca.x509.clone(),
+            MsbOptionCA::One,
+            true,
+            ca_rsa.sk(),
+            rsa.pk(),
+            subject_info.as_ref(),
+            2,
+            0,
+            363,
+            san,
+            MessageDigest::sha256(),
+        )
+        .unwrap()
+        .save_pem(cert)
+        .unwrap();
+    }
+
+    fn tls(certs: &Certs, client_optional: bool) -> ConfigServerTLS {
+        ConfigServerTLS {
+            rust_tls: None,
+            key: Some(certs.server_key.clone()),
+            cert: Some(certs.server_cert.clone()),
+            ca: Some(certs.ca.clone()),
+            client_optional: Some(client_optional),
+            crl: None,
+            crl_allow_stale: None,
+            domain: None,
+            reload_interval: None,
+        }
+    }
+
+    /// 配置吊销列表并要求客户端证书
+    fn tls_crl(certs: &Certs, crl: &str) -> ConfigServerTLS {
+        let mut tls = tls(certs, false);
+        tls.crl = Some(crl.to_string());
+        tls
+    }
+
+    /// 以`openssl`接收器完成一次握手，返回服务端是否握手成功
+    fn openssl_handshake(tls: &ConfigServerTLS, ca: &str, client: Option<(&str, &str)>) -> bool {
+        openssl_accept(openssl_acceptor(tls).unwrap(), ca, client)
+    }
+
+    /// 以指定`openssl`接收器完成一次握手，返回服务端是否握手成功
+    fn openssl_accept(acceptor: SslAcceptor, ca: &str, client: Option<(&str, &str)>) -> bool {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            match acceptor.accept(stream) {
+                Ok(mut res) => res.write_all(b"ok").is_ok(),
+                Err(_) => false,
+            }
+        });
+        let mut builder = SslConnector::builder(SslMethod::tls()).unwrap();
+        builder.set_ca_file(ca).unwrap();
+        builder.set_alpn_protos(ALPN_H2_WIRE).unwrap();
+        if let Some((key, cert)) = client {
+            builder.set_private_key_file(key, SslFiletype::PEM).unwrap();
+            builder.set_certificate_chain_file(cert).unwrap();
+        }
+        let stream = TcpStream::connect(addr).unwrap();
+        if let Ok(mut res) = builder.build().connect("localhost", stream) {
+            let mut buf = [0u8; 2];
+            let _ = res.read_exact(&mut buf);
+        }
+        server.join().unwrap()
+    }
+
+    /// 以`rustls`接收器完成一次握手，返回服务端是否握手成功
+    fn rustls_handshake(tls: &ConfigServerTLS, ca: &str, client: Option<(&str, &str)>) -> bool {
+        let acceptor = rustls_acceptor(tls).unwrap().acceptor;
+        let connector = rustls_connector(ca, client);
+        let domain = DNSNameRef::try_from_ascii_str("localhost").unwrap();
+        Runtime::new().unwrap().block_on(async move {
+            let (client_io, server_io) = tokio::io::duplex(64 * 1024);
+            let server = async move { acceptor.accept(server_io).await.is_ok() };
+            let client = async move { connector.connect(domain, client_io).await.is_ok() };
+            tokio::join!(server, client).0
+        })
+    }
+
+    /// 经`rustls_incoming`接收一次连接，返回握手及吊销校验后连接是否被交付给服务
+    fn rustls_incoming_accepted(tls: &ConfigServerTLS, ca: &str, client: (&str, &str)) -> bool {
+        let reloader = Reloader::new(tls.clone(), rustls_acceptor).unwrap();
+        let connector = rustls_connector(ca, Some(client));
+        let domain = DNSNameRef::try_from_ascii_str("localhost").unwrap();
+        Runtime::new().unwrap().block_on(async move {
+            let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let addr = listener.local_addr().unwrap();
+            let mut incoming = Box::pin(rustls_incoming(listener, reloader));
+            let stream = tokio::net::TcpStream::connect(addr).await.unwrap();
+            let _client = connector.connect(domain, stream).await;
+            match tokio::time::timeout(Duration::from_secs(2), incoming.next()).await {
+                Ok(Some(Ok(_))) => true,
+                _ => false,
+            }
+        })
+    }
+
+    /// 信任`ca`并可选携带客户端证书的`rustls`连接器
+    fn rustls_connector(ca: &str, client: Option<(&str, &str)>) -> TlsConnector {
+        let mut config = ClientConfig::new();
+        config
+            .root_store
+            .add_pem_file(&mut Cursor::new(Filer::read_bytes(ca).unwrap()))
+            .unwrap();
+        config.set_protocols(&[b"h2".to_vec()]);
+        if let Some((key, cert)) = client {
+            let key =
+                pemfile::pkcs8_private_keys(&mut Cursor::new(Filer::read_bytes(key).unwrap()))
+                    .unwrap()
+                    .remove(0);
+            let cert = pemfile::certs(&mut Cursor::new(Filer::read_bytes(cert).unwrap())).unwrap();
+            config.set_single_client_cert(cert, key).unwrap();
+        }
+        TlsConnector::from(Arc::new(config))
+    }
+
+    #[test]
+    fn openssl_client_required() {
+        let certs = generate("src/test/tls/openssl_required");
+        let tls = tls(&certs, false);
+        assert!(openssl_handshake(
+            &tls,
+            &certs.ca,
+            Some((&certs.client_key, &certs.client_cert))
+        ));
+        assert!(!openssl_handshake(&tls, &certs.ca, None));
+        assert!(!openssl_handshake(
+            &tls,
+            &certs.ca,
+            Some((&certs.rogue_key, &certs.rogue_cert))
+        ));
+    }
+
+    #[test]
+    fn openssl_client_optional() {
+        let certs = generate("src/test/tls/openssl_optional");
+        let tls = tls(&certs, true);
+        assert!(openssl_handshake(
+            &tls,
+            &certs.ca,
+            Some((&certs.client_key, &certs.client_cert))
+        ));
+        assert!(openssl_handshake(&tls, &certs.ca, None));
+        assert!(!openssl_handshake(
+            &tls,
+            &certs.ca,
+            Some((&certs.rogue_key, &certs.rogue_cert))
+        ));
+    }
+
+    #[test]
+    fn rustls_client_required() {
+        let certs = generate("src/test/tls/rustls_required");
+        let tls = tls(&certs, false);
+        assert!(rustls_handshake(
+            &tls,
+            &certs.ca,
+            Some((&certs.client_key, &certs.client_cert))
+        ));
+        assert!(!rustls_handshake(&tls, &certs.ca, None));
+        assert!(!rustls_handshake(
+            &tls,
+            &certs.ca,
+            Some((&certs.rogue_key, &certs.rogue_cert))
+        ));
+    }
+
+    #[test]
+    fn rustls_client_optional() {
+        let certs = generate("src/test/tls/rustls_optional");
+        let tls = tls(&certs, true);
+        assert!(rustls_handshake(
+            &tls,
+            &certs.ca,
+            Some((&certs.client_key, &certs.client_cert))
+        ));
+        assert!(rustls_handshake(&tls, &certs.ca, None));
+        assert!(!rustls_handshake(
+            &tls,
+            &certs.ca,
+            Some((&certs.rogue_key, &certs.rogue_cert))
+        ));
+    }
+
+    /// 以`openssl`接收器完成一次握手，返回服务端出示的证书摘要
+    fn openssl_presented(acceptor: SslAcceptor) -> Vec<u8> {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            if let Ok(mut res) = acceptor.accept(stream) {
+                let _ = res.write_all(b"ok");
+            }
+        });
+        let mut builder = SslConnector::builder(SslMethod::tls()).unwrap();
+        builder.set_verify(SslVerifyMode::NONE);
+        builder.set_alpn_protos(ALPN_H2_WIRE).unwrap();
+        let stream = TcpStream::connect(addr).unwrap();
+        let mut res = builder.build().connect("localhost", stream).unwrap();
+        let digest = res
+            .ssl()
+            .peer_certificate()
+            .unwrap()
+            .digest(MessageDigest::sha256())
+            .unwrap()
+            .to_vec();
+        let mut buf = [0u8; 2];
+        let _ = res.read_exact(&mut buf);
+        server.join().unwrap();
+        digest
+    }
+
+    /// 证书文件摘要
+    fn fingerprint(cert: &str) -> Vec<u8> {
+        Cert::load_pem_file(cert)
+            .unwrap()
+            .x509
+            .digest(MessageDigest::sha256())
+            .unwrap()
+            .to_vec()
+    }
+
+    /// 在`dir`下签发新的服务端证书并覆盖当前服务端私钥及证书文件，返回新证书摘要
+    fn swap(certs: &Certs, dir: &str) -> Vec<u8> {
+        let key = format!("{}/swap.key", dir);
+        let cert = format!("{}/swap.pem", dir);
+        let rsa = RSA::new(2048).unwrap();
+        let ca = root(&rsa, "CNSwap");
+        user(&ca, &rsa, "localhost", &key, &cert, false);
+        fs::copy(&key, &certs.server_key).unwrap();
+        fs::copy(&cert, &certs.server_cert).unwrap();
+        fingerprint(&cert)
+    }
+
+    /// 在`timeout`内等待接收器出示摘要为`expect`的证书
+    fn presented_within(
+        reloader: &Reloader<SslAcceptor>,
+        expect: &[u8],
+        timeout: Duration,
+        before: &mut dyn FnMut(),
+    ) -> bool {
+        let start = Instant::now();
+        while start.elapsed() < timeout {
+            before();
+            if openssl_presented(reloader.acceptor()).eq(expect) {
+                return true;
+            }
+            thread::sleep(Duration::from_millis(200));
+        }
+        false
+    }
+
+    #[test]
+    fn reload_swap_certificate() {
+        let dir = "src/test/tls/reload_swap";
+        let certs = generate(dir);
+        let reloader = Reloader::new(tls(&certs, true), openssl_acceptor).unwrap();
+        let old = fingerprint(&certs.server_cert);
+        assert_eq!(openssl_presented(reloader.acceptor()), old);
+        let new = swap(&certs, dir);
+        // 重新加载前新建连接仍使用原证书
+        assert_eq!(openssl_presented(reloader.acceptor()), old);
+        reloader.reload().unwrap();
+        assert_eq!(openssl_presented(reloader.acceptor()), new);
+    }
+
+    #[test]
+    fn reload_bad_certificate() {
+        let certs = generate("src/test/tls/reload_bad");
+        let reloader = Reloader::new(tls(&certs, true), openssl_acceptor).unwrap();
+        let old = fingerprint(&certs.server_cert);
+        fs::write(&certs.server_cert, "not a certificate").unwrap();
+        assert!(reloader.reload().is_err());
+        assert_eq!(openssl_presented(reloader.acceptor()), old);
+    }
+
+    #[test]
+    fn reload_poll() {
+        let dir = "src/test/tls/reload_poll";
+        let certs = generate(dir);
+        let mut tls = tls(&certs, true);
+        tls.reload_interval = Some(1);
+        let reloader = Reloader::new(tls, openssl_acceptor).unwrap();
+        let runtime = Runtime::new().unwrap();
+        let _guard = runtime.enter();
+        Reloader::watch(reloader.clone());
+        // 等待检测任务记录证书文件初始修改时间
+        thread::sleep(Duration::from_millis(1100));
+        let new = swap(&certs, dir);
+        assert!(presented_within(
+            &reloader,
+            &new,
+            Duration::from_secs(10),
+            &mut || {}
+        ));
+
+        // 变更后的证书无法加载时继续使用当前证书
+        fs::write(&certs.server_cert, "not a certificate").unwrap();
+        thread::sleep(Duration::from_millis(2500));
+        assert_eq!(openssl_presented(reloader.acceptor()), new);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn reload_hangup() {
+        use nix::sys::signal::{raise, Signal};
+        use tokio::signal::unix::{signal, SignalKind};
+
+        let dir = "src/test/tls/reload_hangup";
+        let certs = generate(dir);
+        let reloader = Reloader::new(tls(&certs, true), openssl_acceptor).unwrap();
+        let runtime = Runtime::new().unwrap();
+        let _guard = runtime.enter();
+        // 先行注册信号监听，避免监听任务就绪前收到的`SIGHUP`按默认行为结束测试进程
+        let _hangup = signal(SignalKind::hangup()).unwrap();
+        Reloader::watch(reloader.clone());
+        let old = fingerprint(&certs.server_cert);
+        let new = swap(&certs, dir);
+        assert_eq!(openssl_presented(reloader.acceptor()), old);
+        assert!(presented_within(
+            &reloader,
+            &new,
+            Duration::from_secs(10),
+            &mut || raise(Signal::SIGHUP).unwrap()
+        ));
+
+        // 收到信号时证书无法加载则继续使用当前证书
+        fs::write(&certs.server_cert, "not a certificate").unwrap();
+        raise(Signal::SIGHUP).unwrap();
+        thread::sleep(Duration::from_millis(500));
+        assert_eq!(openssl_presented(reloader.acceptor()), new);
+    }
+
+    #[test]
+    fn openssl_client_revoked() {
+        let certs = generate("src/test/tls/openssl_revoked");
+        let plain = tls(&certs, false);
+        let tls = tls_crl(&certs, &certs.crl);
+        assert!(openssl_handshake(
+            &tls,
+            &certs.ca,
+            Some((&certs.client_key, &certs.client_cert))
+        ));
+        assert!(!openssl_handshake(
+            &tls,
+            &certs.ca,
+            Some((&certs.revoked_key, &certs.revoked_cert))
+        ));
+        // 未配置吊销列表时不校验吊销状态
+        assert!(openssl_handshake(
+            &plain,
+            &certs.ca,
+            Some((&certs.revoked_key, &certs.revoked_cert))
+        ));
+    }
+
+    #[test]
+    fn rustls_client_revoked() {
+        let certs = generate("src/test/tls/rustls_revoked");
+        let tls = tls_crl(&certs, &certs.crl);
+        assert!(rustls_incoming_accepted(
+            &tls,
+            &certs.ca,
+            (&certs.client_key, &certs.client_cert)
+        ));
+        // 吊销校验在握手完成后进行，握手本身成功但连接不会交付给服务
+        assert!(rustls_handshake(
+            &tls,
+            &certs.ca,
+            Some((&certs.revoked_key, &certs.revoked_cert))
+        ));
+        assert!(!rustls_incoming_accepted(
+            &tls,
+            &certs.ca,
+            (&certs.revoked_key, &certs.revoked_cert)
+        ));
+    }
+
+    #[test]
+    fn client_crl_unparsable() {
+        let certs = generate("src/test/tls/crl_unparsable");
+        let tls = tls_crl(&certs, &certs.bad_crl);
+        assert!(openssl_acceptor(&tls).is_err());
+        assert!(rustls_acceptor(&tls).is_err());
+
+        // 热加载时吊销列表无法解析则继续使用原接收器，已吊销证书仍被拒绝
+        let reloader = Reloader::new(tls_crl(&certs, &certs.crl), openssl_acceptor).unwrap();
+        fs::copy(&certs.bad_crl, &certs.crl).unwrap();
+        assert!(reloader.reload().is_err());
+        assert!(!openssl_accept(
+            reloader.acceptor(),
+            &certs.ca,
+            Some((&certs.revoked_key, &certs.revoked_cert))
+        ));
+        assert!(openssl_accept(
+            reloader.acceptor(),
+            &certs.ca,
+            Some((&certs.client_key, &certs.client_cert))
+        ));
+    }
+}
@@ -12,82 +12,111 @@
  * limitations under the License.
  */
 
+use std::env;
+use std::process;
+use std::process::Stdio;
+use std::time::Duration;
+
 use clap::{App, Arg, ArgMatches, SubCommand};
-use tokio::sync::mpsc;
 
-use george_comm::io::file::FilerHandler;
-use george_comm::io::Filer;
+use george_comm::errors::{Errs, GeorgeResult};
 use george_deploy::comm::DEPLOY_START_CONFIG_FILEPATH;
-use george_deploy::Builder;
+use george_deploy::{Builder, Init};
+
+use crate::cmd::{Daemon, Service, Start};
 
-use crate::cmd::{Service, Start};
+/// 后台启动时等待服务进程写入PID文件的最长时间，单位秒
+const START_WAIT_SECS: u64 = 30;
 
 impl Start {
     pub fn subcommand() -> App<'static, 'static> {
         SubCommand::with_name("start")
             .version(Builder::version())
-            .about("this is start")
+            .about("start george-server, run in foreground unless daemon is set")
             .arg(
                 Arg::with_name("file")
                     .short("f")
                     .long("config-filepath")
-                    .help("this is file")
+                    .help("config filepath")
                     .takes_value(true),
             )
             .arg(
                 Arg::with_name("filepath")
                     .long("config-filepath-custom")
-                    .help("this is file")
+                    .help("config filepath")
                     .takes_value(true),
             )
+            .arg(
+                Arg::with_name("daemon")
+                    .short("d")
+                    .long("daemon")
+                    .help("run in background and return after george-server started"),
+            )
     }
 
     pub fn matches(matches: &ArgMatches) {
-        if let Some(res) = matches.value_of("file") {
-            println!("prepare run with file...");
-            // start(Some(res.to_string()))
-            Service::start(res)
-        } else if let Some(res) = matches.value_of("filepath") {
-            println!("prepare run...");
-            Service::start(res)
+        let filepath = Start::filepath(matches);
+        if matches.is_present("daemon") {
+            match Start::daemon(filepath) {
+                Ok(daemon) => println!(
+                    "george-server started, [PID] {} running on {}...",
+                    daemon.pid(),
+                    daemon.addr()
+                ),
+                Err(err) => {
+                    println!("george-server start failed! error is {}", err);
+                    process::exit(1)
+                }
+            }
         } else {
-            println!("prepare run with no file...");
-            start(None)
+            println!("starting with file {}...", filepath);
+            Service::start(filepath)
         }
     }
-}
 
-fn start(filepath: Option<String>) {
-    if Filer::exist("lock") {
-        println!("george already started or try to use restart instead!");
-        return;
-    }
-    println!("starting...");
-    let fp: String;
-    match filepath {
-        Some(path) => fp = path,
-        None => fp = DEPLOY_START_CONFIG_FILEPATH.to_string(),
+    /// 配置文件路径，未指定则使用默认配置文件
+    pub(crate) fn filepath(matches: &ArgMatches) -> String {
+        if let Some(res) = matches.value_of("file") {
+            res.to_string()
+        } else if let Some(res) = matches.value_of("filepath") {
+            res.to_string()
+        } else {
+            DEPLOY_START_CONFIG_FILEPATH.to_string()
+        }
     }
-    // let program = format!("./george-server start --config-filepath-custom {}", fp);
-    // println!("program: {}", program);
-    let rt = tokio::runtime::Runtime::new().unwrap();
-    rt.block_on(server_start(fp));
-    println!("george-server started, [PID] {} running...", 100);
-}
 
-async fn server_start(filepath: String) {
-    let (sender, mut receiver) = mpsc::channel(32);
-    tokio::spawn(async move {
-        match sender.send(Service::start(filepath)).await {
-            Err(err) => {
-                panic!("sender send error {}", err);
+    /// 以当前可执行文件在后台启动服务，并等待服务进程写入PID文件
+    pub(crate) fn daemon(filepath: String) -> GeorgeResult<Daemon> {
+        let init = Init::from(filepath.clone())?;
+        let pid_filepath = Daemon::filepath(&init);
+        if let Some(daemon) = Daemon::load(pid_filepath.clone())? {
+            if daemon.alive() {
+                return Err(Errs::string(format!(
+                    "george-server already started with pid {}, try to use restart instead!",
+                    daemon.pid()
+                )));
             }
-            _ => {}
         }
-    });
-
-    println!("send finish...");
-    while let Some(_) = receiver.recv().await {
-        println!("success!");
+        let exe = match env::current_exe() {
+            Ok(res) => res,
+            Err(err) => return Err(Errs::strs("current exe", err)),
+        };
+        let mut child = match process::Command::new(exe)
+            .arg("start")
+            .arg("--config-filepath")
+            .arg(filepath)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+        {
+            Ok(res) => res,
+            Err(err) => return Err(Errs::strs("spawn george-server", err)),
+        };
+        Daemon::wait(
+            pid_filepath,
+            &mut child,
+            Duration::from_secs(START_WAIT_SECS),
+        )
     }
 }
@@ -12,18 +12,69 @@
  * limitations under the License.
  */
 
-use crate::cmd::Status;
-use clap::{App, SubCommand};
-use george_deploy::Builder;
+use std::process;
+
+use clap::{App, Arg, ArgMatches, SubCommand};
+
+use george_deploy::{Builder, Init};
+
+use crate::cmd::daemon::uptime_string;
+use crate::cmd::{Daemon, Start, Status};
+
+/// 服务正在运行
+const STATUS_RUNNING: i32 = 0;
+/// 服务进程已退出但PID文件仍存在
+const STATUS_DEAD: i32 = 1;
+/// 服务未运行
+const STATUS_STOPPED: i32 = 3;
+/// 服务状态未知
+const STATUS_UNKNOWN: i32 = 4;
 
 impl Status {
     pub fn subcommand() -> App<'static, 'static> {
         SubCommand::with_name("status")
             .version(Builder::version())
-            .about("this is status")
+            .about("show george-server status, exit with 0 if running")
+            .arg(
+                Arg::with_name("file")
+                    .short("f")
+                    .long("config-filepath")
+                    .help("config filepath which george-server started with")
+                    .takes_value(true),
+            )
     }
 
-    pub fn matches() {
-        println!("status default");
+    /// 输出服务状态，退出码参考`LSB`约定，0为运行中，1为进程已退出但PID文件残留，3为未运行，4为未知
+    pub fn matches(matches: &ArgMatches) {
+        let daemon = match Init::from(Start::filepath(matches))
+            .and_then(|init| Daemon::load(Daemon::filepath(&init)))
+        {
+            Ok(res) => res,
+            Err(err) => {
+                println!("george-server status unknown! error is {}", err);
+                process::exit(STATUS_UNKNOWN)
+            }
+        };
+        match daemon {
+            Some(daemon) if daemon.alive() => {
+                println!("george-server is running");
+                println!("    pid: {}", daemon.pid());
+                println!("    uptime: {}", uptime_string(daemon.uptime()));
+                println!("    listen: {}", daemon.addr());
+                println!("    data dir: {}", daemon.data_dir());
+                process::exit(STATUS_RUNNING)
+            }
+            Some(daemon) => {
+                println!(
+                    "george-server is dead but pid file exists with pid {}",
+                    daemon.pid()
+                );
+                process::exit(STATUS_DEAD)
+            }
+            None => {
+                println!("george-server is not running");
+                process::exit(STATUS_STOPPED)
+            }
+        }
     }
 }
@@ -12,18 +12,76 @@
  * limitations under the License.
  */
 
-use crate::cmd::Stop;
-use clap::{App, SubCommand};
-use george_deploy::Builder;
+use std::process;
+use std::time::Duration;
+
+use clap::{App, Arg, ArgMatches, SubCommand};
+
+use george_comm::errors::{Errs, GeorgeResult};
+use george_deploy::{Builder, Init};
+
+use crate::cmd::{Daemon, Start, Stop};
+
+/// 等待服务进程退出的默认最长时间，单位秒
+pub(crate) const STOP_TIMEOUT_SECS: &str = "30";
 
 impl Stop {
     pub fn subcommand() -> App<'static, 'static> {
         SubCommand::with_name("stop")
             .version(Builder::version())
-            .about("this is stop")
+            .about("stop george-server gracefully and wait for it to exit")
+            .arg(
+                Arg::with_name("file")
+                    .short("f")
+                    .long("config-filepath")
+                    .help("config filepath which george-server started with")
+                    .takes_value(true),
+            )
+            .arg(
+                Arg::with_name("timeout")
+                    .short("t")
+                    .long("timeout")
+                    .help("secs to wait for george-server to exit")
+                    .default_value(STOP_TIMEOUT_SECS)
+                    .takes_value(true),
+            )
+    }
+
+    pub fn matches(matches: &ArgMatches) {
+        let result = Stop::timeout(matches)
+            .and_then(|timeout| Stop::stop(Start::filepath(matches), timeout));
+        match result {
+            Ok(Some(pid)) => println!("george-server with pid {} stopped!", pid),
+            Ok(None) => println!("george-server is not running!"),
+            Err(err) => {
+                println!("george-server stop failed! error is {}", err);
+                process::exit(1)
+            }
+        }
+    }
+
+    /// 等待服务进程退出的最长时间
+    pub(crate) fn timeout(matches: &ArgMatches) -> GeorgeResult<Duration> {
+        match matches
+            .value_of("timeout")
+            .unwrap_or(STOP_TIMEOUT_SECS)
+            .parse::<u64>()
+        {
+            Ok(res) => Ok(Duration::from_secs(res)),
+            Err(err) => Err(Errs::strs("timeout parse", err)),
+        }
     }
 
-    pub fn matches() {
-        println!("stop default");
+    /// 优雅停止服务进程并等待其退出，返回被停止的服务进程号，服务未运行则返回None
+    pub(crate) fn stop(filepath: String, timeout: Duration) -> GeorgeResult<Option<u32>> {
+        let init = Init::from(filepath)?;
+        match Daemon::load(Daemon::filepath(&init))? {
+            Some(daemon) if daemon.alive() => {
+                println!("stopping george-server with pid {}...", daemon.pid());
+                daemon.stop(timeout)?;
+                Ok(Some(daemon.pid()))
+            }
+            _ => Ok(None),
+        }
     }
 }
@@ -12,7 +12,7 @@
  * limitations under the License.
  */
 
-use crate::cmd::{Command, Service};
+use crate::cmd::Command;
 
 mod cmd;
 
@@ -26,5 +26,5 @@ fn main() {
     // Service::start("george-server/src/examples/conf_tls_terraform_1.yaml")
     // Service::start("george-server/src/examples/conf_tls_1.yaml")
     // Service::start("george-server/src/examples/conf_pki_1.yaml")
-    Service::start("george-server/src/examples/conf_tls.yaml")
+    // Service::start("george-server/src/examples/conf_tls.yaml")
 }
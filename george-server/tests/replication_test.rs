@@ -0,0 +1,153 @@
+/*
+ * Copyright (c) 2021. Aberic - All Rights Reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[cfg(test)]
+mod replication {
+    use std::net::TcpListener;
+    use std::process::{Child, Command, Stdio};
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    use george_comm::io::file::FilerWriter;
+    use george_comm::io::Filer;
+    use george_comm::Time;
+    use george_rpc::client::db::{DatabaseRpcClient, ReplicationRpcClient};
+    use george_rpc::client::RpcClient;
+
+    /// 等待节点启动及变更同步的最长时间
+    const WAIT: Duration = Duration::from_secs(30);
+
+    /// 测试结束时终止的服务进程
+    struct Node(Child);
+
+    impl Drop for Node {
+        fn drop(&mut self) {
+            let _ = self.0.kill();
+            let _ = self.0.wait();
+        }
+    }
+
+    /// 获取一个空闲端口
+    fn port() -> u16 {
+        TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port()
+    }
+
+    /// 写入节点配置并以独立进程启动服务
+    fn start(dir: &str, port: u16, peer_key: &str, primary: Option<u16>) -> Node {
+        let mut conf = format!(
+            "config:
+  log:
+    log_dir: {dir}/log
+    log_file_max_size: 100
+    log_file_max_count: 10
+    log_level: info
+    production: true
+  db:
+    data_dir: {dir}/george
+    thread_count: 10
+  server:
+    port: {port}
+    peer_key: {peer_key}
+",
+            dir = dir,
+            port = port,
+            peer_key = peer_key
+        );
+        if let Some(primary) = primary {
+            conf.push_str(&format!(
+                "    replication:\n      primary: 127.0.0.1:{}\n",
+                primary
+            ));
+        }
+        let filepath = format!("{}/conf.yaml", dir);
+        Filer::write_force(&filepath, conf).unwrap();
+        Node(
+            Command::new(env!("CARGO_BIN_EXE_george-server"))
+                .arg("start")
+                .arg("--config-filepath")
+                .arg(filepath)
+                .stdin(Stdio::null())
+                .stdout(Stdio::null())
+                .stderr(Stdio::null())
+                .spawn()
+                .unwrap(),
+        )
+    }
+
+    /// 重复执行直至成功或超时
+    fn wait<T>(mut f: impl FnMut() -> Option<T>) -> T {
+        let start = Instant::now();
+        loop {
+            if let Some(res) = f() {
+                return res;
+            }
+            assert!(start.elapsed() < WAIT, "wait timeout!");
+            thread::sleep(Duration::from_millis(200));
+        }
+    }
+
+    fn database_client(port: u16) -> DatabaseRpcClient {
+        wait(|| DatabaseRpcClient::new("127.0.0.1", port, None).ok())
+    }
+
+    #[test]
+    fn primary_replica() {
+        let root = format!(
+            "{}/src/test/replication/{}",
+            std::env::current_dir().unwrap().to_string_lossy(),
+            Time::now().nanos()
+        );
+        let peer_key = format!("{}/peer.key", root);
+        Filer::write_force(&peer_key, "09".repeat(32)).unwrap();
+        let (primary_port, replica_port) = (port(), port());
+
+        let _primary = start(&format!("{}/primary", root), primary_port, &peer_key, None);
+        let mut primary = database_client(primary_port);
+        wait(|| primary.create("before".to_string(), "".to_string()).ok());
+
+        // 未携带节点间认证凭证的跟随请求被拒绝
+        let mut rogue = ReplicationRpcClient::new("127.0.0.1", primary_port, None).unwrap();
+        assert!(rogue.follow(0, &mut |_, _| Ok(())).is_err());
+
+        // 从节点先拉取快照，随后跟随主节点的后续变更
+        let _replica = start(
+            &format!("{}/replica", root),
+            replica_port,
+            &peer_key,
+            Some(primary_port),
+        );
+        let mut replica = database_client(replica_port);
+        primary.create("after".to_string(), "".to_string()).unwrap();
+        wait(|| {
+            let names: Vec<String> = replica
+                .list()
+                .ok()?
+                .into_iter()
+                .map(|database| database.name)
+                .collect();
+            if names.contains(&"before".to_string()) && names.contains(&"after".to_string()) {
+                Some(())
+            } else {
+                None
+            }
+        });
+        let mut status = ReplicationRpcClient::new("127.0.0.1", replica_port, None).unwrap();
+        let state = wait(|| status.status().ok().filter(|state| state.lag == 0));
+        assert_eq!(state.role, "replica");
+    }
+}